@@ -0,0 +1,112 @@
+// concrete-core-ffi/codegen/build.rs
+
+use concrete_core_representation::load_ccr;
+use std::env;
+use std::fs::File;
+use std::io::Write;
+use std::path::PathBuf;
+use std::process::{Command, Stdio};
+
+pub mod generation;
+pub mod prune;
+
+fn main() -> Result<(), String> {
+    // We load the neighbouring ccr
+    let mut ccr = load_ccr(get_concrete_core_root());
+
+    // We prune the ccr from unneeded nodes
+    prune::prune(&mut ccr);
+
+    // We generate the binding
+    let binding = generation::generate_binding(&ccr).to_string();
+
+    // We format the binding with rustfmt
+    let formatted_binding = format_binding(binding)?;
+
+    // We write the binding to a `__gen.rs` file
+    write_gen_rs(&formatted_binding);
+
+    // We generate the header from the crate sources and the generated binding
+    write_header();
+
+    Ok(())
+}
+
+fn write_gen_rs(binding: &str) {
+    let mut file = File::create(gen_rs_path()).unwrap();
+    file.write_all(binding.as_bytes()).unwrap();
+}
+
+fn write_header() {
+    let crate_dir = env::var("CARGO_MANIFEST_DIR").unwrap();
+    let package_name = env::var("CARGO_PKG_NAME").unwrap();
+    let output_file = target_dir()
+        .join(format!("{}.h", package_name))
+        .display()
+        .to_string();
+
+    // The generated binding is not part of the crate sources cbindgen parses, hence we add it
+    // explicitly.
+    cbindgen::Builder::new()
+        .with_config(cbindgen::Config::from_root_or_default(&crate_dir))
+        .with_crate(&crate_dir)
+        .with_src(gen_rs_path())
+        .generate()
+        .unwrap()
+        .write_to_file(output_file);
+}
+
+fn gen_rs_path() -> PathBuf {
+    out_dir().join("__gen.rs")
+}
+
+fn out_dir() -> PathBuf {
+    PathBuf::from(env::var("OUT_DIR").unwrap())
+}
+
+/// Find the location of the `target/` directory. Note that this may be
+/// overridden by `cmake`, so we also need to check the `CARGO_TARGET_DIR`
+/// variable.
+fn target_dir() -> PathBuf {
+    if let Ok(target) = env::var("CARGO_TARGET_DIR") {
+        PathBuf::from(target)
+    } else {
+        PathBuf::from(env::var("CARGO_MANIFEST_DIR").unwrap()).join("../target/release")
+    }
+}
+
+fn get_concrete_core_root() -> PathBuf {
+    PathBuf::from(env::var("CARGO_MANIFEST_DIR").unwrap())
+        .join("..")
+        .canonicalize()
+        .unwrap()
+        .join("concrete-core/src/lib.rs")
+}
+
+fn format_binding(input: String) -> Result<String, String> {
+    let mut rustfmt = Command::new("rustfmt")
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .spawn()
+        .map_err(|e| format!("Failed to execute rustfmt: {}", e))?;
+    // The input is written from another thread, so that rustfmt does not block on a full stdout
+    // while its stdin is being written. The stdin is closed when the thread returns.
+    let stdin = rustfmt.stdin.take();
+    let writer = std::thread::spawn(move || {
+        stdin
+            .ok_or_else(|| "Failed to take stdin".to_string())?
+            .write_all(input.as_bytes())
+            .map_err(|e| format!("Failed to write to rustfmt stdin: {}", e))
+    });
+    // The child is waited on even if its input could not be written.
+    let output = rustfmt
+        .wait_with_output()
+        .map_err(|e| format!("Failed to gather rustfmt output: {}", e))?;
+    writer
+        .join()
+        .map_err(|_| "The thread writing to rustfmt panicked".to_string())??;
+    if !output.status.success() {
+        return Err("Failed to format binding.".to_string());
+    }
+    String::from_utf8(output.stdout).map_err(|e| format!("Failed to read rustfmt output: {}", e))
+}
@@ -0,0 +1,743 @@
+//! This module contains a function that generates the binding token stream from the pruned ccr.
+use concrete_core_representation::{ConcreteCore, *};
+use quote::{format_ident, quote, ToTokens};
+use syn::__private::{Span, TokenStream2};
+use syn::{parse_quote, Ident, Type};
+
+/// Generate the whole binding from the pruned ccr.
+pub fn generate_binding(ccr: &ConcreteCore) -> TokenStream2 {
+    let mut output = TokenStream2::new();
+    output.extend(generate_all_entities(ccr));
+    output.extend(generate_all_engines(ccr));
+    output
+}
+
+fn generate_all_entities(ccr: &ConcreteCore) -> TokenStream2 {
+    let mut output = TokenStream2::new();
+    for entity in ccr
+        .backends
+        .iter()
+        .flat_map(|backend| backend.entities.iter())
+    {
+        output.extend(generate_entity_destructor(entity));
+    }
+    output
+}
+
+fn generate_entity_destructor(entity: &Entity) -> TokenStream2 {
+    // INVARIANT: entities are available in the `concrete_core::prelude` module
+    let ident = entity.definition.get_ident();
+    let cfg = &entity.definition.cfg;
+    let (base, precision) = entity_name_fragments(ident);
+    let (checked_ident, unchecked_ident) = match precision {
+        Some(precision) => (
+            format_ident!("destroy_{}_{}", base, precision),
+            format_ident!("destroy_{}_unchecked_{}", base, precision),
+        ),
+        None => (
+            format_ident!("destroy_{}", base),
+            format_ident!("destroy_{}_unchecked", base),
+        ),
+    };
+    let checked_doc = format!(
+        "Destroy a `{}`.\n\n\
+        This function is [checked](crate#safety-checked-and-unchecked-functions).",
+        ident
+    );
+    let unchecked_doc = format!(
+        "[Unchecked](crate#safety-checked-and-unchecked-functions) version of [`{}`]",
+        checked_ident
+    );
+    quote! {
+        #[doc = #checked_doc]
+        #[no_mangle]
+        #cfg
+        pub unsafe extern "C" fn #checked_ident(entity: *mut #ident) -> c_int {
+            catch_panic(|| {
+                check_ptr_is_non_null_and_aligned(entity).unwrap();
+
+                // Reconstruct the box and drop it
+                drop(Box::from_raw(entity));
+            })
+        }
+
+        #[doc = #unchecked_doc]
+        #[no_mangle]
+        #cfg
+        pub unsafe extern "C" fn #unchecked_ident(entity: *mut #ident) -> c_int {
+            catch_panic(|| {
+                // Reconstruct the box and drop it
+                drop(Box::from_raw(entity));
+            })
+        }
+    }
+}
+
+fn generate_all_engines(ccr: &ConcreteCore) -> TokenStream2 {
+    let mut output = TokenStream2::new();
+    for engine in ccr
+        .backends
+        .iter()
+        .flat_map(|backend| backend.engines.iter())
+    {
+        output.extend(generate_engine_constructor(engine));
+        output.extend(generate_engine_destructor(engine));
+        for engine_impl in engine.engine_impls.iter() {
+            output.extend(generate_engine_function(engine_impl, Checking::Checked));
+            output.extend(generate_engine_function(engine_impl, Checking::Unchecked));
+        }
+    }
+    output
+}
+
+fn generate_engine_constructor(engine: &Engine) -> TokenStream2 {
+    // INVARIANT: engines are available in the `concrete_core::prelude` module
+    // INVARIANT: the constructor parameter is either a `Box<dyn Seeder>` or `()`.
+    let engine_ident = engine.definition.get_name();
+    let cfg = &engine.definition.cfg;
+    let snake_engine = camel_case_to_snake_case(&engine_ident.to_string());
+    let checked_ident = format_ident!("new_{}", snake_engine);
+    let unchecked_ident = format_ident!("new_{}_unchecked", snake_engine);
+    let checked_doc;
+    let unchecked_doc = format!(
+        "[Unchecked](crate#safety-checked-and-unchecked-functions) version of [`{}`]",
+        checked_ident
+    );
+    let (public_args, checked_parameters, unchecked_parameters);
+
+    let parameters = engine.abstract_engine_impl.get_parameters_associated_type();
+    let seeder_ast: Type = parse_quote!(Box<dyn Seeder>);
+    let unit_ast: Type = parse_quote!(());
+    if *parameters == seeder_ast {
+        checked_doc = format!(
+            "Create a new `{}`.\n\n\
+            Requires a [`SeederBuilder`] to seed the random generators of the engine.\n\n\
+            This function is [checked](crate#safety-checked-and-unchecked-functions).",
+            engine_ident
+        );
+        public_args = quote!(seeder_builder: *mut SeederBuilder,);
+        checked_parameters = quote! {
+            get_mut_checked(seeder_builder)
+                .unwrap()
+                .create_seeder()
+                .unwrap()
+        };
+        unchecked_parameters = quote!((*seeder_builder).create_seeder().unwrap());
+    } else if *parameters == unit_ast {
+        checked_doc = format!(
+            "Create a new `{}`.\n\n\
+            This function is [checked](crate#safety-checked-and-unchecked-functions).",
+            engine_ident
+        );
+        public_args = quote!();
+        checked_parameters = quote!(());
+        unchecked_parameters = quote!(());
+    } else {
+        panic!(
+            "Unexpected parameters for engine `{}`: {}",
+            engine_ident,
+            parameters.to_token_stream()
+        );
+    }
+
+    quote! {
+        #[doc = #checked_doc]
+        #[no_mangle]
+        #cfg
+        pub unsafe extern "C" fn #checked_ident(
+            #public_args
+            result: *mut *mut #engine_ident,
+        ) -> c_int {
+            catch_panic(|| {
+                check_ptr_is_non_null_and_aligned(result).unwrap();
+
+                // First fill the result with a null ptr so that if we fail and the return code is
+                // not checked, then any access to the result pointer will segfault (mimics malloc
+                // on failure)
+                *result = std::ptr::null_mut();
+
+                let heap_allocated_engine = Box::new(
+                    #engine_ident::new(#checked_parameters)
+                        .or_else(engine_error_as_readable_string)
+                        .unwrap()
+                );
+                *result = Box::into_raw(heap_allocated_engine);
+            })
+        }
+
+        #[doc = #unchecked_doc]
+        #[no_mangle]
+        #cfg
+        pub unsafe extern "C" fn #unchecked_ident(
+            #public_args
+            result: *mut *mut #engine_ident,
+        ) -> c_int {
+            catch_panic(|| {
+                // First fill the result with a null ptr so that if we fail and the return code is
+                // not checked, then any access to the result pointer will segfault (mimics malloc
+                // on failure)
+                *result = std::ptr::null_mut();
+
+                let heap_allocated_engine = Box::new(
+                    #engine_ident::new(#unchecked_parameters)
+                        .or_else(engine_error_as_readable_string)
+                        .unwrap()
+                );
+                *result = Box::into_raw(heap_allocated_engine);
+            })
+        }
+    }
+}
+
+fn generate_engine_destructor(engine: &Engine) -> TokenStream2 {
+    let engine_ident = engine.definition.get_name();
+    let cfg = &engine.definition.cfg;
+    let snake_engine = camel_case_to_snake_case(&engine_ident.to_string());
+    let checked_ident = format_ident!("destroy_{}", snake_engine);
+    let unchecked_ident = format_ident!("destroy_{}_unchecked", snake_engine);
+    let checked_doc = format!(
+        "Destroy a `{}`.\n\n\
+        This function is [checked](crate#safety-checked-and-unchecked-functions).",
+        engine_ident
+    );
+    let unchecked_doc = format!(
+        "[Unchecked](crate#safety-checked-and-unchecked-functions) version of [`{}`]",
+        checked_ident
+    );
+    quote! {
+        #[doc = #checked_doc]
+        #[no_mangle]
+        #cfg
+        pub unsafe extern "C" fn #checked_ident(engine: *mut #engine_ident) -> c_int {
+            catch_panic(|| {
+                check_ptr_is_non_null_and_aligned(engine).unwrap();
+
+                // Reconstruct the box and drop it
+                drop(Box::from_raw(engine));
+            })
+        }
+
+        #[doc = #unchecked_doc]
+        #[no_mangle]
+        #cfg
+        pub unsafe extern "C" fn #unchecked_ident(engine: *mut #engine_ident) -> c_int {
+            catch_panic(|| {
+                // Reconstruct the box and drop it
+                drop(Box::from_raw(engine));
+            })
+        }
+    }
+}
+
+/// Whether the generated function performs the checks or not.
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Checking {
+    Checked,
+    Unchecked,
+}
+
+fn generate_engine_function(engine_impl: &EngineTraitImpl, checking: Checking) -> TokenStream2 {
+    let engine_ident = &engine_impl.engine_type_ident;
+    let cfg = &engine_impl.cfg;
+    let function_ident = generate_engine_function_ident(engine_impl, checking);
+    let function_doc = generate_engine_function_doc(engine_impl, checking);
+    let function_args = generate_engine_function_args(engine_impl);
+    let function_return_arg = generate_engine_function_return_arg(engine_impl);
+    let engine_prelude = generate_engine_function_engine_prelude(checking);
+    let return_prelude = generate_engine_function_return_prelude(engine_impl, checking);
+    let args_prelude = generate_engine_function_args_prelude(engine_impl, checking);
+    let call = generate_engine_function_call(engine_impl, checking);
+    let return_epilogue = generate_engine_function_return_epilogue(engine_impl, call);
+    quote! {
+        #[doc = #function_doc]
+        #[no_mangle]
+        #cfg
+        pub unsafe extern "C" fn #function_ident(
+            engine: *mut #engine_ident,
+            #function_args
+            #function_return_arg
+        ) -> c_int {
+            catch_panic(|| {
+                #return_prelude
+                #engine_prelude
+                #args_prelude
+                #return_epilogue
+            })
+        }
+    }
+}
+
+fn generate_engine_function_ident(engine_impl: &EngineTraitImpl, checking: Checking) -> Ident {
+    let output = format!(
+        "{}_{}{}_{}",
+        camel_case_to_snake_case(&engine_impl.engine_type_ident.to_string()),
+        engine_impl.checked_method.to_fragment(),
+        match checking {
+            Checking::Checked => "",
+            Checking::Unchecked => "_unchecked",
+        },
+        engine_impl
+            .engine_trait_parameters()
+            .iter()
+            .map(ToNameFragment::to_fragment)
+            .collect::<Vec<_>>()
+            .join("_")
+    );
+    Ident::new(output.as_str(), Span::call_site())
+}
+
+fn generate_engine_function_doc(engine_impl: &EngineTraitImpl, checking: Checking) -> String {
+    match checking {
+        Checking::Checked => {
+            let parameters = engine_impl
+                .engine_trait_parameters()
+                .iter()
+                .map(|param| {
+                    param
+                        .get_type()
+                        .to_token_stream()
+                        .to_string()
+                        .replace(' ', "")
+                })
+                .collect::<Vec<_>>()
+                .join(", ");
+            let consumed = engine_impl
+                .checked_method
+                .args()
+                .iter()
+                .filter_map(|arg| match arg {
+                    EngineTraitImplArg::OwnedEntity(pat, _)
+                    | EngineTraitImplArg::ViewEntity(pat, _)
+                    | EngineTraitImplArg::MutViewEntity(pat, _) => Some(format!(
+                        "The `{}` entity is consumed by this call, and must not be used or \
+                        destroyed afterwards.\n\n",
+                        pat.ident
+                    )),
+                    _ => None,
+                })
+                .collect::<String>();
+            format!(
+                "Call the `{}<{}>` implementation of `{}`.\n\n\
+                Refer to `concrete-core` implementation for detailed documentation.\n\n\
+                {}\
+                This function is [checked](crate#safety-checked-and-unchecked-functions).",
+                engine_impl.engine_trait_ident, parameters, engine_impl.engine_type_ident, consumed
+            )
+        }
+        Checking::Unchecked => format!(
+            "[Unchecked](crate#safety-checked-and-unchecked-functions) version of [`{}`]",
+            generate_engine_function_ident(engine_impl, Checking::Checked)
+        ),
+    }
+}
+
+fn generate_engine_function_args(engine_impl: &EngineTraitImpl) -> TokenStream2 {
+    let mut output = TokenStream2::new();
+    engine_impl
+        .checked_method
+        .args()
+        .iter()
+        .for_each(|arg| match arg {
+            EngineTraitImplArg::OwnedEntity(pat, type_)
+            | EngineTraitImplArg::ViewEntity(pat, type_)
+            | EngineTraitImplArg::MutViewEntity(pat, type_)
+            | EngineTraitImplArg::OwnedEntityRefMut(pat, type_)
+            | EngineTraitImplArg::MutViewEntityRefMut(pat, type_) => {
+                let ident = &pat.ident;
+                let entity_ident = type_ident(type_);
+                output.extend(quote!(#ident: *mut #entity_ident,));
+            }
+            EngineTraitImplArg::OwnedEntityRef(pat, type_)
+            | EngineTraitImplArg::ViewEntityRef(pat, type_) => {
+                let ident = &pat.ident;
+                let entity_ident = type_ident(type_);
+                output.extend(quote!(#ident: *const #entity_ident,));
+            }
+            EngineTraitImplArg::Parameter(pat, _) => {
+                let ident = &pat.ident;
+                output.extend(quote!(#ident: usize,));
+            }
+            EngineTraitImplArg::Dispersion(pat, _) => {
+                let ident = &pat.ident;
+                output.extend(quote!(#ident: f64,));
+            }
+            EngineTraitImplArg::Numeric(pat, type_) => {
+                let ident = &pat.ident;
+                output.extend(quote!(#ident: #type_,));
+            }
+            EngineTraitImplArg::NumericRef(pat, _, num) => {
+                let ident = &pat.ident;
+                output.extend(quote!(#ident: #num,));
+            }
+            EngineTraitImplArg::NumericRefMut(pat, _, num) => {
+                let ident = &pat.ident;
+                output.extend(quote!(#ident: *mut #num,));
+            }
+            EngineTraitImplArg::NumericSlice(pat, _, num)
+            | EngineTraitImplArg::NumericVec(pat, _, num) => {
+                let ident = &pat.ident;
+                let len_ident = format_ident!("{}_len", pat.ident);
+                output.extend(quote!(#ident: *const #num, #len_ident: usize,));
+            }
+            EngineTraitImplArg::NumericSliceMut(pat, _, num) => {
+                let ident = &pat.ident;
+                let len_ident = format_ident!("{}_len", pat.ident);
+                output.extend(quote!(#ident: *mut #num, #len_ident: usize,));
+            }
+            _ => panic!("Unexpected arg: {:?}", arg),
+        });
+    output
+}
+
+fn generate_engine_function_return_arg(engine_impl: &EngineTraitImpl) -> TokenStream2 {
+    match engine_impl.checked_method.return_() {
+        EngineTraitImplReturn::OwnedEntity(type_)
+        | EngineTraitImplReturn::ViewEntity(type_)
+        | EngineTraitImplReturn::MutViewEntity(type_) => {
+            let entity_ident = type_ident(type_);
+            quote!(result: *mut *mut #entity_ident,)
+        }
+        EngineTraitImplReturn::Numeric(type_) => quote!(result: *mut #type_,),
+        EngineTraitImplReturn::Unit(_) => quote!(),
+        return_ => panic!("Unexpected return type: {:?}", return_),
+    }
+}
+
+fn generate_engine_function_return_prelude(
+    engine_impl: &EngineTraitImpl,
+    checking: Checking,
+) -> TokenStream2 {
+    let check = match checking {
+        Checking::Checked => quote!(check_ptr_is_non_null_and_aligned(result).unwrap();),
+        Checking::Unchecked => quote!(),
+    };
+    match engine_impl.checked_method.return_() {
+        EngineTraitImplReturn::OwnedEntity(_)
+        | EngineTraitImplReturn::ViewEntity(_)
+        | EngineTraitImplReturn::MutViewEntity(_) => quote! {
+            #check
+
+            // First fill the result with a null ptr so that if we fail and the return code is not
+            // checked, then any access to the result pointer will segfault (mimics malloc on
+            // failure)
+            *result = std::ptr::null_mut();
+        },
+        EngineTraitImplReturn::Numeric(_) => check,
+        _ => quote!(),
+    }
+}
+
+fn generate_engine_function_engine_prelude(checking: Checking) -> TokenStream2 {
+    match checking {
+        Checking::Checked => quote!(let engine = get_mut_checked(engine).unwrap();),
+        Checking::Unchecked => quote!(let engine = &mut (*engine);),
+    }
+}
+
+fn generate_engine_function_args_prelude(
+    engine_impl: &EngineTraitImpl,
+    checking: Checking,
+) -> TokenStream2 {
+    let mut output = TokenStream2::new();
+    for arg in engine_impl.checked_method.args().iter() {
+        let ident = &arg.pat_ident().ident;
+        let block = match (arg, checking) {
+            (
+                EngineTraitImplArg::OwnedEntity(_, _)
+                | EngineTraitImplArg::ViewEntity(_, _)
+                | EngineTraitImplArg::MutViewEntity(_, _),
+                Checking::Checked,
+            ) => quote! {
+                check_ptr_is_non_null_and_aligned(#ident).unwrap();
+                let #ident = *Box::from_raw(#ident);
+            },
+            (
+                EngineTraitImplArg::OwnedEntity(_, _)
+                | EngineTraitImplArg::ViewEntity(_, _)
+                | EngineTraitImplArg::MutViewEntity(_, _),
+                Checking::Unchecked,
+            ) => quote!(let #ident = *Box::from_raw(#ident);),
+            (
+                EngineTraitImplArg::OwnedEntityRef(_, _) | EngineTraitImplArg::ViewEntityRef(_, _),
+                Checking::Checked,
+            ) => quote!(let #ident = get_ref_checked(#ident).unwrap();),
+            (
+                EngineTraitImplArg::OwnedEntityRef(_, _) | EngineTraitImplArg::ViewEntityRef(_, _),
+                Checking::Unchecked,
+            ) => quote!(let #ident = &(*#ident);),
+            (
+                EngineTraitImplArg::OwnedEntityRefMut(_, _)
+                | EngineTraitImplArg::MutViewEntityRefMut(_, _)
+                | EngineTraitImplArg::NumericRefMut(_, _, _),
+                Checking::Checked,
+            ) => quote!(let #ident = get_mut_checked(#ident).unwrap();),
+            (
+                EngineTraitImplArg::OwnedEntityRefMut(_, _)
+                | EngineTraitImplArg::MutViewEntityRefMut(_, _)
+                | EngineTraitImplArg::NumericRefMut(_, _, _),
+                Checking::Unchecked,
+            ) => quote!(let #ident = &mut (*#ident);),
+            (EngineTraitImplArg::Parameter(_, type_), _)
+            | (EngineTraitImplArg::Dispersion(_, type_), _) => {
+                let type_ident = type_ident(type_);
+                quote!(let #ident = #type_ident(#ident);)
+            }
+            (EngineTraitImplArg::NumericSlice(_, _, _), _)
+            | (EngineTraitImplArg::NumericVec(_, _, _), _)
+            | (EngineTraitImplArg::NumericSliceMut(_, _, _), _) => {
+                let len_ident = format_ident!("{}_len", ident);
+                let check = match checking {
+                    Checking::Checked => {
+                        quote!(check_ptr_is_non_null_and_aligned(#ident).unwrap();)
+                    }
+                    Checking::Unchecked => quote!(),
+                };
+                let conversion = match arg {
+                    EngineTraitImplArg::NumericSlice(_, _, _) => {
+                        quote!(std::slice::from_raw_parts(#ident, #len_ident))
+                    }
+                    EngineTraitImplArg::NumericVec(_, _, _) => {
+                        quote!(std::slice::from_raw_parts(#ident, #len_ident).to_vec())
+                    }
+                    _ => quote!(std::slice::from_raw_parts_mut(#ident, #len_ident)),
+                };
+                quote! {
+                    #check
+                    let #ident = #conversion;
+                }
+            }
+            (EngineTraitImplArg::NumericRef(_, _, _), _)
+            | (EngineTraitImplArg::Numeric(_, _), _) => quote!(),
+            _ => panic!("Unexpected arg: {:?}", arg),
+        };
+        output.extend(block);
+    }
+    output
+}
+
+fn generate_engine_function_call(
+    engine_impl: &EngineTraitImpl,
+    checking: Checking,
+) -> TokenStream2 {
+    let mut args = TokenStream2::new();
+    generate_engine_function_call_args_order(engine_impl, checking)
+        .into_iter()
+        .for_each(|arg| match arg {
+            EngineTraitImplArg::NumericRef(pat, _, _) => {
+                let ident = &pat.ident;
+                args.extend(quote!(&#ident,))
+            }
+            arg => {
+                let ident = &arg.pat_ident().ident;
+                args.extend(quote!(#ident,))
+            }
+        });
+    match checking {
+        Checking::Checked => {
+            let method_ident = &engine_impl.checked_method.ident;
+            quote! {
+                engine
+                    .#method_ident(#args)
+                    .or_else(engine_error_as_readable_string)
+                    .unwrap()
+            }
+        }
+        Checking::Unchecked => {
+            let method_ident = &engine_impl.unchecked_method.method.sig.ident;
+            quote!(engine.#method_ident(#args))
+        }
+    }
+}
+
+/// Returns the args in the order expected by the called method.
+///
+/// The unchecked methods may not take their arguments in the same order as the checked ones, in
+/// which case the arguments are matched by name.
+fn generate_engine_function_call_args_order(
+    engine_impl: &EngineTraitImpl,
+    checking: Checking,
+) -> Vec<&EngineTraitImplArg> {
+    let checked_args = engine_impl.checked_method.args();
+    if checking == Checking::Checked {
+        return checked_args.iter().collect();
+    }
+    let unchecked_args = engine_impl
+        .unchecked_method
+        .method
+        .sig
+        .inputs
+        .iter()
+        .filter_map(|input| {
+            probe!(
+                Some(input),
+                syn::FnArg::Typed(t) => t,
+                t -> t.pat.as_ref(),
+                syn::Pat::Ident(p) => p,
+                p -> &p.ident,
+                i >> checked_args.iter().find(|arg| arg.pat_ident().ident == *i)
+            )
+        })
+        .collect::<Vec<_>>();
+    if unchecked_args.len() == checked_args.len() {
+        unchecked_args
+    } else {
+        checked_args.iter().collect()
+    }
+}
+
+fn generate_engine_function_return_epilogue(
+    engine_impl: &EngineTraitImpl,
+    call: TokenStream2,
+) -> TokenStream2 {
+    match engine_impl.checked_method.return_() {
+        EngineTraitImplReturn::OwnedEntity(type_)
+        | EngineTraitImplReturn::ViewEntity(type_)
+        | EngineTraitImplReturn::MutViewEntity(type_) => {
+            let entity_ident = type_ident(type_);
+            quote! {
+                let heap_allocated_output: Box<#entity_ident> = Box::new(#call);
+                *result = Box::into_raw(heap_allocated_output);
+            }
+        }
+        EngineTraitImplReturn::Numeric(type_) => quote! {
+            let output: #type_ = #call;
+            *result = output;
+        },
+        EngineTraitImplReturn::Unit(_) => quote!(#call;),
+        return_ => panic!("Unexpected return type: {:?}", return_),
+    }
+}
+
+/// Returns the identifier of the last segment of a (possibly referenced) type path.
+///
+/// The generic arguments of the type, such as the lifetimes of the views, are left out to be
+/// elided in the signatures of the generated functions.
+fn type_ident(type_: &Type) -> &Ident {
+    probe!(
+        Some(type_),
+        t -> strip_references(t),
+        syn::Type::Path(p) => p,
+        p >> p.path.segments.last(),
+        s -> &s.ident
+    )
+    .unwrap_or_else(|| panic!("Failed to extract ident of `{}`", type_.to_token_stream()))
+}
+
+fn strip_references(type_: &Type) -> &Type {
+    match type_ {
+        syn::Type::Reference(r) => strip_references(r.elem.as_ref()),
+        t => t,
+    }
+}
+
+/// Splits the name of an entity in a snake cased base and the precision of the entity if any.
+///
+/// For instance, `LweCiphertext64` gives `("lwe_ciphertext", Some("u64"))`, `CleartextF64` gives
+/// `("cleartext", Some("f64"))` and `FloatEncoder` gives `("float_encoder", None)`.
+fn entity_name_fragments(ident: &Ident) -> (String, Option<String>) {
+    let snake = camel_case_to_snake_case(&ident.to_string());
+    let base = snake.trim_end_matches(|c: char| c.is_ascii_digit());
+    if base.len() == snake.len() {
+        return (snake, None);
+    }
+    let digits = &snake[base.len()..];
+    match base.strip_suffix("_f") {
+        Some(base) => (base.to_string(), Some(format!("f{}", digits))),
+        None => (
+            base.trim_end_matches('_').to_string(),
+            Some(format!("u{}", digits)),
+        ),
+    }
+}
+
+trait ToNameFragment {
+    fn to_fragment(&self) -> String;
+}
+
+impl ToNameFragment for EngineTraitImplCheckedMethod {
+    fn to_fragment(&self) -> String {
+        self.ident.to_string()
+    }
+}
+
+impl ToNameFragment for EngineTraitImplGenericArgument {
+    fn to_fragment(&self) -> String {
+        match self {
+            EngineTraitImplGenericArgument::OwnedEntity(_)
+            | EngineTraitImplGenericArgument::ViewEntity(_)
+            | EngineTraitImplGenericArgument::MutViewEntity(_)
+            | EngineTraitImplGenericArgument::Config(_) => {
+                match entity_name_fragments(type_ident(self.get_type())) {
+                    (base, Some(precision)) => format!("{}_{}", base, precision),
+                    (base, None) => base,
+                }
+            }
+            EngineTraitImplGenericArgument::Numeric(t) => type_ident(t).to_string(),
+            EngineTraitImplGenericArgument::NumericSlice(t) => probe!(
+                Some(t),
+                syn::Type::Reference(r) => r,
+                r -> r.elem.as_ref(),
+                syn::Type::Slice(s) => s,
+                s -> s.elem.as_ref(),
+                syn::Type::Path(p) => p,
+                t >> t.path.segments.first(),
+                t -> format!("{}_slice", t.ident)
+            )
+            .unwrap(),
+            EngineTraitImplGenericArgument::NumericSliceMut(t) => probe!(
+                Some(t),
+                syn::Type::Reference(r) => r,
+                r -> r.elem.as_ref(),
+                syn::Type::Slice(s) => s,
+                s -> s.elem.as_ref(),
+                syn::Type::Path(p) => p,
+                t >> t.path.segments.first(),
+                t -> format!("{}_mut_slice", t.ident)
+            )
+            .unwrap(),
+            EngineTraitImplGenericArgument::NumericVec(t) => probe!(
+                Some(t),
+                syn::Type::Path(p) => p,
+                p >> p.path.segments.first(),
+                s -> &s.arguments,
+                syn::PathArguments::AngleBracketed(a) => a,
+                a >> a.args.first(),
+                syn::GenericArgument::Type(t) => t,
+                syn::Type::Path(p) => p,
+                t >> t.path.segments.first(),
+                t -> format!("{}_vec", t.ident)
+            )
+            .unwrap(),
+            EngineTraitImplGenericArgument::Unknown(_) => {
+                panic!()
+            }
+        }
+    }
+}
+
+fn camel_case_to_snake_case(input: &str) -> String {
+    let mut output = String::new();
+    let mut iter = input.chars().peekable();
+    loop {
+        match (iter.next(), iter.peek()) {
+            (Some(ch), _) if !ch.is_ascii_alphanumeric() => {
+                panic!()
+            }
+            (Some(ch), Some(nch)) if ch.is_ascii_uppercase() && nch.is_ascii_lowercase() => {
+                output.push(ch.to_ascii_lowercase())
+            }
+            (Some(ch), Some(nch)) if ch.is_ascii_uppercase() && nch.is_ascii_uppercase() => {
+                output.push(ch.to_ascii_lowercase());
+                output.push('_')
+            }
+            (Some(ch), Some(nch)) if nch.is_ascii_uppercase() => {
+                output.push(ch);
+                output.push('_')
+            }
+            (Some(ch), _) => output.push(ch.to_ascii_lowercase()),
+            (None, None) => break,
+            _ => panic!("Failed to transform case of `{}`", input),
+        }
+    }
+    output
+}
@@ -0,0 +1,96 @@
+//! This module contains a function that prunes the ccr to only keep the elements that we want to
+//! expose in the `C` api.
+use concrete_core_representation::{
+    ConcreteCore, EngineTraitImplArg, EngineTraitImplGenericArgument, EngineTraitImplReturn,
+};
+
+const WHITELISTED_BACKENDS: [&str; 2] = ["default", "fft"];
+
+// Those numeric types have no stable `C` counterpart.
+const UNAVAILABLE_NUMERICS: [&str; 2] = ["u128", "i128"];
+
+// Those parameters do not wrap a single `usize` and can not be passed as such.
+const UNAVAILABLE_PARAMETERS: [&str; 1] = ["LweCiphertextRange"];
+
+/// A function that prunes a concrete-core representation from the nodes we do not want in the `C`
+/// api
+pub fn prune(ccr: &mut ConcreteCore) {
+    prune_non_whitelisted_backends(ccr);
+    prune_unavailable_engine_impls(ccr);
+}
+
+/// Prunes the backends which are not whitelisted from the ccr
+fn prune_non_whitelisted_backends(ccr: &mut ConcreteCore) {
+    ccr.backends.retain(|backend| {
+        WHITELISTED_BACKENDS
+            .iter()
+            .any(|whitelisted| backend.ident == *whitelisted)
+    });
+}
+
+/// Prunes the engine trait impls that take unavailable arguments, or return unavailable values.
+fn prune_unavailable_engine_impls(ccr: &mut ConcreteCore) {
+    for engine in ccr
+        .backends
+        .iter_mut()
+        .flat_map(|backend| backend.engines.iter_mut())
+    {
+        engine.engine_impls.retain(|impl_| {
+            let args_available = impl_.checked_method.args().iter().all(|arg| match arg {
+                EngineTraitImplArg::OwnedEntity(_, _) => true,
+                EngineTraitImplArg::OwnedEntityRef(_, _) => true,
+                EngineTraitImplArg::OwnedEntityRefMut(_, _) => true,
+                EngineTraitImplArg::ViewEntity(_, _) => true,
+                EngineTraitImplArg::ViewEntityRef(_, _) => true,
+                EngineTraitImplArg::MutViewEntity(_, _) => true,
+                EngineTraitImplArg::MutViewEntityRefMut(_, _) => true,
+                EngineTraitImplArg::Config(_, _) => false,
+                EngineTraitImplArg::ConfigRef(_, _, _) => false,
+                EngineTraitImplArg::ConfigSlice(_, _, _) => false,
+                EngineTraitImplArg::Parameter(_, type_) => {
+                    !is_one_of(type_, &UNAVAILABLE_PARAMETERS)
+                }
+                EngineTraitImplArg::Dispersion(_, _) => true,
+                EngineTraitImplArg::Numeric(_, type_) => !is_one_of(type_, &UNAVAILABLE_NUMERICS),
+                EngineTraitImplArg::NumericRef(_, _, num)
+                | EngineTraitImplArg::NumericRefMut(_, _, num)
+                | EngineTraitImplArg::NumericSlice(_, _, num)
+                | EngineTraitImplArg::NumericSliceMut(_, _, num)
+                | EngineTraitImplArg::NumericVec(_, _, num) => !UNAVAILABLE_NUMERICS
+                    .iter()
+                    .any(|unavailable| num == unavailable),
+                EngineTraitImplArg::Unknown(_, _) => false,
+            });
+            let return_available = match impl_.checked_method.return_() {
+                EngineTraitImplReturn::OwnedEntity(_) => true,
+                EngineTraitImplReturn::ViewEntity(_) => true,
+                EngineTraitImplReturn::MutViewEntity(_) => true,
+                EngineTraitImplReturn::Config(_) => false,
+                EngineTraitImplReturn::Numeric(type_) => !is_one_of(type_, &UNAVAILABLE_NUMERICS),
+                EngineTraitImplReturn::NumericSlice(_) => false,
+                EngineTraitImplReturn::NumericSliceMut(_) => false,
+                EngineTraitImplReturn::NumericVec(_) => false,
+                EngineTraitImplReturn::Unit(_) => true,
+                EngineTraitImplReturn::Unknown(_) => false,
+            };
+            let parameters_available = impl_
+                .engine_trait_parameters()
+                .iter()
+                .all(|param| !matches!(param, EngineTraitImplGenericArgument::Unknown(_)));
+            args_available && return_available && parameters_available
+        });
+    }
+}
+
+/// Returns whether the type is a path whose last segment is one of the `idents`.
+fn is_one_of(type_: &syn::Type, idents: &[&str]) -> bool {
+    match type_ {
+        syn::Type::Path(path) => path
+            .path
+            .segments
+            .last()
+            .map(|segment| idents.iter().any(|ident| segment.ident == ident))
+            .unwrap_or(false),
+        _ => false,
+    }
+}
@@ -0,0 +1,148 @@
+//! Module providing entry points to the `DefaultEngine` implementations of various
+//! `GlweCiphertextDiscardingDecryptionEngine` traits.
+
+use crate::utils::*;
+use concrete_core::prelude::*;
+use std::os::raw::c_int;
+
+/// Decrypt a `GlweCiphertextView64`. The plaintexts are retrieved in a `u64` array of the size of
+/// the polynomial size of the secret key. View buffer variant.
+///
+/// Refer to `concrete-core` implementation for detailed documentation.
+///
+/// This function is [checked](crate#safety-checked-and-unchecked-functions).
+#[no_mangle]
+pub unsafe extern "C" fn default_engine_discard_decrypt_glwe_ciphertext_u64_view_buffers(
+    engine: *mut DefaultEngine,
+    secret_key: *const GlweSecretKey64,
+    result: *mut u64,
+    input: *const GlweCiphertextView64,
+) -> c_int {
+    catch_panic(|| {
+        check_ptr_is_non_null_and_aligned(result).unwrap();
+
+        let engine = get_mut_checked(engine).unwrap();
+
+        let secret_key = get_ref_checked(secret_key).unwrap();
+
+        let input = get_ref_checked(input).unwrap();
+
+        let result_as_mut_slice =
+            std::slice::from_raw_parts_mut(result, secret_key.polynomial_size().0);
+        let mut plaintext_vector = engine
+            .create_plaintext_vector_from(result_as_mut_slice)
+            .or_else(engine_error_as_readable_string)
+            .unwrap();
+
+        engine
+            .discard_decrypt_glwe_ciphertext(secret_key, &mut plaintext_vector, input)
+            .or_else(engine_error_as_readable_string)
+            .unwrap();
+
+        engine
+            .discard_retrieve_plaintext_vector(result_as_mut_slice, &plaintext_vector)
+            .or_else(engine_error_as_readable_string)
+            .unwrap();
+    })
+}
+
+/// [Unchecked](crate#safety-checked-and-unchecked-functions) version of
+/// [`default_engine_discard_decrypt_glwe_ciphertext_u64_view_buffers`]
+#[no_mangle]
+pub unsafe extern "C" fn default_engine_discard_decrypt_glwe_ciphertext_unchecked_u64_view_buffers(
+    engine: *mut DefaultEngine,
+    secret_key: *const GlweSecretKey64,
+    result: *mut u64,
+    input: *const GlweCiphertextView64,
+) -> c_int {
+    catch_panic(|| {
+        let engine = &mut (*engine);
+
+        let secret_key = &(*secret_key);
+
+        let input = &(*input);
+
+        let result_as_mut_slice =
+            std::slice::from_raw_parts_mut(result, secret_key.polynomial_size().0);
+        let mut plaintext_vector =
+            engine.create_plaintext_vector_from_unchecked(result_as_mut_slice);
+
+        engine.discard_decrypt_glwe_ciphertext_unchecked(secret_key, &mut plaintext_vector, input);
+
+        engine.discard_retrieve_plaintext_vector_unchecked(result_as_mut_slice, &plaintext_vector);
+    })
+}
+
+/// Raw pointer buffer variant of
+/// [`default_engine_discard_decrypt_glwe_ciphertext_u64_view_buffers`]
+#[no_mangle]
+pub unsafe extern "C" fn default_engine_discard_decrypt_glwe_ciphertext_u64_raw_ptr_buffers(
+    engine: *mut DefaultEngine,
+    secret_key: *const GlweSecretKey64,
+    result: *mut u64,
+    input: *const u64,
+) -> c_int {
+    catch_panic(|| {
+        check_ptr_is_non_null_and_aligned(result).unwrap();
+
+        let engine = get_mut_checked(engine).unwrap();
+
+        let secret_key = get_ref_checked(secret_key).unwrap();
+
+        let polynomial_size = secret_key.polynomial_size();
+        let glwe_size = secret_key.glwe_dimension().to_glwe_size();
+
+        let input = get_ref_checked(input).unwrap();
+        let input_as_slice = std::slice::from_raw_parts(input, glwe_size.0 * polynomial_size.0);
+        let input = engine
+            .create_glwe_ciphertext_from(input_as_slice, polynomial_size)
+            .or_else(engine_error_as_readable_string)
+            .unwrap();
+
+        let result_as_mut_slice = std::slice::from_raw_parts_mut(result, polynomial_size.0);
+        let mut plaintext_vector = engine
+            .create_plaintext_vector_from(result_as_mut_slice)
+            .or_else(engine_error_as_readable_string)
+            .unwrap();
+
+        engine
+            .discard_decrypt_glwe_ciphertext(secret_key, &mut plaintext_vector, &input)
+            .or_else(engine_error_as_readable_string)
+            .unwrap();
+
+        engine
+            .discard_retrieve_plaintext_vector(result_as_mut_slice, &plaintext_vector)
+            .or_else(engine_error_as_readable_string)
+            .unwrap();
+    })
+}
+
+/// [Unchecked](crate#safety-checked-and-unchecked-functions) version of
+/// [`default_engine_discard_decrypt_glwe_ciphertext_u64_raw_ptr_buffers`]
+#[no_mangle]
+pub unsafe extern "C" fn default_engine_discard_decrypt_glwe_ciphertext_unchecked_u64_raw_ptr_buffers(
+    engine: *mut DefaultEngine,
+    secret_key: *const GlweSecretKey64,
+    result: *mut u64,
+    input: *const u64,
+) -> c_int {
+    catch_panic(|| {
+        let engine = &mut (*engine);
+
+        let secret_key = &(*secret_key);
+
+        let polynomial_size = secret_key.polynomial_size();
+        let glwe_size = secret_key.glwe_dimension().to_glwe_size();
+
+        let input_as_slice = std::slice::from_raw_parts(input, glwe_size.0 * polynomial_size.0);
+        let input = engine.create_glwe_ciphertext_from_unchecked(input_as_slice, polynomial_size);
+
+        let result_as_mut_slice = std::slice::from_raw_parts_mut(result, polynomial_size.0);
+        let mut plaintext_vector =
+            engine.create_plaintext_vector_from_unchecked(result_as_mut_slice);
+
+        engine.discard_decrypt_glwe_ciphertext_unchecked(secret_key, &mut plaintext_vector, &input);
+
+        engine.discard_retrieve_plaintext_vector_unchecked(result_as_mut_slice, &plaintext_vector);
+    })
+}
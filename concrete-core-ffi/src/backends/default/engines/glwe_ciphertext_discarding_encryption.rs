@@ -0,0 +1,145 @@
+//! Module providing entry points to the `DefaultEngine` implementations of various
+//! `GlweCiphertextDiscardingEncryptionEngine` traits.
+
+use crate::utils::*;
+use concrete_core::prelude::*;
+use std::os::raw::c_int;
+
+/// Encrypt an input `u64` plaintext array into a `GlweCiphertextMutView64`. The array must
+/// contain as many plaintexts as the polynomial size of the secret key. View buffer variant.
+///
+/// Refer to `concrete-core` implementation for detailed documentation.
+///
+/// This function is [checked](crate#safety-checked-and-unchecked-functions).
+#[no_mangle]
+pub unsafe extern "C" fn default_engine_discard_encrypt_glwe_ciphertext_u64_view_buffers(
+    engine: *mut DefaultEngine,
+    secret_key: *const GlweSecretKey64,
+    output: *mut GlweCiphertextMutView64,
+    input: *const u64,
+    noise: f64,
+) -> c_int {
+    catch_panic(|| {
+        let engine = get_mut_checked(engine).unwrap();
+
+        let secret_key = get_ref_checked(secret_key).unwrap();
+
+        let output = get_mut_checked(output).unwrap();
+
+        let input = get_ref_checked(input).unwrap();
+        let input_as_slice = std::slice::from_raw_parts(input, secret_key.polynomial_size().0);
+        let input = engine
+            .create_plaintext_vector_from(input_as_slice)
+            .or_else(engine_error_as_readable_string)
+            .unwrap();
+
+        engine
+            .discard_encrypt_glwe_ciphertext(secret_key, output, &input, Variance(noise))
+            .or_else(engine_error_as_readable_string)
+            .unwrap();
+    })
+}
+
+/// [Unchecked](crate#safety-checked-and-unchecked-functions) version of
+/// [`default_engine_discard_encrypt_glwe_ciphertext_u64_view_buffers`]
+#[no_mangle]
+pub unsafe extern "C" fn default_engine_discard_encrypt_glwe_ciphertext_unchecked_u64_view_buffers(
+    engine: *mut DefaultEngine,
+    secret_key: *const GlweSecretKey64,
+    output: *mut GlweCiphertextMutView64,
+    input: *const u64,
+    noise: f64,
+) -> c_int {
+    catch_panic(|| {
+        let engine = &mut (*engine);
+
+        let secret_key = &(*secret_key);
+
+        let output = &mut (*output);
+
+        let input_as_slice = std::slice::from_raw_parts(input, secret_key.polynomial_size().0);
+        let input = engine.create_plaintext_vector_from_unchecked(input_as_slice);
+
+        engine.discard_encrypt_glwe_ciphertext_unchecked(
+            secret_key,
+            output,
+            &input,
+            Variance(noise),
+        );
+    })
+}
+
+/// Raw pointer buffer variant of
+/// [`default_engine_discard_encrypt_glwe_ciphertext_u64_view_buffers`]
+#[no_mangle]
+pub unsafe extern "C" fn default_engine_discard_encrypt_glwe_ciphertext_u64_raw_ptr_buffers(
+    engine: *mut DefaultEngine,
+    secret_key: *const GlweSecretKey64,
+    output: *mut u64,
+    input: *const u64,
+    noise: f64,
+) -> c_int {
+    catch_panic(|| {
+        let engine = get_mut_checked(engine).unwrap();
+
+        let secret_key = get_ref_checked(secret_key).unwrap();
+
+        let polynomial_size = secret_key.polynomial_size();
+        let glwe_size = secret_key.glwe_dimension().to_glwe_size();
+
+        let output = get_mut_checked(output).unwrap();
+        let output_as_slice =
+            std::slice::from_raw_parts_mut(output, glwe_size.0 * polynomial_size.0);
+        let mut output = engine
+            .create_glwe_ciphertext_from(output_as_slice, polynomial_size)
+            .or_else(engine_error_as_readable_string)
+            .unwrap();
+
+        let input = get_ref_checked(input).unwrap();
+        let input_as_slice = std::slice::from_raw_parts(input, polynomial_size.0);
+        let input = engine
+            .create_plaintext_vector_from(input_as_slice)
+            .or_else(engine_error_as_readable_string)
+            .unwrap();
+
+        engine
+            .discard_encrypt_glwe_ciphertext(secret_key, &mut output, &input, Variance(noise))
+            .or_else(engine_error_as_readable_string)
+            .unwrap();
+    })
+}
+
+/// [Unchecked](crate#safety-checked-and-unchecked-functions) version of
+/// [`default_engine_discard_encrypt_glwe_ciphertext_u64_raw_ptr_buffers`]
+#[no_mangle]
+pub unsafe extern "C" fn default_engine_discard_encrypt_glwe_ciphertext_unchecked_u64_raw_ptr_buffers(
+    engine: *mut DefaultEngine,
+    secret_key: *const GlweSecretKey64,
+    output: *mut u64,
+    input: *const u64,
+    noise: f64,
+) -> c_int {
+    catch_panic(|| {
+        let engine = &mut (*engine);
+
+        let secret_key = &(*secret_key);
+
+        let polynomial_size = secret_key.polynomial_size();
+        let glwe_size = secret_key.glwe_dimension().to_glwe_size();
+
+        let output_as_slice =
+            std::slice::from_raw_parts_mut(output, glwe_size.0 * polynomial_size.0);
+        let mut output =
+            engine.create_glwe_ciphertext_from_unchecked(output_as_slice, polynomial_size);
+
+        let input_as_slice = std::slice::from_raw_parts(input, polynomial_size.0);
+        let input = engine.create_plaintext_vector_from_unchecked(input_as_slice);
+
+        engine.discard_encrypt_glwe_ciphertext_unchecked(
+            secret_key,
+            &mut output,
+            &input,
+            Variance(noise),
+        );
+    })
+}
@@ -0,0 +1,128 @@
+//! Module providing entry points to the `DefaultEngine` implementations of various
+//! `LweCiphertextVectorDiscardingKeyswitchEngine` traits.
+
+use crate::utils::*;
+use concrete_core::prelude::*;
+use std::os::raw::c_int;
+
+/// Keyswitch an `LweCiphertextVectorView64` into an `LweCiphertextVectorMutView64`. View buffer
+/// variant.
+///
+/// Refer to `concrete-core` implementation for detailed documentation.
+///
+/// This function is [checked](crate#safety-checked-and-unchecked-functions).
+#[no_mangle]
+pub unsafe extern "C" fn default_engine_discard_keyswitch_lwe_ciphertext_vector_u64_view_buffers(
+    engine: *mut DefaultEngine,
+    keyswitch_key: *const LweKeyswitchKey64,
+    output: *mut LweCiphertextVectorMutView64,
+    input: *const LweCiphertextVectorView64,
+) -> c_int {
+    catch_panic(|| {
+        let engine = get_mut_checked(engine).unwrap();
+
+        let keyswitch_key = get_ref_checked(keyswitch_key).unwrap();
+
+        let output = get_mut_checked(output).unwrap();
+        let input = get_ref_checked(input).unwrap();
+
+        engine
+            .discard_keyswitch_lwe_ciphertext_vector(output, input, keyswitch_key)
+            .or_else(engine_error_as_readable_string)
+            .unwrap();
+    })
+}
+
+/// [Unchecked](crate#safety-checked-and-unchecked-functions) version of
+/// [`default_engine_discard_keyswitch_lwe_ciphertext_vector_u64_view_buffers`]
+#[no_mangle]
+pub unsafe extern "C" fn default_engine_discard_keyswitch_lwe_ciphertext_vector_unchecked_u64_view_buffers(
+    engine: *mut DefaultEngine,
+    keyswitch_key: *const LweKeyswitchKey64,
+    output: *mut LweCiphertextVectorMutView64,
+    input: *const LweCiphertextVectorView64,
+) -> c_int {
+    catch_panic(|| {
+        let engine = &mut (*engine);
+
+        let keyswitch_key = &(*keyswitch_key);
+
+        let output = &mut (*output);
+        let input = &(*input);
+
+        engine.discard_keyswitch_lwe_ciphertext_vector_unchecked(output, input, keyswitch_key);
+    })
+}
+
+/// Raw pointer variant of
+/// [`default_engine_discard_keyswitch_lwe_ciphertext_vector_u64_view_buffers`]
+#[no_mangle]
+pub unsafe extern "C" fn default_engine_discard_keyswitch_lwe_ciphertext_vector_u64_raw_ptr_buffers(
+    engine: *mut DefaultEngine,
+    keyswitch_key: *const LweKeyswitchKey64,
+    output: *mut u64,
+    input: *const u64,
+    lwe_count: usize,
+) -> c_int {
+    catch_panic(|| {
+        let engine = get_mut_checked(engine).unwrap();
+
+        let keyswitch_key = get_ref_checked(keyswitch_key).unwrap();
+
+        let lwe_input_size = keyswitch_key.input_lwe_dimension().to_lwe_size();
+        let lwe_output_size = keyswitch_key.output_lwe_dimension().to_lwe_size();
+
+        let output = get_mut_checked(output).unwrap();
+        let output_as_slice = std::slice::from_raw_parts_mut(output, lwe_output_size.0 * lwe_count);
+        let mut output = engine
+            .create_lwe_ciphertext_vector_from(output_as_slice, lwe_output_size)
+            .or_else(engine_error_as_readable_string)
+            .unwrap();
+
+        let input = get_ref_checked(input).unwrap();
+        let input_as_slice = std::slice::from_raw_parts(input, lwe_input_size.0 * lwe_count);
+        let input = engine
+            .create_lwe_ciphertext_vector_from(input_as_slice, lwe_input_size)
+            .or_else(engine_error_as_readable_string)
+            .unwrap();
+
+        engine
+            .discard_keyswitch_lwe_ciphertext_vector(&mut output, &input, keyswitch_key)
+            .or_else(engine_error_as_readable_string)
+            .unwrap();
+    })
+}
+
+/// [Unchecked](crate#safety-checked-and-unchecked-functions) version of
+/// [`default_engine_discard_keyswitch_lwe_ciphertext_vector_u64_raw_ptr_buffers`]
+#[no_mangle]
+pub unsafe extern "C" fn default_engine_discard_keyswitch_lwe_ciphertext_vector_unchecked_u64_raw_ptr_buffers(
+    engine: *mut DefaultEngine,
+    keyswitch_key: *const LweKeyswitchKey64,
+    output: *mut u64,
+    input: *const u64,
+    lwe_count: usize,
+) -> c_int {
+    catch_panic(|| {
+        let engine = &mut (*engine);
+
+        let keyswitch_key = &(*keyswitch_key);
+
+        let lwe_input_size = keyswitch_key.input_lwe_dimension().to_lwe_size();
+        let lwe_output_size = keyswitch_key.output_lwe_dimension().to_lwe_size();
+
+        let output_as_slice = std::slice::from_raw_parts_mut(output, lwe_output_size.0 * lwe_count);
+        let mut output =
+            engine.create_lwe_ciphertext_vector_from_unchecked(output_as_slice, lwe_output_size);
+
+        let input_as_slice = std::slice::from_raw_parts(input, lwe_input_size.0 * lwe_count);
+        let input =
+            engine.create_lwe_ciphertext_vector_from_unchecked(input_as_slice, lwe_input_size);
+
+        engine.discard_keyswitch_lwe_ciphertext_vector_unchecked(
+            &mut output,
+            &input,
+            keyswitch_key,
+        );
+    })
+}
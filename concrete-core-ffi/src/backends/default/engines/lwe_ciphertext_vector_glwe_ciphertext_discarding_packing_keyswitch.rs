@@ -0,0 +1,140 @@
+//! Module providing entry points to the `DefaultEngine` implementations of various
+//! `LweCiphertextVectorGlweCiphertextDiscardingPackingKeyswitchEngine` traits.
+
+use crate::utils::*;
+use concrete_core::prelude::*;
+use std::os::raw::c_int;
+
+/// Pack an `LweCiphertextVectorView64` into a `GlweCiphertextMutView64` using an
+/// `LwePackingKeyswitchKey64`. View buffer variant.
+///
+/// Refer to `concrete-core` implementation for detailed documentation.
+///
+/// This function is [checked](crate#safety-checked-and-unchecked-functions).
+#[no_mangle]
+pub unsafe extern "C" fn default_engine_discard_packing_keyswitch_lwe_ciphertext_vector_u64_view_buffers(
+    engine: *mut DefaultEngine,
+    packing_keyswitch_key: *const LwePackingKeyswitchKey64,
+    output: *mut GlweCiphertextMutView64,
+    input: *const LweCiphertextVectorView64,
+) -> c_int {
+    catch_panic(|| {
+        let engine = get_mut_checked(engine).unwrap();
+
+        let packing_keyswitch_key = get_ref_checked(packing_keyswitch_key).unwrap();
+
+        let output = get_mut_checked(output).unwrap();
+        let input = get_ref_checked(input).unwrap();
+
+        engine
+            .discard_packing_keyswitch_lwe_ciphertext_vector(output, input, packing_keyswitch_key)
+            .or_else(engine_error_as_readable_string)
+            .unwrap();
+    })
+}
+
+/// [Unchecked](crate#safety-checked-and-unchecked-functions) version of
+/// [`default_engine_discard_packing_keyswitch_lwe_ciphertext_vector_u64_view_buffers`]
+#[no_mangle]
+pub unsafe extern "C" fn default_engine_discard_packing_keyswitch_lwe_ciphertext_vector_unchecked_u64_view_buffers(
+    engine: *mut DefaultEngine,
+    packing_keyswitch_key: *const LwePackingKeyswitchKey64,
+    output: *mut GlweCiphertextMutView64,
+    input: *const LweCiphertextVectorView64,
+) -> c_int {
+    catch_panic(|| {
+        let engine = &mut (*engine);
+
+        let packing_keyswitch_key = &(*packing_keyswitch_key);
+
+        let output = &mut (*output);
+        let input = &(*input);
+
+        engine.discard_packing_keyswitch_lwe_ciphertext_vector_unchecked(
+            output,
+            input,
+            packing_keyswitch_key,
+        );
+    })
+}
+
+/// Raw pointer variant of
+/// [`default_engine_discard_packing_keyswitch_lwe_ciphertext_vector_u64_view_buffers`]
+#[no_mangle]
+pub unsafe extern "C" fn default_engine_discard_packing_keyswitch_lwe_ciphertext_vector_u64_raw_ptr_buffers(
+    engine: *mut DefaultEngine,
+    packing_keyswitch_key: *const LwePackingKeyswitchKey64,
+    output: *mut u64,
+    input: *const u64,
+    lwe_count: usize,
+) -> c_int {
+    catch_panic(|| {
+        let engine = get_mut_checked(engine).unwrap();
+
+        let packing_keyswitch_key = get_ref_checked(packing_keyswitch_key).unwrap();
+
+        let lwe_input_size = packing_keyswitch_key.input_lwe_dimension().to_lwe_size();
+        let glwe_output_size = packing_keyswitch_key.output_glwe_dimension().to_glwe_size();
+        let polynomial_size = packing_keyswitch_key.output_polynomial_size();
+
+        let output = get_mut_checked(output).unwrap();
+        let output_as_slice =
+            std::slice::from_raw_parts_mut(output, glwe_output_size.0 * polynomial_size.0);
+        let mut output = engine
+            .create_glwe_ciphertext_from(output_as_slice, polynomial_size)
+            .or_else(engine_error_as_readable_string)
+            .unwrap();
+
+        let input = get_ref_checked(input).unwrap();
+        let input_as_slice = std::slice::from_raw_parts(input, lwe_input_size.0 * lwe_count);
+        let input = engine
+            .create_lwe_ciphertext_vector_from(input_as_slice, lwe_input_size)
+            .or_else(engine_error_as_readable_string)
+            .unwrap();
+
+        engine
+            .discard_packing_keyswitch_lwe_ciphertext_vector(
+                &mut output,
+                &input,
+                packing_keyswitch_key,
+            )
+            .or_else(engine_error_as_readable_string)
+            .unwrap();
+    })
+}
+
+/// [Unchecked](crate#safety-checked-and-unchecked-functions) version of
+/// [`default_engine_discard_packing_keyswitch_lwe_ciphertext_vector_u64_raw_ptr_buffers`]
+#[no_mangle]
+pub unsafe extern "C" fn default_engine_discard_packing_keyswitch_lwe_ciphertext_vector_unchecked_u64_raw_ptr_buffers(
+    engine: *mut DefaultEngine,
+    packing_keyswitch_key: *const LwePackingKeyswitchKey64,
+    output: *mut u64,
+    input: *const u64,
+    lwe_count: usize,
+) -> c_int {
+    catch_panic(|| {
+        let engine = &mut (*engine);
+
+        let packing_keyswitch_key = &(*packing_keyswitch_key);
+
+        let lwe_input_size = packing_keyswitch_key.input_lwe_dimension().to_lwe_size();
+        let glwe_output_size = packing_keyswitch_key.output_glwe_dimension().to_glwe_size();
+        let polynomial_size = packing_keyswitch_key.output_polynomial_size();
+
+        let output_as_slice =
+            std::slice::from_raw_parts_mut(output, glwe_output_size.0 * polynomial_size.0);
+        let mut output =
+            engine.create_glwe_ciphertext_from_unchecked(output_as_slice, polynomial_size);
+
+        let input_as_slice = std::slice::from_raw_parts(input, lwe_input_size.0 * lwe_count);
+        let input =
+            engine.create_lwe_ciphertext_vector_from_unchecked(input_as_slice, lwe_input_size);
+
+        engine.discard_packing_keyswitch_lwe_ciphertext_vector_unchecked(
+            &mut output,
+            &input,
+            packing_keyswitch_key,
+        );
+    })
+}
@@ -90,6 +90,7 @@ pub unsafe extern "C" fn default_engine_generate_new_lwe_circuit_bootstrap_priva
 ///
 /// This function is [checked](crate#safety-checked-and-unchecked-functions).
 #[no_mangle]
+#[cfg(feature = "backend_default_parallel")]
 pub unsafe extern "C" fn default_parallel_engine_generate_new_lwe_circuit_bootstrap_private_functional_packing_keyswitch_keys_u64(
     engine: *mut DefaultParallelEngine,
     input_lwe_key: *const LweSecretKey64,
@@ -130,6 +131,7 @@ pub unsafe extern "C" fn default_parallel_engine_generate_new_lwe_circuit_bootst
 /// [Unchecked](crate#safety-checked-and-unchecked-functions) version of
 /// [`default_parallel_engine_generate_new_lwe_circuit_bootstrap_private_functional_packing_keyswitch_keys_u64`]
 #[no_mangle]
+#[cfg(feature = "backend_default_parallel")]
 pub unsafe extern "C" fn default_parallel_engine_generate_new_lwe_circuit_bootstrap_private_functional_packing_keyswitch_keys_unchecked_u64(
     engine: *mut DefaultParallelEngine,
     input_lwe_key: *const LweSecretKey64,
@@ -0,0 +1,84 @@
+//! Module providing entry points to the `DefaultEngine` implementations of various
+//! `LweSeededCiphertextVectorEncryptionEngine` traits.
+
+use crate::utils::*;
+use concrete_core::prelude::*;
+use std::os::raw::c_int;
+
+/// Encrypt an input `u64` plaintext array into a new `LweSeededCiphertextVector64`. Raw pointer
+/// buffer variant, the input array must contain `lwe_count` plaintexts.
+///
+/// Refer to `concrete-core` implementation for detailed documentation.
+///
+/// This function is [checked](crate#safety-checked-and-unchecked-functions).
+#[no_mangle]
+pub unsafe extern "C" fn default_engine_encrypt_lwe_seeded_ciphertext_vector_u64_raw_ptr_buffers(
+    engine: *mut DefaultEngine,
+    secret_key: *const LweSecretKey64,
+    input: *const u64,
+    noise: f64,
+    lwe_count: usize,
+    result: *mut *mut LweSeededCiphertextVector64,
+) -> c_int {
+    catch_panic(|| {
+        check_ptr_is_non_null_and_aligned(result).unwrap();
+
+        // First fill the result with a null ptr so that if we fail and the return code is not
+        // checked, then any access to the result pointer will segfault (mimics malloc on failure)
+        *result = std::ptr::null_mut();
+
+        let engine = get_mut_checked(engine).unwrap();
+
+        let secret_key = get_ref_checked(secret_key).unwrap();
+
+        let input = get_ref_checked(input).unwrap();
+        let input_as_slice = std::slice::from_raw_parts(input, lwe_count);
+        let input = engine
+            .create_plaintext_vector_from(input_as_slice)
+            .or_else(engine_error_as_readable_string)
+            .unwrap();
+
+        let heap_allocated_ciphertext_vector = Box::new(
+            engine
+                .encrypt_lwe_seeded_ciphertext_vector(secret_key, &input, Variance(noise))
+                .or_else(engine_error_as_readable_string)
+                .unwrap(),
+        );
+
+        *result = Box::into_raw(heap_allocated_ciphertext_vector);
+    })
+}
+
+/// [Unchecked](crate#safety-checked-and-unchecked-functions) version of
+/// [`default_engine_encrypt_lwe_seeded_ciphertext_vector_u64_raw_ptr_buffers`]
+#[no_mangle]
+pub unsafe extern "C" fn default_engine_encrypt_lwe_seeded_ciphertext_vector_unchecked_u64_raw_ptr_buffers(
+    engine: *mut DefaultEngine,
+    secret_key: *const LweSecretKey64,
+    input: *const u64,
+    noise: f64,
+    lwe_count: usize,
+    result: *mut *mut LweSeededCiphertextVector64,
+) -> c_int {
+    catch_panic(|| {
+        // First fill the result with a null ptr so that if we fail and the return code is not
+        // checked, then any access to the result pointer will segfault (mimics malloc on failure)
+        *result = std::ptr::null_mut();
+
+        let engine = &mut (*engine);
+
+        let secret_key = &(*secret_key);
+
+        let input_as_slice = std::slice::from_raw_parts(input, lwe_count);
+        let input = engine.create_plaintext_vector_from_unchecked(input_as_slice);
+
+        let heap_allocated_ciphertext_vector =
+            Box::new(engine.encrypt_lwe_seeded_ciphertext_vector_unchecked(
+                secret_key,
+                &input,
+                Variance(noise),
+            ));
+
+        *result = Box::into_raw(heap_allocated_ciphertext_vector);
+    })
+}
@@ -0,0 +1,73 @@
+//! Module providing entry points to the `DefaultEngine` implementations of various
+//! `LweSeededCiphertextVectorToLweCiphertextVectorTransformationEngine` traits.
+
+use crate::utils::*;
+use concrete_core::prelude::*;
+use std::os::raw::c_int;
+
+/// Expand an `LweSeededCiphertextVector64` into a caller-provided buffer, which must be large
+/// enough to contain `lwe_ciphertext_count` ciphertexts of size `lwe_dimension + 1`. The seeded
+/// ciphertext vector is left untouched. Raw pointer buffer variant.
+///
+/// Refer to `concrete-core` implementation for detailed documentation.
+///
+/// This function is [checked](crate#safety-checked-and-unchecked-functions).
+#[no_mangle]
+pub unsafe extern "C" fn default_engine_transform_lwe_seeded_ciphertext_vector_to_lwe_ciphertext_vector_u64_raw_ptr_buffers(
+    engine: *mut DefaultEngine,
+    input: *const LweSeededCiphertextVector64,
+    output: *mut u64,
+) -> c_int {
+    catch_panic(|| {
+        let engine = get_mut_checked(engine).unwrap();
+
+        let input = get_ref_checked(input).unwrap();
+
+        let output = get_mut_checked(output).unwrap();
+        let output_as_slice = std::slice::from_raw_parts_mut(
+            output,
+            input.lwe_dimension().to_lwe_size().0 * input.lwe_ciphertext_count().0,
+        );
+
+        let expanded = engine
+            .transform_lwe_seeded_ciphertext_vector_to_lwe_ciphertext_vector(input.clone())
+            .or_else(engine_error_as_readable_string)
+            .unwrap();
+
+        let expanded_as_vec = engine
+            .consume_retrieve_lwe_ciphertext_vector(expanded)
+            .or_else(engine_error_as_readable_string)
+            .unwrap();
+
+        output_as_slice.copy_from_slice(&expanded_as_vec);
+    })
+}
+
+/// [Unchecked](crate#safety-checked-and-unchecked-functions) version of
+/// [`default_engine_transform_lwe_seeded_ciphertext_vector_to_lwe_ciphertext_vector_u64_raw_ptr_buffers`]
+#[no_mangle]
+pub unsafe extern "C" fn default_engine_transform_lwe_seeded_ciphertext_vector_to_lwe_ciphertext_vector_unchecked_u64_raw_ptr_buffers(
+    engine: *mut DefaultEngine,
+    input: *const LweSeededCiphertextVector64,
+    output: *mut u64,
+) -> c_int {
+    catch_panic(|| {
+        let engine = &mut (*engine);
+
+        let input = &(*input);
+
+        let output_as_slice = std::slice::from_raw_parts_mut(
+            output,
+            input.lwe_dimension().to_lwe_size().0 * input.lwe_ciphertext_count().0,
+        );
+
+        let expanded = engine
+            .transform_lwe_seeded_ciphertext_vector_to_lwe_ciphertext_vector_unchecked(
+                input.clone(),
+            );
+
+        let expanded_as_vec = engine.consume_retrieve_lwe_ciphertext_vector_unchecked(expanded);
+
+        output_as_slice.copy_from_slice(&expanded_as_vec);
+    })
+}
@@ -0,0 +1,360 @@
+//! Module providing entry points to the `FftEngine` implementations of various
+//! `LweCiphertextVectorDiscardingBootstrapEngine` traits.
+
+use crate::utils::*;
+use concrete_core::prelude::*;
+use std::os::raw::c_int;
+
+/// Bootstrap an LWE ciphertext vector using FFT, each ciphertext being bootstrapped with its own
+/// accumulator. View buffer variant.
+///
+/// Refer to `concrete-core` implementation for detailed documentation.
+///
+/// This function is [checked](crate#safety-checked-and-unchecked-functions).
+#[no_mangle]
+pub unsafe extern "C" fn fft_engine_lwe_ciphertext_vector_discarding_bootstrap_u64_view_buffers(
+    engine: *mut FftEngine,
+    bootstrap_key: *const FftFourierLweBootstrapKey64,
+    output: *mut LweCiphertextVectorMutView64,
+    input: *const LweCiphertextVectorView64,
+    accumulators: *const GlweCiphertextVectorView64,
+) -> c_int {
+    catch_panic(|| {
+        let engine = get_mut_checked(engine).unwrap();
+
+        let bootstrap_key = get_ref_checked(bootstrap_key).unwrap();
+
+        let output = get_mut_checked(output).unwrap();
+        let input = get_ref_checked(input).unwrap();
+
+        let accumulators = get_ref_checked(accumulators).unwrap();
+
+        engine
+            .discard_bootstrap_lwe_ciphertext_vector(output, input, accumulators, bootstrap_key)
+            .or_else(engine_error_as_readable_string)
+            .unwrap();
+    })
+}
+
+/// [Unchecked](crate#safety-checked-and-unchecked-functions) version of
+/// [`fft_engine_lwe_ciphertext_vector_discarding_bootstrap_u64_view_buffers`]
+#[no_mangle]
+pub unsafe extern "C" fn fft_engine_lwe_ciphertext_vector_discarding_bootstrap_unchecked_u64_view_buffers(
+    engine: *mut FftEngine,
+    bootstrap_key: *const FftFourierLweBootstrapKey64,
+    output: *mut LweCiphertextVectorMutView64,
+    input: *const LweCiphertextVectorView64,
+    accumulators: *const GlweCiphertextVectorView64,
+) -> c_int {
+    catch_panic(|| {
+        let engine = &mut (*engine);
+
+        let bootstrap_key = &(*bootstrap_key);
+
+        let output = &mut (*output);
+        let input = &(*input);
+
+        let accumulators = &(*accumulators);
+
+        engine.discard_bootstrap_lwe_ciphertext_vector_unchecked(
+            output,
+            input,
+            accumulators,
+            bootstrap_key,
+        );
+    })
+}
+
+/// Raw pointer buffer variant of
+/// [`fft_engine_lwe_ciphertext_vector_discarding_bootstrap_u64_view_buffers`]
+///
+/// The `accumulators` buffer must contain `lwe_count` GLWE ciphertexts.
+#[no_mangle]
+pub unsafe extern "C" fn fft_engine_lwe_ciphertext_vector_discarding_bootstrap_u64_raw_ptr_buffers(
+    fft_engine: *mut FftEngine,
+    default_engine: *mut DefaultEngine,
+    bootstrap_key: *const FftFourierLweBootstrapKey64,
+    output: *mut u64,
+    input: *const u64,
+    accumulators: *const u64,
+    lwe_count: usize,
+) -> c_int {
+    catch_panic(|| {
+        let fft_engine = get_mut_checked(fft_engine).unwrap();
+        let default_engine = get_mut_checked(default_engine).unwrap();
+
+        let bootstrap_key = get_ref_checked(bootstrap_key).unwrap();
+
+        let input_lwe_size = bootstrap_key.input_lwe_dimension().to_lwe_size();
+        let output_lwe_size = bootstrap_key.output_lwe_dimension().to_lwe_size();
+
+        let polynomial_size = bootstrap_key.polynomial_size();
+        let glwe_dimension = bootstrap_key.glwe_dimension();
+        let glwe_size = glwe_dimension.to_glwe_size();
+
+        let output = get_mut_checked(output).unwrap();
+        let output_as_slice = std::slice::from_raw_parts_mut(output, output_lwe_size.0 * lwe_count);
+        let mut output = default_engine
+            .create_lwe_ciphertext_vector_from(output_as_slice, output_lwe_size)
+            .or_else(engine_error_as_readable_string)
+            .unwrap();
+
+        let input = get_ref_checked(input).unwrap();
+        let input_as_slice = std::slice::from_raw_parts(input, input_lwe_size.0 * lwe_count);
+        let input = default_engine
+            .create_lwe_ciphertext_vector_from(input_as_slice, input_lwe_size)
+            .or_else(engine_error_as_readable_string)
+            .unwrap();
+
+        let accumulators = get_ref_checked(accumulators).unwrap();
+        let accumulators_as_slice =
+            std::slice::from_raw_parts(accumulators, glwe_size.0 * polynomial_size.0 * lwe_count);
+        let accumulators = default_engine
+            .create_glwe_ciphertext_vector_from(
+                accumulators_as_slice,
+                glwe_dimension,
+                polynomial_size,
+            )
+            .or_else(engine_error_as_readable_string)
+            .unwrap();
+
+        fft_engine
+            .discard_bootstrap_lwe_ciphertext_vector(
+                &mut output,
+                &input,
+                &accumulators,
+                bootstrap_key,
+            )
+            .or_else(engine_error_as_readable_string)
+            .unwrap();
+    })
+}
+
+/// [Unchecked](crate#safety-checked-and-unchecked-functions) version of
+/// [`fft_engine_lwe_ciphertext_vector_discarding_bootstrap_u64_raw_ptr_buffers`]
+#[no_mangle]
+pub unsafe extern "C" fn fft_engine_lwe_ciphertext_vector_discarding_bootstrap_unchecked_u64_raw_ptr_buffers(
+    fft_engine: *mut FftEngine,
+    default_engine: *mut DefaultEngine,
+    bootstrap_key: *const FftFourierLweBootstrapKey64,
+    output: *mut u64,
+    input: *const u64,
+    accumulators: *const u64,
+    lwe_count: usize,
+) -> c_int {
+    catch_panic(|| {
+        let fft_engine = &mut (*fft_engine);
+        let default_engine = &mut (*default_engine);
+
+        let bootstrap_key = &(*bootstrap_key);
+
+        let input_lwe_size = bootstrap_key.input_lwe_dimension().to_lwe_size();
+        let output_lwe_size = bootstrap_key.output_lwe_dimension().to_lwe_size();
+
+        let polynomial_size = bootstrap_key.polynomial_size();
+        let glwe_dimension = bootstrap_key.glwe_dimension();
+        let glwe_size = glwe_dimension.to_glwe_size();
+
+        let output_as_slice = std::slice::from_raw_parts_mut(output, output_lwe_size.0 * lwe_count);
+        let mut output = default_engine
+            .create_lwe_ciphertext_vector_from_unchecked(output_as_slice, output_lwe_size);
+
+        let input_as_slice = std::slice::from_raw_parts(input, input_lwe_size.0 * lwe_count);
+        let input = default_engine
+            .create_lwe_ciphertext_vector_from_unchecked(input_as_slice, input_lwe_size);
+
+        let accumulators_as_slice =
+            std::slice::from_raw_parts(accumulators, glwe_size.0 * polynomial_size.0 * lwe_count);
+        let accumulators = default_engine.create_glwe_ciphertext_vector_from_unchecked(
+            accumulators_as_slice,
+            glwe_dimension,
+            polynomial_size,
+        );
+
+        fft_engine.discard_bootstrap_lwe_ciphertext_vector_unchecked(
+            &mut output,
+            &input,
+            &accumulators,
+            bootstrap_key,
+        );
+    })
+}
+
+/// Bootstrap an LWE ciphertext vector using FFT, distributing the ciphertexts over the threads of
+/// an `FftParallelEngine`. View buffer variant.
+///
+/// Refer to `concrete-core` implementation for detailed documentation.
+///
+/// This function is [checked](crate#safety-checked-and-unchecked-functions).
+#[no_mangle]
+#[cfg(feature = "backend_fft_parallel")]
+pub unsafe extern "C" fn fft_parallel_engine_lwe_ciphertext_vector_discarding_bootstrap_u64_view_buffers(
+    engine: *mut FftParallelEngine,
+    bootstrap_key: *const FftFourierLweBootstrapKey64,
+    output: *mut LweCiphertextVectorMutView64,
+    input: *const LweCiphertextVectorView64,
+    accumulators: *const GlweCiphertextVectorView64,
+) -> c_int {
+    catch_panic(|| {
+        let engine = get_mut_checked(engine).unwrap();
+
+        let bootstrap_key = get_ref_checked(bootstrap_key).unwrap();
+
+        let output = get_mut_checked(output).unwrap();
+        let input = get_ref_checked(input).unwrap();
+
+        let accumulators = get_ref_checked(accumulators).unwrap();
+
+        engine
+            .discard_bootstrap_lwe_ciphertext_vector(output, input, accumulators, bootstrap_key)
+            .or_else(engine_error_as_readable_string)
+            .unwrap();
+    })
+}
+
+/// [Unchecked](crate#safety-checked-and-unchecked-functions) version of
+/// [`fft_parallel_engine_lwe_ciphertext_vector_discarding_bootstrap_u64_view_buffers`]
+#[no_mangle]
+#[cfg(feature = "backend_fft_parallel")]
+pub unsafe extern "C" fn fft_parallel_engine_lwe_ciphertext_vector_discarding_bootstrap_unchecked_u64_view_buffers(
+    engine: *mut FftParallelEngine,
+    bootstrap_key: *const FftFourierLweBootstrapKey64,
+    output: *mut LweCiphertextVectorMutView64,
+    input: *const LweCiphertextVectorView64,
+    accumulators: *const GlweCiphertextVectorView64,
+) -> c_int {
+    catch_panic(|| {
+        let engine = &mut (*engine);
+
+        let bootstrap_key = &(*bootstrap_key);
+
+        let output = &mut (*output);
+        let input = &(*input);
+
+        let accumulators = &(*accumulators);
+
+        engine.discard_bootstrap_lwe_ciphertext_vector_unchecked(
+            output,
+            input,
+            accumulators,
+            bootstrap_key,
+        );
+    })
+}
+
+/// Raw pointer buffer variant of
+/// [`fft_parallel_engine_lwe_ciphertext_vector_discarding_bootstrap_u64_view_buffers`]
+///
+/// The `accumulators` buffer must contain `lwe_count` GLWE ciphertexts.
+#[no_mangle]
+#[cfg(feature = "backend_fft_parallel")]
+pub unsafe extern "C" fn fft_parallel_engine_lwe_ciphertext_vector_discarding_bootstrap_u64_raw_ptr_buffers(
+    fft_parallel_engine: *mut FftParallelEngine,
+    default_engine: *mut DefaultEngine,
+    bootstrap_key: *const FftFourierLweBootstrapKey64,
+    output: *mut u64,
+    input: *const u64,
+    accumulators: *const u64,
+    lwe_count: usize,
+) -> c_int {
+    catch_panic(|| {
+        let fft_parallel_engine = get_mut_checked(fft_parallel_engine).unwrap();
+        let default_engine = get_mut_checked(default_engine).unwrap();
+
+        let bootstrap_key = get_ref_checked(bootstrap_key).unwrap();
+
+        let input_lwe_size = bootstrap_key.input_lwe_dimension().to_lwe_size();
+        let output_lwe_size = bootstrap_key.output_lwe_dimension().to_lwe_size();
+
+        let polynomial_size = bootstrap_key.polynomial_size();
+        let glwe_dimension = bootstrap_key.glwe_dimension();
+        let glwe_size = glwe_dimension.to_glwe_size();
+
+        let output = get_mut_checked(output).unwrap();
+        let output_as_slice = std::slice::from_raw_parts_mut(output, output_lwe_size.0 * lwe_count);
+        let mut output = default_engine
+            .create_lwe_ciphertext_vector_from(output_as_slice, output_lwe_size)
+            .or_else(engine_error_as_readable_string)
+            .unwrap();
+
+        let input = get_ref_checked(input).unwrap();
+        let input_as_slice = std::slice::from_raw_parts(input, input_lwe_size.0 * lwe_count);
+        let input = default_engine
+            .create_lwe_ciphertext_vector_from(input_as_slice, input_lwe_size)
+            .or_else(engine_error_as_readable_string)
+            .unwrap();
+
+        let accumulators = get_ref_checked(accumulators).unwrap();
+        let accumulators_as_slice =
+            std::slice::from_raw_parts(accumulators, glwe_size.0 * polynomial_size.0 * lwe_count);
+        let accumulators = default_engine
+            .create_glwe_ciphertext_vector_from(
+                accumulators_as_slice,
+                glwe_dimension,
+                polynomial_size,
+            )
+            .or_else(engine_error_as_readable_string)
+            .unwrap();
+
+        fft_parallel_engine
+            .discard_bootstrap_lwe_ciphertext_vector(
+                &mut output,
+                &input,
+                &accumulators,
+                bootstrap_key,
+            )
+            .or_else(engine_error_as_readable_string)
+            .unwrap();
+    })
+}
+
+/// [Unchecked](crate#safety-checked-and-unchecked-functions) version of
+/// [`fft_parallel_engine_lwe_ciphertext_vector_discarding_bootstrap_u64_raw_ptr_buffers`]
+#[no_mangle]
+#[cfg(feature = "backend_fft_parallel")]
+pub unsafe extern "C" fn fft_parallel_engine_lwe_ciphertext_vector_discarding_bootstrap_unchecked_u64_raw_ptr_buffers(
+    fft_parallel_engine: *mut FftParallelEngine,
+    default_engine: *mut DefaultEngine,
+    bootstrap_key: *const FftFourierLweBootstrapKey64,
+    output: *mut u64,
+    input: *const u64,
+    accumulators: *const u64,
+    lwe_count: usize,
+) -> c_int {
+    catch_panic(|| {
+        let fft_parallel_engine = &mut (*fft_parallel_engine);
+        let default_engine = &mut (*default_engine);
+
+        let bootstrap_key = &(*bootstrap_key);
+
+        let input_lwe_size = bootstrap_key.input_lwe_dimension().to_lwe_size();
+        let output_lwe_size = bootstrap_key.output_lwe_dimension().to_lwe_size();
+
+        let polynomial_size = bootstrap_key.polynomial_size();
+        let glwe_dimension = bootstrap_key.glwe_dimension();
+        let glwe_size = glwe_dimension.to_glwe_size();
+
+        let output_as_slice = std::slice::from_raw_parts_mut(output, output_lwe_size.0 * lwe_count);
+        let mut output = default_engine
+            .create_lwe_ciphertext_vector_from_unchecked(output_as_slice, output_lwe_size);
+
+        let input_as_slice = std::slice::from_raw_parts(input, input_lwe_size.0 * lwe_count);
+        let input = default_engine
+            .create_lwe_ciphertext_vector_from_unchecked(input_as_slice, input_lwe_size);
+
+        let accumulators_as_slice =
+            std::slice::from_raw_parts(accumulators, glwe_size.0 * polynomial_size.0 * lwe_count);
+        let accumulators = default_engine.create_glwe_ciphertext_vector_from_unchecked(
+            accumulators_as_slice,
+            glwe_dimension,
+            polynomial_size,
+        );
+
+        fft_parallel_engine.discard_bootstrap_lwe_ciphertext_vector_unchecked(
+            &mut output,
+            &input,
+            &accumulators,
+            bootstrap_key,
+        );
+    })
+}
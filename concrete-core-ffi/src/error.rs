@@ -0,0 +1,64 @@
+//! Module providing the error codes returned by the FFI functions, and the per-thread last error
+//! message.
+//!
+//! Every checked and unchecked function of the FFI returns a `c_int` status code. When the code is
+//! not [`CONCRETE_CORE_FFI_SUCCESS`], a readable description of the failure can be retrieved with
+//! [`concrete_core_ffi_last_error_message`], from the thread which made the failing call.
+
+use std::cell::{Cell, RefCell};
+use std::ffi::CString;
+use std::os::raw::{c_char, c_int};
+
+/// The call succeeded.
+pub const CONCRETE_CORE_FFI_SUCCESS: c_int = 0;
+/// The call panicked for a reason not covered by the other error codes.
+pub const CONCRETE_CORE_FFI_PANIC: c_int = 1;
+/// A pointer passed to the call was null.
+pub const CONCRETE_CORE_FFI_NULL_POINTER: c_int = 2;
+/// A pointer passed to the call was not aligned for the type it points to.
+pub const CONCRETE_CORE_FFI_MISALIGNED_POINTER: c_int = 3;
+/// The engine rejected the inputs of the call, e.g. because of mismatched dimensions.
+pub const CONCRETE_CORE_FFI_ENGINE_ERROR: c_int = 4;
+
+thread_local! {
+    // The code of the error recorded during the current call, if any.
+    static PENDING_ERROR_CODE: Cell<Option<c_int>> = const { Cell::new(None) };
+    // The message of the last error which occurred on this thread.
+    static LAST_ERROR_MESSAGE: RefCell<Option<CString>> = const { RefCell::new(None) };
+}
+
+/// Returns the message describing the last error which occurred on the calling thread, or a null
+/// pointer if no error occurred yet.
+///
+/// The message is kept until the next failing call on the same thread, so the returned pointer
+/// must not be used after another FFI function failed on this thread. The message is owned by the
+/// FFI and must not be freed by the caller.
+#[no_mangle]
+pub extern "C" fn concrete_core_ffi_last_error_message() -> *const c_char {
+    LAST_ERROR_MESSAGE.with(|message| {
+        message
+            .borrow()
+            .as_ref()
+            .map_or(std::ptr::null(), |message| message.as_ptr())
+    })
+}
+
+/// Records an error for the current call, to be reported by [`catch_panic`](super::utils).
+pub(crate) fn record_error(code: c_int, message: &str) {
+    PENDING_ERROR_CODE.with(|pending| pending.set(Some(code)));
+    LAST_ERROR_MESSAGE.with(|last| {
+        // Interior nul bytes would truncate the message on the C side, so they are dropped.
+        let message = CString::new(message.replace('\0', "")).unwrap_or_default();
+        *last.borrow_mut() = Some(message);
+    });
+}
+
+/// Forgets the error recorded for the previous call, if any.
+pub(crate) fn clear_pending_error() {
+    PENDING_ERROR_CODE.with(|pending| pending.set(None));
+}
+
+/// Returns the code of the error recorded for the current call, if any.
+pub(crate) fn take_pending_error() -> Option<c_int> {
+    PENDING_ERROR_CODE.with(|pending| pending.take())
+}
@@ -0,0 +1,23 @@
+//! Module containing the `C` FFI generated from the `concrete-core` sources.
+//!
+//! At build time, the `concrete-core` sources are parsed into a `concrete-core-representation`,
+//! from which the following entry points are generated for the `default` and `fft` backends:
+//!
+//! + A constructor and a destructor for every engine, e.g. `new_default_engine` and
+//!   `destroy_default_engine`.
+//! + A destructor for every entity, e.g. `destroy_lwe_ciphertext_u64`.
+//! + A function for every engine trait implementation whose arguments can be passed across the `C`
+//!   FFI boundary, e.g. `default_engine_create_plaintext_from_u64_plaintext_u64`.
+//!
+//! All of them come in [checked and unchecked](crate#safety-checked-and-unchecked-functions)
+//! versions.
+//!
+//! Entities are passed as opaque pointers, numeric slices as a pointer followed by a length
+//! argument, and values produced by the engines are written to a trailing `result` pointer.
+#[allow(unused_imports)]
+use crate::seeders::SeederBuilder;
+use crate::utils::*;
+use concrete_core::prelude::*;
+use std::os::raw::c_int;
+
+include!(concat!(env!("OUT_DIR"), "/__gen.rs"));
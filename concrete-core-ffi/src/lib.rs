@@ -30,11 +30,16 @@
 //!
 //! This crate follows the `concrete-core` project structure, the exception being there is no
 //! `implementation` or `private` modules in this crate's source tree. You can for example find the
-//! creation and destruction entry points for the [`FftEngine`](backends::fft::engines) in
+//! raw pointer entry points for the [`FftEngine`](backends::fft::engines) in
 //! `backends::fft::engines` instead of `backends::fft::implementation::engines`.
 //!
-//! The [`backends`] module maps to the `concrete-core` backend module and provides wrappers to call
-//! a selection of engines from `C`.
+//! The [`generated`] module is generated at build time from the `concrete-core` sources, using
+//! `concrete-core-representation`. It provides the engines constructors and destructors, the
+//! entities destructors, and wrappers to call every engine implementation of the `default` and
+//! `fft` backends from `C`.
+//!
+//! The [`backends`] module maps to the `concrete-core` backend module and provides hand-written
+//! wrappers to call a selection of engines from `C` with views or raw pointers as buffers.
 //!
 //! The [`seeders`] module provides utilities to provide seeders when building engines. It does not
 //! expose structures allowing to manipulate the seeders themselves but rather gives the possibility
@@ -44,6 +49,10 @@
 //! The [`buffer`] module which provides structures to help with key serialization and
 //! deserialization.
 //!
+//! The [`error`] module which provides the error codes returned by the FFI functions, and
+//! [`concrete_core_ffi_last_error_message`](error::concrete_core_ffi_last_error_message) to
+//! retrieve the message of the last error which occurred on the calling thread.
+//!
 //! The [`mem`] module which contains some alignment constants. Though the aligment should be
 //! consistent across languages, this ensures there are no surprises when passing pointers across
 //! the `C` FFI boundary.
@@ -53,7 +62,18 @@
 //!
 //! # Naming convention
 //!
-//! Functions in the FFI are named following this general pattern:
+//! Functions in the [`generated`] module are named following this pattern:
+//!
+//! `<snake_cased_engine_name>_<engine_function_name>_<snake_cased_engine_trait_parameters>`
+//!
+//! Example if you want to use the `DefaultEngine` to encrypt a `u64` plaintext into an existing
+//! LWE ciphertext, you will call:
+//!
+//! `default_engine_discard_encrypt_lwe_ciphertext_lwe_secret_key_u64_plaintext_u64_lwe_ciphertext_u64`
+//! and its unchecked version is
+//! `default_engine_discard_encrypt_lwe_ciphertext_unchecked_lwe_secret_key_u64_plaintext_u64_lwe_ciphertext_u64`.
+//!
+//! Other functions in the FFI are named following this general pattern:
 //!
 //! `<snake_cased_engine_name>_<engine_function_name>_<data_type>_<additional_information>`
 //!
@@ -84,8 +104,8 @@
 //! - check that every pointer is not null and well aligned
 //! - EXCEPT for destroy engines which use unchecked versions (to avoid unboxing objects), use all
 //!   the checked versions of the required engines
-//! - return 0 if everything went well, 1 otherwise and print the associated error message/panic
-//!   backtrace
+//! - return 0 if everything went well, a non-zero [error code](error) otherwise and print the
+//!   associated error message/panic backtrace
 //!
 //! Note that you can get more insights from a panic using the `RUST_BACKTRACE` env variable as you
 //! normally would in rust (setting it to `1` or `full`).
@@ -96,8 +116,13 @@
 //! - no pointer check is performed, a null pointer or out of bound read/write will likely segfault
 //!   or worse
 //! - use all the unchecked versions of the required engines
-//! - return 0 if everything went well, 1 otherwise and print the associated error message/panic
-//!   backtrace
+//! - return 0 if everything went well, a non-zero [error code](error) otherwise and print the
+//!   associated error message/panic backtrace
+//!
+//! The error codes distinguish null pointers, misaligned pointers, errors reported by the engines
+//! (e.g. mismatched dimensions) and other panics. The message of the last error is kept per thread
+//! and can be retrieved with
+//! [`concrete_core_ffi_last_error_message`](error::concrete_core_ffi_last_error_message).
 //!
 //! We currently propose two "flavours" for the C FFI:
 //!
@@ -128,6 +153,11 @@ pub mod backends;
     feature = "backend_fft_serialization"
 ))]
 pub mod buffer;
+pub mod error;
+#[cfg(any(feature = "backend_default", feature = "backend_fft"))]
+pub mod generated;
 pub mod mem;
 pub mod seeders;
+#[cfg(any(feature = "backend_default_parallel", feature = "backend_fft_parallel"))]
+pub mod threads;
 pub(crate) mod utils;
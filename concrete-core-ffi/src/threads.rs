@@ -0,0 +1,25 @@
+//! Module providing control over the threads used by the parallel engines.
+//!
+//! The `DefaultParallelEngine` and `FftParallelEngine` distribute their work over the global
+//! `rayon` thread pool, which by default uses one thread per logical core.
+
+use crate::utils::*;
+use std::os::raw::c_int;
+
+/// Set the number of threads used by the parallel engines, a `thread_count` of 0 restoring the
+/// default of one thread per logical core.
+///
+/// The thread pool is created once for the whole process, hence this function must be called
+/// before any parallel engine is used, and can only be called once. Later calls fail and leave the
+/// thread count unchanged.
+#[no_mangle]
+pub extern "C" fn concrete_core_ffi_set_thread_count(thread_count: usize) -> c_int {
+    catch_panic(|| {
+        if let Err(error) = rayon::ThreadPoolBuilder::new()
+            .num_threads(thread_count)
+            .build_global()
+        {
+            panic!("Could not set the thread count: {}", error);
+        }
+    })
+}
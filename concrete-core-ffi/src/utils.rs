@@ -1,3 +1,4 @@
+use crate::error::*;
 use std::fmt::{Debug, Display};
 use std::os::raw::c_int;
 
@@ -5,24 +6,40 @@ pub fn catch_panic<F>(closure: F) -> c_int
 where
     F: FnOnce(),
 {
+    clear_pending_error();
     match std::panic::catch_unwind(std::panic::AssertUnwindSafe(closure)) {
-        Ok(_) => 0,
-        _ => 1,
+        Ok(_) => {
+            clear_pending_error();
+            CONCRETE_CORE_FFI_SUCCESS
+        }
+        Err(payload) => take_pending_error().unwrap_or_else(|| {
+            let message = payload
+                .downcast_ref::<&str>()
+                .copied()
+                .or_else(|| payload.downcast_ref::<String>().map(String::as_str))
+                .unwrap_or("unknown panic");
+            record_error(CONCRETE_CORE_FFI_PANIC, message);
+            CONCRETE_CORE_FFI_PANIC
+        }),
     }
 }
 
 pub fn check_ptr_is_non_null_and_aligned<T>(ptr: *const T) -> Result<(), String> {
     if ptr.is_null() {
-        return Err(format!("pointer is null, got: {:p}", ptr));
+        let message = format!("pointer is null, got: {:p}", ptr);
+        record_error(CONCRETE_CORE_FFI_NULL_POINTER, &message);
+        return Err(message);
     }
     let expected_alignment = std::mem::align_of::<T>();
     if ptr as usize % expected_alignment != 0 {
-        return Err(format!(
+        let message = format!(
             "pointer is misaligned, expected {} bytes alignement, got pointer: {:p}. \
             You May have mixed some pointers in your function call. If that's not the case \
             check concrete-core-ffi.h for alignment constants for plain data types allocation.",
             expected_alignment, ptr
-        ));
+        );
+        record_error(CONCRETE_CORE_FFI_MISALIGNED_POINTER, &message);
+        return Err(message);
     }
     Ok(())
 }
@@ -48,5 +65,6 @@ pub fn get_ref_checked<'a, T>(ptr: *const T) -> Result<&'a T, String> {
 }
 
 pub fn engine_error_as_readable_string<R, T: Debug + Display>(error: T) -> Result<R, String> {
+    record_error(CONCRETE_CORE_FFI_ENGINE_ERROR, &error.to_string());
     Err(format!("{:#?}: {}", error, error))
 }
@@ -0,0 +1,71 @@
+#include "concrete-core-ffi.h"
+#include <assert.h>
+#include <stdio.h>
+#include <stdlib.h>
+#include <string.h>
+
+#include "utils.h"
+
+void null_pointer_test(void) {
+  LweSecretKey64 *sk = NULL;
+  int sk_ok = default_engine_generate_new_lwe_secret_key_u64(NULL, 10, &sk);
+  assert(sk_ok == CONCRETE_CORE_FFI_NULL_POINTER);
+
+  const char *message = concrete_core_ffi_last_error_message();
+  assert(message != NULL);
+  printf("Last error: %s\n", message);
+  assert(strstr(message, "pointer is null") != NULL);
+}
+
+void engine_error_test(void) {
+  DefaultEngine *engine = NULL;
+  SeederBuilder *builder = get_best_seeder();
+
+  int default_engine_ok = new_default_engine(builder, &engine);
+  assert(default_engine_ok == CONCRETE_CORE_FFI_SUCCESS);
+
+  // The output ciphertext does not have the same dimension as the inputs
+  size_t input_lwe_size = 11;
+  size_t output_lwe_size = 6;
+  uint64_t *input_ct_buffer = aligned_alloc(U64_ALIGNMENT, sizeof(uint64_t) * input_lwe_size);
+  uint64_t *output_ct_buffer = aligned_alloc(U64_ALIGNMENT, sizeof(uint64_t) * output_lwe_size);
+
+  LweCiphertextView64 *input_ct_as_view = NULL;
+  int input_ct_as_view_ok = default_engine_create_lwe_ciphertext_view_from_u64(
+      engine, input_ct_buffer, input_lwe_size, &input_ct_as_view);
+  assert(input_ct_as_view_ok == CONCRETE_CORE_FFI_SUCCESS);
+
+  LweCiphertextMutView64 *output_ct_as_mut_view = NULL;
+  int output_ct_as_mut_view_ok = default_engine_create_lwe_ciphertext_mut_view_from_u64(
+      engine, output_ct_buffer, output_lwe_size, &output_ct_as_mut_view);
+  assert(output_ct_as_mut_view_ok == CONCRETE_CORE_FFI_SUCCESS);
+
+  int add_ok = default_engine_discard_add_lwe_ciphertext_u64_view_buffers(
+      engine, output_ct_as_mut_view, input_ct_as_view, input_ct_as_view);
+  assert(add_ok == CONCRETE_CORE_FFI_ENGINE_ERROR);
+
+  const char *message = concrete_core_ffi_last_error_message();
+  assert(message != NULL);
+  printf("Last error: %s\n", message);
+  assert(strstr(message, "LWE dimension") != NULL);
+
+  // A successful call does not clear the last error message
+  LweSecretKey64 *sk = NULL;
+  int sk_ok = default_engine_generate_new_lwe_secret_key_u64(engine, 10, &sk);
+  assert(sk_ok == CONCRETE_CORE_FFI_SUCCESS);
+  assert(concrete_core_ffi_last_error_message() == message);
+
+  destroy_lwe_secret_key_u64(sk);
+  destroy_lwe_ciphertext_view_u64(input_ct_as_view);
+  destroy_lwe_ciphertext_mut_view_u64(output_ct_as_mut_view);
+  destroy_default_engine(engine);
+  destroy_seeder_builder(builder);
+  free(input_ct_buffer);
+  free(output_ct_buffer);
+}
+
+int main(void) {
+  null_pointer_test();
+  engine_error_test();
+  return EXIT_SUCCESS;
+}
@@ -0,0 +1,193 @@
+#include "concrete-core-ffi.h"
+#include <assert.h>
+#include <inttypes.h>
+#include <stdio.h>
+#include <stdlib.h>
+#include <tgmath.h>
+
+#include "utils.h"
+
+// This helper function expands the input LUT into output, duplicating values as needed to fill
+// mega cases, taking care of the encoding and the half mega case shift in the process as well.
+// All sizes should be powers of 2.
+void encode_and_expand_lut(uint64_t *output, size_t output_size, size_t out_MESSAGE_BITS,
+                           const uint64_t *lut, size_t lut_size) {
+  assert((output_size % lut_size) == 0);
+
+  size_t mega_case_size = output_size / lut_size;
+
+  assert((mega_case_size % 2) == 0);
+
+  for (size_t idx = 0; idx < mega_case_size / 2; ++idx) {
+    output[idx] = lut[0] << (64 - out_MESSAGE_BITS - 1);
+  }
+
+  for (size_t idx = (lut_size - 1) * mega_case_size + mega_case_size / 2; idx < output_size;
+       ++idx) {
+    output[idx] = -(lut[0] << (64 - out_MESSAGE_BITS - 1));
+  }
+
+  for (size_t lut_idx = 1; lut_idx < lut_size; ++lut_idx) {
+    uint64_t lut_value = lut[lut_idx] << (64 - out_MESSAGE_BITS - 1);
+    size_t start = mega_case_size * (lut_idx - 1) + mega_case_size / 2;
+    for (size_t output_idx = start; output_idx < start + mega_case_size; ++output_idx) {
+      output[output_idx] = lut_value;
+    }
+  }
+}
+
+void parallel_vector_bootstrap_raw_ptr_buffers_test(void) {
+  // The thread count must be set before any parallel engine is used, and only once
+  int thread_count_ok = concrete_core_ffi_set_thread_count(2);
+  assert(thread_count_ok == 0);
+  int thread_count_again_ok = concrete_core_ffi_set_thread_count(4);
+  assert(thread_count_again_ok != 0);
+
+  DefaultEngine *default_engine = NULL;
+  // DANGER IN THE GENERAL CASE YOU WANT A SEEDER PER ENGINE, THIS IS FOR TESTING ONLY
+  SeederBuilder *builder = get_best_seeder();
+
+  int default_engine_ok = new_default_engine(builder, &default_engine);
+  assert(default_engine_ok == 0);
+
+  DefaultParallelEngine *default_parallel_engine = NULL;
+
+  int default_parallel_engine_ok = new_default_parallel_engine(builder, &default_parallel_engine);
+  assert(default_parallel_engine_ok == 0);
+
+  FftEngine *fft_engine = NULL;
+
+  int fft_engine_ok = new_fft_engine(&fft_engine);
+  assert(fft_engine_ok == 0);
+
+  FftParallelEngine *fft_parallel_engine = NULL;
+
+  int fft_parallel_engine_ok = new_fft_parallel_engine(&fft_parallel_engine);
+  assert(fft_parallel_engine_ok == 0);
+
+  double pbs_variance = 0.00000000000001;
+  double encryption_variance = 0.0000000001;
+  size_t glwe_dimension = 1;
+  size_t input_lwe_dimension = 2;
+  size_t poly_size = 1024;
+  size_t level = 3;
+  size_t base_log = 5;
+  size_t output_lwe_dimension = glwe_dimension * poly_size;
+  size_t lwe_count = 4;
+
+  // We generate the keys
+  LweSecretKey64 *input_lwe_sk = NULL;
+  int lwe_in_key_ok = default_engine_generate_new_lwe_secret_key_u64(
+      default_engine, input_lwe_dimension, &input_lwe_sk);
+  assert(lwe_in_key_ok == 0);
+
+  LweSecretKey64 *output_lwe_sk = NULL;
+  int lwe_out_key_ok = default_engine_generate_new_lwe_secret_key_u64(
+      default_engine, output_lwe_dimension, &output_lwe_sk);
+  assert(lwe_out_key_ok == 0);
+
+  GlweSecretKey64 *output_glwe_sk = NULL;
+
+  // This is not part of the C FFI but rather is a C util exposed for convenience in tests.
+  int clone_transform_ok = clone_transform_lwe_secret_key_to_glwe_secret_key_u64(
+      default_engine, output_lwe_sk, poly_size, &output_glwe_sk);
+  assert(clone_transform_ok == 0);
+
+  LweBootstrapKey64 *bsk = NULL;
+  int bsk_ok = default_parallel_engine_generate_new_lwe_bootstrap_key_u64(
+      default_parallel_engine, input_lwe_sk, output_glwe_sk, base_log, level, pbs_variance, &bsk);
+  assert(bsk_ok == 0);
+
+  FftFourierLweBootstrapKey64 *fbsk = NULL;
+  int fbsk_ok = fft_engine_convert_lwe_bootstrap_key_to_fft_fourier_lwe_bootstrap_key_u64(
+      fft_engine, bsk, &fbsk);
+  assert(fbsk_ok == 0);
+
+  // We generate the ciphertexts
+  uint64_t *input_ct_buffer =
+      aligned_alloc(U64_ALIGNMENT, sizeof(uint64_t) * (input_lwe_dimension + 1) * lwe_count);
+  uint64_t *output_ct_buffer =
+      aligned_alloc(U64_ALIGNMENT, sizeof(uint64_t) * (output_lwe_dimension + 1) * lwe_count);
+  uint64_t *plaintexts = aligned_alloc(U64_ALIGNMENT, sizeof(uint64_t) * lwe_count);
+  uint64_t *outputs = aligned_alloc(U64_ALIGNMENT, sizeof(uint64_t) * lwe_count);
+  for (size_t i = 0; i < lwe_count; i++) {
+    plaintexts[i] = ((uint64_t)(i + 1)) << SHIFT;
+  }
+
+  int encrypt_ok = default_engine_discard_encrypt_lwe_ciphertext_vector_u64_raw_ptr_buffers(
+      default_engine, input_lwe_sk, input_ct_buffer, plaintexts, encryption_variance, lwe_count);
+  assert(encrypt_ok == 0);
+
+  // We build one accumulator per ciphertext, all of them encoding the identity
+  int tabulation_length = 1 << MESSAGE_BITS;
+
+  size_t accumulator_size = poly_size * (glwe_dimension + 1);
+
+  uint64_t *accumulators =
+      aligned_alloc(U64_ALIGNMENT, sizeof(uint64_t) * accumulator_size * lwe_count);
+
+  uint64_t *tabulated_function_array =
+      aligned_alloc(U64_ALIGNMENT, sizeof(uint64_t) * tabulation_length);
+  for (int i = 0; i < tabulation_length; i++) {
+    tabulated_function_array[i] = (uint64_t)i;
+  }
+
+  uint64_t *expanded_tabulated_function_array =
+      aligned_alloc(U64_ALIGNMENT, sizeof(uint64_t) * poly_size);
+
+  encode_and_expand_lut(expanded_tabulated_function_array, poly_size, MESSAGE_BITS,
+                        tabulated_function_array, tabulation_length);
+
+  for (size_t i = 0; i < lwe_count; i++) {
+    int trivial_encrypt_ok =
+        default_engine_discard_trivially_encrypt_glwe_ciphertext_u64_raw_ptr_buffers(
+            default_engine, &accumulators[i * accumulator_size], accumulator_size,
+            expanded_tabulated_function_array, poly_size);
+    assert(trivial_encrypt_ok == 0);
+  }
+
+  // We perform the bootstrap
+  int result_ok =
+      fft_parallel_engine_lwe_ciphertext_vector_discarding_bootstrap_u64_raw_ptr_buffers(
+          fft_parallel_engine, default_engine, fbsk, output_ct_buffer, input_ct_buffer,
+          accumulators, lwe_count);
+  assert(result_ok == 0);
+
+  int decrypt_ok = default_engine_decrypt_lwe_ciphertext_vector_u64_raw_ptr_buffers(
+      default_engine, output_lwe_sk, output_ct_buffer, outputs, lwe_count);
+  assert(decrypt_ok == 0);
+
+  // We check that the outputs are the same
+  for (size_t i = 0; i < lwe_count; i++) {
+    double expected = (double)plaintexts[i] / pow(2, SHIFT);
+    double obtained = (double)outputs[i] / pow(2, SHIFT);
+    printf("Expected: %f, Obtained: %f\n", expected, obtained);
+    double abs_diff = fabs(obtained - expected);
+    double rel_error = abs_diff / fmax(expected, obtained);
+    assert(rel_error < 0.01);
+  }
+
+  // We deallocate the objects
+  destroy_lwe_secret_key_u64(input_lwe_sk);
+  destroy_lwe_secret_key_u64(output_lwe_sk);
+  destroy_glwe_secret_key_u64(output_glwe_sk);
+  destroy_lwe_bootstrap_key_u64(bsk);
+  destroy_fft_fourier_lwe_bootstrap_key_u64(fbsk);
+  destroy_default_parallel_engine(default_parallel_engine);
+  destroy_default_engine(default_engine);
+  destroy_fft_parallel_engine(fft_parallel_engine);
+  destroy_fft_engine(fft_engine);
+  destroy_seeder_builder(builder);
+  free(tabulated_function_array);
+  free(expanded_tabulated_function_array);
+  free(accumulators);
+  free(input_ct_buffer);
+  free(output_ct_buffer);
+  free(plaintexts);
+  free(outputs);
+}
+
+int main(void) {
+  parallel_vector_bootstrap_raw_ptr_buffers_test();
+  return EXIT_SUCCESS;
+}
@@ -0,0 +1,213 @@
+#include "concrete-core-ffi.h"
+#include <assert.h>
+#include <stdio.h>
+#include <stdlib.h>
+#include <tgmath.h>
+
+#include "utils.h"
+
+void vector_encrypt_add_decrypt_raw_ptr_buffers_test(void) {
+  // We generate the random sources
+  DefaultEngine *engine = NULL;
+  SeederBuilder *builder = get_best_seeder();
+
+  int default_engine_ok = new_default_engine(builder, &engine);
+  assert(default_engine_ok == 0);
+  double variance = 0.000000001;
+
+  // We generate the secret key
+  size_t lwe_dimension = 10;
+  size_t lwe_count = 4;
+  LweSecretKey64 *sk = NULL;
+  int sk_ok = default_engine_generate_new_lwe_secret_key_u64(engine, lwe_dimension, &sk);
+  assert(sk_ok == 0);
+
+  // We generate the texts
+  size_t buffer_size = sizeof(uint64_t) * (lwe_dimension + 1) * lwe_count;
+  uint64_t *input_ct_1_buffer = aligned_alloc(U64_ALIGNMENT, buffer_size);
+  uint64_t *input_ct_2_buffer = aligned_alloc(U64_ALIGNMENT, buffer_size);
+  uint64_t *output_ct_buffer = aligned_alloc(U64_ALIGNMENT, buffer_size);
+  uint64_t *plaintexts_1 = aligned_alloc(U64_ALIGNMENT, sizeof(uint64_t) * lwe_count);
+  uint64_t *plaintexts_2 = aligned_alloc(U64_ALIGNMENT, sizeof(uint64_t) * lwe_count);
+  uint64_t *outputs = aligned_alloc(U64_ALIGNMENT, sizeof(uint64_t) * lwe_count);
+  for (size_t i = 0; i < lwe_count; i++) {
+    plaintexts_1[i] = ((uint64_t)(i + 1)) << SHIFT;
+    plaintexts_2[i] = ((uint64_t)2) << SHIFT;
+  }
+
+  // We encrypt the plaintexts
+  int enc_ct_1_ok = default_engine_discard_encrypt_lwe_ciphertext_vector_u64_raw_ptr_buffers(
+      engine, sk, input_ct_1_buffer, plaintexts_1, variance, lwe_count);
+  assert(enc_ct_1_ok == 0);
+  int enc_ct_2_ok = default_engine_discard_encrypt_lwe_ciphertext_vector_u64_raw_ptr_buffers(
+      engine, sk, input_ct_2_buffer, plaintexts_2, variance, lwe_count);
+  assert(enc_ct_2_ok == 0);
+
+  int add_ok = default_engine_discard_add_lwe_ciphertext_vector_u64_raw_ptr_buffers(
+      engine, output_ct_buffer, input_ct_1_buffer, input_ct_2_buffer, lwe_dimension, lwe_count);
+  assert(add_ok == 0);
+
+  // We decrypt the plaintexts
+  int decrypt_ok = default_engine_decrypt_lwe_ciphertext_vector_u64_raw_ptr_buffers(
+      engine, sk, output_ct_buffer, outputs, lwe_count);
+  assert(decrypt_ok == 0);
+
+  // We check that the outputs are the same
+  for (size_t i = 0; i < lwe_count; i++) {
+    double expected = ((double)plaintexts_1[i] + (double)plaintexts_2[i]) / pow(2, SHIFT);
+    double obtained = (double)outputs[i] / pow(2, SHIFT);
+    printf("Comparing output. Expected %f, Obtained %f\n", expected, obtained);
+    double abs_diff = fabs(obtained - expected);
+    double rel_error = abs_diff / fmax(expected, obtained);
+    assert(rel_error < 0.002);
+  }
+
+  // We deallocate the objects
+  destroy_lwe_secret_key_u64(sk);
+  destroy_default_engine(engine);
+  destroy_seeder_builder(builder);
+  free(input_ct_1_buffer);
+  free(input_ct_2_buffer);
+  free(output_ct_buffer);
+  free(plaintexts_1);
+  free(plaintexts_2);
+  free(outputs);
+}
+
+void vector_encrypt_add_decrypt_unchecked_raw_ptr_buffers_test(void) {
+  // We generate the random sources
+  DefaultEngine *engine = NULL;
+  SeederBuilder *builder = get_best_seeder_unchecked();
+
+  int default_engine_ok = new_default_engine_unchecked(builder, &engine);
+  assert(default_engine_ok == 0);
+  double variance = 0.000000001;
+
+  // We generate the secret key
+  size_t lwe_dimension = 10;
+  size_t lwe_count = 4;
+  LweSecretKey64 *sk = NULL;
+  int sk_ok = default_engine_generate_new_lwe_secret_key_unchecked_u64(engine, lwe_dimension, &sk);
+  assert(sk_ok == 0);
+
+  // We generate the texts
+  size_t buffer_size = sizeof(uint64_t) * (lwe_dimension + 1) * lwe_count;
+  uint64_t *input_ct_1_buffer = aligned_alloc(U64_ALIGNMENT, buffer_size);
+  uint64_t *input_ct_2_buffer = aligned_alloc(U64_ALIGNMENT, buffer_size);
+  uint64_t *output_ct_buffer = aligned_alloc(U64_ALIGNMENT, buffer_size);
+  uint64_t *plaintexts_1 = aligned_alloc(U64_ALIGNMENT, sizeof(uint64_t) * lwe_count);
+  uint64_t *plaintexts_2 = aligned_alloc(U64_ALIGNMENT, sizeof(uint64_t) * lwe_count);
+  uint64_t *outputs = aligned_alloc(U64_ALIGNMENT, sizeof(uint64_t) * lwe_count);
+  for (size_t i = 0; i < lwe_count; i++) {
+    plaintexts_1[i] = ((uint64_t)(i + 1)) << SHIFT;
+    plaintexts_2[i] = ((uint64_t)2) << SHIFT;
+  }
+
+  // We encrypt the plaintexts
+  int enc_ct_1_ok =
+      default_engine_discard_encrypt_lwe_ciphertext_vector_unchecked_u64_raw_ptr_buffers(
+          engine, sk, input_ct_1_buffer, plaintexts_1, variance, lwe_count);
+  assert(enc_ct_1_ok == 0);
+  int enc_ct_2_ok =
+      default_engine_discard_encrypt_lwe_ciphertext_vector_unchecked_u64_raw_ptr_buffers(
+          engine, sk, input_ct_2_buffer, plaintexts_2, variance, lwe_count);
+  assert(enc_ct_2_ok == 0);
+
+  int add_ok = default_engine_discard_add_lwe_ciphertext_vector_unchecked_u64_raw_ptr_buffers(
+      engine, output_ct_buffer, input_ct_1_buffer, input_ct_2_buffer, lwe_dimension, lwe_count);
+  assert(add_ok == 0);
+
+  // We decrypt the plaintexts
+  int decrypt_ok = default_engine_decrypt_lwe_ciphertext_vector_unchecked_u64_raw_ptr_buffers(
+      engine, sk, output_ct_buffer, outputs, lwe_count);
+  assert(decrypt_ok == 0);
+
+  // We check that the outputs are the same
+  for (size_t i = 0; i < lwe_count; i++) {
+    double expected = ((double)plaintexts_1[i] + (double)plaintexts_2[i]) / pow(2, SHIFT);
+    double obtained = (double)outputs[i] / pow(2, SHIFT);
+    printf("Comparing output. Expected %f, Obtained %f\n", expected, obtained);
+    double abs_diff = fabs(obtained - expected);
+    double rel_error = abs_diff / fmax(expected, obtained);
+    assert(rel_error < 0.002);
+  }
+
+  // We deallocate the objects
+  destroy_lwe_secret_key_unchecked_u64(sk);
+  destroy_default_engine_unchecked(engine);
+  destroy_seeder_builder_unchecked(builder);
+  free(input_ct_1_buffer);
+  free(input_ct_2_buffer);
+  free(output_ct_buffer);
+  free(plaintexts_1);
+  free(plaintexts_2);
+  free(outputs);
+}
+
+void seeded_vector_encrypt_expand_decrypt_raw_ptr_buffers_test(void) {
+  // We generate the random sources
+  DefaultEngine *engine = NULL;
+  SeederBuilder *builder = get_best_seeder();
+
+  int default_engine_ok = new_default_engine(builder, &engine);
+  assert(default_engine_ok == 0);
+  double variance = 0.000000001;
+
+  // We generate the secret key
+  size_t lwe_dimension = 10;
+  size_t lwe_count = 4;
+  LweSecretKey64 *sk = NULL;
+  int sk_ok = default_engine_generate_new_lwe_secret_key_u64(engine, lwe_dimension, &sk);
+  assert(sk_ok == 0);
+
+  // We generate the texts
+  uint64_t *expanded_ct_buffer =
+      aligned_alloc(U64_ALIGNMENT, sizeof(uint64_t) * (lwe_dimension + 1) * lwe_count);
+  uint64_t *plaintexts = aligned_alloc(U64_ALIGNMENT, sizeof(uint64_t) * lwe_count);
+  uint64_t *outputs = aligned_alloc(U64_ALIGNMENT, sizeof(uint64_t) * lwe_count);
+  for (size_t i = 0; i < lwe_count; i++) {
+    plaintexts[i] = ((uint64_t)(i + 1)) << SHIFT;
+  }
+
+  // We encrypt the plaintexts into a seeded vector and expand it in the caller buffer
+  LweSeededCiphertextVector64 *seeded_ct = NULL;
+  int enc_ok = default_engine_encrypt_lwe_seeded_ciphertext_vector_u64_raw_ptr_buffers(
+      engine, sk, plaintexts, variance, lwe_count, &seeded_ct);
+  assert(enc_ok == 0);
+
+  int expand_ok =
+      default_engine_transform_lwe_seeded_ciphertext_vector_to_lwe_ciphertext_vector_u64_raw_ptr_buffers(
+          engine, seeded_ct, expanded_ct_buffer);
+  assert(expand_ok == 0);
+
+  // We decrypt the plaintexts
+  int decrypt_ok = default_engine_decrypt_lwe_ciphertext_vector_u64_raw_ptr_buffers(
+      engine, sk, expanded_ct_buffer, outputs, lwe_count);
+  assert(decrypt_ok == 0);
+
+  // We check that the outputs are the same
+  for (size_t i = 0; i < lwe_count; i++) {
+    double expected = (double)plaintexts[i] / pow(2, SHIFT);
+    double obtained = (double)outputs[i] / pow(2, SHIFT);
+    printf("Comparing output. Expected %f, Obtained %f\n", expected, obtained);
+    double abs_diff = fabs(obtained - expected);
+    double rel_error = abs_diff / fmax(expected, obtained);
+    assert(rel_error < 0.002);
+  }
+
+  // We deallocate the objects
+  destroy_lwe_secret_key_u64(sk);
+  destroy_lwe_seeded_ciphertext_vector_u64(seeded_ct);
+  destroy_default_engine(engine);
+  destroy_seeder_builder(builder);
+  free(expanded_ct_buffer);
+  free(plaintexts);
+  free(outputs);
+}
+
+int main(void) {
+  vector_encrypt_add_decrypt_raw_ptr_buffers_test();
+  vector_encrypt_add_decrypt_unchecked_raw_ptr_buffers_test();
+  seeded_vector_encrypt_expand_decrypt_raw_ptr_buffers_test();
+  return EXIT_SUCCESS;
+}
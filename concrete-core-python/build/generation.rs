@@ -0,0 +1,503 @@
+//! This module contains a function that generates the binding token stream from the pruned ccr.
+use concrete_core_representation::{ConcreteCore, *};
+use quote::{format_ident, quote, ToTokens};
+use std::collections::BTreeMap;
+use syn::__private::{Span, TokenStream2};
+use syn::{parse_quote, Ident, Type};
+
+/// Generate the whole binding from the pruned ccr.
+pub fn generate_binding(ccr: &ConcreteCore) -> TokenStream2 {
+    let mut output = TokenStream2::new();
+    output.extend(generate_all_entities(ccr));
+    output.extend(generate_all_parameters(ccr));
+    output.extend(generate_all_engines(ccr));
+    output.extend(generate_register(ccr));
+    output
+}
+
+fn generate_all_entities(ccr: &ConcreteCore) -> TokenStream2 {
+    let mut output = TokenStream2::new();
+    for entity in ccr
+        .backends
+        .iter()
+        .flat_map(|backend| backend.entities.iter())
+    {
+        output.extend(generate_entity_definition(entity));
+    }
+    output
+}
+
+fn generate_entity_definition(entity: &Entity) -> TokenStream2 {
+    // INVARIANT: entities are available in the `concrete_core::prelude` module
+    // INVARIANT: views are not exported
+    let ident = entity.definition.item_struct.ident.clone();
+    quote! {
+        #[pyclass]
+        pub struct #ident(pub(crate) concrete_core::prelude::#ident);
+    }
+}
+
+/// Returns the parameters and dispersions used by the engine methods, along with the primitive
+/// type they wrap.
+fn collect_parameters(ccr: &ConcreteCore) -> BTreeMap<String, TokenStream2> {
+    let mut output = BTreeMap::new();
+    for arg in ccr
+        .backends
+        .iter()
+        .flat_map(|backend| backend.engines.iter())
+        .flat_map(|engine| engine.engine_impls.iter())
+        .flat_map(|engine_impl| engine_impl.checked_method.args())
+    {
+        match arg {
+            // INVARIANT: the parameters which do not wrap a single `usize` were pruned.
+            EngineTraitImplArg::Parameter(_, type_) => {
+                output.insert(type_ident(type_).to_string(), quote!(usize));
+            }
+            EngineTraitImplArg::Dispersion(_, type_) => {
+                output.insert(type_ident(type_).to_string(), quote!(f64));
+            }
+            _ => {}
+        }
+    }
+    output
+}
+
+fn generate_all_parameters(ccr: &ConcreteCore) -> TokenStream2 {
+    let mut output = TokenStream2::new();
+    for (ident, primitive) in collect_parameters(ccr).iter() {
+        let ident = Ident::new(ident.as_str(), Span::call_site());
+        output.extend(quote! {
+            #[pyclass]
+            #[derive(Clone, Copy)]
+            pub struct #ident(pub(crate) concrete_core::prelude::#ident);
+
+            #[pymethods]
+            impl #ident {
+                #[new]
+                pub fn new(value: #primitive) -> #ident {
+                    #ident(concrete_core::prelude::#ident(value))
+                }
+
+                #[getter]
+                pub fn value(&self) -> #primitive {
+                    self.0 .0
+                }
+
+                fn __repr__(&self) -> String {
+                    format!("{:?}", self.0)
+                }
+            }
+        });
+    }
+    output
+}
+
+fn generate_all_engines(ccr: &ConcreteCore) -> TokenStream2 {
+    let mut output = TokenStream2::new();
+    for engine in ccr
+        .backends
+        .iter()
+        .flat_map(|backend| backend.engines.iter())
+    {
+        // INVARIANT: pyo3 only accepts a single `#[pymethods]` block per class.
+        let engine_ident = engine.definition.get_name();
+        let constructor = generate_engine_constructor(engine);
+        let methods = engine
+            .engine_impls
+            .iter()
+            .map(generate_engine_method)
+            .collect::<Vec<_>>();
+        output.extend(generate_engine_definition(engine));
+        output.extend(quote! {
+            #[pymethods]
+            impl #engine_ident {
+                #constructor
+                #(#methods)*
+            }
+        });
+    }
+    output
+}
+
+fn generate_engine_definition(engine: &Engine) -> TokenStream2 {
+    // INVARIANT: engines are available in the `concrete_core::prelude` module
+    // INVARIANT: engines may own a `Box<dyn Seeder>` which is not `Send`
+    let ident = engine.definition.item_struct.ident.clone();
+    quote! {
+        #[pyclass(unsendable)]
+        pub struct #ident(pub(crate) concrete_core::prelude::#ident);
+    }
+}
+
+fn generate_engine_constructor(engine: &Engine) -> TokenStream2 {
+    // INVARIANT: the constructor parameter is either a `Box<dyn Seeder>` or `()`.
+    let engine_ident = engine.definition.get_name();
+    let parameter_associated_type = engine.abstract_engine_impl.get_parameters_associated_type();
+    let (public_constructor_args, private_constructor_exprs) =
+        if is_seeder(parameter_associated_type) {
+            (
+                quote!(seeder: PyObject),
+                quote!(Box::new(crate::PyFunctionSeeder::new(seeder))),
+            )
+        } else if is_unit(parameter_associated_type) {
+            (quote!(), quote!(()))
+        } else {
+            panic!(
+                "Failed to generate constructor args for `{}`.",
+                engine_ident
+            )
+        };
+    quote! {
+        #[new]
+        pub fn new(#public_constructor_args) -> PyResult<#engine_ident> {
+            concrete_core::prelude::#engine_ident::new(#private_constructor_exprs)
+                .map_err(|e| crate::EngineError::new_err(format!("{}", e)))
+                .map(#engine_ident)
+        }
+    }
+}
+
+fn is_seeder(parameter_associated_type: &Type) -> bool {
+    let test_ast: syn::TypePath = parse_quote!(Box<dyn Seeder>);
+    probe!(
+        Some(parameter_associated_type),
+        syn::Type::Path(p) => p,
+        p ?> *p == &test_ast
+    )
+    .is_some()
+}
+
+fn is_unit(parameter_associated_type: &Type) -> bool {
+    probe!(
+        Some(parameter_associated_type),
+        syn::Type::Tuple(t) => t,
+        t ?> t.elems.is_empty()
+    )
+    .is_some()
+}
+
+fn generate_engine_method(engine_impl: &EngineTraitImpl) -> TokenStream2 {
+    let method_ident = generate_engine_method_ident(engine_impl);
+    let method_args = generate_engine_method_args(engine_impl);
+    let method_return = generate_engine_method_return_type(engine_impl);
+    let method_body = generate_engine_method_body(engine_impl);
+    let method_return_map = generate_engine_method_return_map(engine_impl);
+    quote! {
+        pub fn #method_ident(&mut self, #method_args) -> PyResult<#method_return>{
+            #method_body
+                .map_err(|e| crate::EngineError::new_err(format!("{}", e)))
+                #method_return_map
+        }
+    }
+}
+
+fn generate_engine_method_return_map(engine_impl: &EngineTraitImpl) -> TokenStream2 {
+    match engine_impl.checked_method.return_() {
+        EngineTraitImplReturn::OwnedEntity(v) => {
+            let ident = type_ident(v);
+            quote!(.map(#ident))
+        }
+        EngineTraitImplReturn::Numeric(_) => {
+            quote!()
+        }
+        EngineTraitImplReturn::NumericVec(_) => {
+            quote!(.map(|vec| PyArray1::from_vec(py, vec).to_owned()))
+        }
+        EngineTraitImplReturn::Unit(_) => {
+            quote!()
+        }
+        _ => panic!(
+            "Unexpected return type: {:?}",
+            engine_impl.checked_method.return_()
+        ),
+    }
+}
+
+fn generate_engine_method_body(engine_impl: &EngineTraitImpl) -> TokenStream2 {
+    let mut blocks = TokenStream2::new();
+    engine_impl
+        .checked_method
+        .args()
+        .iter()
+        .for_each(|arg| match arg {
+            EngineTraitImplArg::NumericSlice(pat, _, _) => blocks.extend(quote! {
+                let #pat = #pat
+                    .as_slice()
+                    .map_err(|e| PyValueError::new_err(format!("{}", e)))?;
+            }),
+            EngineTraitImplArg::NumericSliceMut(pat, _, _) => blocks.extend(quote! {
+                let #pat = #pat
+                    .as_slice_mut()
+                    .map_err(|e| PyValueError::new_err(format!("{}", e)))?;
+            }),
+            EngineTraitImplArg::NumericVec(pat, _, _) => blocks.extend(quote! {
+                let #pat = #pat
+                    .to_vec()
+                    .map_err(|e| PyValueError::new_err(format!("{}", e)))?;
+            }),
+            _ => {}
+        });
+
+    let mut args = TokenStream2::new();
+    engine_impl
+        .checked_method
+        .args()
+        .iter()
+        .for_each(|arg| match arg {
+            EngineTraitImplArg::OwnedEntity(pat, _) => args.extend(quote!(#pat.0.clone(),)),
+            EngineTraitImplArg::OwnedEntityRef(pat, _) => args.extend(quote!(& #pat.0,)),
+            EngineTraitImplArg::OwnedEntityRefMut(pat, _) => args.extend(quote!(&mut #pat.0,)),
+            EngineTraitImplArg::Parameter(pat, _) => args.extend(quote!(#pat.0,)),
+            EngineTraitImplArg::Dispersion(pat, _) => args.extend(quote!(#pat.0,)),
+            EngineTraitImplArg::Numeric(pat, _) => args.extend(quote!(#pat,)),
+            EngineTraitImplArg::NumericRef(pat, _, _) => args.extend(quote!(& #pat,)),
+            EngineTraitImplArg::NumericSlice(pat, _, _) => args.extend(quote!(#pat,)),
+            EngineTraitImplArg::NumericSliceMut(pat, _, _) => args.extend(quote!(#pat,)),
+            EngineTraitImplArg::NumericVec(pat, _, _) => args.extend(quote!(#pat,)),
+            _ => {
+                panic!("Unexpected arg: {:?}", arg)
+            }
+        });
+    let method_name = &engine_impl.checked_method.ident;
+    quote!(
+        #blocks
+        self.0.#method_name(#args)
+    )
+}
+
+fn generate_engine_method_return_type(engine_impl: &EngineTraitImpl) -> TokenStream2 {
+    match engine_impl.checked_method.return_() {
+        EngineTraitImplReturn::OwnedEntity(type_) => type_ident(type_).to_token_stream(),
+        EngineTraitImplReturn::NumericVec(type_) => {
+            let num = vec_element_type(type_);
+            quote!(Py<PyArray1<#num>>)
+        }
+        return_ => {
+            let return_type = return_.type_();
+            quote!(#return_type)
+        }
+    }
+}
+
+fn generate_engine_method_args(engine_impl: &EngineTraitImpl) -> TokenStream2 {
+    let mut output = TokenStream2::new();
+    if matches!(
+        engine_impl.checked_method.return_(),
+        EngineTraitImplReturn::NumericVec(_)
+    ) {
+        output.extend(quote! {py: Python<'_>,});
+    }
+    engine_impl
+        .checked_method
+        .args()
+        .iter()
+        .for_each(|arg| match arg {
+            EngineTraitImplArg::OwnedEntity(pat_ident, type_)
+            | EngineTraitImplArg::OwnedEntityRef(pat_ident, type_) => {
+                let ident = type_ident(type_);
+                output.extend(quote! {#pat_ident: PyRef<#ident>,});
+            }
+            EngineTraitImplArg::OwnedEntityRefMut(pat_ident, type_) => {
+                let ident = type_ident(type_);
+                output.extend(quote! {mut #pat_ident: PyRefMut<#ident>,});
+            }
+            EngineTraitImplArg::Parameter(pat_ident, type_)
+            | EngineTraitImplArg::Dispersion(pat_ident, type_) => {
+                let ident = type_ident(type_);
+                output.extend(quote! {#pat_ident: #ident,});
+            }
+            EngineTraitImplArg::NumericRef(pat_ident, _, num_ident) => {
+                output.extend(quote! {#pat_ident: #num_ident,});
+            }
+            EngineTraitImplArg::NumericSlice(pat_ident, _, num_ident)
+            | EngineTraitImplArg::NumericVec(pat_ident, _, num_ident) => {
+                output.extend(quote! {#pat_ident: PyReadonlyArray1<#num_ident>,});
+            }
+            EngineTraitImplArg::NumericSliceMut(pat_ident, _, num_ident) => {
+                output.extend(quote! {mut #pat_ident: PyReadwriteArray1<#num_ident>,});
+            }
+            arg => {
+                let pat_ident = arg.pat_ident();
+                let type_ = arg.type_();
+                output.extend(quote! {#pat_ident: #type_,});
+            }
+        });
+    output
+}
+
+fn generate_engine_method_ident(engine_impl: &EngineTraitImpl) -> TokenStream2 {
+    let output = format!(
+        "{}_{}",
+        engine_impl.checked_method.to_fragment(),
+        engine_impl
+            .engine_trait_parameters()
+            .iter()
+            .map(ToNameFragment::to_fragment)
+            .collect::<Vec<_>>()
+            .join("_")
+    );
+    Ident::new(output.as_str(), Span::call_site()).to_token_stream()
+}
+
+fn generate_register(ccr: &ConcreteCore) -> TokenStream2 {
+    let mut classes = Vec::new();
+    for backend in ccr.backends.iter() {
+        classes.extend(
+            backend
+                .entities
+                .iter()
+                .map(|entity| entity.definition.item_struct.ident.clone()),
+        );
+        classes.extend(
+            backend
+                .engines
+                .iter()
+                .map(|engine| engine.definition.item_struct.ident.clone()),
+        );
+    }
+    classes.extend(
+        collect_parameters(ccr)
+            .keys()
+            .map(|ident| format_ident!("{}", ident)),
+    );
+    quote! {
+        /// Adds all the generated classes to the python module.
+        pub(crate) fn register(module: &PyModule) -> PyResult<()> {
+            #(module.add_class::<#classes>()?;)*
+            Ok(())
+        }
+    }
+}
+
+/// Returns the ident of the last segment of a (possibly referenced) type path.
+fn type_ident(type_: &Type) -> Ident {
+    probe!(
+        Some(strip_references(type_)),
+        syn::Type::Path(p) => p,
+        p >> p.path.segments.last(),
+        s -> s.ident.clone()
+    )
+    .unwrap_or_else(|| panic!("Failed to extract ident of type: {:?}", type_))
+}
+
+fn strip_references(type_: &Type) -> &Type {
+    match type_ {
+        syn::Type::Reference(r) => strip_references(r.elem.as_ref()),
+        t => t,
+    }
+}
+
+/// Returns the element type of a `Vec<T>` type.
+fn vec_element_type(type_: &Type) -> Type {
+    probe!(
+        Some(type_),
+        syn::Type::Path(p) => p,
+        p >> p.path.segments.last(),
+        s -> &s.arguments,
+        syn::PathArguments::AngleBracketed(a) => a,
+        a >> a.args.first(),
+        syn::GenericArgument::Type(t) => t,
+        t -> t.clone()
+    )
+    .unwrap_or_else(|| panic!("Failed to extract element type of: {:?}", type_))
+}
+
+trait ToNameFragment {
+    fn to_fragment(&self) -> String;
+}
+
+impl ToNameFragment for EngineTraitImplCheckedMethod {
+    fn to_fragment(&self) -> String {
+        self.ident.to_string()
+    }
+}
+
+impl ToNameFragment for EngineTraitImplGenericArgument {
+    fn to_fragment(&self) -> String {
+        match self {
+            EngineTraitImplGenericArgument::OwnedEntity(_)
+            | EngineTraitImplGenericArgument::ViewEntity(_)
+            | EngineTraitImplGenericArgument::MutViewEntity(_)
+            | EngineTraitImplGenericArgument::Config(_) => probe!(
+                Some(self.get_type()),
+                syn::Type::Path(t) => t,
+                t >> t.path.segments.first(),
+                t -> t.ident.to_string(),
+                t -> camel_case_to_snake_case(&t)
+            )
+            .unwrap(),
+            EngineTraitImplGenericArgument::Numeric(t) => probe!(
+                Some(t),
+                syn::Type::Path(t) => t,
+                t >> t.path.segments.first(),
+                t -> t.ident.to_string()
+            )
+            .unwrap(),
+            EngineTraitImplGenericArgument::NumericSlice(t) => probe!(
+                Some(t),
+                syn::Type::Reference(r) => r,
+                r -> r.elem.as_ref(),
+                syn::Type::Slice(s) => s,
+                s -> s.elem.as_ref(),
+                syn::Type::Path(p) => p,
+                t >> t.path.segments.first(),
+                t -> format!("{}_slice", t.ident)
+            )
+            .unwrap(),
+            EngineTraitImplGenericArgument::NumericSliceMut(t) => probe!(
+                Some(t),
+                syn::Type::Reference(r) => r,
+                r -> r.elem.as_ref(),
+                syn::Type::Slice(s) => s,
+                s -> s.elem.as_ref(),
+                syn::Type::Path(p) => p,
+                t >> t.path.segments.first(),
+                t -> format!("{}_mut_slice", t.ident)
+            )
+            .unwrap(),
+            EngineTraitImplGenericArgument::NumericVec(t) => probe!(
+                Some(t),
+                syn::Type::Path(p) => p,
+                p >> p.path.segments.first(),
+                s -> &s.arguments,
+                syn::PathArguments::AngleBracketed(a) => a,
+                a >> a.args.first(),
+                syn::GenericArgument::Type(t) => t,
+                syn::Type::Path(p) => p,
+                t >> t.path.segments.first(),
+                t -> format!("{}_vec", t.ident)
+            )
+            .unwrap(),
+            EngineTraitImplGenericArgument::Unknown(_) => {
+                panic!()
+            }
+        }
+    }
+}
+
+fn camel_case_to_snake_case(input: &str) -> String {
+    let mut output = String::new();
+    let mut iter = input.chars().peekable();
+    loop {
+        match (iter.next(), iter.peek()) {
+            (Some(ch), _) if !ch.is_ascii_alphanumeric() => {
+                panic!()
+            }
+            (Some(ch), Some(nch)) if ch.is_ascii_uppercase() && nch.is_ascii_lowercase() => {
+                output.push(ch.to_ascii_lowercase())
+            }
+            (Some(ch), Some(nch)) if ch.is_ascii_uppercase() && nch.is_ascii_uppercase() => {
+                output.push(ch.to_ascii_lowercase());
+                output.push('_')
+            }
+            (Some(ch), Some(nch)) if nch.is_ascii_uppercase() => {
+                output.push(ch);
+                output.push('_')
+            }
+            (Some(ch), _) => output.push(ch.to_ascii_lowercase()),
+            (None, None) => break,
+            _ => panic!("Failed to transform case of `{}`", input),
+        }
+    }
+    output
+}
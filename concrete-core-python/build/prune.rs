@@ -0,0 +1,130 @@
+//! This module contains a function that prunes the ccr to only keep the elements that we want to
+//! expose in the python api.
+use concrete_core_representation::{
+    ConcreteCore, EngineTraitImplArg, EngineTraitImplReturn, EntityOwnership,
+};
+
+const WHITELISTED_BACKENDS: [&str; 2] = ["default", "fft"];
+
+// Memory is managed by the python garbage collector, entities are never destroyed explicitly.
+const BLACKLISTED_ENGINE_TRAITS: [&str; 1] = ["DestructionEngine"];
+
+// Those parameters do not wrap a single `usize` and can not be exposed as such.
+const UNAVAILABLE_PARAMETERS: [&str; 1] = ["LweCiphertextRange"];
+
+// Those numeric types have no numpy counterpart.
+const UNAVAILABLE_ARRAY_NUMERICS: [&str; 2] = ["u128", "i128"];
+
+/// A function that prunes a concrete-core representation from the nodes we do not want in the
+/// python api
+pub fn prune(ccr: &mut ConcreteCore) {
+    prune_non_whitelisted_backends(ccr);
+    prune_unavailable_entities(ccr);
+    prune_unavailable_engine_impls(ccr);
+}
+
+/// Prunes the backends which are not whitelisted from the ccr
+fn prune_non_whitelisted_backends(ccr: &mut ConcreteCore) {
+    ccr.backends.retain(|backend| {
+        WHITELISTED_BACKENDS
+            .iter()
+            .any(|whitelisted| backend.ident == *whitelisted)
+    });
+}
+
+/// Prunes the unavailable entities from the ccr (views and mut views)
+fn prune_unavailable_entities(ccr: &mut ConcreteCore) {
+    for backend in ccr.backends.iter_mut() {
+        backend
+            .entities
+            .retain(|entity| matches!(entity.definition.ownership, EntityOwnership::Owned))
+    }
+}
+
+/// Prunes the engine trait impls that take unavailable arguments, or return unavailable values.
+fn prune_unavailable_engine_impls(ccr: &mut ConcreteCore) {
+    for engine in ccr
+        .backends
+        .iter_mut()
+        .flat_map(|backend| backend.engines.iter_mut())
+    {
+        engine.engine_impls.retain(|impl_| {
+            let trait_available = !BLACKLISTED_ENGINE_TRAITS
+                .iter()
+                .any(|blacklisted| impl_.engine_trait_ident == *blacklisted);
+            let args_available = impl_.checked_method.args().iter().all(|arg| match arg {
+                EngineTraitImplArg::OwnedEntity(_, _) => true,
+                EngineTraitImplArg::OwnedEntityRef(_, _) => true,
+                EngineTraitImplArg::OwnedEntityRefMut(_, _) => true,
+                EngineTraitImplArg::ViewEntity(_, _) => false,
+                EngineTraitImplArg::ViewEntityRef(_, _) => false,
+                EngineTraitImplArg::MutViewEntity(_, _) => false,
+                EngineTraitImplArg::MutViewEntityRefMut(_, _) => false,
+                EngineTraitImplArg::Config(_, _) => false,
+                EngineTraitImplArg::ConfigRef(_, _, _) => false,
+                EngineTraitImplArg::ConfigSlice(_, _, _) => false,
+                EngineTraitImplArg::Parameter(_, type_) => {
+                    !is_one_of(type_, &UNAVAILABLE_PARAMETERS)
+                }
+                EngineTraitImplArg::Dispersion(_, _) => true,
+                EngineTraitImplArg::Numeric(_, _) => true,
+                EngineTraitImplArg::NumericRef(_, _, _) => true,
+                EngineTraitImplArg::NumericRefMut(_, _, _) => false,
+                EngineTraitImplArg::NumericSlice(_, _, num)
+                | EngineTraitImplArg::NumericSliceMut(_, _, num)
+                | EngineTraitImplArg::NumericVec(_, _, num) => !UNAVAILABLE_ARRAY_NUMERICS
+                    .iter()
+                    .any(|unavailable| num == unavailable),
+                EngineTraitImplArg::Unknown(_, _) => false,
+            });
+            let return_available = match impl_.checked_method.return_() {
+                EngineTraitImplReturn::OwnedEntity(_) => true,
+                EngineTraitImplReturn::ViewEntity(_) => false,
+                EngineTraitImplReturn::MutViewEntity(_) => false,
+                EngineTraitImplReturn::Config(_) => false,
+                EngineTraitImplReturn::Numeric(_) => true,
+                EngineTraitImplReturn::NumericSlice(_) => false,
+                EngineTraitImplReturn::NumericSliceMut(_) => false,
+                EngineTraitImplReturn::NumericVec(type_) => {
+                    !is_vec_of_one_of(type_, &UNAVAILABLE_ARRAY_NUMERICS)
+                }
+                EngineTraitImplReturn::Unit(_) => true,
+                EngineTraitImplReturn::Unknown(_) => false,
+            };
+            trait_available && args_available && return_available
+        });
+    }
+}
+
+/// Returns whether the type is a `Vec` whose element is one of the `idents`.
+fn is_vec_of_one_of(type_: &syn::Type, idents: &[&str]) -> bool {
+    let element = match type_ {
+        syn::Type::Path(path) => {
+            path.path
+                .segments
+                .last()
+                .and_then(|segment| match &segment.arguments {
+                    syn::PathArguments::AngleBracketed(args) => args.args.first(),
+                    _ => None,
+                })
+        }
+        _ => None,
+    };
+    match element {
+        Some(syn::GenericArgument::Type(type_)) => is_one_of(type_, idents),
+        _ => false,
+    }
+}
+
+/// Returns whether the type is a path whose last segment is one of the `idents`.
+fn is_one_of(type_: &syn::Type, idents: &[&str]) -> bool {
+    match type_ {
+        syn::Type::Path(path) => path
+            .path
+            .segments
+            .last()
+            .map(|segment| idents.iter().any(|ident| segment.ident == ident))
+            .unwrap_or(false),
+        _ => false,
+    }
+}
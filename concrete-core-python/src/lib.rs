@@ -0,0 +1,65 @@
+use concrete_core::specification::engines::*;
+use numpy::{PyArray1, PyReadonlyArray1, PyReadwriteArray1};
+use pyo3::create_exception;
+use pyo3::exceptions::{PyException, PyValueError};
+use pyo3::prelude::*;
+
+include!(concat!(env!("OUT_DIR"), "/__gen.rs"));
+
+create_exception!(
+    concrete_core_python,
+    EngineError,
+    PyException,
+    "Raised when a concrete-core engine operation fails."
+);
+
+#[pymodule]
+fn concrete_core_python(py: Python<'_>, module: &PyModule) -> PyResult<()> {
+    module.add("EngineError", py.get_type::<EngineError>())?;
+    register(module)
+}
+
+mod seeder {
+    use concrete_core::commons::math::random::Seed;
+    use concrete_core::prelude::Seeder;
+    use pyo3::prelude::*;
+
+    const SEED_BYTES_COUNT: usize = 16;
+
+    /// A seeder calling a python function returning 16 bytes.
+    pub struct PyFunctionSeeder {
+        py_func: PyObject,
+    }
+
+    impl PyFunctionSeeder {
+        pub fn new(py_func: PyObject) -> PyFunctionSeeder {
+            PyFunctionSeeder { py_func }
+        }
+    }
+
+    impl Seeder for PyFunctionSeeder {
+        fn seed(&mut self) -> Seed {
+            Python::with_gil(|py| {
+                let output = self
+                    .py_func
+                    .call0(py)
+                    .expect("The seeder function raised an exception.");
+                let bytes: &[u8] = output
+                    .extract(py)
+                    .expect("The seeder function must return a bytes object.");
+                let buffer: [u8; SEED_BYTES_COUNT] = bytes
+                    .try_into()
+                    .expect("The seeder function must return a bytes object of size 16.");
+                Seed(u128::from_le_bytes(buffer))
+            })
+        }
+
+        fn is_available() -> bool
+        where
+            Self: Sized,
+        {
+            true
+        }
+    }
+}
+pub use seeder::*;
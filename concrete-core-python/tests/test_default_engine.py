@@ -0,0 +1,81 @@
+import os
+
+import numpy as np
+import pytest
+
+from concrete_core_python import (
+    DefaultEngine,
+    DefaultSerializationEngine,
+    EngineError,
+    LweDimension,
+    Variance,
+)
+
+
+def seed():
+    return os.urandom(16)
+
+
+def test_create_cleartext_f64():
+    eng = DefaultEngine(seed)
+    val = 3.0
+    cleartext = eng.create_cleartext_from_f64_cleartext_f64(val)
+    raw = eng.retrieve_cleartext_cleartext_f64_f64(cleartext)
+    assert raw == val
+
+
+def test_create_cleartext_vector_f64():
+    eng = DefaultEngine(seed)
+    val = np.array([3.0, 2.0, 5.0])
+    cleartext_vector = eng.create_cleartext_vector_from_f64_cleartext_vector_f64(val)
+    raw = eng.retrieve_cleartext_vector_cleartext_vector_f64_f64(cleartext_vector)
+    np.testing.assert_array_equal(raw, val)
+
+
+def test_discard_retrieve_cleartext_vector_f64():
+    eng = DefaultEngine(seed)
+    val = np.array([3.0, 2.0, 5.0])
+    cleartext_vector = eng.create_cleartext_vector_from_f64_cleartext_vector_f64(val)
+    output = np.zeros(3)
+    eng.discard_retrieve_cleartext_vector_cleartext_vector_f64_f64(output, cleartext_vector)
+    np.testing.assert_array_equal(output, val)
+
+
+def test_encrypt_decrypt_lwe_ciphertext_u64():
+    eng = DefaultEngine(seed)
+    input = 3 << 50
+    noise = Variance(2.0 ** -25)
+    key = eng.generate_new_lwe_secret_key_lwe_secret_key64(LweDimension(2))
+    plaintext = eng.create_plaintext_from_u64_plaintext64(input)
+    ciphertext = eng.encrypt_lwe_ciphertext_lwe_secret_key64_plaintext64_lwe_ciphertext64(
+        key, plaintext, noise
+    )
+    decrypted = eng.decrypt_lwe_ciphertext_lwe_secret_key64_lwe_ciphertext64_plaintext64(
+        key, ciphertext
+    )
+    output = eng.retrieve_plaintext_plaintext64_u64(decrypted)
+    assert abs(output - input) < 1 << 45
+
+
+def test_serialize_lwe_secret_key_u64():
+    eng = DefaultEngine(seed)
+    ser_eng = DefaultSerializationEngine()
+    key = eng.generate_new_lwe_secret_key_lwe_secret_key64(LweDimension(2))
+    serialized = ser_eng.serialize_lwe_secret_key64_u8_vec(key)
+    assert serialized.dtype == np.uint8
+    recovered = ser_eng.deserialize_u8_slice_lwe_secret_key64(serialized)
+    assert (
+        ser_eng.serialize_lwe_secret_key64_u8_vec(recovered).tobytes()
+        == serialized.tobytes()
+    )
+
+
+def test_engine_error_is_raised():
+    eng = DefaultEngine(seed)
+    with pytest.raises(EngineError):
+        eng.create_cleartext_vector_from_f64_cleartext_vector_f64(np.array([]))
+
+
+def test_parameter_value():
+    assert LweDimension(630).value == 630
+    assert Variance(0.5).value == 0.5
@@ -0,0 +1,17 @@
+use concrete_core_representation::{dump_capabilities_to_files, load_ccr};
+
+mod root;
+
+fn main() {
+    let json_path = std::env::args()
+        .nth(1)
+        .unwrap_or_else(|| String::from("/tmp/ccr_capabilities.json"));
+    let markdown_path = std::env::args()
+        .nth(2)
+        .unwrap_or_else(|| String::from("/tmp/ccr_capabilities.md"));
+    dump_capabilities_to_files(
+        json_path.as_str(),
+        markdown_path.as_str(),
+        load_ccr(root::get_concrete_core_root()),
+    );
+}
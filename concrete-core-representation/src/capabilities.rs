@@ -0,0 +1,500 @@
+//! A module containing a matrix of the engine traits implemented by the backends.
+use crate::{ConcreteCore, EngineTraitImpl, EngineTraitImplGenericArgument};
+use quote::ToTokens;
+use serde::{Deserialize, Serialize};
+use std::borrow::Borrow;
+use std::collections::BTreeSet;
+use std::fs::File;
+use std::io::Write;
+use std::path::Path;
+
+/// A matrix listing which backend implements which `*Engine` trait, for which entity types.
+///
+/// The matrix is built from the `ccr` with [`CapabilityMatrix::from_ccr`], and can be saved to and
+/// loaded back from json, so that tools can query it without parsing the `concrete-core` sources.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct CapabilityMatrix {
+    /// The names of all the backends, in the order of the sources.
+    pub backends: Vec<String>,
+    /// The capabilities, one per engine trait impl.
+    pub capabilities: Vec<Capability>,
+}
+
+/// An implementation of an `*Engine` trait by an engine type of a backend.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct Capability {
+    /// The name of the engine trait, e.g. `LweCiphertextDiscardingKeyswitchEngine`.
+    pub engine_trait: String,
+    /// The name of the backend, e.g. `default`.
+    pub backend: String,
+    /// The name of the engine type, e.g. `DefaultEngine`.
+    pub engine_type: String,
+    /// The generic arguments of the engine trait, e.g. `LweKeyswitchKey64`. The lifetimes are
+    /// omitted.
+    pub generic_arguments: Vec<String>,
+    /// The scalar types of the entities, e.g. `u64`.
+    pub scalar_types: Vec<String>,
+    /// The predicates of the `cfg` attributes the implementation is compiled under, e.g.
+    /// `feature = "backend_fft"`. The implementation is compiled when all of them hold.
+    pub cfg_predicates: Vec<String>,
+}
+
+impl Capability {
+    fn from_engine_impl(backend: &str, engine_impl: &EngineTraitImpl) -> Capability {
+        let generic_arguments: Vec<String> = engine_impl
+            .engine_trait_parameters()
+            .iter()
+            .map(|arg| type_name(arg.get_type()))
+            .collect();
+        let parameters = engine_impl
+            .engine_trait_parameters()
+            .iter()
+            .zip(generic_arguments.iter());
+        // The numeric arguments only give the scalar type when there is no entity argument, since
+        // they can also be buffers of bytes, e.g. for serialization.
+        let scalar_types: BTreeSet<String> = if parameters.clone().any(|(arg, _)| is_entity(arg)) {
+            parameters
+                .filter_map(|(arg, name)| entity_scalar_type(arg, name))
+                .collect()
+        } else {
+            parameters
+                .filter_map(|(arg, name)| numeric_scalar_type(arg, name))
+                .collect()
+        };
+        let scalar_types = scalar_types.into_iter().collect();
+        Capability {
+            engine_trait: engine_impl.engine_trait_ident.to_string(),
+            backend: backend.to_owned(),
+            engine_type: engine_impl.engine_type_ident.to_string(),
+            generic_arguments,
+            scalar_types,
+            cfg_predicates: engine_impl.cfg.predicates(),
+        }
+    }
+}
+
+impl CapabilityMatrix {
+    /// Builds the matrix from the input `ccr`.
+    pub fn from_ccr<CC: Borrow<ConcreteCore>>(ccr: CC) -> CapabilityMatrix {
+        let ccr = ccr.borrow();
+        let backends = ccr
+            .backends
+            .iter()
+            .map(|backend| backend.ident.to_string())
+            .collect();
+        let capabilities = ccr
+            .backends
+            .iter()
+            .flat_map(|backend| {
+                let backend_name = backend.ident.to_string();
+                backend
+                    .engines
+                    .iter()
+                    .flat_map(|engine| engine.engine_impls.iter())
+                    .map(move |engine_impl| {
+                        Capability::from_engine_impl(backend_name.as_str(), engine_impl)
+                    })
+                    .collect::<Vec<_>>()
+            })
+            .collect();
+        CapabilityMatrix {
+            backends,
+            capabilities,
+        }
+    }
+
+    /// Loads a matrix from its json representation.
+    pub fn from_json(json: &str) -> serde_json::Result<CapabilityMatrix> {
+        serde_json::from_str(json)
+    }
+
+    /// Returns the json representation of the matrix.
+    pub fn to_json(&self) -> String {
+        serde_json::to_string_pretty(self).unwrap()
+    }
+
+    /// Returns the names of all the engine traits implemented by at least one backend, sorted.
+    pub fn engine_traits(&self) -> Vec<&str> {
+        self.capabilities
+            .iter()
+            .map(|cap| cap.engine_trait.as_str())
+            .collect::<BTreeSet<_>>()
+            .into_iter()
+            .collect()
+    }
+
+    /// Returns the capabilities matching an engine trait.
+    pub fn capabilities_of<'a>(
+        &'a self,
+        engine_trait: &'a str,
+    ) -> impl Iterator<Item = &'a Capability> + 'a {
+        self.capabilities
+            .iter()
+            .filter(move |cap| cap.engine_trait == engine_trait)
+    }
+
+    /// Returns whether `backend` implements `engine_trait` for the `scalar_type` (e.g. `u64`).
+    pub fn implements(&self, backend: &str, engine_trait: &str, scalar_type: &str) -> bool {
+        self.capabilities_of(engine_trait).any(|cap| {
+            cap.backend == backend && cap.scalar_types.iter().any(|st| st == scalar_type)
+        })
+    }
+
+    /// Returns the backends implementing `engine_trait` for the `scalar_type`, in the order of the
+    /// sources.
+    pub fn backends_implementing(&self, engine_trait: &str, scalar_type: &str) -> Vec<&str> {
+        self.backends
+            .iter()
+            .map(String::as_str)
+            .filter(|backend| self.implements(backend, engine_trait, scalar_type))
+            .collect()
+    }
+
+    /// Returns the first backend of `preferred_backends` implementing `engine_trait` with exactly
+    /// the `generic_arguments` (e.g. `["LweCiphertext64", "LweCiphertext64"]`), if any.
+    pub fn select_backend<'a>(
+        &'a self,
+        engine_trait: &str,
+        generic_arguments: &[&str],
+        preferred_backends: &[&str],
+    ) -> Option<&'a Capability> {
+        preferred_backends.iter().find_map(|backend| {
+            self.capabilities.iter().find(|cap| {
+                cap.engine_trait == engine_trait
+                    && cap.backend == *backend
+                    && cap
+                        .generic_arguments
+                        .iter()
+                        .map(String::as_str)
+                        .eq(generic_arguments.iter().copied())
+            })
+        })
+    }
+
+    /// Returns a markdown representation of the matrix.
+    ///
+    /// The first table lists the scalar types supported by each backend for each engine trait (or
+    /// `yes` when the implementations have no scalar type). It is followed by a table of the
+    /// implementations of each engine trait.
+    pub fn to_markdown(&self) -> String {
+        let mut output = String::new();
+        output.push_str("# Capability matrix\n\n");
+        output.push_str("| Engine trait |");
+        for backend in self.backends.iter() {
+            output.push_str(format!(" {} |", backend).as_str());
+        }
+        output.push_str("\n|---|");
+        output.push_str("---|".repeat(self.backends.len()).as_str());
+        output.push('\n');
+        let engine_traits = self.engine_traits();
+        for engine_trait in engine_traits.iter() {
+            output.push_str(
+                format!(
+                    "| [`{0}`](#{1}) |",
+                    engine_trait,
+                    engine_trait.to_lowercase()
+                )
+                .as_str(),
+            );
+            for backend in self.backends.iter() {
+                let mut capabilities = self
+                    .capabilities_of(engine_trait)
+                    .filter(|cap| cap.backend == *backend)
+                    .peekable();
+                if capabilities.peek().is_none() {
+                    output.push_str("  |");
+                    continue;
+                }
+                let scalar_types = capabilities
+                    .flat_map(|cap| cap.scalar_types.iter().map(String::as_str))
+                    .collect::<BTreeSet<_>>()
+                    .into_iter()
+                    .collect::<Vec<_>>()
+                    .join(", ");
+                if scalar_types.is_empty() {
+                    output.push_str(" yes |");
+                } else {
+                    output.push_str(format!(" {} |", scalar_types).as_str());
+                }
+            }
+            output.push('\n');
+        }
+        for engine_trait in engine_traits.iter() {
+            output.push_str(format!("\n## {}\n\n", engine_trait).as_str());
+            output.push_str("| Backend | Engine | Generic arguments | Cfg predicates |\n");
+            output.push_str("|---|---|---|---|\n");
+            for cap in self.capabilities_of(engine_trait) {
+                output.push_str(
+                    format!(
+                        "| {} | `{}` | {} | {} |\n",
+                        cap.backend,
+                        cap.engine_type,
+                        cap.generic_arguments
+                            .iter()
+                            .map(|arg| format!("`{}`", arg))
+                            .collect::<Vec<_>>()
+                            .join(", "),
+                        cap.cfg_predicates
+                            .iter()
+                            .map(|predicate| format!("`{}`", predicate))
+                            .collect::<Vec<_>>()
+                            .join(", "),
+                    )
+                    .as_str(),
+                );
+            }
+        }
+        output
+    }
+}
+
+/// Dumps the capability matrix of the `ccr` to a json file and a markdown file.
+pub fn dump_capabilities_to_files<P: AsRef<Path>, CC: Borrow<ConcreteCore>>(
+    json_path: P,
+    markdown_path: P,
+    ccr: CC,
+) {
+    let matrix = CapabilityMatrix::from_ccr(ccr);
+    let mut file = File::create(json_path.as_ref()).unwrap();
+    file.write_all(matrix.to_json().as_bytes()).unwrap();
+    let mut file = File::create(markdown_path.as_ref()).unwrap();
+    file.write_all(matrix.to_markdown().as_bytes()).unwrap();
+}
+
+/// Returns the name of a type, using only the last segment of paths, and omitting lifetimes.
+fn type_name(type_: &syn::Type) -> String {
+    match type_ {
+        syn::Type::Path(type_path) => type_path
+            .path
+            .segments
+            .last()
+            .map(|segment| segment.ident.to_string())
+            .unwrap_or_default(),
+        _ => type_.to_token_stream().to_string().replace(' ', ""),
+    }
+}
+
+/// Returns whether a generic argument is an entity.
+fn is_entity(arg: &EngineTraitImplGenericArgument) -> bool {
+    matches!(
+        arg,
+        EngineTraitImplGenericArgument::OwnedEntity(_)
+            | EngineTraitImplGenericArgument::ViewEntity(_)
+            | EngineTraitImplGenericArgument::MutViewEntity(_)
+    )
+}
+
+/// Returns the scalar type of an entity generic argument, if any.
+fn entity_scalar_type(arg: &EngineTraitImplGenericArgument, name: &str) -> Option<String> {
+    match arg {
+        _ if is_entity(arg) => {
+            // INVARIANT: The entity types are suffixed with the bit width of their scalar type,
+            // preceded by an `F` for floating point scalars. The float encoders are the exception,
+            // and always encode `f64` messages.
+            let prefix = name.trim_end_matches(|c: char| c.is_ascii_digit());
+            let width = &name[prefix.len()..];
+            if width.is_empty() {
+                name.starts_with("FloatEncoder")
+                    .then(|| String::from("f64"))
+            } else if prefix.ends_with('F') {
+                Some(format!("f{}", width))
+            } else {
+                Some(format!("u{}", width))
+            }
+        }
+        _ => None,
+    }
+}
+
+/// Returns the scalar type of a numeric generic argument, if any.
+fn numeric_scalar_type(arg: &EngineTraitImplGenericArgument, name: &str) -> Option<String> {
+    match arg {
+        EngineTraitImplGenericArgument::Numeric(_)
+        | EngineTraitImplGenericArgument::NumericSlice(_)
+        | EngineTraitImplGenericArgument::NumericSliceMut(_)
+        | EngineTraitImplGenericArgument::NumericVec(_) => name
+            .split(|c: char| !c.is_ascii_alphanumeric())
+            .find(|word| {
+                word.len() > 1
+                    && word.starts_with(['u', 'i', 'f'])
+                    && word[1..].chars().all(|c| c.is_ascii_digit())
+            })
+            .map(str::to_owned),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::CfgStack;
+    use syn::parse_quote;
+
+    fn capability(backend: &str, generic_arguments: &[&str], scalar_type: &str) -> Capability {
+        Capability {
+            engine_trait: String::from("LweCiphertextDiscardingKeyswitchEngine"),
+            backend: backend.to_owned(),
+            engine_type: format!("{}Engine", backend),
+            generic_arguments: generic_arguments
+                .iter()
+                .map(|arg| arg.to_string())
+                .collect(),
+            scalar_types: vec![scalar_type.to_owned()],
+            cfg_predicates: vec![format!("feature = \"backend_{}\"", backend)],
+        }
+    }
+
+    fn matrix() -> CapabilityMatrix {
+        CapabilityMatrix {
+            backends: vec![
+                String::from("default"),
+                String::from("fft"),
+                String::from("cuda"),
+            ],
+            capabilities: vec![
+                capability(
+                    "default",
+                    &["LweKeyswitchKey32", "LweCiphertext32", "LweCiphertext32"],
+                    "u32",
+                ),
+                capability(
+                    "default",
+                    &["LweKeyswitchKey64", "LweCiphertext64", "LweCiphertext64"],
+                    "u64",
+                ),
+                capability(
+                    "cuda",
+                    &[
+                        "CudaLweKeyswitchKey64",
+                        "CudaLweCiphertext64",
+                        "CudaLweCiphertext64",
+                    ],
+                    "u64",
+                ),
+            ],
+        }
+    }
+
+    #[test]
+    fn test_entity_scalar_type() {
+        let entity = |type_: syn::Type| EngineTraitImplGenericArgument::OwnedEntity(type_);
+        let scalar_type = |arg: EngineTraitImplGenericArgument| {
+            entity_scalar_type(&arg, type_name(arg.get_type()).as_str())
+        };
+        assert_eq!(
+            scalar_type(entity(parse_quote!(LweCiphertext64))),
+            Some(String::from("u64"))
+        );
+        assert_eq!(
+            scalar_type(entity(parse_quote!(Cleartext32))),
+            Some(String::from("u32"))
+        );
+        assert_eq!(
+            scalar_type(entity(parse_quote!(CleartextF64))),
+            Some(String::from("f64"))
+        );
+        assert_eq!(
+            scalar_type(entity(parse_quote!(FloatEncoder))),
+            Some(String::from("f64"))
+        );
+        assert_eq!(
+            scalar_type(EngineTraitImplGenericArgument::ViewEntity(parse_quote!(
+                LweCiphertextView64<'a>
+            ))),
+            Some(String::from("u64"))
+        );
+        assert_eq!(
+            scalar_type(EngineTraitImplGenericArgument::Numeric(parse_quote!(u64))),
+            None
+        );
+    }
+
+    #[test]
+    fn test_numeric_scalar_type() {
+        let scalar_type = |arg: EngineTraitImplGenericArgument| {
+            numeric_scalar_type(&arg, arg.get_type().to_token_stream().to_string().as_str())
+        };
+        assert_eq!(
+            scalar_type(EngineTraitImplGenericArgument::Numeric(parse_quote!(u32))),
+            Some(String::from("u32"))
+        );
+        assert_eq!(
+            scalar_type(EngineTraitImplGenericArgument::NumericSlice(parse_quote!(
+                &'a [f64]
+            ))),
+            Some(String::from("f64"))
+        );
+        assert_eq!(
+            scalar_type(EngineTraitImplGenericArgument::NumericVec(parse_quote!(
+                Vec<i8>
+            ))),
+            Some(String::from("i8"))
+        );
+        assert_eq!(
+            scalar_type(EngineTraitImplGenericArgument::OwnedEntity(parse_quote!(
+                LweCiphertext64
+            ))),
+            None
+        );
+    }
+
+    #[test]
+    fn test_select_backend() {
+        let matrix = matrix();
+        let generic_arguments = ["LweKeyswitchKey64", "LweCiphertext64", "LweCiphertext64"];
+        let selected = matrix
+            .select_backend(
+                "LweCiphertextDiscardingKeyswitchEngine",
+                &generic_arguments,
+                &["cuda", "default"],
+            )
+            .unwrap();
+        assert_eq!(selected.backend, "default");
+        assert_eq!(selected.generic_arguments, generic_arguments);
+        assert!(matrix
+            .select_backend(
+                "LweCiphertextDiscardingKeyswitchEngine",
+                &generic_arguments,
+                &["cuda", "fft"],
+            )
+            .is_none());
+        assert!(matrix
+            .select_backend(
+                "LweCiphertextDiscardingBootstrapEngine",
+                &generic_arguments,
+                &["default"],
+            )
+            .is_none());
+        assert_eq!(
+            matrix.backends_implementing("LweCiphertextDiscardingKeyswitchEngine", "u64"),
+            vec!["default", "cuda"]
+        );
+    }
+
+    #[test]
+    fn test_json_roundtrip() {
+        let matrix = matrix();
+        assert_eq!(
+            CapabilityMatrix::from_json(matrix.to_json().as_str()).unwrap(),
+            matrix
+        );
+    }
+
+    #[test]
+    fn test_cfg_predicates() {
+        let attrs: Vec<syn::Attribute> = vec![
+            parse_quote!(#[cfg(feature = "backend_fft")]),
+            parse_quote!(#[cfg(all(unix, not(feature = "backend_cuda")))]),
+            parse_quote!(#[cfg(any(feature = "x86_64", target_arch = "aarch64"))]),
+            parse_quote!(#[doc = "not a cfg"]),
+        ];
+        assert_eq!(
+            CfgStack::from_attr(&attrs).predicates(),
+            vec![
+                "feature = \"backend_fft\"",
+                "all(unix, not(feature = \"backend_cuda\"))",
+                "any(feature = \"x86_64\", target_arch = \"aarch64\")",
+            ]
+        );
+    }
+}
@@ -1,4 +1,6 @@
 use super::*;
+use proc_macro2::{Delimiter, TokenTree};
+use quote::{quote, ToTokens};
 use serde::Serialize;
 
 /// A node representing a stack of `cfg` attributes.
@@ -43,4 +45,57 @@ impl CfgStack {
         output.push_attrs(attrs);
         output
     }
+
+    /// Returns the predicates of the `cfg` attributes of the stack, e.g. `feature = "backend_fft"`
+    /// for a `#[cfg(feature = "backend_fft")]` attribute, or `all(unix, not(feature = "x"))`.
+    ///
+    /// The item is compiled when all the predicates hold.
+    pub fn predicates(&self) -> Vec<String> {
+        let mut output = Vec::new();
+        for ts in self.0.iter() {
+            let predicate = match ts.clone().into_iter().collect::<Vec<_>>().as_slice() {
+                [TokenTree::Group(group)] if group.delimiter() == Delimiter::Parenthesis => {
+                    predicate_to_string(group.stream())
+                }
+                _ => predicate_to_string(ts.clone()),
+            };
+            if !output.contains(&predicate) {
+                output.push(predicate);
+            }
+        }
+        output
+    }
+}
+
+/// Renders a `cfg` predicate the way it is usually written, e.g. `any(unix, feature = "x")`.
+fn predicate_to_string(ts: TokenStream2) -> String {
+    let mut output = String::new();
+    for token in ts {
+        match token {
+            TokenTree::Group(group) => {
+                let (open, close) = match group.delimiter() {
+                    Delimiter::Parenthesis => ("(", ")"),
+                    Delimiter::Bracket => ("[", "]"),
+                    Delimiter::Brace => ("{", "}"),
+                    Delimiter::None => ("", ""),
+                };
+                output.push_str(open);
+                output.push_str(predicate_to_string(group.stream()).as_str());
+                output.push_str(close);
+            }
+            TokenTree::Punct(punct) if punct.as_char() == ',' => output.push_str(", "),
+            TokenTree::Punct(punct) if punct.as_char() == '=' => output.push_str(" = "),
+            token => output.push_str(token.to_string().as_str()),
+        }
+    }
+    output
+}
+
+impl ToTokens for CfgStack {
+    /// Outputs the stack as a sequence of `#[cfg(...)]` attributes.
+    fn to_tokens(&self, tokens: &mut TokenStream2) {
+        for cfg in self.0.iter() {
+            tokens.extend(quote!(#[cfg #cfg]));
+        }
+    }
 }
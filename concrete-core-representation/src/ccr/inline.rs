@@ -7,13 +7,14 @@ use syn::token::Brace;
 
 pub(crate) fn read_crate<P: AsRef<Path>>(path: P) -> syn::File {
     read_file_and_inline_modules(path, |path| {
-        let forbidden_files = ["commons", "private"];
-        for f in forbidden_files.iter() {
-            if path.as_os_str().to_str().unwrap().contains(f) {
-                return false;
-            }
-        }
-        true
+        // We compare whole path components, so that files such as
+        // `lwe_private_functional_packing_keyswitch_key.rs` are not filtered out.
+        let forbidden_modules = ["commons", "private"];
+        !path.components().any(|component| {
+            forbidden_modules.iter().any(|f| {
+                component.as_os_str() == *f || component.as_os_str() == format!("{}.rs", f).as_str()
+            })
+        })
     })
 }
 
@@ -19,8 +19,16 @@
 //!     + entity type definition = A `pub struct *{...}` definition of a type that implements an
 //!       `*Entity` trait. In a backend.
 
+mod capabilities;
+pub use capabilities::*;
+
 mod ccr;
 pub use ccr::*;
 
 mod misc;
 pub use misc::*;
+
+// The code snippets of the readme are compiled as doctests.
+#[cfg(doctest)]
+#[doc = include_str!("../README.md")]
+pub struct ReadmeDoctests;
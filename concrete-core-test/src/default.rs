@@ -200,5 +200,15 @@ macro_rules! test_parallel {
 
 #[cfg(feature = "backend_default_parallel")]
 test_parallel! {
-    ((BinaryKeyDistribution), LweCiphertextVectorZeroEncryptionFixture, (LweSecretKey, LweCiphertextVector))
+    ((BinaryKeyDistribution), LweCiphertextVectorZeroEncryptionFixture, (LweSecretKey, LweCiphertextVector)),
+    ((BinaryKeyDistribution), LweCiphertextVectorEncryptionFixture, (PlaintextVector, LweSecretKey, LweCiphertextVector)),
+    ((BinaryKeyDistribution), LweCiphertextVectorDecryptionFixture, (PlaintextVector, LweSecretKey, LweCiphertextVector)),
+    ((BinaryKeyDistribution), LweCiphertextVectorDiscardingEncryptionFixture, (PlaintextVector, LweSecretKey,
+        LweCiphertextVector)),
+    ((BinaryKeyDistribution), LweCiphertextVectorDiscardingDecryptionFixture, (PlaintextVector, LweSecretKey,
+        LweCiphertextVector)),
+    ((BinaryKeyDistribution), LweCiphertextVectorDiscardingAffineTransformationFixture, (LweCiphertextVector, CleartextVector, Plaintext, LweCiphertext)),
+    ((BinaryKeyDistribution, BinaryKeyDistribution), LweCiphertextVectorDiscardingKeyswitchFixture, (LweKeyswitchKey, LweCiphertextVector,
+        LweCiphertextVector)),
+    ((BinaryKeyDistribution, BinaryKeyDistribution), LweCiphertextVectorGlweCiphertextDiscardingPackingKeyswitchFixture, (LweCiphertextVector, LwePackingKeyswitchKey, GlweCiphertext))
 }
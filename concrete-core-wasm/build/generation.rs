@@ -27,11 +27,30 @@ fn generate_all_entities(ccr: &ConcreteCore) -> TokenStream2 {
 
 fn generate_entity_definition(entity: &Entity) -> TokenStream2 {
     // INVARIANT: entities are available in the `concrete_core::prelude` module
-    // INVARIANT: views are not exported
+    // INVARIANT: the only generic parameters of views are lifetimes
     let ident = entity.definition.item_struct.ident.clone();
-    quote! {
-        #[wasm_bindgen]
-        pub struct #ident(pub(crate) concrete_core::prelude::#ident);
+    match entity.definition.ownership {
+        EntityOwnership::Owned => quote! {
+            #[wasm_bindgen]
+            pub struct #ident(pub(crate) concrete_core::prelude::#ident);
+        },
+        EntityOwnership::View | EntityOwnership::MutView => {
+            // Views borrow a buffer owned by the second field. Fields are dropped in declaration
+            // order, so the view never outlives its buffer.
+            let lifetimes = entity
+                .definition
+                .item_struct
+                .generics
+                .lifetimes()
+                .map(|_| quote!('static));
+            quote! {
+                #[wasm_bindgen]
+                pub struct #ident(
+                    pub(crate) concrete_core::prelude::#ident<#(#lifetimes),*>,
+                    pub(crate) Box<dyn std::any::Any>,
+                );
+            }
+        }
     }
 }
 
@@ -290,6 +309,16 @@ fn generate_engine_method_return_map(engine_impl: &EngineTraitImpl) -> TokenStre
         EngineTraitImplReturn::OwnedEntity(v) => {
             quote!(.map(#v))
         }
+        EngineTraitImplReturn::ViewEntity(v) | EngineTraitImplReturn::MutViewEntity(v) => {
+            let ident = type_ident(v);
+            let owner = buffer_owner_ident(engine_impl);
+            quote!(.map(|view| #ident(view, #owner)))
+        }
+        EngineTraitImplReturn::NumericSlice(v) | EngineTraitImplReturn::NumericSliceMut(v) => {
+            let num = slice_element_type(v);
+            let owner = buffer_owner_ident(engine_impl);
+            quote!(.map(|_| crate::ViewBuffer::<#num>::reclaim(#owner)))
+        }
         EngineTraitImplReturn::Numeric(_) => {
             quote!()
         }
@@ -308,19 +337,52 @@ fn generate_engine_method_return_map(engine_impl: &EngineTraitImpl) -> TokenStre
 
 fn generate_engine_method_body(engine_impl: &EngineTraitImpl) -> TokenStream2 {
     let mut blocks = TokenStream2::new();
-    engine_impl.checked_method.args().iter().for_each(|arg| {
-        if let EngineTraitImplArg::ConfigSlice(pat, _, config_ident) = arg {
-            let block = quote! {
-                let #pat = #pat
-                    .iter()
-                    .map(|jsval| serde_wasm_bindgen::from_value(jsval.to_owned()))
-                    .collect::<Result<Vec<#config_ident>, _>>()
-                    .map_err(|e| wasm_bindgen::JsError::new(format!("{}", e).as_str()))?;
-                let #pat = #pat.into_iter().map(|t| t.to_concrete_core_type()).collect::<Vec<_>>();
-            };
-            blocks.extend(block)
-        }
-    });
+    engine_impl
+        .checked_method
+        .args()
+        .iter()
+        .for_each(|arg| match arg {
+            EngineTraitImplArg::ConfigSlice(pat, _, config_ident) => {
+                let block = quote! {
+                    let #pat = #pat
+                        .iter()
+                        .map(|jsval| serde_wasm_bindgen::from_value(jsval.to_owned()))
+                        .collect::<Result<Vec<#config_ident>, _>>()
+                        .map_err(|e| wasm_bindgen::JsError::new(format!("{}", e).as_str()))?;
+                    let #pat = #pat.into_iter().map(|t| t.to_concrete_core_type()).collect::<Vec<_>>();
+                };
+                blocks.extend(block)
+            }
+            EngineTraitImplArg::NumericSlice(pat, _, _) if returns_view(engine_impl) => {
+                let owner = buffer_owner_ident(engine_impl);
+                blocks.extend(quote! {
+                    let (#owner, #pat) = crate::ViewBuffer::leak(#pat);
+                    let #pat = &*#pat;
+                })
+            }
+            EngineTraitImplArg::NumericSliceMut(pat, _, _) if returns_view(engine_impl) => {
+                let owner = buffer_owner_ident(engine_impl);
+                blocks.extend(quote! {
+                    let (#owner, #pat) = crate::ViewBuffer::leak(#pat);
+                })
+            }
+            EngineTraitImplArg::ViewEntity(pat, _) | EngineTraitImplArg::MutViewEntity(pat, _) => {
+                let owner = buffer_owner_ident(engine_impl);
+                blocks.extend(quote! {
+                    let (#pat, #owner) = (#pat.0, #pat.1);
+                })
+            }
+            EngineTraitImplArg::NumericRefMut(pat, _, _) => {
+                let message = format!("`{}` must be an array of length 1.", pat.to_token_stream());
+                blocks.extend(quote! {
+                    if #pat.len() != 1 {
+                        return Err(wasm_bindgen::JsError::new(#message));
+                    }
+                    let #pat = &mut #pat[0];
+                })
+            }
+            _ => {}
+        });
 
     let mut args = TokenStream2::new();
     engine_impl
@@ -331,6 +393,10 @@ fn generate_engine_method_body(engine_impl: &EngineTraitImpl) -> TokenStream2 {
             EngineTraitImplArg::OwnedEntity(pat, _) => args.extend(quote!(#pat.0,)),
             EngineTraitImplArg::OwnedEntityRef(pat, _) => args.extend(quote!(& #pat.0,)),
             EngineTraitImplArg::OwnedEntityRefMut(pat, _) => args.extend(quote!(&mut #pat.0,)),
+            EngineTraitImplArg::ViewEntity(pat, _) => args.extend(quote!(#pat,)),
+            EngineTraitImplArg::ViewEntityRef(pat, _) => args.extend(quote!(& #pat.0,)),
+            EngineTraitImplArg::MutViewEntity(pat, _) => args.extend(quote!(#pat,)),
+            EngineTraitImplArg::MutViewEntityRefMut(pat, _) => args.extend(quote!(&mut #pat.0,)),
             EngineTraitImplArg::Config(pat, _) => {
                 args.extend(quote!(#pat.to_concrete_core_type(),))
             }
@@ -344,6 +410,7 @@ fn generate_engine_method_body(engine_impl: &EngineTraitImpl) -> TokenStream2 {
             EngineTraitImplArg::Dispersion(pat, _) => args.extend(quote!(#pat.0,)),
             EngineTraitImplArg::Numeric(pat, _) => args.extend(quote!(#pat,)),
             EngineTraitImplArg::NumericRef(pat, _, _) => args.extend(quote!(& #pat,)),
+            EngineTraitImplArg::NumericRefMut(pat, _, _) => args.extend(quote!(#pat,)),
             EngineTraitImplArg::NumericSlice(pat, _, _) => args.extend(quote!(#pat,)),
             EngineTraitImplArg::NumericSliceMut(pat, _, _) => args.extend(quote!(#pat,)),
             EngineTraitImplArg::NumericVec(pat, _, _) => args.extend(quote!(#pat,)),
@@ -359,8 +426,20 @@ fn generate_engine_method_body(engine_impl: &EngineTraitImpl) -> TokenStream2 {
 }
 
 fn generate_engine_method_return_type(engine_impl: &EngineTraitImpl) -> TokenStream2 {
-    let return_type = engine_impl.checked_method.return_().type_();
-    quote!(#return_type)
+    match engine_impl.checked_method.return_() {
+        EngineTraitImplReturn::ViewEntity(type_) | EngineTraitImplReturn::MutViewEntity(type_) => {
+            type_ident(type_).to_token_stream()
+        }
+        EngineTraitImplReturn::NumericSlice(type_)
+        | EngineTraitImplReturn::NumericSliceMut(type_) => {
+            let num = slice_element_type(type_);
+            quote!(Box<[#num]>)
+        }
+        return_ => {
+            let return_type = return_.type_();
+            quote!(#return_type)
+        }
+    }
 }
 
 fn generate_engine_method_args(engine_impl: &EngineTraitImpl) -> TokenStream2 {
@@ -376,6 +455,28 @@ fn generate_engine_method_args(engine_impl: &EngineTraitImpl) -> TokenStream2 {
             EngineTraitImplArg::ConfigSlice(pat_ident, _, _) => {
                 output.extend(quote! {#pat_ident: Box<[JsValue]>,});
             }
+            EngineTraitImplArg::ViewEntity(pat_ident, type_)
+            | EngineTraitImplArg::MutViewEntity(pat_ident, type_) => {
+                let ident = type_ident(type_);
+                output.extend(quote! {#pat_ident: #ident,});
+            }
+            EngineTraitImplArg::ViewEntityRef(pat_ident, type_) => {
+                let ident = type_ident(type_);
+                output.extend(quote! {#pat_ident: &#ident,});
+            }
+            EngineTraitImplArg::MutViewEntityRefMut(pat_ident, type_) => {
+                let ident = type_ident(type_);
+                output.extend(quote! {#pat_ident: &mut #ident,});
+            }
+            EngineTraitImplArg::NumericRefMut(pat_ident, _, num_ident) => {
+                output.extend(quote! {#pat_ident: &mut [#num_ident],});
+            }
+            EngineTraitImplArg::NumericSlice(pat_ident, _, num_ident)
+            | EngineTraitImplArg::NumericSliceMut(pat_ident, _, num_ident)
+                if returns_view(engine_impl) =>
+            {
+                output.extend(quote! {#pat_ident: Box<[#num_ident]>,});
+            }
             arg => {
                 let pat_ident = arg.pat_ident();
                 let type_ = arg.type_();
@@ -399,6 +500,66 @@ fn generate_engine_method_ident(engine_impl: &EngineTraitImpl) -> TokenStream2 {
     Ident::new(output.as_str(), Span::call_site()).to_token_stream()
 }
 
+/// Returns whether the engine method creates a view over the numeric slice it takes as argument.
+fn returns_view(engine_impl: &EngineTraitImpl) -> bool {
+    matches!(
+        engine_impl.checked_method.return_(),
+        EngineTraitImplReturn::ViewEntity(_) | EngineTraitImplReturn::MutViewEntity(_)
+    )
+}
+
+/// Returns the ident of the owner of the buffer borrowed by a view, when an engine method creates
+/// or consumes a view.
+fn buffer_owner_ident(engine_impl: &EngineTraitImpl) -> Ident {
+    // INVARIANT: engine methods create or consume at most a single view.
+    let pat = engine_impl
+        .checked_method
+        .args()
+        .iter()
+        .find_map(|arg| match arg {
+            EngineTraitImplArg::NumericSlice(pat, _, _)
+            | EngineTraitImplArg::NumericSliceMut(pat, _, _)
+                if returns_view(engine_impl) =>
+            {
+                Some(pat.to_token_stream())
+            }
+            EngineTraitImplArg::ViewEntity(pat, _) | EngineTraitImplArg::MutViewEntity(pat, _) => {
+                Some(pat.to_token_stream())
+            }
+            _ => None,
+        })
+        .expect("Failed to find the buffer borrowed by the view.");
+    format_ident!("{}_buffer_owner", pat.to_string())
+}
+
+/// Returns the ident of the last segment of a (possibly referenced) type path.
+fn type_ident(type_: &Type) -> Ident {
+    probe!(
+        Some(strip_references(type_)),
+        syn::Type::Path(p) => p,
+        p >> p.path.segments.last(),
+        s -> s.ident.clone()
+    )
+    .unwrap_or_else(|| panic!("Failed to extract ident of type: {:?}", type_))
+}
+
+fn strip_references(type_: &Type) -> &Type {
+    match type_ {
+        syn::Type::Reference(r) => strip_references(r.elem.as_ref()),
+        t => t,
+    }
+}
+
+/// Returns the element type of a (possibly mutable) slice reference type.
+fn slice_element_type(type_: &Type) -> Type {
+    probe!(
+        Some(strip_references(type_)),
+        syn::Type::Slice(s) => s,
+        s -> s.elem.as_ref().clone()
+    )
+    .unwrap_or_else(|| panic!("Failed to extract element type of: {:?}", type_))
+}
+
 trait ToNameFragment {
     fn to_fragment(&self) -> String;
 }
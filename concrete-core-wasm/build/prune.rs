@@ -1,16 +1,17 @@
 //! This module contains a function that prunes the ccr to only keep the elements that we want to
 //! expose in the wasm api.
-use concrete_core_representation::{
-    ConcreteCore, EngineTraitImplArg, EngineTraitImplReturn, EntityOwnership,
-};
+use concrete_core_representation::{ConcreteCore, EngineTraitImplArg, EngineTraitImplReturn};
 
-const BLACKLISTED_BACKENDS: [&str; 1] = ["cuda"];
+const BLACKLISTED_BACKENDS: [&str; 2] = ["cuda", "dynamic"];
+
+// Those engines rely on threads, which are not available on `wasm32-unknown-unknown`.
+const BLACKLISTED_ENGINES: [&str; 2] = ["DefaultParallelEngine", "FftParallelEngine"];
 
 /// A function that prunes a concrete-core representation from the nodes we do not want in the wasm
 /// api
 pub fn prune(ccr: &mut ConcreteCore) {
     prune_blacklisted_backends(ccr);
-    prune_unavailable_entities(ccr);
+    prune_blacklisted_engines(ccr);
     prune_unavailable_engine_impls(ccr);
 }
 
@@ -23,12 +24,14 @@ fn prune_blacklisted_backends(ccr: &mut ConcreteCore) {
     });
 }
 
-/// Prunes the unavailable entities from the ccr (views and mut views)
-fn prune_unavailable_entities(ccr: &mut ConcreteCore) {
+/// Prunes the blacklisted engines from the ccr
+fn prune_blacklisted_engines(ccr: &mut ConcreteCore) {
     for backend in ccr.backends.iter_mut() {
-        backend
-            .entities
-            .retain(|entity| matches!(entity.definition.ownership, EntityOwnership::Owned))
+        backend.engines.retain(|engine| {
+            !BLACKLISTED_ENGINES
+                .iter()
+                .any(|blacklisted| engine.definition.get_name() == *blacklisted)
+        });
     }
 }
 
@@ -44,10 +47,10 @@ fn prune_unavailable_engine_impls(ccr: &mut ConcreteCore) {
                 EngineTraitImplArg::OwnedEntity(_, _) => true,
                 EngineTraitImplArg::OwnedEntityRef(_, _) => true,
                 EngineTraitImplArg::OwnedEntityRefMut(_, _) => true,
-                EngineTraitImplArg::ViewEntity(_, _) => false,
-                EngineTraitImplArg::ViewEntityRef(_, _) => false,
-                EngineTraitImplArg::MutViewEntity(_, _) => false,
-                EngineTraitImplArg::MutViewEntityRefMut(_, _) => false,
+                EngineTraitImplArg::ViewEntity(_, _) => true,
+                EngineTraitImplArg::ViewEntityRef(_, _) => true,
+                EngineTraitImplArg::MutViewEntity(_, _) => true,
+                EngineTraitImplArg::MutViewEntityRefMut(_, _) => true,
                 EngineTraitImplArg::Config(_, _) => true,
                 EngineTraitImplArg::ConfigRef(_, _, _) => true,
                 EngineTraitImplArg::ConfigSlice(_, _, _) => true,
@@ -55,20 +58,27 @@ fn prune_unavailable_engine_impls(ccr: &mut ConcreteCore) {
                 EngineTraitImplArg::Dispersion(_, _) => true,
                 EngineTraitImplArg::Numeric(_, _) => true,
                 EngineTraitImplArg::NumericRef(_, _, _) => true,
-                EngineTraitImplArg::NumericRefMut(_, _, _) => false,
+                EngineTraitImplArg::NumericRefMut(_, _, _) => true,
                 EngineTraitImplArg::NumericSlice(_, _, _) => true,
-                EngineTraitImplArg::NumericSliceMut(_, _, _) => false,
+                EngineTraitImplArg::NumericSliceMut(_, _, _) => true,
                 EngineTraitImplArg::NumericVec(_, _, _) => true,
                 EngineTraitImplArg::Unknown(_, _) => false,
             });
+            // Slices can only be returned when they come from the buffer of a consumed view.
+            let consumes_view = impl_.checked_method.args().iter().any(|arg| {
+                matches!(
+                    arg,
+                    EngineTraitImplArg::ViewEntity(_, _) | EngineTraitImplArg::MutViewEntity(_, _)
+                )
+            });
             let return_available = match impl_.checked_method.return_() {
                 EngineTraitImplReturn::OwnedEntity(_) => true,
-                EngineTraitImplReturn::ViewEntity(_) => false,
-                EngineTraitImplReturn::MutViewEntity(_) => false,
+                EngineTraitImplReturn::ViewEntity(_) => true,
+                EngineTraitImplReturn::MutViewEntity(_) => true,
                 EngineTraitImplReturn::Config(_) => false,
                 EngineTraitImplReturn::Numeric(_) => true,
-                EngineTraitImplReturn::NumericSlice(_) => false,
-                EngineTraitImplReturn::NumericSliceMut(_) => false,
+                EngineTraitImplReturn::NumericSlice(_) => consumes_view,
+                EngineTraitImplReturn::NumericSliceMut(_) => consumes_view,
                 EngineTraitImplReturn::NumericVec(_) => true,
                 EngineTraitImplReturn::Unit(_) => true,
                 EngineTraitImplReturn::Unknown(_) => false,
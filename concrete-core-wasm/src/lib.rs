@@ -53,6 +53,48 @@ mod seeder {
 }
 pub use seeder::*;
 
+mod buffer {
+    use std::any::Any;
+
+    /// A buffer borrowed by a view entity, and owned by the same view entity.
+    ///
+    /// Javascript arrays are copied once in the wasm memory when a view is created, and the
+    /// resulting buffer is then borrowed by the view for its whole lifetime, without further copy.
+    pub(crate) struct ViewBuffer<T: 'static>(*mut [T]);
+
+    impl<T: 'static> ViewBuffer<T> {
+        /// Leaks a buffer, and returns a type-erased owner along with a `'static` reference to it.
+        ///
+        /// The reference must not outlive the owner, which frees the buffer when dropped.
+        pub(crate) fn leak(buffer: Box<[T]>) -> (Box<dyn Any>, &'static mut [T]) {
+            let ptr = Box::into_raw(buffer);
+            // SAFETY: The pointer comes from a box, and the allocation is only freed when the
+            // owner is dropped, which the generated view entities only do after dropping the view.
+            (Box::new(ViewBuffer(ptr)), unsafe { &mut *ptr })
+        }
+
+        /// Recovers the buffer from its type-erased owner, once the view borrowing it is gone.
+        pub(crate) fn reclaim(owner: Box<dyn Any>) -> Box<[T]> {
+            let owner = *owner
+                .downcast::<ViewBuffer<T>>()
+                .expect("Unexpected view buffer element type.");
+            let ptr = owner.0;
+            std::mem::forget(owner);
+            // SAFETY: The pointer comes from a box, and was not freed since the owner was
+            // forgotten.
+            unsafe { Box::from_raw(ptr) }
+        }
+    }
+
+    impl<T: 'static> Drop for ViewBuffer<T> {
+        fn drop(&mut self) {
+            // SAFETY: The pointer comes from a box, and the view borrowing it was dropped.
+            unsafe { drop(Box::from_raw(self.0)) }
+        }
+    }
+}
+pub(crate) use buffer::*;
+
 mod commons {
     use wasm_bindgen::prelude::*;
 
@@ -92,7 +134,29 @@ mod commons {
         (PolynomialSize, PolynomialSize, usize),
         (DeltaLog, DeltaLog, usize),
         (ExtractedBitsCount, ExtractedBitsCount, usize),
-        (LwePublicKeyZeroEncryptionCount, LwePublicKeyZeroEncryptionCount, usize)
+        (LwePublicKeyZeroEncryptionCount, LwePublicKeyZeroEncryptionCount, usize),
+        (LweCiphertextIndex, LweCiphertextIndex, usize)
+    }
+
+    #[wasm_bindgen]
+    pub struct LweCiphertextRange(pub(crate) concrete_core::prelude::LweCiphertextRange);
+
+    #[wasm_bindgen]
+    impl LweCiphertextRange {
+        #[wasm_bindgen(constructor)]
+        pub fn new(start: usize, end: usize) -> LweCiphertextRange {
+            LweCiphertextRange(concrete_core::prelude::LweCiphertextRange(start, end))
+        }
+
+        #[wasm_bindgen]
+        pub fn start(&self) -> usize {
+            self.0 .0
+        }
+
+        #[wasm_bindgen]
+        pub fn end(&self) -> usize {
+            self.0 .1
+        }
     }
 
     #[wasm_bindgen]
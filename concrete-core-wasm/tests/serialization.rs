@@ -0,0 +1,102 @@
+//! Pins the bytes produced by the serialization engine on a native target. The wasm tests in
+//! `test.js` check that the bindings produce the very same bytes.
+use concrete_core::commons::math::random::Seed;
+use concrete_core::prelude::*;
+
+// The seeded keys are generated from a seeder always returning the same seed, as the
+// `() => new Uint8Array(16)` seeder of the wasm tests, and without noise, so that their bytes do
+// not depend on the floating point implementation of the target.
+struct ZeroSeeder;
+
+impl Seeder for ZeroSeeder {
+    fn seed(&mut self) -> Seed {
+        Seed(0)
+    }
+
+    fn is_available() -> bool {
+        true
+    }
+}
+
+const LWE_CIPHERTEXT_BYTES: [u8; 44] = [
+    0, 0, 0, 0, 4, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0,
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 128,
+];
+
+const LWE_SEEDED_KEYSWITCH_KEY_BYTES: [u8; 68] = [
+    0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0, 13, 229, 12, 90, 130, 168, 29, 161, 200, 192, 223, 100,
+    251, 11, 244, 55, 8, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0, 0, 0, 0, 252,
+    105, 119, 46, 213, 163, 54, 244, 97, 91, 69, 3, 195, 72, 20, 186,
+];
+
+const LWE_SEEDED_BOOTSTRAP_KEY_BYTES: [u8; 124] = [
+    0, 0, 0, 0, 8, 0, 0, 0, 0, 0, 0, 0, 233, 45, 32, 59, 211, 88, 151, 128, 156, 218, 104, 51, 146,
+    112, 165, 85, 100, 155, 72, 152, 141, 124, 153, 140, 36, 183, 236, 30, 175, 79, 134, 30, 32,
+    15, 254, 101, 81, 57, 99, 127, 70, 211, 91, 74, 238, 19, 254, 84, 48, 42, 164, 132, 194, 92,
+    164, 144, 38, 154, 40, 89, 120, 201, 59, 87, 4, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0, 1,
+    0, 0, 0, 0, 0, 0, 0, 8, 0, 0, 0, 0, 0, 0, 0, 252, 105, 119, 46, 213, 163, 54, 244, 97, 91, 69,
+    3, 195, 72, 20, 186,
+];
+
+#[test]
+fn serialize_lwe_ciphertext_u64() {
+    let mut engine = DefaultEngine::new(Box::new(ZeroSeeder)).unwrap();
+    let mut serialization_engine = DefaultSerializationEngine::new(()).unwrap();
+    let ciphertext: LweCiphertext64 = engine
+        .create_lwe_ciphertext_from(vec![1_u64, 2, 3, 1 << 63])
+        .unwrap();
+    let serialized = serialization_engine.serialize(&ciphertext).unwrap();
+    assert_eq!(serialized, LWE_CIPHERTEXT_BYTES);
+    let deserialized: LweCiphertext64 = serialization_engine
+        .deserialize(LWE_CIPHERTEXT_BYTES.as_slice())
+        .unwrap();
+    assert_eq!(deserialized, ciphertext);
+}
+
+#[test]
+fn serialize_lwe_seeded_keyswitch_key_u64() {
+    let mut engine = DefaultEngine::new(Box::new(ZeroSeeder)).unwrap();
+    let mut serialization_engine = DefaultSerializationEngine::new(()).unwrap();
+    let input_key: LweSecretKey64 = engine.generate_new_lwe_secret_key(LweDimension(2)).unwrap();
+    let output_key: LweSecretKey64 = engine.generate_new_lwe_secret_key(LweDimension(2)).unwrap();
+    let keyswitch_key: LweSeededKeyswitchKey64 = engine
+        .generate_new_lwe_seeded_keyswitch_key(
+            &input_key,
+            &output_key,
+            DecompositionLevelCount(1),
+            DecompositionBaseLog(8),
+            Variance(0.),
+        )
+        .unwrap();
+    let serialized = serialization_engine.serialize(&keyswitch_key).unwrap();
+    assert_eq!(serialized, LWE_SEEDED_KEYSWITCH_KEY_BYTES);
+    let deserialized: LweSeededKeyswitchKey64 = serialization_engine
+        .deserialize(LWE_SEEDED_KEYSWITCH_KEY_BYTES.as_slice())
+        .unwrap();
+    assert_eq!(deserialized, keyswitch_key);
+}
+
+#[test]
+fn serialize_lwe_seeded_bootstrap_key_u64() {
+    let mut engine = DefaultEngine::new(Box::new(ZeroSeeder)).unwrap();
+    let mut serialization_engine = DefaultSerializationEngine::new(()).unwrap();
+    let lwe_key: LweSecretKey64 = engine.generate_new_lwe_secret_key(LweDimension(1)).unwrap();
+    let glwe_key: GlweSecretKey64 = engine
+        .generate_new_glwe_secret_key(GlweDimension(1), PolynomialSize(4))
+        .unwrap();
+    let bootstrap_key: LweSeededBootstrapKey64 = engine
+        .generate_new_lwe_seeded_bootstrap_key(
+            &lwe_key,
+            &glwe_key,
+            DecompositionBaseLog(8),
+            DecompositionLevelCount(1),
+            Variance(0.),
+        )
+        .unwrap();
+    let serialized = serialization_engine.serialize(&bootstrap_key).unwrap();
+    assert_eq!(serialized, LWE_SEEDED_BOOTSTRAP_KEY_BYTES);
+    let deserialized: LweSeededBootstrapKey64 = serialization_engine
+        .deserialize(LWE_SEEDED_BOOTSTRAP_KEY_BYTES.as_slice())
+        .unwrap();
+    assert_eq!(deserialized, bootstrap_key);
+}
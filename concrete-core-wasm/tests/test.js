@@ -1,7 +1,19 @@
 const crypto = require('crypto');
 const test = require('node:test');
 const assert = require('node:assert').strict;
-const {DefaultEngine, JsFunctionSeeder} = require("../pkg");
+const {
+    DecompositionBaseLog,
+    DecompositionLevelCount,
+    DefaultEngine,
+    DefaultSerializationEngine,
+    FftEngine,
+    GlweDimension,
+    JsFunctionSeeder,
+    LweDimension,
+    LweSize,
+    PolynomialSize,
+    Variance
+} = require("../pkg");
 
 function seed() {
     return crypto.randomBytes(16);
@@ -25,3 +37,217 @@ test('create_cleartext_vector_f64', (t) => {
     assert.deepEqual(val, raw);
 });
 
+
+test('discard_retrieve_plaintext_u64', (t) => {
+    let seeder = new JsFunctionSeeder(seed);
+    let eng = new DefaultEngine(seeder);
+    let plaintext = eng.create_plaintext_from_u64_plaintext64(BigInt(3));
+    let output = new BigUint64Array(1);
+    eng.discard_retrieve_plaintext_plaintext64_u64(output, plaintext);
+    assert.strictEqual(output[0], BigInt(3));
+});
+
+test('discard_retrieve_plaintext_vector_u64', (t) => {
+    let seeder = new JsFunctionSeeder(seed);
+    let eng = new DefaultEngine(seeder);
+    let val = new BigUint64Array([BigInt(3), BigInt(2), BigInt(5)]);
+    let plaintext_vector = eng.create_plaintext_vector_from_u64_plaintext_vector64(val);
+    let output = new BigUint64Array(3);
+    eng.discard_retrieve_plaintext_vector_plaintext_vector64_u64(output, plaintext_vector);
+    assert.deepEqual(output, val);
+});
+
+test('encrypt_decrypt_lwe_ciphertext_view_u64', (t) => {
+    let seeder = new JsFunctionSeeder(seed);
+    let eng = new DefaultEngine(seeder);
+    let lwe_dimension = new LweDimension(2);
+    let input = BigInt(3) << BigInt(50);
+    let key = eng.generate_new_lwe_secret_key_lwe_secret_key64(lwe_dimension);
+    let plaintext = eng.create_plaintext_from_u64_plaintext64(input);
+    let mut_view = eng.create_lwe_ciphertext_from_u64_mut_slice_lwe_ciphertext_mut_view64(
+        new BigUint64Array(3)
+    );
+    eng.discard_encrypt_lwe_ciphertext_lwe_secret_key64_plaintext64_lwe_ciphertext_mut_view64(
+        key, mut_view, plaintext, new Variance(Math.pow(2, -25))
+    );
+    let raw = eng.consume_retrieve_lwe_ciphertext_lwe_ciphertext_mut_view64_u64_mut_slice(mut_view);
+    assert.strictEqual(raw.length, 3);
+    let view = eng.create_lwe_ciphertext_from_u64_slice_lwe_ciphertext_view64(raw);
+    let decrypted = eng.decrypt_lwe_ciphertext_lwe_secret_key64_lwe_ciphertext_view64_plaintext64(key, view);
+    let output = eng.retrieve_plaintext_plaintext64_u64(decrypted);
+    let error = output > input ? output - input : input - output;
+    assert.ok(error < (BigInt(1) << BigInt(45)));
+});
+
+test('bootstrap_lwe_ciphertext_u64', (t) => {
+    let seeder = new JsFunctionSeeder(seed);
+    let eng = new DefaultEngine(seeder);
+    let fft_eng = new FftEngine();
+    let glwe_dimension = new GlweDimension(1);
+    let polynomial_size = new PolynomialSize(256);
+    let noise = new Variance(Math.pow(2, -100));
+    let lwe_sk = eng.generate_new_lwe_secret_key_lwe_secret_key64(new LweDimension(4));
+    let glwe_sk = eng.generate_new_glwe_secret_key_glwe_secret_key64(glwe_dimension, polynomial_size);
+    let bsk = eng.generate_new_lwe_bootstrap_key_lwe_secret_key64_glwe_secret_key64_lwe_bootstrap_key64(
+        lwe_sk, glwe_sk, new DecompositionBaseLog(3), new DecompositionLevelCount(5), noise
+    );
+    let fourier_bsk = fft_eng.convert_lwe_bootstrap_key_lwe_bootstrap_key64_fft_fourier_lwe_bootstrap_key64(bsk);
+    let output_sk = eng.transform_glwe_secret_key_to_lwe_secret_key_glwe_secret_key64_lwe_secret_key64(glwe_sk);
+
+    // A constant accumulator maps every input in the first half of the torus to the same value.
+    let value = BigInt(1) << BigInt(60);
+    let acc = eng.trivially_encrypt_glwe_ciphertext_plaintext_vector64_glwe_ciphertext64(
+        glwe_dimension.to_glwe_size(),
+        eng.create_plaintext_vector_from_u64_plaintext_vector64(new BigUint64Array(256).fill(value))
+    );
+    let input = eng.encrypt_lwe_ciphertext_lwe_secret_key64_plaintext64_lwe_ciphertext64(
+        lwe_sk, eng.create_plaintext_from_u64_plaintext64(BigInt(1) << BigInt(59)), noise
+    );
+    let output = eng.trivially_encrypt_lwe_ciphertext_plaintext64_lwe_ciphertext64(
+        new LweSize(257), eng.create_plaintext_from_u64_plaintext64(BigInt(0))
+    );
+    fft_eng.discard_bootstrap_lwe_ciphertext_fft_fourier_lwe_bootstrap_key64_glwe_ciphertext64_lwe_ciphertext64_lwe_ciphertext64(
+        output, input, acc, fourier_bsk
+    );
+
+    let decrypted = eng.decrypt_lwe_ciphertext_lwe_secret_key64_lwe_ciphertext64_plaintext64(output_sk, output);
+    let raw = eng.retrieve_plaintext_plaintext64_u64(decrypted);
+    let error = raw > value ? raw - value : value - raw;
+    assert.ok(error < (BigInt(1) << BigInt(55)));
+});
+
+test('bootstrap_lwe_ciphertext_large_polynomial_u64', (t) => {
+    // The FFT of this polynomial size has 8192 points, above the 4096 points of the largest base
+    // FFT the plans are built upon on wasm32.
+    let seeder = new JsFunctionSeeder(seed);
+    let eng = new DefaultEngine(seeder);
+    let fft_eng = new FftEngine();
+    let glwe_dimension = new GlweDimension(1);
+    let polynomial_size = new PolynomialSize(16384);
+    let noise = new Variance(Math.pow(2, -100));
+    let lwe_sk = eng.generate_new_lwe_secret_key_lwe_secret_key64(new LweDimension(2));
+    let glwe_sk = eng.generate_new_glwe_secret_key_glwe_secret_key64(glwe_dimension, polynomial_size);
+    let bsk = eng.generate_new_lwe_bootstrap_key_lwe_secret_key64_glwe_secret_key64_lwe_bootstrap_key64(
+        lwe_sk, glwe_sk, new DecompositionBaseLog(4), new DecompositionLevelCount(8), noise
+    );
+    let fourier_bsk = fft_eng.convert_lwe_bootstrap_key_lwe_bootstrap_key64_fft_fourier_lwe_bootstrap_key64(bsk);
+    let output_sk = eng.transform_glwe_secret_key_to_lwe_secret_key_glwe_secret_key64_lwe_secret_key64(glwe_sk);
+
+    // A constant accumulator maps every input in the first half of the torus to the same value.
+    let value = BigInt(1) << BigInt(60);
+    let acc = eng.trivially_encrypt_glwe_ciphertext_plaintext_vector64_glwe_ciphertext64(
+        glwe_dimension.to_glwe_size(),
+        eng.create_plaintext_vector_from_u64_plaintext_vector64(new BigUint64Array(16384).fill(value))
+    );
+    let input = eng.encrypt_lwe_ciphertext_lwe_secret_key64_plaintext64_lwe_ciphertext64(
+        lwe_sk, eng.create_plaintext_from_u64_plaintext64(BigInt(1) << BigInt(59)), noise
+    );
+    let output = eng.trivially_encrypt_lwe_ciphertext_plaintext64_lwe_ciphertext64(
+        new LweSize(16385), eng.create_plaintext_from_u64_plaintext64(BigInt(0))
+    );
+    fft_eng.discard_bootstrap_lwe_ciphertext_fft_fourier_lwe_bootstrap_key64_glwe_ciphertext64_lwe_ciphertext64_lwe_ciphertext64(
+        output, input, acc, fourier_bsk
+    );
+
+    let decrypted = eng.decrypt_lwe_ciphertext_lwe_secret_key64_lwe_ciphertext64_plaintext64(output_sk, output);
+    let raw = eng.retrieve_plaintext_plaintext64_u64(decrypted);
+    let error = raw > value ? raw - value : value - raw;
+    assert.ok(error < (BigInt(1) << BigInt(55)));
+});
+
+test('serialize_lwe_ciphertext_u64_matches_rust', (t) => {
+    let seeder = new JsFunctionSeeder(seed);
+    let eng = new DefaultEngine(seeder);
+    let ser_eng = new DefaultSerializationEngine();
+    let ciphertext = eng.create_lwe_ciphertext_from_u64_vec_lwe_ciphertext64(
+        new BigUint64Array([BigInt(1), BigInt(2), BigInt(3), BigInt(1) << BigInt(63)])
+    );
+    // The bytes produced by `DefaultSerializationEngine::serialize` on a native target for the same
+    // ciphertext, as pinned in `serialization.rs`.
+    let expected = new Uint8Array([
+        0, 0, 0, 0, 4, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 128
+    ]);
+    let serialized = ser_eng.serialize_lwe_ciphertext64_u8_vec(ciphertext);
+    assert.deepEqual(serialized, expected);
+    let deserialized = ser_eng.deserialize_u8_slice_lwe_ciphertext64(expected);
+    assert.deepEqual(ser_eng.serialize_lwe_ciphertext64_u8_vec(deserialized), expected);
+});
+
+test('serialize_lwe_seeded_ciphertext_vector_u64', (t) => {
+    let seeder = new JsFunctionSeeder(seed);
+    let eng = new DefaultEngine(seeder);
+    let ser_eng = new DefaultSerializationEngine();
+    let key = eng.generate_new_lwe_secret_key_lwe_secret_key64(new LweDimension(630));
+    let input = new BigUint64Array([BigInt(1) << BigInt(60), BigInt(3) << BigInt(60)]);
+    let seeded = eng.encrypt_lwe_seeded_ciphertext_vector_lwe_secret_key64_plaintext_vector64_lwe_seeded_ciphertext_vector64(
+        key, eng.create_plaintext_vector_from_u64_plaintext_vector64(input), new Variance(Math.pow(2, -50))
+    );
+    let serialized = ser_eng.serialize_lwe_seeded_ciphertext_vector64_u8_vec(seeded);
+    // Only the seed and the bodies are serialized, not the masks.
+    assert.ok(serialized.length < 100);
+
+    let deserialized = ser_eng.deserialize_u8_slice_lwe_seeded_ciphertext_vector64(serialized);
+    let ciphertext_vector = eng.transform_lwe_seeded_ciphertext_vector_to_lwe_ciphertext_vector_lwe_seeded_ciphertext_vector64_lwe_ciphertext_vector64(
+        deserialized
+    );
+    let decrypted = eng.decrypt_lwe_ciphertext_vector_lwe_secret_key64_lwe_ciphertext_vector64_plaintext_vector64(
+        key, ciphertext_vector
+    );
+    let output = eng.retrieve_plaintext_vector_plaintext_vector64_u64(decrypted);
+    for (let i = 0; i < input.length; i++) {
+        let error = output[i] > input[i] ? output[i] - input[i] : input[i] - output[i];
+        assert.ok(error < (BigInt(1) << BigInt(50)));
+    }
+});
+
+// The seeded keys are generated from a constant seed and without noise, so that their bytes are the
+// ones pinned on a native target in `serialization.rs`.
+function zero_seed() {
+    return new Uint8Array(16);
+}
+
+test('serialize_lwe_seeded_keyswitch_key_u64_matches_rust', (t) => {
+    let seeder = new JsFunctionSeeder(zero_seed);
+    let eng = new DefaultEngine(seeder);
+    let ser_eng = new DefaultSerializationEngine();
+    let input_key = eng.generate_new_lwe_secret_key_lwe_secret_key64(new LweDimension(2));
+    let output_key = eng.generate_new_lwe_secret_key_lwe_secret_key64(new LweDimension(2));
+    let ksk = eng.generate_new_lwe_seeded_keyswitch_key_lwe_secret_key64_lwe_secret_key64_lwe_seeded_keyswitch_key64(
+        input_key, output_key, new DecompositionLevelCount(1), new DecompositionBaseLog(8), new Variance(0)
+    );
+    let expected = new Uint8Array([
+        0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0, 13, 229, 12, 90, 130, 168, 29, 161, 200, 192, 223, 100,
+        251, 11, 244, 55, 8, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0, 0, 0, 0, 252,
+        105, 119, 46, 213, 163, 54, 244, 97, 91, 69, 3, 195, 72, 20, 186
+    ]);
+    let serialized = ser_eng.serialize_lwe_seeded_keyswitch_key64_u8_vec(ksk);
+    assert.deepEqual(serialized, expected);
+    let deserialized = ser_eng.deserialize_u8_slice_lwe_seeded_keyswitch_key64(expected);
+    assert.deepEqual(ser_eng.serialize_lwe_seeded_keyswitch_key64_u8_vec(deserialized), expected);
+});
+
+test('serialize_lwe_seeded_bootstrap_key_u64_matches_rust', (t) => {
+    let seeder = new JsFunctionSeeder(zero_seed);
+    let eng = new DefaultEngine(seeder);
+    let ser_eng = new DefaultSerializationEngine();
+    let lwe_key = eng.generate_new_lwe_secret_key_lwe_secret_key64(new LweDimension(1));
+    let glwe_key = eng.generate_new_glwe_secret_key_glwe_secret_key64(
+        new GlweDimension(1), new PolynomialSize(4)
+    );
+    let bsk = eng.generate_new_lwe_seeded_bootstrap_key_lwe_secret_key64_glwe_secret_key64_lwe_seeded_bootstrap_key64(
+        lwe_key, glwe_key, new DecompositionBaseLog(8), new DecompositionLevelCount(1), new Variance(0)
+    );
+    let expected = new Uint8Array([
+        0, 0, 0, 0, 8, 0, 0, 0, 0, 0, 0, 0, 233, 45, 32, 59, 211, 88, 151, 128, 156, 218, 104, 51, 146,
+        112, 165, 85, 100, 155, 72, 152, 141, 124, 153, 140, 36, 183, 236, 30, 175, 79, 134, 30, 32,
+        15, 254, 101, 81, 57, 99, 127, 70, 211, 91, 74, 238, 19, 254, 84, 48, 42, 164, 132, 194, 92,
+        164, 144, 38, 154, 40, 89, 120, 201, 59, 87, 4, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0,
+        1, 0, 0, 0, 0, 0, 0, 0, 8, 0, 0, 0, 0, 0, 0, 0, 252, 105, 119, 46, 213, 163, 54, 244, 97, 91,
+        69, 3, 195, 72, 20, 186
+    ]);
+    let serialized = ser_eng.serialize_lwe_seeded_bootstrap_key64_u8_vec(bsk);
+    assert.deepEqual(serialized, expected);
+    let deserialized = ser_eng.deserialize_u8_slice_lwe_seeded_bootstrap_key64(expected);
+    assert.deepEqual(ser_eng.serialize_lwe_seeded_bootstrap_key64_u8_vec(deserialized), expected);
+});
@@ -1,19 +1,8 @@
-#[cfg(feature = "backend_default_generator_x86_64_aesni")]
-use concrete_csprng::generators::AesniRandomGenerator;
-#[cfg(feature = "backend_default_generator_aarch64_aes")]
-use concrete_csprng::generators::NeonAesRandomGenerator;
-#[cfg(all(
-    not(feature = "backend_default_generator_x86_64_aesni"),
-    not(feature = "backend_default_generator_aarch64_aes")
-))]
-use concrete_csprng::generators::SoftwareRandomGenerator;
+use concrete_csprng::generators::DynamicRandomGenerator;
 
-#[cfg(feature = "backend_default_generator_x86_64_aesni")]
-pub type ActivatedRandomGenerator = AesniRandomGenerator;
-#[cfg(feature = "backend_default_generator_aarch64_aes")]
-pub type ActivatedRandomGenerator = NeonAesRandomGenerator;
-#[cfg(all(
-    not(feature = "backend_default_generator_x86_64_aesni"),
-    not(feature = "backend_default_generator_aarch64_aes")
-))]
-pub type ActivatedRandomGenerator = SoftwareRandomGenerator;
+pub use concrete_csprng::generators::GeneratorKind;
+
+// The fastest AES implementation compiled in and supported by the CPU is selected at runtime, all
+// of them outputting the same stream for a given seed. The other implementations, such as the
+// ChaCha20 one, can be requested explicitly with `DefaultEngine::new_with_generator`.
+pub type ActivatedRandomGenerator = DynamicRandomGenerator;
@@ -0,0 +1,157 @@
+use crate::backends::default::implementation::engines::DefaultEngine;
+use crate::backends::default::implementation::entities::{
+    GlweCiphertext32, GlweCiphertext64, GlweCompressedCiphertext32, GlweCompressedCiphertext64,
+};
+use crate::commons::crypto::glwe::GlweCompressedCiphertext as ImplGlweCompressedCiphertext;
+use crate::prelude::CiphertextModulusLog;
+use crate::specification::engines::{
+    GlweCiphertextCompressionEngine, GlweCiphertextCompressionError,
+};
+
+/// # Description:
+/// Implementation of [`GlweCiphertextCompressionEngine`] for [`DefaultEngine`] that operates on
+/// 32 bits integers.
+impl GlweCiphertextCompressionEngine<GlweCiphertext32, GlweCompressedCiphertext32>
+    for DefaultEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_core::prelude::{
+    ///     CiphertextModulusLog, GlweDimension, PolynomialSize, Variance, *,
+    /// };
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let glwe_dimension = GlweDimension(2);
+    /// let polynomial_size = PolynomialSize(4);
+    /// // There are always polynomial_size messages encrypted in the GLWE ciphertext
+    /// // Here a hard-set encoding is applied (shift by 20 bits)
+    /// let input = vec![3_u32 << 20; polynomial_size.0];
+    /// let noise = Variance(2_f64.powf(-50.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let key: GlweSecretKey32 =
+    ///     engine.generate_new_glwe_secret_key(glwe_dimension, polynomial_size)?;
+    /// let plaintext_vector = engine.create_plaintext_vector_from(&input)?;
+    /// let ciphertext = engine.encrypt_glwe_ciphertext(&key, &plaintext_vector, noise)?;
+    ///
+    /// // Only the 16 most significant bits of each coefficient are kept.
+    /// let compressed: GlweCompressedCiphertext32 =
+    ///     engine.compress_glwe_ciphertext(&ciphertext, CiphertextModulusLog(16))?;
+    /// #
+    /// assert_eq!(compressed.glwe_dimension(), glwe_dimension);
+    /// assert_eq!(compressed.polynomial_size(), polynomial_size);
+    /// assert_eq!(
+    ///     compressed.ciphertext_modulus_log(),
+    ///     CiphertextModulusLog(16)
+    /// );
+    ///
+    /// let decompressed: GlweCiphertext32 = engine.decompress_glwe_ciphertext(&compressed)?;
+    /// let output = engine.decrypt_glwe_ciphertext(&key, &decompressed)?;
+    /// let output = engine.retrieve_plaintext_vector(&output)?;
+    /// for value in output {
+    ///     assert_eq!((value.wrapping_add(1 << 19)) >> 20, 3);
+    /// }
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn compress_glwe_ciphertext(
+        &mut self,
+        input: &GlweCiphertext32,
+        ciphertext_modulus_log: CiphertextModulusLog,
+    ) -> Result<GlweCompressedCiphertext32, GlweCiphertextCompressionError<Self::EngineError>> {
+        GlweCiphertextCompressionError::perform_generic_checks(ciphertext_modulus_log, 32)?;
+        Ok(unsafe { self.compress_glwe_ciphertext_unchecked(input, ciphertext_modulus_log) })
+    }
+
+    unsafe fn compress_glwe_ciphertext_unchecked(
+        &mut self,
+        input: &GlweCiphertext32,
+        ciphertext_modulus_log: CiphertextModulusLog,
+    ) -> GlweCompressedCiphertext32 {
+        GlweCompressedCiphertext32(ImplGlweCompressedCiphertext::compress(
+            &input.0,
+            ciphertext_modulus_log,
+        ))
+    }
+}
+
+/// # Description:
+/// Implementation of [`GlweCiphertextCompressionEngine`] for [`DefaultEngine`] that operates on
+/// 64 bits integers.
+impl GlweCiphertextCompressionEngine<GlweCiphertext64, GlweCompressedCiphertext64>
+    for DefaultEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_core::prelude::{
+    ///     CiphertextModulusLog, GlweDimension, PolynomialSize, Variance, *,
+    /// };
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let glwe_dimension = GlweDimension(2);
+    /// let polynomial_size = PolynomialSize(4);
+    /// // There are always polynomial_size messages encrypted in the GLWE ciphertext
+    /// // Here a hard-set encoding is applied (shift by 50 bits)
+    /// let input = vec![3_u64 << 50; polynomial_size.0];
+    /// let noise = Variance(2_f64.powf(-50.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let key: GlweSecretKey64 =
+    ///     engine.generate_new_glwe_secret_key(glwe_dimension, polynomial_size)?;
+    /// let plaintext_vector = engine.create_plaintext_vector_from(&input)?;
+    /// let ciphertext = engine.encrypt_glwe_ciphertext(&key, &plaintext_vector, noise)?;
+    ///
+    /// // Only the 16 most significant bits of each coefficient are kept.
+    /// let compressed: GlweCompressedCiphertext64 =
+    ///     engine.compress_glwe_ciphertext(&ciphertext, CiphertextModulusLog(16))?;
+    /// #
+    /// assert_eq!(compressed.glwe_dimension(), glwe_dimension);
+    /// assert_eq!(compressed.polynomial_size(), polynomial_size);
+    /// assert_eq!(
+    ///     compressed.ciphertext_modulus_log(),
+    ///     CiphertextModulusLog(16)
+    /// );
+    ///
+    /// let decompressed: GlweCiphertext64 = engine.decompress_glwe_ciphertext(&compressed)?;
+    /// let output = engine.decrypt_glwe_ciphertext(&key, &decompressed)?;
+    /// let output = engine.retrieve_plaintext_vector(&output)?;
+    /// for value in output {
+    ///     assert_eq!((value.wrapping_add(1 << 49)) >> 50, 3);
+    /// }
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn compress_glwe_ciphertext(
+        &mut self,
+        input: &GlweCiphertext64,
+        ciphertext_modulus_log: CiphertextModulusLog,
+    ) -> Result<GlweCompressedCiphertext64, GlweCiphertextCompressionError<Self::EngineError>> {
+        GlweCiphertextCompressionError::perform_generic_checks(ciphertext_modulus_log, 64)?;
+        Ok(unsafe { self.compress_glwe_ciphertext_unchecked(input, ciphertext_modulus_log) })
+    }
+
+    unsafe fn compress_glwe_ciphertext_unchecked(
+        &mut self,
+        input: &GlweCiphertext64,
+        ciphertext_modulus_log: CiphertextModulusLog,
+    ) -> GlweCompressedCiphertext64 {
+        GlweCompressedCiphertext64(ImplGlweCompressedCiphertext::compress(
+            &input.0,
+            ciphertext_modulus_log,
+        ))
+    }
+}
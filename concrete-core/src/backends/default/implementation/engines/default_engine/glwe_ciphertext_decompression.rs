@@ -0,0 +1,142 @@
+use crate::backends::default::implementation::engines::DefaultEngine;
+use crate::backends::default::implementation::entities::{
+    GlweCiphertext32, GlweCiphertext64, GlweCompressedCiphertext32, GlweCompressedCiphertext64,
+};
+use crate::commons::crypto::glwe::GlweCiphertext as ImplGlweCiphertext;
+use crate::specification::engines::{
+    GlweCiphertextDecompressionEngine, GlweCiphertextDecompressionError,
+};
+
+/// # Description:
+/// Implementation of [`GlweCiphertextDecompressionEngine`] for [`DefaultEngine`] that operates on
+/// 32 bits integers.
+impl GlweCiphertextDecompressionEngine<GlweCompressedCiphertext32, GlweCiphertext32>
+    for DefaultEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_core::prelude::{
+    ///     CiphertextModulusLog, GlweDimension, PolynomialSize, Variance, *,
+    /// };
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let glwe_dimension = GlweDimension(2);
+    /// let polynomial_size = PolynomialSize(4);
+    /// // There are always polynomial_size messages encrypted in the GLWE ciphertext
+    /// // Here a hard-set encoding is applied (shift by 20 bits)
+    /// let input = vec![3_u32 << 20; polynomial_size.0];
+    /// let noise = Variance(2_f64.powf(-50.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let key: GlweSecretKey32 =
+    ///     engine.generate_new_glwe_secret_key(glwe_dimension, polynomial_size)?;
+    /// let plaintext_vector = engine.create_plaintext_vector_from(&input)?;
+    /// let ciphertext = engine.encrypt_glwe_ciphertext(&key, &plaintext_vector, noise)?;
+    /// let compressed: GlweCompressedCiphertext32 =
+    ///     engine.compress_glwe_ciphertext(&ciphertext, CiphertextModulusLog(16))?;
+    ///
+    /// let decompressed: GlweCiphertext32 = engine.decompress_glwe_ciphertext(&compressed)?;
+    /// #
+    /// assert_eq!(decompressed.glwe_dimension(), glwe_dimension);
+    /// assert_eq!(decompressed.polynomial_size(), polynomial_size);
+    ///
+    /// // The messages are recovered after rounding away the noise and the compression error.
+    /// let decrypted = engine.decrypt_glwe_ciphertext(&key, &decompressed)?;
+    /// let output = engine.retrieve_plaintext_vector(&decrypted)?;
+    /// for plaintext in output {
+    ///     assert_eq!(plaintext.wrapping_add(1 << 19) >> 20, 3);
+    /// }
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn decompress_glwe_ciphertext(
+        &mut self,
+        input: &GlweCompressedCiphertext32,
+    ) -> Result<GlweCiphertext32, GlweCiphertextDecompressionError<Self::EngineError>> {
+        Ok(unsafe { self.decompress_glwe_ciphertext_unchecked(input) })
+    }
+
+    unsafe fn decompress_glwe_ciphertext_unchecked(
+        &mut self,
+        input: &GlweCompressedCiphertext32,
+    ) -> GlweCiphertext32 {
+        let mut output =
+            ImplGlweCiphertext::allocate(0_u32, input.0.polynomial_size(), input.0.size());
+        input.0.decompress_into(&mut output);
+        GlweCiphertext32(output)
+    }
+}
+
+/// # Description:
+/// Implementation of [`GlweCiphertextDecompressionEngine`] for [`DefaultEngine`] that operates on
+/// 64 bits integers.
+impl GlweCiphertextDecompressionEngine<GlweCompressedCiphertext64, GlweCiphertext64>
+    for DefaultEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_core::prelude::{
+    ///     CiphertextModulusLog, GlweDimension, PolynomialSize, Variance, *,
+    /// };
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let glwe_dimension = GlweDimension(2);
+    /// let polynomial_size = PolynomialSize(4);
+    /// // There are always polynomial_size messages encrypted in the GLWE ciphertext
+    /// // Here a hard-set encoding is applied (shift by 50 bits)
+    /// let input = vec![3_u64 << 50; polynomial_size.0];
+    /// let noise = Variance(2_f64.powf(-50.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let key: GlweSecretKey64 =
+    ///     engine.generate_new_glwe_secret_key(glwe_dimension, polynomial_size)?;
+    /// let plaintext_vector = engine.create_plaintext_vector_from(&input)?;
+    /// let ciphertext = engine.encrypt_glwe_ciphertext(&key, &plaintext_vector, noise)?;
+    /// let compressed: GlweCompressedCiphertext64 =
+    ///     engine.compress_glwe_ciphertext(&ciphertext, CiphertextModulusLog(16))?;
+    ///
+    /// let decompressed: GlweCiphertext64 = engine.decompress_glwe_ciphertext(&compressed)?;
+    /// #
+    /// assert_eq!(decompressed.glwe_dimension(), glwe_dimension);
+    /// assert_eq!(decompressed.polynomial_size(), polynomial_size);
+    ///
+    /// // The messages are recovered after rounding away the noise and the compression error.
+    /// let decrypted = engine.decrypt_glwe_ciphertext(&key, &decompressed)?;
+    /// let output = engine.retrieve_plaintext_vector(&decrypted)?;
+    /// for plaintext in output {
+    ///     assert_eq!(plaintext.wrapping_add(1 << 49) >> 50, 3);
+    /// }
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn decompress_glwe_ciphertext(
+        &mut self,
+        input: &GlweCompressedCiphertext64,
+    ) -> Result<GlweCiphertext64, GlweCiphertextDecompressionError<Self::EngineError>> {
+        Ok(unsafe { self.decompress_glwe_ciphertext_unchecked(input) })
+    }
+
+    unsafe fn decompress_glwe_ciphertext_unchecked(
+        &mut self,
+        input: &GlweCompressedCiphertext64,
+    ) -> GlweCiphertext64 {
+        let mut output =
+            ImplGlweCiphertext::allocate(0_u64, input.0.polynomial_size(), input.0.size());
+        input.0.decompress_into(&mut output);
+        GlweCiphertext64(output)
+    }
+}
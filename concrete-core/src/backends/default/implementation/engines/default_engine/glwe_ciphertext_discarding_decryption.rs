@@ -1,7 +1,7 @@
 use crate::backends::default::implementation::engines::DefaultEngine;
 use crate::backends::default::implementation::entities::{
-    GlweCiphertext32, GlweCiphertext64, GlweSecretKey32, GlweSecretKey64, PlaintextVector32,
-    PlaintextVector64,
+    GlweCiphertext32, GlweCiphertext64, GlweCiphertextView64, GlweSecretKey32, GlweSecretKey64,
+    PlaintextVector32, PlaintextVector64,
 };
 use crate::specification::engines::{
     GlweCiphertextDiscardingDecryptionEngine, GlweCiphertextDiscardingDecryptionError,
@@ -120,3 +120,68 @@ impl GlweCiphertextDiscardingDecryptionEngine<GlweSecretKey64, GlweCiphertext64,
         key.0.decrypt_glwe(&mut output.0, &input.0);
     }
 }
+
+/// # Description:
+/// Implementation of [`GlweCiphertextDiscardingDecryptionEngine`] for [`DefaultEngine`] that
+/// operates on views containing 64 bits integers.
+impl
+    GlweCiphertextDiscardingDecryptionEngine<
+        GlweSecretKey64,
+        GlweCiphertextView64<'_>,
+        PlaintextVector64,
+    > for DefaultEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_core::prelude::{GlweDimension, PlaintextCount, PolynomialSize, Variance, *};
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let glwe_dimension = GlweDimension(2);
+    /// let polynomial_size = PolynomialSize(4);
+    /// // Here a hard-set encoding is applied (shift by 50 bits)
+    /// let input = vec![3_u64 << 50; polynomial_size.0];
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let key: GlweSecretKey64 =
+    ///     engine.generate_new_glwe_secret_key(glwe_dimension, polynomial_size)?;
+    /// let mut plaintext_vector = engine.create_plaintext_vector_from(&input)?;
+    /// let ciphertext = engine.encrypt_glwe_ciphertext(&key, &plaintext_vector, noise)?;
+    ///
+    /// let raw_ciphertext = engine.consume_retrieve_glwe_ciphertext(ciphertext)?;
+    /// let ciphertext: GlweCiphertextView64 =
+    ///     engine.create_glwe_ciphertext_from(&raw_ciphertext[..], polynomial_size)?;
+    ///
+    /// engine.discard_decrypt_glwe_ciphertext(&key, &mut plaintext_vector, &ciphertext)?;
+    /// #
+    /// assert_eq!(plaintext_vector.plaintext_count(), PlaintextCount(4));
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn discard_decrypt_glwe_ciphertext(
+        &mut self,
+        key: &GlweSecretKey64,
+        output: &mut PlaintextVector64,
+        input: &GlweCiphertextView64,
+    ) -> Result<(), GlweCiphertextDiscardingDecryptionError<Self::EngineError>> {
+        GlweCiphertextDiscardingDecryptionError::perform_generic_checks(key, output, input)?;
+        unsafe { self.discard_decrypt_glwe_ciphertext_unchecked(key, output, input) };
+        Ok(())
+    }
+
+    unsafe fn discard_decrypt_glwe_ciphertext_unchecked(
+        &mut self,
+        key: &GlweSecretKey64,
+        output: &mut PlaintextVector64,
+        input: &GlweCiphertextView64,
+    ) {
+        key.0.decrypt_glwe(&mut output.0, &input.0);
+    }
+}
@@ -0,0 +1,144 @@
+use crate::prelude::PlaintextCount;
+
+use crate::backends::default::implementation::engines::DefaultEngine;
+use crate::backends::default::implementation::entities::{
+    GlweCiphertext32, GlweCiphertext64, GlweSecretKey32, GlweSecretKey64, PlaintextVector32,
+    PlaintextVector64,
+};
+use crate::commons::crypto::encoding::PlaintextList as ImplPlaintextList;
+use crate::commons::math::tensor::AsRefTensor;
+use crate::commons::math::torus::signed_torus_distance;
+use crate::specification::engines::{
+    GlweCiphertextNoiseMeasurementEngine, GlweCiphertextNoiseMeasurementError,
+};
+use crate::specification::entities::GlweCiphertextEntity;
+
+/// # Description:
+/// Implementation of [`GlweCiphertextNoiseMeasurementEngine`] for [`DefaultEngine`] that operates
+/// on 32 bits integers.
+impl GlweCiphertextNoiseMeasurementEngine<GlweSecretKey32, GlweCiphertext32, PlaintextVector32>
+    for DefaultEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_core::prelude::{GlweDimension, PolynomialSize, Variance, *};
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let glwe_dimension = GlweDimension(2);
+    /// let polynomial_size = PolynomialSize(4);
+    /// // Here a hard-set encoding is applied (shift by 20 bits)
+    /// let input = vec![3_u32 << 20; polynomial_size.0];
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let key: GlweSecretKey32 =
+    ///     engine.generate_new_glwe_secret_key(glwe_dimension, polynomial_size)?;
+    /// let plaintext_vector = engine.create_plaintext_vector_from(&input)?;
+    /// let ciphertext = engine.encrypt_glwe_ciphertext(&key, &plaintext_vector, noise)?;
+    ///
+    /// let errors = engine.measure_glwe_ciphertext_noise(&key, &ciphertext, &plaintext_vector)?;
+    /// #
+    /// assert_eq!(errors.len(), polynomial_size.0);
+    /// assert!(errors.iter().all(|e| e.abs() < 2_f64.powf(-8.)));
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn measure_glwe_ciphertext_noise(
+        &mut self,
+        key: &GlweSecretKey32,
+        input: &GlweCiphertext32,
+        expected: &PlaintextVector32,
+    ) -> Result<Vec<f64>, GlweCiphertextNoiseMeasurementError<Self::EngineError>> {
+        GlweCiphertextNoiseMeasurementError::perform_generic_checks(key, input, expected)?;
+        Ok(unsafe { self.measure_glwe_ciphertext_noise_unchecked(key, input, expected) })
+    }
+
+    unsafe fn measure_glwe_ciphertext_noise_unchecked(
+        &mut self,
+        key: &GlweSecretKey32,
+        input: &GlweCiphertext32,
+        expected: &PlaintextVector32,
+    ) -> Vec<f64> {
+        let mut plaintext =
+            ImplPlaintextList::allocate(0u32, PlaintextCount(input.polynomial_size().0));
+        key.0.decrypt_glwe(&mut plaintext, &input.0);
+        plaintext
+            .as_tensor()
+            .iter()
+            .zip(expected.0.as_tensor().iter())
+            .map(|(decrypted, expected)| signed_torus_distance(*decrypted, *expected))
+            .collect()
+    }
+}
+
+/// # Description:
+/// Implementation of [`GlweCiphertextNoiseMeasurementEngine`] for [`DefaultEngine`] that operates
+/// on 64 bits integers.
+impl GlweCiphertextNoiseMeasurementEngine<GlweSecretKey64, GlweCiphertext64, PlaintextVector64>
+    for DefaultEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_core::prelude::{GlweDimension, PolynomialSize, Variance, *};
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let glwe_dimension = GlweDimension(2);
+    /// let polynomial_size = PolynomialSize(4);
+    /// // Here a hard-set encoding is applied (shift by 50 bits)
+    /// let input = vec![3_u64 << 50; polynomial_size.0];
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let key: GlweSecretKey64 =
+    ///     engine.generate_new_glwe_secret_key(glwe_dimension, polynomial_size)?;
+    /// let plaintext_vector = engine.create_plaintext_vector_from(&input)?;
+    /// let ciphertext = engine.encrypt_glwe_ciphertext(&key, &plaintext_vector, noise)?;
+    ///
+    /// let errors = engine.measure_glwe_ciphertext_noise(&key, &ciphertext, &plaintext_vector)?;
+    /// #
+    /// assert_eq!(errors.len(), polynomial_size.0);
+    /// assert!(errors.iter().all(|e| e.abs() < 2_f64.powf(-8.)));
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn measure_glwe_ciphertext_noise(
+        &mut self,
+        key: &GlweSecretKey64,
+        input: &GlweCiphertext64,
+        expected: &PlaintextVector64,
+    ) -> Result<Vec<f64>, GlweCiphertextNoiseMeasurementError<Self::EngineError>> {
+        GlweCiphertextNoiseMeasurementError::perform_generic_checks(key, input, expected)?;
+        Ok(unsafe { self.measure_glwe_ciphertext_noise_unchecked(key, input, expected) })
+    }
+
+    unsafe fn measure_glwe_ciphertext_noise_unchecked(
+        &mut self,
+        key: &GlweSecretKey64,
+        input: &GlweCiphertext64,
+        expected: &PlaintextVector64,
+    ) -> Vec<f64> {
+        let mut plaintext =
+            ImplPlaintextList::allocate(0u64, PlaintextCount(input.polynomial_size().0));
+        key.0.decrypt_glwe(&mut plaintext, &input.0);
+        plaintext
+            .as_tensor()
+            .iter()
+            .zip(expected.0.as_tensor().iter())
+            .map(|(decrypted, expected)| signed_torus_distance(*decrypted, *expected))
+            .collect()
+    }
+}
@@ -0,0 +1,184 @@
+use super::ActivatedRandomGenerator;
+use crate::backends::default::engines::{DefaultEngine, DefaultError};
+use crate::backends::default::entities::{
+    GlweCiphertext32, GlweCiphertext64, GlweSeededCiphertextVector32, GlweSeededCiphertextVector64,
+};
+use crate::commons::crypto::glwe::{
+    GlweCiphertext as ImplGlweCiphertext, GlweSeededList as ImplGlweSeededList,
+};
+use crate::commons::math::random::{RandomGenerable, Uniform};
+use crate::commons::numeric::UnsignedInteger;
+use crate::prelude::GlweCiphertextIndex;
+use crate::specification::engines::{
+    GlweSeededCiphertextVectorElementExpansionEngine,
+    GlweSeededCiphertextVectorElementExpansionError,
+};
+
+/// # Description:
+/// Implementation of [`GlweSeededCiphertextVectorElementExpansionEngine`] for [`DefaultEngine`]
+/// that operates on 32 bits integers.
+impl
+    GlweSeededCiphertextVectorElementExpansionEngine<GlweSeededCiphertextVector32, GlweCiphertext32>
+    for DefaultEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_core::prelude::{GlweCiphertextIndex, GlweDimension, PolynomialSize, Variance, *};
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let glwe_dimension = GlweDimension(2);
+    /// let polynomial_size = PolynomialSize(4);
+    /// let glwe_size = glwe_dimension.to_glwe_size().0 * polynomial_size.0;
+    /// // Here a hard-set encoding is applied (shift by 20 bits)
+    /// let input = vec![3_u32 << 20; 12];
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let key: GlweSecretKey32 =
+    ///     engine.generate_new_glwe_secret_key(glwe_dimension, polynomial_size)?;
+    /// let plaintext_vector: PlaintextVector32 = engine.create_plaintext_vector_from(&input)?;
+    ///
+    /// let seeded_ciphertext_vector: GlweSeededCiphertextVector32 =
+    ///     engine.encrypt_glwe_seeded_ciphertext_vector(&key, &plaintext_vector, noise)?;
+    ///
+    /// let ciphertext: GlweCiphertext32 = engine.expand_glwe_seeded_ciphertext_vector_element(
+    ///     &seeded_ciphertext_vector,
+    ///     GlweCiphertextIndex(2),
+    /// )?;
+    /// assert_eq!(ciphertext.glwe_dimension(), glwe_dimension);
+    /// assert_eq!(ciphertext.polynomial_size(), polynomial_size);
+    ///
+    /// // The expanded ciphertext is the same as the one of the fully expanded vector.
+    /// let ciphertext_vector: GlweCiphertextVector32 = engine
+    ///     .transform_glwe_seeded_ciphertext_vector_to_glwe_ciphertext_vector(
+    ///         seeded_ciphertext_vector,
+    ///     )?;
+    /// let expected = engine.consume_retrieve_glwe_ciphertext_vector(ciphertext_vector)?;
+    /// let coefficients = engine.consume_retrieve_glwe_ciphertext(ciphertext)?;
+    /// assert_eq!(coefficients[..], expected[2 * glwe_size..]);
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn expand_glwe_seeded_ciphertext_vector_element(
+        &mut self,
+        seeded_ciphertext_vector: &GlweSeededCiphertextVector32,
+        index: GlweCiphertextIndex,
+    ) -> Result<GlweCiphertext32, GlweSeededCiphertextVectorElementExpansionError<Self::EngineError>>
+    {
+        GlweSeededCiphertextVectorElementExpansionError::perform_generic_checks(
+            seeded_ciphertext_vector,
+            index,
+        )?;
+        let output = expand_element(&seeded_ciphertext_vector.0, index)
+            .map_err(GlweSeededCiphertextVectorElementExpansionError::Engine)?;
+        Ok(GlweCiphertext32(output))
+    }
+
+    unsafe fn expand_glwe_seeded_ciphertext_vector_element_unchecked(
+        &mut self,
+        seeded_ciphertext_vector: &GlweSeededCiphertextVector32,
+        index: GlweCiphertextIndex,
+    ) -> GlweCiphertext32 {
+        GlweCiphertext32(expand_element(&seeded_ciphertext_vector.0, index).unwrap())
+    }
+}
+
+/// # Description:
+/// Implementation of [`GlweSeededCiphertextVectorElementExpansionEngine`] for [`DefaultEngine`]
+/// that operates on 64 bits integers.
+impl
+    GlweSeededCiphertextVectorElementExpansionEngine<GlweSeededCiphertextVector64, GlweCiphertext64>
+    for DefaultEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_core::prelude::{GlweCiphertextIndex, GlweDimension, PolynomialSize, Variance, *};
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let glwe_dimension = GlweDimension(2);
+    /// let polynomial_size = PolynomialSize(4);
+    /// let glwe_size = glwe_dimension.to_glwe_size().0 * polynomial_size.0;
+    /// // Here a hard-set encoding is applied (shift by 50 bits)
+    /// let input = vec![3_u64 << 50; 12];
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let key: GlweSecretKey64 =
+    ///     engine.generate_new_glwe_secret_key(glwe_dimension, polynomial_size)?;
+    /// let plaintext_vector: PlaintextVector64 = engine.create_plaintext_vector_from(&input)?;
+    ///
+    /// let seeded_ciphertext_vector: GlweSeededCiphertextVector64 =
+    ///     engine.encrypt_glwe_seeded_ciphertext_vector(&key, &plaintext_vector, noise)?;
+    ///
+    /// let ciphertext: GlweCiphertext64 = engine.expand_glwe_seeded_ciphertext_vector_element(
+    ///     &seeded_ciphertext_vector,
+    ///     GlweCiphertextIndex(2),
+    /// )?;
+    /// assert_eq!(ciphertext.glwe_dimension(), glwe_dimension);
+    /// assert_eq!(ciphertext.polynomial_size(), polynomial_size);
+    ///
+    /// // The expanded ciphertext is the same as the one of the fully expanded vector.
+    /// let ciphertext_vector: GlweCiphertextVector64 = engine
+    ///     .transform_glwe_seeded_ciphertext_vector_to_glwe_ciphertext_vector(
+    ///         seeded_ciphertext_vector,
+    ///     )?;
+    /// let expected = engine.consume_retrieve_glwe_ciphertext_vector(ciphertext_vector)?;
+    /// let coefficients = engine.consume_retrieve_glwe_ciphertext(ciphertext)?;
+    /// assert_eq!(coefficients[..], expected[2 * glwe_size..]);
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn expand_glwe_seeded_ciphertext_vector_element(
+        &mut self,
+        seeded_ciphertext_vector: &GlweSeededCiphertextVector64,
+        index: GlweCiphertextIndex,
+    ) -> Result<GlweCiphertext64, GlweSeededCiphertextVectorElementExpansionError<Self::EngineError>>
+    {
+        GlweSeededCiphertextVectorElementExpansionError::perform_generic_checks(
+            seeded_ciphertext_vector,
+            index,
+        )?;
+        let output = expand_element(&seeded_ciphertext_vector.0, index)
+            .map_err(GlweSeededCiphertextVectorElementExpansionError::Engine)?;
+        Ok(GlweCiphertext64(output))
+    }
+
+    unsafe fn expand_glwe_seeded_ciphertext_vector_element_unchecked(
+        &mut self,
+        seeded_ciphertext_vector: &GlweSeededCiphertextVector64,
+        index: GlweCiphertextIndex,
+    ) -> GlweCiphertext64 {
+        GlweCiphertext64(expand_element(&seeded_ciphertext_vector.0, index).unwrap())
+    }
+}
+
+/// Expands the `index`-th ciphertext of `seeded_list`.
+fn expand_element<Scalar>(
+    seeded_list: &ImplGlweSeededList<Vec<Scalar>>,
+    index: GlweCiphertextIndex,
+) -> Result<ImplGlweCiphertext<Vec<Scalar>>, DefaultError>
+where
+    Scalar: UnsignedInteger + RandomGenerable<Uniform>,
+{
+    let mut ciphertext = ImplGlweCiphertext::allocate(
+        Scalar::ZERO,
+        seeded_list.polynomial_size(),
+        seeded_list.glwe_size(),
+    );
+    seeded_list
+        .expand_ciphertext_into::<_, _, ActivatedRandomGenerator>(index.0, &mut ciphertext)
+        .map_err(|_| DefaultError::RandomGeneratorSkipTooLarge)?;
+    Ok(ciphertext)
+}
@@ -0,0 +1,199 @@
+use super::ActivatedRandomGenerator;
+use crate::backends::default::engines::{DefaultEngine, DefaultError};
+use crate::backends::default::entities::{
+    GlweCiphertextVector32, GlweCiphertextVector64, GlweSeededCiphertextVector32,
+    GlweSeededCiphertextVector64,
+};
+use crate::commons::crypto::glwe::{
+    GlweList as ImplGlweList, GlweSeededList as ImplGlweSeededList,
+};
+use crate::commons::math::random::{RandomGenerable, Uniform};
+use crate::commons::numeric::UnsignedInteger;
+use crate::prelude::{CiphertextCount, GlweCiphertextRange};
+use crate::specification::engines::{
+    GlweSeededCiphertextVectorRangeExpansionEngine, GlweSeededCiphertextVectorRangeExpansionError,
+};
+
+/// # Description:
+/// Implementation of [`GlweSeededCiphertextVectorRangeExpansionEngine`] for [`DefaultEngine`] that
+/// operates on 32 bits integers.
+impl
+    GlweSeededCiphertextVectorRangeExpansionEngine<
+        GlweSeededCiphertextVector32,
+        GlweCiphertextVector32,
+    > for DefaultEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_core::prelude::{GlweCiphertextRange, GlweDimension, PolynomialSize, Variance, *};
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let glwe_dimension = GlweDimension(2);
+    /// let polynomial_size = PolynomialSize(4);
+    /// let glwe_size = glwe_dimension.to_glwe_size().0 * polynomial_size.0;
+    /// // Here a hard-set encoding is applied (shift by 20 bits)
+    /// let input = vec![3_u32 << 20; 12];
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let key: GlweSecretKey32 =
+    ///     engine.generate_new_glwe_secret_key(glwe_dimension, polynomial_size)?;
+    /// let plaintext_vector: PlaintextVector32 = engine.create_plaintext_vector_from(&input)?;
+    ///
+    /// let seeded_ciphertext_vector: GlweSeededCiphertextVector32 =
+    ///     engine.encrypt_glwe_seeded_ciphertext_vector(&key, &plaintext_vector, noise)?;
+    ///
+    /// let ciphertext_vector: GlweCiphertextVector32 = engine
+    ///     .expand_glwe_seeded_ciphertext_vector_range(
+    ///         &seeded_ciphertext_vector,
+    ///         GlweCiphertextRange(1, 2),
+    ///     )?;
+    /// assert_eq!(
+    ///     ciphertext_vector.glwe_ciphertext_count(),
+    ///     GlweCiphertextCount(2)
+    /// );
+    ///
+    /// // The expanded ciphertexts are the same as the ones of the fully expanded vector.
+    /// let full_ciphertext_vector: GlweCiphertextVector32 = engine
+    ///     .transform_glwe_seeded_ciphertext_vector_to_glwe_ciphertext_vector(
+    ///         seeded_ciphertext_vector,
+    ///     )?;
+    /// let expected = engine.consume_retrieve_glwe_ciphertext_vector(full_ciphertext_vector)?;
+    /// let coefficients = engine.consume_retrieve_glwe_ciphertext_vector(ciphertext_vector)?;
+    /// assert_eq!(coefficients[..], expected[glwe_size..]);
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn expand_glwe_seeded_ciphertext_vector_range(
+        &mut self,
+        seeded_ciphertext_vector: &GlweSeededCiphertextVector32,
+        range: GlweCiphertextRange,
+    ) -> Result<
+        GlweCiphertextVector32,
+        GlweSeededCiphertextVectorRangeExpansionError<Self::EngineError>,
+    > {
+        GlweSeededCiphertextVectorRangeExpansionError::perform_generic_checks(
+            seeded_ciphertext_vector,
+            range,
+        )?;
+        let output = expand_range(&seeded_ciphertext_vector.0, range)
+            .map_err(GlweSeededCiphertextVectorRangeExpansionError::Engine)?;
+        Ok(GlweCiphertextVector32(output))
+    }
+
+    unsafe fn expand_glwe_seeded_ciphertext_vector_range_unchecked(
+        &mut self,
+        seeded_ciphertext_vector: &GlweSeededCiphertextVector32,
+        range: GlweCiphertextRange,
+    ) -> GlweCiphertextVector32 {
+        GlweCiphertextVector32(expand_range(&seeded_ciphertext_vector.0, range).unwrap())
+    }
+}
+
+/// # Description:
+/// Implementation of [`GlweSeededCiphertextVectorRangeExpansionEngine`] for [`DefaultEngine`] that
+/// operates on 64 bits integers.
+impl
+    GlweSeededCiphertextVectorRangeExpansionEngine<
+        GlweSeededCiphertextVector64,
+        GlweCiphertextVector64,
+    > for DefaultEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_core::prelude::{GlweCiphertextRange, GlweDimension, PolynomialSize, Variance, *};
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let glwe_dimension = GlweDimension(2);
+    /// let polynomial_size = PolynomialSize(4);
+    /// let glwe_size = glwe_dimension.to_glwe_size().0 * polynomial_size.0;
+    /// // Here a hard-set encoding is applied (shift by 50 bits)
+    /// let input = vec![3_u64 << 50; 12];
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let key: GlweSecretKey64 =
+    ///     engine.generate_new_glwe_secret_key(glwe_dimension, polynomial_size)?;
+    /// let plaintext_vector: PlaintextVector64 = engine.create_plaintext_vector_from(&input)?;
+    ///
+    /// let seeded_ciphertext_vector: GlweSeededCiphertextVector64 =
+    ///     engine.encrypt_glwe_seeded_ciphertext_vector(&key, &plaintext_vector, noise)?;
+    ///
+    /// let ciphertext_vector: GlweCiphertextVector64 = engine
+    ///     .expand_glwe_seeded_ciphertext_vector_range(
+    ///         &seeded_ciphertext_vector,
+    ///         GlweCiphertextRange(1, 2),
+    ///     )?;
+    /// assert_eq!(
+    ///     ciphertext_vector.glwe_ciphertext_count(),
+    ///     GlweCiphertextCount(2)
+    /// );
+    ///
+    /// // The expanded ciphertexts are the same as the ones of the fully expanded vector.
+    /// let full_ciphertext_vector: GlweCiphertextVector64 = engine
+    ///     .transform_glwe_seeded_ciphertext_vector_to_glwe_ciphertext_vector(
+    ///         seeded_ciphertext_vector,
+    ///     )?;
+    /// let expected = engine.consume_retrieve_glwe_ciphertext_vector(full_ciphertext_vector)?;
+    /// let coefficients = engine.consume_retrieve_glwe_ciphertext_vector(ciphertext_vector)?;
+    /// assert_eq!(coefficients[..], expected[glwe_size..]);
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn expand_glwe_seeded_ciphertext_vector_range(
+        &mut self,
+        seeded_ciphertext_vector: &GlweSeededCiphertextVector64,
+        range: GlweCiphertextRange,
+    ) -> Result<
+        GlweCiphertextVector64,
+        GlweSeededCiphertextVectorRangeExpansionError<Self::EngineError>,
+    > {
+        GlweSeededCiphertextVectorRangeExpansionError::perform_generic_checks(
+            seeded_ciphertext_vector,
+            range,
+        )?;
+        let output = expand_range(&seeded_ciphertext_vector.0, range)
+            .map_err(GlweSeededCiphertextVectorRangeExpansionError::Engine)?;
+        Ok(GlweCiphertextVector64(output))
+    }
+
+    unsafe fn expand_glwe_seeded_ciphertext_vector_range_unchecked(
+        &mut self,
+        seeded_ciphertext_vector: &GlweSeededCiphertextVector64,
+        range: GlweCiphertextRange,
+    ) -> GlweCiphertextVector64 {
+        GlweCiphertextVector64(expand_range(&seeded_ciphertext_vector.0, range).unwrap())
+    }
+}
+
+/// Expands the ciphertexts of `seeded_list` contained in `range`.
+fn expand_range<Scalar>(
+    seeded_list: &ImplGlweSeededList<Vec<Scalar>>,
+    range: GlweCiphertextRange,
+) -> Result<ImplGlweList<Vec<Scalar>>, DefaultError>
+where
+    Scalar: UnsignedInteger + RandomGenerable<Uniform>,
+{
+    let mut ciphertext_vector = ImplGlweList::allocate(
+        Scalar::ZERO,
+        seeded_list.polynomial_size(),
+        seeded_list.glwe_dimension(),
+        CiphertextCount(range.1 - range.0 + 1),
+    );
+    seeded_list
+        .expand_sub_list_into::<_, _, ActivatedRandomGenerator>(range.0, &mut ciphertext_vector)
+        .map_err(|_| DefaultError::RandomGeneratorSkipTooLarge)?;
+    Ok(ciphertext_vector)
+}
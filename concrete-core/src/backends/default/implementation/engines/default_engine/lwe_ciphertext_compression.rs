@@ -0,0 +1,137 @@
+use crate::backends::default::implementation::engines::DefaultEngine;
+use crate::backends::default::implementation::entities::{
+    LweCiphertext32, LweCiphertext64, LweCompressedCiphertext32, LweCompressedCiphertext64,
+};
+use crate::commons::crypto::lwe::LweCompressedCiphertext as ImplLweCompressedCiphertext;
+use crate::prelude::CiphertextModulusLog;
+use crate::specification::engines::{
+    LweCiphertextCompressionEngine, LweCiphertextCompressionError,
+};
+
+/// # Description:
+/// Implementation of [`LweCiphertextCompressionEngine`] for [`DefaultEngine`] that operates on
+/// 32 bits integers.
+impl LweCiphertextCompressionEngine<LweCiphertext32, LweCompressedCiphertext32> for DefaultEngine {
+    /// # Example:
+    /// ```
+    /// use concrete_core::prelude::{CiphertextModulusLog, LweDimension, Variance, *};
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let lwe_dimension = LweDimension(2);
+    /// // Here a hard-set encoding is applied (shift by 20 bits)
+    /// let input = 3_u32 << 20;
+    /// let noise = Variance(2_f64.powf(-50.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let key: LweSecretKey32 = engine.generate_new_lwe_secret_key(lwe_dimension)?;
+    /// let plaintext = engine.create_plaintext_from(&input)?;
+    /// let ciphertext = engine.encrypt_lwe_ciphertext(&key, &plaintext, noise)?;
+    ///
+    /// // Only the 16 most significant bits of each coefficient are kept.
+    /// let compressed: LweCompressedCiphertext32 =
+    ///     engine.compress_lwe_ciphertext(&ciphertext, CiphertextModulusLog(16))?;
+    /// #
+    /// assert_eq!(compressed.lwe_dimension(), lwe_dimension);
+    /// assert_eq!(
+    ///     compressed.ciphertext_modulus_log(),
+    ///     CiphertextModulusLog(16)
+    /// );
+    ///
+    /// let decompressed: LweCiphertext32 = engine.decompress_lwe_ciphertext(&compressed)?;
+    /// let output = engine.decrypt_lwe_ciphertext(&key, &decompressed)?;
+    /// let output = engine.retrieve_plaintext(&output)?;
+    /// assert_eq!((output.wrapping_add(1 << 19)) >> 20, 3);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn compress_lwe_ciphertext(
+        &mut self,
+        input: &LweCiphertext32,
+        ciphertext_modulus_log: CiphertextModulusLog,
+    ) -> Result<LweCompressedCiphertext32, LweCiphertextCompressionError<Self::EngineError>> {
+        LweCiphertextCompressionError::perform_generic_checks(ciphertext_modulus_log, 32)?;
+        Ok(unsafe { self.compress_lwe_ciphertext_unchecked(input, ciphertext_modulus_log) })
+    }
+
+    unsafe fn compress_lwe_ciphertext_unchecked(
+        &mut self,
+        input: &LweCiphertext32,
+        ciphertext_modulus_log: CiphertextModulusLog,
+    ) -> LweCompressedCiphertext32 {
+        LweCompressedCiphertext32(ImplLweCompressedCiphertext::compress(
+            &input.0,
+            ciphertext_modulus_log,
+        ))
+    }
+}
+
+/// # Description:
+/// Implementation of [`LweCiphertextCompressionEngine`] for [`DefaultEngine`] that operates on
+/// 64 bits integers.
+impl LweCiphertextCompressionEngine<LweCiphertext64, LweCompressedCiphertext64> for DefaultEngine {
+    /// # Example:
+    /// ```
+    /// use concrete_core::prelude::{CiphertextModulusLog, LweDimension, Variance, *};
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let lwe_dimension = LweDimension(2);
+    /// // Here a hard-set encoding is applied (shift by 50 bits)
+    /// let input = 3_u64 << 50;
+    /// let noise = Variance(2_f64.powf(-50.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let key: LweSecretKey64 = engine.generate_new_lwe_secret_key(lwe_dimension)?;
+    /// let plaintext = engine.create_plaintext_from(&input)?;
+    /// let ciphertext = engine.encrypt_lwe_ciphertext(&key, &plaintext, noise)?;
+    ///
+    /// // Only the 16 most significant bits of each coefficient are kept.
+    /// let compressed: LweCompressedCiphertext64 =
+    ///     engine.compress_lwe_ciphertext(&ciphertext, CiphertextModulusLog(16))?;
+    /// #
+    /// assert_eq!(compressed.lwe_dimension(), lwe_dimension);
+    /// assert_eq!(
+    ///     compressed.ciphertext_modulus_log(),
+    ///     CiphertextModulusLog(16)
+    /// );
+    ///
+    /// let decompressed: LweCiphertext64 = engine.decompress_lwe_ciphertext(&compressed)?;
+    /// let output = engine.decrypt_lwe_ciphertext(&key, &decompressed)?;
+    /// let output = engine.retrieve_plaintext(&output)?;
+    /// assert_eq!((output.wrapping_add(1 << 49)) >> 50, 3);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn compress_lwe_ciphertext(
+        &mut self,
+        input: &LweCiphertext64,
+        ciphertext_modulus_log: CiphertextModulusLog,
+    ) -> Result<LweCompressedCiphertext64, LweCiphertextCompressionError<Self::EngineError>> {
+        LweCiphertextCompressionError::perform_generic_checks(ciphertext_modulus_log, 64)?;
+        Ok(unsafe { self.compress_lwe_ciphertext_unchecked(input, ciphertext_modulus_log) })
+    }
+
+    unsafe fn compress_lwe_ciphertext_unchecked(
+        &mut self,
+        input: &LweCiphertext64,
+        ciphertext_modulus_log: CiphertextModulusLog,
+    ) -> LweCompressedCiphertext64 {
+        LweCompressedCiphertext64(ImplLweCompressedCiphertext::compress(
+            &input.0,
+            ciphertext_modulus_log,
+        ))
+    }
+}
@@ -0,0 +1,124 @@
+use crate::backends::default::implementation::engines::DefaultEngine;
+use crate::backends::default::implementation::entities::{
+    LweCiphertext32, LweCiphertext64, LweCompressedCiphertext32, LweCompressedCiphertext64,
+};
+use crate::commons::crypto::lwe::LweCiphertext as ImplLweCiphertext;
+use crate::specification::engines::{
+    LweCiphertextDecompressionEngine, LweCiphertextDecompressionError,
+};
+
+/// # Description:
+/// Implementation of [`LweCiphertextDecompressionEngine`] for [`DefaultEngine`] that operates on
+/// 32 bits integers.
+impl LweCiphertextDecompressionEngine<LweCompressedCiphertext32, LweCiphertext32>
+    for DefaultEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_core::prelude::{CiphertextModulusLog, LweDimension, Variance, *};
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let lwe_dimension = LweDimension(2);
+    /// // Here a hard-set encoding is applied (shift by 20 bits)
+    /// let input = 3_u32 << 20;
+    /// let noise = Variance(2_f64.powf(-50.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let key: LweSecretKey32 = engine.generate_new_lwe_secret_key(lwe_dimension)?;
+    /// let plaintext = engine.create_plaintext_from(&input)?;
+    /// let ciphertext = engine.encrypt_lwe_ciphertext(&key, &plaintext, noise)?;
+    /// let compressed: LweCompressedCiphertext32 =
+    ///     engine.compress_lwe_ciphertext(&ciphertext, CiphertextModulusLog(16))?;
+    ///
+    /// let decompressed: LweCiphertext32 = engine.decompress_lwe_ciphertext(&compressed)?;
+    /// #
+    /// assert_eq!(decompressed.lwe_dimension(), lwe_dimension);
+    ///
+    /// // The message is recovered after rounding away the noise and the compression error.
+    /// let decrypted = engine.decrypt_lwe_ciphertext(&key, &decompressed)?;
+    /// let output = engine.retrieve_plaintext(&decrypted)?;
+    /// assert_eq!(output.wrapping_add(1 << 19) >> 20, 3);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn decompress_lwe_ciphertext(
+        &mut self,
+        input: &LweCompressedCiphertext32,
+    ) -> Result<LweCiphertext32, LweCiphertextDecompressionError<Self::EngineError>> {
+        Ok(unsafe { self.decompress_lwe_ciphertext_unchecked(input) })
+    }
+
+    unsafe fn decompress_lwe_ciphertext_unchecked(
+        &mut self,
+        input: &LweCompressedCiphertext32,
+    ) -> LweCiphertext32 {
+        let mut output = ImplLweCiphertext::allocate(0_u32, input.0.lwe_size());
+        input.0.decompress_into(&mut output);
+        LweCiphertext32(output)
+    }
+}
+
+/// # Description:
+/// Implementation of [`LweCiphertextDecompressionEngine`] for [`DefaultEngine`] that operates on
+/// 64 bits integers.
+impl LweCiphertextDecompressionEngine<LweCompressedCiphertext64, LweCiphertext64>
+    for DefaultEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_core::prelude::{CiphertextModulusLog, LweDimension, Variance, *};
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let lwe_dimension = LweDimension(2);
+    /// // Here a hard-set encoding is applied (shift by 50 bits)
+    /// let input = 3_u64 << 50;
+    /// let noise = Variance(2_f64.powf(-50.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let key: LweSecretKey64 = engine.generate_new_lwe_secret_key(lwe_dimension)?;
+    /// let plaintext = engine.create_plaintext_from(&input)?;
+    /// let ciphertext = engine.encrypt_lwe_ciphertext(&key, &plaintext, noise)?;
+    /// let compressed: LweCompressedCiphertext64 =
+    ///     engine.compress_lwe_ciphertext(&ciphertext, CiphertextModulusLog(16))?;
+    ///
+    /// let decompressed: LweCiphertext64 = engine.decompress_lwe_ciphertext(&compressed)?;
+    /// #
+    /// assert_eq!(decompressed.lwe_dimension(), lwe_dimension);
+    ///
+    /// // The message is recovered after rounding away the noise and the compression error.
+    /// let decrypted = engine.decrypt_lwe_ciphertext(&key, &decompressed)?;
+    /// let output = engine.retrieve_plaintext(&decrypted)?;
+    /// assert_eq!(output.wrapping_add(1 << 49) >> 50, 3);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn decompress_lwe_ciphertext(
+        &mut self,
+        input: &LweCompressedCiphertext64,
+    ) -> Result<LweCiphertext64, LweCiphertextDecompressionError<Self::EngineError>> {
+        Ok(unsafe { self.decompress_lwe_ciphertext_unchecked(input) })
+    }
+
+    unsafe fn decompress_lwe_ciphertext_unchecked(
+        &mut self,
+        input: &LweCompressedCiphertext64,
+    ) -> LweCiphertext64 {
+        let mut output = ImplLweCiphertext::allocate(0_u64, input.0.lwe_size());
+        input.0.decompress_into(&mut output);
+        LweCiphertext64(output)
+    }
+}
@@ -2,6 +2,7 @@ use crate::backends::default::implementation::engines::DefaultEngine;
 use crate::backends::default::implementation::entities::{
     LweCiphertext32, LweCiphertext64, LweCiphertextMutView32, LweCiphertextMutView64,
     LweCiphertextView32, LweCiphertextView64, LweKeyswitchKey32, LweKeyswitchKey64,
+    LweKeyswitchKeyView32, LweKeyswitchKeyView64,
 };
 use crate::specification::engines::{
     LweCiphertextDiscardingKeyswitchEngine, LweCiphertextDiscardingKeyswitchError,
@@ -314,3 +315,175 @@ impl
         ksk.0.keyswitch_ciphertext(&mut output.0, &input.0);
     }
 }
+
+/// # Description:
+/// Implementation of [`LweCiphertextDiscardingKeyswitchEngine`] for [`DefaultEngine`] that operates
+/// on 32 bits integers, using a view to a keyswitch key.
+///
+/// The keyswitch key view can be created without copy from a memory-mapped file, using an
+/// [`EntityMappedDeserializationEngine`](`crate::specification::engines::EntityMappedDeserializationEngine`).
+impl
+    LweCiphertextDiscardingKeyswitchEngine<
+        LweKeyswitchKeyView32<'_>,
+        LweCiphertext32,
+        LweCiphertext32,
+    > for DefaultEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_core::prelude::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, LweDimension, Variance, *,
+    /// };
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let input_lwe_dimension = LweDimension(6);
+    /// let output_lwe_dimension = LweDimension(3);
+    /// let decomposition_level_count = DecompositionLevelCount(2);
+    /// let decomposition_base_log = DecompositionBaseLog(8);
+    /// let noise = Variance(2_f64.powf(-25.));
+    /// // Here a hard-set encoding is applied (shift by 20 bits)
+    /// let input = 3_u32 << 20;
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let input_key: LweSecretKey32 = engine.generate_new_lwe_secret_key(input_lwe_dimension)?;
+    /// let output_key: LweSecretKey32 = engine.generate_new_lwe_secret_key(output_lwe_dimension)?;
+    /// let keyswitch_key = engine.generate_new_lwe_keyswitch_key(
+    ///     &input_key,
+    ///     &output_key,
+    ///     decomposition_level_count,
+    ///     decomposition_base_log,
+    ///     noise,
+    /// )?;
+    ///
+    /// // Get the keyswitch key as a view
+    /// let raw_keyswitch_key = engine.consume_retrieve_lwe_keyswitch_key(keyswitch_key)?;
+    /// let keyswitch_key: LweKeyswitchKeyView32 = engine.create_lwe_keyswitch_key_from(
+    ///     &raw_keyswitch_key[..],
+    ///     output_lwe_dimension,
+    ///     decomposition_base_log,
+    ///     decomposition_level_count,
+    /// )?;
+    ///
+    /// let plaintext = engine.create_plaintext_from(&input)?;
+    /// let ciphertext_1 = engine.encrypt_lwe_ciphertext(&input_key, &plaintext, noise)?;
+    /// let mut ciphertext_2 = engine.zero_encrypt_lwe_ciphertext(&output_key, noise)?;
+    ///
+    /// engine.discard_keyswitch_lwe_ciphertext(&mut ciphertext_2, &ciphertext_1, &keyswitch_key)?;
+    /// #
+    /// assert_eq!(ciphertext_2.lwe_dimension(), output_lwe_dimension);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn discard_keyswitch_lwe_ciphertext(
+        &mut self,
+        output: &mut LweCiphertext32,
+        input: &LweCiphertext32,
+        ksk: &LweKeyswitchKeyView32<'_>,
+    ) -> Result<(), LweCiphertextDiscardingKeyswitchError<Self::EngineError>> {
+        LweCiphertextDiscardingKeyswitchError::perform_generic_checks(output, input, ksk)?;
+        unsafe { self.discard_keyswitch_lwe_ciphertext_unchecked(output, input, ksk) };
+        Ok(())
+    }
+
+    unsafe fn discard_keyswitch_lwe_ciphertext_unchecked(
+        &mut self,
+        output: &mut LweCiphertext32,
+        input: &LweCiphertext32,
+        ksk: &LweKeyswitchKeyView32<'_>,
+    ) {
+        ksk.0.keyswitch_ciphertext(&mut output.0, &input.0);
+    }
+}
+
+/// # Description:
+/// Implementation of [`LweCiphertextDiscardingKeyswitchEngine`] for [`DefaultEngine`] that operates
+/// on 64 bits integers, using a view to a keyswitch key.
+///
+/// The keyswitch key view can be created without copy from a memory-mapped file, using an
+/// [`EntityMappedDeserializationEngine`](`crate::specification::engines::EntityMappedDeserializationEngine`).
+impl
+    LweCiphertextDiscardingKeyswitchEngine<
+        LweKeyswitchKeyView64<'_>,
+        LweCiphertext64,
+        LweCiphertext64,
+    > for DefaultEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_core::prelude::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, LweDimension, Variance, *,
+    /// };
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let input_lwe_dimension = LweDimension(6);
+    /// let output_lwe_dimension = LweDimension(3);
+    /// let decomposition_level_count = DecompositionLevelCount(2);
+    /// let decomposition_base_log = DecompositionBaseLog(8);
+    /// let noise = Variance(2_f64.powf(-50.));
+    /// // Here a hard-set encoding is applied (shift by 50 bits)
+    /// let input = 3_u64 << 50;
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let input_key: LweSecretKey64 = engine.generate_new_lwe_secret_key(input_lwe_dimension)?;
+    /// let output_key: LweSecretKey64 = engine.generate_new_lwe_secret_key(output_lwe_dimension)?;
+    /// let keyswitch_key = engine.generate_new_lwe_keyswitch_key(
+    ///     &input_key,
+    ///     &output_key,
+    ///     decomposition_level_count,
+    ///     decomposition_base_log,
+    ///     noise,
+    /// )?;
+    ///
+    /// // Get the keyswitch key as a view
+    /// let raw_keyswitch_key = engine.consume_retrieve_lwe_keyswitch_key(keyswitch_key)?;
+    /// let keyswitch_key: LweKeyswitchKeyView64 = engine.create_lwe_keyswitch_key_from(
+    ///     &raw_keyswitch_key[..],
+    ///     output_lwe_dimension,
+    ///     decomposition_base_log,
+    ///     decomposition_level_count,
+    /// )?;
+    ///
+    /// let plaintext = engine.create_plaintext_from(&input)?;
+    /// let ciphertext_1 = engine.encrypt_lwe_ciphertext(&input_key, &plaintext, noise)?;
+    /// let mut ciphertext_2 = engine.zero_encrypt_lwe_ciphertext(&output_key, noise)?;
+    ///
+    /// engine.discard_keyswitch_lwe_ciphertext(&mut ciphertext_2, &ciphertext_1, &keyswitch_key)?;
+    /// #
+    /// assert_eq!(ciphertext_2.lwe_dimension(), output_lwe_dimension);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn discard_keyswitch_lwe_ciphertext(
+        &mut self,
+        output: &mut LweCiphertext64,
+        input: &LweCiphertext64,
+        ksk: &LweKeyswitchKeyView64<'_>,
+    ) -> Result<(), LweCiphertextDiscardingKeyswitchError<Self::EngineError>> {
+        LweCiphertextDiscardingKeyswitchError::perform_generic_checks(output, input, ksk)?;
+        unsafe { self.discard_keyswitch_lwe_ciphertext_unchecked(output, input, ksk) };
+        Ok(())
+    }
+
+    unsafe fn discard_keyswitch_lwe_ciphertext_unchecked(
+        &mut self,
+        output: &mut LweCiphertext64,
+        input: &LweCiphertext64,
+        ksk: &LweKeyswitchKeyView64<'_>,
+    ) {
+        ksk.0.keyswitch_ciphertext(&mut output.0, &input.0);
+    }
+}
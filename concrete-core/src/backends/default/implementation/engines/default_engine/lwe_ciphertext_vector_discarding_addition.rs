@@ -1,6 +1,7 @@
 use crate::backends::default::implementation::engines::DefaultEngine;
 use crate::backends::default::implementation::entities::{
-    LweCiphertextVector32, LweCiphertextVector64,
+    LweCiphertextVector32, LweCiphertextVector64, LweCiphertextVectorMutView64,
+    LweCiphertextVectorView64,
 };
 use crate::commons::math::tensor::{AsMutTensor, AsRefTensor};
 use crate::specification::engines::{
@@ -146,3 +147,85 @@ impl LweCiphertextVectorDiscardingAdditionEngine<LweCiphertextVector64, LweCiphe
         }
     }
 }
+
+/// # Description:
+/// Implementation of [`LweCiphertextVectorDiscardingAdditionEngine`] for [`DefaultEngine`]
+/// that operates on views containing 64 bits integers.
+impl
+    LweCiphertextVectorDiscardingAdditionEngine<
+        LweCiphertextVectorView64<'_>,
+        LweCiphertextVectorMutView64<'_>,
+    > for DefaultEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_core::prelude::{LweDimension, LweSize, Variance, *};
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let lwe_dimension = LweDimension(2);
+    /// // Here a hard-set encoding is applied (shift by 50 bits)
+    /// let input_vector = vec![3_u64 << 50; 8];
+    /// let noise = Variance::from_variance(2_f64.powf(-25.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let key: LweSecretKey64 = engine.generate_new_lwe_secret_key(lwe_dimension)?;
+    /// let plaintext_vector: PlaintextVector64 = engine.create_plaintext_vector_from(&input_vector)?;
+    ///
+    /// let mut input_container = vec![0_u64; lwe_dimension.to_lwe_size().0 * input_vector.len()];
+    /// let mut input: LweCiphertextVectorMutView64 = engine
+    ///     .create_lwe_ciphertext_vector_from(&mut input_container[..], lwe_dimension.to_lwe_size())?;
+    /// engine.discard_encrypt_lwe_ciphertext_vector(&key, &mut input, &plaintext_vector, noise)?;
+    ///
+    /// // Convert MutView to View
+    /// let raw_input = engine.consume_retrieve_lwe_ciphertext_vector(input)?;
+    /// let input: LweCiphertextVectorView64 =
+    ///     engine.create_lwe_ciphertext_vector_from(&raw_input[..], lwe_dimension.to_lwe_size())?;
+    ///
+    /// let mut output_container = vec![0_u64; lwe_dimension.to_lwe_size().0 * input_vector.len()];
+    /// let mut output: LweCiphertextVectorMutView64 = engine.create_lwe_ciphertext_vector_from(
+    ///     &mut output_container[..],
+    ///     lwe_dimension.to_lwe_size(),
+    /// )?;
+    ///
+    /// engine.discard_add_lwe_ciphertext_vector(&mut output, &input, &input)?;
+    /// #
+    /// assert_eq!(output.lwe_dimension(), lwe_dimension);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn discard_add_lwe_ciphertext_vector(
+        &mut self,
+        output: &mut LweCiphertextVectorMutView64,
+        input_1: &LweCiphertextVectorView64,
+        input_2: &LweCiphertextVectorView64,
+    ) -> Result<(), LweCiphertextVectorDiscardingAdditionError<Self::EngineError>> {
+        LweCiphertextVectorDiscardingAdditionError::perform_generic_checks(
+            output, input_1, input_2,
+        )?;
+        unsafe { self.discard_add_lwe_ciphertext_vector_unchecked(output, input_1, input_2) };
+        Ok(())
+    }
+
+    unsafe fn discard_add_lwe_ciphertext_vector_unchecked(
+        &mut self,
+        output: &mut LweCiphertextVectorMutView64,
+        input_1: &LweCiphertextVectorView64,
+        input_2: &LweCiphertextVectorView64,
+    ) {
+        for (mut out, (in_1, in_2)) in output
+            .0
+            .ciphertext_iter_mut()
+            .zip(input_1.0.ciphertext_iter().zip(input_2.0.ciphertext_iter()))
+        {
+            out.as_mut_tensor().fill_with_copy(in_1.as_tensor());
+            out.update_with_add(&in_2);
+        }
+    }
+}
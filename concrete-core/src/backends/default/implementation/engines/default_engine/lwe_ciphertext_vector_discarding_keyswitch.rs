@@ -0,0 +1,279 @@
+use crate::backends::default::implementation::engines::DefaultEngine;
+use crate::backends::default::implementation::entities::{
+    LweCiphertextVector32, LweCiphertextVector64, LweCiphertextVectorMutView64,
+    LweCiphertextVectorView64, LweKeyswitchKey32, LweKeyswitchKey64,
+};
+use crate::specification::engines::{
+    LweCiphertextVectorDiscardingKeyswitchEngine, LweCiphertextVectorDiscardingKeyswitchError,
+};
+
+/// # Description:
+/// Implementation of [`LweCiphertextVectorDiscardingKeyswitchEngine`] for [`DefaultEngine`]
+/// that operates on 32 bits integers.
+impl
+    LweCiphertextVectorDiscardingKeyswitchEngine<
+        LweKeyswitchKey32,
+        LweCiphertextVector32,
+        LweCiphertextVector32,
+    > for DefaultEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_core::prelude::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, LweCiphertextCount, LweDimension, Variance,
+    ///     *,
+    /// };
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let input_lwe_dimension = LweDimension(6);
+    /// let output_lwe_dimension = LweDimension(3);
+    /// let decomposition_level_count = DecompositionLevelCount(2);
+    /// let decomposition_base_log = DecompositionBaseLog(8);
+    /// let noise = Variance(2_f64.powf(-25.));
+    /// // Here a hard-set encoding is applied (shift by 20 bits)
+    /// let input_vector = vec![3_u32 << 20; 8];
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let input_key: LweSecretKey32 = engine.generate_new_lwe_secret_key(input_lwe_dimension)?;
+    /// let output_key: LweSecretKey32 = engine.generate_new_lwe_secret_key(output_lwe_dimension)?;
+    /// let keyswitch_key = engine.generate_new_lwe_keyswitch_key(
+    ///     &input_key,
+    ///     &output_key,
+    ///     decomposition_level_count,
+    ///     decomposition_base_log,
+    ///     noise,
+    /// )?;
+    /// let plaintext_vector = engine.create_plaintext_vector_from(&input_vector)?;
+    /// let ciphertext_vector =
+    ///     engine.encrypt_lwe_ciphertext_vector(&input_key, &plaintext_vector, noise)?;
+    /// let mut output_ciphertext_vector =
+    ///     engine.zero_encrypt_lwe_ciphertext_vector(&output_key, noise, LweCiphertextCount(8))?;
+    ///
+    /// engine.discard_keyswitch_lwe_ciphertext_vector(
+    ///     &mut output_ciphertext_vector,
+    ///     &ciphertext_vector,
+    ///     &keyswitch_key,
+    /// )?;
+    /// #
+    /// assert_eq!(
+    ///     output_ciphertext_vector.lwe_dimension(),
+    ///     output_lwe_dimension
+    /// );
+    /// assert_eq!(
+    ///     output_ciphertext_vector.lwe_ciphertext_count(),
+    ///     LweCiphertextCount(8)
+    /// );
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn discard_keyswitch_lwe_ciphertext_vector(
+        &mut self,
+        output: &mut LweCiphertextVector32,
+        input: &LweCiphertextVector32,
+        ksk: &LweKeyswitchKey32,
+    ) -> Result<(), LweCiphertextVectorDiscardingKeyswitchError<Self::EngineError>> {
+        LweCiphertextVectorDiscardingKeyswitchError::perform_generic_checks(output, input, ksk)?;
+        unsafe { self.discard_keyswitch_lwe_ciphertext_vector_unchecked(output, input, ksk) };
+        Ok(())
+    }
+
+    unsafe fn discard_keyswitch_lwe_ciphertext_vector_unchecked(
+        &mut self,
+        output: &mut LweCiphertextVector32,
+        input: &LweCiphertextVector32,
+        ksk: &LweKeyswitchKey32,
+    ) {
+        ksk.0.keyswitch_list(&mut output.0, &input.0);
+    }
+}
+
+/// # Description:
+/// Implementation of [`LweCiphertextVectorDiscardingKeyswitchEngine`] for [`DefaultEngine`]
+/// that operates on 64 bits integers.
+impl
+    LweCiphertextVectorDiscardingKeyswitchEngine<
+        LweKeyswitchKey64,
+        LweCiphertextVector64,
+        LweCiphertextVector64,
+    > for DefaultEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_core::prelude::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, LweCiphertextCount, LweDimension, Variance,
+    ///     *,
+    /// };
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let input_lwe_dimension = LweDimension(6);
+    /// let output_lwe_dimension = LweDimension(3);
+    /// let decomposition_level_count = DecompositionLevelCount(2);
+    /// let decomposition_base_log = DecompositionBaseLog(8);
+    /// let noise = Variance(2_f64.powf(-50.));
+    /// // Here a hard-set encoding is applied (shift by 50 bits)
+    /// let input_vector = vec![3_u64 << 50; 8];
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let input_key: LweSecretKey64 = engine.generate_new_lwe_secret_key(input_lwe_dimension)?;
+    /// let output_key: LweSecretKey64 = engine.generate_new_lwe_secret_key(output_lwe_dimension)?;
+    /// let keyswitch_key = engine.generate_new_lwe_keyswitch_key(
+    ///     &input_key,
+    ///     &output_key,
+    ///     decomposition_level_count,
+    ///     decomposition_base_log,
+    ///     noise,
+    /// )?;
+    /// let plaintext_vector = engine.create_plaintext_vector_from(&input_vector)?;
+    /// let ciphertext_vector =
+    ///     engine.encrypt_lwe_ciphertext_vector(&input_key, &plaintext_vector, noise)?;
+    /// let mut output_ciphertext_vector =
+    ///     engine.zero_encrypt_lwe_ciphertext_vector(&output_key, noise, LweCiphertextCount(8))?;
+    ///
+    /// engine.discard_keyswitch_lwe_ciphertext_vector(
+    ///     &mut output_ciphertext_vector,
+    ///     &ciphertext_vector,
+    ///     &keyswitch_key,
+    /// )?;
+    /// #
+    /// assert_eq!(
+    ///     output_ciphertext_vector.lwe_dimension(),
+    ///     output_lwe_dimension
+    /// );
+    /// assert_eq!(
+    ///     output_ciphertext_vector.lwe_ciphertext_count(),
+    ///     LweCiphertextCount(8)
+    /// );
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn discard_keyswitch_lwe_ciphertext_vector(
+        &mut self,
+        output: &mut LweCiphertextVector64,
+        input: &LweCiphertextVector64,
+        ksk: &LweKeyswitchKey64,
+    ) -> Result<(), LweCiphertextVectorDiscardingKeyswitchError<Self::EngineError>> {
+        LweCiphertextVectorDiscardingKeyswitchError::perform_generic_checks(output, input, ksk)?;
+        unsafe { self.discard_keyswitch_lwe_ciphertext_vector_unchecked(output, input, ksk) };
+        Ok(())
+    }
+
+    unsafe fn discard_keyswitch_lwe_ciphertext_vector_unchecked(
+        &mut self,
+        output: &mut LweCiphertextVector64,
+        input: &LweCiphertextVector64,
+        ksk: &LweKeyswitchKey64,
+    ) {
+        ksk.0.keyswitch_list(&mut output.0, &input.0);
+    }
+}
+
+/// # Description:
+/// Implementation of [`LweCiphertextVectorDiscardingKeyswitchEngine`] for [`DefaultEngine`]
+/// that operates on views containing 64 bits integers.
+impl
+    LweCiphertextVectorDiscardingKeyswitchEngine<
+        LweKeyswitchKey64,
+        LweCiphertextVectorView64<'_>,
+        LweCiphertextVectorMutView64<'_>,
+    > for DefaultEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_core::prelude::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, LweCiphertextCount, LweDimension, Variance,
+    ///     *,
+    /// };
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let input_lwe_dimension = LweDimension(6);
+    /// let output_lwe_dimension = LweDimension(3);
+    /// let decomposition_level_count = DecompositionLevelCount(2);
+    /// let decomposition_base_log = DecompositionBaseLog(8);
+    /// let noise = Variance(2_f64.powf(-50.));
+    /// // Here a hard-set encoding is applied (shift by 50 bits)
+    /// let input_vector = vec![3_u64 << 50; 8];
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let input_key: LweSecretKey64 = engine.generate_new_lwe_secret_key(input_lwe_dimension)?;
+    /// let output_key: LweSecretKey64 = engine.generate_new_lwe_secret_key(output_lwe_dimension)?;
+    /// let keyswitch_key = engine.generate_new_lwe_keyswitch_key(
+    ///     &input_key,
+    ///     &output_key,
+    ///     decomposition_level_count,
+    ///     decomposition_base_log,
+    ///     noise,
+    /// )?;
+    /// let plaintext_vector = engine.create_plaintext_vector_from(&input_vector)?;
+    ///
+    /// let mut input_container = vec![0_u64; input_lwe_dimension.to_lwe_size().0 * input_vector.len()];
+    /// let mut input: LweCiphertextVectorMutView64 = engine.create_lwe_ciphertext_vector_from(
+    ///     &mut input_container[..],
+    ///     input_lwe_dimension.to_lwe_size(),
+    /// )?;
+    /// engine.discard_encrypt_lwe_ciphertext_vector(
+    ///     &input_key,
+    ///     &mut input,
+    ///     &plaintext_vector,
+    ///     noise,
+    /// )?;
+    ///
+    /// // Convert MutView to View
+    /// let raw_input = engine.consume_retrieve_lwe_ciphertext_vector(input)?;
+    /// let input: LweCiphertextVectorView64 = engine
+    ///     .create_lwe_ciphertext_vector_from(&raw_input[..], input_lwe_dimension.to_lwe_size())?;
+    ///
+    /// let mut output_container =
+    ///     vec![0_u64; output_lwe_dimension.to_lwe_size().0 * input_vector.len()];
+    /// let mut output: LweCiphertextVectorMutView64 = engine.create_lwe_ciphertext_vector_from(
+    ///     &mut output_container[..],
+    ///     output_lwe_dimension.to_lwe_size(),
+    /// )?;
+    ///
+    /// engine.discard_keyswitch_lwe_ciphertext_vector(&mut output, &input, &keyswitch_key)?;
+    /// #
+    /// assert_eq!(output.lwe_dimension(), output_lwe_dimension);
+    /// assert_eq!(output.lwe_ciphertext_count(), LweCiphertextCount(8));
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn discard_keyswitch_lwe_ciphertext_vector(
+        &mut self,
+        output: &mut LweCiphertextVectorMutView64,
+        input: &LweCiphertextVectorView64,
+        ksk: &LweKeyswitchKey64,
+    ) -> Result<(), LweCiphertextVectorDiscardingKeyswitchError<Self::EngineError>> {
+        LweCiphertextVectorDiscardingKeyswitchError::perform_generic_checks(output, input, ksk)?;
+        unsafe { self.discard_keyswitch_lwe_ciphertext_vector_unchecked(output, input, ksk) };
+        Ok(())
+    }
+
+    unsafe fn discard_keyswitch_lwe_ciphertext_vector_unchecked(
+        &mut self,
+        output: &mut LweCiphertextVectorMutView64,
+        input: &LweCiphertextVectorView64,
+        ksk: &LweKeyswitchKey64,
+    ) {
+        ksk.0.keyswitch_list(&mut output.0, &input.0);
+    }
+}
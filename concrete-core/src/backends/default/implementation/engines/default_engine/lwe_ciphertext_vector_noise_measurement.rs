@@ -0,0 +1,160 @@
+use crate::prelude::{NoiseStatistics, PlaintextCount};
+
+use crate::backends::default::implementation::engines::DefaultEngine;
+use crate::backends::default::implementation::entities::{
+    LweCiphertextVector32, LweCiphertextVector64, LweSecretKey32, LweSecretKey64,
+    PlaintextVector32, PlaintextVector64,
+};
+use crate::commons::crypto::encoding::PlaintextList as ImplPlaintextList;
+use crate::commons::math::tensor::AsRefTensor;
+use crate::commons::math::torus::signed_torus_distance;
+use crate::specification::engines::{
+    LweCiphertextVectorNoiseMeasurementEngine, LweCiphertextVectorNoiseMeasurementError,
+};
+use crate::specification::entities::LweCiphertextVectorEntity;
+
+/// # Description:
+/// Implementation of [`LweCiphertextVectorNoiseMeasurementEngine`] for [`DefaultEngine`] that
+/// operates on 32 bits integers.
+impl
+    LweCiphertextVectorNoiseMeasurementEngine<
+        LweSecretKey32,
+        LweCiphertextVector32,
+        PlaintextVector32,
+    > for DefaultEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_core::prelude::{LweDimension, Variance, *};
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let lwe_dimension = LweDimension(6);
+    /// // Here a hard-set encoding is applied (shift by 20 bits)
+    /// let input = vec![3_u32 << 20; 1000];
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let key: LweSecretKey32 = engine.generate_new_lwe_secret_key(lwe_dimension)?;
+    /// let plaintext_vector: PlaintextVector32 = engine.create_plaintext_vector_from(&input)?;
+    /// let ciphertext_vector: LweCiphertextVector32 =
+    ///     engine.encrypt_lwe_ciphertext_vector(&key, &plaintext_vector, noise)?;
+    ///
+    /// let statistics =
+    ///     engine.measure_lwe_ciphertext_vector_noise(&key, &ciphertext_vector, &plaintext_vector)?;
+    /// #
+    /// assert_eq!(statistics.sample_count, 1000);
+    /// assert!(statistics.mean.abs() < 2_f64.powf(-14.));
+    /// assert!(
+    ///     (statistics.variance.get_log_standard_dev() - noise.get_log_standard_dev()).abs() < 0.5
+    /// );
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn measure_lwe_ciphertext_vector_noise(
+        &mut self,
+        key: &LweSecretKey32,
+        input: &LweCiphertextVector32,
+        expected: &PlaintextVector32,
+    ) -> Result<NoiseStatistics, LweCiphertextVectorNoiseMeasurementError<Self::EngineError>> {
+        LweCiphertextVectorNoiseMeasurementError::perform_generic_checks(key, input, expected)?;
+        Ok(unsafe { self.measure_lwe_ciphertext_vector_noise_unchecked(key, input, expected) })
+    }
+
+    unsafe fn measure_lwe_ciphertext_vector_noise_unchecked(
+        &mut self,
+        key: &LweSecretKey32,
+        input: &LweCiphertextVector32,
+        expected: &PlaintextVector32,
+    ) -> NoiseStatistics {
+        let mut plaintext =
+            ImplPlaintextList::allocate(0u32, PlaintextCount(input.lwe_ciphertext_count().0));
+        key.0.decrypt_lwe_list(&mut plaintext, &input.0);
+        let errors: Vec<f64> = plaintext
+            .as_tensor()
+            .iter()
+            .zip(expected.0.as_tensor().iter())
+            .map(|(decrypted, expected)| signed_torus_distance(*decrypted, *expected))
+            .collect();
+        NoiseStatistics::from_torus_errors(&errors)
+    }
+}
+
+/// # Description:
+/// Implementation of [`LweCiphertextVectorNoiseMeasurementEngine`] for [`DefaultEngine`] that
+/// operates on 64 bits integers.
+impl
+    LweCiphertextVectorNoiseMeasurementEngine<
+        LweSecretKey64,
+        LweCiphertextVector64,
+        PlaintextVector64,
+    > for DefaultEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_core::prelude::{LweDimension, Variance, *};
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let lwe_dimension = LweDimension(6);
+    /// // Here a hard-set encoding is applied (shift by 50 bits)
+    /// let input = vec![3_u64 << 50; 1000];
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let key: LweSecretKey64 = engine.generate_new_lwe_secret_key(lwe_dimension)?;
+    /// let plaintext_vector: PlaintextVector64 = engine.create_plaintext_vector_from(&input)?;
+    /// let ciphertext_vector: LweCiphertextVector64 =
+    ///     engine.encrypt_lwe_ciphertext_vector(&key, &plaintext_vector, noise)?;
+    ///
+    /// let statistics =
+    ///     engine.measure_lwe_ciphertext_vector_noise(&key, &ciphertext_vector, &plaintext_vector)?;
+    /// #
+    /// assert_eq!(statistics.sample_count, 1000);
+    /// assert!(statistics.mean.abs() < 2_f64.powf(-14.));
+    /// assert!(
+    ///     (statistics.variance.get_log_standard_dev() - noise.get_log_standard_dev()).abs() < 0.5
+    /// );
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn measure_lwe_ciphertext_vector_noise(
+        &mut self,
+        key: &LweSecretKey64,
+        input: &LweCiphertextVector64,
+        expected: &PlaintextVector64,
+    ) -> Result<NoiseStatistics, LweCiphertextVectorNoiseMeasurementError<Self::EngineError>> {
+        LweCiphertextVectorNoiseMeasurementError::perform_generic_checks(key, input, expected)?;
+        Ok(unsafe { self.measure_lwe_ciphertext_vector_noise_unchecked(key, input, expected) })
+    }
+
+    unsafe fn measure_lwe_ciphertext_vector_noise_unchecked(
+        &mut self,
+        key: &LweSecretKey64,
+        input: &LweCiphertextVector64,
+        expected: &PlaintextVector64,
+    ) -> NoiseStatistics {
+        let mut plaintext =
+            ImplPlaintextList::allocate(0u64, PlaintextCount(input.lwe_ciphertext_count().0));
+        key.0.decrypt_lwe_list(&mut plaintext, &input.0);
+        let errors: Vec<f64> = plaintext
+            .as_tensor()
+            .iter()
+            .zip(expected.0.as_tensor().iter())
+            .map(|(decrypted, expected)| signed_torus_distance(*decrypted, *expected))
+            .collect();
+        NoiseStatistics::from_torus_errors(&errors)
+    }
+}
@@ -0,0 +1,171 @@
+use super::ActivatedRandomGenerator;
+use crate::backends::default::engines::{DefaultEngine, DefaultError};
+use crate::backends::default::entities::{
+    LweCiphertext32, LweCiphertext64, LweSeededCiphertextVector32, LweSeededCiphertextVector64,
+};
+use crate::commons::crypto::lwe::{
+    LweCiphertext as ImplLweCiphertext, LweSeededList as ImplLweSeededList,
+};
+use crate::commons::math::random::{RandomGenerable, Uniform};
+use crate::commons::numeric::UnsignedInteger;
+use crate::prelude::LweCiphertextIndex;
+use crate::specification::engines::{
+    LweSeededCiphertextVectorElementExpansionEngine, LweSeededCiphertextVectorElementExpansionError,
+};
+
+/// # Description:
+/// Implementation of [`LweSeededCiphertextVectorElementExpansionEngine`] for [`DefaultEngine`] that
+/// operates on 32 bits integers.
+impl LweSeededCiphertextVectorElementExpansionEngine<LweSeededCiphertextVector32, LweCiphertext32>
+    for DefaultEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_core::prelude::{LweCiphertextIndex, LweDimension, Variance, *};
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let lwe_dimension = LweDimension(6);
+    /// let lwe_size = lwe_dimension.to_lwe_size().0;
+    /// // Here a hard-set encoding is applied (shift by 20 bits)
+    /// let input = vec![3_u32 << 20; 3];
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let key: LweSecretKey32 = engine.generate_new_lwe_secret_key(lwe_dimension)?;
+    /// let plaintext_vector: PlaintextVector32 = engine.create_plaintext_vector_from(&input)?;
+    ///
+    /// let seeded_ciphertext_vector: LweSeededCiphertextVector32 =
+    ///     engine.encrypt_lwe_seeded_ciphertext_vector(&key, &plaintext_vector, noise)?;
+    ///
+    /// let ciphertext: LweCiphertext32 = engine.expand_lwe_seeded_ciphertext_vector_element(
+    ///     &seeded_ciphertext_vector,
+    ///     LweCiphertextIndex(2),
+    /// )?;
+    /// assert_eq!(ciphertext.lwe_dimension(), lwe_dimension);
+    ///
+    /// // The expanded ciphertext is the same as the one of the fully expanded vector.
+    /// let ciphertext_vector: LweCiphertextVector32 = engine
+    ///     .transform_lwe_seeded_ciphertext_vector_to_lwe_ciphertext_vector(
+    ///         seeded_ciphertext_vector,
+    ///     )?;
+    /// let expected = engine.consume_retrieve_lwe_ciphertext_vector(ciphertext_vector)?;
+    /// let coefficients = engine.consume_retrieve_lwe_ciphertext(ciphertext)?;
+    /// assert_eq!(coefficients[..], expected[2 * lwe_size..]);
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn expand_lwe_seeded_ciphertext_vector_element(
+        &mut self,
+        seeded_ciphertext_vector: &LweSeededCiphertextVector32,
+        index: LweCiphertextIndex,
+    ) -> Result<LweCiphertext32, LweSeededCiphertextVectorElementExpansionError<Self::EngineError>>
+    {
+        LweSeededCiphertextVectorElementExpansionError::perform_generic_checks(
+            seeded_ciphertext_vector,
+            index,
+        )?;
+        let output = expand_element(&seeded_ciphertext_vector.0, index)
+            .map_err(LweSeededCiphertextVectorElementExpansionError::Engine)?;
+        Ok(LweCiphertext32(output))
+    }
+
+    unsafe fn expand_lwe_seeded_ciphertext_vector_element_unchecked(
+        &mut self,
+        seeded_ciphertext_vector: &LweSeededCiphertextVector32,
+        index: LweCiphertextIndex,
+    ) -> LweCiphertext32 {
+        LweCiphertext32(expand_element(&seeded_ciphertext_vector.0, index).unwrap())
+    }
+}
+
+/// # Description:
+/// Implementation of [`LweSeededCiphertextVectorElementExpansionEngine`] for [`DefaultEngine`] that
+/// operates on 64 bits integers.
+impl LweSeededCiphertextVectorElementExpansionEngine<LweSeededCiphertextVector64, LweCiphertext64>
+    for DefaultEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_core::prelude::{LweCiphertextIndex, LweDimension, Variance, *};
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let lwe_dimension = LweDimension(6);
+    /// let lwe_size = lwe_dimension.to_lwe_size().0;
+    /// // Here a hard-set encoding is applied (shift by 50 bits)
+    /// let input = vec![3_u64 << 50; 3];
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let key: LweSecretKey64 = engine.generate_new_lwe_secret_key(lwe_dimension)?;
+    /// let plaintext_vector: PlaintextVector64 = engine.create_plaintext_vector_from(&input)?;
+    ///
+    /// let seeded_ciphertext_vector: LweSeededCiphertextVector64 =
+    ///     engine.encrypt_lwe_seeded_ciphertext_vector(&key, &plaintext_vector, noise)?;
+    ///
+    /// let ciphertext: LweCiphertext64 = engine.expand_lwe_seeded_ciphertext_vector_element(
+    ///     &seeded_ciphertext_vector,
+    ///     LweCiphertextIndex(2),
+    /// )?;
+    /// assert_eq!(ciphertext.lwe_dimension(), lwe_dimension);
+    ///
+    /// // The expanded ciphertext is the same as the one of the fully expanded vector.
+    /// let ciphertext_vector: LweCiphertextVector64 = engine
+    ///     .transform_lwe_seeded_ciphertext_vector_to_lwe_ciphertext_vector(
+    ///         seeded_ciphertext_vector,
+    ///     )?;
+    /// let expected = engine.consume_retrieve_lwe_ciphertext_vector(ciphertext_vector)?;
+    /// let coefficients = engine.consume_retrieve_lwe_ciphertext(ciphertext)?;
+    /// assert_eq!(coefficients[..], expected[2 * lwe_size..]);
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn expand_lwe_seeded_ciphertext_vector_element(
+        &mut self,
+        seeded_ciphertext_vector: &LweSeededCiphertextVector64,
+        index: LweCiphertextIndex,
+    ) -> Result<LweCiphertext64, LweSeededCiphertextVectorElementExpansionError<Self::EngineError>>
+    {
+        LweSeededCiphertextVectorElementExpansionError::perform_generic_checks(
+            seeded_ciphertext_vector,
+            index,
+        )?;
+        let output = expand_element(&seeded_ciphertext_vector.0, index)
+            .map_err(LweSeededCiphertextVectorElementExpansionError::Engine)?;
+        Ok(LweCiphertext64(output))
+    }
+
+    unsafe fn expand_lwe_seeded_ciphertext_vector_element_unchecked(
+        &mut self,
+        seeded_ciphertext_vector: &LweSeededCiphertextVector64,
+        index: LweCiphertextIndex,
+    ) -> LweCiphertext64 {
+        LweCiphertext64(expand_element(&seeded_ciphertext_vector.0, index).unwrap())
+    }
+}
+
+/// Expands the `index`-th ciphertext of `seeded_list`.
+fn expand_element<Scalar>(
+    seeded_list: &ImplLweSeededList<Vec<Scalar>>,
+    index: LweCiphertextIndex,
+) -> Result<ImplLweCiphertext<Vec<Scalar>>, DefaultError>
+where
+    Scalar: UnsignedInteger + RandomGenerable<Uniform>,
+{
+    let mut ciphertext = ImplLweCiphertext::allocate(Scalar::ZERO, seeded_list.lwe_size());
+    seeded_list
+        .expand_ciphertext_into::<_, _, ActivatedRandomGenerator>(index.0, &mut ciphertext)
+        .map_err(|_| DefaultError::RandomGeneratorSkipTooLarge)?;
+    Ok(ciphertext)
+}
@@ -0,0 +1,192 @@
+use super::ActivatedRandomGenerator;
+use crate::backends::default::engines::{DefaultEngine, DefaultError};
+use crate::backends::default::entities::{
+    LweCiphertextVector32, LweCiphertextVector64, LweSeededCiphertextVector32,
+    LweSeededCiphertextVector64,
+};
+use crate::commons::crypto::lwe::{LweList as ImplLweList, LweSeededList as ImplLweSeededList};
+use crate::commons::math::random::{RandomGenerable, Uniform};
+use crate::commons::numeric::UnsignedInteger;
+use crate::prelude::{CiphertextCount, LweCiphertextRange};
+use crate::specification::engines::{
+    LweSeededCiphertextVectorRangeExpansionEngine, LweSeededCiphertextVectorRangeExpansionError,
+};
+
+/// # Description:
+/// Implementation of [`LweSeededCiphertextVectorRangeExpansionEngine`] for [`DefaultEngine`] that
+/// operates on 32 bits integers.
+impl
+    LweSeededCiphertextVectorRangeExpansionEngine<
+        LweSeededCiphertextVector32,
+        LweCiphertextVector32,
+    > for DefaultEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_core::prelude::{LweCiphertextRange, LweDimension, Variance, *};
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let lwe_dimension = LweDimension(6);
+    /// let lwe_size = lwe_dimension.to_lwe_size().0;
+    /// // Here a hard-set encoding is applied (shift by 20 bits)
+    /// let input = vec![3_u32 << 20; 3];
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let key: LweSecretKey32 = engine.generate_new_lwe_secret_key(lwe_dimension)?;
+    /// let plaintext_vector: PlaintextVector32 = engine.create_plaintext_vector_from(&input)?;
+    ///
+    /// let seeded_ciphertext_vector: LweSeededCiphertextVector32 =
+    ///     engine.encrypt_lwe_seeded_ciphertext_vector(&key, &plaintext_vector, noise)?;
+    ///
+    /// let ciphertext_vector: LweCiphertextVector32 = engine
+    ///     .expand_lwe_seeded_ciphertext_vector_range(
+    ///         &seeded_ciphertext_vector,
+    ///         LweCiphertextRange(1, 2),
+    ///     )?;
+    /// assert_eq!(
+    ///     ciphertext_vector.lwe_ciphertext_count(),
+    ///     LweCiphertextCount(2)
+    /// );
+    ///
+    /// // The expanded ciphertexts are the same as the ones of the fully expanded vector.
+    /// let full_ciphertext_vector: LweCiphertextVector32 = engine
+    ///     .transform_lwe_seeded_ciphertext_vector_to_lwe_ciphertext_vector(
+    ///         seeded_ciphertext_vector,
+    ///     )?;
+    /// let expected = engine.consume_retrieve_lwe_ciphertext_vector(full_ciphertext_vector)?;
+    /// let coefficients = engine.consume_retrieve_lwe_ciphertext_vector(ciphertext_vector)?;
+    /// assert_eq!(coefficients[..], expected[lwe_size..]);
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn expand_lwe_seeded_ciphertext_vector_range(
+        &mut self,
+        seeded_ciphertext_vector: &LweSeededCiphertextVector32,
+        range: LweCiphertextRange,
+    ) -> Result<
+        LweCiphertextVector32,
+        LweSeededCiphertextVectorRangeExpansionError<Self::EngineError>,
+    > {
+        LweSeededCiphertextVectorRangeExpansionError::perform_generic_checks(
+            seeded_ciphertext_vector,
+            range,
+        )?;
+        let output = expand_range(&seeded_ciphertext_vector.0, range)
+            .map_err(LweSeededCiphertextVectorRangeExpansionError::Engine)?;
+        Ok(LweCiphertextVector32(output))
+    }
+
+    unsafe fn expand_lwe_seeded_ciphertext_vector_range_unchecked(
+        &mut self,
+        seeded_ciphertext_vector: &LweSeededCiphertextVector32,
+        range: LweCiphertextRange,
+    ) -> LweCiphertextVector32 {
+        LweCiphertextVector32(expand_range(&seeded_ciphertext_vector.0, range).unwrap())
+    }
+}
+
+/// # Description:
+/// Implementation of [`LweSeededCiphertextVectorRangeExpansionEngine`] for [`DefaultEngine`] that
+/// operates on 64 bits integers.
+impl
+    LweSeededCiphertextVectorRangeExpansionEngine<
+        LweSeededCiphertextVector64,
+        LweCiphertextVector64,
+    > for DefaultEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_core::prelude::{LweCiphertextRange, LweDimension, Variance, *};
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let lwe_dimension = LweDimension(6);
+    /// let lwe_size = lwe_dimension.to_lwe_size().0;
+    /// // Here a hard-set encoding is applied (shift by 50 bits)
+    /// let input = vec![3_u64 << 50; 3];
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let key: LweSecretKey64 = engine.generate_new_lwe_secret_key(lwe_dimension)?;
+    /// let plaintext_vector: PlaintextVector64 = engine.create_plaintext_vector_from(&input)?;
+    ///
+    /// let seeded_ciphertext_vector: LweSeededCiphertextVector64 =
+    ///     engine.encrypt_lwe_seeded_ciphertext_vector(&key, &plaintext_vector, noise)?;
+    ///
+    /// let ciphertext_vector: LweCiphertextVector64 = engine
+    ///     .expand_lwe_seeded_ciphertext_vector_range(
+    ///         &seeded_ciphertext_vector,
+    ///         LweCiphertextRange(1, 2),
+    ///     )?;
+    /// assert_eq!(
+    ///     ciphertext_vector.lwe_ciphertext_count(),
+    ///     LweCiphertextCount(2)
+    /// );
+    ///
+    /// // The expanded ciphertexts are the same as the ones of the fully expanded vector.
+    /// let full_ciphertext_vector: LweCiphertextVector64 = engine
+    ///     .transform_lwe_seeded_ciphertext_vector_to_lwe_ciphertext_vector(
+    ///         seeded_ciphertext_vector,
+    ///     )?;
+    /// let expected = engine.consume_retrieve_lwe_ciphertext_vector(full_ciphertext_vector)?;
+    /// let coefficients = engine.consume_retrieve_lwe_ciphertext_vector(ciphertext_vector)?;
+    /// assert_eq!(coefficients[..], expected[lwe_size..]);
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn expand_lwe_seeded_ciphertext_vector_range(
+        &mut self,
+        seeded_ciphertext_vector: &LweSeededCiphertextVector64,
+        range: LweCiphertextRange,
+    ) -> Result<
+        LweCiphertextVector64,
+        LweSeededCiphertextVectorRangeExpansionError<Self::EngineError>,
+    > {
+        LweSeededCiphertextVectorRangeExpansionError::perform_generic_checks(
+            seeded_ciphertext_vector,
+            range,
+        )?;
+        let output = expand_range(&seeded_ciphertext_vector.0, range)
+            .map_err(LweSeededCiphertextVectorRangeExpansionError::Engine)?;
+        Ok(LweCiphertextVector64(output))
+    }
+
+    unsafe fn expand_lwe_seeded_ciphertext_vector_range_unchecked(
+        &mut self,
+        seeded_ciphertext_vector: &LweSeededCiphertextVector64,
+        range: LweCiphertextRange,
+    ) -> LweCiphertextVector64 {
+        LweCiphertextVector64(expand_range(&seeded_ciphertext_vector.0, range).unwrap())
+    }
+}
+
+/// Expands the ciphertexts of `seeded_list` contained in `range`.
+fn expand_range<Scalar>(
+    seeded_list: &ImplLweSeededList<Vec<Scalar>>,
+    range: LweCiphertextRange,
+) -> Result<ImplLweList<Vec<Scalar>>, DefaultError>
+where
+    Scalar: UnsignedInteger + RandomGenerable<Uniform>,
+{
+    let mut ciphertext_vector = ImplLweList::allocate(
+        Scalar::ZERO,
+        seeded_list.lwe_size(),
+        CiphertextCount(range.1 - range.0 + 1),
+    );
+    seeded_list
+        .expand_sub_list_into::<_, _, ActivatedRandomGenerator>(range.0, &mut ciphertext_vector)
+        .map_err(|_| DefaultError::RandomGeneratorSkipTooLarge)?;
+    Ok(ciphertext_vector)
+}
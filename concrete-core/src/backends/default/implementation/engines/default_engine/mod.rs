@@ -1,12 +1,16 @@
-use super::ActivatedRandomGenerator;
+use super::{ActivatedRandomGenerator, GeneratorKind};
 use crate::commons::crypto::secret::generators::{
     DeterministicSeeder as ImplDeterministicSeeder,
-    EncryptionRandomGenerator as ImplEncryptionRandomGenerator,
+    EncryptionRandomGenerator as ImplEncryptionRandomGenerator, EncryptionRandomGeneratorState,
     SecretRandomGenerator as ImplSecretRandomGenerator,
 };
+use crate::commons::math::random::RandomGenerator as ImplRandomGenerator;
 use crate::specification::engines::sealed::AbstractEngineSeal;
 use crate::specification::engines::AbstractEngine;
+use concrete_csprng::generators::GeneratorState;
 use concrete_csprng::seeders::Seeder;
+#[cfg(feature = "backend_default_serialization")]
+use serde::{Deserialize, Serialize};
 use std::error::Error;
 use std::fmt::{Display, Formatter};
 
@@ -22,6 +26,9 @@ pub enum DefaultError {
     FloatEncoderNullPrecision,
     FloatEncoderMinMaxOrder,
     FloatEncoderNullRadius,
+    UnavailableRandomGenerator,
+    InvalidRandomGeneratorState,
+    RandomGeneratorSkipTooLarge,
 }
 
 impl Display for DefaultError {
@@ -42,6 +49,21 @@ impl Display for DefaultError {
             DefaultError::FloatEncoderNullRadius => {
                 write!(f, "Tried to create a float encoder with null radius.")
             }
+            DefaultError::UnavailableRandomGenerator => write!(
+                f,
+                "Tried to use a random generator which is not compiled in, or not supported by \
+                the CPU."
+            ),
+            DefaultError::InvalidRandomGeneratorState => {
+                write!(
+                    f,
+                    "Tried to restore a random generator from an invalid state."
+                )
+            }
+            DefaultError::RandomGeneratorSkipTooLarge => write!(
+                f,
+                "Tried to skip more bytes than the random generator can produce."
+            ),
         }
     }
 }
@@ -62,6 +84,160 @@ pub struct DefaultEngine {
     /// [`ImplEncryptionRandomGenerator`] to encrypt seeded types.
     seeder: ImplDeterministicSeeder<ActivatedRandomGenerator>,
 }
+/// A snapshot of the state of the random generators of a [`DefaultEngine`].
+///
+/// It can be used to checkpoint an engine, e.g. during a long key generation, and to build an
+/// engine resuming from this exact point later on, with [`DefaultEngine::from_state`].
+///
+/// # Note:
+///
+/// The snapshot contains the secret keys of the random generators: it must be kept as secret as
+/// the seed the engine was created from. The keys are left out of its `Debug` output.
+#[cfg_attr(
+    feature = "backend_default_serialization",
+    derive(Serialize, Deserialize)
+)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct DefaultEngineState {
+    secret_generator: GeneratorState,
+    encryption_generator: EncryptionRandomGeneratorState,
+    seeder: GeneratorState,
+}
+
+impl DefaultEngine {
+    /// Returns a snapshot of the state of the random generators of the engine.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let lwe_dimension = LweDimension(6);
+    /// // Here a hard-set encoding is applied (shift by 20 bits)
+    /// let input = 3_u32 << 20;
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let key: LweSecretKey32 = engine.generate_new_lwe_secret_key(lwe_dimension)?;
+    ///
+    /// let state = engine.save_state();
+    /// let mut restored_engine = DefaultEngine::from_state(state)?;
+    ///
+    /// let plaintext = engine.create_plaintext_from(&input)?;
+    /// let ciphertext = engine.encrypt_lwe_ciphertext(&key, &plaintext, noise)?;
+    /// let restored_ciphertext = restored_engine.encrypt_lwe_ciphertext(&key, &plaintext, noise)?;
+    /// assert_eq!(ciphertext, restored_ciphertext);
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn save_state(&self) -> DefaultEngineState {
+        DefaultEngineState {
+            secret_generator: self.secret_generator.save_state(),
+            encryption_generator: self.encryption_generator.save_state(),
+            seeder: self.seeder.save_state(),
+        }
+    }
+
+    /// Creates a new engine whose random generators are restored from a snapshot.
+    ///
+    /// The restored engine produces exactly the same keys, ciphertexts and seeds as the engine the
+    /// snapshot was taken from, from the moment the snapshot was taken.
+    pub fn from_state(state: DefaultEngineState) -> Result<DefaultEngine, DefaultError> {
+        let invalid_state = |_| DefaultError::InvalidRandomGeneratorState;
+        Ok(DefaultEngine {
+            secret_generator: ImplSecretRandomGenerator::restore_state(state.secret_generator)
+                .map_err(invalid_state)?,
+            encryption_generator: ImplEncryptionRandomGenerator::restore_state(
+                state.encryption_generator,
+            )
+            .map_err(invalid_state)?,
+            seeder: ImplDeterministicSeeder::restore_state(state.seeder).map_err(invalid_state)?,
+        })
+    }
+}
+
+impl DefaultEngine {
+    /// Creates a new engine whose random generators use the given implementation.
+    ///
+    /// By default, the engine selects at runtime the fastest AES implementation supported by the
+    /// CPU. Since all the AES implementations output the same stream for a given seed, an engine
+    /// created with this method and an AES implementation produces the same keys and ciphertexts
+    /// as one created with [`AbstractEngine::new`] from the same seeder. The ChaCha20
+    /// implementation ([`GeneratorKind::ChaCha`]), available with the
+    /// `backend_default_generator_chacha` feature, outputs a different stream.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use concrete_core::commons::crypto::secret::generators::DeterministicSeeder;
+    /// use concrete_core::prelude::*;
+    /// use concrete_csprng::seeders::Seed;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let lwe_dimension = LweDimension(6);
+    /// // Here a hard-set encoding is applied (shift by 20 bits)
+    /// let input = 3_u32 << 20;
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// // Both engines are seeded the same way, which is totally unsafe.
+    /// let seeder = || {
+    ///     Box::new(DeterministicSeeder::<ActivatedRandomGenerator>::new(Seed(
+    ///         0,
+    ///     )))
+    /// };
+    /// let mut engine = DefaultEngine::new(seeder())?;
+    /// let mut software_engine = DefaultEngine::new_with_generator(seeder(), GeneratorKind::Software)?;
+    ///
+    /// let key: LweSecretKey32 = engine.generate_new_lwe_secret_key(lwe_dimension)?;
+    /// let software_key: LweSecretKey32 =
+    ///     software_engine.generate_new_lwe_secret_key(lwe_dimension)?;
+    /// assert_eq!(key, software_key);
+    ///
+    /// let plaintext = engine.create_plaintext_from(&input)?;
+    /// let ciphertext = engine.encrypt_lwe_ciphertext(&key, &plaintext, noise)?;
+    /// let software_ciphertext =
+    ///     software_engine.encrypt_lwe_ciphertext(&software_key, &plaintext, noise)?;
+    /// assert_eq!(ciphertext, software_ciphertext);
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn new_with_generator(
+        mut seeder: Box<dyn Seeder>,
+        generator: GeneratorKind,
+    ) -> Result<DefaultEngine, DefaultError> {
+        let new_generator = |seed| {
+            ActivatedRandomGenerator::new_with_kind(seed, generator)
+                .map(ImplRandomGenerator::from_byte_generator)
+                .map_err(|_| DefaultError::UnavailableRandomGenerator)
+        };
+        // The seeds are drawn in the same order as in `AbstractEngine::new`.
+        let mut deterministic_seeder =
+            ImplDeterministicSeeder::from_generator(new_generator(seeder.seed())?);
+        let secret_generator =
+            ImplSecretRandomGenerator::from_generator(new_generator(deterministic_seeder.seed())?);
+        let mask_generator = new_generator(deterministic_seeder.seed())?;
+        let noise_generator = new_generator(deterministic_seeder.seed())?;
+        Ok(DefaultEngine {
+            secret_generator,
+            encryption_generator: ImplEncryptionRandomGenerator::from_generators(
+                mask_generator,
+                noise_generator,
+            ),
+            seeder: deterministic_seeder,
+        })
+    }
+}
+
 impl AbstractEngineSeal for DefaultEngine {}
 
 impl AbstractEngine for DefaultEngine {
@@ -101,13 +277,16 @@ mod encoder_vector_creation;
 mod ggsw_ciphertext_scalar_discarding_encryption;
 mod ggsw_ciphertext_scalar_encryption;
 mod ggsw_ciphertext_scalar_trivial_encryption;
+mod glwe_ciphertext_compression;
 mod glwe_ciphertext_consuming_retrieval;
 mod glwe_ciphertext_creation;
+mod glwe_ciphertext_decompression;
 mod glwe_ciphertext_decryption;
 mod glwe_ciphertext_discarding_decryption;
 mod glwe_ciphertext_discarding_encryption;
 mod glwe_ciphertext_discarding_trivial_encryption;
 mod glwe_ciphertext_encryption;
+mod glwe_ciphertext_noise_measurement;
 mod glwe_ciphertext_trivial_decryption;
 mod glwe_ciphertext_trivial_encryption;
 mod glwe_ciphertext_vector_consuming_retrieval;
@@ -123,7 +302,9 @@ mod glwe_ciphertext_zero_encryption;
 mod glwe_secret_key_generation;
 mod glwe_seeded_ciphertext_encryption;
 mod glwe_seeded_ciphertext_to_glwe_ciphertext_transformation;
+mod glwe_seeded_ciphertext_vector_element_expansion;
 mod glwe_seeded_ciphertext_vector_encryption;
+mod glwe_seeded_ciphertext_vector_range_expansion;
 mod glwe_seeded_vector_to_glwe_ciphertext_vector_transformation;
 mod glwe_to_lwe_secret_key_transformation;
 mod lwe_bootstrap_key_consuming_retrieval;
@@ -132,8 +313,10 @@ mod lwe_bootstrap_key_discarding_conversion;
 mod lwe_bootstrap_key_generation;
 mod lwe_ciphertext_cleartext_discarding_multiplication;
 mod lwe_ciphertext_cleartext_fusing_multiplication;
+mod lwe_ciphertext_compression;
 mod lwe_ciphertext_consuming_retrieval;
 mod lwe_ciphertext_creation;
+mod lwe_ciphertext_decompression;
 mod lwe_ciphertext_decryption;
 mod lwe_ciphertext_discarding_addition;
 mod lwe_ciphertext_discarding_decryption;
@@ -147,6 +330,7 @@ mod lwe_ciphertext_encryption;
 mod lwe_ciphertext_fusing_addition;
 mod lwe_ciphertext_fusing_opposite;
 mod lwe_ciphertext_fusing_subtraction;
+mod lwe_ciphertext_noise_measurement;
 mod lwe_ciphertext_plaintext_discarding_addition;
 mod lwe_ciphertext_plaintext_discarding_subtraction;
 mod lwe_ciphertext_plaintext_fusing_addition;
@@ -161,12 +345,14 @@ mod lwe_ciphertext_vector_discarding_addition;
 mod lwe_ciphertext_vector_discarding_affine_transformation;
 mod lwe_ciphertext_vector_discarding_decryption;
 mod lwe_ciphertext_vector_discarding_encryption;
+mod lwe_ciphertext_vector_discarding_keyswitch;
 mod lwe_ciphertext_vector_discarding_subtraction;
 mod lwe_ciphertext_vector_encryption;
 mod lwe_ciphertext_vector_fusing_addition;
 mod lwe_ciphertext_vector_fusing_subtraction;
 mod lwe_ciphertext_vector_glwe_ciphertext_discarding_packing_keyswitch;
 mod lwe_ciphertext_vector_glwe_ciphertext_discarding_private_functional_packing_keyswitch;
+mod lwe_ciphertext_vector_noise_measurement;
 mod lwe_ciphertext_vector_plaintext_vector_discarding_addition;
 mod lwe_ciphertext_vector_trivial_decryption;
 mod lwe_ciphertext_vector_trivial_encryption;
@@ -184,7 +370,9 @@ mod lwe_secret_key_generation;
 mod lwe_seeded_bootstrap_key_generation;
 mod lwe_seeded_bootstrap_key_to_lwe_bootstrap_key_transformation;
 mod lwe_seeded_ciphertext_encryption;
+mod lwe_seeded_ciphertext_vector_element_expansion;
 mod lwe_seeded_ciphertext_vector_encryption;
+mod lwe_seeded_ciphertext_vector_range_expansion;
 mod lwe_seeded_ciphertext_vector_to_lwe_ciphertext_vector_transformation;
 mod lwe_seeded_keyswitch_key_generation;
 mod lwe_seeded_keyswitch_key_to_lwe_keyswitch_key_transformation;
@@ -0,0 +1,172 @@
+use crate::prelude::Variance;
+
+use crate::backends::default::implementation::engines::DefaultParallelEngine;
+use crate::backends::default::implementation::entities::{
+    LweCiphertextVector32, LweCiphertextVector64, LweSecretKey32, LweSecretKey64,
+    PlaintextVector32, PlaintextVector64,
+};
+use crate::specification::engines::{
+    LweCiphertextVectorDiscardingEncryptionEngine, LweCiphertextVectorDiscardingEncryptionError,
+};
+
+/// # Description:
+/// Implementation of [`LweCiphertextVectorDiscardingEncryptionEngine`] for
+/// [`DefaultParallelEngine`] that operates on 32 bits integers.
+///
+/// The ciphertexts are encrypted in parallel, using forks of the engine random generator: the
+/// output is the same as the one of the [`DefaultEngine`](crate::prelude::DefaultEngine)
+/// implementation, for engines created with the same seed.
+impl
+    LweCiphertextVectorDiscardingEncryptionEngine<
+        LweSecretKey32,
+        PlaintextVector32,
+        LweCiphertextVector32,
+    > for DefaultParallelEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_core::prelude::{LweCiphertextCount, LweDimension, Variance, *};
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let lwe_dimension = LweDimension(6);
+    /// // Here a hard-set encoding is applied (shift by 20 bits)
+    /// let input = vec![3_u32 << 20; 3];
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let mut par_engine = DefaultParallelEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let key: LweSecretKey32 = engine.generate_new_lwe_secret_key(lwe_dimension)?;
+    /// let plaintext_vector: PlaintextVector32 = engine.create_plaintext_vector_from(&input)?;
+    /// let mut ciphertext_vector: LweCiphertextVector32 =
+    ///     par_engine.zero_encrypt_lwe_ciphertext_vector(&key, noise, LweCiphertextCount(3))?;
+    ///
+    /// par_engine.discard_encrypt_lwe_ciphertext_vector(
+    ///     &key,
+    ///     &mut ciphertext_vector,
+    ///     &plaintext_vector,
+    ///     noise,
+    /// )?;
+    /// #
+    /// assert_eq!(ciphertext_vector.lwe_dimension(), lwe_dimension);
+    /// assert_eq!(
+    /// #     ciphertext_vector.lwe_ciphertext_count(),
+    /// #     LweCiphertextCount(3)
+    /// # );
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn discard_encrypt_lwe_ciphertext_vector(
+        &mut self,
+        key: &LweSecretKey32,
+        output: &mut LweCiphertextVector32,
+        input: &PlaintextVector32,
+        noise: Variance,
+    ) -> Result<(), LweCiphertextVectorDiscardingEncryptionError<Self::EngineError>> {
+        LweCiphertextVectorDiscardingEncryptionError::perform_generic_checks(key, output, input)?;
+        unsafe { self.discard_encrypt_lwe_ciphertext_vector_unchecked(key, output, input, noise) };
+        Ok(())
+    }
+
+    unsafe fn discard_encrypt_lwe_ciphertext_vector_unchecked(
+        &mut self,
+        key: &LweSecretKey32,
+        output: &mut LweCiphertextVector32,
+        input: &PlaintextVector32,
+        noise: Variance,
+    ) {
+        key.0.par_encrypt_lwe_list(
+            &mut output.0,
+            &input.0,
+            noise,
+            &mut self.encryption_generator,
+        );
+    }
+}
+
+/// # Description:
+/// Implementation of [`LweCiphertextVectorDiscardingEncryptionEngine`] for
+/// [`DefaultParallelEngine`] that operates on 64 bits integers.
+///
+/// The ciphertexts are encrypted in parallel, using forks of the engine random generator: the
+/// output is the same as the one of the [`DefaultEngine`](crate::prelude::DefaultEngine)
+/// implementation, for engines created with the same seed.
+impl
+    LweCiphertextVectorDiscardingEncryptionEngine<
+        LweSecretKey64,
+        PlaintextVector64,
+        LweCiphertextVector64,
+    > for DefaultParallelEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_core::prelude::{LweCiphertextCount, LweDimension, Variance, *};
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let lwe_dimension = LweDimension(6);
+    /// // Here a hard-set encoding is applied (shift by 50 bits)
+    /// let input = vec![3_u64 << 50; 3];
+    /// let noise = Variance(2_f64.powf(-50.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let mut par_engine = DefaultParallelEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let key: LweSecretKey64 = engine.generate_new_lwe_secret_key(lwe_dimension)?;
+    /// let plaintext_vector: PlaintextVector64 = engine.create_plaintext_vector_from(&input)?;
+    /// let mut ciphertext_vector: LweCiphertextVector64 =
+    ///     par_engine.zero_encrypt_lwe_ciphertext_vector(&key, noise, LweCiphertextCount(3))?;
+    ///
+    /// par_engine.discard_encrypt_lwe_ciphertext_vector(
+    ///     &key,
+    ///     &mut ciphertext_vector,
+    ///     &plaintext_vector,
+    ///     noise,
+    /// )?;
+    /// #
+    /// assert_eq!(ciphertext_vector.lwe_dimension(), lwe_dimension);
+    /// assert_eq!(
+    /// #     ciphertext_vector.lwe_ciphertext_count(),
+    /// #     LweCiphertextCount(3)
+    /// # );
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn discard_encrypt_lwe_ciphertext_vector(
+        &mut self,
+        key: &LweSecretKey64,
+        output: &mut LweCiphertextVector64,
+        input: &PlaintextVector64,
+        noise: Variance,
+    ) -> Result<(), LweCiphertextVectorDiscardingEncryptionError<Self::EngineError>> {
+        LweCiphertextVectorDiscardingEncryptionError::perform_generic_checks(key, output, input)?;
+        unsafe { self.discard_encrypt_lwe_ciphertext_vector_unchecked(key, output, input, noise) };
+        Ok(())
+    }
+
+    unsafe fn discard_encrypt_lwe_ciphertext_vector_unchecked(
+        &mut self,
+        key: &LweSecretKey64,
+        output: &mut LweCiphertextVector64,
+        input: &PlaintextVector64,
+        noise: Variance,
+    ) {
+        key.0.par_encrypt_lwe_list(
+            &mut output.0,
+            &input.0,
+            noise,
+            &mut self.encryption_generator,
+        );
+    }
+}
@@ -0,0 +1,184 @@
+use crate::prelude::{CiphertextCount, Variance};
+
+use crate::backends::default::implementation::engines::DefaultParallelEngine;
+use crate::backends::default::implementation::entities::{
+    LweCiphertextVector32, LweCiphertextVector64, LweSecretKey32, LweSecretKey64,
+    PlaintextVector32, PlaintextVector64,
+};
+use crate::commons::crypto::lwe::LweList as ImplLweList;
+use crate::specification::engines::{
+    LweCiphertextVectorEncryptionEngine, LweCiphertextVectorEncryptionError,
+};
+use crate::specification::entities::{LweSecretKeyEntity, PlaintextVectorEntity};
+
+/// # Description:
+/// Implementation of [`LweCiphertextVectorEncryptionEngine`] for [`DefaultParallelEngine`] that
+/// operates on 32 bits integers.
+///
+/// The ciphertexts are encrypted in parallel, using forks of the engine random generator: the
+/// output is the same as the one of the [`DefaultEngine`](crate::prelude::DefaultEngine)
+/// implementation, for engines created with the same seed.
+impl LweCiphertextVectorEncryptionEngine<LweSecretKey32, PlaintextVector32, LweCiphertextVector32>
+    for DefaultParallelEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_core::prelude::{LweCiphertextCount, LweDimension, Variance, *};
+    /// use concrete_csprng::seeders::Seed;
+    /// # use std::error::Error;
+    ///
+    /// // A seeder always returning the same seed, which is totally unsafe.
+    /// struct ConstantSeeder(u128);
+    ///
+    /// impl Seeder for ConstantSeeder {
+    ///     fn seed(&mut self) -> Seed {
+    ///         Seed(self.0)
+    ///     }
+    ///
+    ///     fn is_available() -> bool {
+    ///         true
+    ///     }
+    /// }
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let lwe_dimension = LweDimension(6);
+    /// // Here a hard-set encoding is applied (shift by 20 bits)
+    /// let input = vec![3_u32 << 20; 3];
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// // Both engines are created from the same seed.
+    /// let mut engine = DefaultEngine::new(Box::new(ConstantSeeder(0)))?;
+    /// let mut par_engine = DefaultParallelEngine::new(Box::new(ConstantSeeder(0)))?;
+    /// let key: LweSecretKey32 = engine.generate_new_lwe_secret_key(lwe_dimension)?;
+    /// let plaintext_vector: PlaintextVector32 = engine.create_plaintext_vector_from(&input)?;
+    ///
+    /// let ciphertext_vector: LweCiphertextVector32 =
+    ///     par_engine.encrypt_lwe_ciphertext_vector(&key, &plaintext_vector, noise)?;
+    /// #
+    /// assert_eq!(ciphertext_vector.lwe_dimension(), lwe_dimension);
+    /// assert_eq!(
+    /// #    ciphertext_vector.lwe_ciphertext_count(),
+    /// #    LweCiphertextCount(3)
+    /// # );
+    ///
+    /// // Engines created with the same seed produce the same ciphertexts
+    /// let sequential_ciphertext_vector: LweCiphertextVector32 =
+    ///     engine.encrypt_lwe_ciphertext_vector(&key, &plaintext_vector, noise)?;
+    /// assert_eq!(ciphertext_vector, sequential_ciphertext_vector);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn encrypt_lwe_ciphertext_vector(
+        &mut self,
+        key: &LweSecretKey32,
+        input: &PlaintextVector32,
+        noise: Variance,
+    ) -> Result<LweCiphertextVector32, LweCiphertextVectorEncryptionError<Self::EngineError>> {
+        Ok(unsafe { self.encrypt_lwe_ciphertext_vector_unchecked(key, input, noise) })
+    }
+
+    unsafe fn encrypt_lwe_ciphertext_vector_unchecked(
+        &mut self,
+        key: &LweSecretKey32,
+        input: &PlaintextVector32,
+        noise: Variance,
+    ) -> LweCiphertextVector32 {
+        let mut vector = ImplLweList::allocate(
+            0u32,
+            key.lwe_dimension().to_lwe_size(),
+            CiphertextCount(input.plaintext_count().0),
+        );
+        key.0
+            .par_encrypt_lwe_list(&mut vector, &input.0, noise, &mut self.encryption_generator);
+        LweCiphertextVector32(vector)
+    }
+}
+
+/// # Description:
+/// Implementation of [`LweCiphertextVectorEncryptionEngine`] for [`DefaultParallelEngine`] that
+/// operates on 64 bits integers.
+///
+/// The ciphertexts are encrypted in parallel, using forks of the engine random generator: the
+/// output is the same as the one of the [`DefaultEngine`](crate::prelude::DefaultEngine)
+/// implementation, for engines created with the same seed.
+impl LweCiphertextVectorEncryptionEngine<LweSecretKey64, PlaintextVector64, LweCiphertextVector64>
+    for DefaultParallelEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_core::prelude::{LweCiphertextCount, LweDimension, Variance, *};
+    /// use concrete_csprng::seeders::Seed;
+    /// # use std::error::Error;
+    ///
+    /// // A seeder always returning the same seed, which is totally unsafe.
+    /// struct ConstantSeeder(u128);
+    ///
+    /// impl Seeder for ConstantSeeder {
+    ///     fn seed(&mut self) -> Seed {
+    ///         Seed(self.0)
+    ///     }
+    ///
+    ///     fn is_available() -> bool {
+    ///         true
+    ///     }
+    /// }
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let lwe_dimension = LweDimension(6);
+    /// // Here a hard-set encoding is applied (shift by 50 bits)
+    /// let input = vec![3_u64 << 50; 3];
+    /// let noise = Variance(2_f64.powf(-50.));
+    ///
+    /// // Both engines are created from the same seed.
+    /// let mut engine = DefaultEngine::new(Box::new(ConstantSeeder(0)))?;
+    /// let mut par_engine = DefaultParallelEngine::new(Box::new(ConstantSeeder(0)))?;
+    /// let key: LweSecretKey64 = engine.generate_new_lwe_secret_key(lwe_dimension)?;
+    /// let plaintext_vector: PlaintextVector64 = engine.create_plaintext_vector_from(&input)?;
+    ///
+    /// let ciphertext_vector: LweCiphertextVector64 =
+    ///     par_engine.encrypt_lwe_ciphertext_vector(&key, &plaintext_vector, noise)?;
+    /// #
+    /// assert_eq!(ciphertext_vector.lwe_dimension(), lwe_dimension);
+    /// assert_eq!(
+    /// #    ciphertext_vector.lwe_ciphertext_count(),
+    /// #    LweCiphertextCount(3)
+    /// # );
+    ///
+    /// // Engines created with the same seed produce the same ciphertexts
+    /// let sequential_ciphertext_vector: LweCiphertextVector64 =
+    ///     engine.encrypt_lwe_ciphertext_vector(&key, &plaintext_vector, noise)?;
+    /// assert_eq!(ciphertext_vector, sequential_ciphertext_vector);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn encrypt_lwe_ciphertext_vector(
+        &mut self,
+        key: &LweSecretKey64,
+        input: &PlaintextVector64,
+        noise: Variance,
+    ) -> Result<LweCiphertextVector64, LweCiphertextVectorEncryptionError<Self::EngineError>> {
+        Ok(unsafe { self.encrypt_lwe_ciphertext_vector_unchecked(key, input, noise) })
+    }
+
+    unsafe fn encrypt_lwe_ciphertext_vector_unchecked(
+        &mut self,
+        key: &LweSecretKey64,
+        input: &PlaintextVector64,
+        noise: Variance,
+    ) -> LweCiphertextVector64 {
+        let mut vector = ImplLweList::allocate(
+            0u64,
+            key.lwe_dimension().to_lwe_size(),
+            CiphertextCount(input.plaintext_count().0),
+        );
+        key.0
+            .par_encrypt_lwe_list(&mut vector, &input.0, noise, &mut self.encryption_generator);
+        LweCiphertextVector64(vector)
+    }
+}
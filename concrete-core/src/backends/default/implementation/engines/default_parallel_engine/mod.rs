@@ -51,6 +51,11 @@ impl AbstractEngine for DefaultParallelEngine {
         let mut deterministic_seeder =
             ImplDeterministicSeeder::<ActivatedRandomGenerator>::new(parameters.seed());
 
+        // The default engine uses the first seed for its secret generator. We skip it, so that a
+        // default engine and a default parallel engine created from the same seed share the same
+        // encryption generator, and thus produce the same ciphertexts.
+        let _secret_seed = deterministic_seeder.seed();
+
         Ok(DefaultParallelEngine {
             encryption_generator: ImplEncryptionRandomGenerator::new(
                 deterministic_seeder.seed(),
@@ -62,6 +67,13 @@ impl AbstractEngine for DefaultParallelEngine {
 }
 
 mod lwe_bootstrap_key_generation;
+mod lwe_ciphertext_vector_decryption;
+mod lwe_ciphertext_vector_discarding_affine_transformation;
+mod lwe_ciphertext_vector_discarding_decryption;
+mod lwe_ciphertext_vector_discarding_encryption;
+mod lwe_ciphertext_vector_discarding_keyswitch;
+mod lwe_ciphertext_vector_encryption;
+mod lwe_ciphertext_vector_glwe_ciphertext_discarding_packing_keyswitch;
 mod lwe_ciphertext_vector_zero_encryption;
 mod lwe_circuit_bootstrap_private_functional_packing_keyswitch_keys_generation;
 mod lwe_public_key_generation;
@@ -0,0 +1,3895 @@
+#![allow(clippy::missing_safety_doc)]
+
+use crate::prelude::{
+    Cleartext32, Cleartext64, CleartextF64, CleartextVector32, CleartextVector64,
+    CleartextVectorEntity, CleartextVectorF64, DefaultSerializationEngine,
+    DefaultSerializationError, EncoderVectorEntity, EntityContainerDeserializationEngine,
+    EntityContainerDeserializationError, EntityDeserializationEngine, FloatEncoder,
+    FloatEncoderVector, GgswCiphertext32, GgswCiphertext64, GgswCiphertextEntity,
+    GgswSeededCiphertext32, GgswSeededCiphertext64, GgswSeededCiphertextEntity, GlweCiphertext32,
+    GlweCiphertext64, GlweCiphertextEntity, GlweCiphertextVector32, GlweCiphertextVector64,
+    GlweCiphertextVectorEntity, GlweCompressedCiphertext32, GlweCompressedCiphertext64,
+    GlweCompressedCiphertextEntity, GlweSecretKey32, GlweSecretKey64, GlweSecretKeyEntity,
+    GlweSeededCiphertext32, GlweSeededCiphertext64, GlweSeededCiphertextEntity,
+    GlweSeededCiphertextVector32, GlweSeededCiphertextVector64, GlweSeededCiphertextVectorEntity,
+    LweBootstrapKey32, LweBootstrapKey64, LweBootstrapKeyEntity, LweCiphertext32, LweCiphertext64,
+    LweCiphertextEntity, LweCiphertextVector32, LweCiphertextVector64, LweCiphertextVectorEntity,
+    LweCircuitBootstrapPrivateFunctionalPackingKeyswitchKeys32,
+    LweCircuitBootstrapPrivateFunctionalPackingKeyswitchKeys64,
+    LweCircuitBootstrapPrivateFunctionalPackingKeyswitchKeysEntity, LweCompressedCiphertext32,
+    LweCompressedCiphertext64, LweCompressedCiphertextEntity, LweKeyswitchKey32, LweKeyswitchKey64,
+    LweKeyswitchKeyEntity, LwePackingKeyswitchKey32, LwePackingKeyswitchKey64,
+    LwePackingKeyswitchKeyEntity, LwePublicKey32, LwePublicKey64, LwePublicKeyEntity,
+    LweSecretKey32, LweSecretKey64, LweSecretKeyEntity, LweSeededBootstrapKey32,
+    LweSeededBootstrapKey64, LweSeededBootstrapKeyEntity, LweSeededCiphertext32,
+    LweSeededCiphertext64, LweSeededCiphertextEntity, LweSeededCiphertextVector32,
+    LweSeededCiphertextVector64, LweSeededCiphertextVectorEntity, LweSeededKeyswitchKey32,
+    LweSeededKeyswitchKey64, LweSeededKeyswitchKeyEntity, Plaintext32, Plaintext64,
+    PlaintextVector32, PlaintextVector64, PlaintextVectorEntity,
+};
+use crate::specification::container::{ContainerEntityKind, ContainerHeader};
+
+/// # Description:
+/// Implementation of [`EntityContainerDeserializationEngine`] for [`DefaultSerializationEngine`]
+/// that operates on 32 bits integers. It deserializes from a container a cleartext entity.
+impl EntityContainerDeserializationEngine<&[u8], Cleartext32> for DefaultSerializationEngine {
+    /// # Example:
+    /// ```
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// let input: u32 = 3;
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let cleartext: Cleartext32 = engine.create_cleartext_from(&input)?;
+    /// let mut serialization_engine = DefaultSerializationEngine::new(())?;
+    /// let serialized = serialization_engine.serialize_container(&cleartext)?;
+    /// let recovered = serialization_engine.deserialize_container(serialized.as_slice())?;
+    /// assert_eq!(cleartext, recovered);
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn deserialize_container(
+        &mut self,
+        serialized: &[u8],
+    ) -> Result<Cleartext32, EntityContainerDeserializationError<Self::EngineError>> {
+        EntityContainerDeserializationError::perform_generic_checks(
+            serialized,
+            ContainerEntityKind::Cleartext,
+            32,
+        )?;
+        let (header, payload) = ContainerHeader::split_payload(serialized)?;
+        let entity: Cleartext32 = self.deserialize(payload)?;
+        if header.parameters != [0, 0, 0, 0, 0] {
+            return Err(EntityContainerDeserializationError::ParametersMismatch);
+        }
+        Ok(entity)
+    }
+
+    unsafe fn deserialize_container_unchecked(&mut self, serialized: &[u8]) -> Cleartext32 {
+        let (_, payload) = ContainerHeader::split_payload(serialized).unwrap();
+        self.deserialize_unchecked(payload)
+    }
+}
+
+/// # Description:
+/// Implementation of [`EntityContainerDeserializationEngine`] for [`DefaultSerializationEngine`]
+/// that operates on 64 bits integers. It deserializes from a container a cleartext entity.
+impl EntityContainerDeserializationEngine<&[u8], Cleartext64> for DefaultSerializationEngine {
+    /// # Example:
+    /// ```
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// let input: u64 = 3;
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let cleartext: Cleartext64 = engine.create_cleartext_from(&input)?;
+    /// let mut serialization_engine = DefaultSerializationEngine::new(())?;
+    /// let serialized = serialization_engine.serialize_container(&cleartext)?;
+    /// let recovered = serialization_engine.deserialize_container(serialized.as_slice())?;
+    /// assert_eq!(cleartext, recovered);
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn deserialize_container(
+        &mut self,
+        serialized: &[u8],
+    ) -> Result<Cleartext64, EntityContainerDeserializationError<Self::EngineError>> {
+        EntityContainerDeserializationError::perform_generic_checks(
+            serialized,
+            ContainerEntityKind::Cleartext,
+            64,
+        )?;
+        let (header, payload) = ContainerHeader::split_payload(serialized)?;
+        let entity: Cleartext64 = self.deserialize(payload)?;
+        if header.parameters != [0, 0, 0, 0, 0] {
+            return Err(EntityContainerDeserializationError::ParametersMismatch);
+        }
+        Ok(entity)
+    }
+
+    unsafe fn deserialize_container_unchecked(&mut self, serialized: &[u8]) -> Cleartext64 {
+        let (_, payload) = ContainerHeader::split_payload(serialized).unwrap();
+        self.deserialize_unchecked(payload)
+    }
+}
+
+/// # Description:
+/// Implementation of [`EntityContainerDeserializationEngine`] for [`DefaultSerializationEngine`]
+/// that operates on 64 bits integers. It deserializes from a container a floating point cleartext
+/// entity.
+impl EntityContainerDeserializationEngine<&[u8], CleartextF64> for DefaultSerializationEngine {
+    /// # Example:
+    /// ```
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// let input: f64 = 3.;
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let cleartext: CleartextF64 = engine.create_cleartext_from(&input)?;
+    /// let mut serialization_engine = DefaultSerializationEngine::new(())?;
+    /// let serialized = serialization_engine.serialize_container(&cleartext)?;
+    /// let recovered = serialization_engine.deserialize_container(serialized.as_slice())?;
+    /// assert_eq!(cleartext, recovered);
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn deserialize_container(
+        &mut self,
+        serialized: &[u8],
+    ) -> Result<CleartextF64, EntityContainerDeserializationError<Self::EngineError>> {
+        EntityContainerDeserializationError::perform_generic_checks(
+            serialized,
+            ContainerEntityKind::CleartextF64,
+            64,
+        )?;
+        let (header, payload) = ContainerHeader::split_payload(serialized)?;
+        let entity: CleartextF64 = self.deserialize(payload)?;
+        if header.parameters != [0, 0, 0, 0, 0] {
+            return Err(EntityContainerDeserializationError::ParametersMismatch);
+        }
+        Ok(entity)
+    }
+
+    unsafe fn deserialize_container_unchecked(&mut self, serialized: &[u8]) -> CleartextF64 {
+        let (_, payload) = ContainerHeader::split_payload(serialized).unwrap();
+        self.deserialize_unchecked(payload)
+    }
+}
+
+/// # Description:
+/// Implementation of [`EntityContainerDeserializationEngine`] for [`DefaultSerializationEngine`]
+/// that operates on 32 bits integers. It deserializes from a container a cleartext vector entity.
+impl EntityContainerDeserializationEngine<&[u8], CleartextVector32> for DefaultSerializationEngine {
+    /// # Example:
+    /// ```
+    /// use concrete_core::prelude::{CleartextCount, *};
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// let input = vec![3_u32; 100];
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let cleartext_vector: CleartextVector32 = engine.create_cleartext_vector_from(&input)?;
+    /// let mut serialization_engine = DefaultSerializationEngine::new(())?;
+    /// let serialized = serialization_engine.serialize_container(&cleartext_vector)?;
+    /// let recovered = serialization_engine.deserialize_container(serialized.as_slice())?;
+    /// assert_eq!(cleartext_vector, recovered);
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn deserialize_container(
+        &mut self,
+        serialized: &[u8],
+    ) -> Result<CleartextVector32, EntityContainerDeserializationError<Self::EngineError>> {
+        EntityContainerDeserializationError::perform_generic_checks(
+            serialized,
+            ContainerEntityKind::CleartextVector,
+            32,
+        )?;
+        let (header, payload) = ContainerHeader::split_payload(serialized)?;
+        let entity: CleartextVector32 = self.deserialize(payload)?;
+        if header.parameters != [entity.cleartext_count().0 as u64, 0, 0, 0, 0] {
+            return Err(EntityContainerDeserializationError::ParametersMismatch);
+        }
+        Ok(entity)
+    }
+
+    unsafe fn deserialize_container_unchecked(&mut self, serialized: &[u8]) -> CleartextVector32 {
+        let (_, payload) = ContainerHeader::split_payload(serialized).unwrap();
+        self.deserialize_unchecked(payload)
+    }
+}
+
+/// # Description:
+/// Implementation of [`EntityContainerDeserializationEngine`] for [`DefaultSerializationEngine`]
+/// that operates on 64 bits integers. It deserializes from a container a cleartext vector entity.
+impl EntityContainerDeserializationEngine<&[u8], CleartextVector64> for DefaultSerializationEngine {
+    /// # Example:
+    /// ```
+    /// use concrete_core::prelude::{CleartextCount, *};
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// let input = vec![3_u64; 100];
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let cleartext_vector: CleartextVector64 = engine.create_cleartext_vector_from(&input)?;
+    /// let mut serialization_engine = DefaultSerializationEngine::new(())?;
+    /// let serialized = serialization_engine.serialize_container(&cleartext_vector)?;
+    /// let recovered = serialization_engine.deserialize_container(serialized.as_slice())?;
+    /// assert_eq!(cleartext_vector, recovered);
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn deserialize_container(
+        &mut self,
+        serialized: &[u8],
+    ) -> Result<CleartextVector64, EntityContainerDeserializationError<Self::EngineError>> {
+        EntityContainerDeserializationError::perform_generic_checks(
+            serialized,
+            ContainerEntityKind::CleartextVector,
+            64,
+        )?;
+        let (header, payload) = ContainerHeader::split_payload(serialized)?;
+        let entity: CleartextVector64 = self.deserialize(payload)?;
+        if header.parameters != [entity.cleartext_count().0 as u64, 0, 0, 0, 0] {
+            return Err(EntityContainerDeserializationError::ParametersMismatch);
+        }
+        Ok(entity)
+    }
+
+    unsafe fn deserialize_container_unchecked(&mut self, serialized: &[u8]) -> CleartextVector64 {
+        let (_, payload) = ContainerHeader::split_payload(serialized).unwrap();
+        self.deserialize_unchecked(payload)
+    }
+}
+
+/// # Description:
+/// Implementation of [`EntityContainerDeserializationEngine`] for [`DefaultSerializationEngine`]
+/// that operates on 64 bits integers. It deserializes from a container a floating point cleartext
+/// vector entity.
+impl EntityContainerDeserializationEngine<&[u8], CleartextVectorF64>
+    for DefaultSerializationEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_core::prelude::{CleartextCount, *};
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// let input = vec![3.0_f64; 100];
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let cleartext_vector: CleartextVectorF64 = engine.create_cleartext_vector_from(&input)?;
+    /// let mut serialization_engine = DefaultSerializationEngine::new(())?;
+    /// let serialized = serialization_engine.serialize_container(&cleartext_vector)?;
+    /// let recovered = serialization_engine.deserialize_container(serialized.as_slice())?;
+    /// assert_eq!(cleartext_vector, recovered);
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn deserialize_container(
+        &mut self,
+        serialized: &[u8],
+    ) -> Result<CleartextVectorF64, EntityContainerDeserializationError<Self::EngineError>> {
+        EntityContainerDeserializationError::perform_generic_checks(
+            serialized,
+            ContainerEntityKind::CleartextVectorF64,
+            64,
+        )?;
+        let (header, payload) = ContainerHeader::split_payload(serialized)?;
+        let entity: CleartextVectorF64 = self.deserialize(payload)?;
+        if header.parameters != [entity.cleartext_count().0 as u64, 0, 0, 0, 0] {
+            return Err(EntityContainerDeserializationError::ParametersMismatch);
+        }
+        Ok(entity)
+    }
+
+    unsafe fn deserialize_container_unchecked(&mut self, serialized: &[u8]) -> CleartextVectorF64 {
+        let (_, payload) = ContainerHeader::split_payload(serialized).unwrap();
+        self.deserialize_unchecked(payload)
+    }
+}
+
+/// # Description:
+/// Implementation of [`EntityContainerDeserializationEngine`] for [`DefaultSerializationEngine`]
+/// that operates on 32 bits integers. It deserializes from a container a GGSW ciphertext entity.
+impl EntityContainerDeserializationEngine<&[u8], GgswCiphertext32> for DefaultSerializationEngine {
+    /// # Example:
+    /// ```
+    /// use concrete_core::prelude::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, PolynomialSize, Variance, *,
+    /// };
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let glwe_dimension = GlweDimension(2);
+    /// let polynomial_size = PolynomialSize(4);
+    /// let level = DecompositionLevelCount(1);
+    /// let base_log = DecompositionBaseLog(4);
+    /// // Here a hard-set encoding is applied (shift by 20 bits)
+    /// let input = 3_u32 << 20;
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let key: GlweSecretKey32 =
+    ///     engine.generate_new_glwe_secret_key(glwe_dimension, polynomial_size)?;
+    /// let plaintext = engine.create_plaintext_from(&input)?;
+    ///
+    /// let ciphertext =
+    ///     engine.encrypt_scalar_ggsw_ciphertext(&key, &plaintext, noise, level, base_log)?;
+    ///
+    /// let mut serialization_engine = DefaultSerializationEngine::new(())?;
+    /// let serialized = serialization_engine.serialize_container(&ciphertext)?;
+    /// let recovered = serialization_engine.deserialize_container(serialized.as_slice())?;
+    /// assert_eq!(ciphertext, recovered);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn deserialize_container(
+        &mut self,
+        serialized: &[u8],
+    ) -> Result<GgswCiphertext32, EntityContainerDeserializationError<Self::EngineError>> {
+        EntityContainerDeserializationError::perform_generic_checks(
+            serialized,
+            ContainerEntityKind::GgswCiphertext,
+            32,
+        )?;
+        let (header, payload) = ContainerHeader::split_payload(serialized)?;
+        let entity: GgswCiphertext32 = self.deserialize(payload)?;
+        if header.parameters
+            != [
+                entity.glwe_dimension().0 as u64,
+                entity.polynomial_size().0 as u64,
+                entity.decomposition_base_log().0 as u64,
+                entity.decomposition_level_count().0 as u64,
+                0,
+            ]
+        {
+            return Err(EntityContainerDeserializationError::ParametersMismatch);
+        }
+        Ok(entity)
+    }
+
+    unsafe fn deserialize_container_unchecked(&mut self, serialized: &[u8]) -> GgswCiphertext32 {
+        let (_, payload) = ContainerHeader::split_payload(serialized).unwrap();
+        self.deserialize_unchecked(payload)
+    }
+}
+
+/// # Description:
+/// Implementation of [`EntityContainerDeserializationEngine`] for [`DefaultSerializationEngine`]
+/// that operates on 64 bits integers. It deserializes from a container a GGSW ciphertext entity.
+impl EntityContainerDeserializationEngine<&[u8], GgswCiphertext64> for DefaultSerializationEngine {
+    /// # Example:
+    /// ```
+    /// use concrete_core::prelude::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, PolynomialSize, Variance, *,
+    /// };
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let glwe_dimension = GlweDimension(2);
+    /// let polynomial_size = PolynomialSize(4);
+    /// let level = DecompositionLevelCount(1);
+    /// let base_log = DecompositionBaseLog(4);
+    /// // Here a hard-set encoding is applied (shift by 50 bits)
+    /// let input = 3_u64 << 50;
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let key: GlweSecretKey64 =
+    ///     engine.generate_new_glwe_secret_key(glwe_dimension, polynomial_size)?;
+    /// let plaintext = engine.create_plaintext_from(&input)?;
+    ///
+    /// let ciphertext =
+    ///     engine.encrypt_scalar_ggsw_ciphertext(&key, &plaintext, noise, level, base_log)?;
+    ///
+    /// let mut serialization_engine = DefaultSerializationEngine::new(())?;
+    /// let serialized = serialization_engine.serialize_container(&ciphertext)?;
+    /// let recovered = serialization_engine.deserialize_container(serialized.as_slice())?;
+    /// assert_eq!(ciphertext, recovered);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn deserialize_container(
+        &mut self,
+        serialized: &[u8],
+    ) -> Result<GgswCiphertext64, EntityContainerDeserializationError<Self::EngineError>> {
+        EntityContainerDeserializationError::perform_generic_checks(
+            serialized,
+            ContainerEntityKind::GgswCiphertext,
+            64,
+        )?;
+        let (header, payload) = ContainerHeader::split_payload(serialized)?;
+        let entity: GgswCiphertext64 = self.deserialize(payload)?;
+        if header.parameters
+            != [
+                entity.glwe_dimension().0 as u64,
+                entity.polynomial_size().0 as u64,
+                entity.decomposition_base_log().0 as u64,
+                entity.decomposition_level_count().0 as u64,
+                0,
+            ]
+        {
+            return Err(EntityContainerDeserializationError::ParametersMismatch);
+        }
+        Ok(entity)
+    }
+
+    unsafe fn deserialize_container_unchecked(&mut self, serialized: &[u8]) -> GgswCiphertext64 {
+        let (_, payload) = ContainerHeader::split_payload(serialized).unwrap();
+        self.deserialize_unchecked(payload)
+    }
+}
+
+/// # Description:
+/// Implementation of [`EntityContainerDeserializationEngine`] for [`DefaultSerializationEngine`]
+/// that operates on 32 bits integers. It deserializes from a container a seeded GGSW ciphertext
+/// entity.
+impl EntityContainerDeserializationEngine<&[u8], GgswSeededCiphertext32>
+    for DefaultSerializationEngine
+{
+    fn deserialize_container(
+        &mut self,
+        serialized: &[u8],
+    ) -> Result<GgswSeededCiphertext32, EntityContainerDeserializationError<Self::EngineError>>
+    {
+        EntityContainerDeserializationError::perform_generic_checks(
+            serialized,
+            ContainerEntityKind::GgswSeededCiphertext,
+            32,
+        )?;
+        let (header, payload) = ContainerHeader::split_payload(serialized)?;
+        let entity: GgswSeededCiphertext32 = self.deserialize(payload)?;
+        if header.parameters
+            != [
+                entity.glwe_dimension().0 as u64,
+                entity.polynomial_size().0 as u64,
+                entity.decomposition_base_log().0 as u64,
+                entity.decomposition_level_count().0 as u64,
+                0,
+            ]
+        {
+            return Err(EntityContainerDeserializationError::ParametersMismatch);
+        }
+        Ok(entity)
+    }
+
+    unsafe fn deserialize_container_unchecked(
+        &mut self,
+        serialized: &[u8],
+    ) -> GgswSeededCiphertext32 {
+        let (_, payload) = ContainerHeader::split_payload(serialized).unwrap();
+        self.deserialize_unchecked(payload)
+    }
+}
+
+/// # Description:
+/// Implementation of [`EntityContainerDeserializationEngine`] for [`DefaultSerializationEngine`]
+/// that operates on 64 bits integers. It deserializes from a container a seeded GGSW ciphertext
+/// entity.
+impl EntityContainerDeserializationEngine<&[u8], GgswSeededCiphertext64>
+    for DefaultSerializationEngine
+{
+    fn deserialize_container(
+        &mut self,
+        serialized: &[u8],
+    ) -> Result<GgswSeededCiphertext64, EntityContainerDeserializationError<Self::EngineError>>
+    {
+        EntityContainerDeserializationError::perform_generic_checks(
+            serialized,
+            ContainerEntityKind::GgswSeededCiphertext,
+            64,
+        )?;
+        let (header, payload) = ContainerHeader::split_payload(serialized)?;
+        let entity: GgswSeededCiphertext64 = self.deserialize(payload)?;
+        if header.parameters
+            != [
+                entity.glwe_dimension().0 as u64,
+                entity.polynomial_size().0 as u64,
+                entity.decomposition_base_log().0 as u64,
+                entity.decomposition_level_count().0 as u64,
+                0,
+            ]
+        {
+            return Err(EntityContainerDeserializationError::ParametersMismatch);
+        }
+        Ok(entity)
+    }
+
+    unsafe fn deserialize_container_unchecked(
+        &mut self,
+        serialized: &[u8],
+    ) -> GgswSeededCiphertext64 {
+        let (_, payload) = ContainerHeader::split_payload(serialized).unwrap();
+        self.deserialize_unchecked(payload)
+    }
+}
+
+/// # Description:
+/// Implementation of [`EntityContainerDeserializationEngine`] for [`DefaultSerializationEngine`]
+/// that operates on 32 bits integers. It deserializes from a container a GLWE ciphertext entity.
+impl EntityContainerDeserializationEngine<&[u8], GlweCiphertext32> for DefaultSerializationEngine {
+    /// # Example:
+    /// ```
+    /// use concrete_core::prelude::{GlweDimension, PolynomialSize, Variance, *};
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let glwe_dimension = GlweDimension(2);
+    /// let polynomial_size = PolynomialSize(4);
+    /// // There are always polynomial_size messages encrypted in the GLWE ciphertext
+    /// // Here a hard-set encoding is applied (shift by 20 bits)
+    /// let input = vec![3_u32 << 20; polynomial_size.0];
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let key: GlweSecretKey32 =
+    ///     engine.generate_new_glwe_secret_key(glwe_dimension, polynomial_size)?;
+    /// let plaintext_vector = engine.create_plaintext_vector_from(&input)?;
+    ///
+    /// let ciphertext = engine.encrypt_glwe_ciphertext(&key, &plaintext_vector, noise)?;
+    ///
+    /// let mut serialization_engine = DefaultSerializationEngine::new(())?;
+    /// let serialized = serialization_engine.serialize_container(&ciphertext)?;
+    /// let recovered = serialization_engine.deserialize_container(serialized.as_slice())?;
+    /// assert_eq!(ciphertext, recovered);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn deserialize_container(
+        &mut self,
+        serialized: &[u8],
+    ) -> Result<GlweCiphertext32, EntityContainerDeserializationError<Self::EngineError>> {
+        EntityContainerDeserializationError::perform_generic_checks(
+            serialized,
+            ContainerEntityKind::GlweCiphertext,
+            32,
+        )?;
+        let (header, payload) = ContainerHeader::split_payload(serialized)?;
+        let entity: GlweCiphertext32 = self.deserialize(payload)?;
+        if header.parameters
+            != [
+                entity.glwe_dimension().0 as u64,
+                entity.polynomial_size().0 as u64,
+                0,
+                0,
+                0,
+            ]
+        {
+            return Err(EntityContainerDeserializationError::ParametersMismatch);
+        }
+        Ok(entity)
+    }
+
+    unsafe fn deserialize_container_unchecked(&mut self, serialized: &[u8]) -> GlweCiphertext32 {
+        let (_, payload) = ContainerHeader::split_payload(serialized).unwrap();
+        self.deserialize_unchecked(payload)
+    }
+}
+
+/// # Description:
+/// Implementation of [`EntityContainerDeserializationEngine`] for [`DefaultSerializationEngine`]
+/// that operates on 64 bits integers. It deserializes from a container a GLWE ciphertext entity.
+impl EntityContainerDeserializationEngine<&[u8], GlweCiphertext64> for DefaultSerializationEngine {
+    /// # Example:
+    /// ```
+    /// use concrete_core::prelude::{GlweDimension, PolynomialSize, Variance, *};
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let glwe_dimension = GlweDimension(2);
+    /// let polynomial_size = PolynomialSize(4);
+    /// // There are always polynomial_size messages encrypted in the GLWE ciphertext
+    /// // Here a hard-set encoding is applied (shift by 50 bits)
+    /// let input = vec![3_u64 << 50; polynomial_size.0];
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let key: GlweSecretKey64 =
+    ///     engine.generate_new_glwe_secret_key(glwe_dimension, polynomial_size)?;
+    /// let plaintext_vector = engine.create_plaintext_vector_from(&input)?;
+    ///
+    /// let ciphertext = engine.encrypt_glwe_ciphertext(&key, &plaintext_vector, noise)?;
+    ///
+    /// let mut serialization_engine = DefaultSerializationEngine::new(())?;
+    /// let serialized = serialization_engine.serialize_container(&ciphertext)?;
+    /// let recovered = serialization_engine.deserialize_container(serialized.as_slice())?;
+    /// assert_eq!(ciphertext, recovered);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn deserialize_container(
+        &mut self,
+        serialized: &[u8],
+    ) -> Result<GlweCiphertext64, EntityContainerDeserializationError<Self::EngineError>> {
+        EntityContainerDeserializationError::perform_generic_checks(
+            serialized,
+            ContainerEntityKind::GlweCiphertext,
+            64,
+        )?;
+        let (header, payload) = ContainerHeader::split_payload(serialized)?;
+        let entity: GlweCiphertext64 = self.deserialize(payload)?;
+        if header.parameters
+            != [
+                entity.glwe_dimension().0 as u64,
+                entity.polynomial_size().0 as u64,
+                0,
+                0,
+                0,
+            ]
+        {
+            return Err(EntityContainerDeserializationError::ParametersMismatch);
+        }
+        Ok(entity)
+    }
+
+    unsafe fn deserialize_container_unchecked(&mut self, serialized: &[u8]) -> GlweCiphertext64 {
+        let (_, payload) = ContainerHeader::split_payload(serialized).unwrap();
+        self.deserialize_unchecked(payload)
+    }
+}
+
+/// # Description:
+/// Implementation of [`EntityContainerDeserializationEngine`] for [`DefaultSerializationEngine`]
+/// that operates on 32 bits integers. It deserializes from a container a GLWE ciphertext vector
+/// entity.
+impl EntityContainerDeserializationEngine<&[u8], GlweCiphertextVector32>
+    for DefaultSerializationEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_core::prelude::{GlweCiphertextCount, GlweDimension, PolynomialSize, Variance, *};
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let glwe_dimension = GlweDimension(2);
+    /// let polynomial_size = PolynomialSize(4);
+    /// // Here a hard-set encoding is applied (shift by 20 bits)
+    /// let input = vec![3_u32 << 20; 8];
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let key: GlweSecretKey32 =
+    ///     engine.generate_new_glwe_secret_key(glwe_dimension, polynomial_size)?;
+    /// let plaintext_vector = engine.create_plaintext_vector_from(&input)?;
+    ///
+    /// let ciphertext_vector =
+    ///     engine.encrypt_glwe_ciphertext_vector(&key, &plaintext_vector, noise)?;
+    ///
+    /// let mut serialization_engine = DefaultSerializationEngine::new(())?;
+    /// let serialized = serialization_engine.serialize_container(&ciphertext_vector)?;
+    /// let recovered = serialization_engine.deserialize_container(serialized.as_slice())?;
+    /// assert_eq!(ciphertext_vector, recovered);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn deserialize_container(
+        &mut self,
+        serialized: &[u8],
+    ) -> Result<GlweCiphertextVector32, EntityContainerDeserializationError<Self::EngineError>>
+    {
+        EntityContainerDeserializationError::perform_generic_checks(
+            serialized,
+            ContainerEntityKind::GlweCiphertextVector,
+            32,
+        )?;
+        let (header, payload) = ContainerHeader::split_payload(serialized)?;
+        let entity: GlweCiphertextVector32 = self.deserialize(payload)?;
+        if header.parameters
+            != [
+                entity.glwe_dimension().0 as u64,
+                entity.polynomial_size().0 as u64,
+                entity.glwe_ciphertext_count().0 as u64,
+                0,
+                0,
+            ]
+        {
+            return Err(EntityContainerDeserializationError::ParametersMismatch);
+        }
+        Ok(entity)
+    }
+
+    unsafe fn deserialize_container_unchecked(
+        &mut self,
+        serialized: &[u8],
+    ) -> GlweCiphertextVector32 {
+        let (_, payload) = ContainerHeader::split_payload(serialized).unwrap();
+        self.deserialize_unchecked(payload)
+    }
+}
+
+/// # Description:
+/// Implementation of [`EntityContainerDeserializationEngine`] for [`DefaultSerializationEngine`]
+/// that operates on 64 bits integers. It deserializes from a container a GLWE ciphertext vector
+/// entity.
+impl EntityContainerDeserializationEngine<&[u8], GlweCiphertextVector64>
+    for DefaultSerializationEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_core::prelude::{GlweCiphertextCount, GlweDimension, PolynomialSize, Variance, *};
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let glwe_dimension = GlweDimension(2);
+    /// let polynomial_size = PolynomialSize(4);
+    /// // Here a hard-set encoding is applied (shift by 50 bits)
+    /// let input = vec![3_u64 << 50; 8];
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let key: GlweSecretKey64 =
+    ///     engine.generate_new_glwe_secret_key(glwe_dimension, polynomial_size)?;
+    /// let plaintext_vector = engine.create_plaintext_vector_from(&input)?;
+    ///
+    /// let ciphertext_vector =
+    ///     engine.encrypt_glwe_ciphertext_vector(&key, &plaintext_vector, noise)?;
+    ///
+    /// let mut serialization_engine = DefaultSerializationEngine::new(())?;
+    /// let serialized = serialization_engine.serialize_container(&ciphertext_vector)?;
+    /// let recovered = serialization_engine.deserialize_container(serialized.as_slice())?;
+    /// assert_eq!(ciphertext_vector, recovered);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn deserialize_container(
+        &mut self,
+        serialized: &[u8],
+    ) -> Result<GlweCiphertextVector64, EntityContainerDeserializationError<Self::EngineError>>
+    {
+        EntityContainerDeserializationError::perform_generic_checks(
+            serialized,
+            ContainerEntityKind::GlweCiphertextVector,
+            64,
+        )?;
+        let (header, payload) = ContainerHeader::split_payload(serialized)?;
+        let entity: GlweCiphertextVector64 = self.deserialize(payload)?;
+        if header.parameters
+            != [
+                entity.glwe_dimension().0 as u64,
+                entity.polynomial_size().0 as u64,
+                entity.glwe_ciphertext_count().0 as u64,
+                0,
+                0,
+            ]
+        {
+            return Err(EntityContainerDeserializationError::ParametersMismatch);
+        }
+        Ok(entity)
+    }
+
+    unsafe fn deserialize_container_unchecked(
+        &mut self,
+        serialized: &[u8],
+    ) -> GlweCiphertextVector64 {
+        let (_, payload) = ContainerHeader::split_payload(serialized).unwrap();
+        self.deserialize_unchecked(payload)
+    }
+}
+
+/// # Description:
+/// Implementation of [`EntityContainerDeserializationEngine`] for [`DefaultSerializationEngine`]
+/// that operates on 32 bits integers. It deserializes from a container a GLWE secret key entity.
+impl EntityContainerDeserializationEngine<&[u8], GlweSecretKey32> for DefaultSerializationEngine {
+    /// # Example:
+    /// ```
+    /// use concrete_core::prelude::{GlweDimension, PolynomialSize, *};
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let glwe_dimension = GlweDimension(2);
+    /// let polynomial_size = PolynomialSize(4);
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let glwe_secret_key: GlweSecretKey32 =
+    ///     engine.generate_new_glwe_secret_key(glwe_dimension, polynomial_size)?;
+    ///
+    /// let mut serialization_engine = DefaultSerializationEngine::new(())?;
+    /// let serialized = serialization_engine.serialize_container(&glwe_secret_key)?;
+    /// let recovered = serialization_engine.deserialize_container(serialized.as_slice())?;
+    /// assert_eq!(glwe_secret_key, recovered);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn deserialize_container(
+        &mut self,
+        serialized: &[u8],
+    ) -> Result<GlweSecretKey32, EntityContainerDeserializationError<Self::EngineError>> {
+        EntityContainerDeserializationError::perform_generic_checks(
+            serialized,
+            ContainerEntityKind::GlweSecretKey,
+            32,
+        )?;
+        let (header, payload) = ContainerHeader::split_payload(serialized)?;
+        let entity: GlweSecretKey32 = self.deserialize(payload)?;
+        if header.parameters
+            != [
+                entity.glwe_dimension().0 as u64,
+                entity.polynomial_size().0 as u64,
+                0,
+                0,
+                0,
+            ]
+        {
+            return Err(EntityContainerDeserializationError::ParametersMismatch);
+        }
+        Ok(entity)
+    }
+
+    unsafe fn deserialize_container_unchecked(&mut self, serialized: &[u8]) -> GlweSecretKey32 {
+        let (_, payload) = ContainerHeader::split_payload(serialized).unwrap();
+        self.deserialize_unchecked(payload)
+    }
+}
+
+/// # Description:
+/// Implementation of [`EntityContainerDeserializationEngine`] for [`DefaultSerializationEngine`]
+/// that operates on 64 bits integers. It deserializes from a container a GLWE secret key entity.
+impl EntityContainerDeserializationEngine<&[u8], GlweSecretKey64> for DefaultSerializationEngine {
+    /// # Example:
+    /// ```
+    /// use concrete_core::prelude::{GlweDimension, PolynomialSize, *};
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let glwe_dimension = GlweDimension(2);
+    /// let polynomial_size = PolynomialSize(4);
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let glwe_secret_key: GlweSecretKey64 =
+    ///     engine.generate_new_glwe_secret_key(glwe_dimension, polynomial_size)?;
+    ///
+    /// let mut serialization_engine = DefaultSerializationEngine::new(())?;
+    /// let serialized = serialization_engine.serialize_container(&glwe_secret_key)?;
+    /// let recovered = serialization_engine.deserialize_container(serialized.as_slice())?;
+    /// assert_eq!(glwe_secret_key, recovered);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn deserialize_container(
+        &mut self,
+        serialized: &[u8],
+    ) -> Result<GlweSecretKey64, EntityContainerDeserializationError<Self::EngineError>> {
+        EntityContainerDeserializationError::perform_generic_checks(
+            serialized,
+            ContainerEntityKind::GlweSecretKey,
+            64,
+        )?;
+        let (header, payload) = ContainerHeader::split_payload(serialized)?;
+        let entity: GlweSecretKey64 = self.deserialize(payload)?;
+        if header.parameters
+            != [
+                entity.glwe_dimension().0 as u64,
+                entity.polynomial_size().0 as u64,
+                0,
+                0,
+                0,
+            ]
+        {
+            return Err(EntityContainerDeserializationError::ParametersMismatch);
+        }
+        Ok(entity)
+    }
+
+    unsafe fn deserialize_container_unchecked(&mut self, serialized: &[u8]) -> GlweSecretKey64 {
+        let (_, payload) = ContainerHeader::split_payload(serialized).unwrap();
+        self.deserialize_unchecked(payload)
+    }
+}
+
+/// # Description:
+/// Implementation of [`EntityContainerDeserializationEngine`] for [`DefaultSerializationEngine`]
+/// that operates on 32 bits integers. It deserializes from a container a GLWE seeded ciphertext
+/// entity.
+impl EntityContainerDeserializationEngine<&[u8], GlweSeededCiphertext32>
+    for DefaultSerializationEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_core::prelude::{GlweDimension, PolynomialSize, Variance, *};
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let glwe_dimension = GlweDimension(2);
+    /// let polynomial_size = PolynomialSize(4);
+    /// // There are always polynomial_size messages encrypted in the GLWE ciphertext
+    /// // Here a hard-set encoding is applied (shift by 20 bits)
+    /// let input = vec![3_u32 << 20; polynomial_size.0];
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let key: GlweSecretKey32 =
+    ///     engine.generate_new_glwe_secret_key(glwe_dimension, polynomial_size)?;
+    /// let plaintext_vector = engine.create_plaintext_vector_from(&input)?;
+    ///
+    /// let seeded_ciphertext =
+    ///     engine.encrypt_glwe_seeded_ciphertext(&key, &plaintext_vector, noise)?;
+    ///
+    /// let mut serialization_engine = DefaultSerializationEngine::new(())?;
+    /// let serialized = serialization_engine.serialize_container(&seeded_ciphertext)?;
+    /// let recovered = serialization_engine.deserialize_container(serialized.as_slice())?;
+    /// assert_eq!(seeded_ciphertext, recovered);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn deserialize_container(
+        &mut self,
+        serialized: &[u8],
+    ) -> Result<GlweSeededCiphertext32, EntityContainerDeserializationError<Self::EngineError>>
+    {
+        EntityContainerDeserializationError::perform_generic_checks(
+            serialized,
+            ContainerEntityKind::GlweSeededCiphertext,
+            32,
+        )?;
+        let (header, payload) = ContainerHeader::split_payload(serialized)?;
+        let entity: GlweSeededCiphertext32 = self.deserialize(payload)?;
+        if header.parameters
+            != [
+                entity.glwe_dimension().0 as u64,
+                entity.polynomial_size().0 as u64,
+                0,
+                0,
+                0,
+            ]
+        {
+            return Err(EntityContainerDeserializationError::ParametersMismatch);
+        }
+        Ok(entity)
+    }
+
+    unsafe fn deserialize_container_unchecked(
+        &mut self,
+        serialized: &[u8],
+    ) -> GlweSeededCiphertext32 {
+        let (_, payload) = ContainerHeader::split_payload(serialized).unwrap();
+        self.deserialize_unchecked(payload)
+    }
+}
+
+/// # Description:
+/// Implementation of [`EntityContainerDeserializationEngine`] for [`DefaultSerializationEngine`]
+/// that operates on 64 bits integers. It deserializes from a container a GLWE seeded ciphertext
+/// entity.
+impl EntityContainerDeserializationEngine<&[u8], GlweSeededCiphertext64>
+    for DefaultSerializationEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_core::prelude::{GlweDimension, PolynomialSize, Variance, *};
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let glwe_dimension = GlweDimension(2);
+    /// let polynomial_size = PolynomialSize(4);
+    /// // There are always polynomial_size messages encrypted in the GLWE ciphertext
+    /// // Here a hard-set encoding is applied (shift by 50 bits)
+    /// let input = vec![3_u64 << 50; polynomial_size.0];
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let key: GlweSecretKey64 =
+    ///     engine.generate_new_glwe_secret_key(glwe_dimension, polynomial_size)?;
+    /// let plaintext_vector = engine.create_plaintext_vector_from(&input)?;
+    ///
+    /// let seeded_ciphertext =
+    ///     engine.encrypt_glwe_seeded_ciphertext(&key, &plaintext_vector, noise)?;
+    ///
+    /// let mut serialization_engine = DefaultSerializationEngine::new(())?;
+    /// let serialized = serialization_engine.serialize_container(&seeded_ciphertext)?;
+    /// let recovered = serialization_engine.deserialize_container(serialized.as_slice())?;
+    /// assert_eq!(seeded_ciphertext, recovered);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn deserialize_container(
+        &mut self,
+        serialized: &[u8],
+    ) -> Result<GlweSeededCiphertext64, EntityContainerDeserializationError<Self::EngineError>>
+    {
+        EntityContainerDeserializationError::perform_generic_checks(
+            serialized,
+            ContainerEntityKind::GlweSeededCiphertext,
+            64,
+        )?;
+        let (header, payload) = ContainerHeader::split_payload(serialized)?;
+        let entity: GlweSeededCiphertext64 = self.deserialize(payload)?;
+        if header.parameters
+            != [
+                entity.glwe_dimension().0 as u64,
+                entity.polynomial_size().0 as u64,
+                0,
+                0,
+                0,
+            ]
+        {
+            return Err(EntityContainerDeserializationError::ParametersMismatch);
+        }
+        Ok(entity)
+    }
+
+    unsafe fn deserialize_container_unchecked(
+        &mut self,
+        serialized: &[u8],
+    ) -> GlweSeededCiphertext64 {
+        let (_, payload) = ContainerHeader::split_payload(serialized).unwrap();
+        self.deserialize_unchecked(payload)
+    }
+}
+
+/// # Description:
+/// Implementation of [`EntityContainerDeserializationEngine`] for [`DefaultSerializationEngine`]
+/// that operates on 32 bits integers. It deserializes from a container a GLWE seeded ciphertext
+/// entity.
+impl EntityContainerDeserializationEngine<&[u8], GlweCompressedCiphertext32>
+    for DefaultSerializationEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_core::prelude::{GlweDimension, PolynomialSize, Variance, *};
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let glwe_dimension = GlweDimension(2);
+    /// let polynomial_size = PolynomialSize(4);
+    /// // There are always polynomial_size messages encrypted in the GLWE ciphertext
+    /// // Here a hard-set encoding is applied (shift by 20 bits)
+    /// let input = vec![3_u32 << 20; polynomial_size.0];
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let key: GlweSecretKey32 =
+    ///     engine.generate_new_glwe_secret_key(glwe_dimension, polynomial_size)?;
+    /// let plaintext_vector = engine.create_plaintext_vector_from(&input)?;
+    ///
+    /// let ciphertext = engine.encrypt_glwe_ciphertext(&key, &plaintext_vector, noise)?;
+    /// let compressed_ciphertext =
+    ///     engine.compress_glwe_ciphertext(&ciphertext, CiphertextModulusLog(16))?;
+    ///
+    /// let mut serialization_engine = DefaultSerializationEngine::new(())?;
+    /// let serialized = serialization_engine.serialize_container(&compressed_ciphertext)?;
+    /// let recovered = serialization_engine.deserialize_container(serialized.as_slice())?;
+    /// assert_eq!(compressed_ciphertext, recovered);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn deserialize_container(
+        &mut self,
+        serialized: &[u8],
+    ) -> Result<GlweCompressedCiphertext32, EntityContainerDeserializationError<Self::EngineError>>
+    {
+        EntityContainerDeserializationError::perform_generic_checks(
+            serialized,
+            ContainerEntityKind::GlweCompressedCiphertext,
+            32,
+        )?;
+        let (header, payload) = ContainerHeader::split_payload(serialized)?;
+        let entity: GlweCompressedCiphertext32 = self.deserialize(payload)?;
+        if header.parameters
+            != [
+                entity.glwe_dimension().0 as u64,
+                entity.polynomial_size().0 as u64,
+                entity.ciphertext_modulus_log().0 as u64,
+                0,
+                0,
+            ]
+        {
+            return Err(EntityContainerDeserializationError::ParametersMismatch);
+        }
+        Ok(entity)
+    }
+
+    unsafe fn deserialize_container_unchecked(
+        &mut self,
+        serialized: &[u8],
+    ) -> GlweCompressedCiphertext32 {
+        let (_, payload) = ContainerHeader::split_payload(serialized).unwrap();
+        self.deserialize_unchecked(payload)
+    }
+}
+
+/// # Description:
+/// Implementation of [`EntityContainerDeserializationEngine`] for [`DefaultSerializationEngine`]
+/// that operates on 64 bits integers. It deserializes from a container a GLWE seeded ciphertext
+/// entity.
+impl EntityContainerDeserializationEngine<&[u8], GlweCompressedCiphertext64>
+    for DefaultSerializationEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_core::prelude::{GlweDimension, PolynomialSize, Variance, *};
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let glwe_dimension = GlweDimension(2);
+    /// let polynomial_size = PolynomialSize(4);
+    /// // There are always polynomial_size messages encrypted in the GLWE ciphertext
+    /// // Here a hard-set encoding is applied (shift by 50 bits)
+    /// let input = vec![3_u64 << 50; polynomial_size.0];
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let key: GlweSecretKey64 =
+    ///     engine.generate_new_glwe_secret_key(glwe_dimension, polynomial_size)?;
+    /// let plaintext_vector = engine.create_plaintext_vector_from(&input)?;
+    ///
+    /// let ciphertext = engine.encrypt_glwe_ciphertext(&key, &plaintext_vector, noise)?;
+    /// let compressed_ciphertext =
+    ///     engine.compress_glwe_ciphertext(&ciphertext, CiphertextModulusLog(16))?;
+    ///
+    /// let mut serialization_engine = DefaultSerializationEngine::new(())?;
+    /// let serialized = serialization_engine.serialize_container(&compressed_ciphertext)?;
+    /// let recovered = serialization_engine.deserialize_container(serialized.as_slice())?;
+    /// assert_eq!(compressed_ciphertext, recovered);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn deserialize_container(
+        &mut self,
+        serialized: &[u8],
+    ) -> Result<GlweCompressedCiphertext64, EntityContainerDeserializationError<Self::EngineError>>
+    {
+        EntityContainerDeserializationError::perform_generic_checks(
+            serialized,
+            ContainerEntityKind::GlweCompressedCiphertext,
+            64,
+        )?;
+        let (header, payload) = ContainerHeader::split_payload(serialized)?;
+        let entity: GlweCompressedCiphertext64 = self.deserialize(payload)?;
+        if header.parameters
+            != [
+                entity.glwe_dimension().0 as u64,
+                entity.polynomial_size().0 as u64,
+                entity.ciphertext_modulus_log().0 as u64,
+                0,
+                0,
+            ]
+        {
+            return Err(EntityContainerDeserializationError::ParametersMismatch);
+        }
+        Ok(entity)
+    }
+
+    unsafe fn deserialize_container_unchecked(
+        &mut self,
+        serialized: &[u8],
+    ) -> GlweCompressedCiphertext64 {
+        let (_, payload) = ContainerHeader::split_payload(serialized).unwrap();
+        self.deserialize_unchecked(payload)
+    }
+}
+
+/// # Description:
+/// Implementation of [`EntityContainerDeserializationEngine`] for [`DefaultSerializationEngine`]
+/// that operates on 32 bits integers. It deserializes from a container a GLWE seeded ciphertext
+/// vector entity.
+impl EntityContainerDeserializationEngine<&[u8], GlweSeededCiphertextVector32>
+    for DefaultSerializationEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_core::prelude::{GlweCiphertextCount, GlweDimension, PolynomialSize, Variance, *};
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let glwe_dimension = GlweDimension(2);
+    /// let polynomial_size = PolynomialSize(4);
+    /// // Here a hard-set encoding is applied (shift by 20 bits)
+    /// let input = vec![3_u32 << 20; 8];
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let key: GlweSecretKey32 =
+    ///     engine.generate_new_glwe_secret_key(glwe_dimension, polynomial_size)?;
+    /// let plaintext_vector = engine.create_plaintext_vector_from(&input)?;
+    ///
+    /// let seeded_ciphertext_vector =
+    ///     engine.encrypt_glwe_seeded_ciphertext_vector(&key, &plaintext_vector, noise)?;
+    ///
+    /// let mut serialization_engine = DefaultSerializationEngine::new(())?;
+    /// let serialized = serialization_engine.serialize_container(&seeded_ciphertext_vector)?;
+    /// let recovered = serialization_engine.deserialize_container(serialized.as_slice())?;
+    /// assert_eq!(seeded_ciphertext_vector, recovered);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn deserialize_container(
+        &mut self,
+        serialized: &[u8],
+    ) -> Result<GlweSeededCiphertextVector32, EntityContainerDeserializationError<Self::EngineError>>
+    {
+        EntityContainerDeserializationError::perform_generic_checks(
+            serialized,
+            ContainerEntityKind::GlweSeededCiphertextVector,
+            32,
+        )?;
+        let (header, payload) = ContainerHeader::split_payload(serialized)?;
+        let entity: GlweSeededCiphertextVector32 = self.deserialize(payload)?;
+        if header.parameters
+            != [
+                entity.glwe_dimension().0 as u64,
+                entity.polynomial_size().0 as u64,
+                entity.glwe_ciphertext_count().0 as u64,
+                0,
+                0,
+            ]
+        {
+            return Err(EntityContainerDeserializationError::ParametersMismatch);
+        }
+        Ok(entity)
+    }
+
+    unsafe fn deserialize_container_unchecked(
+        &mut self,
+        serialized: &[u8],
+    ) -> GlweSeededCiphertextVector32 {
+        let (_, payload) = ContainerHeader::split_payload(serialized).unwrap();
+        self.deserialize_unchecked(payload)
+    }
+}
+
+/// # Description:
+/// Implementation of [`EntityContainerDeserializationEngine`] for [`DefaultSerializationEngine`]
+/// that operates on 64 bits integers. It deserializes from a container a GLWE seeded ciphertext
+/// vector entity.
+impl EntityContainerDeserializationEngine<&[u8], GlweSeededCiphertextVector64>
+    for DefaultSerializationEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_core::prelude::{GlweCiphertextCount, GlweDimension, PolynomialSize, Variance, *};
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let glwe_dimension = GlweDimension(2);
+    /// let polynomial_size = PolynomialSize(4);
+    /// // Here a hard-set encoding is applied (shift by 50 bits)
+    /// let input = vec![3_u64 << 50; 8];
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let key: GlweSecretKey64 =
+    ///     engine.generate_new_glwe_secret_key(glwe_dimension, polynomial_size)?;
+    /// let plaintext_vector = engine.create_plaintext_vector_from(&input)?;
+    ///
+    /// let seeded_ciphertext_vector =
+    ///     engine.encrypt_glwe_seeded_ciphertext_vector(&key, &plaintext_vector, noise)?;
+    ///
+    /// let mut serialization_engine = DefaultSerializationEngine::new(())?;
+    /// let serialized = serialization_engine.serialize_container(&seeded_ciphertext_vector)?;
+    /// let recovered = serialization_engine.deserialize_container(serialized.as_slice())?;
+    /// assert_eq!(seeded_ciphertext_vector, recovered);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn deserialize_container(
+        &mut self,
+        serialized: &[u8],
+    ) -> Result<GlweSeededCiphertextVector64, EntityContainerDeserializationError<Self::EngineError>>
+    {
+        EntityContainerDeserializationError::perform_generic_checks(
+            serialized,
+            ContainerEntityKind::GlweSeededCiphertextVector,
+            64,
+        )?;
+        let (header, payload) = ContainerHeader::split_payload(serialized)?;
+        let entity: GlweSeededCiphertextVector64 = self.deserialize(payload)?;
+        if header.parameters
+            != [
+                entity.glwe_dimension().0 as u64,
+                entity.polynomial_size().0 as u64,
+                entity.glwe_ciphertext_count().0 as u64,
+                0,
+                0,
+            ]
+        {
+            return Err(EntityContainerDeserializationError::ParametersMismatch);
+        }
+        Ok(entity)
+    }
+
+    unsafe fn deserialize_container_unchecked(
+        &mut self,
+        serialized: &[u8],
+    ) -> GlweSeededCiphertextVector64 {
+        let (_, payload) = ContainerHeader::split_payload(serialized).unwrap();
+        self.deserialize_unchecked(payload)
+    }
+}
+
+/// # Description:
+/// Implementation of [`EntityContainerDeserializationEngine`] for [`DefaultSerializationEngine`]
+/// that operates on 32 bits integers. It deserializes from a container a LWE bootstrap key entity.
+impl EntityContainerDeserializationEngine<&[u8], LweBootstrapKey32> for DefaultSerializationEngine {
+    /// # Example
+    /// ```
+    /// use concrete_core::prelude::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, LweDimension, PolynomialSize,
+    ///     Variance, *,
+    /// };
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let (lwe_dim, glwe_dim, poly_size) = (LweDimension(4), GlweDimension(6), PolynomialSize(256));
+    /// let (dec_lc, dec_bl) = (DecompositionLevelCount(3), DecompositionBaseLog(5));
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let lwe_sk: LweSecretKey32 = engine.generate_new_lwe_secret_key(lwe_dim)?;
+    /// let glwe_sk: GlweSecretKey32 = engine.generate_new_glwe_secret_key(glwe_dim, poly_size)?;
+    ///
+    /// let bsk: LweBootstrapKey32 =
+    ///     engine.generate_new_lwe_bootstrap_key(&lwe_sk, &glwe_sk, dec_bl, dec_lc, noise)?;
+    ///
+    /// let mut serialization_engine = DefaultSerializationEngine::new(())?;
+    /// let serialized = serialization_engine.serialize_container(&bsk)?;
+    /// let recovered = serialization_engine.deserialize_container(serialized.as_slice())?;
+    /// assert_eq!(bsk, recovered);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn deserialize_container(
+        &mut self,
+        serialized: &[u8],
+    ) -> Result<LweBootstrapKey32, EntityContainerDeserializationError<Self::EngineError>> {
+        EntityContainerDeserializationError::perform_generic_checks(
+            serialized,
+            ContainerEntityKind::LweBootstrapKey,
+            32,
+        )?;
+        let (header, payload) = ContainerHeader::split_payload(serialized)?;
+        let entity: LweBootstrapKey32 = self.deserialize(payload)?;
+        if header.parameters
+            != [
+                entity.input_lwe_dimension().0 as u64,
+                entity.glwe_dimension().0 as u64,
+                entity.polynomial_size().0 as u64,
+                entity.decomposition_base_log().0 as u64,
+                entity.decomposition_level_count().0 as u64,
+            ]
+        {
+            return Err(EntityContainerDeserializationError::ParametersMismatch);
+        }
+        Ok(entity)
+    }
+
+    unsafe fn deserialize_container_unchecked(&mut self, serialized: &[u8]) -> LweBootstrapKey32 {
+        let (_, payload) = ContainerHeader::split_payload(serialized).unwrap();
+        self.deserialize_unchecked(payload)
+    }
+}
+
+/// # Description:
+/// Implementation of [`EntityContainerDeserializationEngine`] for [`DefaultSerializationEngine`]
+/// that operates on 64 bits integers. It deserializes from a container a LWE bootstrap key entity.
+impl EntityContainerDeserializationEngine<&[u8], LweBootstrapKey64> for DefaultSerializationEngine {
+    /// # Example
+    /// ```
+    /// use concrete_core::prelude::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, LweDimension, PolynomialSize,
+    ///     Variance, *,
+    /// };
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let (lwe_dim, glwe_dim, poly_size) = (LweDimension(4), GlweDimension(6), PolynomialSize(256));
+    /// let (dec_lc, dec_bl) = (DecompositionLevelCount(3), DecompositionBaseLog(5));
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let lwe_sk: LweSecretKey64 = engine.generate_new_lwe_secret_key(lwe_dim)?;
+    /// let glwe_sk: GlweSecretKey64 = engine.generate_new_glwe_secret_key(glwe_dim, poly_size)?;
+    ///
+    /// let bsk: LweBootstrapKey64 =
+    ///     engine.generate_new_lwe_bootstrap_key(&lwe_sk, &glwe_sk, dec_bl, dec_lc, noise)?;
+    ///
+    /// let mut serialization_engine = DefaultSerializationEngine::new(())?;
+    /// let serialized = serialization_engine.serialize_container(&bsk)?;
+    /// let recovered = serialization_engine.deserialize_container(serialized.as_slice())?;
+    /// assert_eq!(bsk, recovered);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn deserialize_container(
+        &mut self,
+        serialized: &[u8],
+    ) -> Result<LweBootstrapKey64, EntityContainerDeserializationError<Self::EngineError>> {
+        EntityContainerDeserializationError::perform_generic_checks(
+            serialized,
+            ContainerEntityKind::LweBootstrapKey,
+            64,
+        )?;
+        let (header, payload) = ContainerHeader::split_payload(serialized)?;
+        let entity: LweBootstrapKey64 = self.deserialize(payload)?;
+        if header.parameters
+            != [
+                entity.input_lwe_dimension().0 as u64,
+                entity.glwe_dimension().0 as u64,
+                entity.polynomial_size().0 as u64,
+                entity.decomposition_base_log().0 as u64,
+                entity.decomposition_level_count().0 as u64,
+            ]
+        {
+            return Err(EntityContainerDeserializationError::ParametersMismatch);
+        }
+        Ok(entity)
+    }
+
+    unsafe fn deserialize_container_unchecked(&mut self, serialized: &[u8]) -> LweBootstrapKey64 {
+        let (_, payload) = ContainerHeader::split_payload(serialized).unwrap();
+        self.deserialize_unchecked(payload)
+    }
+}
+
+/// # Description:
+/// Implementation of [`EntityContainerDeserializationEngine`] for [`DefaultSerializationEngine`]
+/// that operates on 32 bits integers. It deserializes from a container a LWE ciphertext entity.
+impl EntityContainerDeserializationEngine<&[u8], LweCiphertext32> for DefaultSerializationEngine {
+    /// # Example:
+    /// ```
+    /// use concrete_core::prelude::{LweDimension, Variance, *};
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let lwe_dimension = LweDimension(2);
+    /// // Here a hard-set encoding is applied (shift by 20 bits)
+    /// let input = 3_u32 << 20;
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let key: LweSecretKey32 = engine.generate_new_lwe_secret_key(lwe_dimension)?;
+    /// let plaintext = engine.create_plaintext_from(&input)?;
+    ///
+    /// let ciphertext = engine.encrypt_lwe_ciphertext(&key, &plaintext, noise)?;
+    ///
+    /// let mut serialization_engine = DefaultSerializationEngine::new(())?;
+    /// let serialized = serialization_engine.serialize_container(&ciphertext)?;
+    /// let recovered = serialization_engine.deserialize_container(serialized.as_slice())?;
+    /// assert_eq!(ciphertext, recovered);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn deserialize_container(
+        &mut self,
+        serialized: &[u8],
+    ) -> Result<LweCiphertext32, EntityContainerDeserializationError<Self::EngineError>> {
+        EntityContainerDeserializationError::perform_generic_checks(
+            serialized,
+            ContainerEntityKind::LweCiphertext,
+            32,
+        )?;
+        let (header, payload) = ContainerHeader::split_payload(serialized)?;
+        let entity: LweCiphertext32 = self.deserialize(payload)?;
+        if header.parameters != [entity.lwe_dimension().0 as u64, 0, 0, 0, 0] {
+            return Err(EntityContainerDeserializationError::ParametersMismatch);
+        }
+        Ok(entity)
+    }
+
+    unsafe fn deserialize_container_unchecked(&mut self, serialized: &[u8]) -> LweCiphertext32 {
+        let (_, payload) = ContainerHeader::split_payload(serialized).unwrap();
+        self.deserialize_unchecked(payload)
+    }
+}
+
+/// # Description:
+/// Implementation of [`EntityContainerDeserializationEngine`] for [`DefaultSerializationEngine`]
+/// that operates on 64 bits integers. It deserializes from a container a LWE ciphertext entity.
+impl EntityContainerDeserializationEngine<&[u8], LweCiphertext64> for DefaultSerializationEngine {
+    /// # Example:
+    /// ```
+    /// use concrete_core::prelude::{LweDimension, Variance, *};
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let lwe_dimension = LweDimension(2);
+    /// // Here a hard-set encoding is applied (shift by 50 bits)
+    /// let input = 3_u64 << 50;
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let key: LweSecretKey64 = engine.generate_new_lwe_secret_key(lwe_dimension)?;
+    /// let plaintext = engine.create_plaintext_from(&input)?;
+    ///
+    /// let ciphertext = engine.encrypt_lwe_ciphertext(&key, &plaintext, noise)?;
+    ///
+    /// let mut serialization_engine = DefaultSerializationEngine::new(())?;
+    /// let serialized = serialization_engine.serialize_container(&ciphertext)?;
+    /// let recovered = serialization_engine.deserialize_container(serialized.as_slice())?;
+    /// assert_eq!(ciphertext, recovered);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn deserialize_container(
+        &mut self,
+        serialized: &[u8],
+    ) -> Result<LweCiphertext64, EntityContainerDeserializationError<Self::EngineError>> {
+        EntityContainerDeserializationError::perform_generic_checks(
+            serialized,
+            ContainerEntityKind::LweCiphertext,
+            64,
+        )?;
+        let (header, payload) = ContainerHeader::split_payload(serialized)?;
+        let entity: LweCiphertext64 = self.deserialize(payload)?;
+        if header.parameters != [entity.lwe_dimension().0 as u64, 0, 0, 0, 0] {
+            return Err(EntityContainerDeserializationError::ParametersMismatch);
+        }
+        Ok(entity)
+    }
+
+    unsafe fn deserialize_container_unchecked(&mut self, serialized: &[u8]) -> LweCiphertext64 {
+        let (_, payload) = ContainerHeader::split_payload(serialized).unwrap();
+        self.deserialize_unchecked(payload)
+    }
+}
+
+/// # Description:
+/// Implementation of [`EntityContainerDeserializationEngine`] for [`DefaultSerializationEngine`]
+/// that operates on 32 bits integers. It deserializes from a container a LWE ciphertext vector
+/// entity.
+impl EntityContainerDeserializationEngine<&[u8], LweCiphertextVector32>
+    for DefaultSerializationEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_core::prelude::{LweCiphertextCount, LweDimension, Variance, *};
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let lwe_dimension = LweDimension(6);
+    /// // Here a hard-set encoding is applied (shift by 20 bits)
+    /// let input = vec![3_u32 << 20; 3];
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let key: LweSecretKey32 = engine.generate_new_lwe_secret_key(lwe_dimension)?;
+    /// let plaintext_vector: PlaintextVector32 = engine.create_plaintext_vector_from(&input)?;
+    ///
+    /// let mut ciphertext_vector: LweCiphertextVector32 =
+    ///     engine.encrypt_lwe_ciphertext_vector(&key, &plaintext_vector, noise)?;
+    ///
+    /// let mut serialization_engine = DefaultSerializationEngine::new(())?;
+    /// let serialized = serialization_engine.serialize_container(&ciphertext_vector)?;
+    /// let recovered = serialization_engine.deserialize_container(serialized.as_slice())?;
+    /// assert_eq!(ciphertext_vector, recovered);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn deserialize_container(
+        &mut self,
+        serialized: &[u8],
+    ) -> Result<LweCiphertextVector32, EntityContainerDeserializationError<Self::EngineError>> {
+        EntityContainerDeserializationError::perform_generic_checks(
+            serialized,
+            ContainerEntityKind::LweCiphertextVector,
+            32,
+        )?;
+        let (header, payload) = ContainerHeader::split_payload(serialized)?;
+        let entity: LweCiphertextVector32 = self.deserialize(payload)?;
+        if header.parameters
+            != [
+                entity.lwe_dimension().0 as u64,
+                entity.lwe_ciphertext_count().0 as u64,
+                0,
+                0,
+                0,
+            ]
+        {
+            return Err(EntityContainerDeserializationError::ParametersMismatch);
+        }
+        Ok(entity)
+    }
+
+    unsafe fn deserialize_container_unchecked(
+        &mut self,
+        serialized: &[u8],
+    ) -> LweCiphertextVector32 {
+        let (_, payload) = ContainerHeader::split_payload(serialized).unwrap();
+        self.deserialize_unchecked(payload)
+    }
+}
+
+/// # Description:
+/// Implementation of [`EntityContainerDeserializationEngine`] for [`DefaultSerializationEngine`]
+/// that operates on 64 bits integers. It deserializes from a container a LWE ciphertext vector
+/// entity.
+impl EntityContainerDeserializationEngine<&[u8], LweCiphertextVector64>
+    for DefaultSerializationEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_core::prelude::{LweCiphertextCount, LweDimension, Variance, *};
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let lwe_dimension = LweDimension(6);
+    /// // Here a hard-set encoding is applied (shift by 50 bits)
+    /// let input = vec![3_u64 << 50; 3];
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let key: LweSecretKey64 = engine.generate_new_lwe_secret_key(lwe_dimension)?;
+    /// let plaintext_vector: PlaintextVector64 = engine.create_plaintext_vector_from(&input)?;
+    ///
+    /// let mut ciphertext_vector: LweCiphertextVector64 =
+    ///     engine.encrypt_lwe_ciphertext_vector(&key, &plaintext_vector, noise)?;
+    ///
+    /// let mut serialization_engine = DefaultSerializationEngine::new(())?;
+    /// let serialized = serialization_engine.serialize_container(&ciphertext_vector)?;
+    /// let recovered = serialization_engine.deserialize_container(serialized.as_slice())?;
+    /// assert_eq!(ciphertext_vector, recovered);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn deserialize_container(
+        &mut self,
+        serialized: &[u8],
+    ) -> Result<LweCiphertextVector64, EntityContainerDeserializationError<Self::EngineError>> {
+        EntityContainerDeserializationError::perform_generic_checks(
+            serialized,
+            ContainerEntityKind::LweCiphertextVector,
+            64,
+        )?;
+        let (header, payload) = ContainerHeader::split_payload(serialized)?;
+        let entity: LweCiphertextVector64 = self.deserialize(payload)?;
+        if header.parameters
+            != [
+                entity.lwe_dimension().0 as u64,
+                entity.lwe_ciphertext_count().0 as u64,
+                0,
+                0,
+                0,
+            ]
+        {
+            return Err(EntityContainerDeserializationError::ParametersMismatch);
+        }
+        Ok(entity)
+    }
+
+    unsafe fn deserialize_container_unchecked(
+        &mut self,
+        serialized: &[u8],
+    ) -> LweCiphertextVector64 {
+        let (_, payload) = ContainerHeader::split_payload(serialized).unwrap();
+        self.deserialize_unchecked(payload)
+    }
+}
+
+/// # Description:
+/// Implementation of [`EntityContainerDeserializationEngine`] for [`DefaultSerializationEngine`]
+/// that operates on 32 bits integers. It deserializes from a container an LWE circuit bootstrap
+/// private functional packing keyswitch vector.
+impl
+    EntityContainerDeserializationEngine<
+        &[u8],
+        LweCircuitBootstrapPrivateFunctionalPackingKeyswitchKeys32,
+    > for DefaultSerializationEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_core::prelude::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, FunctionalPackingKeyswitchKeyCount,
+    ///     GlweDimension, LweDimension, Variance, *,
+    /// };
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let input_lwe_dimension = LweDimension(10);
+    /// let output_glwe_dimension = GlweDimension(3);
+    /// let polynomial_size = PolynomialSize(256);
+    /// let decomposition_base_log = DecompositionBaseLog(3);
+    /// let decomposition_level_count = DecompositionLevelCount(5);
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let input_key: LweSecretKey32 = engine.generate_new_lwe_secret_key(input_lwe_dimension)?;
+    /// let output_key: GlweSecretKey32 =
+    ///     engine.generate_new_glwe_secret_key(output_glwe_dimension, polynomial_size)?;
+    ///
+    /// let cbs_private_functional_packing_keyswitch_key:
+    ///     LweCircuitBootstrapPrivateFunctionalPackingKeyswitchKeys32 =
+    ///     engine
+    ///     .generate_new_lwe_circuit_bootstrap_private_functional_packing_keyswitch_keys(
+    ///         &input_key,
+    ///         &output_key,
+    ///         decomposition_base_log,
+    ///         decomposition_level_count,
+    ///         noise,
+    /// )?;
+    ///
+    /// let mut serialization_engine = DefaultSerializationEngine::new(())?;
+    /// let serialized =
+    ///     serialization_engine.serialize_container(&cbs_private_functional_packing_keyswitch_key)?;
+    /// let recovered = serialization_engine.deserialize_container(serialized.as_slice())?;
+    /// assert_eq!(cbs_private_functional_packing_keyswitch_key, recovered);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn deserialize_container(
+        &mut self,
+        serialized: &[u8],
+    ) -> Result<
+        LweCircuitBootstrapPrivateFunctionalPackingKeyswitchKeys32,
+        EntityContainerDeserializationError<Self::EngineError>,
+    > {
+        EntityContainerDeserializationError::perform_generic_checks(
+            serialized,
+            ContainerEntityKind::LweCircuitBootstrapPrivateFunctionalPackingKeyswitchKeys,
+            32,
+        )?;
+        let (header, payload) = ContainerHeader::split_payload(serialized)?;
+        let entity: LweCircuitBootstrapPrivateFunctionalPackingKeyswitchKeys32 =
+            self.deserialize(payload)?;
+        if header.parameters
+            != [
+                entity.input_lwe_dimension().0 as u64,
+                entity.output_glwe_dimension().0 as u64,
+                entity.output_polynomial_size().0 as u64,
+                entity.decomposition_base_log().0 as u64,
+                entity.decomposition_level_count().0 as u64,
+            ]
+        {
+            return Err(EntityContainerDeserializationError::ParametersMismatch);
+        }
+        Ok(entity)
+    }
+
+    unsafe fn deserialize_container_unchecked(
+        &mut self,
+        serialized: &[u8],
+    ) -> LweCircuitBootstrapPrivateFunctionalPackingKeyswitchKeys32 {
+        let (_, payload) = ContainerHeader::split_payload(serialized).unwrap();
+        self.deserialize_unchecked(payload)
+    }
+}
+
+/// # Description:
+/// Implementation of [`EntityContainerDeserializationEngine`] for [`DefaultSerializationEngine`]
+/// that operates on 64 bits integers. It deserializes from a container an LWE circuit bootstrap
+/// private functional packing keyswitch vector.
+impl
+    EntityContainerDeserializationEngine<
+        &[u8],
+        LweCircuitBootstrapPrivateFunctionalPackingKeyswitchKeys64,
+    > for DefaultSerializationEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_core::prelude::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, FunctionalPackingKeyswitchKeyCount,
+    ///     GlweDimension, LweDimension, Variance, *,
+    /// };
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let input_lwe_dimension = LweDimension(10);
+    /// let output_glwe_dimension = GlweDimension(3);
+    /// let polynomial_size = PolynomialSize(256);
+    /// let decomposition_base_log = DecompositionBaseLog(3);
+    /// let decomposition_level_count = DecompositionLevelCount(5);
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let input_key: LweSecretKey64 = engine.generate_new_lwe_secret_key(input_lwe_dimension)?;
+    /// let output_key: GlweSecretKey64 =
+    ///     engine.generate_new_glwe_secret_key(output_glwe_dimension, polynomial_size)?;
+    ///
+    /// let cbs_private_functional_packing_keyswitch_key:
+    ///     LweCircuitBootstrapPrivateFunctionalPackingKeyswitchKeys64 =
+    ///     engine
+    ///     .generate_new_lwe_circuit_bootstrap_private_functional_packing_keyswitch_keys(
+    ///         &input_key,
+    ///         &output_key,
+    ///         decomposition_base_log,
+    ///         decomposition_level_count,
+    ///         noise,
+    /// )?;
+    ///
+    /// let mut serialization_engine = DefaultSerializationEngine::new(())?;
+    /// let serialized =
+    ///     serialization_engine.serialize_container(&cbs_private_functional_packing_keyswitch_key)?;
+    /// let recovered = serialization_engine.deserialize_container(serialized.as_slice())?;
+    /// assert_eq!(cbs_private_functional_packing_keyswitch_key, recovered);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn deserialize_container(
+        &mut self,
+        serialized: &[u8],
+    ) -> Result<
+        LweCircuitBootstrapPrivateFunctionalPackingKeyswitchKeys64,
+        EntityContainerDeserializationError<Self::EngineError>,
+    > {
+        EntityContainerDeserializationError::perform_generic_checks(
+            serialized,
+            ContainerEntityKind::LweCircuitBootstrapPrivateFunctionalPackingKeyswitchKeys,
+            64,
+        )?;
+        let (header, payload) = ContainerHeader::split_payload(serialized)?;
+        let entity: LweCircuitBootstrapPrivateFunctionalPackingKeyswitchKeys64 =
+            self.deserialize(payload)?;
+        if header.parameters
+            != [
+                entity.input_lwe_dimension().0 as u64,
+                entity.output_glwe_dimension().0 as u64,
+                entity.output_polynomial_size().0 as u64,
+                entity.decomposition_base_log().0 as u64,
+                entity.decomposition_level_count().0 as u64,
+            ]
+        {
+            return Err(EntityContainerDeserializationError::ParametersMismatch);
+        }
+        Ok(entity)
+    }
+
+    unsafe fn deserialize_container_unchecked(
+        &mut self,
+        serialized: &[u8],
+    ) -> LweCircuitBootstrapPrivateFunctionalPackingKeyswitchKeys64 {
+        let (_, payload) = ContainerHeader::split_payload(serialized).unwrap();
+        self.deserialize_unchecked(payload)
+    }
+}
+
+/// # Description:
+/// Implementation of [`EntityContainerDeserializationEngine`] for [`DefaultSerializationEngine`]
+/// that operates on 32 bits integers. It deserializes from a container a LWE keyswitch key entity.
+impl EntityContainerDeserializationEngine<&[u8], LweKeyswitchKey32> for DefaultSerializationEngine {
+    /// # Example:
+    /// ```
+    /// use concrete_core::prelude::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, LweDimension, Variance, *,
+    /// };
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let input_lwe_dimension = LweDimension(6);
+    /// let output_lwe_dimension = LweDimension(3);
+    /// let decomposition_level_count = DecompositionLevelCount(2);
+    /// let decomposition_base_log = DecompositionBaseLog(8);
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let input_key: LweSecretKey32 = engine.generate_new_lwe_secret_key(input_lwe_dimension)?;
+    /// let output_key: LweSecretKey32 = engine.generate_new_lwe_secret_key(output_lwe_dimension)?;
+    ///
+    /// let keyswitch_key = engine.generate_new_lwe_keyswitch_key(
+    ///     &input_key,
+    ///     &output_key,
+    ///     decomposition_level_count,
+    ///     decomposition_base_log,
+    ///     noise,
+    /// )?;
+    ///
+    /// let mut serialization_engine = DefaultSerializationEngine::new(())?;
+    /// let serialized = serialization_engine.serialize_container(&keyswitch_key)?;
+    /// let recovered = serialization_engine.deserialize_container(serialized.as_slice())?;
+    /// assert_eq!(keyswitch_key, recovered);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn deserialize_container(
+        &mut self,
+        serialized: &[u8],
+    ) -> Result<LweKeyswitchKey32, EntityContainerDeserializationError<Self::EngineError>> {
+        EntityContainerDeserializationError::perform_generic_checks(
+            serialized,
+            ContainerEntityKind::LweKeyswitchKey,
+            32,
+        )?;
+        let (header, payload) = ContainerHeader::split_payload(serialized)?;
+        let entity: LweKeyswitchKey32 = self.deserialize(payload)?;
+        if header.parameters
+            != [
+                entity.input_lwe_dimension().0 as u64,
+                entity.output_lwe_dimension().0 as u64,
+                entity.decomposition_base_log().0 as u64,
+                entity.decomposition_level_count().0 as u64,
+                0,
+            ]
+        {
+            return Err(EntityContainerDeserializationError::ParametersMismatch);
+        }
+        Ok(entity)
+    }
+
+    unsafe fn deserialize_container_unchecked(&mut self, serialized: &[u8]) -> LweKeyswitchKey32 {
+        let (_, payload) = ContainerHeader::split_payload(serialized).unwrap();
+        self.deserialize_unchecked(payload)
+    }
+}
+
+/// # Description:
+/// Implementation of [`EntityContainerDeserializationEngine`] for [`DefaultSerializationEngine`]
+/// that operates on 64 bits integers. It deserializes from a container a LWE keyswitch key entity.
+impl EntityContainerDeserializationEngine<&[u8], LweKeyswitchKey64> for DefaultSerializationEngine {
+    /// # Example:
+    /// ```
+    /// use concrete_core::prelude::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, LweDimension, Variance, *,
+    /// };
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let input_lwe_dimension = LweDimension(6);
+    /// let output_lwe_dimension = LweDimension(3);
+    /// let decomposition_level_count = DecompositionLevelCount(2);
+    /// let decomposition_base_log = DecompositionBaseLog(8);
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let input_key: LweSecretKey64 = engine.generate_new_lwe_secret_key(input_lwe_dimension)?;
+    /// let output_key: LweSecretKey64 = engine.generate_new_lwe_secret_key(output_lwe_dimension)?;
+    ///
+    /// let keyswitch_key = engine.generate_new_lwe_keyswitch_key(
+    ///     &input_key,
+    ///     &output_key,
+    ///     decomposition_level_count,
+    ///     decomposition_base_log,
+    ///     noise,
+    /// )?;
+    ///
+    /// let mut serialization_engine = DefaultSerializationEngine::new(())?;
+    /// let serialized = serialization_engine.serialize_container(&keyswitch_key)?;
+    /// let recovered = serialization_engine.deserialize_container(serialized.as_slice())?;
+    /// assert_eq!(keyswitch_key, recovered);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn deserialize_container(
+        &mut self,
+        serialized: &[u8],
+    ) -> Result<LweKeyswitchKey64, EntityContainerDeserializationError<Self::EngineError>> {
+        EntityContainerDeserializationError::perform_generic_checks(
+            serialized,
+            ContainerEntityKind::LweKeyswitchKey,
+            64,
+        )?;
+        let (header, payload) = ContainerHeader::split_payload(serialized)?;
+        let entity: LweKeyswitchKey64 = self.deserialize(payload)?;
+        if header.parameters
+            != [
+                entity.input_lwe_dimension().0 as u64,
+                entity.output_lwe_dimension().0 as u64,
+                entity.decomposition_base_log().0 as u64,
+                entity.decomposition_level_count().0 as u64,
+                0,
+            ]
+        {
+            return Err(EntityContainerDeserializationError::ParametersMismatch);
+        }
+        Ok(entity)
+    }
+
+    unsafe fn deserialize_container_unchecked(&mut self, serialized: &[u8]) -> LweKeyswitchKey64 {
+        let (_, payload) = ContainerHeader::split_payload(serialized).unwrap();
+        self.deserialize_unchecked(payload)
+    }
+}
+
+/// # Description:
+/// Implementation of [`EntityContainerDeserializationEngine`] for [`DefaultSerializationEngine`]
+/// that operates on 32 bits integers. It deserializes from a container a LWE secret key entity.
+impl EntityContainerDeserializationEngine<&[u8], LweSecretKey32> for DefaultSerializationEngine {
+    /// # Example:
+    /// ```
+    /// use concrete_core::prelude::{LweDimension, *};
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let lwe_dimension = LweDimension(6);
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let lwe_secret_key: LweSecretKey32 = engine.generate_new_lwe_secret_key(lwe_dimension)?;
+    ///
+    /// let mut serialization_engine = DefaultSerializationEngine::new(())?;
+    /// let serialized = serialization_engine.serialize_container(&lwe_secret_key)?;
+    /// let recovered = serialization_engine.deserialize_container(serialized.as_slice())?;
+    /// assert_eq!(lwe_secret_key, recovered);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn deserialize_container(
+        &mut self,
+        serialized: &[u8],
+    ) -> Result<LweSecretKey32, EntityContainerDeserializationError<Self::EngineError>> {
+        EntityContainerDeserializationError::perform_generic_checks(
+            serialized,
+            ContainerEntityKind::LweSecretKey,
+            32,
+        )?;
+        let (header, payload) = ContainerHeader::split_payload(serialized)?;
+        let entity: LweSecretKey32 = self.deserialize(payload)?;
+        if header.parameters != [entity.lwe_dimension().0 as u64, 0, 0, 0, 0] {
+            return Err(EntityContainerDeserializationError::ParametersMismatch);
+        }
+        Ok(entity)
+    }
+
+    unsafe fn deserialize_container_unchecked(&mut self, serialized: &[u8]) -> LweSecretKey32 {
+        let (_, payload) = ContainerHeader::split_payload(serialized).unwrap();
+        self.deserialize_unchecked(payload)
+    }
+}
+
+/// # Description:
+/// Implementation of [`EntityContainerDeserializationEngine`] for [`DefaultSerializationEngine`]
+/// that operates on 64 bits integers. It deserializes from a container a LWE secret key entity.
+impl EntityContainerDeserializationEngine<&[u8], LweSecretKey64> for DefaultSerializationEngine {
+    /// # Example:
+    /// ```
+    /// use concrete_core::prelude::{LweDimension, *};
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let lwe_dimension = LweDimension(6);
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let lwe_secret_key: LweSecretKey64 = engine.generate_new_lwe_secret_key(lwe_dimension)?;
+    ///
+    /// let mut serialization_engine = DefaultSerializationEngine::new(())?;
+    /// let serialized = serialization_engine.serialize_container(&lwe_secret_key)?;
+    /// let recovered = serialization_engine.deserialize_container(serialized.as_slice())?;
+    /// assert_eq!(lwe_secret_key, recovered);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn deserialize_container(
+        &mut self,
+        serialized: &[u8],
+    ) -> Result<LweSecretKey64, EntityContainerDeserializationError<Self::EngineError>> {
+        EntityContainerDeserializationError::perform_generic_checks(
+            serialized,
+            ContainerEntityKind::LweSecretKey,
+            64,
+        )?;
+        let (header, payload) = ContainerHeader::split_payload(serialized)?;
+        let entity: LweSecretKey64 = self.deserialize(payload)?;
+        if header.parameters != [entity.lwe_dimension().0 as u64, 0, 0, 0, 0] {
+            return Err(EntityContainerDeserializationError::ParametersMismatch);
+        }
+        Ok(entity)
+    }
+
+    unsafe fn deserialize_container_unchecked(&mut self, serialized: &[u8]) -> LweSecretKey64 {
+        let (_, payload) = ContainerHeader::split_payload(serialized).unwrap();
+        self.deserialize_unchecked(payload)
+    }
+}
+
+/// # Description:
+/// Implementation of [`EntityContainerDeserializationEngine`] for [`DefaultSerializationEngine`]
+/// that operates on 32 bits integers. It deserializes from a container a seeded LWE bootstrap key
+/// entity.
+impl EntityContainerDeserializationEngine<&[u8], LweSeededBootstrapKey32>
+    for DefaultSerializationEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_core::prelude::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, LweDimension, PolynomialSize,
+    ///     Variance, *,
+    /// };
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let (lwe_dim, glwe_dim, poly_size) = (LweDimension(4), GlweDimension(6), PolynomialSize(256));
+    /// let (dec_lc, dec_bl) = (DecompositionLevelCount(3), DecompositionBaseLog(5));
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let lwe_sk: LweSecretKey32 = engine.generate_new_lwe_secret_key(lwe_dim)?;
+    /// let glwe_sk: GlweSecretKey32 = engine.generate_new_glwe_secret_key(glwe_dim, poly_size)?;
+    ///
+    /// let bsk: LweSeededBootstrapKey32 =
+    ///     engine.generate_new_lwe_seeded_bootstrap_key(&lwe_sk, &glwe_sk, dec_bl, dec_lc, noise)?;
+    ///
+    /// let mut serialization_engine = DefaultSerializationEngine::new(())?;
+    /// let serialized = serialization_engine.serialize_container(&bsk)?;
+    /// let recovered = serialization_engine.deserialize_container(serialized.as_slice())?;
+    ///
+    /// assert_eq!(bsk, recovered);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn deserialize_container(
+        &mut self,
+        serialized: &[u8],
+    ) -> Result<LweSeededBootstrapKey32, EntityContainerDeserializationError<Self::EngineError>>
+    {
+        EntityContainerDeserializationError::perform_generic_checks(
+            serialized,
+            ContainerEntityKind::LweSeededBootstrapKey,
+            32,
+        )?;
+        let (header, payload) = ContainerHeader::split_payload(serialized)?;
+        let entity: LweSeededBootstrapKey32 = self.deserialize(payload)?;
+        if header.parameters
+            != [
+                entity.input_lwe_dimension().0 as u64,
+                entity.glwe_dimension().0 as u64,
+                entity.polynomial_size().0 as u64,
+                entity.decomposition_base_log().0 as u64,
+                entity.decomposition_level_count().0 as u64,
+            ]
+        {
+            return Err(EntityContainerDeserializationError::ParametersMismatch);
+        }
+        Ok(entity)
+    }
+
+    unsafe fn deserialize_container_unchecked(
+        &mut self,
+        serialized: &[u8],
+    ) -> LweSeededBootstrapKey32 {
+        let (_, payload) = ContainerHeader::split_payload(serialized).unwrap();
+        self.deserialize_unchecked(payload)
+    }
+}
+
+/// # Description:
+/// Implementation of [`EntityContainerDeserializationEngine`] for [`DefaultSerializationEngine`]
+/// that operates on 64 bits integers. It deserializes from a container a seeded LWE bootstrap key
+/// entity.
+impl EntityContainerDeserializationEngine<&[u8], LweSeededBootstrapKey64>
+    for DefaultSerializationEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_core::prelude::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, LweDimension, PolynomialSize,
+    ///     Variance, *,
+    /// };
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let (lwe_dim, glwe_dim, poly_size) = (LweDimension(4), GlweDimension(6), PolynomialSize(256));
+    /// let (dec_lc, dec_bl) = (DecompositionLevelCount(3), DecompositionBaseLog(5));
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let lwe_sk: LweSecretKey64 = engine.generate_new_lwe_secret_key(lwe_dim)?;
+    /// let glwe_sk: GlweSecretKey64 = engine.generate_new_glwe_secret_key(glwe_dim, poly_size)?;
+    ///
+    /// let bsk: LweSeededBootstrapKey64 =
+    ///     engine.generate_new_lwe_seeded_bootstrap_key(&lwe_sk, &glwe_sk, dec_bl, dec_lc, noise)?;
+    ///
+    /// let mut serialization_engine = DefaultSerializationEngine::new(())?;
+    /// let serialized = serialization_engine.serialize_container(&bsk)?;
+    /// let recovered = serialization_engine.deserialize_container(serialized.as_slice())?;
+    ///
+    /// assert_eq!(bsk, recovered);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn deserialize_container(
+        &mut self,
+        serialized: &[u8],
+    ) -> Result<LweSeededBootstrapKey64, EntityContainerDeserializationError<Self::EngineError>>
+    {
+        EntityContainerDeserializationError::perform_generic_checks(
+            serialized,
+            ContainerEntityKind::LweSeededBootstrapKey,
+            64,
+        )?;
+        let (header, payload) = ContainerHeader::split_payload(serialized)?;
+        let entity: LweSeededBootstrapKey64 = self.deserialize(payload)?;
+        if header.parameters
+            != [
+                entity.input_lwe_dimension().0 as u64,
+                entity.glwe_dimension().0 as u64,
+                entity.polynomial_size().0 as u64,
+                entity.decomposition_base_log().0 as u64,
+                entity.decomposition_level_count().0 as u64,
+            ]
+        {
+            return Err(EntityContainerDeserializationError::ParametersMismatch);
+        }
+        Ok(entity)
+    }
+
+    unsafe fn deserialize_container_unchecked(
+        &mut self,
+        serialized: &[u8],
+    ) -> LweSeededBootstrapKey64 {
+        let (_, payload) = ContainerHeader::split_payload(serialized).unwrap();
+        self.deserialize_unchecked(payload)
+    }
+}
+
+/// # Description:
+/// Implementation of [`EntityContainerDeserializationEngine`] for [`DefaultSerializationEngine`]
+/// that operates on 32 bits integers. It deserializes from a container a seeded LWE ciphertext
+/// entity.
+impl EntityContainerDeserializationEngine<&[u8], LweSeededCiphertext32>
+    for DefaultSerializationEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_core::prelude::{LweDimension, Variance, *};
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let lwe_dimension = LweDimension(2);
+    /// // Here a hard-set encoding is applied (shift by 20 bits)
+    /// let input = 3_u32 << 20;
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let key: LweSecretKey32 = engine.generate_new_lwe_secret_key(lwe_dimension)?;
+    /// let plaintext = engine.create_plaintext_from(&input)?;
+    ///
+    /// let ciphertext: LweSeededCiphertext32 =
+    ///     engine.encrypt_lwe_seeded_ciphertext(&key, &plaintext, noise)?;
+    ///
+    /// let mut serialization_engine = DefaultSerializationEngine::new(())?;
+    /// let serialized = serialization_engine.serialize_container(&ciphertext)?;
+    /// let recovered = serialization_engine.deserialize_container(serialized.as_slice())?;
+    /// assert_eq!(ciphertext, recovered);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn deserialize_container(
+        &mut self,
+        serialized: &[u8],
+    ) -> Result<LweSeededCiphertext32, EntityContainerDeserializationError<Self::EngineError>> {
+        EntityContainerDeserializationError::perform_generic_checks(
+            serialized,
+            ContainerEntityKind::LweSeededCiphertext,
+            32,
+        )?;
+        let (header, payload) = ContainerHeader::split_payload(serialized)?;
+        let entity: LweSeededCiphertext32 = self.deserialize(payload)?;
+        if header.parameters != [entity.lwe_dimension().0 as u64, 0, 0, 0, 0] {
+            return Err(EntityContainerDeserializationError::ParametersMismatch);
+        }
+        Ok(entity)
+    }
+
+    unsafe fn deserialize_container_unchecked(
+        &mut self,
+        serialized: &[u8],
+    ) -> LweSeededCiphertext32 {
+        let (_, payload) = ContainerHeader::split_payload(serialized).unwrap();
+        self.deserialize_unchecked(payload)
+    }
+}
+
+/// # Description:
+/// Implementation of [`EntityContainerDeserializationEngine`] for [`DefaultSerializationEngine`]
+/// that operates on 64 bits integers. It deserializes from a container a seeded LWE ciphertext
+/// entity.
+impl EntityContainerDeserializationEngine<&[u8], LweSeededCiphertext64>
+    for DefaultSerializationEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_core::prelude::{LweDimension, Variance, *};
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let lwe_dimension = LweDimension(2);
+    /// // Here a hard-set encoding is applied (shift by 50 bits)
+    /// let input = 3_u64 << 50;
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let key: LweSecretKey64 = engine.generate_new_lwe_secret_key(lwe_dimension)?;
+    /// let plaintext = engine.create_plaintext_from(&input)?;
+    ///
+    /// let ciphertext: LweSeededCiphertext64 =
+    ///     engine.encrypt_lwe_seeded_ciphertext(&key, &plaintext, noise)?;
+    ///
+    /// let mut serialization_engine = DefaultSerializationEngine::new(())?;
+    /// let serialized = serialization_engine.serialize_container(&ciphertext)?;
+    /// let recovered = serialization_engine.deserialize_container(serialized.as_slice())?;
+    /// assert_eq!(ciphertext, recovered);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn deserialize_container(
+        &mut self,
+        serialized: &[u8],
+    ) -> Result<LweSeededCiphertext64, EntityContainerDeserializationError<Self::EngineError>> {
+        EntityContainerDeserializationError::perform_generic_checks(
+            serialized,
+            ContainerEntityKind::LweSeededCiphertext,
+            64,
+        )?;
+        let (header, payload) = ContainerHeader::split_payload(serialized)?;
+        let entity: LweSeededCiphertext64 = self.deserialize(payload)?;
+        if header.parameters != [entity.lwe_dimension().0 as u64, 0, 0, 0, 0] {
+            return Err(EntityContainerDeserializationError::ParametersMismatch);
+        }
+        Ok(entity)
+    }
+
+    unsafe fn deserialize_container_unchecked(
+        &mut self,
+        serialized: &[u8],
+    ) -> LweSeededCiphertext64 {
+        let (_, payload) = ContainerHeader::split_payload(serialized).unwrap();
+        self.deserialize_unchecked(payload)
+    }
+}
+
+/// # Description:
+/// Implementation of [`EntityContainerDeserializationEngine`] for [`DefaultSerializationEngine`]
+/// that operates on 32 bits integers. It deserializes from a container a compressed LWE ciphertext
+/// entity.
+impl EntityContainerDeserializationEngine<&[u8], LweCompressedCiphertext32>
+    for DefaultSerializationEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_core::prelude::{LweDimension, Variance, *};
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let lwe_dimension = LweDimension(2);
+    /// // Here a hard-set encoding is applied (shift by 20 bits)
+    /// let input = 3_u32 << 20;
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let key: LweSecretKey32 = engine.generate_new_lwe_secret_key(lwe_dimension)?;
+    /// let plaintext = engine.create_plaintext_from(&input)?;
+    ///
+    /// let ciphertext = engine.encrypt_lwe_ciphertext(&key, &plaintext, noise)?;
+    /// let compressed_ciphertext: LweCompressedCiphertext32 =
+    ///     engine.compress_lwe_ciphertext(&ciphertext, CiphertextModulusLog(16))?;
+    ///
+    /// let mut serialization_engine = DefaultSerializationEngine::new(())?;
+    /// let serialized = serialization_engine.serialize_container(&compressed_ciphertext)?;
+    /// let recovered = serialization_engine.deserialize_container(serialized.as_slice())?;
+    /// assert_eq!(compressed_ciphertext, recovered);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn deserialize_container(
+        &mut self,
+        serialized: &[u8],
+    ) -> Result<LweCompressedCiphertext32, EntityContainerDeserializationError<Self::EngineError>>
+    {
+        EntityContainerDeserializationError::perform_generic_checks(
+            serialized,
+            ContainerEntityKind::LweCompressedCiphertext,
+            32,
+        )?;
+        let (header, payload) = ContainerHeader::split_payload(serialized)?;
+        let entity: LweCompressedCiphertext32 = self.deserialize(payload)?;
+        if header.parameters
+            != [
+                entity.lwe_dimension().0 as u64,
+                entity.ciphertext_modulus_log().0 as u64,
+                0,
+                0,
+                0,
+            ]
+        {
+            return Err(EntityContainerDeserializationError::ParametersMismatch);
+        }
+        Ok(entity)
+    }
+
+    unsafe fn deserialize_container_unchecked(
+        &mut self,
+        serialized: &[u8],
+    ) -> LweCompressedCiphertext32 {
+        let (_, payload) = ContainerHeader::split_payload(serialized).unwrap();
+        self.deserialize_unchecked(payload)
+    }
+}
+
+/// # Description:
+/// Implementation of [`EntityContainerDeserializationEngine`] for [`DefaultSerializationEngine`]
+/// that operates on 64 bits integers. It deserializes from a container a compressed LWE ciphertext
+/// entity.
+impl EntityContainerDeserializationEngine<&[u8], LweCompressedCiphertext64>
+    for DefaultSerializationEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_core::prelude::{LweDimension, Variance, *};
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let lwe_dimension = LweDimension(2);
+    /// // Here a hard-set encoding is applied (shift by 50 bits)
+    /// let input = 3_u64 << 50;
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let key: LweSecretKey64 = engine.generate_new_lwe_secret_key(lwe_dimension)?;
+    /// let plaintext = engine.create_plaintext_from(&input)?;
+    ///
+    /// let ciphertext = engine.encrypt_lwe_ciphertext(&key, &plaintext, noise)?;
+    /// let compressed_ciphertext: LweCompressedCiphertext64 =
+    ///     engine.compress_lwe_ciphertext(&ciphertext, CiphertextModulusLog(16))?;
+    ///
+    /// let mut serialization_engine = DefaultSerializationEngine::new(())?;
+    /// let serialized = serialization_engine.serialize_container(&compressed_ciphertext)?;
+    /// let recovered = serialization_engine.deserialize_container(serialized.as_slice())?;
+    /// assert_eq!(compressed_ciphertext, recovered);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn deserialize_container(
+        &mut self,
+        serialized: &[u8],
+    ) -> Result<LweCompressedCiphertext64, EntityContainerDeserializationError<Self::EngineError>>
+    {
+        EntityContainerDeserializationError::perform_generic_checks(
+            serialized,
+            ContainerEntityKind::LweCompressedCiphertext,
+            64,
+        )?;
+        let (header, payload) = ContainerHeader::split_payload(serialized)?;
+        let entity: LweCompressedCiphertext64 = self.deserialize(payload)?;
+        if header.parameters
+            != [
+                entity.lwe_dimension().0 as u64,
+                entity.ciphertext_modulus_log().0 as u64,
+                0,
+                0,
+                0,
+            ]
+        {
+            return Err(EntityContainerDeserializationError::ParametersMismatch);
+        }
+        Ok(entity)
+    }
+
+    unsafe fn deserialize_container_unchecked(
+        &mut self,
+        serialized: &[u8],
+    ) -> LweCompressedCiphertext64 {
+        let (_, payload) = ContainerHeader::split_payload(serialized).unwrap();
+        self.deserialize_unchecked(payload)
+    }
+}
+
+/// # Description:
+/// Implementation of [`EntityContainerDeserializationEngine`] for [`DefaultSerializationEngine`]
+/// that operates on 32 bits integers. It deserializes from a container a seeded LWE ciphertext
+/// vector entity.
+impl EntityContainerDeserializationEngine<&[u8], LweSeededCiphertextVector32>
+    for DefaultSerializationEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_core::prelude::{LweCiphertextCount, LweDimension, Variance, *};
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let lwe_dimension = LweDimension(6);
+    /// // Here a hard-set encoding is applied (shift by 20 bits)
+    /// let input = vec![3_u32 << 20; 3];
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let key: LweSecretKey32 = engine.generate_new_lwe_secret_key(lwe_dimension)?;
+    /// let plaintext_vector: PlaintextVector32 = engine.create_plaintext_vector_from(&input)?;
+    ///
+    /// let mut ciphertext_vector: LweSeededCiphertextVector32 =
+    ///     engine.encrypt_lwe_seeded_ciphertext_vector(&key, &plaintext_vector, noise)?;
+    ///
+    /// let mut serialization_engine = DefaultSerializationEngine::new(())?;
+    /// let serialized = serialization_engine.serialize_container(&ciphertext_vector)?;
+    /// let recovered = serialization_engine.deserialize_container(serialized.as_slice())?;
+    /// assert_eq!(ciphertext_vector, recovered);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn deserialize_container(
+        &mut self,
+        serialized: &[u8],
+    ) -> Result<LweSeededCiphertextVector32, EntityContainerDeserializationError<Self::EngineError>>
+    {
+        EntityContainerDeserializationError::perform_generic_checks(
+            serialized,
+            ContainerEntityKind::LweSeededCiphertextVector,
+            32,
+        )?;
+        let (header, payload) = ContainerHeader::split_payload(serialized)?;
+        let entity: LweSeededCiphertextVector32 = self.deserialize(payload)?;
+        if header.parameters
+            != [
+                entity.lwe_dimension().0 as u64,
+                entity.lwe_ciphertext_count().0 as u64,
+                0,
+                0,
+                0,
+            ]
+        {
+            return Err(EntityContainerDeserializationError::ParametersMismatch);
+        }
+        Ok(entity)
+    }
+
+    unsafe fn deserialize_container_unchecked(
+        &mut self,
+        serialized: &[u8],
+    ) -> LweSeededCiphertextVector32 {
+        let (_, payload) = ContainerHeader::split_payload(serialized).unwrap();
+        self.deserialize_unchecked(payload)
+    }
+}
+
+/// # Description:
+/// Implementation of [`EntityContainerDeserializationEngine`] for [`DefaultSerializationEngine`]
+/// that operates on 64 bits integers. It deserializes from a container a seeded LWE ciphertext
+/// vector entity.
+impl EntityContainerDeserializationEngine<&[u8], LweSeededCiphertextVector64>
+    for DefaultSerializationEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_core::prelude::{LweCiphertextCount, LweDimension, Variance, *};
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let lwe_dimension = LweDimension(6);
+    /// // Here a hard-set encoding is applied (shift by 50 bits)
+    /// let input = vec![3_u64 << 50; 3];
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let key: LweSecretKey64 = engine.generate_new_lwe_secret_key(lwe_dimension)?;
+    /// let plaintext_vector: PlaintextVector64 = engine.create_plaintext_vector_from(&input)?;
+    ///
+    /// let mut ciphertext_vector: LweSeededCiphertextVector64 =
+    ///     engine.encrypt_lwe_seeded_ciphertext_vector(&key, &plaintext_vector, noise)?;
+    ///
+    /// let mut serialization_engine = DefaultSerializationEngine::new(())?;
+    /// let serialized = serialization_engine.serialize_container(&ciphertext_vector)?;
+    /// let recovered = serialization_engine.deserialize_container(serialized.as_slice())?;
+    /// assert_eq!(ciphertext_vector, recovered);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn deserialize_container(
+        &mut self,
+        serialized: &[u8],
+    ) -> Result<LweSeededCiphertextVector64, EntityContainerDeserializationError<Self::EngineError>>
+    {
+        EntityContainerDeserializationError::perform_generic_checks(
+            serialized,
+            ContainerEntityKind::LweSeededCiphertextVector,
+            64,
+        )?;
+        let (header, payload) = ContainerHeader::split_payload(serialized)?;
+        let entity: LweSeededCiphertextVector64 = self.deserialize(payload)?;
+        if header.parameters
+            != [
+                entity.lwe_dimension().0 as u64,
+                entity.lwe_ciphertext_count().0 as u64,
+                0,
+                0,
+                0,
+            ]
+        {
+            return Err(EntityContainerDeserializationError::ParametersMismatch);
+        }
+        Ok(entity)
+    }
+
+    unsafe fn deserialize_container_unchecked(
+        &mut self,
+        serialized: &[u8],
+    ) -> LweSeededCiphertextVector64 {
+        let (_, payload) = ContainerHeader::split_payload(serialized).unwrap();
+        self.deserialize_unchecked(payload)
+    }
+}
+
+/// # Description:
+/// Implementation of [`EntityContainerDeserializationEngine`] for [`DefaultSerializationEngine`]
+/// that operates on 32 bits integers. It deserializes from a container a seeded LWE ciphertext
+/// keyswitch key entity.
+impl EntityContainerDeserializationEngine<&[u8], LweSeededKeyswitchKey32>
+    for DefaultSerializationEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_core::prelude::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, LweDimension, Variance, *,
+    /// };
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let input_lwe_dimension = LweDimension(6);
+    /// let output_lwe_dimension = LweDimension(3);
+    /// let decomposition_level_count = DecompositionLevelCount(2);
+    /// let decomposition_base_log = DecompositionBaseLog(8);
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let input_key: LweSecretKey32 = engine.generate_new_lwe_secret_key(input_lwe_dimension)?;
+    /// let output_key: LweSecretKey32 = engine.generate_new_lwe_secret_key(output_lwe_dimension)?;
+    ///
+    /// let seeded_keyswitch_key = engine.generate_new_lwe_seeded_keyswitch_key(
+    ///     &input_key,
+    ///     &output_key,
+    ///     decomposition_level_count,
+    ///     decomposition_base_log,
+    ///     noise,
+    /// )?;
+    ///
+    /// let mut serialization_engine = DefaultSerializationEngine::new(())?;
+    /// let serialized = serialization_engine.serialize_container(&seeded_keyswitch_key)?;
+    /// let recovered = serialization_engine.deserialize_container(serialized.as_slice())?;
+    /// assert_eq!(seeded_keyswitch_key, recovered);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn deserialize_container(
+        &mut self,
+        serialized: &[u8],
+    ) -> Result<LweSeededKeyswitchKey32, EntityContainerDeserializationError<Self::EngineError>>
+    {
+        EntityContainerDeserializationError::perform_generic_checks(
+            serialized,
+            ContainerEntityKind::LweSeededKeyswitchKey,
+            32,
+        )?;
+        let (header, payload) = ContainerHeader::split_payload(serialized)?;
+        let entity: LweSeededKeyswitchKey32 = self.deserialize(payload)?;
+        if header.parameters
+            != [
+                entity.input_lwe_dimension().0 as u64,
+                entity.output_lwe_dimension().0 as u64,
+                entity.decomposition_base_log().0 as u64,
+                entity.decomposition_level_count().0 as u64,
+                0,
+            ]
+        {
+            return Err(EntityContainerDeserializationError::ParametersMismatch);
+        }
+        Ok(entity)
+    }
+
+    unsafe fn deserialize_container_unchecked(
+        &mut self,
+        serialized: &[u8],
+    ) -> LweSeededKeyswitchKey32 {
+        let (_, payload) = ContainerHeader::split_payload(serialized).unwrap();
+        self.deserialize_unchecked(payload)
+    }
+}
+
+/// # Description:
+/// Implementation of [`EntityContainerDeserializationEngine`] for [`DefaultSerializationEngine`]
+/// that operates on 64 bits integers. It deserializes from a container a seeded LWE ciphertext
+/// keyswitch key entity.
+impl EntityContainerDeserializationEngine<&[u8], LweSeededKeyswitchKey64>
+    for DefaultSerializationEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_core::prelude::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, LweDimension, Variance, *,
+    /// };
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let input_lwe_dimension = LweDimension(6);
+    /// let output_lwe_dimension = LweDimension(3);
+    /// let decomposition_level_count = DecompositionLevelCount(2);
+    /// let decomposition_base_log = DecompositionBaseLog(8);
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let input_key: LweSecretKey64 = engine.generate_new_lwe_secret_key(input_lwe_dimension)?;
+    /// let output_key: LweSecretKey64 = engine.generate_new_lwe_secret_key(output_lwe_dimension)?;
+    ///
+    /// let seeded_keyswitch_key = engine.generate_new_lwe_seeded_keyswitch_key(
+    ///     &input_key,
+    ///     &output_key,
+    ///     decomposition_level_count,
+    ///     decomposition_base_log,
+    ///     noise,
+    /// )?;
+    ///
+    /// let mut serialization_engine = DefaultSerializationEngine::new(())?;
+    /// let serialized = serialization_engine.serialize_container(&seeded_keyswitch_key)?;
+    /// let recovered = serialization_engine.deserialize_container(serialized.as_slice())?;
+    /// assert_eq!(seeded_keyswitch_key, recovered);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn deserialize_container(
+        &mut self,
+        serialized: &[u8],
+    ) -> Result<LweSeededKeyswitchKey64, EntityContainerDeserializationError<Self::EngineError>>
+    {
+        EntityContainerDeserializationError::perform_generic_checks(
+            serialized,
+            ContainerEntityKind::LweSeededKeyswitchKey,
+            64,
+        )?;
+        let (header, payload) = ContainerHeader::split_payload(serialized)?;
+        let entity: LweSeededKeyswitchKey64 = self.deserialize(payload)?;
+        if header.parameters
+            != [
+                entity.input_lwe_dimension().0 as u64,
+                entity.output_lwe_dimension().0 as u64,
+                entity.decomposition_base_log().0 as u64,
+                entity.decomposition_level_count().0 as u64,
+                0,
+            ]
+        {
+            return Err(EntityContainerDeserializationError::ParametersMismatch);
+        }
+        Ok(entity)
+    }
+
+    unsafe fn deserialize_container_unchecked(
+        &mut self,
+        serialized: &[u8],
+    ) -> LweSeededKeyswitchKey64 {
+        let (_, payload) = ContainerHeader::split_payload(serialized).unwrap();
+        self.deserialize_unchecked(payload)
+    }
+}
+
+/// # Description:
+/// Implementation of [`EntityContainerDeserializationEngine`] for [`DefaultSerializationEngine`]
+/// that operates on 32 bits integers. It serializes a packing keyswitch key entity.
+impl EntityContainerDeserializationEngine<&[u8], LwePackingKeyswitchKey32>
+    for DefaultSerializationEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_core::prelude::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, LweDimension, Variance, *,
+    /// };
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let input_lwe_dimension = LweDimension(6);
+    /// let output_lwe_dimension = LweDimension(3);
+    /// let decomposition_level_count = DecompositionLevelCount(2);
+    /// let decomposition_base_log = DecompositionBaseLog(8);
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let input_key: LweSecretKey32 = engine.generate_new_lwe_secret_key(input_lwe_dimension)?;
+    /// let output_key: LweSecretKey32 = engine.generate_new_lwe_secret_key(output_lwe_dimension)?;
+    ///
+    /// let packing_keyswitch_key = engine.generate_new_lwe_keyswitch_key(
+    ///     &input_key,
+    ///     &output_key,
+    ///     decomposition_level_count,
+    ///     decomposition_base_log,
+    ///     noise,
+    /// )?;
+    ///
+    /// let mut serialization_engine = DefaultSerializationEngine::new(())?;
+    /// let serialized = serialization_engine.serialize_container(&packing_keyswitch_key)?;
+    /// let recovered = serialization_engine.deserialize_container(serialized.as_slice())?;
+    /// assert_eq!(packing_keyswitch_key, recovered);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn deserialize_container(
+        &mut self,
+        serialized: &[u8],
+    ) -> Result<LwePackingKeyswitchKey32, EntityContainerDeserializationError<Self::EngineError>>
+    {
+        EntityContainerDeserializationError::perform_generic_checks(
+            serialized,
+            ContainerEntityKind::LwePackingKeyswitchKey,
+            32,
+        )?;
+        let (header, payload) = ContainerHeader::split_payload(serialized)?;
+        let entity: LwePackingKeyswitchKey32 = self.deserialize(payload)?;
+        if header.parameters
+            != [
+                entity.input_lwe_dimension().0 as u64,
+                entity.output_glwe_dimension().0 as u64,
+                entity.output_polynomial_size().0 as u64,
+                entity.decomposition_base_log().0 as u64,
+                entity.decomposition_level_count().0 as u64,
+            ]
+        {
+            return Err(EntityContainerDeserializationError::ParametersMismatch);
+        }
+        Ok(entity)
+    }
+
+    unsafe fn deserialize_container_unchecked(
+        &mut self,
+        serialized: &[u8],
+    ) -> LwePackingKeyswitchKey32 {
+        let (_, payload) = ContainerHeader::split_payload(serialized).unwrap();
+        self.deserialize_unchecked(payload)
+    }
+}
+
+/// # Description:
+/// Implementation of [`EntityContainerDeserializationEngine`] for [`DefaultSerializationEngine`]
+/// that operates on 64 bits integers. It deserializes from a container a packing keyswitch key
+/// entity.
+impl EntityContainerDeserializationEngine<&[u8], LwePackingKeyswitchKey64>
+    for DefaultSerializationEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_core::prelude::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, LweDimension, Variance, *,
+    /// };
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let input_lwe_dimension = LweDimension(6);
+    /// let output_lwe_dimension = LweDimension(3);
+    /// let decomposition_level_count = DecompositionLevelCount(2);
+    /// let decomposition_base_log = DecompositionBaseLog(8);
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let input_key: LweSecretKey64 = engine.generate_new_lwe_secret_key(input_lwe_dimension)?;
+    /// let output_key: LweSecretKey64 = engine.generate_new_lwe_secret_key(output_lwe_dimension)?;
+    ///
+    /// let packing_keyswitch_key = engine.generate_new_lwe_keyswitch_key(
+    ///     &input_key,
+    ///     &output_key,
+    ///     decomposition_level_count,
+    ///     decomposition_base_log,
+    ///     noise,
+    /// )?;
+    ///
+    /// let mut serialization_engine = DefaultSerializationEngine::new(())?;
+    /// let serialized = serialization_engine.serialize_container(&packing_keyswitch_key)?;
+    /// let recovered = serialization_engine.deserialize_container(serialized.as_slice())?;
+    /// assert_eq!(packing_keyswitch_key, recovered);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn deserialize_container(
+        &mut self,
+        serialized: &[u8],
+    ) -> Result<LwePackingKeyswitchKey64, EntityContainerDeserializationError<Self::EngineError>>
+    {
+        EntityContainerDeserializationError::perform_generic_checks(
+            serialized,
+            ContainerEntityKind::LwePackingKeyswitchKey,
+            64,
+        )?;
+        let (header, payload) = ContainerHeader::split_payload(serialized)?;
+        let entity: LwePackingKeyswitchKey64 = self.deserialize(payload)?;
+        if header.parameters
+            != [
+                entity.input_lwe_dimension().0 as u64,
+                entity.output_glwe_dimension().0 as u64,
+                entity.output_polynomial_size().0 as u64,
+                entity.decomposition_base_log().0 as u64,
+                entity.decomposition_level_count().0 as u64,
+            ]
+        {
+            return Err(EntityContainerDeserializationError::ParametersMismatch);
+        }
+        Ok(entity)
+    }
+
+    unsafe fn deserialize_container_unchecked(
+        &mut self,
+        serialized: &[u8],
+    ) -> LwePackingKeyswitchKey64 {
+        let (_, payload) = ContainerHeader::split_payload(serialized).unwrap();
+        self.deserialize_unchecked(payload)
+    }
+}
+
+/// # Description:
+/// Implementation of [`EntityContainerDeserializationEngine`] for [`DefaultSerializationEngine`]
+/// that operates on 32 bits integers. It deserializes from a container an LWE public key.
+impl EntityContainerDeserializationEngine<&[u8], LwePublicKey32> for DefaultSerializationEngine {
+    /// # Example:
+    /// ```
+    /// use concrete_core::prelude::{LweDimension, LwePublicKeyZeroEncryptionCount, *};
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let lwe_dimension = LweDimension(6);
+    /// let noise = Variance(2_f64.powf(-50.));
+    /// let lwe_public_key_zero_encryption_count = LwePublicKeyZeroEncryptionCount(42);
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let lwe_secret_key: LweSecretKey32 = engine.generate_new_lwe_secret_key(lwe_dimension)?;
+    ///
+    /// let public_key: LwePublicKey32 = engine.generate_new_lwe_public_key(
+    ///     &lwe_secret_key,
+    ///     noise,
+    ///     lwe_public_key_zero_encryption_count,
+    /// )?;
+    ///
+    /// let mut serialization_engine = DefaultSerializationEngine::new(())?;
+    /// let serialized = serialization_engine.serialize_container(&public_key)?;
+    /// let recovered = serialization_engine.deserialize_container(serialized.as_slice())?;
+    /// assert_eq!(public_key, recovered);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn deserialize_container(
+        &mut self,
+        serialized: &[u8],
+    ) -> Result<LwePublicKey32, EntityContainerDeserializationError<Self::EngineError>> {
+        EntityContainerDeserializationError::perform_generic_checks(
+            serialized,
+            ContainerEntityKind::LwePublicKey,
+            32,
+        )?;
+        let (header, payload) = ContainerHeader::split_payload(serialized)?;
+        let entity: LwePublicKey32 = self.deserialize(payload)?;
+        if header.parameters
+            != [
+                entity.lwe_dimension().0 as u64,
+                entity.lwe_zero_encryption_count().0 as u64,
+                0,
+                0,
+                0,
+            ]
+        {
+            return Err(EntityContainerDeserializationError::ParametersMismatch);
+        }
+        Ok(entity)
+    }
+
+    unsafe fn deserialize_container_unchecked(&mut self, serialized: &[u8]) -> LwePublicKey32 {
+        let (_, payload) = ContainerHeader::split_payload(serialized).unwrap();
+        self.deserialize_unchecked(payload)
+    }
+}
+
+/// # Description:
+/// Implementation of [`EntityContainerDeserializationEngine`] for [`DefaultSerializationEngine`]
+/// that operates on 64 bits integers. It deserializes from a container an LWE public key.
+impl EntityContainerDeserializationEngine<&[u8], LwePublicKey64> for DefaultSerializationEngine {
+    /// # Example:
+    /// ```
+    /// use concrete_core::prelude::{LweDimension, LwePublicKeyZeroEncryptionCount, *};
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let lwe_dimension = LweDimension(6);
+    /// let noise = Variance(2_f64.powf(-50.));
+    /// let lwe_public_key_zero_encryption_count = LwePublicKeyZeroEncryptionCount(42);
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let lwe_secret_key: LweSecretKey64 = engine.generate_new_lwe_secret_key(lwe_dimension)?;
+    ///
+    /// let public_key: LwePublicKey64 = engine.generate_new_lwe_public_key(
+    ///     &lwe_secret_key,
+    ///     noise,
+    ///     lwe_public_key_zero_encryption_count,
+    /// )?;
+    ///
+    /// let mut serialization_engine = DefaultSerializationEngine::new(())?;
+    /// let serialized = serialization_engine.serialize_container(&public_key)?;
+    /// let recovered = serialization_engine.deserialize_container(serialized.as_slice())?;
+    /// assert_eq!(public_key, recovered);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn deserialize_container(
+        &mut self,
+        serialized: &[u8],
+    ) -> Result<LwePublicKey64, EntityContainerDeserializationError<Self::EngineError>> {
+        EntityContainerDeserializationError::perform_generic_checks(
+            serialized,
+            ContainerEntityKind::LwePublicKey,
+            64,
+        )?;
+        let (header, payload) = ContainerHeader::split_payload(serialized)?;
+        let entity: LwePublicKey64 = self.deserialize(payload)?;
+        if header.parameters
+            != [
+                entity.lwe_dimension().0 as u64,
+                entity.lwe_zero_encryption_count().0 as u64,
+                0,
+                0,
+                0,
+            ]
+        {
+            return Err(EntityContainerDeserializationError::ParametersMismatch);
+        }
+        Ok(entity)
+    }
+
+    unsafe fn deserialize_container_unchecked(&mut self, serialized: &[u8]) -> LwePublicKey64 {
+        let (_, payload) = ContainerHeader::split_payload(serialized).unwrap();
+        self.deserialize_unchecked(payload)
+    }
+}
+
+/// # Description:
+/// Implementation of [`EntityContainerDeserializationEngine`] for [`DefaultSerializationEngine`]
+/// that operates on 32 bits integers. It deserializes from a container a plaintext entity.
+impl EntityContainerDeserializationEngine<&[u8], Plaintext32> for DefaultSerializationEngine {
+    /// # Example:
+    /// ```
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // Here a hard-set encoding is applied (shift by 20 bits)
+    /// let input = 3_u32 << 20;
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let plaintext: Plaintext32 = engine.create_plaintext_from(&input)?;
+    ///
+    /// let mut serialization_engine = DefaultSerializationEngine::new(())?;
+    /// let serialized = serialization_engine.serialize_container(&plaintext)?;
+    /// let recovered = serialization_engine.deserialize_container(serialized.as_slice())?;
+    /// assert_eq!(plaintext, recovered);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn deserialize_container(
+        &mut self,
+        serialized: &[u8],
+    ) -> Result<Plaintext32, EntityContainerDeserializationError<Self::EngineError>> {
+        EntityContainerDeserializationError::perform_generic_checks(
+            serialized,
+            ContainerEntityKind::Plaintext,
+            32,
+        )?;
+        let (header, payload) = ContainerHeader::split_payload(serialized)?;
+        let entity: Plaintext32 = self.deserialize(payload)?;
+        if header.parameters != [0, 0, 0, 0, 0] {
+            return Err(EntityContainerDeserializationError::ParametersMismatch);
+        }
+        Ok(entity)
+    }
+
+    unsafe fn deserialize_container_unchecked(&mut self, serialized: &[u8]) -> Plaintext32 {
+        let (_, payload) = ContainerHeader::split_payload(serialized).unwrap();
+        self.deserialize_unchecked(payload)
+    }
+}
+
+/// # Description:
+/// Implementation of [`EntityContainerDeserializationEngine`] for [`DefaultSerializationEngine`]
+/// that operates on 64 bits integers. It deserializes from a container a plaintext entity.
+impl EntityContainerDeserializationEngine<&[u8], Plaintext64> for DefaultSerializationEngine {
+    /// # Example:
+    /// ```
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // Here a hard-set encoding is applied (shift by 50 bits)
+    /// let input = 3_u64 << 50;
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let plaintext: Plaintext64 = engine.create_plaintext_from(&input)?;
+    ///
+    /// let mut serialization_engine = DefaultSerializationEngine::new(())?;
+    /// let serialized = serialization_engine.serialize_container(&plaintext)?;
+    /// let recovered = serialization_engine.deserialize_container(serialized.as_slice())?;
+    /// assert_eq!(plaintext, recovered);
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn deserialize_container(
+        &mut self,
+        serialized: &[u8],
+    ) -> Result<Plaintext64, EntityContainerDeserializationError<Self::EngineError>> {
+        EntityContainerDeserializationError::perform_generic_checks(
+            serialized,
+            ContainerEntityKind::Plaintext,
+            64,
+        )?;
+        let (header, payload) = ContainerHeader::split_payload(serialized)?;
+        let entity: Plaintext64 = self.deserialize(payload)?;
+        if header.parameters != [0, 0, 0, 0, 0] {
+            return Err(EntityContainerDeserializationError::ParametersMismatch);
+        }
+        Ok(entity)
+    }
+
+    unsafe fn deserialize_container_unchecked(&mut self, serialized: &[u8]) -> Plaintext64 {
+        let (_, payload) = ContainerHeader::split_payload(serialized).unwrap();
+        self.deserialize_unchecked(payload)
+    }
+}
+
+/// # Description:
+/// Implementation of [`EntityContainerDeserializationEngine`] for [`DefaultSerializationEngine`]
+/// that operates on 32 bits integers. It deserializes from a container a plaintext vector entity.
+impl EntityContainerDeserializationEngine<&[u8], PlaintextVector32> for DefaultSerializationEngine {
+    /// # Example:
+    /// ```
+    /// use concrete_core::prelude::{PlaintextCount, *};
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // Here a hard-set encoding is applied (shift by 20 bits)
+    /// let input = vec![3_u32 << 20; 3];
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let plaintext_vector: PlaintextVector32 = engine.create_plaintext_vector_from(&input)?;
+    ///
+    /// let mut serialization_engine = DefaultSerializationEngine::new(())?;
+    /// let serialized = serialization_engine.serialize_container(&plaintext_vector)?;
+    /// let recovered = serialization_engine.deserialize_container(serialized.as_slice())?;
+    /// assert_eq!(plaintext_vector, recovered);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn deserialize_container(
+        &mut self,
+        serialized: &[u8],
+    ) -> Result<PlaintextVector32, EntityContainerDeserializationError<Self::EngineError>> {
+        EntityContainerDeserializationError::perform_generic_checks(
+            serialized,
+            ContainerEntityKind::PlaintextVector,
+            32,
+        )?;
+        let (header, payload) = ContainerHeader::split_payload(serialized)?;
+        let entity: PlaintextVector32 = self.deserialize(payload)?;
+        if header.parameters != [entity.plaintext_count().0 as u64, 0, 0, 0, 0] {
+            return Err(EntityContainerDeserializationError::ParametersMismatch);
+        }
+        Ok(entity)
+    }
+
+    unsafe fn deserialize_container_unchecked(&mut self, serialized: &[u8]) -> PlaintextVector32 {
+        let (_, payload) = ContainerHeader::split_payload(serialized).unwrap();
+        self.deserialize_unchecked(payload)
+    }
+}
+
+/// # Description:
+/// Implementation of [`EntityContainerDeserializationEngine`] for [`DefaultSerializationEngine`]
+/// that operates on 64 bits integers. It deserializes from a container a plaintext vector entity.
+impl EntityContainerDeserializationEngine<&[u8], PlaintextVector64> for DefaultSerializationEngine {
+    /// # Example:
+    /// ```
+    /// use concrete_core::prelude::{PlaintextCount, *};
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // Here a hard-set encoding is applied (shift by 50 bits)
+    /// let input = vec![3_u64 << 50; 3];
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let plaintext_vector: PlaintextVector64 = engine.create_plaintext_vector_from(&input)?;
+    ///
+    /// let mut serialization_engine = DefaultSerializationEngine::new(())?;
+    /// let serialized = serialization_engine.serialize_container(&plaintext_vector)?;
+    /// let recovered = serialization_engine.deserialize_container(serialized.as_slice())?;
+    /// assert_eq!(plaintext_vector, recovered);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn deserialize_container(
+        &mut self,
+        serialized: &[u8],
+    ) -> Result<PlaintextVector64, EntityContainerDeserializationError<Self::EngineError>> {
+        EntityContainerDeserializationError::perform_generic_checks(
+            serialized,
+            ContainerEntityKind::PlaintextVector,
+            64,
+        )?;
+        let (header, payload) = ContainerHeader::split_payload(serialized)?;
+        let entity: PlaintextVector64 = self.deserialize(payload)?;
+        if header.parameters != [entity.plaintext_count().0 as u64, 0, 0, 0, 0] {
+            return Err(EntityContainerDeserializationError::ParametersMismatch);
+        }
+        Ok(entity)
+    }
+
+    unsafe fn deserialize_container_unchecked(&mut self, serialized: &[u8]) -> PlaintextVector64 {
+        let (_, payload) = ContainerHeader::split_payload(serialized).unwrap();
+        self.deserialize_unchecked(payload)
+    }
+}
+
+/// # Description:
+/// Implementation of [`EntityContainerDeserializationEngine`] for [`DefaultSerializationEngine`]
+/// that operates on 64 bits integers. It deserializes from a container a float encoder entity.
+impl EntityContainerDeserializationEngine<&[u8], FloatEncoder> for DefaultSerializationEngine {
+    /// # Example:
+    /// ```
+    /// use concrete_core::prelude::{PlaintextCount, *};
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let encoder = engine.create_encoder_from(&FloatEncoderMinMaxConfig {
+    ///     min: 0.,
+    ///     max: 10.,
+    ///     nb_bit_precision: 8,
+    ///     nb_bit_padding: 1,
+    /// })?;
+    ///
+    /// let mut serialization_engine = DefaultSerializationEngine::new(())?;
+    /// let serialized = serialization_engine.serialize_container(&encoder)?;
+    /// let recovered = serialization_engine.deserialize_container(serialized.as_slice())?;
+    /// assert_eq!(encoder, recovered);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn deserialize_container(
+        &mut self,
+        serialized: &[u8],
+    ) -> Result<FloatEncoder, EntityContainerDeserializationError<Self::EngineError>> {
+        EntityContainerDeserializationError::perform_generic_checks(
+            serialized,
+            ContainerEntityKind::FloatEncoder,
+            64,
+        )?;
+        let (header, payload) = ContainerHeader::split_payload(serialized)?;
+        let entity: FloatEncoder = self.deserialize(payload)?;
+        if header.parameters != [0, 0, 0, 0, 0] {
+            return Err(EntityContainerDeserializationError::ParametersMismatch);
+        }
+        Ok(entity)
+    }
+
+    unsafe fn deserialize_container_unchecked(&mut self, serialized: &[u8]) -> FloatEncoder {
+        let (_, payload) = ContainerHeader::split_payload(serialized).unwrap();
+        self.deserialize_unchecked(payload)
+    }
+}
+
+/// # Description:
+/// Implementation of [`EntityContainerDeserializationEngine`] for [`DefaultSerializationEngine`]
+/// that operates on 64 bits integers. It deserializes from a container a float encoder vector
+/// entity.
+impl EntityContainerDeserializationEngine<&[u8], FloatEncoderVector>
+    for DefaultSerializationEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_core::prelude::PlaintextCount;
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let encoder_vector = engine.create_encoder_vector_from(&vec![
+    ///     FloatEncoderCenterRadiusConfig {
+    ///         center: 10.,
+    ///         radius: 5.,
+    ///         nb_bit_precision: 8,
+    ///         nb_bit_padding: 1,
+    ///     };
+    ///     1
+    /// ])?;
+    ///
+    /// let mut serialization_engine = DefaultSerializationEngine::new(())?;
+    /// let serialized = serialization_engine.serialize_container(&encoder_vector)?;
+    /// let recovered = serialization_engine.deserialize_container(serialized.as_slice())?;
+    /// assert_eq!(encoder_vector, recovered);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn deserialize_container(
+        &mut self,
+        serialized: &[u8],
+    ) -> Result<FloatEncoderVector, EntityContainerDeserializationError<Self::EngineError>> {
+        EntityContainerDeserializationError::perform_generic_checks(
+            serialized,
+            ContainerEntityKind::FloatEncoderVector,
+            64,
+        )?;
+        let (header, payload) = ContainerHeader::split_payload(serialized)?;
+        let entity: FloatEncoderVector = self.deserialize(payload)?;
+        if header.parameters != [entity.encoder_count().0 as u64, 0, 0, 0, 0] {
+            return Err(EntityContainerDeserializationError::ParametersMismatch);
+        }
+        Ok(entity)
+    }
+
+    unsafe fn deserialize_container_unchecked(&mut self, serialized: &[u8]) -> FloatEncoderVector {
+        let (_, payload) = ContainerHeader::split_payload(serialized).unwrap();
+        self.deserialize_unchecked(payload)
+    }
+}
+
+/// An entity of the default backend, deserialized from a container whose content was not known
+/// in advance.
+#[derive(Debug, PartialEq)]
+pub enum DefaultContainedEntity {
+    Cleartext32(Cleartext32),
+    Cleartext64(Cleartext64),
+    CleartextF64(CleartextF64),
+    CleartextVector32(CleartextVector32),
+    CleartextVector64(CleartextVector64),
+    CleartextVectorF64(CleartextVectorF64),
+    GgswCiphertext32(GgswCiphertext32),
+    GgswCiphertext64(GgswCiphertext64),
+    GgswSeededCiphertext32(GgswSeededCiphertext32),
+    GgswSeededCiphertext64(GgswSeededCiphertext64),
+    GlweCiphertext32(GlweCiphertext32),
+    GlweCiphertext64(GlweCiphertext64),
+    GlweCiphertextVector32(GlweCiphertextVector32),
+    GlweCiphertextVector64(GlweCiphertextVector64),
+    GlweSecretKey32(GlweSecretKey32),
+    GlweSecretKey64(GlweSecretKey64),
+    GlweCompressedCiphertext32(GlweCompressedCiphertext32),
+    GlweCompressedCiphertext64(GlweCompressedCiphertext64),
+    GlweSeededCiphertext32(GlweSeededCiphertext32),
+    GlweSeededCiphertext64(GlweSeededCiphertext64),
+    GlweSeededCiphertextVector32(GlweSeededCiphertextVector32),
+    GlweSeededCiphertextVector64(GlweSeededCiphertextVector64),
+    LweBootstrapKey32(LweBootstrapKey32),
+    LweBootstrapKey64(LweBootstrapKey64),
+    LweCiphertext32(LweCiphertext32),
+    LweCiphertext64(LweCiphertext64),
+    LweCiphertextVector32(LweCiphertextVector32),
+    LweCiphertextVector64(LweCiphertextVector64),
+    LweCircuitBootstrapPrivateFunctionalPackingKeyswitchKeys32(
+        LweCircuitBootstrapPrivateFunctionalPackingKeyswitchKeys32,
+    ),
+    LweCircuitBootstrapPrivateFunctionalPackingKeyswitchKeys64(
+        LweCircuitBootstrapPrivateFunctionalPackingKeyswitchKeys64,
+    ),
+    LweKeyswitchKey32(LweKeyswitchKey32),
+    LweKeyswitchKey64(LweKeyswitchKey64),
+    LweSecretKey32(LweSecretKey32),
+    LweSecretKey64(LweSecretKey64),
+    LweSeededBootstrapKey32(LweSeededBootstrapKey32),
+    LweSeededBootstrapKey64(LweSeededBootstrapKey64),
+    LweCompressedCiphertext32(LweCompressedCiphertext32),
+    LweCompressedCiphertext64(LweCompressedCiphertext64),
+    LweSeededCiphertext32(LweSeededCiphertext32),
+    LweSeededCiphertext64(LweSeededCiphertext64),
+    LweSeededCiphertextVector32(LweSeededCiphertextVector32),
+    LweSeededCiphertextVector64(LweSeededCiphertextVector64),
+    LweSeededKeyswitchKey32(LweSeededKeyswitchKey32),
+    LweSeededKeyswitchKey64(LweSeededKeyswitchKey64),
+    LwePackingKeyswitchKey32(LwePackingKeyswitchKey32),
+    LwePackingKeyswitchKey64(LwePackingKeyswitchKey64),
+    LwePublicKey32(LwePublicKey32),
+    LwePublicKey64(LwePublicKey64),
+    Plaintext32(Plaintext32),
+    Plaintext64(Plaintext64),
+    PlaintextVector32(PlaintextVector32),
+    PlaintextVector64(PlaintextVector64),
+    FloatEncoder(FloatEncoder),
+    FloatEncoderVector(FloatEncoderVector),
+}
+
+impl DefaultSerializationEngine {
+    /// Deserializes an entity from a container, without knowing its type in advance.
+    ///
+    /// The kind of the entity and the width of its scalars are read from the header of the
+    /// container, which is then checked like in
+    /// [`EntityContainerDeserializationEngine::deserialize_container`].
+    ///
+    /// # Example:
+    /// ```
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let input_lwe_dimension = LweDimension(6);
+    /// let output_lwe_dimension = LweDimension(3);
+    /// let decomposition_level_count = DecompositionLevelCount(2);
+    /// let decomposition_base_log = DecompositionBaseLog(8);
+    /// let noise = Variance(2_f64.powf(-50.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let input_key: LweSecretKey64 = engine.generate_new_lwe_secret_key(input_lwe_dimension)?;
+    /// let output_key: LweSecretKey64 = engine.generate_new_lwe_secret_key(output_lwe_dimension)?;
+    /// let keyswitch_key = engine.generate_new_lwe_keyswitch_key(
+    ///     &input_key,
+    ///     &output_key,
+    ///     decomposition_level_count,
+    ///     decomposition_base_log,
+    ///     noise,
+    /// )?;
+    ///
+    /// let mut serialization_engine = DefaultSerializationEngine::new(())?;
+    /// let serialized = serialization_engine.serialize_container(&keyswitch_key)?;
+    ///
+    /// // The header can be inspected before deserializing the entity.
+    /// let (header, _) = ContainerHeader::split_payload(serialized.as_slice())?;
+    /// assert_eq!(header.kind, ContainerEntityKind::LweKeyswitchKey);
+    /// assert_eq!(header.scalar_width, 64);
+    ///
+    /// match serialization_engine.deserialize_any_container(serialized.as_slice())? {
+    ///     DefaultContainedEntity::LweKeyswitchKey64(recovered) => {
+    ///         assert_eq!(keyswitch_key, recovered)
+    ///     }
+    ///     _ => panic!("Unexpected entity"),
+    /// }
+    ///
+    /// // Deserializing the container as another kind of entity fails.
+    /// let result: Result<LweBootstrapKey64, _> =
+    ///     serialization_engine.deserialize_container(serialized.as_slice());
+    /// assert!(matches!(
+    ///     result,
+    ///     Err(EntityContainerDeserializationError::EntityKindMismatch)
+    /// ));
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn deserialize_any_container(
+        &mut self,
+        serialized: &[u8],
+    ) -> Result<
+        DefaultContainedEntity,
+        EntityContainerDeserializationError<DefaultSerializationError>,
+    > {
+        let (header, _) = ContainerHeader::split_payload(serialized)?;
+        match (header.kind, header.scalar_width) {
+            (ContainerEntityKind::Cleartext, 32) => self
+                .deserialize_container(serialized)
+                .map(DefaultContainedEntity::Cleartext32),
+            (ContainerEntityKind::Cleartext, 64) => self
+                .deserialize_container(serialized)
+                .map(DefaultContainedEntity::Cleartext64),
+            (ContainerEntityKind::CleartextF64, 64) => self
+                .deserialize_container(serialized)
+                .map(DefaultContainedEntity::CleartextF64),
+            (ContainerEntityKind::CleartextVector, 32) => self
+                .deserialize_container(serialized)
+                .map(DefaultContainedEntity::CleartextVector32),
+            (ContainerEntityKind::CleartextVector, 64) => self
+                .deserialize_container(serialized)
+                .map(DefaultContainedEntity::CleartextVector64),
+            (ContainerEntityKind::CleartextVectorF64, 64) => self
+                .deserialize_container(serialized)
+                .map(DefaultContainedEntity::CleartextVectorF64),
+            (ContainerEntityKind::GgswCiphertext, 32) => self
+                .deserialize_container(serialized)
+                .map(DefaultContainedEntity::GgswCiphertext32),
+            (ContainerEntityKind::GgswCiphertext, 64) => self
+                .deserialize_container(serialized)
+                .map(DefaultContainedEntity::GgswCiphertext64),
+            (ContainerEntityKind::GgswSeededCiphertext, 32) => self
+                .deserialize_container(serialized)
+                .map(DefaultContainedEntity::GgswSeededCiphertext32),
+            (ContainerEntityKind::GgswSeededCiphertext, 64) => self
+                .deserialize_container(serialized)
+                .map(DefaultContainedEntity::GgswSeededCiphertext64),
+            (ContainerEntityKind::GlweCiphertext, 32) => self
+                .deserialize_container(serialized)
+                .map(DefaultContainedEntity::GlweCiphertext32),
+            (ContainerEntityKind::GlweCiphertext, 64) => self
+                .deserialize_container(serialized)
+                .map(DefaultContainedEntity::GlweCiphertext64),
+            (ContainerEntityKind::GlweCiphertextVector, 32) => self
+                .deserialize_container(serialized)
+                .map(DefaultContainedEntity::GlweCiphertextVector32),
+            (ContainerEntityKind::GlweCiphertextVector, 64) => self
+                .deserialize_container(serialized)
+                .map(DefaultContainedEntity::GlweCiphertextVector64),
+            (ContainerEntityKind::GlweSecretKey, 32) => self
+                .deserialize_container(serialized)
+                .map(DefaultContainedEntity::GlweSecretKey32),
+            (ContainerEntityKind::GlweSecretKey, 64) => self
+                .deserialize_container(serialized)
+                .map(DefaultContainedEntity::GlweSecretKey64),
+            (ContainerEntityKind::GlweCompressedCiphertext, 32) => self
+                .deserialize_container(serialized)
+                .map(DefaultContainedEntity::GlweCompressedCiphertext32),
+            (ContainerEntityKind::GlweCompressedCiphertext, 64) => self
+                .deserialize_container(serialized)
+                .map(DefaultContainedEntity::GlweCompressedCiphertext64),
+            (ContainerEntityKind::GlweSeededCiphertext, 32) => self
+                .deserialize_container(serialized)
+                .map(DefaultContainedEntity::GlweSeededCiphertext32),
+            (ContainerEntityKind::GlweSeededCiphertext, 64) => self
+                .deserialize_container(serialized)
+                .map(DefaultContainedEntity::GlweSeededCiphertext64),
+            (ContainerEntityKind::GlweSeededCiphertextVector, 32) => self
+                .deserialize_container(serialized)
+                .map(DefaultContainedEntity::GlweSeededCiphertextVector32),
+            (ContainerEntityKind::GlweSeededCiphertextVector, 64) => self
+                .deserialize_container(serialized)
+                .map(DefaultContainedEntity::GlweSeededCiphertextVector64),
+            (ContainerEntityKind::LweBootstrapKey, 32) => self
+                .deserialize_container(serialized)
+                .map(DefaultContainedEntity::LweBootstrapKey32),
+            (ContainerEntityKind::LweBootstrapKey, 64) => self
+                .deserialize_container(serialized)
+                .map(DefaultContainedEntity::LweBootstrapKey64),
+            (ContainerEntityKind::LweCiphertext, 32) => self
+                .deserialize_container(serialized)
+                .map(DefaultContainedEntity::LweCiphertext32),
+            (ContainerEntityKind::LweCiphertext, 64) => self
+                .deserialize_container(serialized)
+                .map(DefaultContainedEntity::LweCiphertext64),
+            (ContainerEntityKind::LweCiphertextVector, 32) => self
+                .deserialize_container(serialized)
+                .map(DefaultContainedEntity::LweCiphertextVector32),
+            (ContainerEntityKind::LweCiphertextVector, 64) => self
+                .deserialize_container(serialized)
+                .map(DefaultContainedEntity::LweCiphertextVector64),
+            (ContainerEntityKind::LweCircuitBootstrapPrivateFunctionalPackingKeyswitchKeys, 32) => self
+                .deserialize_container(serialized)
+                .map(DefaultContainedEntity::LweCircuitBootstrapPrivateFunctionalPackingKeyswitchKeys32),
+            (ContainerEntityKind::LweCircuitBootstrapPrivateFunctionalPackingKeyswitchKeys, 64) => self
+                .deserialize_container(serialized)
+                .map(DefaultContainedEntity::LweCircuitBootstrapPrivateFunctionalPackingKeyswitchKeys64),
+            (ContainerEntityKind::LweKeyswitchKey, 32) => self
+                .deserialize_container(serialized)
+                .map(DefaultContainedEntity::LweKeyswitchKey32),
+            (ContainerEntityKind::LweKeyswitchKey, 64) => self
+                .deserialize_container(serialized)
+                .map(DefaultContainedEntity::LweKeyswitchKey64),
+            (ContainerEntityKind::LweSecretKey, 32) => self
+                .deserialize_container(serialized)
+                .map(DefaultContainedEntity::LweSecretKey32),
+            (ContainerEntityKind::LweSecretKey, 64) => self
+                .deserialize_container(serialized)
+                .map(DefaultContainedEntity::LweSecretKey64),
+            (ContainerEntityKind::LweSeededBootstrapKey, 32) => self
+                .deserialize_container(serialized)
+                .map(DefaultContainedEntity::LweSeededBootstrapKey32),
+            (ContainerEntityKind::LweSeededBootstrapKey, 64) => self
+                .deserialize_container(serialized)
+                .map(DefaultContainedEntity::LweSeededBootstrapKey64),
+            (ContainerEntityKind::LweCompressedCiphertext, 32) => self
+                .deserialize_container(serialized)
+                .map(DefaultContainedEntity::LweCompressedCiphertext32),
+            (ContainerEntityKind::LweCompressedCiphertext, 64) => self
+                .deserialize_container(serialized)
+                .map(DefaultContainedEntity::LweCompressedCiphertext64),
+            (ContainerEntityKind::LweSeededCiphertext, 32) => self
+                .deserialize_container(serialized)
+                .map(DefaultContainedEntity::LweSeededCiphertext32),
+            (ContainerEntityKind::LweSeededCiphertext, 64) => self
+                .deserialize_container(serialized)
+                .map(DefaultContainedEntity::LweSeededCiphertext64),
+            (ContainerEntityKind::LweSeededCiphertextVector, 32) => self
+                .deserialize_container(serialized)
+                .map(DefaultContainedEntity::LweSeededCiphertextVector32),
+            (ContainerEntityKind::LweSeededCiphertextVector, 64) => self
+                .deserialize_container(serialized)
+                .map(DefaultContainedEntity::LweSeededCiphertextVector64),
+            (ContainerEntityKind::LweSeededKeyswitchKey, 32) => self
+                .deserialize_container(serialized)
+                .map(DefaultContainedEntity::LweSeededKeyswitchKey32),
+            (ContainerEntityKind::LweSeededKeyswitchKey, 64) => self
+                .deserialize_container(serialized)
+                .map(DefaultContainedEntity::LweSeededKeyswitchKey64),
+            (ContainerEntityKind::LwePackingKeyswitchKey, 32) => self
+                .deserialize_container(serialized)
+                .map(DefaultContainedEntity::LwePackingKeyswitchKey32),
+            (ContainerEntityKind::LwePackingKeyswitchKey, 64) => self
+                .deserialize_container(serialized)
+                .map(DefaultContainedEntity::LwePackingKeyswitchKey64),
+            (ContainerEntityKind::LwePublicKey, 32) => self
+                .deserialize_container(serialized)
+                .map(DefaultContainedEntity::LwePublicKey32),
+            (ContainerEntityKind::LwePublicKey, 64) => self
+                .deserialize_container(serialized)
+                .map(DefaultContainedEntity::LwePublicKey64),
+            (ContainerEntityKind::Plaintext, 32) => self
+                .deserialize_container(serialized)
+                .map(DefaultContainedEntity::Plaintext32),
+            (ContainerEntityKind::Plaintext, 64) => self
+                .deserialize_container(serialized)
+                .map(DefaultContainedEntity::Plaintext64),
+            (ContainerEntityKind::PlaintextVector, 32) => self
+                .deserialize_container(serialized)
+                .map(DefaultContainedEntity::PlaintextVector32),
+            (ContainerEntityKind::PlaintextVector, 64) => self
+                .deserialize_container(serialized)
+                .map(DefaultContainedEntity::PlaintextVector64),
+            (ContainerEntityKind::FloatEncoder, 64) => self
+                .deserialize_container(serialized)
+                .map(DefaultContainedEntity::FloatEncoder),
+            (ContainerEntityKind::FloatEncoderVector, 64) => self
+                .deserialize_container(serialized)
+                .map(DefaultContainedEntity::FloatEncoderVector),
+            _ => Err(EntityContainerDeserializationError::EntityKindMismatch),
+        }
+    }
+}
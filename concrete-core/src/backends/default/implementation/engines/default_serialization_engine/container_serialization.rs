@@ -0,0 +1,3232 @@
+#![allow(clippy::missing_safety_doc)]
+
+use crate::prelude::{
+    Cleartext32, Cleartext64, CleartextF64, CleartextVector32, CleartextVector64,
+    CleartextVectorEntity, CleartextVectorF64, DefaultSerializationEngine, EncoderVectorEntity,
+    EntityContainerSerializationEngine, EntityContainerSerializationError,
+    EntitySerializationEngine, FloatEncoder, FloatEncoderVector, GgswCiphertext32,
+    GgswCiphertext64, GgswCiphertextEntity, GgswSeededCiphertext32, GgswSeededCiphertext64,
+    GgswSeededCiphertextEntity, GlweCiphertext32, GlweCiphertext64, GlweCiphertextEntity,
+    GlweCiphertextVector32, GlweCiphertextVector64, GlweCiphertextVectorEntity,
+    GlweCompressedCiphertext32, GlweCompressedCiphertext64, GlweCompressedCiphertextEntity,
+    GlweSecretKey32, GlweSecretKey64, GlweSecretKeyEntity, GlweSeededCiphertext32,
+    GlweSeededCiphertext64, GlweSeededCiphertextEntity, GlweSeededCiphertextVector32,
+    GlweSeededCiphertextVector64, GlweSeededCiphertextVectorEntity, LweBootstrapKey32,
+    LweBootstrapKey64, LweBootstrapKeyEntity, LweCiphertext32, LweCiphertext64,
+    LweCiphertextEntity, LweCiphertextVector32, LweCiphertextVector64, LweCiphertextVectorEntity,
+    LweCircuitBootstrapPrivateFunctionalPackingKeyswitchKeys32,
+    LweCircuitBootstrapPrivateFunctionalPackingKeyswitchKeys64,
+    LweCircuitBootstrapPrivateFunctionalPackingKeyswitchKeysEntity, LweCompressedCiphertext32,
+    LweCompressedCiphertext64, LweCompressedCiphertextEntity, LweKeyswitchKey32, LweKeyswitchKey64,
+    LweKeyswitchKeyEntity, LwePackingKeyswitchKey32, LwePackingKeyswitchKey64,
+    LwePackingKeyswitchKeyEntity, LwePublicKey32, LwePublicKey64, LwePublicKeyEntity,
+    LweSecretKey32, LweSecretKey64, LweSecretKeyEntity, LweSeededBootstrapKey32,
+    LweSeededBootstrapKey64, LweSeededBootstrapKeyEntity, LweSeededCiphertext32,
+    LweSeededCiphertext64, LweSeededCiphertextEntity, LweSeededCiphertextVector32,
+    LweSeededCiphertextVector64, LweSeededCiphertextVectorEntity, LweSeededKeyswitchKey32,
+    LweSeededKeyswitchKey64, LweSeededKeyswitchKeyEntity, Plaintext32, Plaintext64,
+    PlaintextVector32, PlaintextVector64, PlaintextVectorEntity,
+};
+use crate::specification::container::{ContainerEntityKind, ContainerHeader};
+
+/// # Description:
+/// Implementation of [`EntityContainerSerializationEngine`] for [`DefaultSerializationEngine`] that
+/// operates on 32 bits integers. It serializes in a container a cleartext entity.
+impl EntityContainerSerializationEngine<Cleartext32, Vec<u8>> for DefaultSerializationEngine {
+    /// # Example:
+    /// ```
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// let input: u32 = 3;
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let cleartext: Cleartext32 = engine.create_cleartext_from(&input)?;
+    /// let mut serialization_engine = DefaultSerializationEngine::new(())?;
+    /// let serialized = serialization_engine.serialize_container(&cleartext)?;
+    /// let recovered = serialization_engine.deserialize_container(serialized.as_slice())?;
+    /// assert_eq!(cleartext, recovered);
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn serialize_container(
+        &mut self,
+        entity: &Cleartext32,
+    ) -> Result<Vec<u8>, EntityContainerSerializationError<Self::EngineError>> {
+        let payload = self.serialize(entity)?;
+        let header = ContainerHeader::new(
+            ContainerEntityKind::Cleartext,
+            32,
+            [0, 0, 0, 0, 0],
+            &payload,
+        );
+        Ok(header.wrap(&payload))
+    }
+
+    unsafe fn serialize_container_unchecked(&mut self, entity: &Cleartext32) -> Vec<u8> {
+        self.serialize_container(entity).unwrap()
+    }
+}
+
+/// # Description:
+/// Implementation of [`EntityContainerSerializationEngine`] for [`DefaultSerializationEngine`] that
+/// operates on 64 bits integers. It serializes in a container a cleartext entity.
+impl EntityContainerSerializationEngine<Cleartext64, Vec<u8>> for DefaultSerializationEngine {
+    /// # Example:
+    /// ```
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// let input: u64 = 3;
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let cleartext: Cleartext64 = engine.create_cleartext_from(&input)?;
+    /// let mut serialization_engine = DefaultSerializationEngine::new(())?;
+    /// let serialized = serialization_engine.serialize_container(&cleartext)?;
+    /// let recovered = serialization_engine.deserialize_container(serialized.as_slice())?;
+    /// assert_eq!(cleartext, recovered);
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn serialize_container(
+        &mut self,
+        entity: &Cleartext64,
+    ) -> Result<Vec<u8>, EntityContainerSerializationError<Self::EngineError>> {
+        let payload = self.serialize(entity)?;
+        let header = ContainerHeader::new(
+            ContainerEntityKind::Cleartext,
+            64,
+            [0, 0, 0, 0, 0],
+            &payload,
+        );
+        Ok(header.wrap(&payload))
+    }
+
+    unsafe fn serialize_container_unchecked(&mut self, entity: &Cleartext64) -> Vec<u8> {
+        self.serialize_container(entity).unwrap()
+    }
+}
+
+/// # Description:
+/// Implementation of [`EntityContainerSerializationEngine`] for [`DefaultSerializationEngine`] that
+/// operates on 64 bits integers. It serializes in a container a floating point cleartext.
+impl EntityContainerSerializationEngine<CleartextF64, Vec<u8>> for DefaultSerializationEngine {
+    /// # Example:
+    /// ```
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// let input: f64 = 3.;
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let cleartext: CleartextF64 = engine.create_cleartext_from(&input)?;
+    /// let mut serialization_engine = DefaultSerializationEngine::new(())?;
+    /// let serialized = serialization_engine.serialize_container(&cleartext)?;
+    /// let recovered = serialization_engine.deserialize_container(serialized.as_slice())?;
+    /// assert_eq!(cleartext, recovered);
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn serialize_container(
+        &mut self,
+        entity: &CleartextF64,
+    ) -> Result<Vec<u8>, EntityContainerSerializationError<Self::EngineError>> {
+        let payload = self.serialize(entity)?;
+        let header = ContainerHeader::new(
+            ContainerEntityKind::CleartextF64,
+            64,
+            [0, 0, 0, 0, 0],
+            &payload,
+        );
+        Ok(header.wrap(&payload))
+    }
+
+    unsafe fn serialize_container_unchecked(&mut self, entity: &CleartextF64) -> Vec<u8> {
+        self.serialize_container(entity).unwrap()
+    }
+}
+
+/// # Description:
+/// Implementation of [`EntityContainerSerializationEngine`] for [`DefaultSerializationEngine`] that
+/// operates on 32 bits integers. It serializes in a container a cleartext vector entity.
+impl EntityContainerSerializationEngine<CleartextVector32, Vec<u8>> for DefaultSerializationEngine {
+    /// # Example:
+    /// ```
+    /// use concrete_core::prelude::{CleartextCount, *};
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// let input = vec![3_u32; 100];
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let cleartext_vector: CleartextVector32 = engine.create_cleartext_vector_from(&input)?;
+    /// let mut serialization_engine = DefaultSerializationEngine::new(())?;
+    /// let serialized = serialization_engine.serialize_container(&cleartext_vector)?;
+    /// let recovered = serialization_engine.deserialize_container(serialized.as_slice())?;
+    /// assert_eq!(cleartext_vector, recovered);
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn serialize_container(
+        &mut self,
+        entity: &CleartextVector32,
+    ) -> Result<Vec<u8>, EntityContainerSerializationError<Self::EngineError>> {
+        let payload = self.serialize(entity)?;
+        let header = ContainerHeader::new(
+            ContainerEntityKind::CleartextVector,
+            32,
+            [entity.cleartext_count().0 as u64, 0, 0, 0, 0],
+            &payload,
+        );
+        Ok(header.wrap(&payload))
+    }
+
+    unsafe fn serialize_container_unchecked(&mut self, entity: &CleartextVector32) -> Vec<u8> {
+        self.serialize_container(entity).unwrap()
+    }
+}
+
+/// # Description:
+/// Implementation of [`EntityContainerSerializationEngine`] for [`DefaultSerializationEngine`] that
+/// operates on 64 bits integers. It serializes in a container a cleartext vector entity.
+impl EntityContainerSerializationEngine<CleartextVector64, Vec<u8>> for DefaultSerializationEngine {
+    /// # Example:
+    /// ```
+    /// use concrete_core::prelude::{CleartextCount, *};
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// let input = vec![3_u64; 100];
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let cleartext_vector: CleartextVector64 = engine.create_cleartext_vector_from(&input)?;
+    /// let mut serialization_engine = DefaultSerializationEngine::new(())?;
+    /// let serialized = serialization_engine.serialize_container(&cleartext_vector)?;
+    /// let recovered = serialization_engine.deserialize_container(serialized.as_slice())?;
+    /// assert_eq!(cleartext_vector, recovered);
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn serialize_container(
+        &mut self,
+        entity: &CleartextVector64,
+    ) -> Result<Vec<u8>, EntityContainerSerializationError<Self::EngineError>> {
+        let payload = self.serialize(entity)?;
+        let header = ContainerHeader::new(
+            ContainerEntityKind::CleartextVector,
+            64,
+            [entity.cleartext_count().0 as u64, 0, 0, 0, 0],
+            &payload,
+        );
+        Ok(header.wrap(&payload))
+    }
+
+    unsafe fn serialize_container_unchecked(&mut self, entity: &CleartextVector64) -> Vec<u8> {
+        self.serialize_container(entity).unwrap()
+    }
+}
+
+/// # Description:
+/// Implementation of [`EntityContainerSerializationEngine`] for [`DefaultSerializationEngine`] that
+/// operates on 64 bits integers. It serializes in a container a floating point cleartext vector
+/// entity.
+impl EntityContainerSerializationEngine<CleartextVectorF64, Vec<u8>>
+    for DefaultSerializationEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_core::prelude::{CleartextCount, *};
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// let input = vec![3.0_f64; 100];
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let cleartext_vector: CleartextVectorF64 = engine.create_cleartext_vector_from(&input)?;
+    /// let mut serialization_engine = DefaultSerializationEngine::new(())?;
+    /// let serialized = serialization_engine.serialize_container(&cleartext_vector)?;
+    /// let recovered = serialization_engine.deserialize_container(serialized.as_slice())?;
+    /// assert_eq!(cleartext_vector, recovered);
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn serialize_container(
+        &mut self,
+        entity: &CleartextVectorF64,
+    ) -> Result<Vec<u8>, EntityContainerSerializationError<Self::EngineError>> {
+        let payload = self.serialize(entity)?;
+        let header = ContainerHeader::new(
+            ContainerEntityKind::CleartextVectorF64,
+            64,
+            [entity.cleartext_count().0 as u64, 0, 0, 0, 0],
+            &payload,
+        );
+        Ok(header.wrap(&payload))
+    }
+
+    unsafe fn serialize_container_unchecked(&mut self, entity: &CleartextVectorF64) -> Vec<u8> {
+        self.serialize_container(entity).unwrap()
+    }
+}
+
+/// # Description:
+/// Implementation of [`EntityContainerSerializationEngine`] for [`DefaultSerializationEngine`] that
+/// operates on 32 bits integers. It serializes in a container a GGSW ciphertext entity.
+impl EntityContainerSerializationEngine<GgswCiphertext32, Vec<u8>> for DefaultSerializationEngine {
+    /// # Example:
+    /// ```
+    /// use concrete_core::prelude::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, PolynomialSize, Variance, *,
+    /// };
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let glwe_dimension = GlweDimension(2);
+    /// let polynomial_size = PolynomialSize(4);
+    /// let level = DecompositionLevelCount(1);
+    /// let base_log = DecompositionBaseLog(4);
+    /// // Here a hard-set encoding is applied (shift by 20 bits)
+    /// let input = 3_u32 << 20;
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let key: GlweSecretKey32 =
+    ///     engine.generate_new_glwe_secret_key(glwe_dimension, polynomial_size)?;
+    /// let plaintext = engine.create_plaintext_from(&input)?;
+    ///
+    /// let ciphertext =
+    ///     engine.encrypt_scalar_ggsw_ciphertext(&key, &plaintext, noise, level, base_log)?;
+    ///
+    /// let mut serialization_engine = DefaultSerializationEngine::new(())?;
+    /// let serialized = serialization_engine.serialize_container(&ciphertext)?;
+    /// let recovered = serialization_engine.deserialize_container(serialized.as_slice())?;
+    /// assert_eq!(ciphertext, recovered);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn serialize_container(
+        &mut self,
+        entity: &GgswCiphertext32,
+    ) -> Result<Vec<u8>, EntityContainerSerializationError<Self::EngineError>> {
+        let payload = self.serialize(entity)?;
+        let header = ContainerHeader::new(
+            ContainerEntityKind::GgswCiphertext,
+            32,
+            [
+                entity.glwe_dimension().0 as u64,
+                entity.polynomial_size().0 as u64,
+                entity.decomposition_base_log().0 as u64,
+                entity.decomposition_level_count().0 as u64,
+                0,
+            ],
+            &payload,
+        );
+        Ok(header.wrap(&payload))
+    }
+
+    unsafe fn serialize_container_unchecked(&mut self, entity: &GgswCiphertext32) -> Vec<u8> {
+        self.serialize_container(entity).unwrap()
+    }
+}
+
+/// # Description:
+/// Implementation of [`EntityContainerSerializationEngine`] for [`DefaultSerializationEngine`] that
+/// operates on 64 bits integers. It serializes in a container a GGSW ciphertext entity.
+impl EntityContainerSerializationEngine<GgswCiphertext64, Vec<u8>> for DefaultSerializationEngine {
+    /// # Example:
+    /// ```
+    /// use concrete_core::prelude::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, PolynomialSize, Variance, *,
+    /// };
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let glwe_dimension = GlweDimension(2);
+    /// let polynomial_size = PolynomialSize(4);
+    /// let level = DecompositionLevelCount(1);
+    /// let base_log = DecompositionBaseLog(4);
+    /// // Here a hard-set encoding is applied (shift by 50 bits)
+    /// let input = 3_u64 << 50;
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let key: GlweSecretKey64 =
+    ///     engine.generate_new_glwe_secret_key(glwe_dimension, polynomial_size)?;
+    /// let plaintext = engine.create_plaintext_from(&input)?;
+    ///
+    /// let ciphertext =
+    ///     engine.encrypt_scalar_ggsw_ciphertext(&key, &plaintext, noise, level, base_log)?;
+    ///
+    /// let mut serialization_engine = DefaultSerializationEngine::new(())?;
+    /// let serialized = serialization_engine.serialize_container(&ciphertext)?;
+    /// let recovered = serialization_engine.deserialize_container(serialized.as_slice())?;
+    /// assert_eq!(ciphertext, recovered);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn serialize_container(
+        &mut self,
+        entity: &GgswCiphertext64,
+    ) -> Result<Vec<u8>, EntityContainerSerializationError<Self::EngineError>> {
+        let payload = self.serialize(entity)?;
+        let header = ContainerHeader::new(
+            ContainerEntityKind::GgswCiphertext,
+            64,
+            [
+                entity.glwe_dimension().0 as u64,
+                entity.polynomial_size().0 as u64,
+                entity.decomposition_base_log().0 as u64,
+                entity.decomposition_level_count().0 as u64,
+                0,
+            ],
+            &payload,
+        );
+        Ok(header.wrap(&payload))
+    }
+
+    unsafe fn serialize_container_unchecked(&mut self, entity: &GgswCiphertext64) -> Vec<u8> {
+        self.serialize_container(entity).unwrap()
+    }
+}
+
+/// # Description:
+/// Implementation of [`EntityContainerSerializationEngine`] for [`DefaultSerializationEngine`] that
+/// operates on 32 bits integers. It serializes in a container a seeded GGSW ciphertext entity.
+impl EntityContainerSerializationEngine<GgswSeededCiphertext32, Vec<u8>>
+    for DefaultSerializationEngine
+{
+    fn serialize_container(
+        &mut self,
+        entity: &GgswSeededCiphertext32,
+    ) -> Result<Vec<u8>, EntityContainerSerializationError<Self::EngineError>> {
+        let payload = self.serialize(entity)?;
+        let header = ContainerHeader::new(
+            ContainerEntityKind::GgswSeededCiphertext,
+            32,
+            [
+                entity.glwe_dimension().0 as u64,
+                entity.polynomial_size().0 as u64,
+                entity.decomposition_base_log().0 as u64,
+                entity.decomposition_level_count().0 as u64,
+                0,
+            ],
+            &payload,
+        );
+        Ok(header.wrap(&payload))
+    }
+
+    unsafe fn serialize_container_unchecked(&mut self, entity: &GgswSeededCiphertext32) -> Vec<u8> {
+        self.serialize_container(entity).unwrap()
+    }
+}
+
+/// # Description:
+/// Implementation of [`EntityContainerSerializationEngine`] for [`DefaultSerializationEngine`] that
+/// operates on 64 bits integers. It serializes in a container a seeded GGSW ciphertext entity.
+impl EntityContainerSerializationEngine<GgswSeededCiphertext64, Vec<u8>>
+    for DefaultSerializationEngine
+{
+    fn serialize_container(
+        &mut self,
+        entity: &GgswSeededCiphertext64,
+    ) -> Result<Vec<u8>, EntityContainerSerializationError<Self::EngineError>> {
+        let payload = self.serialize(entity)?;
+        let header = ContainerHeader::new(
+            ContainerEntityKind::GgswSeededCiphertext,
+            64,
+            [
+                entity.glwe_dimension().0 as u64,
+                entity.polynomial_size().0 as u64,
+                entity.decomposition_base_log().0 as u64,
+                entity.decomposition_level_count().0 as u64,
+                0,
+            ],
+            &payload,
+        );
+        Ok(header.wrap(&payload))
+    }
+
+    unsafe fn serialize_container_unchecked(&mut self, entity: &GgswSeededCiphertext64) -> Vec<u8> {
+        self.serialize_container(entity).unwrap()
+    }
+}
+
+/// # Description:
+/// Implementation of [`EntityContainerSerializationEngine`] for [`DefaultSerializationEngine`] that
+/// operates on 32 bits integers. It serializes in a container a GLWE ciphertext entity.
+impl EntityContainerSerializationEngine<GlweCiphertext32, Vec<u8>> for DefaultSerializationEngine {
+    /// # Example:
+    /// ```
+    /// use concrete_core::prelude::{GlweDimension, PolynomialSize, Variance, *};
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let glwe_dimension = GlweDimension(2);
+    /// let polynomial_size = PolynomialSize(4);
+    /// // There are always polynomial_size messages encrypted in the GLWE ciphertext
+    /// // Here a hard-set encoding is applied (shift by 20 bits)
+    /// let input = vec![3_u32 << 20; polynomial_size.0];
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let key: GlweSecretKey32 =
+    ///     engine.generate_new_glwe_secret_key(glwe_dimension, polynomial_size)?;
+    /// let plaintext_vector = engine.create_plaintext_vector_from(&input)?;
+    ///
+    /// let ciphertext = engine.encrypt_glwe_ciphertext(&key, &plaintext_vector, noise)?;
+    ///
+    /// let mut serialization_engine = DefaultSerializationEngine::new(())?;
+    /// let serialized = serialization_engine.serialize_container(&ciphertext)?;
+    /// let recovered = serialization_engine.deserialize_container(serialized.as_slice())?;
+    /// assert_eq!(ciphertext, recovered);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn serialize_container(
+        &mut self,
+        entity: &GlweCiphertext32,
+    ) -> Result<Vec<u8>, EntityContainerSerializationError<Self::EngineError>> {
+        let payload = self.serialize(entity)?;
+        let header = ContainerHeader::new(
+            ContainerEntityKind::GlweCiphertext,
+            32,
+            [
+                entity.glwe_dimension().0 as u64,
+                entity.polynomial_size().0 as u64,
+                0,
+                0,
+                0,
+            ],
+            &payload,
+        );
+        Ok(header.wrap(&payload))
+    }
+
+    unsafe fn serialize_container_unchecked(&mut self, entity: &GlweCiphertext32) -> Vec<u8> {
+        self.serialize_container(entity).unwrap()
+    }
+}
+
+/// # Description:
+/// Implementation of [`EntityContainerSerializationEngine`] for [`DefaultSerializationEngine`] that
+/// operates on 64 bits integers. It serializes in a container a GLWE ciphertext entity.
+impl EntityContainerSerializationEngine<GlweCiphertext64, Vec<u8>> for DefaultSerializationEngine {
+    /// # Example:
+    /// ```
+    /// use concrete_core::prelude::{GlweDimension, PolynomialSize, Variance, *};
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let glwe_dimension = GlweDimension(2);
+    /// let polynomial_size = PolynomialSize(4);
+    /// // There are always polynomial_size messages encrypted in the GLWE ciphertext
+    /// // Here a hard-set encoding is applied (shift by 50 bits)
+    /// let input = vec![3_u64 << 50; polynomial_size.0];
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let key: GlweSecretKey64 =
+    ///     engine.generate_new_glwe_secret_key(glwe_dimension, polynomial_size)?;
+    /// let plaintext_vector = engine.create_plaintext_vector_from(&input)?;
+    ///
+    /// let ciphertext = engine.encrypt_glwe_ciphertext(&key, &plaintext_vector, noise)?;
+    ///
+    /// let mut serialization_engine = DefaultSerializationEngine::new(())?;
+    /// let serialized = serialization_engine.serialize_container(&ciphertext)?;
+    /// let recovered = serialization_engine.deserialize_container(serialized.as_slice())?;
+    /// assert_eq!(ciphertext, recovered);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn serialize_container(
+        &mut self,
+        entity: &GlweCiphertext64,
+    ) -> Result<Vec<u8>, EntityContainerSerializationError<Self::EngineError>> {
+        let payload = self.serialize(entity)?;
+        let header = ContainerHeader::new(
+            ContainerEntityKind::GlweCiphertext,
+            64,
+            [
+                entity.glwe_dimension().0 as u64,
+                entity.polynomial_size().0 as u64,
+                0,
+                0,
+                0,
+            ],
+            &payload,
+        );
+        Ok(header.wrap(&payload))
+    }
+
+    unsafe fn serialize_container_unchecked(&mut self, entity: &GlweCiphertext64) -> Vec<u8> {
+        self.serialize_container(entity).unwrap()
+    }
+}
+
+/// # Description:
+/// Implementation of [`EntityContainerSerializationEngine`] for [`DefaultSerializationEngine`] that
+/// operates on 32 bits integers. It serializes in a container a GLWE ciphertext vector entity.
+impl EntityContainerSerializationEngine<GlweCiphertextVector32, Vec<u8>>
+    for DefaultSerializationEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_core::prelude::{GlweCiphertextCount, GlweDimension, PolynomialSize, Variance, *};
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let glwe_dimension = GlweDimension(2);
+    /// let polynomial_size = PolynomialSize(4);
+    /// let glwe_count = GlweCiphertextCount(2);
+    /// // Here a hard-set encoding is applied (shift by 20 bits)
+    /// let input =
+    ///     vec![3_u32 << 20; glwe_dimension.to_glwe_size().0 * polynomial_size.0 * glwe_count.0];
+    /// let noise = Variance(2_f64.powf(-50.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let key: GlweSecretKey32 =
+    ///     engine.generate_new_glwe_secret_key(glwe_dimension, polynomial_size)?;
+    /// let plaintext_vector = engine.create_plaintext_vector_from(&input)?;
+    ///
+    /// let ciphertext_vector =
+    ///     engine.encrypt_glwe_ciphertext_vector(&key, &plaintext_vector, noise)?;
+    ///
+    /// let mut serialization_engine = DefaultSerializationEngine::new(())?;
+    /// let serialized = serialization_engine.serialize_container(&ciphertext_vector)?;
+    /// let recovered = serialization_engine.deserialize_container(serialized.as_slice())?;
+    /// assert_eq!(ciphertext_vector, recovered);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn serialize_container(
+        &mut self,
+        entity: &GlweCiphertextVector32,
+    ) -> Result<Vec<u8>, EntityContainerSerializationError<Self::EngineError>> {
+        let payload = self.serialize(entity)?;
+        let header = ContainerHeader::new(
+            ContainerEntityKind::GlweCiphertextVector,
+            32,
+            [
+                entity.glwe_dimension().0 as u64,
+                entity.polynomial_size().0 as u64,
+                entity.glwe_ciphertext_count().0 as u64,
+                0,
+                0,
+            ],
+            &payload,
+        );
+        Ok(header.wrap(&payload))
+    }
+
+    unsafe fn serialize_container_unchecked(&mut self, entity: &GlweCiphertextVector32) -> Vec<u8> {
+        self.serialize_container(entity).unwrap()
+    }
+}
+
+/// # Description:
+/// Implementation of [`EntityContainerSerializationEngine`] for [`DefaultSerializationEngine`] that
+/// operates on 64 bits integers. It serializes in a container a GLWE ciphertext vector entity.
+impl EntityContainerSerializationEngine<GlweCiphertextVector64, Vec<u8>>
+    for DefaultSerializationEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_core::prelude::{GlweCiphertextCount, GlweDimension, PolynomialSize, Variance, *};
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let glwe_dimension = GlweDimension(2);
+    /// let polynomial_size = PolynomialSize(4);
+    /// let glwe_count = GlweCiphertextCount(2);
+    /// // Here a hard-set encoding is applied (shift by 50 bits)
+    /// let input =
+    ///     vec![3_u64 << 50; glwe_dimension.to_glwe_size().0 * polynomial_size.0 * glwe_count.0];
+    /// let noise = Variance(2_f64.powf(-50.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let key: GlweSecretKey64 =
+    ///     engine.generate_new_glwe_secret_key(glwe_dimension, polynomial_size)?;
+    /// let plaintext_vector = engine.create_plaintext_vector_from(&input)?;
+    ///
+    /// let ciphertext_vector =
+    ///     engine.encrypt_glwe_ciphertext_vector(&key, &plaintext_vector, noise)?;
+    ///
+    /// let mut serialization_engine = DefaultSerializationEngine::new(())?;
+    /// let serialized = serialization_engine.serialize_container(&ciphertext_vector)?;
+    /// let recovered = serialization_engine.deserialize_container(serialized.as_slice())?;
+    /// assert_eq!(ciphertext_vector, recovered);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn serialize_container(
+        &mut self,
+        entity: &GlweCiphertextVector64,
+    ) -> Result<Vec<u8>, EntityContainerSerializationError<Self::EngineError>> {
+        let payload = self.serialize(entity)?;
+        let header = ContainerHeader::new(
+            ContainerEntityKind::GlweCiphertextVector,
+            64,
+            [
+                entity.glwe_dimension().0 as u64,
+                entity.polynomial_size().0 as u64,
+                entity.glwe_ciphertext_count().0 as u64,
+                0,
+                0,
+            ],
+            &payload,
+        );
+        Ok(header.wrap(&payload))
+    }
+
+    unsafe fn serialize_container_unchecked(&mut self, entity: &GlweCiphertextVector64) -> Vec<u8> {
+        self.serialize_container(entity).unwrap()
+    }
+}
+
+/// # Description:
+/// Implementation of [`EntityContainerSerializationEngine`] for [`DefaultSerializationEngine`] that
+/// operates on 32 bits integers. It serializes in a container a GLWE secret key entity.
+impl EntityContainerSerializationEngine<GlweSecretKey32, Vec<u8>> for DefaultSerializationEngine {
+    /// # Example:
+    /// ```
+    /// use concrete_core::prelude::{GlweDimension, PolynomialSize, *};
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let glwe_dimension = GlweDimension(2);
+    /// let polynomial_size = PolynomialSize(4);
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let glwe_secret_key: GlweSecretKey32 =
+    ///     engine.generate_new_glwe_secret_key(glwe_dimension, polynomial_size)?;
+    ///
+    /// let mut serialization_engine = DefaultSerializationEngine::new(())?;
+    /// let serialized = serialization_engine.serialize_container(&glwe_secret_key)?;
+    /// let recovered = serialization_engine.deserialize_container(serialized.as_slice())?;
+    /// assert_eq!(glwe_secret_key, recovered);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn serialize_container(
+        &mut self,
+        entity: &GlweSecretKey32,
+    ) -> Result<Vec<u8>, EntityContainerSerializationError<Self::EngineError>> {
+        let payload = self.serialize(entity)?;
+        let header = ContainerHeader::new(
+            ContainerEntityKind::GlweSecretKey,
+            32,
+            [
+                entity.glwe_dimension().0 as u64,
+                entity.polynomial_size().0 as u64,
+                0,
+                0,
+                0,
+            ],
+            &payload,
+        );
+        Ok(header.wrap(&payload))
+    }
+
+    unsafe fn serialize_container_unchecked(&mut self, entity: &GlweSecretKey32) -> Vec<u8> {
+        self.serialize_container(entity).unwrap()
+    }
+}
+
+/// # Description:
+/// Implementation of [`EntityContainerSerializationEngine`] for [`DefaultSerializationEngine`] that
+/// operates on 64 bits integers. It serializes in a container a GLWE secret key entity.
+impl EntityContainerSerializationEngine<GlweSecretKey64, Vec<u8>> for DefaultSerializationEngine {
+    /// # Example:
+    /// ```
+    /// use concrete_core::prelude::{GlweDimension, PolynomialSize, *};
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let glwe_dimension = GlweDimension(2);
+    /// let polynomial_size = PolynomialSize(4);
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let glwe_secret_key: GlweSecretKey64 =
+    ///     engine.generate_new_glwe_secret_key(glwe_dimension, polynomial_size)?;
+    ///
+    /// let mut serialization_engine = DefaultSerializationEngine::new(())?;
+    /// let serialized = serialization_engine.serialize_container(&glwe_secret_key)?;
+    /// let recovered = serialization_engine.deserialize_container(serialized.as_slice())?;
+    /// assert_eq!(glwe_secret_key, recovered);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn serialize_container(
+        &mut self,
+        entity: &GlweSecretKey64,
+    ) -> Result<Vec<u8>, EntityContainerSerializationError<Self::EngineError>> {
+        let payload = self.serialize(entity)?;
+        let header = ContainerHeader::new(
+            ContainerEntityKind::GlweSecretKey,
+            64,
+            [
+                entity.glwe_dimension().0 as u64,
+                entity.polynomial_size().0 as u64,
+                0,
+                0,
+                0,
+            ],
+            &payload,
+        );
+        Ok(header.wrap(&payload))
+    }
+
+    unsafe fn serialize_container_unchecked(&mut self, entity: &GlweSecretKey64) -> Vec<u8> {
+        self.serialize_container(entity).unwrap()
+    }
+}
+
+/// # Description:
+/// Implementation of [`EntityContainerSerializationEngine`] for [`DefaultSerializationEngine`] that
+/// operates on 32 bits integers. It serializes in a container a seeded GLWE ciphertext entity.
+impl EntityContainerSerializationEngine<GlweSeededCiphertext32, Vec<u8>>
+    for DefaultSerializationEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_core::prelude::{GlweDimension, PolynomialSize, Variance, *};
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let glwe_dimension = GlweDimension(2);
+    /// let polynomial_size = PolynomialSize(4);
+    /// // There are always polynomial_size messages encrypted in the GLWE ciphertext
+    /// // Here a hard-set encoding is applied (shift by 20 bits)
+    /// let input = vec![3_u32 << 20; polynomial_size.0];
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let key: GlweSecretKey32 =
+    ///     engine.generate_new_glwe_secret_key(glwe_dimension, polynomial_size)?;
+    /// let plaintext_vector = engine.create_plaintext_vector_from(&input)?;
+    ///
+    /// let seeded_ciphertext =
+    ///     engine.encrypt_glwe_seeded_ciphertext(&key, &plaintext_vector, noise)?;
+    ///
+    /// let mut serialization_engine = DefaultSerializationEngine::new(())?;
+    /// let serialized = serialization_engine.serialize_container(&seeded_ciphertext)?;
+    /// let recovered = serialization_engine.deserialize_container(serialized.as_slice())?;
+    /// assert_eq!(seeded_ciphertext, recovered);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn serialize_container(
+        &mut self,
+        entity: &GlweSeededCiphertext32,
+    ) -> Result<Vec<u8>, EntityContainerSerializationError<Self::EngineError>> {
+        let payload = self.serialize(entity)?;
+        let header = ContainerHeader::new(
+            ContainerEntityKind::GlweSeededCiphertext,
+            32,
+            [
+                entity.glwe_dimension().0 as u64,
+                entity.polynomial_size().0 as u64,
+                0,
+                0,
+                0,
+            ],
+            &payload,
+        );
+        Ok(header.wrap(&payload))
+    }
+
+    unsafe fn serialize_container_unchecked(&mut self, entity: &GlweSeededCiphertext32) -> Vec<u8> {
+        self.serialize_container(entity).unwrap()
+    }
+}
+
+/// # Description:
+/// Implementation of [`EntityContainerSerializationEngine`] for [`DefaultSerializationEngine`] that
+/// operates on 64 bits integers. It serializes in a container a seeded GLWE ciphertext entity.
+impl EntityContainerSerializationEngine<GlweSeededCiphertext64, Vec<u8>>
+    for DefaultSerializationEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_core::prelude::{GlweDimension, PolynomialSize, Variance, *};
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let glwe_dimension = GlweDimension(2);
+    /// let polynomial_size = PolynomialSize(4);
+    /// // There are always polynomial_size messages encrypted in the GLWE ciphertext
+    /// // Here a hard-set encoding is applied (shift by 50 bits)
+    /// let input = vec![3_u64 << 50; polynomial_size.0];
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let key: GlweSecretKey64 =
+    ///     engine.generate_new_glwe_secret_key(glwe_dimension, polynomial_size)?;
+    /// let plaintext_vector = engine.create_plaintext_vector_from(&input)?;
+    ///
+    /// let seeded_ciphertext =
+    ///     engine.encrypt_glwe_seeded_ciphertext(&key, &plaintext_vector, noise)?;
+    ///
+    /// let mut serialization_engine = DefaultSerializationEngine::new(())?;
+    /// let serialized = serialization_engine.serialize_container(&seeded_ciphertext)?;
+    /// let recovered = serialization_engine.deserialize_container(serialized.as_slice())?;
+    /// assert_eq!(seeded_ciphertext, recovered);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn serialize_container(
+        &mut self,
+        entity: &GlweSeededCiphertext64,
+    ) -> Result<Vec<u8>, EntityContainerSerializationError<Self::EngineError>> {
+        let payload = self.serialize(entity)?;
+        let header = ContainerHeader::new(
+            ContainerEntityKind::GlweSeededCiphertext,
+            64,
+            [
+                entity.glwe_dimension().0 as u64,
+                entity.polynomial_size().0 as u64,
+                0,
+                0,
+                0,
+            ],
+            &payload,
+        );
+        Ok(header.wrap(&payload))
+    }
+
+    unsafe fn serialize_container_unchecked(&mut self, entity: &GlweSeededCiphertext64) -> Vec<u8> {
+        self.serialize_container(entity).unwrap()
+    }
+}
+
+/// # Description:
+/// Implementation of [`EntityContainerSerializationEngine`] for [`DefaultSerializationEngine`] that
+/// operates on 32 bits integers. It serializes in a container a compressed GLWE ciphertext entity.
+impl EntityContainerSerializationEngine<GlweCompressedCiphertext32, Vec<u8>>
+    for DefaultSerializationEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_core::prelude::{GlweDimension, PolynomialSize, Variance, *};
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let glwe_dimension = GlweDimension(2);
+    /// let polynomial_size = PolynomialSize(4);
+    /// // There are always polynomial_size messages encrypted in the GLWE ciphertext
+    /// // Here a hard-set encoding is applied (shift by 20 bits)
+    /// let input = vec![3_u32 << 20; polynomial_size.0];
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let key: GlweSecretKey32 =
+    ///     engine.generate_new_glwe_secret_key(glwe_dimension, polynomial_size)?;
+    /// let plaintext_vector = engine.create_plaintext_vector_from(&input)?;
+    ///
+    /// let ciphertext = engine.encrypt_glwe_ciphertext(&key, &plaintext_vector, noise)?;
+    /// let compressed_ciphertext =
+    ///     engine.compress_glwe_ciphertext(&ciphertext, CiphertextModulusLog(16))?;
+    ///
+    /// let mut serialization_engine = DefaultSerializationEngine::new(())?;
+    /// let serialized = serialization_engine.serialize_container(&compressed_ciphertext)?;
+    /// let recovered = serialization_engine.deserialize_container(serialized.as_slice())?;
+    /// assert_eq!(compressed_ciphertext, recovered);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn serialize_container(
+        &mut self,
+        entity: &GlweCompressedCiphertext32,
+    ) -> Result<Vec<u8>, EntityContainerSerializationError<Self::EngineError>> {
+        let payload = self.serialize(entity)?;
+        let header = ContainerHeader::new(
+            ContainerEntityKind::GlweCompressedCiphertext,
+            32,
+            [
+                entity.glwe_dimension().0 as u64,
+                entity.polynomial_size().0 as u64,
+                entity.ciphertext_modulus_log().0 as u64,
+                0,
+                0,
+            ],
+            &payload,
+        );
+        Ok(header.wrap(&payload))
+    }
+
+    unsafe fn serialize_container_unchecked(
+        &mut self,
+        entity: &GlweCompressedCiphertext32,
+    ) -> Vec<u8> {
+        self.serialize_container(entity).unwrap()
+    }
+}
+
+/// # Description:
+/// Implementation of [`EntityContainerSerializationEngine`] for [`DefaultSerializationEngine`] that
+/// operates on 64 bits integers. It serializes in a container a compressed GLWE ciphertext entity.
+impl EntityContainerSerializationEngine<GlweCompressedCiphertext64, Vec<u8>>
+    for DefaultSerializationEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_core::prelude::{GlweDimension, PolynomialSize, Variance, *};
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let glwe_dimension = GlweDimension(2);
+    /// let polynomial_size = PolynomialSize(4);
+    /// // There are always polynomial_size messages encrypted in the GLWE ciphertext
+    /// // Here a hard-set encoding is applied (shift by 50 bits)
+    /// let input = vec![3_u64 << 50; polynomial_size.0];
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let key: GlweSecretKey64 =
+    ///     engine.generate_new_glwe_secret_key(glwe_dimension, polynomial_size)?;
+    /// let plaintext_vector = engine.create_plaintext_vector_from(&input)?;
+    ///
+    /// let ciphertext = engine.encrypt_glwe_ciphertext(&key, &plaintext_vector, noise)?;
+    /// let compressed_ciphertext =
+    ///     engine.compress_glwe_ciphertext(&ciphertext, CiphertextModulusLog(16))?;
+    ///
+    /// let mut serialization_engine = DefaultSerializationEngine::new(())?;
+    /// let serialized = serialization_engine.serialize_container(&compressed_ciphertext)?;
+    /// let recovered = serialization_engine.deserialize_container(serialized.as_slice())?;
+    /// assert_eq!(compressed_ciphertext, recovered);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn serialize_container(
+        &mut self,
+        entity: &GlweCompressedCiphertext64,
+    ) -> Result<Vec<u8>, EntityContainerSerializationError<Self::EngineError>> {
+        let payload = self.serialize(entity)?;
+        let header = ContainerHeader::new(
+            ContainerEntityKind::GlweCompressedCiphertext,
+            64,
+            [
+                entity.glwe_dimension().0 as u64,
+                entity.polynomial_size().0 as u64,
+                entity.ciphertext_modulus_log().0 as u64,
+                0,
+                0,
+            ],
+            &payload,
+        );
+        Ok(header.wrap(&payload))
+    }
+
+    unsafe fn serialize_container_unchecked(
+        &mut self,
+        entity: &GlweCompressedCiphertext64,
+    ) -> Vec<u8> {
+        self.serialize_container(entity).unwrap()
+    }
+}
+
+/// # Description:
+/// Implementation of [`EntityContainerSerializationEngine`] for [`DefaultSerializationEngine`] that
+/// operates on 32 bits integers. It serializes in a container a seeded GLWE ciphertext vector
+/// entity.
+impl EntityContainerSerializationEngine<GlweSeededCiphertextVector32, Vec<u8>>
+    for DefaultSerializationEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_core::prelude::{GlweCiphertextCount, GlweDimension, PolynomialSize, Variance, *};
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let glwe_dimension = GlweDimension(2);
+    /// let polynomial_size = PolynomialSize(4);
+    /// // Here a hard-set encoding is applied (shift by 20 bits)
+    /// let input = vec![3_u32 << 20; 8];
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let key: GlweSecretKey32 =
+    ///     engine.generate_new_glwe_secret_key(glwe_dimension, polynomial_size)?;
+    /// let plaintext_vector = engine.create_plaintext_vector_from(&input)?;
+    ///
+    /// let seeded_ciphertext_vector =
+    ///     engine.encrypt_glwe_seeded_ciphertext_vector(&key, &plaintext_vector, noise)?;
+    ///
+    /// let mut serialization_engine = DefaultSerializationEngine::new(())?;
+    /// let serialized = serialization_engine.serialize_container(&seeded_ciphertext_vector)?;
+    /// let recovered = serialization_engine.deserialize_container(serialized.as_slice())?;
+    /// assert_eq!(seeded_ciphertext_vector, recovered);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn serialize_container(
+        &mut self,
+        entity: &GlweSeededCiphertextVector32,
+    ) -> Result<Vec<u8>, EntityContainerSerializationError<Self::EngineError>> {
+        let payload = self.serialize(entity)?;
+        let header = ContainerHeader::new(
+            ContainerEntityKind::GlweSeededCiphertextVector,
+            32,
+            [
+                entity.glwe_dimension().0 as u64,
+                entity.polynomial_size().0 as u64,
+                entity.glwe_ciphertext_count().0 as u64,
+                0,
+                0,
+            ],
+            &payload,
+        );
+        Ok(header.wrap(&payload))
+    }
+
+    unsafe fn serialize_container_unchecked(
+        &mut self,
+        entity: &GlweSeededCiphertextVector32,
+    ) -> Vec<u8> {
+        self.serialize_container(entity).unwrap()
+    }
+}
+
+/// # Description:
+/// Implementation of [`EntityContainerSerializationEngine`] for [`DefaultSerializationEngine`] that
+/// operates on 64 bits integers. It serializes in a container a seeded GLWE ciphertext vector
+/// entity.
+impl EntityContainerSerializationEngine<GlweSeededCiphertextVector64, Vec<u8>>
+    for DefaultSerializationEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_core::prelude::{GlweCiphertextCount, GlweDimension, PolynomialSize, Variance, *};
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let glwe_dimension = GlweDimension(2);
+    /// let polynomial_size = PolynomialSize(4);
+    /// // Here a hard-set encoding is applied (shift by 50 bits)
+    /// let input = vec![3_u64 << 50; 8];
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let key: GlweSecretKey64 =
+    ///     engine.generate_new_glwe_secret_key(glwe_dimension, polynomial_size)?;
+    /// let plaintext_vector = engine.create_plaintext_vector_from(&input)?;
+    ///
+    /// let seeded_ciphertext_vector =
+    ///     engine.encrypt_glwe_seeded_ciphertext_vector(&key, &plaintext_vector, noise)?;
+    ///
+    /// let mut serialization_engine = DefaultSerializationEngine::new(())?;
+    /// let serialized = serialization_engine.serialize_container(&seeded_ciphertext_vector)?;
+    /// let recovered = serialization_engine.deserialize_container(serialized.as_slice())?;
+    /// assert_eq!(seeded_ciphertext_vector, recovered);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn serialize_container(
+        &mut self,
+        entity: &GlweSeededCiphertextVector64,
+    ) -> Result<Vec<u8>, EntityContainerSerializationError<Self::EngineError>> {
+        let payload = self.serialize(entity)?;
+        let header = ContainerHeader::new(
+            ContainerEntityKind::GlweSeededCiphertextVector,
+            64,
+            [
+                entity.glwe_dimension().0 as u64,
+                entity.polynomial_size().0 as u64,
+                entity.glwe_ciphertext_count().0 as u64,
+                0,
+                0,
+            ],
+            &payload,
+        );
+        Ok(header.wrap(&payload))
+    }
+
+    unsafe fn serialize_container_unchecked(
+        &mut self,
+        entity: &GlweSeededCiphertextVector64,
+    ) -> Vec<u8> {
+        self.serialize_container(entity).unwrap()
+    }
+}
+
+/// # Description:
+/// Implementation of [`EntityContainerSerializationEngine`] for [`DefaultSerializationEngine`] that
+/// operates on 32 bits integers. It serializes in a container a LWE bootstrap key entity.
+impl EntityContainerSerializationEngine<LweBootstrapKey32, Vec<u8>> for DefaultSerializationEngine {
+    /// # Example
+    /// ```
+    /// use concrete_core::prelude::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, LweDimension, PolynomialSize,
+    ///     Variance, *,
+    /// };
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let (lwe_dim, glwe_dim, poly_size) = (LweDimension(4), GlweDimension(6), PolynomialSize(256));
+    /// let (dec_lc, dec_bl) = (DecompositionLevelCount(3), DecompositionBaseLog(5));
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let lwe_sk: LweSecretKey32 = engine.generate_new_lwe_secret_key(lwe_dim)?;
+    /// let glwe_sk: GlweSecretKey32 = engine.generate_new_glwe_secret_key(glwe_dim, poly_size)?;
+    ///
+    /// let bsk: LweBootstrapKey32 =
+    ///     engine.generate_new_lwe_bootstrap_key(&lwe_sk, &glwe_sk, dec_bl, dec_lc, noise)?;
+    ///
+    /// let mut serialization_engine = DefaultSerializationEngine::new(())?;
+    /// let serialized = serialization_engine.serialize_container(&bsk)?;
+    /// let recovered = serialization_engine.deserialize_container(serialized.as_slice())?;
+    /// assert_eq!(bsk, recovered);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn serialize_container(
+        &mut self,
+        entity: &LweBootstrapKey32,
+    ) -> Result<Vec<u8>, EntityContainerSerializationError<Self::EngineError>> {
+        let payload = self.serialize(entity)?;
+        let header = ContainerHeader::new(
+            ContainerEntityKind::LweBootstrapKey,
+            32,
+            [
+                entity.input_lwe_dimension().0 as u64,
+                entity.glwe_dimension().0 as u64,
+                entity.polynomial_size().0 as u64,
+                entity.decomposition_base_log().0 as u64,
+                entity.decomposition_level_count().0 as u64,
+            ],
+            &payload,
+        );
+        Ok(header.wrap(&payload))
+    }
+
+    unsafe fn serialize_container_unchecked(&mut self, entity: &LweBootstrapKey32) -> Vec<u8> {
+        self.serialize_container(entity).unwrap()
+    }
+}
+
+/// # Description:
+/// Implementation of [`EntityContainerSerializationEngine`] for [`DefaultSerializationEngine`] that
+/// operates on 64 bits integers. It serializes in a container a LWE bootstrap key entity.
+impl EntityContainerSerializationEngine<LweBootstrapKey64, Vec<u8>> for DefaultSerializationEngine {
+    /// # Example
+    /// ```
+    /// use concrete_core::prelude::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, LweDimension, PolynomialSize,
+    ///     Variance, *,
+    /// };
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let (lwe_dim, glwe_dim, poly_size) = (LweDimension(4), GlweDimension(6), PolynomialSize(256));
+    /// let (dec_lc, dec_bl) = (DecompositionLevelCount(3), DecompositionBaseLog(5));
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let lwe_sk: LweSecretKey64 = engine.generate_new_lwe_secret_key(lwe_dim)?;
+    /// let glwe_sk: GlweSecretKey64 = engine.generate_new_glwe_secret_key(glwe_dim, poly_size)?;
+    ///
+    /// let bsk: LweBootstrapKey64 =
+    ///     engine.generate_new_lwe_bootstrap_key(&lwe_sk, &glwe_sk, dec_bl, dec_lc, noise)?;
+    ///
+    /// let mut serialization_engine = DefaultSerializationEngine::new(())?;
+    /// let serialized = serialization_engine.serialize_container(&bsk)?;
+    /// let recovered = serialization_engine.deserialize_container(serialized.as_slice())?;
+    /// assert_eq!(bsk, recovered);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn serialize_container(
+        &mut self,
+        entity: &LweBootstrapKey64,
+    ) -> Result<Vec<u8>, EntityContainerSerializationError<Self::EngineError>> {
+        let payload = self.serialize(entity)?;
+        let header = ContainerHeader::new(
+            ContainerEntityKind::LweBootstrapKey,
+            64,
+            [
+                entity.input_lwe_dimension().0 as u64,
+                entity.glwe_dimension().0 as u64,
+                entity.polynomial_size().0 as u64,
+                entity.decomposition_base_log().0 as u64,
+                entity.decomposition_level_count().0 as u64,
+            ],
+            &payload,
+        );
+        Ok(header.wrap(&payload))
+    }
+
+    unsafe fn serialize_container_unchecked(&mut self, entity: &LweBootstrapKey64) -> Vec<u8> {
+        self.serialize_container(entity).unwrap()
+    }
+}
+
+/// # Description:
+/// Implementation of [`EntityContainerSerializationEngine`] for [`DefaultSerializationEngine`] that
+/// operates on 32 bits integers. It serializes in a container a LWE ciphertext entity.
+impl EntityContainerSerializationEngine<LweCiphertext32, Vec<u8>> for DefaultSerializationEngine {
+    /// # Example:
+    /// ```
+    /// use concrete_core::prelude::{LweDimension, Variance, *};
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let lwe_dimension = LweDimension(2);
+    /// // Here a hard-set encoding is applied (shift by 20 bits)
+    /// let input = 3_u32 << 20;
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let key: LweSecretKey32 = engine.generate_new_lwe_secret_key(lwe_dimension)?;
+    /// let plaintext = engine.create_plaintext_from(&input)?;
+    ///
+    /// let ciphertext = engine.encrypt_lwe_ciphertext(&key, &plaintext, noise)?;
+    ///
+    /// let mut serialization_engine = DefaultSerializationEngine::new(())?;
+    /// let serialized = serialization_engine.serialize_container(&ciphertext)?;
+    /// let recovered = serialization_engine.deserialize_container(serialized.as_slice())?;
+    /// assert_eq!(ciphertext, recovered);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn serialize_container(
+        &mut self,
+        entity: &LweCiphertext32,
+    ) -> Result<Vec<u8>, EntityContainerSerializationError<Self::EngineError>> {
+        let payload = self.serialize(entity)?;
+        let header = ContainerHeader::new(
+            ContainerEntityKind::LweCiphertext,
+            32,
+            [entity.lwe_dimension().0 as u64, 0, 0, 0, 0],
+            &payload,
+        );
+        Ok(header.wrap(&payload))
+    }
+
+    unsafe fn serialize_container_unchecked(&mut self, entity: &LweCiphertext32) -> Vec<u8> {
+        self.serialize_container(entity).unwrap()
+    }
+}
+
+/// # Description:
+/// Implementation of [`EntityContainerSerializationEngine`] for [`DefaultSerializationEngine`] that
+/// operates on 64 bits integers. It serializes in a container a LWE ciphertext entity.
+impl EntityContainerSerializationEngine<LweCiphertext64, Vec<u8>> for DefaultSerializationEngine {
+    /// # Example:
+    /// ```
+    /// use concrete_core::prelude::{LweDimension, Variance, *};
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let lwe_dimension = LweDimension(2);
+    /// // Here a hard-set encoding is applied (shift by 50 bits)
+    /// let input = 3_u64 << 50;
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let key: LweSecretKey64 = engine.generate_new_lwe_secret_key(lwe_dimension)?;
+    /// let plaintext = engine.create_plaintext_from(&input)?;
+    ///
+    /// let ciphertext = engine.encrypt_lwe_ciphertext(&key, &plaintext, noise)?;
+    ///
+    /// let mut serialization_engine = DefaultSerializationEngine::new(())?;
+    /// let serialized = serialization_engine.serialize_container(&ciphertext)?;
+    /// let recovered = serialization_engine.deserialize_container(serialized.as_slice())?;
+    /// assert_eq!(ciphertext, recovered);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn serialize_container(
+        &mut self,
+        entity: &LweCiphertext64,
+    ) -> Result<Vec<u8>, EntityContainerSerializationError<Self::EngineError>> {
+        let payload = self.serialize(entity)?;
+        let header = ContainerHeader::new(
+            ContainerEntityKind::LweCiphertext,
+            64,
+            [entity.lwe_dimension().0 as u64, 0, 0, 0, 0],
+            &payload,
+        );
+        Ok(header.wrap(&payload))
+    }
+
+    unsafe fn serialize_container_unchecked(&mut self, entity: &LweCiphertext64) -> Vec<u8> {
+        self.serialize_container(entity).unwrap()
+    }
+}
+
+/// # Description:
+/// Implementation of [`EntityContainerSerializationEngine`] for [`DefaultSerializationEngine`] that
+/// operates on 32 bits integers. It serializes in a container an LWE circuit bootstrap private
+/// functional packing keyswitch vector.
+impl
+    EntityContainerSerializationEngine<
+        LweCircuitBootstrapPrivateFunctionalPackingKeyswitchKeys32,
+        Vec<u8>,
+    > for DefaultSerializationEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_core::prelude::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, FunctionalPackingKeyswitchKeyCount,
+    ///     GlweDimension, LweDimension, Variance, *,
+    /// };
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let input_lwe_dimension = LweDimension(10);
+    /// let output_glwe_dimension = GlweDimension(3);
+    /// let polynomial_size = PolynomialSize(256);
+    /// let decomposition_base_log = DecompositionBaseLog(3);
+    /// let decomposition_level_count = DecompositionLevelCount(5);
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let input_key: LweSecretKey32 = engine.generate_new_lwe_secret_key(input_lwe_dimension)?;
+    /// let output_key: GlweSecretKey32 =
+    ///     engine.generate_new_glwe_secret_key(output_glwe_dimension, polynomial_size)?;
+    ///
+    /// let cbs_private_functional_packing_keyswitch_key:
+    ///     LweCircuitBootstrapPrivateFunctionalPackingKeyswitchKeys32 =
+    ///     engine
+    ///     .generate_new_lwe_circuit_bootstrap_private_functional_packing_keyswitch_keys(
+    ///         &input_key,
+    ///         &output_key,
+    ///         decomposition_base_log,
+    ///         decomposition_level_count,
+    ///         noise,
+    /// )?;
+    ///
+    /// let mut serialization_engine = DefaultSerializationEngine::new(())?;
+    /// let serialized =
+    ///     serialization_engine.serialize_container(&cbs_private_functional_packing_keyswitch_key)?;
+    /// let recovered = serialization_engine.deserialize_container(serialized.as_slice())?;
+    /// assert_eq!(cbs_private_functional_packing_keyswitch_key, recovered);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn serialize_container(
+        &mut self,
+        entity: &LweCircuitBootstrapPrivateFunctionalPackingKeyswitchKeys32,
+    ) -> Result<Vec<u8>, EntityContainerSerializationError<Self::EngineError>> {
+        let payload = self.serialize(entity)?;
+        let header = ContainerHeader::new(
+            ContainerEntityKind::LweCircuitBootstrapPrivateFunctionalPackingKeyswitchKeys,
+            32,
+            [
+                entity.input_lwe_dimension().0 as u64,
+                entity.output_glwe_dimension().0 as u64,
+                entity.output_polynomial_size().0 as u64,
+                entity.decomposition_base_log().0 as u64,
+                entity.decomposition_level_count().0 as u64,
+            ],
+            &payload,
+        );
+        Ok(header.wrap(&payload))
+    }
+
+    unsafe fn serialize_container_unchecked(
+        &mut self,
+        entity: &LweCircuitBootstrapPrivateFunctionalPackingKeyswitchKeys32,
+    ) -> Vec<u8> {
+        self.serialize_container(entity).unwrap()
+    }
+}
+
+/// # Description:
+/// Implementation of [`EntityContainerSerializationEngine`] for [`DefaultSerializationEngine`] that
+/// operates on 64 bits integers. It serializes in a container an LWE circuit bootstrap private
+/// functional packing keyswitch vector.
+impl
+    EntityContainerSerializationEngine<
+        LweCircuitBootstrapPrivateFunctionalPackingKeyswitchKeys64,
+        Vec<u8>,
+    > for DefaultSerializationEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_core::prelude::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, FunctionalPackingKeyswitchKeyCount,
+    ///     GlweDimension, LweDimension, Variance, *,
+    /// };
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let input_lwe_dimension = LweDimension(10);
+    /// let output_glwe_dimension = GlweDimension(3);
+    /// let polynomial_size = PolynomialSize(256);
+    /// let decomposition_base_log = DecompositionBaseLog(3);
+    /// let decomposition_level_count = DecompositionLevelCount(5);
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let input_key: LweSecretKey64 = engine.generate_new_lwe_secret_key(input_lwe_dimension)?;
+    /// let output_key: GlweSecretKey64 =
+    ///     engine.generate_new_glwe_secret_key(output_glwe_dimension, polynomial_size)?;
+    ///
+    /// let cbs_private_functional_packing_keyswitch_key:
+    ///     LweCircuitBootstrapPrivateFunctionalPackingKeyswitchKeys64 =
+    ///     engine
+    ///     .generate_new_lwe_circuit_bootstrap_private_functional_packing_keyswitch_keys(
+    ///         &input_key,
+    ///         &output_key,
+    ///         decomposition_base_log,
+    ///         decomposition_level_count,
+    ///         noise,
+    /// )?;
+    ///
+    /// let mut serialization_engine = DefaultSerializationEngine::new(())?;
+    /// let serialized =
+    ///     serialization_engine.serialize_container(&cbs_private_functional_packing_keyswitch_key)?;
+    /// let recovered = serialization_engine.deserialize_container(serialized.as_slice())?;
+    /// assert_eq!(cbs_private_functional_packing_keyswitch_key, recovered);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn serialize_container(
+        &mut self,
+        entity: &LweCircuitBootstrapPrivateFunctionalPackingKeyswitchKeys64,
+    ) -> Result<Vec<u8>, EntityContainerSerializationError<Self::EngineError>> {
+        let payload = self.serialize(entity)?;
+        let header = ContainerHeader::new(
+            ContainerEntityKind::LweCircuitBootstrapPrivateFunctionalPackingKeyswitchKeys,
+            64,
+            [
+                entity.input_lwe_dimension().0 as u64,
+                entity.output_glwe_dimension().0 as u64,
+                entity.output_polynomial_size().0 as u64,
+                entity.decomposition_base_log().0 as u64,
+                entity.decomposition_level_count().0 as u64,
+            ],
+            &payload,
+        );
+        Ok(header.wrap(&payload))
+    }
+
+    unsafe fn serialize_container_unchecked(
+        &mut self,
+        entity: &LweCircuitBootstrapPrivateFunctionalPackingKeyswitchKeys64,
+    ) -> Vec<u8> {
+        self.serialize_container(entity).unwrap()
+    }
+}
+
+/// # Description:
+/// Implementation of [`EntityContainerSerializationEngine`] for [`DefaultSerializationEngine`] that
+/// operates on 32 bits integers. It serializes in a container a LWE ciphertext vector entity.
+impl EntityContainerSerializationEngine<LweCiphertextVector32, Vec<u8>>
+    for DefaultSerializationEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_core::prelude::{LweCiphertextCount, LweDimension, Variance, *};
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let lwe_dimension = LweDimension(6);
+    /// // Here a hard-set encoding is applied (shift by 20 bits)
+    /// let input = vec![3_u32 << 20; 3];
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let key: LweSecretKey32 = engine.generate_new_lwe_secret_key(lwe_dimension)?;
+    /// let plaintext_vector: PlaintextVector32 = engine.create_plaintext_vector_from(&input)?;
+    ///
+    /// let mut ciphertext_vector: LweCiphertextVector32 =
+    ///     engine.encrypt_lwe_ciphertext_vector(&key, &plaintext_vector, noise)?;
+    ///
+    /// let mut serialization_engine = DefaultSerializationEngine::new(())?;
+    /// let serialized = serialization_engine.serialize_container(&ciphertext_vector)?;
+    /// let recovered = serialization_engine.deserialize_container(serialized.as_slice())?;
+    /// assert_eq!(ciphertext_vector, recovered);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn serialize_container(
+        &mut self,
+        entity: &LweCiphertextVector32,
+    ) -> Result<Vec<u8>, EntityContainerSerializationError<Self::EngineError>> {
+        let payload = self.serialize(entity)?;
+        let header = ContainerHeader::new(
+            ContainerEntityKind::LweCiphertextVector,
+            32,
+            [
+                entity.lwe_dimension().0 as u64,
+                entity.lwe_ciphertext_count().0 as u64,
+                0,
+                0,
+                0,
+            ],
+            &payload,
+        );
+        Ok(header.wrap(&payload))
+    }
+
+    unsafe fn serialize_container_unchecked(&mut self, entity: &LweCiphertextVector32) -> Vec<u8> {
+        self.serialize_container(entity).unwrap()
+    }
+}
+
+/// # Description:
+/// Implementation of [`EntityContainerSerializationEngine`] for [`DefaultSerializationEngine`] that
+/// operates on 64 bits integers. It serializes in a container a LWE ciphertext vector entity.
+impl EntityContainerSerializationEngine<LweCiphertextVector64, Vec<u8>>
+    for DefaultSerializationEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_core::prelude::{LweCiphertextCount, LweDimension, Variance, *};
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let lwe_dimension = LweDimension(6);
+    /// // Here a hard-set encoding is applied (shift by 50 bits)
+    /// let input = vec![3_u64 << 50; 3];
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let key: LweSecretKey64 = engine.generate_new_lwe_secret_key(lwe_dimension)?;
+    /// let plaintext_vector: PlaintextVector64 = engine.create_plaintext_vector_from(&input)?;
+    ///
+    /// let mut ciphertext_vector: LweCiphertextVector64 =
+    ///     engine.encrypt_lwe_ciphertext_vector(&key, &plaintext_vector, noise)?;
+    ///
+    /// let mut serialization_engine = DefaultSerializationEngine::new(())?;
+    /// let serialized = serialization_engine.serialize_container(&ciphertext_vector)?;
+    /// let recovered = serialization_engine.deserialize_container(serialized.as_slice())?;
+    /// assert_eq!(ciphertext_vector, recovered);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn serialize_container(
+        &mut self,
+        entity: &LweCiphertextVector64,
+    ) -> Result<Vec<u8>, EntityContainerSerializationError<Self::EngineError>> {
+        let payload = self.serialize(entity)?;
+        let header = ContainerHeader::new(
+            ContainerEntityKind::LweCiphertextVector,
+            64,
+            [
+                entity.lwe_dimension().0 as u64,
+                entity.lwe_ciphertext_count().0 as u64,
+                0,
+                0,
+                0,
+            ],
+            &payload,
+        );
+        Ok(header.wrap(&payload))
+    }
+
+    unsafe fn serialize_container_unchecked(&mut self, entity: &LweCiphertextVector64) -> Vec<u8> {
+        self.serialize_container(entity).unwrap()
+    }
+}
+
+/// # Description:
+/// Implementation of [`EntityContainerSerializationEngine`] for [`DefaultSerializationEngine`] that
+/// operates on 32 bits integers. It serializes in a container a LWE keyswitch key entity.
+impl EntityContainerSerializationEngine<LweKeyswitchKey32, Vec<u8>> for DefaultSerializationEngine {
+    /// # Example:
+    /// ```
+    /// use concrete_core::prelude::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, LweDimension, Variance, *,
+    /// };
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let input_lwe_dimension = LweDimension(6);
+    /// let output_lwe_dimension = LweDimension(3);
+    /// let decomposition_level_count = DecompositionLevelCount(2);
+    /// let decomposition_base_log = DecompositionBaseLog(8);
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let input_key: LweSecretKey32 = engine.generate_new_lwe_secret_key(input_lwe_dimension)?;
+    /// let output_key: LweSecretKey32 = engine.generate_new_lwe_secret_key(output_lwe_dimension)?;
+    ///
+    /// let keyswitch_key = engine.generate_new_lwe_keyswitch_key(
+    ///     &input_key,
+    ///     &output_key,
+    ///     decomposition_level_count,
+    ///     decomposition_base_log,
+    ///     noise,
+    /// )?;
+    ///
+    /// let mut serialization_engine = DefaultSerializationEngine::new(())?;
+    /// let serialized = serialization_engine.serialize_container(&keyswitch_key)?;
+    /// let recovered = serialization_engine.deserialize_container(serialized.as_slice())?;
+    /// assert_eq!(keyswitch_key, recovered);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn serialize_container(
+        &mut self,
+        entity: &LweKeyswitchKey32,
+    ) -> Result<Vec<u8>, EntityContainerSerializationError<Self::EngineError>> {
+        let payload = self.serialize(entity)?;
+        let header = ContainerHeader::new(
+            ContainerEntityKind::LweKeyswitchKey,
+            32,
+            [
+                entity.input_lwe_dimension().0 as u64,
+                entity.output_lwe_dimension().0 as u64,
+                entity.decomposition_base_log().0 as u64,
+                entity.decomposition_level_count().0 as u64,
+                0,
+            ],
+            &payload,
+        );
+        Ok(header.wrap(&payload))
+    }
+
+    unsafe fn serialize_container_unchecked(&mut self, entity: &LweKeyswitchKey32) -> Vec<u8> {
+        self.serialize_container(entity).unwrap()
+    }
+}
+
+/// # Description:
+/// Implementation of [`EntityContainerSerializationEngine`] for [`DefaultSerializationEngine`] that
+/// operates on 64 bits integers. It serializes in a container a LWE keyswitch key entity.
+impl EntityContainerSerializationEngine<LweKeyswitchKey64, Vec<u8>> for DefaultSerializationEngine {
+    /// # Example:
+    /// ```
+    /// use concrete_core::prelude::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, LweDimension, Variance, *,
+    /// };
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let input_lwe_dimension = LweDimension(6);
+    /// let output_lwe_dimension = LweDimension(3);
+    /// let decomposition_level_count = DecompositionLevelCount(2);
+    /// let decomposition_base_log = DecompositionBaseLog(8);
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let input_key: LweSecretKey64 = engine.generate_new_lwe_secret_key(input_lwe_dimension)?;
+    /// let output_key: LweSecretKey64 = engine.generate_new_lwe_secret_key(output_lwe_dimension)?;
+    ///
+    /// let keyswitch_key = engine.generate_new_lwe_keyswitch_key(
+    ///     &input_key,
+    ///     &output_key,
+    ///     decomposition_level_count,
+    ///     decomposition_base_log,
+    ///     noise,
+    /// )?;
+    ///
+    /// let mut serialization_engine = DefaultSerializationEngine::new(())?;
+    /// let serialized = serialization_engine.serialize_container(&keyswitch_key)?;
+    /// let recovered = serialization_engine.deserialize_container(serialized.as_slice())?;
+    /// assert_eq!(keyswitch_key, recovered);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn serialize_container(
+        &mut self,
+        entity: &LweKeyswitchKey64,
+    ) -> Result<Vec<u8>, EntityContainerSerializationError<Self::EngineError>> {
+        let payload = self.serialize(entity)?;
+        let header = ContainerHeader::new(
+            ContainerEntityKind::LweKeyswitchKey,
+            64,
+            [
+                entity.input_lwe_dimension().0 as u64,
+                entity.output_lwe_dimension().0 as u64,
+                entity.decomposition_base_log().0 as u64,
+                entity.decomposition_level_count().0 as u64,
+                0,
+            ],
+            &payload,
+        );
+        Ok(header.wrap(&payload))
+    }
+
+    unsafe fn serialize_container_unchecked(&mut self, entity: &LweKeyswitchKey64) -> Vec<u8> {
+        self.serialize_container(entity).unwrap()
+    }
+}
+
+/// # Description:
+/// Implementation of [`EntityContainerSerializationEngine`] for [`DefaultSerializationEngine`] that
+/// operates on 32 bits integers. It serializes in a container a LWE secret key entity.
+impl EntityContainerSerializationEngine<LweSecretKey32, Vec<u8>> for DefaultSerializationEngine {
+    /// # Example:
+    /// ```
+    /// use concrete_core::prelude::{LweDimension, *};
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let lwe_dimension = LweDimension(6);
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let lwe_secret_key: LweSecretKey32 = engine.generate_new_lwe_secret_key(lwe_dimension)?;
+    ///
+    /// let mut serialization_engine = DefaultSerializationEngine::new(())?;
+    /// let serialized = serialization_engine.serialize_container(&lwe_secret_key)?;
+    /// let recovered = serialization_engine.deserialize_container(serialized.as_slice())?;
+    /// assert_eq!(lwe_secret_key, recovered);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn serialize_container(
+        &mut self,
+        entity: &LweSecretKey32,
+    ) -> Result<Vec<u8>, EntityContainerSerializationError<Self::EngineError>> {
+        let payload = self.serialize(entity)?;
+        let header = ContainerHeader::new(
+            ContainerEntityKind::LweSecretKey,
+            32,
+            [entity.lwe_dimension().0 as u64, 0, 0, 0, 0],
+            &payload,
+        );
+        Ok(header.wrap(&payload))
+    }
+
+    unsafe fn serialize_container_unchecked(&mut self, entity: &LweSecretKey32) -> Vec<u8> {
+        self.serialize_container(entity).unwrap()
+    }
+}
+
+/// # Description:
+/// Implementation of [`EntityContainerSerializationEngine`] for [`DefaultSerializationEngine`] that
+/// operates on 64 bits integers. It serializes in a container a LWE secret key entity.
+impl EntityContainerSerializationEngine<LweSecretKey64, Vec<u8>> for DefaultSerializationEngine {
+    /// # Example:
+    /// ```
+    /// use concrete_core::prelude::{LweDimension, *};
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let lwe_dimension = LweDimension(6);
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let lwe_secret_key: LweSecretKey64 = engine.generate_new_lwe_secret_key(lwe_dimension)?;
+    ///
+    /// let mut serialization_engine = DefaultSerializationEngine::new(())?;
+    /// let serialized = serialization_engine.serialize_container(&lwe_secret_key)?;
+    /// let recovered = serialization_engine.deserialize_container(serialized.as_slice())?;
+    /// assert_eq!(lwe_secret_key, recovered);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn serialize_container(
+        &mut self,
+        entity: &LweSecretKey64,
+    ) -> Result<Vec<u8>, EntityContainerSerializationError<Self::EngineError>> {
+        let payload = self.serialize(entity)?;
+        let header = ContainerHeader::new(
+            ContainerEntityKind::LweSecretKey,
+            64,
+            [entity.lwe_dimension().0 as u64, 0, 0, 0, 0],
+            &payload,
+        );
+        Ok(header.wrap(&payload))
+    }
+
+    unsafe fn serialize_container_unchecked(&mut self, entity: &LweSecretKey64) -> Vec<u8> {
+        self.serialize_container(entity).unwrap()
+    }
+}
+
+/// # Description:
+/// Implementation of [`EntityContainerSerializationEngine`] for [`DefaultSerializationEngine`] that
+/// operates on 32 bits integers. It serializes in a container a seeded LWE bootstrap key entity.
+impl EntityContainerSerializationEngine<LweSeededBootstrapKey32, Vec<u8>>
+    for DefaultSerializationEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_core::prelude::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, LweDimension, PolynomialSize,
+    ///     Variance, *,
+    /// };
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let (lwe_dim, glwe_dim, poly_size) = (LweDimension(4), GlweDimension(6), PolynomialSize(256));
+    /// let (dec_lc, dec_bl) = (DecompositionLevelCount(3), DecompositionBaseLog(5));
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let lwe_sk: LweSecretKey32 = engine.generate_new_lwe_secret_key(lwe_dim)?;
+    /// let glwe_sk: GlweSecretKey32 = engine.generate_new_glwe_secret_key(glwe_dim, poly_size)?;
+    ///
+    /// let bsk: LweSeededBootstrapKey32 =
+    ///     engine.generate_new_lwe_seeded_bootstrap_key(&lwe_sk, &glwe_sk, dec_bl, dec_lc, noise)?;
+    ///
+    /// let mut serialization_engine = DefaultSerializationEngine::new(())?;
+    /// let serialized = serialization_engine.serialize_container(&bsk)?;
+    /// let recovered = serialization_engine.deserialize_container(serialized.as_slice())?;
+    ///
+    /// assert_eq!(bsk, recovered);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn serialize_container(
+        &mut self,
+        entity: &LweSeededBootstrapKey32,
+    ) -> Result<Vec<u8>, EntityContainerSerializationError<Self::EngineError>> {
+        let payload = self.serialize(entity)?;
+        let header = ContainerHeader::new(
+            ContainerEntityKind::LweSeededBootstrapKey,
+            32,
+            [
+                entity.input_lwe_dimension().0 as u64,
+                entity.glwe_dimension().0 as u64,
+                entity.polynomial_size().0 as u64,
+                entity.decomposition_base_log().0 as u64,
+                entity.decomposition_level_count().0 as u64,
+            ],
+            &payload,
+        );
+        Ok(header.wrap(&payload))
+    }
+
+    unsafe fn serialize_container_unchecked(
+        &mut self,
+        entity: &LweSeededBootstrapKey32,
+    ) -> Vec<u8> {
+        self.serialize_container(entity).unwrap()
+    }
+}
+
+/// # Description:
+/// Implementation of [`EntityContainerSerializationEngine`] for [`DefaultSerializationEngine`] that
+/// operates on 64 bits integers. It serializes in a container a seeded LWE bootstrap key entity.
+impl EntityContainerSerializationEngine<LweSeededBootstrapKey64, Vec<u8>>
+    for DefaultSerializationEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_core::prelude::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, LweDimension, PolynomialSize,
+    ///     Variance, *,
+    /// };
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let (lwe_dim, glwe_dim, poly_size) = (LweDimension(4), GlweDimension(6), PolynomialSize(256));
+    /// let (dec_lc, dec_bl) = (DecompositionLevelCount(3), DecompositionBaseLog(5));
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let lwe_sk: LweSecretKey64 = engine.generate_new_lwe_secret_key(lwe_dim)?;
+    /// let glwe_sk: GlweSecretKey64 = engine.generate_new_glwe_secret_key(glwe_dim, poly_size)?;
+    ///
+    /// let bsk: LweSeededBootstrapKey64 =
+    ///     engine.generate_new_lwe_seeded_bootstrap_key(&lwe_sk, &glwe_sk, dec_bl, dec_lc, noise)?;
+    ///
+    /// let mut serialization_engine = DefaultSerializationEngine::new(())?;
+    /// let serialized = serialization_engine.serialize_container(&bsk)?;
+    /// let recovered = serialization_engine.deserialize_container(serialized.as_slice())?;
+    ///
+    /// assert_eq!(bsk, recovered);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn serialize_container(
+        &mut self,
+        entity: &LweSeededBootstrapKey64,
+    ) -> Result<Vec<u8>, EntityContainerSerializationError<Self::EngineError>> {
+        let payload = self.serialize(entity)?;
+        let header = ContainerHeader::new(
+            ContainerEntityKind::LweSeededBootstrapKey,
+            64,
+            [
+                entity.input_lwe_dimension().0 as u64,
+                entity.glwe_dimension().0 as u64,
+                entity.polynomial_size().0 as u64,
+                entity.decomposition_base_log().0 as u64,
+                entity.decomposition_level_count().0 as u64,
+            ],
+            &payload,
+        );
+        Ok(header.wrap(&payload))
+    }
+
+    unsafe fn serialize_container_unchecked(
+        &mut self,
+        entity: &LweSeededBootstrapKey64,
+    ) -> Vec<u8> {
+        self.serialize_container(entity).unwrap()
+    }
+}
+
+/// # Description:
+/// Implementation of [`EntityContainerSerializationEngine`] for [`DefaultSerializationEngine`] that
+/// operates on 32 bits integers. It serializes in a container a seeded LWE ciphertext entity.
+impl EntityContainerSerializationEngine<LweSeededCiphertext32, Vec<u8>>
+    for DefaultSerializationEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_core::prelude::{LweDimension, Variance, *};
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let lwe_dimension = LweDimension(2);
+    /// // Here a hard-set encoding is applied (shift by 20 bits)
+    /// let input = 3_u32 << 20;
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let key: LweSecretKey32 = engine.generate_new_lwe_secret_key(lwe_dimension)?;
+    /// let plaintext = engine.create_plaintext_from(&input)?;
+    ///
+    /// let ciphertext: LweSeededCiphertext32 =
+    ///     engine.encrypt_lwe_seeded_ciphertext(&key, &plaintext, noise)?;
+    ///
+    /// let mut serialization_engine = DefaultSerializationEngine::new(())?;
+    /// let serialized = serialization_engine.serialize_container(&ciphertext)?;
+    /// let recovered = serialization_engine.deserialize_container(serialized.as_slice())?;
+    /// assert_eq!(ciphertext, recovered);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn serialize_container(
+        &mut self,
+        entity: &LweSeededCiphertext32,
+    ) -> Result<Vec<u8>, EntityContainerSerializationError<Self::EngineError>> {
+        let payload = self.serialize(entity)?;
+        let header = ContainerHeader::new(
+            ContainerEntityKind::LweSeededCiphertext,
+            32,
+            [entity.lwe_dimension().0 as u64, 0, 0, 0, 0],
+            &payload,
+        );
+        Ok(header.wrap(&payload))
+    }
+
+    unsafe fn serialize_container_unchecked(&mut self, entity: &LweSeededCiphertext32) -> Vec<u8> {
+        self.serialize_container(entity).unwrap()
+    }
+}
+
+/// # Description:
+/// Implementation of [`EntityContainerSerializationEngine`] for [`DefaultSerializationEngine`] that
+/// operates on 64 bits integers. It serializes in a container a seeded LWE ciphertext entity.
+impl EntityContainerSerializationEngine<LweSeededCiphertext64, Vec<u8>>
+    for DefaultSerializationEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_core::prelude::{LweDimension, Variance, *};
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let lwe_dimension = LweDimension(2);
+    /// // Here a hard-set encoding is applied (shift by 50 bits)
+    /// let input = 3_u64 << 50;
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let key: LweSecretKey64 = engine.generate_new_lwe_secret_key(lwe_dimension)?;
+    /// let plaintext = engine.create_plaintext_from(&input)?;
+    ///
+    /// let ciphertext: LweSeededCiphertext64 =
+    ///     engine.encrypt_lwe_seeded_ciphertext(&key, &plaintext, noise)?;
+    ///
+    /// let mut serialization_engine = DefaultSerializationEngine::new(())?;
+    /// let serialized = serialization_engine.serialize_container(&ciphertext)?;
+    /// let recovered = serialization_engine.deserialize_container(serialized.as_slice())?;
+    /// assert_eq!(ciphertext, recovered);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn serialize_container(
+        &mut self,
+        entity: &LweSeededCiphertext64,
+    ) -> Result<Vec<u8>, EntityContainerSerializationError<Self::EngineError>> {
+        let payload = self.serialize(entity)?;
+        let header = ContainerHeader::new(
+            ContainerEntityKind::LweSeededCiphertext,
+            64,
+            [entity.lwe_dimension().0 as u64, 0, 0, 0, 0],
+            &payload,
+        );
+        Ok(header.wrap(&payload))
+    }
+
+    unsafe fn serialize_container_unchecked(&mut self, entity: &LweSeededCiphertext64) -> Vec<u8> {
+        self.serialize_container(entity).unwrap()
+    }
+}
+
+/// # Description:
+/// Implementation of [`EntityContainerSerializationEngine`] for [`DefaultSerializationEngine`] that
+/// operates on 32 bits integers. It serializes in a container a compressed LWE ciphertext entity.
+impl EntityContainerSerializationEngine<LweCompressedCiphertext32, Vec<u8>>
+    for DefaultSerializationEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_core::prelude::{LweDimension, Variance, *};
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let lwe_dimension = LweDimension(2);
+    /// // Here a hard-set encoding is applied (shift by 20 bits)
+    /// let input = 3_u32 << 20;
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let key: LweSecretKey32 = engine.generate_new_lwe_secret_key(lwe_dimension)?;
+    /// let plaintext = engine.create_plaintext_from(&input)?;
+    ///
+    /// let ciphertext = engine.encrypt_lwe_ciphertext(&key, &plaintext, noise)?;
+    /// let compressed_ciphertext: LweCompressedCiphertext32 =
+    ///     engine.compress_lwe_ciphertext(&ciphertext, CiphertextModulusLog(16))?;
+    ///
+    /// let mut serialization_engine = DefaultSerializationEngine::new(())?;
+    /// let serialized = serialization_engine.serialize_container(&compressed_ciphertext)?;
+    /// let recovered = serialization_engine.deserialize_container(serialized.as_slice())?;
+    /// assert_eq!(compressed_ciphertext, recovered);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn serialize_container(
+        &mut self,
+        entity: &LweCompressedCiphertext32,
+    ) -> Result<Vec<u8>, EntityContainerSerializationError<Self::EngineError>> {
+        let payload = self.serialize(entity)?;
+        let header = ContainerHeader::new(
+            ContainerEntityKind::LweCompressedCiphertext,
+            32,
+            [
+                entity.lwe_dimension().0 as u64,
+                entity.ciphertext_modulus_log().0 as u64,
+                0,
+                0,
+                0,
+            ],
+            &payload,
+        );
+        Ok(header.wrap(&payload))
+    }
+
+    unsafe fn serialize_container_unchecked(
+        &mut self,
+        entity: &LweCompressedCiphertext32,
+    ) -> Vec<u8> {
+        self.serialize_container(entity).unwrap()
+    }
+}
+
+/// # Description:
+/// Implementation of [`EntityContainerSerializationEngine`] for [`DefaultSerializationEngine`] that
+/// operates on 64 bits integers. It serializes in a container a compressed LWE ciphertext entity.
+impl EntityContainerSerializationEngine<LweCompressedCiphertext64, Vec<u8>>
+    for DefaultSerializationEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_core::prelude::{LweDimension, Variance, *};
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let lwe_dimension = LweDimension(2);
+    /// // Here a hard-set encoding is applied (shift by 50 bits)
+    /// let input = 3_u64 << 50;
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let key: LweSecretKey64 = engine.generate_new_lwe_secret_key(lwe_dimension)?;
+    /// let plaintext = engine.create_plaintext_from(&input)?;
+    ///
+    /// let ciphertext = engine.encrypt_lwe_ciphertext(&key, &plaintext, noise)?;
+    /// let compressed_ciphertext: LweCompressedCiphertext64 =
+    ///     engine.compress_lwe_ciphertext(&ciphertext, CiphertextModulusLog(16))?;
+    ///
+    /// let mut serialization_engine = DefaultSerializationEngine::new(())?;
+    /// let serialized = serialization_engine.serialize_container(&compressed_ciphertext)?;
+    /// let recovered = serialization_engine.deserialize_container(serialized.as_slice())?;
+    /// assert_eq!(compressed_ciphertext, recovered);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn serialize_container(
+        &mut self,
+        entity: &LweCompressedCiphertext64,
+    ) -> Result<Vec<u8>, EntityContainerSerializationError<Self::EngineError>> {
+        let payload = self.serialize(entity)?;
+        let header = ContainerHeader::new(
+            ContainerEntityKind::LweCompressedCiphertext,
+            64,
+            [
+                entity.lwe_dimension().0 as u64,
+                entity.ciphertext_modulus_log().0 as u64,
+                0,
+                0,
+                0,
+            ],
+            &payload,
+        );
+        Ok(header.wrap(&payload))
+    }
+
+    unsafe fn serialize_container_unchecked(
+        &mut self,
+        entity: &LweCompressedCiphertext64,
+    ) -> Vec<u8> {
+        self.serialize_container(entity).unwrap()
+    }
+}
+
+/// # Description:
+/// Implementation of [`EntityContainerSerializationEngine`] for [`DefaultSerializationEngine`] that
+/// operates on 32 bits integers. It serializes in a container a seeded LWE ciphertext vector
+/// entity.
+impl EntityContainerSerializationEngine<LweSeededCiphertextVector32, Vec<u8>>
+    for DefaultSerializationEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_core::prelude::{LweCiphertextCount, LweDimension, Variance, *};
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let lwe_dimension = LweDimension(6);
+    /// // Here a hard-set encoding is applied (shift by 20 bits)
+    /// let input = vec![3_u32 << 20; 3];
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let key: LweSecretKey32 = engine.generate_new_lwe_secret_key(lwe_dimension)?;
+    /// let plaintext_vector: PlaintextVector32 = engine.create_plaintext_vector_from(&input)?;
+    ///
+    /// let mut ciphertext_vector: LweSeededCiphertextVector32 =
+    ///     engine.encrypt_lwe_seeded_ciphertext_vector(&key, &plaintext_vector, noise)?;
+    ///
+    /// let mut serialization_engine = DefaultSerializationEngine::new(())?;
+    /// let serialized = serialization_engine.serialize_container(&ciphertext_vector)?;
+    /// let recovered = serialization_engine.deserialize_container(serialized.as_slice())?;
+    /// assert_eq!(ciphertext_vector, recovered);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn serialize_container(
+        &mut self,
+        entity: &LweSeededCiphertextVector32,
+    ) -> Result<Vec<u8>, EntityContainerSerializationError<Self::EngineError>> {
+        let payload = self.serialize(entity)?;
+        let header = ContainerHeader::new(
+            ContainerEntityKind::LweSeededCiphertextVector,
+            32,
+            [
+                entity.lwe_dimension().0 as u64,
+                entity.lwe_ciphertext_count().0 as u64,
+                0,
+                0,
+                0,
+            ],
+            &payload,
+        );
+        Ok(header.wrap(&payload))
+    }
+
+    unsafe fn serialize_container_unchecked(
+        &mut self,
+        entity: &LweSeededCiphertextVector32,
+    ) -> Vec<u8> {
+        self.serialize_container(entity).unwrap()
+    }
+}
+
+/// # Description:
+/// Implementation of [`EntityContainerSerializationEngine`] for [`DefaultSerializationEngine`] that
+/// operates on 64 bits integers. It serializes in a container a seeded LWE ciphertext vector
+/// entity.
+impl EntityContainerSerializationEngine<LweSeededCiphertextVector64, Vec<u8>>
+    for DefaultSerializationEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_core::prelude::{LweCiphertextCount, LweDimension, Variance, *};
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let lwe_dimension = LweDimension(6);
+    /// // Here a hard-set encoding is applied (shift by 50 bits)
+    /// let input = vec![3_u64 << 50; 3];
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let key: LweSecretKey64 = engine.generate_new_lwe_secret_key(lwe_dimension)?;
+    /// let plaintext_vector: PlaintextVector64 = engine.create_plaintext_vector_from(&input)?;
+    ///
+    /// let mut ciphertext_vector: LweSeededCiphertextVector64 =
+    ///     engine.encrypt_lwe_seeded_ciphertext_vector(&key, &plaintext_vector, noise)?;
+    ///
+    /// let mut serialization_engine = DefaultSerializationEngine::new(())?;
+    /// let serialized = serialization_engine.serialize_container(&ciphertext_vector)?;
+    /// let recovered = serialization_engine.deserialize_container(serialized.as_slice())?;
+    /// assert_eq!(ciphertext_vector, recovered);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn serialize_container(
+        &mut self,
+        entity: &LweSeededCiphertextVector64,
+    ) -> Result<Vec<u8>, EntityContainerSerializationError<Self::EngineError>> {
+        let payload = self.serialize(entity)?;
+        let header = ContainerHeader::new(
+            ContainerEntityKind::LweSeededCiphertextVector,
+            64,
+            [
+                entity.lwe_dimension().0 as u64,
+                entity.lwe_ciphertext_count().0 as u64,
+                0,
+                0,
+                0,
+            ],
+            &payload,
+        );
+        Ok(header.wrap(&payload))
+    }
+
+    unsafe fn serialize_container_unchecked(
+        &mut self,
+        entity: &LweSeededCiphertextVector64,
+    ) -> Vec<u8> {
+        self.serialize_container(entity).unwrap()
+    }
+}
+
+/// # Description:
+/// Implementation of [`EntityContainerSerializationEngine`] for [`DefaultSerializationEngine`] that
+/// operates on 32 bits integers. It serializes in a container a seeded LWE keyswitch key entity.
+impl EntityContainerSerializationEngine<LweSeededKeyswitchKey32, Vec<u8>>
+    for DefaultSerializationEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_core::prelude::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, LweDimension, Variance, *,
+    /// };
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let input_lwe_dimension = LweDimension(6);
+    /// let output_lwe_dimension = LweDimension(3);
+    /// let decomposition_level_count = DecompositionLevelCount(2);
+    /// let decomposition_base_log = DecompositionBaseLog(8);
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let input_key: LweSecretKey32 = engine.generate_new_lwe_secret_key(input_lwe_dimension)?;
+    /// let output_key: LweSecretKey32 = engine.generate_new_lwe_secret_key(output_lwe_dimension)?;
+    ///
+    /// let seeded_keyswitch_key = engine.generate_new_lwe_seeded_keyswitch_key(
+    ///     &input_key,
+    ///     &output_key,
+    ///     decomposition_level_count,
+    ///     decomposition_base_log,
+    ///     noise,
+    /// )?;
+    ///
+    /// let mut serialization_engine = DefaultSerializationEngine::new(())?;
+    /// let serialized = serialization_engine.serialize_container(&seeded_keyswitch_key)?;
+    /// let recovered = serialization_engine.deserialize_container(serialized.as_slice())?;
+    /// assert_eq!(seeded_keyswitch_key, recovered);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn serialize_container(
+        &mut self,
+        entity: &LweSeededKeyswitchKey32,
+    ) -> Result<Vec<u8>, EntityContainerSerializationError<Self::EngineError>> {
+        let payload = self.serialize(entity)?;
+        let header = ContainerHeader::new(
+            ContainerEntityKind::LweSeededKeyswitchKey,
+            32,
+            [
+                entity.input_lwe_dimension().0 as u64,
+                entity.output_lwe_dimension().0 as u64,
+                entity.decomposition_base_log().0 as u64,
+                entity.decomposition_level_count().0 as u64,
+                0,
+            ],
+            &payload,
+        );
+        Ok(header.wrap(&payload))
+    }
+
+    unsafe fn serialize_container_unchecked(
+        &mut self,
+        entity: &LweSeededKeyswitchKey32,
+    ) -> Vec<u8> {
+        self.serialize_container(entity).unwrap()
+    }
+}
+
+/// # Description:
+/// Implementation of [`EntityContainerSerializationEngine`] for [`DefaultSerializationEngine`] that
+/// operates on 64 bits integers. It serializes in a container a seeded LWE keyswitch key entity.
+impl EntityContainerSerializationEngine<LweSeededKeyswitchKey64, Vec<u8>>
+    for DefaultSerializationEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_core::prelude::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, LweDimension, Variance, *,
+    /// };
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let input_lwe_dimension = LweDimension(6);
+    /// let output_lwe_dimension = LweDimension(3);
+    /// let decomposition_level_count = DecompositionLevelCount(2);
+    /// let decomposition_base_log = DecompositionBaseLog(8);
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let input_key: LweSecretKey64 = engine.generate_new_lwe_secret_key(input_lwe_dimension)?;
+    /// let output_key: LweSecretKey64 = engine.generate_new_lwe_secret_key(output_lwe_dimension)?;
+    ///
+    /// let seeded_keyswitch_key = engine.generate_new_lwe_seeded_keyswitch_key(
+    ///     &input_key,
+    ///     &output_key,
+    ///     decomposition_level_count,
+    ///     decomposition_base_log,
+    ///     noise,
+    /// )?;
+    ///
+    /// let mut serialization_engine = DefaultSerializationEngine::new(())?;
+    /// let serialized = serialization_engine.serialize_container(&seeded_keyswitch_key)?;
+    /// let recovered = serialization_engine.deserialize_container(serialized.as_slice())?;
+    /// assert_eq!(seeded_keyswitch_key, recovered);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn serialize_container(
+        &mut self,
+        entity: &LweSeededKeyswitchKey64,
+    ) -> Result<Vec<u8>, EntityContainerSerializationError<Self::EngineError>> {
+        let payload = self.serialize(entity)?;
+        let header = ContainerHeader::new(
+            ContainerEntityKind::LweSeededKeyswitchKey,
+            64,
+            [
+                entity.input_lwe_dimension().0 as u64,
+                entity.output_lwe_dimension().0 as u64,
+                entity.decomposition_base_log().0 as u64,
+                entity.decomposition_level_count().0 as u64,
+                0,
+            ],
+            &payload,
+        );
+        Ok(header.wrap(&payload))
+    }
+
+    unsafe fn serialize_container_unchecked(
+        &mut self,
+        entity: &LweSeededKeyswitchKey64,
+    ) -> Vec<u8> {
+        self.serialize_container(entity).unwrap()
+    }
+}
+
+/// # Description:
+/// Implementation of [`EntityContainerSerializationEngine`] for [`DefaultSerializationEngine`] that
+/// operates on 32 bits integers. It serializes in a container a packing keyswitch key entity.
+impl EntityContainerSerializationEngine<LwePackingKeyswitchKey32, Vec<u8>>
+    for DefaultSerializationEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_core::prelude::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, LweDimension, Variance, *,
+    /// };
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let input_lwe_dimension = LweDimension(6);
+    /// let output_lwe_dimension = LweDimension(3);
+    /// let decomposition_level_count = DecompositionLevelCount(2);
+    /// let decomposition_base_log = DecompositionBaseLog(8);
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let input_key: LweSecretKey32 = engine.generate_new_lwe_secret_key(input_lwe_dimension)?;
+    /// let output_key: LweSecretKey32 = engine.generate_new_lwe_secret_key(output_lwe_dimension)?;
+    ///
+    /// let packing_keyswitch_key = engine.generate_new_lwe_keyswitch_key(
+    ///     &input_key,
+    ///     &output_key,
+    ///     decomposition_level_count,
+    ///     decomposition_base_log,
+    ///     noise,
+    /// )?;
+    ///
+    /// let mut serialization_engine = DefaultSerializationEngine::new(())?;
+    /// let serialized = serialization_engine.serialize_container(&packing_keyswitch_key)?;
+    /// let recovered = serialization_engine.deserialize_container(serialized.as_slice())?;
+    /// assert_eq!(packing_keyswitch_key, recovered);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn serialize_container(
+        &mut self,
+        entity: &LwePackingKeyswitchKey32,
+    ) -> Result<Vec<u8>, EntityContainerSerializationError<Self::EngineError>> {
+        let payload = self.serialize(entity)?;
+        let header = ContainerHeader::new(
+            ContainerEntityKind::LwePackingKeyswitchKey,
+            32,
+            [
+                entity.input_lwe_dimension().0 as u64,
+                entity.output_glwe_dimension().0 as u64,
+                entity.output_polynomial_size().0 as u64,
+                entity.decomposition_base_log().0 as u64,
+                entity.decomposition_level_count().0 as u64,
+            ],
+            &payload,
+        );
+        Ok(header.wrap(&payload))
+    }
+
+    unsafe fn serialize_container_unchecked(
+        &mut self,
+        entity: &LwePackingKeyswitchKey32,
+    ) -> Vec<u8> {
+        self.serialize_container(entity).unwrap()
+    }
+}
+
+/// # Description:
+/// Implementation of [`EntityContainerSerializationEngine`] for [`DefaultSerializationEngine`] that
+/// operates on 64 bits integers. It serializes in a container a packing keyswitch key entity.
+impl EntityContainerSerializationEngine<LwePackingKeyswitchKey64, Vec<u8>>
+    for DefaultSerializationEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_core::prelude::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, LweDimension, Variance, *,
+    /// };
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let input_lwe_dimension = LweDimension(6);
+    /// let output_lwe_dimension = LweDimension(3);
+    /// let decomposition_level_count = DecompositionLevelCount(2);
+    /// let decomposition_base_log = DecompositionBaseLog(8);
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let input_key: LweSecretKey64 = engine.generate_new_lwe_secret_key(input_lwe_dimension)?;
+    /// let output_key: LweSecretKey64 = engine.generate_new_lwe_secret_key(output_lwe_dimension)?;
+    ///
+    /// let packing_keyswitch_key = engine.generate_new_lwe_keyswitch_key(
+    ///     &input_key,
+    ///     &output_key,
+    ///     decomposition_level_count,
+    ///     decomposition_base_log,
+    ///     noise,
+    /// )?;
+    ///
+    /// let mut serialization_engine = DefaultSerializationEngine::new(())?;
+    /// let serialized = serialization_engine.serialize_container(&packing_keyswitch_key)?;
+    /// let recovered = serialization_engine.deserialize_container(serialized.as_slice())?;
+    /// assert_eq!(packing_keyswitch_key, recovered);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn serialize_container(
+        &mut self,
+        entity: &LwePackingKeyswitchKey64,
+    ) -> Result<Vec<u8>, EntityContainerSerializationError<Self::EngineError>> {
+        let payload = self.serialize(entity)?;
+        let header = ContainerHeader::new(
+            ContainerEntityKind::LwePackingKeyswitchKey,
+            64,
+            [
+                entity.input_lwe_dimension().0 as u64,
+                entity.output_glwe_dimension().0 as u64,
+                entity.output_polynomial_size().0 as u64,
+                entity.decomposition_base_log().0 as u64,
+                entity.decomposition_level_count().0 as u64,
+            ],
+            &payload,
+        );
+        Ok(header.wrap(&payload))
+    }
+
+    unsafe fn serialize_container_unchecked(
+        &mut self,
+        entity: &LwePackingKeyswitchKey64,
+    ) -> Vec<u8> {
+        self.serialize_container(entity).unwrap()
+    }
+}
+
+/// # Description:
+/// Implementation of [`EntityContainerSerializationEngine`] for [`DefaultSerializationEngine`] that
+/// operates on 32 bits integers. It serializes in a container an LWE public key.
+impl EntityContainerSerializationEngine<LwePublicKey32, Vec<u8>> for DefaultSerializationEngine {
+    /// # Example:
+    /// ```
+    /// use concrete_core::prelude::{LweDimension, LwePublicKeyZeroEncryptionCount, *};
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let lwe_dimension = LweDimension(6);
+    /// let noise = Variance(2_f64.powf(-50.));
+    /// let lwe_public_key_zero_encryption_count = LwePublicKeyZeroEncryptionCount(42);
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let lwe_secret_key: LweSecretKey32 = engine.generate_new_lwe_secret_key(lwe_dimension)?;
+    ///
+    /// let public_key: LwePublicKey32 = engine.generate_new_lwe_public_key(
+    ///     &lwe_secret_key,
+    ///     noise,
+    ///     lwe_public_key_zero_encryption_count,
+    /// )?;
+    ///
+    /// let mut serialization_engine = DefaultSerializationEngine::new(())?;
+    /// let serialized = serialization_engine.serialize_container(&public_key)?;
+    /// let recovered = serialization_engine.deserialize_container(serialized.as_slice())?;
+    /// assert_eq!(public_key, recovered);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn serialize_container(
+        &mut self,
+        entity: &LwePublicKey32,
+    ) -> Result<Vec<u8>, EntityContainerSerializationError<Self::EngineError>> {
+        let payload = self.serialize(entity)?;
+        let header = ContainerHeader::new(
+            ContainerEntityKind::LwePublicKey,
+            32,
+            [
+                entity.lwe_dimension().0 as u64,
+                entity.lwe_zero_encryption_count().0 as u64,
+                0,
+                0,
+                0,
+            ],
+            &payload,
+        );
+        Ok(header.wrap(&payload))
+    }
+
+    unsafe fn serialize_container_unchecked(&mut self, entity: &LwePublicKey32) -> Vec<u8> {
+        self.serialize_container(entity).unwrap()
+    }
+}
+
+/// # Description:
+/// Implementation of [`EntityContainerSerializationEngine`] for [`DefaultSerializationEngine`] that
+/// operates on 64 bits integers. It serializes in a container an LWE public key.
+impl EntityContainerSerializationEngine<LwePublicKey64, Vec<u8>> for DefaultSerializationEngine {
+    /// # Example:
+    /// ```
+    /// use concrete_core::prelude::{LweDimension, LwePublicKeyZeroEncryptionCount, *};
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let lwe_dimension = LweDimension(6);
+    /// let noise = Variance(2_f64.powf(-50.));
+    /// let lwe_public_key_zero_encryption_count = LwePublicKeyZeroEncryptionCount(42);
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let lwe_secret_key: LweSecretKey64 = engine.generate_new_lwe_secret_key(lwe_dimension)?;
+    ///
+    /// let public_key: LwePublicKey64 = engine.generate_new_lwe_public_key(
+    ///     &lwe_secret_key,
+    ///     noise,
+    ///     lwe_public_key_zero_encryption_count,
+    /// )?;
+    ///
+    /// let mut serialization_engine = DefaultSerializationEngine::new(())?;
+    /// let serialized = serialization_engine.serialize_container(&public_key)?;
+    /// let recovered = serialization_engine.deserialize_container(serialized.as_slice())?;
+    /// assert_eq!(public_key, recovered);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn serialize_container(
+        &mut self,
+        entity: &LwePublicKey64,
+    ) -> Result<Vec<u8>, EntityContainerSerializationError<Self::EngineError>> {
+        let payload = self.serialize(entity)?;
+        let header = ContainerHeader::new(
+            ContainerEntityKind::LwePublicKey,
+            64,
+            [
+                entity.lwe_dimension().0 as u64,
+                entity.lwe_zero_encryption_count().0 as u64,
+                0,
+                0,
+                0,
+            ],
+            &payload,
+        );
+        Ok(header.wrap(&payload))
+    }
+
+    unsafe fn serialize_container_unchecked(&mut self, entity: &LwePublicKey64) -> Vec<u8> {
+        self.serialize_container(entity).unwrap()
+    }
+}
+
+/// # Description:
+/// Implementation of [`EntityContainerSerializationEngine`] for [`DefaultSerializationEngine`] that
+/// operates on 32 bits integers. It serializes in a container a plaintext entity.
+impl EntityContainerSerializationEngine<Plaintext32, Vec<u8>> for DefaultSerializationEngine {
+    /// # Example:
+    /// ```
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // Here a hard-set encoding is applied (shift by 20 bits)
+    /// let input = 3_u32 << 20;
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let plaintext: Plaintext32 = engine.create_plaintext_from(&input)?;
+    ///
+    /// let mut serialization_engine = DefaultSerializationEngine::new(())?;
+    /// let serialized = serialization_engine.serialize_container(&plaintext)?;
+    /// let recovered = serialization_engine.deserialize_container(serialized.as_slice())?;
+    /// assert_eq!(plaintext, recovered);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn serialize_container(
+        &mut self,
+        entity: &Plaintext32,
+    ) -> Result<Vec<u8>, EntityContainerSerializationError<Self::EngineError>> {
+        let payload = self.serialize(entity)?;
+        let header = ContainerHeader::new(
+            ContainerEntityKind::Plaintext,
+            32,
+            [0, 0, 0, 0, 0],
+            &payload,
+        );
+        Ok(header.wrap(&payload))
+    }
+
+    unsafe fn serialize_container_unchecked(&mut self, entity: &Plaintext32) -> Vec<u8> {
+        self.serialize_container(entity).unwrap()
+    }
+}
+
+/// # Description:
+/// Implementation of [`EntityContainerSerializationEngine`] for [`DefaultSerializationEngine`] that
+/// operates on 64 bits integers. It serializes in a container a plaintext entity.
+impl EntityContainerSerializationEngine<Plaintext64, Vec<u8>> for DefaultSerializationEngine {
+    /// # Example:
+    /// ```
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // Here a hard-set encoding is applied (shift by 50 bits)
+    /// let input = 3_u64 << 50;
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let plaintext: Plaintext64 = engine.create_plaintext_from(&input)?;
+    ///
+    /// let mut serialization_engine = DefaultSerializationEngine::new(())?;
+    /// let serialized = serialization_engine.serialize_container(&plaintext)?;
+    /// let recovered = serialization_engine.deserialize_container(serialized.as_slice())?;
+    /// assert_eq!(plaintext, recovered);
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn serialize_container(
+        &mut self,
+        entity: &Plaintext64,
+    ) -> Result<Vec<u8>, EntityContainerSerializationError<Self::EngineError>> {
+        let payload = self.serialize(entity)?;
+        let header = ContainerHeader::new(
+            ContainerEntityKind::Plaintext,
+            64,
+            [0, 0, 0, 0, 0],
+            &payload,
+        );
+        Ok(header.wrap(&payload))
+    }
+
+    unsafe fn serialize_container_unchecked(&mut self, entity: &Plaintext64) -> Vec<u8> {
+        self.serialize_container(entity).unwrap()
+    }
+}
+
+/// # Description:
+/// Implementation of [`EntityContainerSerializationEngine`] for [`DefaultSerializationEngine`] that
+/// operates on 32 bits integers. It serializes in a container a plaintext vector entity.
+impl EntityContainerSerializationEngine<PlaintextVector32, Vec<u8>> for DefaultSerializationEngine {
+    /// # Example:
+    /// ```
+    /// use concrete_core::prelude::{PlaintextCount, *};
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // Here a hard-set encoding is applied (shift by 20 bits)
+    /// let input = vec![3_u32 << 20; 3];
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let plaintext_vector: PlaintextVector32 = engine.create_plaintext_vector_from(&input)?;
+    ///
+    /// let mut serialization_engine = DefaultSerializationEngine::new(())?;
+    /// let serialized = serialization_engine.serialize_container(&plaintext_vector)?;
+    /// let recovered = serialization_engine.deserialize_container(serialized.as_slice())?;
+    /// assert_eq!(plaintext_vector, recovered);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn serialize_container(
+        &mut self,
+        entity: &PlaintextVector32,
+    ) -> Result<Vec<u8>, EntityContainerSerializationError<Self::EngineError>> {
+        let payload = self.serialize(entity)?;
+        let header = ContainerHeader::new(
+            ContainerEntityKind::PlaintextVector,
+            32,
+            [entity.plaintext_count().0 as u64, 0, 0, 0, 0],
+            &payload,
+        );
+        Ok(header.wrap(&payload))
+    }
+
+    unsafe fn serialize_container_unchecked(&mut self, entity: &PlaintextVector32) -> Vec<u8> {
+        self.serialize_container(entity).unwrap()
+    }
+}
+
+/// # Description:
+/// Implementation of [`EntityContainerSerializationEngine`] for [`DefaultSerializationEngine`] that
+/// operates on 64 bits integers. It serializes in a container a plaintext vector entity.
+impl EntityContainerSerializationEngine<PlaintextVector64, Vec<u8>> for DefaultSerializationEngine {
+    /// # Example:
+    /// ```
+    /// use concrete_core::prelude::{PlaintextCount, *};
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // Here a hard-set encoding is applied (shift by 50 bits)
+    /// let input = vec![3_u64 << 50; 3];
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let plaintext_vector: PlaintextVector64 = engine.create_plaintext_vector_from(&input)?;
+    ///
+    /// let mut serialization_engine = DefaultSerializationEngine::new(())?;
+    /// let serialized = serialization_engine.serialize_container(&plaintext_vector)?;
+    /// let recovered = serialization_engine.deserialize_container(serialized.as_slice())?;
+    /// assert_eq!(plaintext_vector, recovered);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn serialize_container(
+        &mut self,
+        entity: &PlaintextVector64,
+    ) -> Result<Vec<u8>, EntityContainerSerializationError<Self::EngineError>> {
+        let payload = self.serialize(entity)?;
+        let header = ContainerHeader::new(
+            ContainerEntityKind::PlaintextVector,
+            64,
+            [entity.plaintext_count().0 as u64, 0, 0, 0, 0],
+            &payload,
+        );
+        Ok(header.wrap(&payload))
+    }
+
+    unsafe fn serialize_container_unchecked(&mut self, entity: &PlaintextVector64) -> Vec<u8> {
+        self.serialize_container(entity).unwrap()
+    }
+}
+
+/// # Description:
+/// Implementation of [`EntityContainerSerializationEngine`] for [`DefaultSerializationEngine`] that
+/// operates on 64 bits integers. It serializes in a container a float encoder entity.
+impl EntityContainerSerializationEngine<FloatEncoder, Vec<u8>> for DefaultSerializationEngine {
+    /// # Example:
+    /// ```
+    /// use concrete_core::prelude::{PlaintextCount, *};
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let encoder = engine.create_encoder_from(&FloatEncoderMinMaxConfig {
+    ///     min: 0.,
+    ///     max: 10.,
+    ///     nb_bit_precision: 8,
+    ///     nb_bit_padding: 1,
+    /// })?;
+    ///
+    /// let mut serialization_engine = DefaultSerializationEngine::new(())?;
+    /// let serialized = serialization_engine.serialize_container(&encoder)?;
+    /// let recovered = serialization_engine.deserialize_container(serialized.as_slice())?;
+    /// assert_eq!(encoder, recovered);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn serialize_container(
+        &mut self,
+        entity: &FloatEncoder,
+    ) -> Result<Vec<u8>, EntityContainerSerializationError<Self::EngineError>> {
+        let payload = self.serialize(entity)?;
+        let header = ContainerHeader::new(
+            ContainerEntityKind::FloatEncoder,
+            64,
+            [0, 0, 0, 0, 0],
+            &payload,
+        );
+        Ok(header.wrap(&payload))
+    }
+
+    unsafe fn serialize_container_unchecked(&mut self, entity: &FloatEncoder) -> Vec<u8> {
+        self.serialize_container(entity).unwrap()
+    }
+}
+
+/// # Description:
+/// Implementation of [`EntityContainerSerializationEngine`] for [`DefaultSerializationEngine`] that
+/// operates on 64 bits integers. It serializes in a container a float encoder vector entity.
+impl EntityContainerSerializationEngine<FloatEncoderVector, Vec<u8>>
+    for DefaultSerializationEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_core::prelude::PlaintextCount;
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let encoder_vector = engine.create_encoder_vector_from(&vec![
+    ///     FloatEncoderCenterRadiusConfig {
+    ///         center: 10.,
+    ///         radius: 5.,
+    ///         nb_bit_precision: 8,
+    ///         nb_bit_padding: 1,
+    ///     };
+    ///     1
+    /// ])?;
+    ///
+    /// let mut serialization_engine = DefaultSerializationEngine::new(())?;
+    /// let serialized = serialization_engine.serialize_container(&encoder_vector)?;
+    /// let recovered = serialization_engine.deserialize_container(serialized.as_slice())?;
+    /// assert_eq!(encoder_vector, recovered);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn serialize_container(
+        &mut self,
+        entity: &FloatEncoderVector,
+    ) -> Result<Vec<u8>, EntityContainerSerializationError<Self::EngineError>> {
+        let payload = self.serialize(entity)?;
+        let header = ContainerHeader::new(
+            ContainerEntityKind::FloatEncoderVector,
+            64,
+            [entity.encoder_count().0 as u64, 0, 0, 0, 0],
+            &payload,
+        );
+        Ok(header.wrap(&payload))
+    }
+
+    unsafe fn serialize_container_unchecked(&mut self, entity: &FloatEncoderVector) -> Vec<u8> {
+        self.serialize_container(entity).unwrap()
+    }
+}
@@ -1,68 +1,21 @@
 #![allow(clippy::missing_safety_doc)]
-use crate::commons::crypto::bootstrap::{
-    StandardBootstrapKey as ImplStandardBootstrapKey,
-    StandardSeededBootstrapKey as ImplStandardSeededBootstrapKey,
-};
-use crate::commons::crypto::encoding::{
-    Cleartext as ImplCleartext, CleartextList as ImplCleartextList,
-    FloatEncoder as ImplFloatEncoder, Plaintext as ImplPlaintext,
-    PlaintextList as ImplPlaintextList,
-};
-use crate::commons::crypto::ggsw::{
-    StandardGgswCiphertext as ImplStandardGgswCiphertext,
-    StandardGgswSeededCiphertext as ImplStandardGgswSeededCiphertext,
-};
-use crate::commons::crypto::glwe::{
-    GlweCiphertext as ImplGlweCiphertext, GlweList as ImplGlweList,
-    GlweSeededCiphertext as ImplGlweSeededCiphertext, GlweSeededList as ImplGlweSeededList,
-    LwePackingKeyswitchKey as ImplLwePackingKeyswitchKey,
-    LwePrivateFunctionalPackingKeyswitchKeyList as ImplLweCircuitBoostrapPrivateFunctionalPackingKeyswitchKeys,
-};
-use crate::commons::crypto::lwe::{
-    LweCiphertext as ImplLweCiphertext, LweKeyswitchKey as ImplLweKeyswitchKey,
-    LweList as ImplLweList, LweSeededCiphertext as ImplLweSeededCiphertext,
-    LweSeededKeyswitchKey as ImplLweSeededKeyswitchKey, LweSeededList as ImplLweSeededList,
-};
-use crate::commons::crypto::secret::{
-    GlweSecretKey as ImplGlweSecretKey, LweSecretKey as ImplLweSecretKey,
-};
 use crate::prelude::{
-    BinaryKeyKind, Cleartext32, Cleartext32Version, Cleartext64, Cleartext64Version, CleartextF64,
-    CleartextF64Version, CleartextVector32, CleartextVector32Version, CleartextVector64,
-    CleartextVector64Version, CleartextVectorF64, CleartextVectorF64Version,
-    DefaultSerializationEngine, DefaultSerializationError, EntityDeserializationEngine,
-    EntityDeserializationError, FloatEncoder, FloatEncoderVector, FloatEncoderVectorVersion,
-    FloatEncoderVersion, GgswCiphertext32, GgswCiphertext32Version, GgswCiphertext64,
-    GgswCiphertext64Version, GgswSeededCiphertext32, GgswSeededCiphertext32Version,
-    GgswSeededCiphertext64, GgswSeededCiphertext64Version, GlweCiphertext32,
-    GlweCiphertext32Version, GlweCiphertext64, GlweCiphertext64Version, GlweCiphertextVector32,
-    GlweCiphertextVector32Version, GlweCiphertextVector64, GlweCiphertextVector64Version,
-    GlweSecretKey32, GlweSecretKey32Version, GlweSecretKey64, GlweSecretKey64Version,
-    GlweSeededCiphertext32, GlweSeededCiphertext32Version, GlweSeededCiphertext64,
-    GlweSeededCiphertext64Version, GlweSeededCiphertextVector32,
-    GlweSeededCiphertextVector32Version, GlweSeededCiphertextVector64,
-    GlweSeededCiphertextVector64Version, LweBootstrapKey32, LweBootstrapKey32Version,
-    LweBootstrapKey64, LweBootstrapKey64Version, LweCiphertext32, LweCiphertext32Version,
-    LweCiphertext64, LweCiphertext64Version, LweCiphertextVector32, LweCiphertextVector32Version,
-    LweCiphertextVector64, LweCiphertextVector64Version,
-    LweCircuitBootstrapPrivateFunctionalPackingKeyswitchKeys32,
-    LweCircuitBootstrapPrivateFunctionalPackingKeyswitchKeys32Version,
-    LweCircuitBootstrapPrivateFunctionalPackingKeyswitchKeys64,
-    LweCircuitBootstrapPrivateFunctionalPackingKeyswitchKeys64Version, LweKeyswitchKey32,
-    LweKeyswitchKey32Version, LweKeyswitchKey64, LweKeyswitchKey64Version,
-    LwePackingKeyswitchKey32, LwePackingKeyswitchKey32Version, LwePackingKeyswitchKey64,
-    LwePackingKeyswitchKey64Version, LwePublicKey32, LwePublicKey32Version, LwePublicKey64,
-    LwePublicKey64Version, LweSecretKey32, LweSecretKey32Version, LweSecretKey64,
-    LweSecretKey64Version, LweSeededBootstrapKey32, LweSeededBootstrapKey32Version,
-    LweSeededBootstrapKey64, LweSeededBootstrapKey64Version, LweSeededCiphertext32,
-    LweSeededCiphertext32Version, LweSeededCiphertext64, LweSeededCiphertext64Version,
-    LweSeededCiphertextVector32, LweSeededCiphertextVector32Version, LweSeededCiphertextVector64,
-    LweSeededCiphertextVector64Version, LweSeededKeyswitchKey32, LweSeededKeyswitchKey32Version,
-    LweSeededKeyswitchKey64, LweSeededKeyswitchKey64Version, Plaintext32, Plaintext32Version,
-    Plaintext64, Plaintext64Version, PlaintextVector32, PlaintextVector32Version,
-    PlaintextVector64, PlaintextVector64Version,
+    Cleartext32, Cleartext64, CleartextF64, CleartextVector32, CleartextVector64,
+    CleartextVectorF64, DefaultSerializationEngine, EntityDeserializationEngine,
+    EntityDeserializationError, FloatEncoder, FloatEncoderVector, GgswCiphertext32,
+    GgswCiphertext64, GgswSeededCiphertext32, GgswSeededCiphertext64, GlweCiphertext32,
+    GlweCiphertext64, GlweCiphertextVector32, GlweCiphertextVector64, GlweCompressedCiphertext32,
+    GlweCompressedCiphertext64, GlweSecretKey32, GlweSecretKey64, GlweSeededCiphertext32,
+    GlweSeededCiphertext64, GlweSeededCiphertextVector32, GlweSeededCiphertextVector64,
+    LweBootstrapKey32, LweBootstrapKey64, LweCiphertext32, LweCiphertext64, LweCiphertextVector32,
+    LweCiphertextVector64, LweCircuitBootstrapPrivateFunctionalPackingKeyswitchKeys32,
+    LweCircuitBootstrapPrivateFunctionalPackingKeyswitchKeys64, LweCompressedCiphertext32,
+    LweCompressedCiphertext64, LweKeyswitchKey32, LweKeyswitchKey64, LwePackingKeyswitchKey32,
+    LwePackingKeyswitchKey64, LwePublicKey32, LwePublicKey64, LweSecretKey32, LweSecretKey64,
+    LweSeededBootstrapKey32, LweSeededBootstrapKey64, LweSeededCiphertext32, LweSeededCiphertext64,
+    LweSeededCiphertextVector32, LweSeededCiphertextVector64, LweSeededKeyswitchKey32,
+    LweSeededKeyswitchKey64, Plaintext32, Plaintext64, PlaintextVector32, PlaintextVector64,
 };
-use serde::Deserialize;
 
 /// # Description:
 /// Implementation of [`EntityDeserializationEngine`] for [`DefaultSerializationEngine`] that
@@ -93,26 +46,7 @@ impl EntityDeserializationEngine<&[u8], Cleartext32> for DefaultSerializationEng
         &mut self,
         serialized: &[u8],
     ) -> Result<Cleartext32, EntityDeserializationError<Self::EngineError>> {
-        #[derive(Deserialize)]
-        struct DeserializableCleartext32 {
-            version: Cleartext32Version,
-            inner: ImplCleartext<u32>,
-        }
-        let deserialized: DeserializableCleartext32 = bincode::deserialize(serialized)
-            .map_err(DefaultSerializationError::Deserialization)
-            .map_err(EntityDeserializationError::Engine)?;
-        match deserialized {
-            DeserializableCleartext32 {
-                version: Cleartext32Version::Unsupported,
-                ..
-            } => Err(EntityDeserializationError::Engine(
-                DefaultSerializationError::UnsupportedVersion,
-            )),
-            DeserializableCleartext32 {
-                version: Cleartext32Version::V0,
-                inner,
-            } => Ok(Cleartext32(inner)),
-        }
+        self.deserialize_from_slice(serialized)
     }
 
     unsafe fn deserialize_unchecked(&mut self, serialized: &[u8]) -> Cleartext32 {
@@ -149,26 +83,7 @@ impl EntityDeserializationEngine<&[u8], Cleartext64> for DefaultSerializationEng
         &mut self,
         serialized: &[u8],
     ) -> Result<Cleartext64, EntityDeserializationError<Self::EngineError>> {
-        #[derive(Deserialize)]
-        struct DeserializableCleartext64 {
-            version: Cleartext64Version,
-            inner: ImplCleartext<u64>,
-        }
-        let deserialized: DeserializableCleartext64 = bincode::deserialize(serialized)
-            .map_err(DefaultSerializationError::Deserialization)
-            .map_err(EntityDeserializationError::Engine)?;
-        match deserialized {
-            DeserializableCleartext64 {
-                version: Cleartext64Version::Unsupported,
-                ..
-            } => Err(EntityDeserializationError::Engine(
-                DefaultSerializationError::UnsupportedVersion,
-            )),
-            DeserializableCleartext64 {
-                version: Cleartext64Version::V0,
-                inner,
-            } => Ok(Cleartext64(inner)),
-        }
+        self.deserialize_from_slice(serialized)
     }
 
     unsafe fn deserialize_unchecked(&mut self, serialized: &[u8]) -> Cleartext64 {
@@ -205,26 +120,7 @@ impl EntityDeserializationEngine<&[u8], CleartextF64> for DefaultSerializationEn
         &mut self,
         serialized: &[u8],
     ) -> Result<CleartextF64, EntityDeserializationError<Self::EngineError>> {
-        #[derive(Deserialize)]
-        struct DeserializableCleartextF64 {
-            version: CleartextF64Version,
-            inner: ImplCleartext<f64>,
-        }
-        let deserialized: DeserializableCleartextF64 = bincode::deserialize(serialized)
-            .map_err(DefaultSerializationError::Deserialization)
-            .map_err(EntityDeserializationError::Engine)?;
-        match deserialized {
-            DeserializableCleartextF64 {
-                version: CleartextF64Version::Unsupported,
-                ..
-            } => Err(EntityDeserializationError::Engine(
-                DefaultSerializationError::UnsupportedVersion,
-            )),
-            DeserializableCleartextF64 {
-                version: CleartextF64Version::V0,
-                inner,
-            } => Ok(CleartextF64(inner)),
-        }
+        self.deserialize_from_slice(serialized)
     }
 
     unsafe fn deserialize_unchecked(&mut self, serialized: &[u8]) -> CleartextF64 {
@@ -261,26 +157,7 @@ impl EntityDeserializationEngine<&[u8], CleartextVector32> for DefaultSerializat
         &mut self,
         serialized: &[u8],
     ) -> Result<CleartextVector32, EntityDeserializationError<Self::EngineError>> {
-        #[derive(Deserialize)]
-        struct DeserializableCleartextVector32 {
-            version: CleartextVector32Version,
-            inner: ImplCleartextList<Vec<u32>>,
-        }
-        let deserialized: DeserializableCleartextVector32 = bincode::deserialize(serialized)
-            .map_err(DefaultSerializationError::Deserialization)
-            .map_err(EntityDeserializationError::Engine)?;
-        match deserialized {
-            DeserializableCleartextVector32 {
-                version: CleartextVector32Version::Unsupported,
-                ..
-            } => Err(EntityDeserializationError::Engine(
-                DefaultSerializationError::UnsupportedVersion,
-            )),
-            DeserializableCleartextVector32 {
-                version: CleartextVector32Version::V0,
-                inner,
-            } => Ok(CleartextVector32(inner)),
-        }
+        self.deserialize_from_slice(serialized)
     }
 
     unsafe fn deserialize_unchecked(&mut self, serialized: &[u8]) -> CleartextVector32 {
@@ -317,26 +194,7 @@ impl EntityDeserializationEngine<&[u8], CleartextVector64> for DefaultSerializat
         &mut self,
         serialized: &[u8],
     ) -> Result<CleartextVector64, EntityDeserializationError<Self::EngineError>> {
-        #[derive(Deserialize)]
-        struct DeserializableCleartextVector64 {
-            version: CleartextVector64Version,
-            inner: ImplCleartextList<Vec<u64>>,
-        }
-        let deserialized: DeserializableCleartextVector64 = bincode::deserialize(serialized)
-            .map_err(DefaultSerializationError::Deserialization)
-            .map_err(EntityDeserializationError::Engine)?;
-        match deserialized {
-            DeserializableCleartextVector64 {
-                version: CleartextVector64Version::Unsupported,
-                ..
-            } => Err(EntityDeserializationError::Engine(
-                DefaultSerializationError::UnsupportedVersion,
-            )),
-            DeserializableCleartextVector64 {
-                version: CleartextVector64Version::V0,
-                inner,
-            } => Ok(CleartextVector64(inner)),
-        }
+        self.deserialize_from_slice(serialized)
     }
 
     unsafe fn deserialize_unchecked(&mut self, serialized: &[u8]) -> CleartextVector64 {
@@ -373,26 +231,7 @@ impl EntityDeserializationEngine<&[u8], CleartextVectorF64> for DefaultSerializa
         &mut self,
         serialized: &[u8],
     ) -> Result<CleartextVectorF64, EntityDeserializationError<Self::EngineError>> {
-        #[derive(Deserialize)]
-        struct DeserializableCleartextVectorF64 {
-            version: CleartextVectorF64Version,
-            inner: ImplCleartextList<Vec<f64>>,
-        }
-        let deserialized: DeserializableCleartextVectorF64 = bincode::deserialize(serialized)
-            .map_err(DefaultSerializationError::Deserialization)
-            .map_err(EntityDeserializationError::Engine)?;
-        match deserialized {
-            DeserializableCleartextVectorF64 {
-                version: CleartextVectorF64Version::Unsupported,
-                ..
-            } => Err(EntityDeserializationError::Engine(
-                DefaultSerializationError::UnsupportedVersion,
-            )),
-            DeserializableCleartextVectorF64 {
-                version: CleartextVectorF64Version::V0,
-                inner,
-            } => Ok(CleartextVectorF64(inner)),
-        }
+        self.deserialize_from_slice(serialized)
     }
 
     unsafe fn deserialize_unchecked(&mut self, serialized: &[u8]) -> CleartextVectorF64 {
@@ -445,26 +284,7 @@ impl EntityDeserializationEngine<&[u8], GgswCiphertext32> for DefaultSerializati
         &mut self,
         serialized: &[u8],
     ) -> Result<GgswCiphertext32, EntityDeserializationError<Self::EngineError>> {
-        #[derive(Deserialize)]
-        struct DeserializableGgswCiphertext32 {
-            version: GgswCiphertext32Version,
-            inner: ImplStandardGgswCiphertext<Vec<u32>>,
-        }
-        let deserialized: DeserializableGgswCiphertext32 = bincode::deserialize(serialized)
-            .map_err(DefaultSerializationError::Deserialization)
-            .map_err(EntityDeserializationError::Engine)?;
-        match deserialized {
-            DeserializableGgswCiphertext32 {
-                version: GgswCiphertext32Version::Unsupported,
-                ..
-            } => Err(EntityDeserializationError::Engine(
-                DefaultSerializationError::UnsupportedVersion,
-            )),
-            DeserializableGgswCiphertext32 {
-                version: GgswCiphertext32Version::V0,
-                inner,
-            } => Ok(GgswCiphertext32(inner)),
-        }
+        self.deserialize_from_slice(serialized)
     }
 
     unsafe fn deserialize_unchecked(&mut self, serialized: &[u8]) -> GgswCiphertext32 {
@@ -517,26 +337,7 @@ impl EntityDeserializationEngine<&[u8], GgswCiphertext64> for DefaultSerializati
         &mut self,
         serialized: &[u8],
     ) -> Result<GgswCiphertext64, EntityDeserializationError<Self::EngineError>> {
-        #[derive(Deserialize)]
-        struct DeserializableGgswCiphertext64 {
-            version: GgswCiphertext64Version,
-            inner: ImplStandardGgswCiphertext<Vec<u64>>,
-        }
-        let deserialized: DeserializableGgswCiphertext64 = bincode::deserialize(serialized)
-            .map_err(DefaultSerializationError::Deserialization)
-            .map_err(EntityDeserializationError::Engine)?;
-        match deserialized {
-            DeserializableGgswCiphertext64 {
-                version: GgswCiphertext64Version::Unsupported,
-                ..
-            } => Err(EntityDeserializationError::Engine(
-                DefaultSerializationError::UnsupportedVersion,
-            )),
-            DeserializableGgswCiphertext64 {
-                version: GgswCiphertext64Version::V0,
-                inner,
-            } => Ok(GgswCiphertext64(inner)),
-        }
+        self.deserialize_from_slice(serialized)
     }
 
     unsafe fn deserialize_unchecked(&mut self, serialized: &[u8]) -> GgswCiphertext64 {
@@ -553,26 +354,7 @@ impl EntityDeserializationEngine<&[u8], GgswSeededCiphertext32> for DefaultSeria
         &mut self,
         serialized: &[u8],
     ) -> Result<GgswSeededCiphertext32, EntityDeserializationError<Self::EngineError>> {
-        #[derive(Deserialize)]
-        struct DeserializableGgswSeededCiphertext32 {
-            version: GgswSeededCiphertext32Version,
-            inner: ImplStandardGgswSeededCiphertext<Vec<u32>>,
-        }
-        let deserialized: DeserializableGgswSeededCiphertext32 = bincode::deserialize(serialized)
-            .map_err(DefaultSerializationError::Deserialization)
-            .map_err(EntityDeserializationError::Engine)?;
-        match deserialized {
-            DeserializableGgswSeededCiphertext32 {
-                version: GgswSeededCiphertext32Version::Unsupported,
-                ..
-            } => Err(EntityDeserializationError::Engine(
-                DefaultSerializationError::UnsupportedVersion,
-            )),
-            DeserializableGgswSeededCiphertext32 {
-                version: GgswSeededCiphertext32Version::V0,
-                inner,
-            } => Ok(GgswSeededCiphertext32(inner)),
-        }
+        self.deserialize_from_slice(serialized)
     }
 
     unsafe fn deserialize_unchecked(&mut self, serialized: &[u8]) -> GgswSeededCiphertext32 {
@@ -589,26 +371,7 @@ impl EntityDeserializationEngine<&[u8], GgswSeededCiphertext64> for DefaultSeria
         &mut self,
         serialized: &[u8],
     ) -> Result<GgswSeededCiphertext64, EntityDeserializationError<Self::EngineError>> {
-        #[derive(Deserialize)]
-        struct DeserializableGgswSeededCiphertext64 {
-            version: GgswSeededCiphertext64Version,
-            inner: ImplStandardGgswSeededCiphertext<Vec<u64>>,
-        }
-        let deserialized: DeserializableGgswSeededCiphertext64 = bincode::deserialize(serialized)
-            .map_err(DefaultSerializationError::Deserialization)
-            .map_err(EntityDeserializationError::Engine)?;
-        match deserialized {
-            DeserializableGgswSeededCiphertext64 {
-                version: GgswSeededCiphertext64Version::Unsupported,
-                ..
-            } => Err(EntityDeserializationError::Engine(
-                DefaultSerializationError::UnsupportedVersion,
-            )),
-            DeserializableGgswSeededCiphertext64 {
-                version: GgswSeededCiphertext64Version::V0,
-                inner,
-            } => Ok(GgswSeededCiphertext64(inner)),
-        }
+        self.deserialize_from_slice(serialized)
     }
 
     unsafe fn deserialize_unchecked(&mut self, serialized: &[u8]) -> GgswSeededCiphertext64 {
@@ -657,26 +420,7 @@ impl EntityDeserializationEngine<&[u8], GlweCiphertext32> for DefaultSerializati
         &mut self,
         serialized: &[u8],
     ) -> Result<GlweCiphertext32, EntityDeserializationError<Self::EngineError>> {
-        #[derive(Deserialize)]
-        struct DeserializableGlweCiphertext32 {
-            version: GlweCiphertext32Version,
-            inner: ImplGlweCiphertext<Vec<u32>>,
-        }
-        let deserialized: DeserializableGlweCiphertext32 = bincode::deserialize(serialized)
-            .map_err(DefaultSerializationError::Deserialization)
-            .map_err(EntityDeserializationError::Engine)?;
-        match deserialized {
-            DeserializableGlweCiphertext32 {
-                version: GlweCiphertext32Version::Unsupported,
-                ..
-            } => Err(EntityDeserializationError::Engine(
-                DefaultSerializationError::UnsupportedVersion,
-            )),
-            DeserializableGlweCiphertext32 {
-                version: GlweCiphertext32Version::V0,
-                inner,
-            } => Ok(GlweCiphertext32(inner)),
-        }
+        self.deserialize_from_slice(serialized)
     }
 
     unsafe fn deserialize_unchecked(&mut self, serialized: &[u8]) -> GlweCiphertext32 {
@@ -725,26 +469,7 @@ impl EntityDeserializationEngine<&[u8], GlweCiphertext64> for DefaultSerializati
         &mut self,
         serialized: &[u8],
     ) -> Result<GlweCiphertext64, EntityDeserializationError<Self::EngineError>> {
-        #[derive(Deserialize)]
-        struct DeserializableGlweCiphertext64 {
-            version: GlweCiphertext64Version,
-            inner: ImplGlweCiphertext<Vec<u64>>,
-        }
-        let deserialized: DeserializableGlweCiphertext64 = bincode::deserialize(serialized)
-            .map_err(DefaultSerializationError::Deserialization)
-            .map_err(EntityDeserializationError::Engine)?;
-        match deserialized {
-            DeserializableGlweCiphertext64 {
-                version: GlweCiphertext64Version::Unsupported,
-                ..
-            } => Err(EntityDeserializationError::Engine(
-                DefaultSerializationError::UnsupportedVersion,
-            )),
-            DeserializableGlweCiphertext64 {
-                version: GlweCiphertext64Version::V0,
-                inner,
-            } => Ok(GlweCiphertext64(inner)),
-        }
+        self.deserialize_from_slice(serialized)
     }
 
     unsafe fn deserialize_unchecked(&mut self, serialized: &[u8]) -> GlweCiphertext64 {
@@ -793,26 +518,7 @@ impl EntityDeserializationEngine<&[u8], GlweCiphertextVector32> for DefaultSeria
         &mut self,
         serialized: &[u8],
     ) -> Result<GlweCiphertextVector32, EntityDeserializationError<Self::EngineError>> {
-        #[derive(Deserialize)]
-        struct DeserializableGlweCiphertextVector32 {
-            version: GlweCiphertextVector32Version,
-            inner: ImplGlweList<Vec<u32>>,
-        }
-        let deserialized: DeserializableGlweCiphertextVector32 = bincode::deserialize(serialized)
-            .map_err(DefaultSerializationError::Deserialization)
-            .map_err(EntityDeserializationError::Engine)?;
-        match deserialized {
-            DeserializableGlweCiphertextVector32 {
-                version: GlweCiphertextVector32Version::Unsupported,
-                ..
-            } => Err(EntityDeserializationError::Engine(
-                DefaultSerializationError::UnsupportedVersion,
-            )),
-            DeserializableGlweCiphertextVector32 {
-                version: GlweCiphertextVector32Version::V0,
-                inner,
-            } => Ok(GlweCiphertextVector32(inner)),
-        }
+        self.deserialize_from_slice(serialized)
     }
 
     unsafe fn deserialize_unchecked(&mut self, serialized: &[u8]) -> GlweCiphertextVector32 {
@@ -861,26 +567,7 @@ impl EntityDeserializationEngine<&[u8], GlweCiphertextVector64> for DefaultSeria
         &mut self,
         serialized: &[u8],
     ) -> Result<GlweCiphertextVector64, EntityDeserializationError<Self::EngineError>> {
-        #[derive(Deserialize)]
-        struct DeserializableGlweCiphertextVector64 {
-            version: GlweCiphertextVector64Version,
-            inner: ImplGlweList<Vec<u64>>,
-        }
-        let deserialized: DeserializableGlweCiphertextVector64 = bincode::deserialize(serialized)
-            .map_err(DefaultSerializationError::Deserialization)
-            .map_err(EntityDeserializationError::Engine)?;
-        match deserialized {
-            DeserializableGlweCiphertextVector64 {
-                version: GlweCiphertextVector64Version::Unsupported,
-                ..
-            } => Err(EntityDeserializationError::Engine(
-                DefaultSerializationError::UnsupportedVersion,
-            )),
-            DeserializableGlweCiphertextVector64 {
-                version: GlweCiphertextVector64Version::V0,
-                inner,
-            } => Ok(GlweCiphertextVector64(inner)),
-        }
+        self.deserialize_from_slice(serialized)
     }
 
     unsafe fn deserialize_unchecked(&mut self, serialized: &[u8]) -> GlweCiphertextVector64 {
@@ -922,26 +609,7 @@ impl EntityDeserializationEngine<&[u8], GlweSecretKey32> for DefaultSerializatio
         &mut self,
         serialized: &[u8],
     ) -> Result<GlweSecretKey32, EntityDeserializationError<Self::EngineError>> {
-        #[derive(Deserialize)]
-        struct DeserializableGlweSecretKey32 {
-            version: GlweSecretKey32Version,
-            inner: ImplGlweSecretKey<BinaryKeyKind, Vec<u32>>,
-        }
-        let deserialized: DeserializableGlweSecretKey32 = bincode::deserialize(serialized)
-            .map_err(DefaultSerializationError::Deserialization)
-            .map_err(EntityDeserializationError::Engine)?;
-        match deserialized {
-            DeserializableGlweSecretKey32 {
-                version: GlweSecretKey32Version::Unsupported,
-                ..
-            } => Err(EntityDeserializationError::Engine(
-                DefaultSerializationError::UnsupportedVersion,
-            )),
-            DeserializableGlweSecretKey32 {
-                version: GlweSecretKey32Version::V0,
-                inner,
-            } => Ok(GlweSecretKey32(inner)),
-        }
+        self.deserialize_from_slice(serialized)
     }
 
     unsafe fn deserialize_unchecked(&mut self, serialized: &[u8]) -> GlweSecretKey32 {
@@ -983,26 +651,7 @@ impl EntityDeserializationEngine<&[u8], GlweSecretKey64> for DefaultSerializatio
         &mut self,
         serialized: &[u8],
     ) -> Result<GlweSecretKey64, EntityDeserializationError<Self::EngineError>> {
-        #[derive(Deserialize)]
-        struct DeserializableGlweSecretKey64 {
-            version: GlweSecretKey64Version,
-            inner: ImplGlweSecretKey<BinaryKeyKind, Vec<u64>>,
-        }
-        let deserialized: DeserializableGlweSecretKey64 = bincode::deserialize(serialized)
-            .map_err(DefaultSerializationError::Deserialization)
-            .map_err(EntityDeserializationError::Engine)?;
-        match deserialized {
-            DeserializableGlweSecretKey64 {
-                version: GlweSecretKey64Version::Unsupported,
-                ..
-            } => Err(EntityDeserializationError::Engine(
-                DefaultSerializationError::UnsupportedVersion,
-            )),
-            DeserializableGlweSecretKey64 {
-                version: GlweSecretKey64Version::V0,
-                inner,
-            } => Ok(GlweSecretKey64(inner)),
-        }
+        self.deserialize_from_slice(serialized)
     }
 
     unsafe fn deserialize_unchecked(&mut self, serialized: &[u8]) -> GlweSecretKey64 {
@@ -1052,26 +701,7 @@ impl EntityDeserializationEngine<&[u8], GlweSeededCiphertext32> for DefaultSeria
         &mut self,
         serialized: &[u8],
     ) -> Result<GlweSeededCiphertext32, EntityDeserializationError<Self::EngineError>> {
-        #[derive(Deserialize)]
-        struct DeserializableGlweSeededCiphertext32 {
-            version: GlweSeededCiphertext32Version,
-            inner: ImplGlweSeededCiphertext<Vec<u32>>,
-        }
-        let deserialized: DeserializableGlweSeededCiphertext32 = bincode::deserialize(serialized)
-            .map_err(DefaultSerializationError::Deserialization)
-            .map_err(EntityDeserializationError::Engine)?;
-        match deserialized {
-            DeserializableGlweSeededCiphertext32 {
-                version: GlweSeededCiphertext32Version::Unsupported,
-                ..
-            } => Err(EntityDeserializationError::Engine(
-                DefaultSerializationError::UnsupportedVersion,
-            )),
-            DeserializableGlweSeededCiphertext32 {
-                version: GlweSeededCiphertext32Version::V0,
-                inner,
-            } => Ok(GlweSeededCiphertext32(inner)),
-        }
+        self.deserialize_from_slice(serialized)
     }
 
     unsafe fn deserialize_unchecked(&mut self, serialized: &[u8]) -> GlweSeededCiphertext32 {
@@ -1121,26 +751,7 @@ impl EntityDeserializationEngine<&[u8], GlweSeededCiphertext64> for DefaultSeria
         &mut self,
         serialized: &[u8],
     ) -> Result<GlweSeededCiphertext64, EntityDeserializationError<Self::EngineError>> {
-        #[derive(Deserialize)]
-        struct DeserializableGlweSeededCiphertext64 {
-            version: GlweSeededCiphertext64Version,
-            inner: ImplGlweSeededCiphertext<Vec<u64>>,
-        }
-        let deserialized: DeserializableGlweSeededCiphertext64 = bincode::deserialize(serialized)
-            .map_err(DefaultSerializationError::Deserialization)
-            .map_err(EntityDeserializationError::Engine)?;
-        match deserialized {
-            DeserializableGlweSeededCiphertext64 {
-                version: GlweSeededCiphertext64Version::Unsupported,
-                ..
-            } => Err(EntityDeserializationError::Engine(
-                DefaultSerializationError::UnsupportedVersion,
-            )),
-            DeserializableGlweSeededCiphertext64 {
-                version: GlweSeededCiphertext64Version::V0,
-                inner,
-            } => Ok(GlweSeededCiphertext64(inner)),
-        }
+        self.deserialize_from_slice(serialized)
     }
 
     unsafe fn deserialize_unchecked(&mut self, serialized: &[u8]) -> GlweSeededCiphertext64 {
@@ -1148,6 +759,108 @@ impl EntityDeserializationEngine<&[u8], GlweSeededCiphertext64> for DefaultSeria
     }
 }
 
+/// # Description:
+/// Implementation of [`EntityDeserializationEngine`] for [`DefaultSerializationEngine`] that
+/// operates on 32 bits integers. It deserializes a GLWE compressed ciphertext entity.
+impl EntityDeserializationEngine<&[u8], GlweCompressedCiphertext32> for DefaultSerializationEngine {
+    /// # Example:
+    /// ```
+    /// use concrete_core::prelude::{GlweDimension, PolynomialSize, Variance, *};
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let glwe_dimension = GlweDimension(2);
+    /// let polynomial_size = PolynomialSize(4);
+    /// // There are always polynomial_size messages encrypted in the GLWE ciphertext
+    /// // Here a hard-set encoding is applied (shift by 20 bits)
+    /// let input = vec![3_u32 << 20; polynomial_size.0];
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let key: GlweSecretKey32 =
+    ///     engine.generate_new_glwe_secret_key(glwe_dimension, polynomial_size)?;
+    /// let plaintext_vector = engine.create_plaintext_vector_from(&input)?;
+    ///
+    /// let ciphertext = engine.encrypt_glwe_ciphertext(&key, &plaintext_vector, noise)?;
+    /// let compressed_ciphertext =
+    ///     engine.compress_glwe_ciphertext(&ciphertext, CiphertextModulusLog(16))?;
+    ///
+    /// let mut serialization_engine = DefaultSerializationEngine::new(())?;
+    /// let serialized = serialization_engine.serialize(&compressed_ciphertext)?;
+    /// let recovered = serialization_engine.deserialize(serialized.as_slice())?;
+    /// assert_eq!(compressed_ciphertext, recovered);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn deserialize(
+        &mut self,
+        serialized: &[u8],
+    ) -> Result<GlweCompressedCiphertext32, EntityDeserializationError<Self::EngineError>> {
+        self.deserialize_from_slice(serialized)
+    }
+
+    unsafe fn deserialize_unchecked(&mut self, serialized: &[u8]) -> GlweCompressedCiphertext32 {
+        self.deserialize(serialized).unwrap()
+    }
+}
+
+/// # Description:
+/// Implementation of [`EntityDeserializationEngine`] for [`DefaultSerializationEngine`] that
+/// operates on 64 bits integers. It deserializes a GLWE compressed ciphertext entity.
+impl EntityDeserializationEngine<&[u8], GlweCompressedCiphertext64> for DefaultSerializationEngine {
+    /// # Example:
+    /// ```
+    /// use concrete_core::prelude::{GlweDimension, PolynomialSize, Variance, *};
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let glwe_dimension = GlweDimension(2);
+    /// let polynomial_size = PolynomialSize(4);
+    /// // There are always polynomial_size messages encrypted in the GLWE ciphertext
+    /// // Here a hard-set encoding is applied (shift by 50 bits)
+    /// let input = vec![3_u64 << 50; polynomial_size.0];
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let key: GlweSecretKey64 =
+    ///     engine.generate_new_glwe_secret_key(glwe_dimension, polynomial_size)?;
+    /// let plaintext_vector = engine.create_plaintext_vector_from(&input)?;
+    ///
+    /// let ciphertext = engine.encrypt_glwe_ciphertext(&key, &plaintext_vector, noise)?;
+    /// let compressed_ciphertext =
+    ///     engine.compress_glwe_ciphertext(&ciphertext, CiphertextModulusLog(16))?;
+    ///
+    /// let mut serialization_engine = DefaultSerializationEngine::new(())?;
+    /// let serialized = serialization_engine.serialize(&compressed_ciphertext)?;
+    /// let recovered = serialization_engine.deserialize(serialized.as_slice())?;
+    /// assert_eq!(compressed_ciphertext, recovered);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn deserialize(
+        &mut self,
+        serialized: &[u8],
+    ) -> Result<GlweCompressedCiphertext64, EntityDeserializationError<Self::EngineError>> {
+        self.deserialize_from_slice(serialized)
+    }
+
+    unsafe fn deserialize_unchecked(&mut self, serialized: &[u8]) -> GlweCompressedCiphertext64 {
+        self.deserialize(serialized).unwrap()
+    }
+}
+
 /// # Description:
 /// Implementation of [`EntityDeserializationEngine`] for [`DefaultSerializationEngine`] that
 /// operates on 32 bits integers. It deserializes a GLWE seeded ciphertext vector entity.
@@ -1191,27 +904,7 @@ impl EntityDeserializationEngine<&[u8], GlweSeededCiphertextVector32>
         &mut self,
         serialized: &[u8],
     ) -> Result<GlweSeededCiphertextVector32, EntityDeserializationError<Self::EngineError>> {
-        #[derive(Deserialize)]
-        struct DeserializableGlweSeededCiphertextVector32 {
-            version: GlweSeededCiphertextVector32Version,
-            inner: ImplGlweSeededList<Vec<u32>>,
-        }
-        let deserialized: DeserializableGlweSeededCiphertextVector32 =
-            bincode::deserialize(serialized)
-                .map_err(DefaultSerializationError::Deserialization)
-                .map_err(EntityDeserializationError::Engine)?;
-        match deserialized {
-            DeserializableGlweSeededCiphertextVector32 {
-                version: GlweSeededCiphertextVector32Version::Unsupported,
-                ..
-            } => Err(EntityDeserializationError::Engine(
-                DefaultSerializationError::UnsupportedVersion,
-            )),
-            DeserializableGlweSeededCiphertextVector32 {
-                version: GlweSeededCiphertextVector32Version::V0,
-                inner,
-            } => Ok(GlweSeededCiphertextVector32(inner)),
-        }
+        self.deserialize_from_slice(serialized)
     }
 
     unsafe fn deserialize_unchecked(&mut self, serialized: &[u8]) -> GlweSeededCiphertextVector32 {
@@ -1262,27 +955,7 @@ impl EntityDeserializationEngine<&[u8], GlweSeededCiphertextVector64>
         &mut self,
         serialized: &[u8],
     ) -> Result<GlweSeededCiphertextVector64, EntityDeserializationError<Self::EngineError>> {
-        #[derive(Deserialize)]
-        struct DeserializableGlweSeededCiphertextVector64 {
-            version: GlweSeededCiphertextVector64Version,
-            inner: ImplGlweSeededList<Vec<u64>>,
-        }
-        let deserialized: DeserializableGlweSeededCiphertextVector64 =
-            bincode::deserialize(serialized)
-                .map_err(DefaultSerializationError::Deserialization)
-                .map_err(EntityDeserializationError::Engine)?;
-        match deserialized {
-            DeserializableGlweSeededCiphertextVector64 {
-                version: GlweSeededCiphertextVector64Version::Unsupported,
-                ..
-            } => Err(EntityDeserializationError::Engine(
-                DefaultSerializationError::UnsupportedVersion,
-            )),
-            DeserializableGlweSeededCiphertextVector64 {
-                version: GlweSeededCiphertextVector64Version::V0,
-                inner,
-            } => Ok(GlweSeededCiphertextVector64(inner)),
-        }
+        self.deserialize_from_slice(serialized)
     }
 
     unsafe fn deserialize_unchecked(&mut self, serialized: &[u8]) -> GlweSeededCiphertextVector64 {
@@ -1331,26 +1004,7 @@ impl EntityDeserializationEngine<&[u8], LweBootstrapKey32> for DefaultSerializat
         &mut self,
         serialized: &[u8],
     ) -> Result<LweBootstrapKey32, EntityDeserializationError<Self::EngineError>> {
-        #[derive(Deserialize)]
-        struct DeserializableLweBootstrapKey32 {
-            version: LweBootstrapKey32Version,
-            inner: ImplStandardBootstrapKey<Vec<u32>>,
-        }
-        let deserialized: DeserializableLweBootstrapKey32 = bincode::deserialize(serialized)
-            .map_err(DefaultSerializationError::Deserialization)
-            .map_err(EntityDeserializationError::Engine)?;
-        match deserialized {
-            DeserializableLweBootstrapKey32 {
-                version: LweBootstrapKey32Version::Unsupported,
-                ..
-            } => Err(EntityDeserializationError::Engine(
-                DefaultSerializationError::UnsupportedVersion,
-            )),
-            DeserializableLweBootstrapKey32 {
-                version: LweBootstrapKey32Version::V0,
-                inner,
-            } => Ok(LweBootstrapKey32(inner)),
-        }
+        self.deserialize_from_slice(serialized)
     }
 
     unsafe fn deserialize_unchecked(&mut self, serialized: &[u8]) -> LweBootstrapKey32 {
@@ -1399,26 +1053,7 @@ impl EntityDeserializationEngine<&[u8], LweBootstrapKey64> for DefaultSerializat
         &mut self,
         serialized: &[u8],
     ) -> Result<LweBootstrapKey64, EntityDeserializationError<Self::EngineError>> {
-        #[derive(Deserialize)]
-        struct DeserializableLweBootstrapKey64 {
-            version: LweBootstrapKey64Version,
-            inner: ImplStandardBootstrapKey<Vec<u64>>,
-        }
-        let deserialized: DeserializableLweBootstrapKey64 = bincode::deserialize(serialized)
-            .map_err(DefaultSerializationError::Deserialization)
-            .map_err(EntityDeserializationError::Engine)?;
-        match deserialized {
-            DeserializableLweBootstrapKey64 {
-                version: LweBootstrapKey64Version::Unsupported,
-                ..
-            } => Err(EntityDeserializationError::Engine(
-                DefaultSerializationError::UnsupportedVersion,
-            )),
-            DeserializableLweBootstrapKey64 {
-                version: LweBootstrapKey64Version::V0,
-                inner,
-            } => Ok(LweBootstrapKey64(inner)),
-        }
+        self.deserialize_from_slice(serialized)
     }
 
     unsafe fn deserialize_unchecked(&mut self, serialized: &[u8]) -> LweBootstrapKey64 {
@@ -1464,26 +1099,7 @@ impl EntityDeserializationEngine<&[u8], LweCiphertext32> for DefaultSerializatio
         &mut self,
         serialized: &[u8],
     ) -> Result<LweCiphertext32, EntityDeserializationError<Self::EngineError>> {
-        #[derive(Deserialize)]
-        struct DeserializableLweCiphertext32 {
-            version: LweCiphertext32Version,
-            inner: ImplLweCiphertext<Vec<u32>>,
-        }
-        let deserialized: DeserializableLweCiphertext32 = bincode::deserialize(serialized)
-            .map_err(DefaultSerializationError::Deserialization)
-            .map_err(EntityDeserializationError::Engine)?;
-        match deserialized {
-            DeserializableLweCiphertext32 {
-                version: LweCiphertext32Version::Unsupported,
-                ..
-            } => Err(EntityDeserializationError::Engine(
-                DefaultSerializationError::UnsupportedVersion,
-            )),
-            DeserializableLweCiphertext32 {
-                version: LweCiphertext32Version::V0,
-                inner,
-            } => Ok(LweCiphertext32(inner)),
-        }
+        self.deserialize_from_slice(serialized)
     }
 
     unsafe fn deserialize_unchecked(&mut self, serialized: &[u8]) -> LweCiphertext32 {
@@ -1530,26 +1146,7 @@ impl EntityDeserializationEngine<&[u8], LweCiphertext64> for DefaultSerializatio
         &mut self,
         serialized: &[u8],
     ) -> Result<LweCiphertext64, EntityDeserializationError<Self::EngineError>> {
-        #[derive(Deserialize)]
-        struct DeserializableLweCiphertext64 {
-            version: LweCiphertext64Version,
-            inner: ImplLweCiphertext<Vec<u64>>,
-        }
-        let deserialized: DeserializableLweCiphertext64 = bincode::deserialize(serialized)
-            .map_err(DefaultSerializationError::Deserialization)
-            .map_err(EntityDeserializationError::Engine)?;
-        match deserialized {
-            DeserializableLweCiphertext64 {
-                version: LweCiphertext64Version::Unsupported,
-                ..
-            } => Err(EntityDeserializationError::Engine(
-                DefaultSerializationError::UnsupportedVersion,
-            )),
-            DeserializableLweCiphertext64 {
-                version: LweCiphertext64Version::V0,
-                inner,
-            } => Ok(LweCiphertext64(inner)),
-        }
+        self.deserialize_from_slice(serialized)
     }
 
     unsafe fn deserialize_unchecked(&mut self, serialized: &[u8]) -> LweCiphertext64 {
@@ -1596,26 +1193,7 @@ impl EntityDeserializationEngine<&[u8], LweCiphertextVector32> for DefaultSerial
         &mut self,
         serialized: &[u8],
     ) -> Result<LweCiphertextVector32, EntityDeserializationError<Self::EngineError>> {
-        #[derive(Deserialize)]
-        struct DeserializableLweCiphertextVector32 {
-            version: LweCiphertextVector32Version,
-            inner: ImplLweList<Vec<u32>>,
-        }
-        let deserialized: DeserializableLweCiphertextVector32 = bincode::deserialize(serialized)
-            .map_err(DefaultSerializationError::Deserialization)
-            .map_err(EntityDeserializationError::Engine)?;
-        match deserialized {
-            DeserializableLweCiphertextVector32 {
-                version: LweCiphertextVector32Version::Unsupported,
-                ..
-            } => Err(EntityDeserializationError::Engine(
-                DefaultSerializationError::UnsupportedVersion,
-            )),
-            DeserializableLweCiphertextVector32 {
-                version: LweCiphertextVector32Version::V0,
-                inner,
-            } => Ok(LweCiphertextVector32(inner)),
-        }
+        self.deserialize_from_slice(serialized)
     }
 
     unsafe fn deserialize_unchecked(&mut self, serialized: &[u8]) -> LweCiphertextVector32 {
@@ -1662,26 +1240,7 @@ impl EntityDeserializationEngine<&[u8], LweCiphertextVector64> for DefaultSerial
         &mut self,
         serialized: &[u8],
     ) -> Result<LweCiphertextVector64, EntityDeserializationError<Self::EngineError>> {
-        #[derive(Deserialize)]
-        struct DeserializableLweCiphertextVector64 {
-            version: LweCiphertextVector64Version,
-            inner: ImplLweList<Vec<u64>>,
-        }
-        let deserialized: DeserializableLweCiphertextVector64 = bincode::deserialize(serialized)
-            .map_err(DefaultSerializationError::Deserialization)
-            .map_err(EntityDeserializationError::Engine)?;
-        match deserialized {
-            DeserializableLweCiphertextVector64 {
-                version: LweCiphertextVector64Version::Unsupported,
-                ..
-            } => Err(EntityDeserializationError::Engine(
-                DefaultSerializationError::UnsupportedVersion,
-            )),
-            DeserializableLweCiphertextVector64 {
-                version: LweCiphertextVector64Version::V0,
-                inner,
-            } => Ok(LweCiphertextVector64(inner)),
-        }
+        self.deserialize_from_slice(serialized)
     }
 
     unsafe fn deserialize_unchecked(&mut self, serialized: &[u8]) -> LweCiphertextVector64 {
@@ -1749,30 +1308,7 @@ impl EntityDeserializationEngine<&[u8], LweCircuitBootstrapPrivateFunctionalPack
         LweCircuitBootstrapPrivateFunctionalPackingKeyswitchKeys32,
         EntityDeserializationError<Self::EngineError>,
     > {
-        #[derive(Deserialize)]
-        struct DeserializableLweCircuitBootstrapPrivateFunctionalPackingKeyswitchKeys32 {
-            version: LweCircuitBootstrapPrivateFunctionalPackingKeyswitchKeys32Version,
-            inner: ImplLweCircuitBoostrapPrivateFunctionalPackingKeyswitchKeys<Vec<u32>>,
-        }
-        let deserialized: DeserializableLweCircuitBootstrapPrivateFunctionalPackingKeyswitchKeys32 =
-            bincode::deserialize(serialized)
-                .map_err(DefaultSerializationError::Deserialization)
-                .map_err(EntityDeserializationError::Engine)?;
-        match deserialized {
-            DeserializableLweCircuitBootstrapPrivateFunctionalPackingKeyswitchKeys32 {
-                version:
-                    LweCircuitBootstrapPrivateFunctionalPackingKeyswitchKeys32Version::Unsupported,
-                ..
-            } => Err(EntityDeserializationError::Engine(
-                DefaultSerializationError::UnsupportedVersion,
-            )),
-            DeserializableLweCircuitBootstrapPrivateFunctionalPackingKeyswitchKeys32 {
-                version: LweCircuitBootstrapPrivateFunctionalPackingKeyswitchKeys32Version::V0,
-                inner,
-            } => Ok(LweCircuitBootstrapPrivateFunctionalPackingKeyswitchKeys32(
-                inner,
-            )),
-        }
+        self.deserialize_from_slice(serialized)
     }
 
     unsafe fn deserialize_unchecked(
@@ -1843,30 +1379,7 @@ impl EntityDeserializationEngine<&[u8], LweCircuitBootstrapPrivateFunctionalPack
         LweCircuitBootstrapPrivateFunctionalPackingKeyswitchKeys64,
         EntityDeserializationError<Self::EngineError>,
     > {
-        #[derive(Deserialize)]
-        struct DeserializableLweCircuitBootstrapPrivateFunctionalPackingKeyswitchKeys64 {
-            version: LweCircuitBootstrapPrivateFunctionalPackingKeyswitchKeys64Version,
-            inner: ImplLweCircuitBoostrapPrivateFunctionalPackingKeyswitchKeys<Vec<u64>>,
-        }
-        let deserialized: DeserializableLweCircuitBootstrapPrivateFunctionalPackingKeyswitchKeys64 =
-            bincode::deserialize(serialized)
-                .map_err(DefaultSerializationError::Deserialization)
-                .map_err(EntityDeserializationError::Engine)?;
-        match deserialized {
-            DeserializableLweCircuitBootstrapPrivateFunctionalPackingKeyswitchKeys64 {
-                version:
-                    LweCircuitBootstrapPrivateFunctionalPackingKeyswitchKeys64Version::Unsupported,
-                ..
-            } => Err(EntityDeserializationError::Engine(
-                DefaultSerializationError::UnsupportedVersion,
-            )),
-            DeserializableLweCircuitBootstrapPrivateFunctionalPackingKeyswitchKeys64 {
-                version: LweCircuitBootstrapPrivateFunctionalPackingKeyswitchKeys64Version::V0,
-                inner,
-            } => Ok(LweCircuitBootstrapPrivateFunctionalPackingKeyswitchKeys64(
-                inner,
-            )),
-        }
+        self.deserialize_from_slice(serialized)
     }
 
     unsafe fn deserialize_unchecked(
@@ -1924,26 +1437,7 @@ impl EntityDeserializationEngine<&[u8], LweKeyswitchKey32> for DefaultSerializat
         &mut self,
         serialized: &[u8],
     ) -> Result<LweKeyswitchKey32, EntityDeserializationError<Self::EngineError>> {
-        #[derive(Deserialize)]
-        struct DeserializableLweKeyswitchKey32 {
-            version: LweKeyswitchKey32Version,
-            inner: ImplLweKeyswitchKey<Vec<u32>>,
-        }
-        let deserialized: DeserializableLweKeyswitchKey32 = bincode::deserialize(serialized)
-            .map_err(DefaultSerializationError::Deserialization)
-            .map_err(EntityDeserializationError::Engine)?;
-        match deserialized {
-            DeserializableLweKeyswitchKey32 {
-                version: LweKeyswitchKey32Version::Unsupported,
-                ..
-            } => Err(EntityDeserializationError::Engine(
-                DefaultSerializationError::UnsupportedVersion,
-            )),
-            DeserializableLweKeyswitchKey32 {
-                version: LweKeyswitchKey32Version::V0,
-                inner,
-            } => Ok(LweKeyswitchKey32(inner)),
-        }
+        self.deserialize_from_slice(serialized)
     }
 
     unsafe fn deserialize_unchecked(&mut self, serialized: &[u8]) -> LweKeyswitchKey32 {
@@ -1998,26 +1492,7 @@ impl EntityDeserializationEngine<&[u8], LweKeyswitchKey64> for DefaultSerializat
         &mut self,
         serialized: &[u8],
     ) -> Result<LweKeyswitchKey64, EntityDeserializationError<Self::EngineError>> {
-        #[derive(Deserialize)]
-        struct DeserializableLweKeyswitchKey64 {
-            version: LweKeyswitchKey64Version,
-            inner: ImplLweKeyswitchKey<Vec<u64>>,
-        }
-        let deserialized: DeserializableLweKeyswitchKey64 = bincode::deserialize(serialized)
-            .map_err(DefaultSerializationError::Deserialization)
-            .map_err(EntityDeserializationError::Engine)?;
-        match deserialized {
-            DeserializableLweKeyswitchKey64 {
-                version: LweKeyswitchKey64Version::Unsupported,
-                ..
-            } => Err(EntityDeserializationError::Engine(
-                DefaultSerializationError::UnsupportedVersion,
-            )),
-            DeserializableLweKeyswitchKey64 {
-                version: LweKeyswitchKey64Version::V0,
-                inner,
-            } => Ok(LweKeyswitchKey64(inner)),
-        }
+        self.deserialize_from_slice(serialized)
     }
 
     unsafe fn deserialize_unchecked(&mut self, serialized: &[u8]) -> LweKeyswitchKey64 {
@@ -2057,26 +1532,7 @@ impl EntityDeserializationEngine<&[u8], LweSecretKey32> for DefaultSerialization
         &mut self,
         serialized: &[u8],
     ) -> Result<LweSecretKey32, EntityDeserializationError<Self::EngineError>> {
-        #[derive(Deserialize)]
-        struct DeserializableLweSecretKey32 {
-            version: LweSecretKey32Version,
-            inner: ImplLweSecretKey<BinaryKeyKind, Vec<u32>>,
-        }
-        let deserialized: DeserializableLweSecretKey32 = bincode::deserialize(serialized)
-            .map_err(DefaultSerializationError::Deserialization)
-            .map_err(EntityDeserializationError::Engine)?;
-        match deserialized {
-            DeserializableLweSecretKey32 {
-                version: LweSecretKey32Version::Unsupported,
-                ..
-            } => Err(EntityDeserializationError::Engine(
-                DefaultSerializationError::UnsupportedVersion,
-            )),
-            DeserializableLweSecretKey32 {
-                version: LweSecretKey32Version::V0,
-                inner,
-            } => Ok(LweSecretKey32(inner)),
-        }
+        self.deserialize_from_slice(serialized)
     }
 
     unsafe fn deserialize_unchecked(&mut self, serialized: &[u8]) -> LweSecretKey32 {
@@ -2116,26 +1572,7 @@ impl EntityDeserializationEngine<&[u8], LweSecretKey64> for DefaultSerialization
         &mut self,
         serialized: &[u8],
     ) -> Result<LweSecretKey64, EntityDeserializationError<Self::EngineError>> {
-        #[derive(Deserialize)]
-        struct DeserializableLweSecretKey64 {
-            version: LweSecretKey64Version,
-            inner: ImplLweSecretKey<BinaryKeyKind, Vec<u64>>,
-        }
-        let deserialized: DeserializableLweSecretKey64 = bincode::deserialize(serialized)
-            .map_err(DefaultSerializationError::Deserialization)
-            .map_err(EntityDeserializationError::Engine)?;
-        match deserialized {
-            DeserializableLweSecretKey64 {
-                version: LweSecretKey64Version::Unsupported,
-                ..
-            } => Err(EntityDeserializationError::Engine(
-                DefaultSerializationError::UnsupportedVersion,
-            )),
-            DeserializableLweSecretKey64 {
-                version: LweSecretKey64Version::V0,
-                inner,
-            } => Ok(LweSecretKey64(inner)),
-        }
+        self.deserialize_from_slice(serialized)
     }
 
     unsafe fn deserialize_unchecked(&mut self, serialized: &[u8]) -> LweSecretKey64 {
@@ -2185,26 +1622,7 @@ impl EntityDeserializationEngine<&[u8], LweSeededBootstrapKey32> for DefaultSeri
         &mut self,
         serialized: &[u8],
     ) -> Result<LweSeededBootstrapKey32, EntityDeserializationError<Self::EngineError>> {
-        #[derive(Deserialize)]
-        struct DeserializableLweSeededBootstrapKey32 {
-            version: LweSeededBootstrapKey32Version,
-            inner: ImplStandardSeededBootstrapKey<Vec<u32>>,
-        }
-        let deserialized: DeserializableLweSeededBootstrapKey32 = bincode::deserialize(serialized)
-            .map_err(DefaultSerializationError::Deserialization)
-            .map_err(EntityDeserializationError::Engine)?;
-        match deserialized {
-            DeserializableLweSeededBootstrapKey32 {
-                version: LweSeededBootstrapKey32Version::Unsupported,
-                ..
-            } => Err(EntityDeserializationError::Engine(
-                DefaultSerializationError::UnsupportedVersion,
-            )),
-            DeserializableLweSeededBootstrapKey32 {
-                version: LweSeededBootstrapKey32Version::V0,
-                inner,
-            } => Ok(LweSeededBootstrapKey32(inner)),
-        }
+        self.deserialize_from_slice(serialized)
     }
 
     unsafe fn deserialize_unchecked(&mut self, serialized: &[u8]) -> LweSeededBootstrapKey32 {
@@ -2254,26 +1672,7 @@ impl EntityDeserializationEngine<&[u8], LweSeededBootstrapKey64> for DefaultSeri
         &mut self,
         serialized: &[u8],
     ) -> Result<LweSeededBootstrapKey64, EntityDeserializationError<Self::EngineError>> {
-        #[derive(Deserialize)]
-        struct DeserializableLweSeededBootstrapKey64 {
-            version: LweSeededBootstrapKey64Version,
-            inner: ImplStandardSeededBootstrapKey<Vec<u64>>,
-        }
-        let deserialized: DeserializableLweSeededBootstrapKey64 = bincode::deserialize(serialized)
-            .map_err(DefaultSerializationError::Deserialization)
-            .map_err(EntityDeserializationError::Engine)?;
-        match deserialized {
-            DeserializableLweSeededBootstrapKey64 {
-                version: LweSeededBootstrapKey64Version::Unsupported,
-                ..
-            } => Err(EntityDeserializationError::Engine(
-                DefaultSerializationError::UnsupportedVersion,
-            )),
-            DeserializableLweSeededBootstrapKey64 {
-                version: LweSeededBootstrapKey64Version::V0,
-                inner,
-            } => Ok(LweSeededBootstrapKey64(inner)),
-        }
+        self.deserialize_from_slice(serialized)
     }
 
     unsafe fn deserialize_unchecked(&mut self, serialized: &[u8]) -> LweSeededBootstrapKey64 {
@@ -2320,26 +1719,7 @@ impl EntityDeserializationEngine<&[u8], LweSeededCiphertext32> for DefaultSerial
         &mut self,
         serialized: &[u8],
     ) -> Result<LweSeededCiphertext32, EntityDeserializationError<Self::EngineError>> {
-        #[derive(Deserialize)]
-        struct DeserializableLweSeededCiphertext32 {
-            version: LweSeededCiphertext32Version,
-            inner: ImplLweSeededCiphertext<u32>,
-        }
-        let deserialized: DeserializableLweSeededCiphertext32 = bincode::deserialize(serialized)
-            .map_err(DefaultSerializationError::Deserialization)
-            .map_err(EntityDeserializationError::Engine)?;
-        match deserialized {
-            DeserializableLweSeededCiphertext32 {
-                version: LweSeededCiphertext32Version::Unsupported,
-                ..
-            } => Err(EntityDeserializationError::Engine(
-                DefaultSerializationError::UnsupportedVersion,
-            )),
-            DeserializableLweSeededCiphertext32 {
-                version: LweSeededCiphertext32Version::V0,
-                inner,
-            } => Ok(LweSeededCiphertext32(inner)),
-        }
+        self.deserialize_from_slice(serialized)
     }
 
     unsafe fn deserialize_unchecked(&mut self, serialized: &[u8]) -> LweSeededCiphertext32 {
@@ -2386,26 +1766,7 @@ impl EntityDeserializationEngine<&[u8], LweSeededCiphertext64> for DefaultSerial
         &mut self,
         serialized: &[u8],
     ) -> Result<LweSeededCiphertext64, EntityDeserializationError<Self::EngineError>> {
-        #[derive(Deserialize)]
-        struct DeserializableLweSeededCiphertext64 {
-            version: LweSeededCiphertext64Version,
-            inner: ImplLweSeededCiphertext<u64>,
-        }
-        let deserialized: DeserializableLweSeededCiphertext64 = bincode::deserialize(serialized)
-            .map_err(DefaultSerializationError::Deserialization)
-            .map_err(EntityDeserializationError::Engine)?;
-        match deserialized {
-            DeserializableLweSeededCiphertext64 {
-                version: LweSeededCiphertext64Version::Unsupported,
-                ..
-            } => Err(EntityDeserializationError::Engine(
-                DefaultSerializationError::UnsupportedVersion,
-            )),
-            DeserializableLweSeededCiphertext64 {
-                version: LweSeededCiphertext64Version::V0,
-                inner,
-            } => Ok(LweSeededCiphertext64(inner)),
-        }
+        self.deserialize_from_slice(serialized)
     }
 
     unsafe fn deserialize_unchecked(&mut self, serialized: &[u8]) -> LweSeededCiphertext64 {
@@ -2413,6 +1774,102 @@ impl EntityDeserializationEngine<&[u8], LweSeededCiphertext64> for DefaultSerial
     }
 }
 
+/// # Description:
+/// Implementation of [`EntityDeserializationEngine`] for [`DefaultSerializationEngine`] that
+/// operates on 32 bits integers. It deserializes a compressed LWE ciphertext entity.
+impl EntityDeserializationEngine<&[u8], LweCompressedCiphertext32> for DefaultSerializationEngine {
+    /// # Example:
+    /// ```
+    /// use concrete_core::prelude::{LweDimension, Variance, *};
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let lwe_dimension = LweDimension(2);
+    /// // Here a hard-set encoding is applied (shift by 20 bits)
+    /// let input = 3_u32 << 20;
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let key: LweSecretKey32 = engine.generate_new_lwe_secret_key(lwe_dimension)?;
+    /// let plaintext = engine.create_plaintext_from(&input)?;
+    ///
+    /// let ciphertext = engine.encrypt_lwe_ciphertext(&key, &plaintext, noise)?;
+    /// let compressed_ciphertext: LweCompressedCiphertext32 =
+    ///     engine.compress_lwe_ciphertext(&ciphertext, CiphertextModulusLog(16))?;
+    ///
+    /// let mut serialization_engine = DefaultSerializationEngine::new(())?;
+    /// let serialized = serialization_engine.serialize(&compressed_ciphertext)?;
+    /// let recovered = serialization_engine.deserialize(serialized.as_slice())?;
+    /// assert_eq!(compressed_ciphertext, recovered);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn deserialize(
+        &mut self,
+        serialized: &[u8],
+    ) -> Result<LweCompressedCiphertext32, EntityDeserializationError<Self::EngineError>> {
+        self.deserialize_from_slice(serialized)
+    }
+
+    unsafe fn deserialize_unchecked(&mut self, serialized: &[u8]) -> LweCompressedCiphertext32 {
+        self.deserialize(serialized).unwrap()
+    }
+}
+
+/// # Description:
+/// Implementation of [`EntityDeserializationEngine`] for [`DefaultSerializationEngine`] that
+/// operates on 64 bits integers. It deserializes a compressed LWE ciphertext entity.
+impl EntityDeserializationEngine<&[u8], LweCompressedCiphertext64> for DefaultSerializationEngine {
+    /// # Example:
+    /// ```
+    /// use concrete_core::prelude::{LweDimension, Variance, *};
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let lwe_dimension = LweDimension(2);
+    /// // Here a hard-set encoding is applied (shift by 50 bits)
+    /// let input = 3_u64 << 50;
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let key: LweSecretKey64 = engine.generate_new_lwe_secret_key(lwe_dimension)?;
+    /// let plaintext = engine.create_plaintext_from(&input)?;
+    ///
+    /// let ciphertext = engine.encrypt_lwe_ciphertext(&key, &plaintext, noise)?;
+    /// let compressed_ciphertext: LweCompressedCiphertext64 =
+    ///     engine.compress_lwe_ciphertext(&ciphertext, CiphertextModulusLog(16))?;
+    ///
+    /// let mut serialization_engine = DefaultSerializationEngine::new(())?;
+    /// let serialized = serialization_engine.serialize(&compressed_ciphertext)?;
+    /// let recovered = serialization_engine.deserialize(serialized.as_slice())?;
+    /// assert_eq!(compressed_ciphertext, recovered);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn deserialize(
+        &mut self,
+        serialized: &[u8],
+    ) -> Result<LweCompressedCiphertext64, EntityDeserializationError<Self::EngineError>> {
+        self.deserialize_from_slice(serialized)
+    }
+
+    unsafe fn deserialize_unchecked(&mut self, serialized: &[u8]) -> LweCompressedCiphertext64 {
+        self.deserialize(serialized).unwrap()
+    }
+}
+
 /// # Description:
 /// Implementation of [`EntityDeserializationEngine`] for [`DefaultSerializationEngine`] that
 /// operates on 32 bits integers. It deserializes a seeded LWE ciphertext vector entity.
@@ -2454,27 +1911,7 @@ impl EntityDeserializationEngine<&[u8], LweSeededCiphertextVector32>
         &mut self,
         serialized: &[u8],
     ) -> Result<LweSeededCiphertextVector32, EntityDeserializationError<Self::EngineError>> {
-        #[derive(Deserialize)]
-        struct DeserializableLweSeededCiphertextVector32 {
-            version: LweSeededCiphertextVector32Version,
-            inner: ImplLweSeededList<Vec<u32>>,
-        }
-        let deserialized: DeserializableLweSeededCiphertextVector32 =
-            bincode::deserialize(serialized)
-                .map_err(DefaultSerializationError::Deserialization)
-                .map_err(EntityDeserializationError::Engine)?;
-        match deserialized {
-            DeserializableLweSeededCiphertextVector32 {
-                version: LweSeededCiphertextVector32Version::Unsupported,
-                ..
-            } => Err(EntityDeserializationError::Engine(
-                DefaultSerializationError::UnsupportedVersion,
-            )),
-            DeserializableLweSeededCiphertextVector32 {
-                version: LweSeededCiphertextVector32Version::V0,
-                inner,
-            } => Ok(LweSeededCiphertextVector32(inner)),
-        }
+        self.deserialize_from_slice(serialized)
     }
 
     unsafe fn deserialize_unchecked(&mut self, serialized: &[u8]) -> LweSeededCiphertextVector32 {
@@ -2523,27 +1960,7 @@ impl EntityDeserializationEngine<&[u8], LweSeededCiphertextVector64>
         &mut self,
         serialized: &[u8],
     ) -> Result<LweSeededCiphertextVector64, EntityDeserializationError<Self::EngineError>> {
-        #[derive(Deserialize)]
-        struct DeserializableLweSeededCiphertextVector64 {
-            version: LweSeededCiphertextVector64Version,
-            inner: ImplLweSeededList<Vec<u64>>,
-        }
-        let deserialized: DeserializableLweSeededCiphertextVector64 =
-            bincode::deserialize(serialized)
-                .map_err(DefaultSerializationError::Deserialization)
-                .map_err(EntityDeserializationError::Engine)?;
-        match deserialized {
-            DeserializableLweSeededCiphertextVector64 {
-                version: LweSeededCiphertextVector64Version::Unsupported,
-                ..
-            } => Err(EntityDeserializationError::Engine(
-                DefaultSerializationError::UnsupportedVersion,
-            )),
-            DeserializableLweSeededCiphertextVector64 {
-                version: LweSeededCiphertextVector64Version::V0,
-                inner,
-            } => Ok(LweSeededCiphertextVector64(inner)),
-        }
+        self.deserialize_from_slice(serialized)
     }
 
     unsafe fn deserialize_unchecked(&mut self, serialized: &[u8]) -> LweSeededCiphertextVector64 {
@@ -2598,26 +2015,7 @@ impl EntityDeserializationEngine<&[u8], LweSeededKeyswitchKey32> for DefaultSeri
         &mut self,
         serialized: &[u8],
     ) -> Result<LweSeededKeyswitchKey32, EntityDeserializationError<Self::EngineError>> {
-        #[derive(Deserialize)]
-        struct DeserializableLweSeededKeyswitchKey32 {
-            version: LweSeededKeyswitchKey32Version,
-            inner: ImplLweSeededKeyswitchKey<Vec<u32>>,
-        }
-        let deserialized: DeserializableLweSeededKeyswitchKey32 = bincode::deserialize(serialized)
-            .map_err(DefaultSerializationError::Deserialization)
-            .map_err(EntityDeserializationError::Engine)?;
-        match deserialized {
-            DeserializableLweSeededKeyswitchKey32 {
-                version: LweSeededKeyswitchKey32Version::Unsupported,
-                ..
-            } => Err(EntityDeserializationError::Engine(
-                DefaultSerializationError::UnsupportedVersion,
-            )),
-            DeserializableLweSeededKeyswitchKey32 {
-                version: LweSeededKeyswitchKey32Version::V0,
-                inner,
-            } => Ok(LweSeededKeyswitchKey32(inner)),
-        }
+        self.deserialize_from_slice(serialized)
     }
 
     unsafe fn deserialize_unchecked(&mut self, serialized: &[u8]) -> LweSeededKeyswitchKey32 {
@@ -2672,26 +2070,7 @@ impl EntityDeserializationEngine<&[u8], LweSeededKeyswitchKey64> for DefaultSeri
         &mut self,
         serialized: &[u8],
     ) -> Result<LweSeededKeyswitchKey64, EntityDeserializationError<Self::EngineError>> {
-        #[derive(Deserialize)]
-        struct DeserializableLweSeededKeyswitchKey64 {
-            version: LweSeededKeyswitchKey64Version,
-            inner: ImplLweSeededKeyswitchKey<Vec<u64>>,
-        }
-        let deserialized: DeserializableLweSeededKeyswitchKey64 = bincode::deserialize(serialized)
-            .map_err(DefaultSerializationError::Deserialization)
-            .map_err(EntityDeserializationError::Engine)?;
-        match deserialized {
-            DeserializableLweSeededKeyswitchKey64 {
-                version: LweSeededKeyswitchKey64Version::Unsupported,
-                ..
-            } => Err(EntityDeserializationError::Engine(
-                DefaultSerializationError::UnsupportedVersion,
-            )),
-            DeserializableLweSeededKeyswitchKey64 {
-                version: LweSeededKeyswitchKey64Version::V0,
-                inner,
-            } => Ok(LweSeededKeyswitchKey64(inner)),
-        }
+        self.deserialize_from_slice(serialized)
     }
 
     unsafe fn deserialize_unchecked(&mut self, serialized: &[u8]) -> LweSeededKeyswitchKey64 {
@@ -2746,26 +2125,7 @@ impl EntityDeserializationEngine<&[u8], LwePackingKeyswitchKey32> for DefaultSer
         &mut self,
         serialized: &[u8],
     ) -> Result<LwePackingKeyswitchKey32, EntityDeserializationError<Self::EngineError>> {
-        #[derive(Deserialize)]
-        struct DeserializablePackingKeyswitchKey32 {
-            version: LwePackingKeyswitchKey32Version,
-            inner: ImplLwePackingKeyswitchKey<Vec<u32>>,
-        }
-        let deserialized: DeserializablePackingKeyswitchKey32 = bincode::deserialize(serialized)
-            .map_err(DefaultSerializationError::Deserialization)
-            .map_err(EntityDeserializationError::Engine)?;
-        match deserialized {
-            DeserializablePackingKeyswitchKey32 {
-                version: LwePackingKeyswitchKey32Version::Unsupported,
-                ..
-            } => Err(EntityDeserializationError::Engine(
-                DefaultSerializationError::UnsupportedVersion,
-            )),
-            DeserializablePackingKeyswitchKey32 {
-                version: LwePackingKeyswitchKey32Version::V0,
-                inner,
-            } => Ok(LwePackingKeyswitchKey32(inner)),
-        }
+        self.deserialize_from_slice(serialized)
     }
 
     unsafe fn deserialize_unchecked(&mut self, serialized: &[u8]) -> LwePackingKeyswitchKey32 {
@@ -2820,26 +2180,7 @@ impl EntityDeserializationEngine<&[u8], LwePackingKeyswitchKey64> for DefaultSer
         &mut self,
         serialized: &[u8],
     ) -> Result<LwePackingKeyswitchKey64, EntityDeserializationError<Self::EngineError>> {
-        #[derive(Deserialize)]
-        struct DeserializablePackingKeyswitchKey64 {
-            version: LwePackingKeyswitchKey64Version,
-            inner: ImplLwePackingKeyswitchKey<Vec<u64>>,
-        }
-        let deserialized: DeserializablePackingKeyswitchKey64 = bincode::deserialize(serialized)
-            .map_err(DefaultSerializationError::Deserialization)
-            .map_err(EntityDeserializationError::Engine)?;
-        match deserialized {
-            DeserializablePackingKeyswitchKey64 {
-                version: LwePackingKeyswitchKey64Version::Unsupported,
-                ..
-            } => Err(EntityDeserializationError::Engine(
-                DefaultSerializationError::UnsupportedVersion,
-            )),
-            DeserializablePackingKeyswitchKey64 {
-                version: LwePackingKeyswitchKey64Version::V0,
-                inner,
-            } => Ok(LwePackingKeyswitchKey64(inner)),
-        }
+        self.deserialize_from_slice(serialized)
     }
 
     unsafe fn deserialize_unchecked(&mut self, serialized: &[u8]) -> LwePackingKeyswitchKey64 {
@@ -2887,26 +2228,7 @@ impl EntityDeserializationEngine<&[u8], LwePublicKey32> for DefaultSerialization
         &mut self,
         serialized: &[u8],
     ) -> Result<LwePublicKey32, EntityDeserializationError<Self::EngineError>> {
-        #[derive(Deserialize)]
-        struct DeserializableLwePublicKey32 {
-            version: LwePublicKey32Version,
-            inner: ImplLweList<Vec<u32>>,
-        }
-        let deserialized: DeserializableLwePublicKey32 = bincode::deserialize(serialized)
-            .map_err(DefaultSerializationError::Deserialization)
-            .map_err(EntityDeserializationError::Engine)?;
-        match deserialized {
-            DeserializableLwePublicKey32 {
-                version: LwePublicKey32Version::Unsupported,
-                ..
-            } => Err(EntityDeserializationError::Engine(
-                DefaultSerializationError::UnsupportedVersion,
-            )),
-            DeserializableLwePublicKey32 {
-                version: LwePublicKey32Version::V0,
-                inner,
-            } => Ok(LwePublicKey32(inner)),
-        }
+        self.deserialize_from_slice(serialized)
     }
 
     unsafe fn deserialize_unchecked(&mut self, serialized: &[u8]) -> LwePublicKey32 {
@@ -2954,26 +2276,7 @@ impl EntityDeserializationEngine<&[u8], LwePublicKey64> for DefaultSerialization
         &mut self,
         serialized: &[u8],
     ) -> Result<LwePublicKey64, EntityDeserializationError<Self::EngineError>> {
-        #[derive(Deserialize)]
-        struct DeserializableLwePublicKey64 {
-            version: LwePublicKey64Version,
-            inner: ImplLweList<Vec<u64>>,
-        }
-        let deserialized: DeserializableLwePublicKey64 = bincode::deserialize(serialized)
-            .map_err(DefaultSerializationError::Deserialization)
-            .map_err(EntityDeserializationError::Engine)?;
-        match deserialized {
-            DeserializableLwePublicKey64 {
-                version: LwePublicKey64Version::Unsupported,
-                ..
-            } => Err(EntityDeserializationError::Engine(
-                DefaultSerializationError::UnsupportedVersion,
-            )),
-            DeserializableLwePublicKey64 {
-                version: LwePublicKey64Version::V0,
-                inner,
-            } => Ok(LwePublicKey64(inner)),
-        }
+        self.deserialize_from_slice(serialized)
     }
 
     unsafe fn deserialize_unchecked(&mut self, serialized: &[u8]) -> LwePublicKey64 {
@@ -3013,26 +2316,7 @@ impl EntityDeserializationEngine<&[u8], Plaintext32> for DefaultSerializationEng
         &mut self,
         serialized: &[u8],
     ) -> Result<Plaintext32, EntityDeserializationError<Self::EngineError>> {
-        #[derive(Deserialize)]
-        struct DeserializablePlaintext32 {
-            version: Plaintext32Version,
-            inner: ImplPlaintext<u32>,
-        }
-        let deserialized: DeserializablePlaintext32 = bincode::deserialize(serialized)
-            .map_err(DefaultSerializationError::Deserialization)
-            .map_err(EntityDeserializationError::Engine)?;
-        match deserialized {
-            DeserializablePlaintext32 {
-                version: Plaintext32Version::Unsupported,
-                ..
-            } => Err(EntityDeserializationError::Engine(
-                DefaultSerializationError::UnsupportedVersion,
-            )),
-            DeserializablePlaintext32 {
-                version: Plaintext32Version::V0,
-                inner,
-            } => Ok(Plaintext32(inner)),
-        }
+        self.deserialize_from_slice(serialized)
     }
 
     unsafe fn deserialize_unchecked(&mut self, serialized: &[u8]) -> Plaintext32 {
@@ -3071,26 +2355,7 @@ impl EntityDeserializationEngine<&[u8], Plaintext64> for DefaultSerializationEng
         &mut self,
         serialized: &[u8],
     ) -> Result<Plaintext64, EntityDeserializationError<Self::EngineError>> {
-        #[derive(Deserialize)]
-        struct DeserializablePlaintext64 {
-            version: Plaintext64Version,
-            inner: ImplPlaintext<u64>,
-        }
-        let deserialized: DeserializablePlaintext64 = bincode::deserialize(serialized)
-            .map_err(DefaultSerializationError::Deserialization)
-            .map_err(EntityDeserializationError::Engine)?;
-        match deserialized {
-            DeserializablePlaintext64 {
-                version: Plaintext64Version::Unsupported,
-                ..
-            } => Err(EntityDeserializationError::Engine(
-                DefaultSerializationError::UnsupportedVersion,
-            )),
-            DeserializablePlaintext64 {
-                version: Plaintext64Version::V0,
-                inner,
-            } => Ok(Plaintext64(inner)),
-        }
+        self.deserialize_from_slice(serialized)
     }
 
     unsafe fn deserialize_unchecked(&mut self, serialized: &[u8]) -> Plaintext64 {
@@ -3130,26 +2395,7 @@ impl EntityDeserializationEngine<&[u8], PlaintextVector32> for DefaultSerializat
         &mut self,
         serialized: &[u8],
     ) -> Result<PlaintextVector32, EntityDeserializationError<Self::EngineError>> {
-        #[derive(Deserialize)]
-        struct DeserializablePlaintextVector32 {
-            version: PlaintextVector32Version,
-            inner: ImplPlaintextList<Vec<u32>>,
-        }
-        let deserialized: DeserializablePlaintextVector32 = bincode::deserialize(serialized)
-            .map_err(DefaultSerializationError::Deserialization)
-            .map_err(EntityDeserializationError::Engine)?;
-        match deserialized {
-            DeserializablePlaintextVector32 {
-                version: PlaintextVector32Version::Unsupported,
-                ..
-            } => Err(EntityDeserializationError::Engine(
-                DefaultSerializationError::UnsupportedVersion,
-            )),
-            DeserializablePlaintextVector32 {
-                version: PlaintextVector32Version::V0,
-                inner,
-            } => Ok(PlaintextVector32(inner)),
-        }
+        self.deserialize_from_slice(serialized)
     }
 
     unsafe fn deserialize_unchecked(&mut self, serialized: &[u8]) -> PlaintextVector32 {
@@ -3189,26 +2435,7 @@ impl EntityDeserializationEngine<&[u8], PlaintextVector64> for DefaultSerializat
         &mut self,
         serialized: &[u8],
     ) -> Result<PlaintextVector64, EntityDeserializationError<Self::EngineError>> {
-        #[derive(Deserialize)]
-        struct DeserializablePlaintextVector64 {
-            version: PlaintextVector64Version,
-            inner: ImplPlaintextList<Vec<u64>>,
-        }
-        let deserialized: DeserializablePlaintextVector64 = bincode::deserialize(serialized)
-            .map_err(DefaultSerializationError::Deserialization)
-            .map_err(EntityDeserializationError::Engine)?;
-        match deserialized {
-            DeserializablePlaintextVector64 {
-                version: PlaintextVector64Version::Unsupported,
-                ..
-            } => Err(EntityDeserializationError::Engine(
-                DefaultSerializationError::UnsupportedVersion,
-            )),
-            DeserializablePlaintextVector64 {
-                version: PlaintextVector64Version::V0,
-                inner,
-            } => Ok(PlaintextVector64(inner)),
-        }
+        self.deserialize_from_slice(serialized)
     }
 
     unsafe fn deserialize_unchecked(&mut self, serialized: &[u8]) -> PlaintextVector64 {
@@ -3251,26 +2478,7 @@ impl EntityDeserializationEngine<&[u8], FloatEncoder> for DefaultSerializationEn
         &mut self,
         serialized: &[u8],
     ) -> Result<FloatEncoder, EntityDeserializationError<Self::EngineError>> {
-        #[derive(Deserialize)]
-        struct DeserializableFloatEncoder {
-            version: FloatEncoderVersion,
-            inner: ImplFloatEncoder,
-        }
-        let deserialized: DeserializableFloatEncoder = bincode::deserialize(serialized)
-            .map_err(DefaultSerializationError::Deserialization)
-            .map_err(EntityDeserializationError::Engine)?;
-        match deserialized {
-            DeserializableFloatEncoder {
-                version: FloatEncoderVersion::Unsupported,
-                ..
-            } => Err(EntityDeserializationError::Engine(
-                DefaultSerializationError::UnsupportedVersion,
-            )),
-            DeserializableFloatEncoder {
-                version: FloatEncoderVersion::V0,
-                inner,
-            } => Ok(FloatEncoder(inner)),
-        }
+        self.deserialize_from_slice(serialized)
     }
 
     unsafe fn deserialize_unchecked(&mut self, serialized: &[u8]) -> FloatEncoder {
@@ -3317,26 +2525,7 @@ impl EntityDeserializationEngine<&[u8], FloatEncoderVector> for DefaultSerializa
         &mut self,
         serialized: &[u8],
     ) -> Result<FloatEncoderVector, EntityDeserializationError<Self::EngineError>> {
-        #[derive(Deserialize)]
-        struct DeserializableFloatEncoderVector {
-            version: FloatEncoderVectorVersion,
-            inner: Vec<ImplFloatEncoder>,
-        }
-        let deserialized: DeserializableFloatEncoderVector = bincode::deserialize(serialized)
-            .map_err(DefaultSerializationError::Deserialization)
-            .map_err(EntityDeserializationError::Engine)?;
-        match deserialized {
-            DeserializableFloatEncoderVector {
-                version: FloatEncoderVectorVersion::Unsupported,
-                ..
-            } => Err(EntityDeserializationError::Engine(
-                DefaultSerializationError::UnsupportedVersion,
-            )),
-            DeserializableFloatEncoderVector {
-                version: FloatEncoderVectorVersion::V0,
-                inner,
-            } => Ok(FloatEncoderVector(inner)),
-        }
+        self.deserialize_from_slice(serialized)
     }
 
     unsafe fn deserialize_unchecked(&mut self, serialized: &[u8]) -> FloatEncoderVector {
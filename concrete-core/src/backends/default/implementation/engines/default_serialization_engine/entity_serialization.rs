@@ -1,73 +1,27 @@
 #![allow(clippy::missing_safety_doc)]
 
-use crate::commons::crypto::bootstrap::{
-    StandardBootstrapKey as ImplStandardBootstrapKey,
-    StandardSeededBootstrapKey as ImplStandardSeededBootstrapKey,
-};
-use crate::commons::crypto::encoding::{
-    Cleartext as ImplCleartext, CleartextList as ImplCleartextList,
-    FloatEncoder as ImplFloatEncoder, Plaintext as ImplPlaintext,
-    PlaintextList as ImplPlaintextList,
-};
-use crate::commons::crypto::ggsw::{
-    StandardGgswCiphertext as ImplStandardGgswCiphertext,
-    StandardGgswSeededCiphertext as ImplStandardGgswSeededCiphertext,
-};
-use crate::commons::crypto::glwe::{
-    GlweCiphertext as ImplGlweCiphertext, GlweList as ImplGlweList,
-    GlweSeededCiphertext as ImplGlweSeededCiphertext, GlweSeededList as ImplGlweSeededList,
-    LwePackingKeyswitchKey as ImplLwePackingKeyswitchKey,
-    LwePrivateFunctionalPackingKeyswitchKeyList as ImplLweCircuitBoostrapPrivateFunctionalPackingKeyswitchKeys,
-};
-use crate::commons::crypto::lwe::{
-    LweCiphertext as ImplLweCiphertext, LweKeyswitchKey as ImplLweKeyswitchKey,
-    LweList as ImplLweList, LweSeededCiphertext as ImplLweSeededCiphertext,
-    LweSeededKeyswitchKey as ImplLweSeededKeyswitchKey, LweSeededList as ImplLweSeededList,
-};
-use crate::commons::crypto::secret::{
-    GlweSecretKey as ImplGlweSecretKey, LweSecretKey as ImplLweSecretKey,
-};
 use crate::prelude::{
-    BinaryKeyKind, Cleartext32, Cleartext32Version, Cleartext64, Cleartext64Version, CleartextF64,
-    CleartextF64Version, CleartextVector32, CleartextVector32Version, CleartextVector64,
-    CleartextVector64Version, CleartextVectorF64, CleartextVectorF64Version,
-    DefaultSerializationEngine, DefaultSerializationError, EntitySerializationEngine,
-    EntitySerializationError, FloatEncoder, FloatEncoderVector, FloatEncoderVectorVersion,
-    FloatEncoderVersion, GgswCiphertext32, GgswCiphertext32Version, GgswCiphertext64,
-    GgswCiphertext64Version, GgswSeededCiphertext32, GgswSeededCiphertext32Version,
-    GgswSeededCiphertext64, GgswSeededCiphertext64Version, GlweCiphertext32,
-    GlweCiphertext32Version, GlweCiphertext64, GlweCiphertext64Version, GlweCiphertextMutView32,
-    GlweCiphertextMutView64, GlweCiphertextVector32, GlweCiphertextVector32Version,
-    GlweCiphertextVector64, GlweCiphertextVector64Version, GlweCiphertextVectorMutView32,
-    GlweCiphertextVectorMutView64, GlweCiphertextVectorView32, GlweCiphertextVectorView64,
-    GlweCiphertextView32, GlweCiphertextView64, GlweSecretKey32, GlweSecretKey32Version,
-    GlweSecretKey64, GlweSecretKey64Version, GlweSeededCiphertext32, GlweSeededCiphertext32Version,
-    GlweSeededCiphertext64, GlweSeededCiphertext64Version, GlweSeededCiphertextVector32,
-    GlweSeededCiphertextVector32Version, GlweSeededCiphertextVector64,
-    GlweSeededCiphertextVector64Version, LweBootstrapKey32, LweBootstrapKey32Version,
-    LweBootstrapKey64, LweBootstrapKey64Version, LweCiphertext32, LweCiphertext32Version,
-    LweCiphertext64, LweCiphertext64Version, LweCiphertextMutView32, LweCiphertextMutView64,
-    LweCiphertextVector32, LweCiphertextVector32Version, LweCiphertextVector64,
-    LweCiphertextVector64Version, LweCiphertextVectorMutView32, LweCiphertextVectorMutView64,
+    Cleartext32, Cleartext64, CleartextF64, CleartextVector32, CleartextVector64,
+    CleartextVectorF64, DefaultSerializationEngine, EntitySerializationEngine,
+    EntitySerializationError, FloatEncoder, FloatEncoderVector, GgswCiphertext32, GgswCiphertext64,
+    GgswSeededCiphertext32, GgswSeededCiphertext64, GlweCiphertext32, GlweCiphertext64,
+    GlweCiphertextMutView32, GlweCiphertextMutView64, GlweCiphertextVector32,
+    GlweCiphertextVector64, GlweCiphertextVectorMutView32, GlweCiphertextVectorMutView64,
+    GlweCiphertextVectorView32, GlweCiphertextVectorView64, GlweCiphertextView32,
+    GlweCiphertextView64, GlweCompressedCiphertext32, GlweCompressedCiphertext64, GlweSecretKey32,
+    GlweSecretKey64, GlweSeededCiphertext32, GlweSeededCiphertext64, GlweSeededCiphertextVector32,
+    GlweSeededCiphertextVector64, LweBootstrapKey32, LweBootstrapKey64, LweCiphertext32,
+    LweCiphertext64, LweCiphertextMutView32, LweCiphertextMutView64, LweCiphertextVector32,
+    LweCiphertextVector64, LweCiphertextVectorMutView32, LweCiphertextVectorMutView64,
     LweCiphertextVectorView32, LweCiphertextVectorView64, LweCiphertextView32, LweCiphertextView64,
     LweCircuitBootstrapPrivateFunctionalPackingKeyswitchKeys32,
-    LweCircuitBootstrapPrivateFunctionalPackingKeyswitchKeys32Version,
-    LweCircuitBootstrapPrivateFunctionalPackingKeyswitchKeys64,
-    LweCircuitBootstrapPrivateFunctionalPackingKeyswitchKeys64Version, LweKeyswitchKey32,
-    LweKeyswitchKey32Version, LweKeyswitchKey64, LweKeyswitchKey64Version,
-    LwePackingKeyswitchKey32, LwePackingKeyswitchKey32Version, LwePackingKeyswitchKey64,
-    LwePackingKeyswitchKey64Version, LwePublicKey32, LwePublicKey32Version, LwePublicKey64,
-    LwePublicKey64Version, LweSecretKey32, LweSecretKey32Version, LweSecretKey64,
-    LweSecretKey64Version, LweSeededBootstrapKey32, LweSeededBootstrapKey32Version,
-    LweSeededBootstrapKey64, LweSeededBootstrapKey64Version, LweSeededCiphertext32,
-    LweSeededCiphertext32Version, LweSeededCiphertext64, LweSeededCiphertext64Version,
-    LweSeededCiphertextVector32, LweSeededCiphertextVector32Version, LweSeededCiphertextVector64,
-    LweSeededCiphertextVector64Version, LweSeededKeyswitchKey32, LweSeededKeyswitchKey32Version,
-    LweSeededKeyswitchKey64, LweSeededKeyswitchKey64Version, Plaintext32, Plaintext32Version,
-    Plaintext64, Plaintext64Version, PlaintextVector32, PlaintextVector32Version,
-    PlaintextVector64, PlaintextVector64Version,
+    LweCircuitBootstrapPrivateFunctionalPackingKeyswitchKeys64, LweCompressedCiphertext32,
+    LweCompressedCiphertext64, LweKeyswitchKey32, LweKeyswitchKey64, LwePackingKeyswitchKey32,
+    LwePackingKeyswitchKey64, LwePublicKey32, LwePublicKey64, LweSecretKey32, LweSecretKey64,
+    LweSeededBootstrapKey32, LweSeededBootstrapKey64, LweSeededCiphertext32, LweSeededCiphertext64,
+    LweSeededCiphertextVector32, LweSeededCiphertextVector64, LweSeededKeyswitchKey32,
+    LweSeededKeyswitchKey64, Plaintext32, Plaintext64, PlaintextVector32, PlaintextVector64,
 };
-use serde::Serialize;
 
 /// # Description:
 /// Implementation of [`EntitySerializationEngine`] for [`DefaultSerializationEngine`] that operates
@@ -98,18 +52,7 @@ impl EntitySerializationEngine<Cleartext32, Vec<u8>> for DefaultSerializationEng
         &mut self,
         entity: &Cleartext32,
     ) -> Result<Vec<u8>, EntitySerializationError<Self::EngineError>> {
-        #[derive(Serialize)]
-        struct SerializableCleartext32<'a> {
-            version: Cleartext32Version,
-            inner: &'a ImplCleartext<u32>,
-        }
-        let serializable = SerializableCleartext32 {
-            version: Cleartext32Version::V0,
-            inner: &entity.0,
-        };
-        bincode::serialize(&serializable)
-            .map_err(DefaultSerializationError::Serialization)
-            .map_err(EntitySerializationError::Engine)
+        self.serialize_to_vec(entity)
     }
 
     unsafe fn serialize_unchecked(&mut self, entity: &Cleartext32) -> Vec<u8> {
@@ -146,18 +89,7 @@ impl EntitySerializationEngine<Cleartext64, Vec<u8>> for DefaultSerializationEng
         &mut self,
         entity: &Cleartext64,
     ) -> Result<Vec<u8>, EntitySerializationError<Self::EngineError>> {
-        #[derive(Serialize)]
-        struct SerializableCleartext64<'a> {
-            version: Cleartext64Version,
-            inner: &'a ImplCleartext<u64>,
-        }
-        let serializable = SerializableCleartext64 {
-            version: Cleartext64Version::V0,
-            inner: &entity.0,
-        };
-        bincode::serialize(&serializable)
-            .map_err(DefaultSerializationError::Serialization)
-            .map_err(EntitySerializationError::Engine)
+        self.serialize_to_vec(entity)
     }
 
     unsafe fn serialize_unchecked(&mut self, entity: &Cleartext64) -> Vec<u8> {
@@ -194,18 +126,7 @@ impl EntitySerializationEngine<CleartextF64, Vec<u8>> for DefaultSerializationEn
         &mut self,
         entity: &CleartextF64,
     ) -> Result<Vec<u8>, EntitySerializationError<Self::EngineError>> {
-        #[derive(Serialize)]
-        struct SerializableCleartextF64<'a> {
-            version: CleartextF64Version,
-            inner: &'a ImplCleartext<f64>,
-        }
-        let serializable = SerializableCleartextF64 {
-            version: CleartextF64Version::V0,
-            inner: &entity.0,
-        };
-        bincode::serialize(&serializable)
-            .map_err(DefaultSerializationError::Serialization)
-            .map_err(EntitySerializationError::Engine)
+        self.serialize_to_vec(entity)
     }
 
     unsafe fn serialize_unchecked(&mut self, entity: &CleartextF64) -> Vec<u8> {
@@ -242,18 +163,7 @@ impl EntitySerializationEngine<CleartextVector32, Vec<u8>> for DefaultSerializat
         &mut self,
         entity: &CleartextVector32,
     ) -> Result<Vec<u8>, EntitySerializationError<Self::EngineError>> {
-        #[derive(Serialize)]
-        struct SerializableCleartextVector32<'a> {
-            version: CleartextVector32Version,
-            inner: &'a ImplCleartextList<Vec<u32>>,
-        }
-        let serializable = SerializableCleartextVector32 {
-            version: CleartextVector32Version::V0,
-            inner: &entity.0,
-        };
-        bincode::serialize(&serializable)
-            .map_err(DefaultSerializationError::Serialization)
-            .map_err(EntitySerializationError::Engine)
+        self.serialize_to_vec(entity)
     }
 
     unsafe fn serialize_unchecked(&mut self, entity: &CleartextVector32) -> Vec<u8> {
@@ -290,18 +200,7 @@ impl EntitySerializationEngine<CleartextVector64, Vec<u8>> for DefaultSerializat
         &mut self,
         entity: &CleartextVector64,
     ) -> Result<Vec<u8>, EntitySerializationError<Self::EngineError>> {
-        #[derive(Serialize)]
-        struct SerializableCleartextVector64<'a> {
-            version: CleartextVector64Version,
-            inner: &'a ImplCleartextList<Vec<u64>>,
-        }
-        let serializable = SerializableCleartextVector64 {
-            version: CleartextVector64Version::V0,
-            inner: &entity.0,
-        };
-        bincode::serialize(&serializable)
-            .map_err(DefaultSerializationError::Serialization)
-            .map_err(EntitySerializationError::Engine)
+        self.serialize_to_vec(entity)
     }
 
     unsafe fn serialize_unchecked(&mut self, entity: &CleartextVector64) -> Vec<u8> {
@@ -338,18 +237,7 @@ impl EntitySerializationEngine<CleartextVectorF64, Vec<u8>> for DefaultSerializa
         &mut self,
         entity: &CleartextVectorF64,
     ) -> Result<Vec<u8>, EntitySerializationError<Self::EngineError>> {
-        #[derive(Serialize)]
-        struct SerializableCleartextVectorF64<'a> {
-            version: CleartextVectorF64Version,
-            inner: &'a ImplCleartextList<Vec<f64>>,
-        }
-        let serializable = SerializableCleartextVectorF64 {
-            version: CleartextVectorF64Version::V0,
-            inner: &entity.0,
-        };
-        bincode::serialize(&serializable)
-            .map_err(DefaultSerializationError::Serialization)
-            .map_err(EntitySerializationError::Engine)
+        self.serialize_to_vec(entity)
     }
 
     unsafe fn serialize_unchecked(&mut self, entity: &CleartextVectorF64) -> Vec<u8> {
@@ -402,18 +290,7 @@ impl EntitySerializationEngine<GgswCiphertext32, Vec<u8>> for DefaultSerializati
         &mut self,
         entity: &GgswCiphertext32,
     ) -> Result<Vec<u8>, EntitySerializationError<Self::EngineError>> {
-        #[derive(Serialize)]
-        struct SerializableGgswCiphertext32<'a> {
-            version: GgswCiphertext32Version,
-            inner: &'a ImplStandardGgswCiphertext<Vec<u32>>,
-        }
-        let serializable = SerializableGgswCiphertext32 {
-            version: GgswCiphertext32Version::V0,
-            inner: &entity.0,
-        };
-        bincode::serialize(&serializable)
-            .map_err(DefaultSerializationError::Serialization)
-            .map_err(EntitySerializationError::Engine)
+        self.serialize_to_vec(entity)
     }
 
     unsafe fn serialize_unchecked(&mut self, entity: &GgswCiphertext32) -> Vec<u8> {
@@ -466,18 +343,7 @@ impl EntitySerializationEngine<GgswCiphertext64, Vec<u8>> for DefaultSerializati
         &mut self,
         entity: &GgswCiphertext64,
     ) -> Result<Vec<u8>, EntitySerializationError<Self::EngineError>> {
-        #[derive(Serialize)]
-        struct SerializableGgswCiphertext64<'a> {
-            version: GgswCiphertext64Version,
-            inner: &'a ImplStandardGgswCiphertext<Vec<u64>>,
-        }
-        let serializable = SerializableGgswCiphertext64 {
-            version: GgswCiphertext64Version::V0,
-            inner: &entity.0,
-        };
-        bincode::serialize(&serializable)
-            .map_err(DefaultSerializationError::Serialization)
-            .map_err(EntitySerializationError::Engine)
+        self.serialize_to_vec(entity)
     }
 
     unsafe fn serialize_unchecked(&mut self, entity: &GgswCiphertext64) -> Vec<u8> {
@@ -494,18 +360,7 @@ impl EntitySerializationEngine<GgswSeededCiphertext32, Vec<u8>> for DefaultSeria
         &mut self,
         entity: &GgswSeededCiphertext32,
     ) -> Result<Vec<u8>, EntitySerializationError<Self::EngineError>> {
-        #[derive(Serialize)]
-        struct SerializableGgswSeededCiphertext32<'a> {
-            version: GgswSeededCiphertext32Version,
-            inner: &'a ImplStandardGgswSeededCiphertext<Vec<u32>>,
-        }
-        let serializable = SerializableGgswSeededCiphertext32 {
-            version: GgswSeededCiphertext32Version::V0,
-            inner: &entity.0,
-        };
-        bincode::serialize(&serializable)
-            .map_err(DefaultSerializationError::Serialization)
-            .map_err(EntitySerializationError::Engine)
+        self.serialize_to_vec(entity)
     }
 
     unsafe fn serialize_unchecked(&mut self, entity: &GgswSeededCiphertext32) -> Vec<u8> {
@@ -522,18 +377,7 @@ impl EntitySerializationEngine<GgswSeededCiphertext64, Vec<u8>> for DefaultSeria
         &mut self,
         entity: &GgswSeededCiphertext64,
     ) -> Result<Vec<u8>, EntitySerializationError<Self::EngineError>> {
-        #[derive(Serialize)]
-        struct SerializableGgswSeededCiphertext64<'a> {
-            version: GgswSeededCiphertext64Version,
-            inner: &'a ImplStandardGgswSeededCiphertext<Vec<u64>>,
-        }
-        let serializable = SerializableGgswSeededCiphertext64 {
-            version: GgswSeededCiphertext64Version::V0,
-            inner: &entity.0,
-        };
-        bincode::serialize(&serializable)
-            .map_err(DefaultSerializationError::Serialization)
-            .map_err(EntitySerializationError::Engine)
+        self.serialize_to_vec(entity)
     }
 
     unsafe fn serialize_unchecked(&mut self, entity: &GgswSeededCiphertext64) -> Vec<u8> {
@@ -582,18 +426,7 @@ impl EntitySerializationEngine<GlweCiphertext32, Vec<u8>> for DefaultSerializati
         &mut self,
         entity: &GlweCiphertext32,
     ) -> Result<Vec<u8>, EntitySerializationError<Self::EngineError>> {
-        #[derive(Serialize)]
-        struct SerializableGlweCiphertext32<'a> {
-            version: GlweCiphertext32Version,
-            inner: &'a ImplGlweCiphertext<Vec<u32>>,
-        }
-        let serializable = SerializableGlweCiphertext32 {
-            version: GlweCiphertext32Version::V0,
-            inner: &entity.0,
-        };
-        bincode::serialize(&serializable)
-            .map_err(DefaultSerializationError::Serialization)
-            .map_err(EntitySerializationError::Engine)
+        self.serialize_to_vec(entity)
     }
 
     unsafe fn serialize_unchecked(&mut self, entity: &GlweCiphertext32) -> Vec<u8> {
@@ -642,18 +475,7 @@ impl EntitySerializationEngine<GlweCiphertext64, Vec<u8>> for DefaultSerializati
         &mut self,
         entity: &GlweCiphertext64,
     ) -> Result<Vec<u8>, EntitySerializationError<Self::EngineError>> {
-        #[derive(Serialize)]
-        struct SerializableGlweCiphertext64<'a> {
-            version: GlweCiphertext64Version,
-            inner: &'a ImplGlweCiphertext<Vec<u64>>,
-        }
-        let serializable = SerializableGlweCiphertext64 {
-            version: GlweCiphertext64Version::V0,
-            inner: &entity.0,
-        };
-        bincode::serialize(&serializable)
-            .map_err(DefaultSerializationError::Serialization)
-            .map_err(EntitySerializationError::Engine)
+        self.serialize_to_vec(entity)
     }
 
     unsafe fn serialize_unchecked(&mut self, entity: &GlweCiphertext64) -> Vec<u8> {
@@ -708,18 +530,7 @@ impl<'b> EntitySerializationEngine<GlweCiphertextView32<'b>, Vec<u8>>
         &mut self,
         entity: &GlweCiphertextView32<'b>,
     ) -> Result<Vec<u8>, EntitySerializationError<Self::EngineError>> {
-        #[derive(Serialize)]
-        struct SerializableGlweCiphertextView32<'a, 'b> {
-            version: GlweCiphertext32Version,
-            inner: &'a ImplGlweCiphertext<&'b [u32]>,
-        }
-        let serializable = SerializableGlweCiphertextView32 {
-            version: GlweCiphertext32Version::V0,
-            inner: &entity.0,
-        };
-        bincode::serialize(&serializable)
-            .map_err(DefaultSerializationError::Serialization)
-            .map_err(EntitySerializationError::Engine)
+        self.serialize_to_vec(entity)
     }
 
     unsafe fn serialize_unchecked(&mut self, entity: &GlweCiphertextView32<'b>) -> Vec<u8> {
@@ -774,18 +585,7 @@ impl<'b> EntitySerializationEngine<GlweCiphertextView64<'b>, Vec<u8>>
         &mut self,
         entity: &GlweCiphertextView64<'b>,
     ) -> Result<Vec<u8>, EntitySerializationError<Self::EngineError>> {
-        #[derive(Serialize)]
-        struct SerializableGlweCiphertextView64<'a, 'b> {
-            version: GlweCiphertext64Version,
-            inner: &'a ImplGlweCiphertext<&'b [u64]>,
-        }
-        let serializable = SerializableGlweCiphertextView64 {
-            version: GlweCiphertext64Version::V0,
-            inner: &entity.0,
-        };
-        bincode::serialize(&serializable)
-            .map_err(DefaultSerializationError::Serialization)
-            .map_err(EntitySerializationError::Engine)
+        self.serialize_to_vec(entity)
     }
 
     unsafe fn serialize_unchecked(&mut self, entity: &GlweCiphertextView64<'b>) -> Vec<u8> {
@@ -840,18 +640,7 @@ impl<'b> EntitySerializationEngine<GlweCiphertextMutView32<'b>, Vec<u8>>
         &mut self,
         entity: &GlweCiphertextMutView32<'b>,
     ) -> Result<Vec<u8>, EntitySerializationError<Self::EngineError>> {
-        #[derive(Serialize)]
-        struct SerializableGlweCiphertextMutView32<'a, 'b> {
-            version: GlweCiphertext32Version,
-            inner: &'a ImplGlweCiphertext<&'b mut [u32]>,
-        }
-        let serializable = SerializableGlweCiphertextMutView32 {
-            version: GlweCiphertext32Version::V0,
-            inner: &entity.0,
-        };
-        bincode::serialize(&serializable)
-            .map_err(DefaultSerializationError::Serialization)
-            .map_err(EntitySerializationError::Engine)
+        self.serialize_to_vec(entity)
     }
 
     unsafe fn serialize_unchecked(&mut self, entity: &GlweCiphertextMutView32<'b>) -> Vec<u8> {
@@ -906,18 +695,7 @@ impl<'b> EntitySerializationEngine<GlweCiphertextMutView64<'b>, Vec<u8>>
         &mut self,
         entity: &GlweCiphertextMutView64<'b>,
     ) -> Result<Vec<u8>, EntitySerializationError<Self::EngineError>> {
-        #[derive(Serialize)]
-        struct SerializableGlweCiphertextMutView64<'a, 'b> {
-            version: GlweCiphertext64Version,
-            inner: &'a ImplGlweCiphertext<&'b mut [u64]>,
-        }
-        let serializable = SerializableGlweCiphertextMutView64 {
-            version: GlweCiphertext64Version::V0,
-            inner: &entity.0,
-        };
-        bincode::serialize(&serializable)
-            .map_err(DefaultSerializationError::Serialization)
-            .map_err(EntitySerializationError::Engine)
+        self.serialize_to_vec(entity)
     }
 
     unsafe fn serialize_unchecked(&mut self, entity: &GlweCiphertextMutView64<'b>) -> Vec<u8> {
@@ -968,18 +746,7 @@ impl EntitySerializationEngine<GlweCiphertextVector32, Vec<u8>> for DefaultSeria
         &mut self,
         entity: &GlweCiphertextVector32,
     ) -> Result<Vec<u8>, EntitySerializationError<Self::EngineError>> {
-        #[derive(Serialize)]
-        struct SerializableGlweCiphertextVector32<'a> {
-            version: GlweCiphertextVector32Version,
-            inner: &'a ImplGlweList<Vec<u32>>,
-        }
-        let serializable = SerializableGlweCiphertextVector32 {
-            version: GlweCiphertextVector32Version::V0,
-            inner: &entity.0,
-        };
-        bincode::serialize(&serializable)
-            .map_err(DefaultSerializationError::Serialization)
-            .map_err(EntitySerializationError::Engine)
+        self.serialize_to_vec(entity)
     }
 
     unsafe fn serialize_unchecked(&mut self, entity: &GlweCiphertextVector32) -> Vec<u8> {
@@ -1030,18 +797,7 @@ impl EntitySerializationEngine<GlweCiphertextVector64, Vec<u8>> for DefaultSeria
         &mut self,
         entity: &GlweCiphertextVector64,
     ) -> Result<Vec<u8>, EntitySerializationError<Self::EngineError>> {
-        #[derive(Serialize)]
-        struct SerializableGlweCiphertextVector64<'a> {
-            version: GlweCiphertextVector64Version,
-            inner: &'a ImplGlweList<Vec<u64>>,
-        }
-        let serializable = SerializableGlweCiphertextVector64 {
-            version: GlweCiphertextVector64Version::V0,
-            inner: &entity.0,
-        };
-        bincode::serialize(&serializable)
-            .map_err(DefaultSerializationError::Serialization)
-            .map_err(EntitySerializationError::Engine)
+        self.serialize_to_vec(entity)
     }
 
     unsafe fn serialize_unchecked(&mut self, entity: &GlweCiphertextVector64) -> Vec<u8> {
@@ -1103,18 +859,7 @@ impl<'b> EntitySerializationEngine<GlweCiphertextVectorView32<'b>, Vec<u8>>
         &mut self,
         entity: &GlweCiphertextVectorView32<'b>,
     ) -> Result<Vec<u8>, EntitySerializationError<Self::EngineError>> {
-        #[derive(Serialize)]
-        struct SerializableGlweCiphertextVectorView32<'a, 'b> {
-            version: GlweCiphertextVector32Version,
-            inner: &'a ImplGlweList<&'b [u32]>,
-        }
-        let serializable = SerializableGlweCiphertextVectorView32 {
-            version: GlweCiphertextVector32Version::V0,
-            inner: &entity.0,
-        };
-        bincode::serialize(&serializable)
-            .map_err(DefaultSerializationError::Serialization)
-            .map_err(EntitySerializationError::Engine)
+        self.serialize_to_vec(entity)
     }
 
     unsafe fn serialize_unchecked(&mut self, entity: &GlweCiphertextVectorView32<'b>) -> Vec<u8> {
@@ -1176,18 +921,7 @@ impl<'b> EntitySerializationEngine<GlweCiphertextVectorView64<'b>, Vec<u8>>
         &mut self,
         entity: &GlweCiphertextVectorView64<'b>,
     ) -> Result<Vec<u8>, EntitySerializationError<Self::EngineError>> {
-        #[derive(Serialize)]
-        struct SerializableGlweCiphertextVectorView64<'a, 'b> {
-            version: GlweCiphertextVector64Version,
-            inner: &'a ImplGlweList<&'b [u64]>,
-        }
-        let serializable = SerializableGlweCiphertextVectorView64 {
-            version: GlweCiphertextVector64Version::V0,
-            inner: &entity.0,
-        };
-        bincode::serialize(&serializable)
-            .map_err(DefaultSerializationError::Serialization)
-            .map_err(EntitySerializationError::Engine)
+        self.serialize_to_vec(entity)
     }
 
     unsafe fn serialize_unchecked(&mut self, entity: &GlweCiphertextVectorView64<'b>) -> Vec<u8> {
@@ -1248,18 +982,7 @@ impl<'b> EntitySerializationEngine<GlweCiphertextVectorMutView32<'b>, Vec<u8>>
         &mut self,
         entity: &GlweCiphertextVectorMutView32<'b>,
     ) -> Result<Vec<u8>, EntitySerializationError<Self::EngineError>> {
-        #[derive(Serialize)]
-        struct SerializableGlweCiphertextVectorMutView32<'a, 'b> {
-            version: GlweCiphertextVector32Version,
-            inner: &'a ImplGlweList<&'b mut [u32]>,
-        }
-        let serializable = SerializableGlweCiphertextVectorMutView32 {
-            version: GlweCiphertextVector32Version::V0,
-            inner: &entity.0,
-        };
-        bincode::serialize(&serializable)
-            .map_err(DefaultSerializationError::Serialization)
-            .map_err(EntitySerializationError::Engine)
+        self.serialize_to_vec(entity)
     }
 
     unsafe fn serialize_unchecked(
@@ -1323,18 +1046,7 @@ impl<'b> EntitySerializationEngine<GlweCiphertextVectorMutView64<'b>, Vec<u8>>
         &mut self,
         entity: &GlweCiphertextVectorMutView64<'b>,
     ) -> Result<Vec<u8>, EntitySerializationError<Self::EngineError>> {
-        #[derive(Serialize)]
-        struct SerializableGlweCiphertextVectorMutView64<'a, 'b> {
-            version: GlweCiphertextVector64Version,
-            inner: &'a ImplGlweList<&'b mut [u64]>,
-        }
-        let serializable = SerializableGlweCiphertextVectorMutView64 {
-            version: GlweCiphertextVector64Version::V0,
-            inner: &entity.0,
-        };
-        bincode::serialize(&serializable)
-            .map_err(DefaultSerializationError::Serialization)
-            .map_err(EntitySerializationError::Engine)
+        self.serialize_to_vec(entity)
     }
 
     unsafe fn serialize_unchecked(
@@ -1379,18 +1091,7 @@ impl EntitySerializationEngine<GlweSecretKey32, Vec<u8>> for DefaultSerializatio
         &mut self,
         entity: &GlweSecretKey32,
     ) -> Result<Vec<u8>, EntitySerializationError<Self::EngineError>> {
-        #[derive(Serialize)]
-        struct SerializableGlweSecretKey32<'a> {
-            version: GlweSecretKey32Version,
-            inner: &'a ImplGlweSecretKey<BinaryKeyKind, Vec<u32>>,
-        }
-        let serializable = SerializableGlweSecretKey32 {
-            version: GlweSecretKey32Version::V0,
-            inner: &entity.0,
-        };
-        bincode::serialize(&serializable)
-            .map_err(DefaultSerializationError::Serialization)
-            .map_err(EntitySerializationError::Engine)
+        self.serialize_to_vec(entity)
     }
 
     unsafe fn serialize_unchecked(&mut self, entity: &GlweSecretKey32) -> Vec<u8> {
@@ -1432,18 +1133,7 @@ impl EntitySerializationEngine<GlweSecretKey64, Vec<u8>> for DefaultSerializatio
         &mut self,
         entity: &GlweSecretKey64,
     ) -> Result<Vec<u8>, EntitySerializationError<Self::EngineError>> {
-        #[derive(Serialize)]
-        struct SerializableGlweSecretKey64<'a> {
-            version: GlweSecretKey64Version,
-            inner: &'a ImplGlweSecretKey<BinaryKeyKind, Vec<u64>>,
-        }
-        let serializable = SerializableGlweSecretKey64 {
-            version: GlweSecretKey64Version::V0,
-            inner: &entity.0,
-        };
-        bincode::serialize(&serializable)
-            .map_err(DefaultSerializationError::Serialization)
-            .map_err(EntitySerializationError::Engine)
+        self.serialize_to_vec(entity)
     }
 
     unsafe fn serialize_unchecked(&mut self, entity: &GlweSecretKey64) -> Vec<u8> {
@@ -1493,18 +1183,7 @@ impl EntitySerializationEngine<GlweSeededCiphertext32, Vec<u8>> for DefaultSeria
         &mut self,
         entity: &GlweSeededCiphertext32,
     ) -> Result<Vec<u8>, EntitySerializationError<Self::EngineError>> {
-        #[derive(Serialize)]
-        struct SerializableGlweSeededCiphertext32<'a> {
-            version: GlweSeededCiphertext32Version,
-            inner: &'a ImplGlweSeededCiphertext<Vec<u32>>,
-        }
-        let serializable = SerializableGlweSeededCiphertext32 {
-            version: GlweSeededCiphertext32Version::V0,
-            inner: &entity.0,
-        };
-        bincode::serialize(&serializable)
-            .map_err(DefaultSerializationError::Serialization)
-            .map_err(EntitySerializationError::Engine)
+        self.serialize_to_vec(entity)
     }
 
     unsafe fn serialize_unchecked(&mut self, entity: &GlweSeededCiphertext32) -> Vec<u8> {
@@ -1554,18 +1233,7 @@ impl EntitySerializationEngine<GlweSeededCiphertext64, Vec<u8>> for DefaultSeria
         &mut self,
         entity: &GlweSeededCiphertext64,
     ) -> Result<Vec<u8>, EntitySerializationError<Self::EngineError>> {
-        #[derive(Serialize)]
-        struct SerializableGlweSeededCiphertext64<'a> {
-            version: GlweSeededCiphertext64Version,
-            inner: &'a ImplGlweSeededCiphertext<Vec<u64>>,
-        }
-        let serializable = SerializableGlweSeededCiphertext64 {
-            version: GlweSeededCiphertext64Version::V0,
-            inner: &entity.0,
-        };
-        bincode::serialize(&serializable)
-            .map_err(DefaultSerializationError::Serialization)
-            .map_err(EntitySerializationError::Engine)
+        self.serialize_to_vec(entity)
     }
 
     unsafe fn serialize_unchecked(&mut self, entity: &GlweSeededCiphertext64) -> Vec<u8> {
@@ -1573,6 +1241,108 @@ impl EntitySerializationEngine<GlweSeededCiphertext64, Vec<u8>> for DefaultSeria
     }
 }
 
+/// # Description:
+/// Implementation of [`EntitySerializationEngine`] for [`DefaultSerializationEngine`] that operates
+/// on 32 bits integers. It serializes a compressed GLWE ciphertext entity.
+impl EntitySerializationEngine<GlweCompressedCiphertext32, Vec<u8>> for DefaultSerializationEngine {
+    /// # Example:
+    /// ```
+    /// use concrete_core::prelude::{GlweDimension, PolynomialSize, Variance, *};
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let glwe_dimension = GlweDimension(2);
+    /// let polynomial_size = PolynomialSize(4);
+    /// // There are always polynomial_size messages encrypted in the GLWE ciphertext
+    /// // Here a hard-set encoding is applied (shift by 20 bits)
+    /// let input = vec![3_u32 << 20; polynomial_size.0];
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let key: GlweSecretKey32 =
+    ///     engine.generate_new_glwe_secret_key(glwe_dimension, polynomial_size)?;
+    /// let plaintext_vector = engine.create_plaintext_vector_from(&input)?;
+    ///
+    /// let ciphertext = engine.encrypt_glwe_ciphertext(&key, &plaintext_vector, noise)?;
+    /// let compressed_ciphertext =
+    ///     engine.compress_glwe_ciphertext(&ciphertext, CiphertextModulusLog(16))?;
+    ///
+    /// let mut serialization_engine = DefaultSerializationEngine::new(())?;
+    /// let serialized = serialization_engine.serialize(&compressed_ciphertext)?;
+    /// let recovered = serialization_engine.deserialize(serialized.as_slice())?;
+    /// assert_eq!(compressed_ciphertext, recovered);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn serialize(
+        &mut self,
+        entity: &GlweCompressedCiphertext32,
+    ) -> Result<Vec<u8>, EntitySerializationError<Self::EngineError>> {
+        self.serialize_to_vec(entity)
+    }
+
+    unsafe fn serialize_unchecked(&mut self, entity: &GlweCompressedCiphertext32) -> Vec<u8> {
+        self.serialize(entity).unwrap()
+    }
+}
+
+/// # Description:
+/// Implementation of [`EntitySerializationEngine`] for [`DefaultSerializationEngine`] that operates
+/// on 64 bits integers. It serializes a compressed GLWE ciphertext entity.
+impl EntitySerializationEngine<GlweCompressedCiphertext64, Vec<u8>> for DefaultSerializationEngine {
+    /// # Example:
+    /// ```
+    /// use concrete_core::prelude::{GlweDimension, PolynomialSize, Variance, *};
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let glwe_dimension = GlweDimension(2);
+    /// let polynomial_size = PolynomialSize(4);
+    /// // There are always polynomial_size messages encrypted in the GLWE ciphertext
+    /// // Here a hard-set encoding is applied (shift by 50 bits)
+    /// let input = vec![3_u64 << 50; polynomial_size.0];
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let key: GlweSecretKey64 =
+    ///     engine.generate_new_glwe_secret_key(glwe_dimension, polynomial_size)?;
+    /// let plaintext_vector = engine.create_plaintext_vector_from(&input)?;
+    ///
+    /// let ciphertext = engine.encrypt_glwe_ciphertext(&key, &plaintext_vector, noise)?;
+    /// let compressed_ciphertext =
+    ///     engine.compress_glwe_ciphertext(&ciphertext, CiphertextModulusLog(16))?;
+    ///
+    /// let mut serialization_engine = DefaultSerializationEngine::new(())?;
+    /// let serialized = serialization_engine.serialize(&compressed_ciphertext)?;
+    /// let recovered = serialization_engine.deserialize(serialized.as_slice())?;
+    /// assert_eq!(compressed_ciphertext, recovered);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn serialize(
+        &mut self,
+        entity: &GlweCompressedCiphertext64,
+    ) -> Result<Vec<u8>, EntitySerializationError<Self::EngineError>> {
+        self.serialize_to_vec(entity)
+    }
+
+    unsafe fn serialize_unchecked(&mut self, entity: &GlweCompressedCiphertext64) -> Vec<u8> {
+        self.serialize(entity).unwrap()
+    }
+}
+
 /// # Description:
 /// Implementation of [`EntitySerializationEngine`] for [`DefaultSerializationEngine`] that operates
 /// on 32 bits integers. It serializes a seeded GLWE ciphertext vector entity.
@@ -1616,18 +1386,7 @@ impl EntitySerializationEngine<GlweSeededCiphertextVector32, Vec<u8>>
         &mut self,
         entity: &GlweSeededCiphertextVector32,
     ) -> Result<Vec<u8>, EntitySerializationError<Self::EngineError>> {
-        #[derive(Serialize)]
-        struct SerializableGlweSeededCiphertextVector32<'a> {
-            version: GlweSeededCiphertextVector32Version,
-            inner: &'a ImplGlweSeededList<Vec<u32>>,
-        }
-        let serializable = SerializableGlweSeededCiphertextVector32 {
-            version: GlweSeededCiphertextVector32Version::V0,
-            inner: &entity.0,
-        };
-        bincode::serialize(&serializable)
-            .map_err(DefaultSerializationError::Serialization)
-            .map_err(EntitySerializationError::Engine)
+        self.serialize_to_vec(entity)
     }
 
     unsafe fn serialize_unchecked(&mut self, entity: &GlweSeededCiphertextVector32) -> Vec<u8> {
@@ -1678,18 +1437,7 @@ impl EntitySerializationEngine<GlweSeededCiphertextVector64, Vec<u8>>
         &mut self,
         entity: &GlweSeededCiphertextVector64,
     ) -> Result<Vec<u8>, EntitySerializationError<Self::EngineError>> {
-        #[derive(Serialize)]
-        struct SerializableGlweSeededCiphertextVector64<'a> {
-            version: GlweSeededCiphertextVector64Version,
-            inner: &'a ImplGlweSeededList<Vec<u64>>,
-        }
-        let serializable = SerializableGlweSeededCiphertextVector64 {
-            version: GlweSeededCiphertextVector64Version::V0,
-            inner: &entity.0,
-        };
-        bincode::serialize(&serializable)
-            .map_err(DefaultSerializationError::Serialization)
-            .map_err(EntitySerializationError::Engine)
+        self.serialize_to_vec(entity)
     }
 
     unsafe fn serialize_unchecked(&mut self, entity: &GlweSeededCiphertextVector64) -> Vec<u8> {
@@ -1738,18 +1486,7 @@ impl EntitySerializationEngine<LweBootstrapKey32, Vec<u8>> for DefaultSerializat
         &mut self,
         entity: &LweBootstrapKey32,
     ) -> Result<Vec<u8>, EntitySerializationError<Self::EngineError>> {
-        #[derive(Serialize)]
-        struct SerializableLweBootstrapKey32<'a> {
-            version: LweBootstrapKey32Version,
-            inner: &'a ImplStandardBootstrapKey<Vec<u32>>,
-        }
-        let serializable = SerializableLweBootstrapKey32 {
-            version: LweBootstrapKey32Version::V0,
-            inner: &entity.0,
-        };
-        bincode::serialize(&serializable)
-            .map_err(DefaultSerializationError::Serialization)
-            .map_err(EntitySerializationError::Engine)
+        self.serialize_to_vec(entity)
     }
 
     unsafe fn serialize_unchecked(&mut self, entity: &LweBootstrapKey32) -> Vec<u8> {
@@ -1798,18 +1535,7 @@ impl EntitySerializationEngine<LweBootstrapKey64, Vec<u8>> for DefaultSerializat
         &mut self,
         entity: &LweBootstrapKey64,
     ) -> Result<Vec<u8>, EntitySerializationError<Self::EngineError>> {
-        #[derive(Serialize)]
-        struct SerializableLweBootstrapKey64<'a> {
-            version: LweBootstrapKey64Version,
-            inner: &'a ImplStandardBootstrapKey<Vec<u64>>,
-        }
-        let serializable = SerializableLweBootstrapKey64 {
-            version: LweBootstrapKey64Version::V0,
-            inner: &entity.0,
-        };
-        bincode::serialize(&serializable)
-            .map_err(DefaultSerializationError::Serialization)
-            .map_err(EntitySerializationError::Engine)
+        self.serialize_to_vec(entity)
     }
 
     unsafe fn serialize_unchecked(&mut self, entity: &LweBootstrapKey64) -> Vec<u8> {
@@ -1855,18 +1581,7 @@ impl EntitySerializationEngine<LweCiphertext32, Vec<u8>> for DefaultSerializatio
         &mut self,
         entity: &LweCiphertext32,
     ) -> Result<Vec<u8>, EntitySerializationError<Self::EngineError>> {
-        #[derive(Serialize)]
-        struct SerializableLweCiphertext32<'a> {
-            version: LweCiphertext32Version,
-            inner: &'a ImplLweCiphertext<Vec<u32>>,
-        }
-        let serializable = SerializableLweCiphertext32 {
-            version: LweCiphertext32Version::V0,
-            inner: &entity.0,
-        };
-        bincode::serialize(&serializable)
-            .map_err(DefaultSerializationError::Serialization)
-            .map_err(EntitySerializationError::Engine)
+        self.serialize_to_vec(entity)
     }
 
     unsafe fn serialize_unchecked(&mut self, entity: &LweCiphertext32) -> Vec<u8> {
@@ -1913,18 +1628,7 @@ impl EntitySerializationEngine<LweCiphertext64, Vec<u8>> for DefaultSerializatio
         &mut self,
         entity: &LweCiphertext64,
     ) -> Result<Vec<u8>, EntitySerializationError<Self::EngineError>> {
-        #[derive(Serialize)]
-        struct SerializableLweCiphertext64<'a> {
-            version: LweCiphertext64Version,
-            inner: &'a ImplLweCiphertext<Vec<u64>>,
-        }
-        let serializable = SerializableLweCiphertext64 {
-            version: LweCiphertext64Version::V0,
-            inner: &entity.0,
-        };
-        bincode::serialize(&serializable)
-            .map_err(DefaultSerializationError::Serialization)
-            .map_err(EntitySerializationError::Engine)
+        self.serialize_to_vec(entity)
     }
 
     unsafe fn serialize_unchecked(&mut self, entity: &LweCiphertext64) -> Vec<u8> {
@@ -1975,18 +1679,7 @@ impl<'b> EntitySerializationEngine<LweCiphertextView32<'b>, Vec<u8>>
         &mut self,
         entity: &LweCiphertextView32<'b>,
     ) -> Result<Vec<u8>, EntitySerializationError<Self::EngineError>> {
-        #[derive(Serialize)]
-        struct SerializableLweCiphertextView32<'a, 'b> {
-            version: LweCiphertext32Version,
-            inner: &'a ImplLweCiphertext<&'b [u32]>,
-        }
-        let serializable = SerializableLweCiphertextView32 {
-            version: LweCiphertext32Version::V0,
-            inner: &entity.0,
-        };
-        bincode::serialize(&serializable)
-            .map_err(DefaultSerializationError::Serialization)
-            .map_err(EntitySerializationError::Engine)
+        self.serialize_to_vec(entity)
     }
 
     unsafe fn serialize_unchecked(&mut self, entity: &LweCiphertextView32<'b>) -> Vec<u8> {
@@ -2039,18 +1732,7 @@ impl<'b> EntitySerializationEngine<LweCiphertextView64<'b>, Vec<u8>>
         &mut self,
         entity: &LweCiphertextView64<'b>,
     ) -> Result<Vec<u8>, EntitySerializationError<Self::EngineError>> {
-        #[derive(Serialize)]
-        struct SerializableLweCiphertextView64<'a, 'b> {
-            version: LweCiphertext64Version,
-            inner: &'a ImplLweCiphertext<&'b [u64]>,
-        }
-        let serializable = SerializableLweCiphertextView64 {
-            version: LweCiphertext64Version::V0,
-            inner: &entity.0,
-        };
-        bincode::serialize(&serializable)
-            .map_err(DefaultSerializationError::Serialization)
-            .map_err(EntitySerializationError::Engine)
+        self.serialize_to_vec(entity)
     }
 
     unsafe fn serialize_unchecked(&mut self, entity: &LweCiphertextView64<'b>) -> Vec<u8> {
@@ -2102,18 +1784,7 @@ impl<'b> EntitySerializationEngine<LweCiphertextMutView32<'b>, Vec<u8>>
         &mut self,
         entity: &LweCiphertextMutView32<'b>,
     ) -> Result<Vec<u8>, EntitySerializationError<Self::EngineError>> {
-        #[derive(Serialize)]
-        struct SerializableLweCiphertextMutView32<'a, 'b> {
-            version: LweCiphertext32Version,
-            inner: &'a ImplLweCiphertext<&'b mut [u32]>,
-        }
-        let serializable = SerializableLweCiphertextMutView32 {
-            version: LweCiphertext32Version::V0,
-            inner: &entity.0,
-        };
-        bincode::serialize(&serializable)
-            .map_err(DefaultSerializationError::Serialization)
-            .map_err(EntitySerializationError::Engine)
+        self.serialize_to_vec(entity)
     }
 
     unsafe fn serialize_unchecked(&mut self, entity: &LweCiphertextMutView32<'b>) -> Vec<u8> {
@@ -2166,18 +1837,7 @@ impl<'b> EntitySerializationEngine<LweCiphertextMutView64<'b>, Vec<u8>>
         &mut self,
         entity: &LweCiphertextMutView64<'b>,
     ) -> Result<Vec<u8>, EntitySerializationError<Self::EngineError>> {
-        #[derive(Serialize)]
-        struct SerializableLweCiphertextMutView64<'a, 'b> {
-            version: LweCiphertext64Version,
-            inner: &'a ImplLweCiphertext<&'b mut [u64]>,
-        }
-        let serializable = SerializableLweCiphertextMutView64 {
-            version: LweCiphertext64Version::V0,
-            inner: &entity.0,
-        };
-        bincode::serialize(&serializable)
-            .map_err(DefaultSerializationError::Serialization)
-            .map_err(EntitySerializationError::Engine)
+        self.serialize_to_vec(entity)
     }
 
     unsafe fn serialize_unchecked(&mut self, entity: &LweCiphertextMutView64<'b>) -> Vec<u8> {
@@ -2242,18 +1902,7 @@ impl EntitySerializationEngine<LweCircuitBootstrapPrivateFunctionalPackingKeyswi
         &mut self,
         entity: &LweCircuitBootstrapPrivateFunctionalPackingKeyswitchKeys32,
     ) -> Result<Vec<u8>, EntitySerializationError<Self::EngineError>> {
-        #[derive(Serialize)]
-        struct SerializableLweCircuitBootstrapPrivateFunctionalPackingKeyswitchKeys32<'a> {
-            version: LweCircuitBootstrapPrivateFunctionalPackingKeyswitchKeys32Version,
-            inner: &'a ImplLweCircuitBoostrapPrivateFunctionalPackingKeyswitchKeys<Vec<u32>>,
-        }
-        let serializable = SerializableLweCircuitBootstrapPrivateFunctionalPackingKeyswitchKeys32 {
-            version: LweCircuitBootstrapPrivateFunctionalPackingKeyswitchKeys32Version::V0,
-            inner: &entity.0,
-        };
-        bincode::serialize(&serializable)
-            .map_err(DefaultSerializationError::Serialization)
-            .map_err(EntitySerializationError::Engine)
+        self.serialize_to_vec(entity)
     }
 
     unsafe fn serialize_unchecked(
@@ -2321,18 +1970,7 @@ impl EntitySerializationEngine<LweCircuitBootstrapPrivateFunctionalPackingKeyswi
         &mut self,
         entity: &LweCircuitBootstrapPrivateFunctionalPackingKeyswitchKeys64,
     ) -> Result<Vec<u8>, EntitySerializationError<Self::EngineError>> {
-        #[derive(Serialize)]
-        struct SerializableLweCircuitBootstrapPrivateFunctionalPackingKeyswitchKeys64<'a> {
-            version: LweCircuitBootstrapPrivateFunctionalPackingKeyswitchKeys64Version,
-            inner: &'a ImplLweCircuitBoostrapPrivateFunctionalPackingKeyswitchKeys<Vec<u64>>,
-        }
-        let serializable = SerializableLweCircuitBootstrapPrivateFunctionalPackingKeyswitchKeys64 {
-            version: LweCircuitBootstrapPrivateFunctionalPackingKeyswitchKeys64Version::V0,
-            inner: &entity.0,
-        };
-        bincode::serialize(&serializable)
-            .map_err(DefaultSerializationError::Serialization)
-            .map_err(EntitySerializationError::Engine)
+        self.serialize_to_vec(entity)
     }
 
     unsafe fn serialize_unchecked(
@@ -2382,18 +2020,7 @@ impl EntitySerializationEngine<LweCiphertextVector32, Vec<u8>> for DefaultSerial
         &mut self,
         entity: &LweCiphertextVector32,
     ) -> Result<Vec<u8>, EntitySerializationError<Self::EngineError>> {
-        #[derive(Serialize)]
-        struct SerializableLweCiphertextVector32<'a> {
-            version: LweCiphertextVector32Version,
-            inner: &'a ImplLweList<Vec<u32>>,
-        }
-        let serializable = SerializableLweCiphertextVector32 {
-            version: LweCiphertextVector32Version::V0,
-            inner: &entity.0,
-        };
-        bincode::serialize(&serializable)
-            .map_err(DefaultSerializationError::Serialization)
-            .map_err(EntitySerializationError::Engine)
+        self.serialize_to_vec(entity)
     }
 
     unsafe fn serialize_unchecked(&mut self, entity: &LweCiphertextVector32) -> Vec<u8> {
@@ -2440,18 +2067,7 @@ impl EntitySerializationEngine<LweCiphertextVector64, Vec<u8>> for DefaultSerial
         &mut self,
         entity: &LweCiphertextVector64,
     ) -> Result<Vec<u8>, EntitySerializationError<Self::EngineError>> {
-        #[derive(Serialize)]
-        struct SerializableLweCiphertextVector64<'a> {
-            version: LweCiphertextVector64Version,
-            inner: &'a ImplLweList<Vec<u64>>,
-        }
-        let serializable = SerializableLweCiphertextVector64 {
-            version: LweCiphertextVector64Version::V0,
-            inner: &entity.0,
-        };
-        bincode::serialize(&serializable)
-            .map_err(DefaultSerializationError::Serialization)
-            .map_err(EntitySerializationError::Engine)
+        self.serialize_to_vec(entity)
     }
 
     unsafe fn serialize_unchecked(&mut self, entity: &LweCiphertextVector64) -> Vec<u8> {
@@ -2506,19 +2122,7 @@ impl<'b> EntitySerializationEngine<LweCiphertextVectorView32<'b>, Vec<u8>>
         &mut self,
         entity: &LweCiphertextVectorView32<'b>,
     ) -> Result<Vec<u8>, EntitySerializationError<Self::EngineError>> {
-        #[derive(Serialize)]
-        struct SerializableLweCiphertextVectorView32<'a, 'b> {
-            version: LweCiphertextVector32Version,
-            inner: &'a ImplLweList<&'b [u32]>,
-        }
-
-        let serializable = SerializableLweCiphertextVectorView32 {
-            version: LweCiphertextVector32Version::V0,
-            inner: &entity.0,
-        };
-        bincode::serialize(&serializable)
-            .map_err(DefaultSerializationError::Serialization)
-            .map_err(EntitySerializationError::Engine)
+        self.serialize_to_vec(entity)
     }
 
     unsafe fn serialize_unchecked(&mut self, entity: &LweCiphertextVectorView32<'b>) -> Vec<u8> {
@@ -2573,19 +2177,7 @@ impl<'b> EntitySerializationEngine<LweCiphertextVectorView64<'b>, Vec<u8>>
         &mut self,
         entity: &LweCiphertextVectorView64<'b>,
     ) -> Result<Vec<u8>, EntitySerializationError<Self::EngineError>> {
-        #[derive(Serialize)]
-        struct SerializableLweCiphertextVectorView64<'a, 'b> {
-            version: LweCiphertextVector64Version,
-            inner: &'a ImplLweList<&'b [u64]>,
-        }
-
-        let serializable = SerializableLweCiphertextVectorView64 {
-            version: LweCiphertextVector64Version::V0,
-            inner: &entity.0,
-        };
-        bincode::serialize(&serializable)
-            .map_err(DefaultSerializationError::Serialization)
-            .map_err(EntitySerializationError::Engine)
+        self.serialize_to_vec(entity)
     }
 
     unsafe fn serialize_unchecked(&mut self, entity: &LweCiphertextVectorView64<'b>) -> Vec<u8> {
@@ -2642,19 +2234,7 @@ impl<'b> EntitySerializationEngine<LweCiphertextVectorMutView32<'b>, Vec<u8>>
         &mut self,
         entity: &LweCiphertextVectorMutView32<'b>,
     ) -> Result<Vec<u8>, EntitySerializationError<Self::EngineError>> {
-        #[derive(Serialize)]
-        struct SerializableLweCiphertextVectorMutView32<'a, 'b> {
-            version: LweCiphertextVector32Version,
-            inner: &'a ImplLweList<&'b mut [u32]>,
-        }
-
-        let serializable = SerializableLweCiphertextVectorMutView32 {
-            version: LweCiphertextVector32Version::V0,
-            inner: &entity.0,
-        };
-        bincode::serialize(&serializable)
-            .map_err(DefaultSerializationError::Serialization)
-            .map_err(EntitySerializationError::Engine)
+        self.serialize_to_vec(entity)
     }
 
     unsafe fn serialize_unchecked(&mut self, entity: &LweCiphertextVectorMutView32<'b>) -> Vec<u8> {
@@ -2711,19 +2291,7 @@ impl<'b> EntitySerializationEngine<LweCiphertextVectorMutView64<'b>, Vec<u8>>
         &mut self,
         entity: &LweCiphertextVectorMutView64<'b>,
     ) -> Result<Vec<u8>, EntitySerializationError<Self::EngineError>> {
-        #[derive(Serialize)]
-        struct SerializableLweCiphertextVectorMutView64<'a, 'b> {
-            version: LweCiphertextVector64Version,
-            inner: &'a ImplLweList<&'b mut [u64]>,
-        }
-
-        let serializable = SerializableLweCiphertextVectorMutView64 {
-            version: LweCiphertextVector64Version::V0,
-            inner: &entity.0,
-        };
-        bincode::serialize(&serializable)
-            .map_err(DefaultSerializationError::Serialization)
-            .map_err(EntitySerializationError::Engine)
+        self.serialize_to_vec(entity)
     }
 
     unsafe fn serialize_unchecked(&mut self, entity: &LweCiphertextVectorMutView64<'b>) -> Vec<u8> {
@@ -2778,18 +2346,7 @@ impl EntitySerializationEngine<LweKeyswitchKey32, Vec<u8>> for DefaultSerializat
         &mut self,
         entity: &LweKeyswitchKey32,
     ) -> Result<Vec<u8>, EntitySerializationError<Self::EngineError>> {
-        #[derive(Serialize)]
-        struct SerializableLweKeyswitchKey32<'a> {
-            version: LweKeyswitchKey32Version,
-            inner: &'a ImplLweKeyswitchKey<Vec<u32>>,
-        }
-        let serializable = SerializableLweKeyswitchKey32 {
-            version: LweKeyswitchKey32Version::V0,
-            inner: &entity.0,
-        };
-        bincode::serialize(&serializable)
-            .map_err(DefaultSerializationError::Serialization)
-            .map_err(EntitySerializationError::Engine)
+        self.serialize_to_vec(entity)
     }
 
     unsafe fn serialize_unchecked(&mut self, entity: &LweKeyswitchKey32) -> Vec<u8> {
@@ -2844,18 +2401,7 @@ impl EntitySerializationEngine<LweKeyswitchKey64, Vec<u8>> for DefaultSerializat
         &mut self,
         entity: &LweKeyswitchKey64,
     ) -> Result<Vec<u8>, EntitySerializationError<Self::EngineError>> {
-        #[derive(Serialize)]
-        struct SerializableLweKeyswitchKey64<'a> {
-            version: LweKeyswitchKey64Version,
-            inner: &'a ImplLweKeyswitchKey<Vec<u64>>,
-        }
-        let serializable = SerializableLweKeyswitchKey64 {
-            version: LweKeyswitchKey64Version::V0,
-            inner: &entity.0,
-        };
-        bincode::serialize(&serializable)
-            .map_err(DefaultSerializationError::Serialization)
-            .map_err(EntitySerializationError::Engine)
+        self.serialize_to_vec(entity)
     }
 
     unsafe fn serialize_unchecked(&mut self, entity: &LweKeyswitchKey64) -> Vec<u8> {
@@ -2895,18 +2441,7 @@ impl EntitySerializationEngine<LweSecretKey32, Vec<u8>> for DefaultSerialization
         &mut self,
         entity: &LweSecretKey32,
     ) -> Result<Vec<u8>, EntitySerializationError<Self::EngineError>> {
-        #[derive(Serialize)]
-        struct SerializableLweSecretKey32<'a> {
-            version: LweSecretKey32Version,
-            inner: &'a ImplLweSecretKey<BinaryKeyKind, Vec<u32>>,
-        }
-        let serializable = SerializableLweSecretKey32 {
-            version: LweSecretKey32Version::V0,
-            inner: &entity.0,
-        };
-        bincode::serialize(&serializable)
-            .map_err(DefaultSerializationError::Serialization)
-            .map_err(EntitySerializationError::Engine)
+        self.serialize_to_vec(entity)
     }
 
     unsafe fn serialize_unchecked(&mut self, entity: &LweSecretKey32) -> Vec<u8> {
@@ -2946,18 +2481,7 @@ impl EntitySerializationEngine<LweSecretKey64, Vec<u8>> for DefaultSerialization
         &mut self,
         entity: &LweSecretKey64,
     ) -> Result<Vec<u8>, EntitySerializationError<Self::EngineError>> {
-        #[derive(Serialize)]
-        struct SerializableLweSecretKey64<'a> {
-            version: LweSecretKey64Version,
-            inner: &'a ImplLweSecretKey<BinaryKeyKind, Vec<u64>>,
-        }
-        let serializable = SerializableLweSecretKey64 {
-            version: LweSecretKey64Version::V0,
-            inner: &entity.0,
-        };
-        bincode::serialize(&serializable)
-            .map_err(DefaultSerializationError::Serialization)
-            .map_err(EntitySerializationError::Engine)
+        self.serialize_to_vec(entity)
     }
 
     unsafe fn serialize_unchecked(&mut self, entity: &LweSecretKey64) -> Vec<u8> {
@@ -3007,18 +2531,7 @@ impl EntitySerializationEngine<LweSeededBootstrapKey32, Vec<u8>> for DefaultSeri
         &mut self,
         entity: &LweSeededBootstrapKey32,
     ) -> Result<Vec<u8>, EntitySerializationError<Self::EngineError>> {
-        #[derive(Serialize)]
-        struct SerializableLweSeededBootstrapKey32<'a> {
-            version: LweSeededBootstrapKey32Version,
-            inner: &'a ImplStandardSeededBootstrapKey<Vec<u32>>,
-        }
-        let serializable = SerializableLweSeededBootstrapKey32 {
-            version: LweSeededBootstrapKey32Version::V0,
-            inner: &entity.0,
-        };
-        bincode::serialize(&serializable)
-            .map_err(DefaultSerializationError::Serialization)
-            .map_err(EntitySerializationError::Engine)
+        self.serialize_to_vec(entity)
     }
 
     unsafe fn serialize_unchecked(&mut self, entity: &LweSeededBootstrapKey32) -> Vec<u8> {
@@ -3069,18 +2582,7 @@ impl EntitySerializationEngine<LweSeededBootstrapKey64, Vec<u8>> for DefaultSeri
         &mut self,
         entity: &LweSeededBootstrapKey64,
     ) -> Result<Vec<u8>, EntitySerializationError<Self::EngineError>> {
-        #[derive(Serialize)]
-        struct SerializableLweSeededBootstrapKey64<'a> {
-            version: LweSeededBootstrapKey64Version,
-            inner: &'a ImplStandardSeededBootstrapKey<Vec<u64>>,
-        }
-        let serializable = SerializableLweSeededBootstrapKey64 {
-            version: LweSeededBootstrapKey64Version::V0,
-            inner: &entity.0,
-        };
-        bincode::serialize(&serializable)
-            .map_err(DefaultSerializationError::Serialization)
-            .map_err(EntitySerializationError::Engine)
+        self.serialize_to_vec(entity)
     }
 
     unsafe fn serialize_unchecked(&mut self, entity: &LweSeededBootstrapKey64) -> Vec<u8> {
@@ -3127,18 +2629,7 @@ impl EntitySerializationEngine<LweSeededCiphertext32, Vec<u8>> for DefaultSerial
         &mut self,
         entity: &LweSeededCiphertext32,
     ) -> Result<Vec<u8>, EntitySerializationError<Self::EngineError>> {
-        #[derive(Serialize)]
-        struct SerializableLweSeededCiphertext32<'a> {
-            version: LweSeededCiphertext32Version,
-            inner: &'a ImplLweSeededCiphertext<u32>,
-        }
-        let serializable = SerializableLweSeededCiphertext32 {
-            version: LweSeededCiphertext32Version::V0,
-            inner: &entity.0,
-        };
-        bincode::serialize(&serializable)
-            .map_err(DefaultSerializationError::Serialization)
-            .map_err(EntitySerializationError::Engine)
+        self.serialize_to_vec(entity)
     }
 
     unsafe fn serialize_unchecked(&mut self, entity: &LweSeededCiphertext32) -> Vec<u8> {
@@ -3185,18 +2676,7 @@ impl EntitySerializationEngine<LweSeededCiphertext64, Vec<u8>> for DefaultSerial
         &mut self,
         entity: &LweSeededCiphertext64,
     ) -> Result<Vec<u8>, EntitySerializationError<Self::EngineError>> {
-        #[derive(Serialize)]
-        struct SerializableLweSeededCiphertext64<'a> {
-            version: LweSeededCiphertext64Version,
-            inner: &'a ImplLweSeededCiphertext<u64>,
-        }
-        let serializable = SerializableLweSeededCiphertext64 {
-            version: LweSeededCiphertext64Version::V0,
-            inner: &entity.0,
-        };
-        bincode::serialize(&serializable)
-            .map_err(DefaultSerializationError::Serialization)
-            .map_err(EntitySerializationError::Engine)
+        self.serialize_to_vec(entity)
     }
 
     unsafe fn serialize_unchecked(&mut self, entity: &LweSeededCiphertext64) -> Vec<u8> {
@@ -3204,6 +2684,102 @@ impl EntitySerializationEngine<LweSeededCiphertext64, Vec<u8>> for DefaultSerial
     }
 }
 
+/// # Description:
+/// Implementation of [`EntitySerializationEngine`] for [`DefaultSerializationEngine`] that operates
+/// on 32 bits integers. It serializes a compressed LWE ciphertext entity.
+impl EntitySerializationEngine<LweCompressedCiphertext32, Vec<u8>> for DefaultSerializationEngine {
+    /// # Example:
+    /// ```
+    /// use concrete_core::prelude::{LweDimension, Variance, *};
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let lwe_dimension = LweDimension(2);
+    /// // Here a hard-set encoding is applied (shift by 20 bits)
+    /// let input = 3_u32 << 20;
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let key: LweSecretKey32 = engine.generate_new_lwe_secret_key(lwe_dimension)?;
+    /// let plaintext = engine.create_plaintext_from(&input)?;
+    ///
+    /// let ciphertext = engine.encrypt_lwe_ciphertext(&key, &plaintext, noise)?;
+    /// let compressed_ciphertext: LweCompressedCiphertext32 =
+    ///     engine.compress_lwe_ciphertext(&ciphertext, CiphertextModulusLog(16))?;
+    ///
+    /// let mut serialization_engine = DefaultSerializationEngine::new(())?;
+    /// let serialized = serialization_engine.serialize(&compressed_ciphertext)?;
+    /// let recovered = serialization_engine.deserialize(serialized.as_slice())?;
+    /// assert_eq!(compressed_ciphertext, recovered);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn serialize(
+        &mut self,
+        entity: &LweCompressedCiphertext32,
+    ) -> Result<Vec<u8>, EntitySerializationError<Self::EngineError>> {
+        self.serialize_to_vec(entity)
+    }
+
+    unsafe fn serialize_unchecked(&mut self, entity: &LweCompressedCiphertext32) -> Vec<u8> {
+        self.serialize(entity).unwrap()
+    }
+}
+
+/// # Description:
+/// Implementation of [`EntitySerializationEngine`] for [`DefaultSerializationEngine`] that operates
+/// on 64 bits integers. It serializes a compressed LWE ciphertext entity.
+impl EntitySerializationEngine<LweCompressedCiphertext64, Vec<u8>> for DefaultSerializationEngine {
+    /// # Example:
+    /// ```
+    /// use concrete_core::prelude::{LweDimension, Variance, *};
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let lwe_dimension = LweDimension(2);
+    /// // Here a hard-set encoding is applied (shift by 50 bits)
+    /// let input = 3_u64 << 50;
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let key: LweSecretKey64 = engine.generate_new_lwe_secret_key(lwe_dimension)?;
+    /// let plaintext = engine.create_plaintext_from(&input)?;
+    ///
+    /// let ciphertext = engine.encrypt_lwe_ciphertext(&key, &plaintext, noise)?;
+    /// let compressed_ciphertext: LweCompressedCiphertext64 =
+    ///     engine.compress_lwe_ciphertext(&ciphertext, CiphertextModulusLog(16))?;
+    ///
+    /// let mut serialization_engine = DefaultSerializationEngine::new(())?;
+    /// let serialized = serialization_engine.serialize(&compressed_ciphertext)?;
+    /// let recovered = serialization_engine.deserialize(serialized.as_slice())?;
+    /// assert_eq!(compressed_ciphertext, recovered);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn serialize(
+        &mut self,
+        entity: &LweCompressedCiphertext64,
+    ) -> Result<Vec<u8>, EntitySerializationError<Self::EngineError>> {
+        self.serialize_to_vec(entity)
+    }
+
+    unsafe fn serialize_unchecked(&mut self, entity: &LweCompressedCiphertext64) -> Vec<u8> {
+        self.serialize(entity).unwrap()
+    }
+}
+
 /// # Description:
 /// Implementation of [`EntitySerializationEngine`] for [`DefaultSerializationEngine`] that operates
 /// on 32 bits integers. It serializes a seeded LWE ciphertext vector entity.
@@ -3245,18 +2821,7 @@ impl EntitySerializationEngine<LweSeededCiphertextVector32, Vec<u8>>
         &mut self,
         entity: &LweSeededCiphertextVector32,
     ) -> Result<Vec<u8>, EntitySerializationError<Self::EngineError>> {
-        #[derive(Serialize)]
-        struct SerializableLweSeededCiphertextVector32<'a> {
-            version: LweSeededCiphertextVector32Version,
-            inner: &'a ImplLweSeededList<Vec<u32>>,
-        }
-        let serializable = SerializableLweSeededCiphertextVector32 {
-            version: LweSeededCiphertextVector32Version::V0,
-            inner: &entity.0,
-        };
-        bincode::serialize(&serializable)
-            .map_err(DefaultSerializationError::Serialization)
-            .map_err(EntitySerializationError::Engine)
+        self.serialize_to_vec(entity)
     }
 
     unsafe fn serialize_unchecked(&mut self, entity: &LweSeededCiphertextVector32) -> Vec<u8> {
@@ -3305,18 +2870,7 @@ impl EntitySerializationEngine<LweSeededCiphertextVector64, Vec<u8>>
         &mut self,
         entity: &LweSeededCiphertextVector64,
     ) -> Result<Vec<u8>, EntitySerializationError<Self::EngineError>> {
-        #[derive(Serialize)]
-        struct SerializableLweSeededCiphertextVector64<'a> {
-            version: LweSeededCiphertextVector64Version,
-            inner: &'a ImplLweSeededList<Vec<u64>>,
-        }
-        let serializable = SerializableLweSeededCiphertextVector64 {
-            version: LweSeededCiphertextVector64Version::V0,
-            inner: &entity.0,
-        };
-        bincode::serialize(&serializable)
-            .map_err(DefaultSerializationError::Serialization)
-            .map_err(EntitySerializationError::Engine)
+        self.serialize_to_vec(entity)
     }
 
     unsafe fn serialize_unchecked(&mut self, entity: &LweSeededCiphertextVector64) -> Vec<u8> {
@@ -3371,18 +2925,7 @@ impl EntitySerializationEngine<LweSeededKeyswitchKey32, Vec<u8>> for DefaultSeri
         &mut self,
         entity: &LweSeededKeyswitchKey32,
     ) -> Result<Vec<u8>, EntitySerializationError<Self::EngineError>> {
-        #[derive(Serialize)]
-        struct LweSeededKeyswitchKey32<'a> {
-            version: LweSeededKeyswitchKey32Version,
-            inner: &'a ImplLweSeededKeyswitchKey<Vec<u32>>,
-        }
-        let serializable = LweSeededKeyswitchKey32 {
-            version: LweSeededKeyswitchKey32Version::V0,
-            inner: &entity.0,
-        };
-        bincode::serialize(&serializable)
-            .map_err(DefaultSerializationError::Serialization)
-            .map_err(EntitySerializationError::Engine)
+        self.serialize_to_vec(entity)
     }
 
     unsafe fn serialize_unchecked(&mut self, entity: &LweSeededKeyswitchKey32) -> Vec<u8> {
@@ -3437,18 +2980,7 @@ impl EntitySerializationEngine<LweSeededKeyswitchKey64, Vec<u8>> for DefaultSeri
         &mut self,
         entity: &LweSeededKeyswitchKey64,
     ) -> Result<Vec<u8>, EntitySerializationError<Self::EngineError>> {
-        #[derive(Serialize)]
-        struct LweSeededKeyswitchKey64<'a> {
-            version: LweSeededKeyswitchKey64Version,
-            inner: &'a ImplLweSeededKeyswitchKey<Vec<u64>>,
-        }
-        let serializable = LweSeededKeyswitchKey64 {
-            version: LweSeededKeyswitchKey64Version::V0,
-            inner: &entity.0,
-        };
-        bincode::serialize(&serializable)
-            .map_err(DefaultSerializationError::Serialization)
-            .map_err(EntitySerializationError::Engine)
+        self.serialize_to_vec(entity)
     }
 
     unsafe fn serialize_unchecked(&mut self, entity: &LweSeededKeyswitchKey64) -> Vec<u8> {
@@ -3503,18 +3035,7 @@ impl EntitySerializationEngine<LwePackingKeyswitchKey32, Vec<u8>> for DefaultSer
         &mut self,
         entity: &LwePackingKeyswitchKey32,
     ) -> Result<Vec<u8>, EntitySerializationError<Self::EngineError>> {
-        #[derive(Serialize)]
-        struct SerializablePackingKeyswitchKey32<'a> {
-            version: LwePackingKeyswitchKey32Version,
-            inner: &'a ImplLwePackingKeyswitchKey<Vec<u32>>,
-        }
-        let serializable = SerializablePackingKeyswitchKey32 {
-            version: LwePackingKeyswitchKey32Version::V0,
-            inner: &entity.0,
-        };
-        bincode::serialize(&serializable)
-            .map_err(DefaultSerializationError::Serialization)
-            .map_err(EntitySerializationError::Engine)
+        self.serialize_to_vec(entity)
     }
 
     unsafe fn serialize_unchecked(&mut self, entity: &LwePackingKeyswitchKey32) -> Vec<u8> {
@@ -3569,18 +3090,7 @@ impl EntitySerializationEngine<LwePackingKeyswitchKey64, Vec<u8>> for DefaultSer
         &mut self,
         entity: &LwePackingKeyswitchKey64,
     ) -> Result<Vec<u8>, EntitySerializationError<Self::EngineError>> {
-        #[derive(Serialize)]
-        struct SerializablePackingKeyswitchKey64<'a> {
-            version: LwePackingKeyswitchKey64Version,
-            inner: &'a ImplLwePackingKeyswitchKey<Vec<u64>>,
-        }
-        let serializable = SerializablePackingKeyswitchKey64 {
-            version: LwePackingKeyswitchKey64Version::V0,
-            inner: &entity.0,
-        };
-        bincode::serialize(&serializable)
-            .map_err(DefaultSerializationError::Serialization)
-            .map_err(EntitySerializationError::Engine)
+        self.serialize_to_vec(entity)
     }
 
     unsafe fn serialize_unchecked(&mut self, entity: &LwePackingKeyswitchKey64) -> Vec<u8> {
@@ -3628,18 +3138,7 @@ impl EntitySerializationEngine<LwePublicKey32, Vec<u8>> for DefaultSerialization
         &mut self,
         entity: &LwePublicKey32,
     ) -> Result<Vec<u8>, EntitySerializationError<Self::EngineError>> {
-        #[derive(Serialize)]
-        struct SerializableLwePublicKey32<'a> {
-            version: LwePublicKey32Version,
-            inner: &'a ImplLweList<Vec<u32>>,
-        }
-        let serializable = SerializableLwePublicKey32 {
-            version: LwePublicKey32Version::V0,
-            inner: &entity.0,
-        };
-        bincode::serialize(&serializable)
-            .map_err(DefaultSerializationError::Serialization)
-            .map_err(EntitySerializationError::Engine)
+        self.serialize_to_vec(entity)
     }
 
     unsafe fn serialize_unchecked(&mut self, entity: &LwePublicKey32) -> Vec<u8> {
@@ -3687,18 +3186,7 @@ impl EntitySerializationEngine<LwePublicKey64, Vec<u8>> for DefaultSerialization
         &mut self,
         entity: &LwePublicKey64,
     ) -> Result<Vec<u8>, EntitySerializationError<Self::EngineError>> {
-        #[derive(Serialize)]
-        struct SerializableLwePublicKey64<'a> {
-            version: LwePublicKey64Version,
-            inner: &'a ImplLweList<Vec<u64>>,
-        }
-        let serializable = SerializableLwePublicKey64 {
-            version: LwePublicKey64Version::V0,
-            inner: &entity.0,
-        };
-        bincode::serialize(&serializable)
-            .map_err(DefaultSerializationError::Serialization)
-            .map_err(EntitySerializationError::Engine)
+        self.serialize_to_vec(entity)
     }
 
     unsafe fn serialize_unchecked(&mut self, entity: &LwePublicKey64) -> Vec<u8> {
@@ -3738,18 +3226,7 @@ impl EntitySerializationEngine<Plaintext32, Vec<u8>> for DefaultSerializationEng
         &mut self,
         entity: &Plaintext32,
     ) -> Result<Vec<u8>, EntitySerializationError<Self::EngineError>> {
-        #[derive(Serialize)]
-        struct SerializablePlaintext32<'a> {
-            version: Plaintext32Version,
-            inner: &'a ImplPlaintext<u32>,
-        }
-        let serializable = SerializablePlaintext32 {
-            version: Plaintext32Version::V0,
-            inner: &entity.0,
-        };
-        bincode::serialize(&serializable)
-            .map_err(DefaultSerializationError::Serialization)
-            .map_err(EntitySerializationError::Engine)
+        self.serialize_to_vec(entity)
     }
 
     unsafe fn serialize_unchecked(&mut self, entity: &Plaintext32) -> Vec<u8> {
@@ -3788,18 +3265,7 @@ impl EntitySerializationEngine<Plaintext64, Vec<u8>> for DefaultSerializationEng
         &mut self,
         entity: &Plaintext64,
     ) -> Result<Vec<u8>, EntitySerializationError<Self::EngineError>> {
-        #[derive(Serialize)]
-        struct SerializablePlaintext64<'a> {
-            version: Plaintext64Version,
-            inner: &'a ImplPlaintext<u64>,
-        }
-        let serializable = SerializablePlaintext64 {
-            version: Plaintext64Version::V0,
-            inner: &entity.0,
-        };
-        bincode::serialize(&serializable)
-            .map_err(DefaultSerializationError::Serialization)
-            .map_err(EntitySerializationError::Engine)
+        self.serialize_to_vec(entity)
     }
 
     unsafe fn serialize_unchecked(&mut self, entity: &Plaintext64) -> Vec<u8> {
@@ -3839,18 +3305,7 @@ impl EntitySerializationEngine<PlaintextVector32, Vec<u8>> for DefaultSerializat
         &mut self,
         entity: &PlaintextVector32,
     ) -> Result<Vec<u8>, EntitySerializationError<Self::EngineError>> {
-        #[derive(Serialize)]
-        struct SerializablePlaintextVector32<'a> {
-            version: PlaintextVector32Version,
-            inner: &'a ImplPlaintextList<Vec<u32>>,
-        }
-        let serializable = SerializablePlaintextVector32 {
-            version: PlaintextVector32Version::V0,
-            inner: &entity.0,
-        };
-        bincode::serialize(&serializable)
-            .map_err(DefaultSerializationError::Serialization)
-            .map_err(EntitySerializationError::Engine)
+        self.serialize_to_vec(entity)
     }
 
     unsafe fn serialize_unchecked(&mut self, entity: &PlaintextVector32) -> Vec<u8> {
@@ -3890,18 +3345,7 @@ impl EntitySerializationEngine<PlaintextVector64, Vec<u8>> for DefaultSerializat
         &mut self,
         entity: &PlaintextVector64,
     ) -> Result<Vec<u8>, EntitySerializationError<Self::EngineError>> {
-        #[derive(Serialize)]
-        struct SerializablePlaintextVector64<'a> {
-            version: PlaintextVector64Version,
-            inner: &'a ImplPlaintextList<Vec<u64>>,
-        }
-        let serializable = SerializablePlaintextVector64 {
-            version: PlaintextVector64Version::V0,
-            inner: &entity.0,
-        };
-        bincode::serialize(&serializable)
-            .map_err(DefaultSerializationError::Serialization)
-            .map_err(EntitySerializationError::Engine)
+        self.serialize_to_vec(entity)
     }
 
     unsafe fn serialize_unchecked(&mut self, entity: &PlaintextVector64) -> Vec<u8> {
@@ -3944,18 +3388,7 @@ impl EntitySerializationEngine<FloatEncoder, Vec<u8>> for DefaultSerializationEn
         &mut self,
         entity: &FloatEncoder,
     ) -> Result<Vec<u8>, EntitySerializationError<Self::EngineError>> {
-        #[derive(Serialize)]
-        struct SerializableFloatEncoder<'a> {
-            version: FloatEncoderVersion,
-            inner: &'a ImplFloatEncoder,
-        }
-        let serializable = SerializableFloatEncoder {
-            version: FloatEncoderVersion::V0,
-            inner: &entity.0,
-        };
-        bincode::serialize(&serializable)
-            .map_err(DefaultSerializationError::Serialization)
-            .map_err(EntitySerializationError::Engine)
+        self.serialize_to_vec(entity)
     }
 
     unsafe fn serialize_unchecked(&mut self, entity: &FloatEncoder) -> Vec<u8> {
@@ -4002,18 +3435,7 @@ impl EntitySerializationEngine<FloatEncoderVector, Vec<u8>> for DefaultSerializa
         &mut self,
         entity: &FloatEncoderVector,
     ) -> Result<Vec<u8>, EntitySerializationError<Self::EngineError>> {
-        #[derive(Serialize)]
-        struct SerializableFloatEncoderVector<'a> {
-            version: FloatEncoderVectorVersion,
-            inner: &'a Vec<ImplFloatEncoder>,
-        }
-        let serializable = SerializableFloatEncoderVector {
-            version: FloatEncoderVectorVersion::V0,
-            inner: &entity.0,
-        };
-        bincode::serialize(&serializable)
-            .map_err(DefaultSerializationError::Serialization)
-            .map_err(EntitySerializationError::Engine)
+        self.serialize_to_vec(entity)
     }
 
     unsafe fn serialize_unchecked(&mut self, entity: &FloatEncoderVector) -> Vec<u8> {
@@ -0,0 +1,210 @@
+#![allow(clippy::missing_safety_doc)]
+
+use crate::commons::crypto::lwe::LweKeyswitchKey as ImplLweKeyswitchKey;
+use crate::prelude::{
+    DecompositionBaseLog, DecompositionLevelCount, DefaultSerializationEngine,
+    EntityMappedDeserializationEngine, EntityMappedDeserializationError, LweDimension,
+    LweKeyswitchKeyView32, LweKeyswitchKeyView64,
+};
+use crate::specification::mapped::{
+    non_zero_parameter, payload_len, slice_from_payload, MappedEntityKind, MappedFormatError,
+    MappedHeader,
+};
+
+/// # Description:
+/// Implementation of [`EntityMappedDeserializationEngine`] for [`DefaultSerializationEngine`] that
+/// operates on 32 bits integers. It creates a view to an LWE keyswitch key from bytes in the
+/// mapped format, without copying them.
+impl<'data> EntityMappedDeserializationEngine<&'data [u8], LweKeyswitchKeyView32<'data>>
+    for DefaultSerializationEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let input_lwe_dimension = LweDimension(6);
+    /// let output_lwe_dimension = LweDimension(3);
+    /// let decomposition_level_count = DecompositionLevelCount(2);
+    /// let decomposition_base_log = DecompositionBaseLog(8);
+    /// let noise = Variance(2_f64.powf(-25.));
+    /// // Here a hard-set encoding is applied (shift by 20 bits)
+    /// let input = 3_u32 << 20;
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let input_key: LweSecretKey32 = engine.generate_new_lwe_secret_key(input_lwe_dimension)?;
+    /// let output_key: LweSecretKey32 = engine.generate_new_lwe_secret_key(output_lwe_dimension)?;
+    /// let keyswitch_key = engine.generate_new_lwe_keyswitch_key(
+    ///     &input_key,
+    ///     &output_key,
+    ///     decomposition_level_count,
+    ///     decomposition_base_log,
+    ///     noise,
+    /// )?;
+    ///
+    /// let mut serialization_engine = DefaultSerializationEngine::new(())?;
+    /// let mut serialized = Vec::new();
+    /// serialization_engine.serialize_mapped_into(&keyswitch_key, &mut serialized)?;
+    ///
+    /// // The bytes would usually be memory-mapped from a file.
+    /// let bytes = MappedBytes::read_from(&mut serialized.as_slice())?;
+    /// let keyswitch_key_view: LweKeyswitchKeyView32 =
+    ///     serialization_engine.deserialize_mapped(bytes.as_slice())?;
+    ///
+    /// let plaintext = engine.create_plaintext_from(&input)?;
+    /// let ciphertext_1 = engine.encrypt_lwe_ciphertext(&input_key, &plaintext, noise)?;
+    /// let mut ciphertext_2 = engine.zero_encrypt_lwe_ciphertext(&output_key, noise)?;
+    ///
+    /// engine.discard_keyswitch_lwe_ciphertext(
+    ///     &mut ciphertext_2,
+    ///     &ciphertext_1,
+    ///     &keyswitch_key_view,
+    /// )?;
+    /// #
+    /// assert_eq!(ciphertext_2.lwe_dimension(), output_lwe_dimension);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn deserialize_mapped(
+        &mut self,
+        bytes: &'data [u8],
+    ) -> Result<LweKeyswitchKeyView32<'data>, EntityMappedDeserializationError<Self::EngineError>>
+    {
+        EntityMappedDeserializationError::perform_generic_checks(
+            bytes,
+            MappedEntityKind::LweKeyswitchKey32,
+        )?;
+        Ok(LweKeyswitchKeyView32(keyswitch_key_from_mapped(
+            bytes,
+            MappedEntityKind::LweKeyswitchKey32,
+        )?))
+    }
+
+    unsafe fn deserialize_mapped_unchecked(
+        &mut self,
+        bytes: &'data [u8],
+    ) -> LweKeyswitchKeyView32<'data> {
+        LweKeyswitchKeyView32(
+            keyswitch_key_from_mapped(bytes, MappedEntityKind::LweKeyswitchKey32).unwrap(),
+        )
+    }
+}
+
+/// # Description:
+/// Implementation of [`EntityMappedDeserializationEngine`] for [`DefaultSerializationEngine`] that
+/// operates on 64 bits integers. It creates a view to an LWE keyswitch key from bytes in the
+/// mapped format, without copying them.
+impl<'data> EntityMappedDeserializationEngine<&'data [u8], LweKeyswitchKeyView64<'data>>
+    for DefaultSerializationEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let input_lwe_dimension = LweDimension(6);
+    /// let output_lwe_dimension = LweDimension(3);
+    /// let decomposition_level_count = DecompositionLevelCount(2);
+    /// let decomposition_base_log = DecompositionBaseLog(8);
+    /// let noise = Variance(2_f64.powf(-50.));
+    /// // Here a hard-set encoding is applied (shift by 50 bits)
+    /// let input = 3_u64 << 50;
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let input_key: LweSecretKey64 = engine.generate_new_lwe_secret_key(input_lwe_dimension)?;
+    /// let output_key: LweSecretKey64 = engine.generate_new_lwe_secret_key(output_lwe_dimension)?;
+    /// let keyswitch_key = engine.generate_new_lwe_keyswitch_key(
+    ///     &input_key,
+    ///     &output_key,
+    ///     decomposition_level_count,
+    ///     decomposition_base_log,
+    ///     noise,
+    /// )?;
+    ///
+    /// let mut serialization_engine = DefaultSerializationEngine::new(())?;
+    /// let mut serialized = Vec::new();
+    /// serialization_engine.serialize_mapped_into(&keyswitch_key, &mut serialized)?;
+    ///
+    /// // The bytes would usually be memory-mapped from a file.
+    /// let bytes = MappedBytes::read_from(&mut serialized.as_slice())?;
+    /// let keyswitch_key_view: LweKeyswitchKeyView64 =
+    ///     serialization_engine.deserialize_mapped(bytes.as_slice())?;
+    ///
+    /// let plaintext = engine.create_plaintext_from(&input)?;
+    /// let ciphertext_1 = engine.encrypt_lwe_ciphertext(&input_key, &plaintext, noise)?;
+    /// let mut ciphertext_2 = engine.zero_encrypt_lwe_ciphertext(&output_key, noise)?;
+    ///
+    /// engine.discard_keyswitch_lwe_ciphertext(
+    ///     &mut ciphertext_2,
+    ///     &ciphertext_1,
+    ///     &keyswitch_key_view,
+    /// )?;
+    /// #
+    /// assert_eq!(ciphertext_2.lwe_dimension(), output_lwe_dimension);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn deserialize_mapped(
+        &mut self,
+        bytes: &'data [u8],
+    ) -> Result<LweKeyswitchKeyView64<'data>, EntityMappedDeserializationError<Self::EngineError>>
+    {
+        EntityMappedDeserializationError::perform_generic_checks(
+            bytes,
+            MappedEntityKind::LweKeyswitchKey64,
+        )?;
+        Ok(LweKeyswitchKeyView64(keyswitch_key_from_mapped(
+            bytes,
+            MappedEntityKind::LweKeyswitchKey64,
+        )?))
+    }
+
+    unsafe fn deserialize_mapped_unchecked(
+        &mut self,
+        bytes: &'data [u8],
+    ) -> LweKeyswitchKeyView64<'data> {
+        LweKeyswitchKeyView64(
+            keyswitch_key_from_mapped(bytes, MappedEntityKind::LweKeyswitchKey64).unwrap(),
+        )
+    }
+}
+
+/// Creates a keyswitch key using the payload of `bytes` as storage.
+fn keyswitch_key_from_mapped<Scalar: Copy>(
+    bytes: &[u8],
+    kind: MappedEntityKind,
+) -> Result<ImplLweKeyswitchKey<&[Scalar]>, MappedFormatError> {
+    let (header, payload) = MappedHeader::split_payload(bytes, kind)?;
+    let [input_lwe_dimension, output_lwe_dimension, base_log, level_count, _] = header.parameters;
+    let output_lwe_size = output_lwe_dimension
+        .checked_add(1)
+        .ok_or(MappedFormatError::InvalidHeader)?;
+    let base_log = non_zero_parameter(base_log)?;
+    let level_count = non_zero_parameter(level_count)?;
+    let expected_len = payload_len(&[input_lwe_dimension, output_lwe_size, level_count as u64])?;
+    // SAFETY: every bit pattern is a valid integer.
+    let data = unsafe { slice_from_payload::<Scalar>(payload)? };
+    if data.len() != expected_len {
+        return Err(MappedFormatError::InvalidPayloadSize);
+    }
+    Ok(ImplLweKeyswitchKey::from_container(
+        data,
+        DecompositionBaseLog(base_log),
+        DecompositionLevelCount(level_count),
+        LweDimension(output_lwe_dimension as usize),
+    ))
+}
@@ -0,0 +1,181 @@
+#![allow(clippy::missing_safety_doc)]
+
+use crate::commons::math::tensor::{AsRefSlice, AsRefTensor};
+use crate::prelude::{
+    DefaultSerializationEngine, DefaultSerializationError, EntityMappedSerializationEngine,
+    EntityMappedSerializationError, LweKeyswitchKey32, LweKeyswitchKey64, LweKeyswitchKeyEntity,
+};
+use crate::specification::mapped::{payload_from_slice, MappedEntityKind, MappedHeader};
+use std::io::Write;
+
+/// # Description:
+/// Implementation of [`EntityMappedSerializationEngine`] for [`DefaultSerializationEngine`] that
+/// operates on 32 bits integers. It serializes an LWE keyswitch key in the mapped format.
+impl<Writer: Write> EntityMappedSerializationEngine<LweKeyswitchKey32, Writer>
+    for DefaultSerializationEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let input_lwe_dimension = LweDimension(6);
+    /// let output_lwe_dimension = LweDimension(3);
+    /// let decomposition_level_count = DecompositionLevelCount(2);
+    /// let decomposition_base_log = DecompositionBaseLog(8);
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let input_key: LweSecretKey32 = engine.generate_new_lwe_secret_key(input_lwe_dimension)?;
+    /// let output_key: LweSecretKey32 = engine.generate_new_lwe_secret_key(output_lwe_dimension)?;
+    /// let keyswitch_key = engine.generate_new_lwe_keyswitch_key(
+    ///     &input_key,
+    ///     &output_key,
+    ///     decomposition_level_count,
+    ///     decomposition_base_log,
+    ///     noise,
+    /// )?;
+    ///
+    /// let mut serialization_engine = DefaultSerializationEngine::new(())?;
+    /// let mut serialized = Vec::new();
+    /// serialization_engine.serialize_mapped_into(&keyswitch_key, &mut serialized)?;
+    ///
+    /// // The bytes would usually be memory-mapped from a file.
+    /// let bytes = MappedBytes::read_from(&mut serialized.as_slice())?;
+    /// let keyswitch_key_view: LweKeyswitchKeyView32 =
+    ///     serialization_engine.deserialize_mapped(bytes.as_slice())?;
+    /// assert_eq!(
+    ///     keyswitch_key_view.input_lwe_dimension(),
+    ///     keyswitch_key.input_lwe_dimension()
+    /// );
+    /// assert_eq!(
+    ///     keyswitch_key_view.output_lwe_dimension(),
+    ///     keyswitch_key.output_lwe_dimension()
+    /// );
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn serialize_mapped_into(
+        &mut self,
+        entity: &LweKeyswitchKey32,
+        writer: &mut Writer,
+    ) -> Result<(), EntityMappedSerializationError<Self::EngineError>> {
+        let payload = payload_from_slice(entity.0.as_tensor().as_slice());
+        let header = MappedHeader {
+            kind: MappedEntityKind::LweKeyswitchKey32,
+            parameters: [
+                entity.input_lwe_dimension().0 as u64,
+                entity.output_lwe_dimension().0 as u64,
+                entity.decomposition_base_log().0 as u64,
+                entity.decomposition_level_count().0 as u64,
+                0,
+            ],
+            payload_size: payload.len() as u64,
+        };
+        writer
+            .write_all(&header.to_bytes())
+            .and_then(|_| writer.write_all(payload))
+            .map_err(|err| DefaultSerializationError::Serialization(err.into()))
+            .map_err(EntityMappedSerializationError::Engine)
+    }
+
+    unsafe fn serialize_mapped_into_unchecked(
+        &mut self,
+        entity: &LweKeyswitchKey32,
+        writer: &mut Writer,
+    ) {
+        self.serialize_mapped_into(entity, writer).unwrap()
+    }
+}
+
+/// # Description:
+/// Implementation of [`EntityMappedSerializationEngine`] for [`DefaultSerializationEngine`] that
+/// operates on 64 bits integers. It serializes an LWE keyswitch key in the mapped format.
+impl<Writer: Write> EntityMappedSerializationEngine<LweKeyswitchKey64, Writer>
+    for DefaultSerializationEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let input_lwe_dimension = LweDimension(6);
+    /// let output_lwe_dimension = LweDimension(3);
+    /// let decomposition_level_count = DecompositionLevelCount(2);
+    /// let decomposition_base_log = DecompositionBaseLog(8);
+    /// let noise = Variance(2_f64.powf(-50.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let input_key: LweSecretKey64 = engine.generate_new_lwe_secret_key(input_lwe_dimension)?;
+    /// let output_key: LweSecretKey64 = engine.generate_new_lwe_secret_key(output_lwe_dimension)?;
+    /// let keyswitch_key = engine.generate_new_lwe_keyswitch_key(
+    ///     &input_key,
+    ///     &output_key,
+    ///     decomposition_level_count,
+    ///     decomposition_base_log,
+    ///     noise,
+    /// )?;
+    ///
+    /// let mut serialization_engine = DefaultSerializationEngine::new(())?;
+    /// let mut serialized = Vec::new();
+    /// serialization_engine.serialize_mapped_into(&keyswitch_key, &mut serialized)?;
+    ///
+    /// // The bytes would usually be memory-mapped from a file.
+    /// let bytes = MappedBytes::read_from(&mut serialized.as_slice())?;
+    /// let keyswitch_key_view: LweKeyswitchKeyView64 =
+    ///     serialization_engine.deserialize_mapped(bytes.as_slice())?;
+    /// assert_eq!(
+    ///     keyswitch_key_view.input_lwe_dimension(),
+    ///     keyswitch_key.input_lwe_dimension()
+    /// );
+    /// assert_eq!(
+    ///     keyswitch_key_view.output_lwe_dimension(),
+    ///     keyswitch_key.output_lwe_dimension()
+    /// );
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn serialize_mapped_into(
+        &mut self,
+        entity: &LweKeyswitchKey64,
+        writer: &mut Writer,
+    ) -> Result<(), EntityMappedSerializationError<Self::EngineError>> {
+        let payload = payload_from_slice(entity.0.as_tensor().as_slice());
+        let header = MappedHeader {
+            kind: MappedEntityKind::LweKeyswitchKey64,
+            parameters: [
+                entity.input_lwe_dimension().0 as u64,
+                entity.output_lwe_dimension().0 as u64,
+                entity.decomposition_base_log().0 as u64,
+                entity.decomposition_level_count().0 as u64,
+                0,
+            ],
+            payload_size: payload.len() as u64,
+        };
+        writer
+            .write_all(&header.to_bytes())
+            .and_then(|_| writer.write_all(payload))
+            .map_err(|err| DefaultSerializationError::Serialization(err.into()))
+            .map_err(EntityMappedSerializationError::Engine)
+    }
+
+    unsafe fn serialize_mapped_into_unchecked(
+        &mut self,
+        entity: &LweKeyswitchKey64,
+        writer: &mut Writer,
+    ) {
+        self.serialize_mapped_into(entity, writer).unwrap()
+    }
+}
@@ -1,7 +1,15 @@
+use crate::commons::crypto::validation::ValidateExpansion;
 use crate::prelude::sealed::AbstractEngineSeal;
-use crate::prelude::AbstractEngine;
+use crate::prelude::{
+    AbstractEngine, AbstractEntity, EntityDeserializationError, EntitySerializationError,
+    EntityStreamDeserializationEngine, EntityStreamDeserializationError,
+    EntityStreamSerializationEngine, EntityStreamSerializationError,
+};
+use bincode::Options;
+use serde::de::DeserializeOwned;
 use std::error::Error;
 use std::fmt::{Display, Formatter};
+use std::io::Read;
 
 /// The error which can occur in the executions of the `DefaultSerializationEngine` operations.
 #[derive(Debug)]
@@ -34,7 +42,167 @@ impl Display for DefaultSerializationError {
 
 impl Error for DefaultSerializationError {}
 
-pub struct DefaultSerializationEngine;
+pub struct DefaultSerializationEngine {
+    size_limit: Option<u64>,
+}
+
+impl DefaultSerializationEngine {
+    /// Creates an engine which refuses to deserialize entities larger than `size_limit` bytes.
+    ///
+    /// Engines created with [`AbstractEngine::new`] have no size limit, which is fine for trusted
+    /// data. When deserializing data coming from untrusted sources, the limit prevents a forged
+    /// length prefix from triggering an arbitrarily large allocation. The limit also bounds the size
+    /// of seeded entities once expanded, which can be much larger than their serialized form. In
+    /// both cases, the invariants of every deserialized entity are checked before it is returned.
+    ///
+    /// # Example
+    /// ```
+    /// use concrete_core::prelude::{LweDimension, *};
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let lwe_dimension = LweDimension(6);
+    /// // Here a hard-set encoding is applied (shift by 20 bits)
+    /// let input = 3_u32 << 20;
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let key: LweSecretKey32 = engine.generate_new_lwe_secret_key(lwe_dimension)?;
+    /// let plaintext = engine.create_plaintext_from(&input)?;
+    /// let ciphertext = engine.encrypt_lwe_ciphertext(&key, &plaintext, noise)?;
+    ///
+    /// let mut serialization_engine = DefaultSerializationEngine::new(())?;
+    /// let serialized = serialization_engine.serialize(&ciphertext)?;
+    ///
+    /// // A ciphertext of dimension 6 fits in 1 kB, but not in 16 bytes.
+    /// let mut bounded_engine = DefaultSerializationEngine::with_size_limit(1024);
+    /// let recovered: LweCiphertext32 = bounded_engine.deserialize(serialized.as_slice())?;
+    /// assert_eq!(ciphertext, recovered);
+    /// let mut tight_engine = DefaultSerializationEngine::with_size_limit(16);
+    /// let result: Result<LweCiphertext32, _> = tight_engine.deserialize(serialized.as_slice());
+    /// assert!(matches!(
+    ///     result,
+    ///     Err(EntityDeserializationError::SizeLimitExceeded)
+    /// ));
+    ///
+    /// // The serialized ciphertext holds a version tag on 4 bytes, the length of the ciphertext on
+    /// // 8 bytes, and its 7 coefficients. Removing the mask leaves a ciphertext of null dimension,
+    /// // which is rejected.
+    /// let mut forged = serialized[..16].to_vec();
+    /// forged[4..12].copy_from_slice(&1_u64.to_le_bytes());
+    /// let result: Result<LweCiphertext32, _> = serialization_engine.deserialize(forged.as_slice());
+    /// assert!(matches!(
+    ///     result,
+    ///     Err(EntityDeserializationError::NullDimension)
+    /// ));
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn with_size_limit(size_limit: u64) -> DefaultSerializationEngine {
+        DefaultSerializationEngine {
+            size_limit: Some(size_limit),
+        }
+    }
+
+    /// Returns the size limit in bytes of the deserialized entities, if any.
+    pub fn size_limit(&self) -> Option<u64> {
+        self.size_limit
+    }
+
+    // The `Vec<u8>` serialization of an entity is its stream serialization into a vector, such
+    // that both formats are guaranteed to stay the same.
+    fn serialize_to_vec<Entity>(
+        &mut self,
+        entity: &Entity,
+    ) -> Result<Vec<u8>, EntitySerializationError<DefaultSerializationError>>
+    where
+        Entity: AbstractEntity,
+        Self: EntityStreamSerializationEngine<Entity, Vec<u8>>,
+    {
+        let mut serialized = Vec::new();
+        self.serialize_into(entity, &mut serialized)
+            .map_err(|err| match err {
+                EntityStreamSerializationError::Engine(err) => {
+                    EntitySerializationError::Engine(err)
+                }
+            })?;
+        Ok(serialized)
+    }
+
+    // The `&[u8]` deserialization of an entity is its stream deserialization from the slice.
+    fn deserialize_from_slice<'s, Entity>(
+        &mut self,
+        mut serialized: &'s [u8],
+    ) -> Result<Entity, EntityDeserializationError<DefaultSerializationError>>
+    where
+        Entity: AbstractEntity,
+        Self: EntityStreamDeserializationEngine<&'s [u8], Entity>,
+    {
+        if matches!(self.size_limit, Some(limit) if serialized.len() as u64 > limit) {
+            return Err(EntityDeserializationError::SizeLimitExceeded);
+        }
+        self.deserialize_from(&mut serialized)
+            .map_err(|err| match err {
+                EntityStreamDeserializationError::Engine(err) => {
+                    EntityDeserializationError::Engine(err)
+                }
+                EntityStreamDeserializationError::SizeLimitExceeded => {
+                    EntityDeserializationError::SizeLimitExceeded
+                }
+                EntityStreamDeserializationError::NullDimension => {
+                    EntityDeserializationError::NullDimension
+                }
+                EntityStreamDeserializationError::InvalidDecomposition => {
+                    EntityDeserializationError::InvalidDecomposition
+                }
+                EntityStreamDeserializationError::InvalidContainerSize => {
+                    EntityDeserializationError::InvalidContainerSize
+                }
+                EntityStreamDeserializationError::InvalidEncoder => {
+                    EntityDeserializationError::InvalidEncoder
+                }
+            })
+    }
+
+    // Seeded entities are expanded to containers much larger than their serialized form, whose size
+    // is bounded by the size limit as well.
+    fn check_expanded_size(
+        &self,
+        entity: &impl ValidateExpansion,
+    ) -> Result<(), EntityStreamDeserializationError<DefaultSerializationError>> {
+        match (entity.expanded_size(), self.size_limit) {
+            (Some(size), Some(limit)) if size as u64 <= limit => Ok(()),
+            (Some(_), None) => Ok(()),
+            _ => Err(EntityStreamDeserializationError::SizeLimitExceeded),
+        }
+    }
+
+    fn deserialize_bounded_from<T: DeserializeOwned>(
+        &self,
+        reader: impl Read,
+    ) -> Result<T, EntityStreamDeserializationError<DefaultSerializationError>> {
+        // Those are the options used by `bincode::deserialize_from`, with the size limit of the
+        // engine, which bounds the amount of data read from the stream.
+        bincode::DefaultOptions::new()
+            .with_fixint_encoding()
+            .allow_trailing_bytes()
+            .with_limit(self.size_limit.unwrap_or(u64::MAX))
+            .deserialize_from(reader)
+            .map_err(|err| match *err {
+                bincode::ErrorKind::SizeLimit => {
+                    EntityStreamDeserializationError::SizeLimitExceeded
+                }
+                _ => EntityStreamDeserializationError::Engine(
+                    DefaultSerializationError::Deserialization(err),
+                ),
+            })
+    }
+}
 
 impl AbstractEngineSeal for DefaultSerializationEngine {}
 
@@ -46,9 +214,19 @@ impl AbstractEngine for DefaultSerializationEngine {
     where
         Self: Sized,
     {
-        Ok(DefaultSerializationEngine)
+        Ok(DefaultSerializationEngine { size_limit: None })
     }
 }
 
+mod container_deserialization;
+mod container_serialization;
 mod entity_deserialization;
 mod entity_serialization;
+mod entity_stream_deserialization;
+mod entity_stream_serialization;
+mod mapped_deserialization;
+mod mapped_serialization;
+#[cfg(test)]
+mod tests;
+
+pub use container_deserialization::DefaultContainedEntity;
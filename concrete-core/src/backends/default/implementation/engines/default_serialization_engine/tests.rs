@@ -0,0 +1,545 @@
+use crate::commons::crypto::ggsw::StandardGgswSeededCiphertext as ImplStandardGgswSeededCiphertext;
+use crate::commons::crypto::glwe::{
+    GlweSeededCiphertext as ImplGlweSeededCiphertext, GlweSeededList as ImplGlweSeededList,
+};
+use crate::commons::crypto::lwe::{
+    LweBody, LweSeededCiphertext as ImplLweSeededCiphertext,
+    LweSeededKeyswitchKey as ImplLweSeededKeyswitchKey, LweSeededList as ImplLweSeededList,
+};
+use crate::commons::math::random::CompressionSeed;
+use crate::prelude::*;
+use concrete_csprng::seeders::Seed;
+use std::fmt::Debug;
+
+// Unix seeder must be given a secret input.
+// Here we just give it 0, which is totally unsafe.
+const UNSAFE_SECRET: u128 = 0;
+
+// Checks that the vector and the stream serializations of an entity produce the same bytes, and
+// returns them.
+fn check_serialization<Entity>(engine: &mut DefaultSerializationEngine, entity: &Entity) -> Vec<u8>
+where
+    Entity: AbstractEntity,
+    DefaultSerializationEngine: EntitySerializationEngine<Entity, Vec<u8>>
+        + EntityStreamSerializationEngine<Entity, Vec<u8>>,
+{
+    let serialized = engine.serialize(entity).unwrap();
+    let mut streamed = Vec::new();
+    engine.serialize_into(entity, &mut streamed).unwrap();
+    assert_eq!(serialized, streamed);
+    serialized
+}
+
+// Checks that the slice and the stream deserializations of an entity recover the same entity
+// from the same bytes.
+fn check_round_trip<Entity>(engine: &mut DefaultSerializationEngine, entity: &Entity)
+where
+    Entity: AbstractEntity + Debug + PartialEq,
+    DefaultSerializationEngine: EntitySerializationEngine<Entity, Vec<u8>>
+        + EntityStreamSerializationEngine<Entity, Vec<u8>>,
+    for<'a> DefaultSerializationEngine: EntityDeserializationEngine<&'a [u8], Entity>
+        + EntityStreamDeserializationEngine<&'a [u8], Entity>,
+{
+    let serialized = check_serialization(engine, entity);
+    let deserialized: Entity = engine.deserialize(serialized.as_slice()).unwrap();
+    let streamed: Entity = engine.deserialize_from(&mut serialized.as_slice()).unwrap();
+    assert_eq!(&deserialized, entity);
+    assert_eq!(&streamed, entity);
+}
+
+macro_rules! test_serialization_formats {
+    (
+        $test:ident,
+        $Scalar:ty,
+        [$($Alias:ident = $Entity:ident),* $(,)?],
+        [$($ViewAlias:ident = $View:ident),* $(,)?] $(,)?
+    ) => {
+        #[test]
+        fn $test() {
+            $(type $Alias = $Entity;)*
+            $(type $ViewAlias<'a> = $View<'a>;)*
+
+            let lwe_dimension = LweDimension(10);
+            let glwe_dimension = GlweDimension(2);
+            let polynomial_size = PolynomialSize(64);
+            let level = DecompositionLevelCount(3);
+            let base_log = DecompositionBaseLog(4);
+            let noise = Variance(2_f64.powf(-25.));
+            let input: $Scalar = 3 << 20;
+
+            let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET))).unwrap();
+            let mut serialization_engine = DefaultSerializationEngine::new(()).unwrap();
+            let ser = &mut serialization_engine;
+
+            let cleartext: Cleartext = engine.create_cleartext_from(&input).unwrap();
+            check_round_trip(ser, &cleartext);
+            let cleartext_vector: CleartextVector =
+                engine.create_cleartext_vector_from(&[input; 8]).unwrap();
+            check_round_trip(ser, &cleartext_vector);
+            let plaintext: Plaintext = engine.create_plaintext_from(&input).unwrap();
+            check_round_trip(ser, &plaintext);
+            let plaintext_vector: PlaintextVector = engine
+                .create_plaintext_vector_from(&vec![input; polynomial_size.0])
+                .unwrap();
+            check_round_trip(ser, &plaintext_vector);
+
+            let lwe_key: LweSecretKey = engine.generate_new_lwe_secret_key(lwe_dimension).unwrap();
+            check_round_trip(ser, &lwe_key);
+            let other_lwe_key: LweSecretKey =
+                engine.generate_new_lwe_secret_key(lwe_dimension).unwrap();
+            let glwe_key: GlweSecretKey = engine
+                .generate_new_glwe_secret_key(glwe_dimension, polynomial_size)
+                .unwrap();
+            check_round_trip(ser, &glwe_key);
+
+            let lwe_ciphertext: LweCiphertext = engine
+                .encrypt_lwe_ciphertext(&lwe_key, &plaintext, noise)
+                .unwrap();
+            check_round_trip(ser, &lwe_ciphertext);
+            let lwe_ciphertext_vector: LweCiphertextVector = engine
+                .encrypt_lwe_ciphertext_vector(&lwe_key, &plaintext_vector, noise)
+                .unwrap();
+            check_round_trip(ser, &lwe_ciphertext_vector);
+            let lwe_seeded_ciphertext: LweSeededCiphertext = engine
+                .encrypt_lwe_seeded_ciphertext(&lwe_key, &plaintext, noise)
+                .unwrap();
+            check_round_trip(ser, &lwe_seeded_ciphertext);
+            let lwe_seeded_ciphertext_vector: LweSeededCiphertextVector = engine
+                .encrypt_lwe_seeded_ciphertext_vector(&lwe_key, &plaintext_vector, noise)
+                .unwrap();
+            check_round_trip(ser, &lwe_seeded_ciphertext_vector);
+            let lwe_compressed_ciphertext: LweCompressedCiphertext = engine
+                .compress_lwe_ciphertext(&lwe_ciphertext, CiphertextModulusLog(16))
+                .unwrap();
+            check_round_trip(ser, &lwe_compressed_ciphertext);
+
+            let glwe_ciphertext: GlweCiphertext = engine
+                .encrypt_glwe_ciphertext(&glwe_key, &plaintext_vector, noise)
+                .unwrap();
+            check_round_trip(ser, &glwe_ciphertext);
+            let glwe_ciphertext_vector: GlweCiphertextVector = engine
+                .encrypt_glwe_ciphertext_vector(&glwe_key, &plaintext_vector, noise)
+                .unwrap();
+            check_round_trip(ser, &glwe_ciphertext_vector);
+            let glwe_seeded_ciphertext: GlweSeededCiphertext = engine
+                .encrypt_glwe_seeded_ciphertext(&glwe_key, &plaintext_vector, noise)
+                .unwrap();
+            check_round_trip(ser, &glwe_seeded_ciphertext);
+            let glwe_seeded_ciphertext_vector: GlweSeededCiphertextVector = engine
+                .encrypt_glwe_seeded_ciphertext_vector(&glwe_key, &plaintext_vector, noise)
+                .unwrap();
+            check_round_trip(ser, &glwe_seeded_ciphertext_vector);
+            let glwe_compressed_ciphertext: GlweCompressedCiphertext = engine
+                .compress_glwe_ciphertext(&glwe_ciphertext, CiphertextModulusLog(16))
+                .unwrap();
+            check_round_trip(ser, &glwe_compressed_ciphertext);
+
+            let ggsw_ciphertext: GgswCiphertext = engine
+                .encrypt_scalar_ggsw_ciphertext(&glwe_key, &plaintext, noise, level, base_log)
+                .unwrap();
+            check_round_trip(ser, &ggsw_ciphertext);
+            let ggsw_seeded_ciphertext = GgswSeededCiphertext {
+                0: ImplStandardGgswSeededCiphertext::allocate(
+                    polynomial_size,
+                    glwe_dimension.to_glwe_size(),
+                    level,
+                    base_log,
+                    CompressionSeed { seed: Seed(0) },
+                ),
+            };
+            check_round_trip(ser, &ggsw_seeded_ciphertext);
+
+            let keyswitch_key: LweKeyswitchKey = engine
+                .generate_new_lwe_keyswitch_key(&lwe_key, &other_lwe_key, level, base_log, noise)
+                .unwrap();
+            check_round_trip(ser, &keyswitch_key);
+            let seeded_keyswitch_key: LweSeededKeyswitchKey = engine
+                .generate_new_lwe_seeded_keyswitch_key(
+                    &lwe_key,
+                    &other_lwe_key,
+                    level,
+                    base_log,
+                    noise,
+                )
+                .unwrap();
+            check_round_trip(ser, &seeded_keyswitch_key);
+            let packing_keyswitch_key: LwePackingKeyswitchKey = engine
+                .generate_new_lwe_packing_keyswitch_key(&lwe_key, &glwe_key, level, base_log, noise)
+                .unwrap();
+            check_round_trip(ser, &packing_keyswitch_key);
+            let cbs_packing_keyswitch_keys: LweCircuitBootstrapPrivateFunctionalPackingKeyswitchKeys =
+                engine
+                    .generate_new_lwe_circuit_bootstrap_private_functional_packing_keyswitch_keys(
+                        &lwe_key, &glwe_key, base_log, level, noise,
+                    )
+                    .unwrap();
+            check_round_trip(ser, &cbs_packing_keyswitch_keys);
+            let bootstrap_key: LweBootstrapKey = engine
+                .generate_new_lwe_bootstrap_key(&lwe_key, &glwe_key, base_log, level, noise)
+                .unwrap();
+            check_round_trip(ser, &bootstrap_key);
+            let seeded_bootstrap_key: LweSeededBootstrapKey = engine
+                .generate_new_lwe_seeded_bootstrap_key(&lwe_key, &glwe_key, base_log, level, noise)
+                .unwrap();
+            check_round_trip(ser, &seeded_bootstrap_key);
+            let public_key: LwePublicKey = engine
+                .generate_new_lwe_public_key(&lwe_key, noise, LwePublicKeyZeroEncryptionCount(10))
+                .unwrap();
+            check_round_trip(ser, &public_key);
+
+            let lwe_size = lwe_dimension.to_lwe_size();
+            let mut lwe_container = vec![input; 2 * lwe_size.0];
+            let view: LweCiphertextView = engine
+                .create_lwe_ciphertext_from(&lwe_container[..lwe_size.0])
+                .unwrap();
+            check_serialization(ser, &view);
+            let view: LweCiphertextVectorView = engine
+                .create_lwe_ciphertext_vector_from(&lwe_container[..], lwe_size)
+                .unwrap();
+            check_serialization(ser, &view);
+            let view: LweCiphertextMutView = engine
+                .create_lwe_ciphertext_from(&mut lwe_container[..lwe_size.0])
+                .unwrap();
+            check_serialization(ser, &view);
+            let view: LweCiphertextVectorMutView = engine
+                .create_lwe_ciphertext_vector_from(&mut lwe_container[..], lwe_size)
+                .unwrap();
+            check_serialization(ser, &view);
+
+            let glwe_len = glwe_dimension.to_glwe_size().0 * polynomial_size.0;
+            let mut glwe_container = vec![input; 2 * glwe_len];
+            let view: GlweCiphertextView = engine
+                .create_glwe_ciphertext_from(&glwe_container[..glwe_len], polynomial_size)
+                .unwrap();
+            check_serialization(ser, &view);
+            let view: GlweCiphertextVectorView = engine
+                .create_glwe_ciphertext_vector_from(
+                    &glwe_container[..],
+                    glwe_dimension,
+                    polynomial_size,
+                )
+                .unwrap();
+            check_serialization(ser, &view);
+            let view: GlweCiphertextMutView = engine
+                .create_glwe_ciphertext_from(&mut glwe_container[..glwe_len], polynomial_size)
+                .unwrap();
+            check_serialization(ser, &view);
+            let view: GlweCiphertextVectorMutView = engine
+                .create_glwe_ciphertext_vector_from(
+                    &mut glwe_container[..],
+                    glwe_dimension,
+                    polynomial_size,
+                )
+                .unwrap();
+            check_serialization(ser, &view);
+        }
+    };
+}
+
+test_serialization_formats!(
+    test_serialization_formats_32,
+    u32,
+    [
+        Cleartext = Cleartext32,
+        CleartextVector = CleartextVector32,
+        Plaintext = Plaintext32,
+        PlaintextVector = PlaintextVector32,
+        LweSecretKey = LweSecretKey32,
+        GlweSecretKey = GlweSecretKey32,
+        LweCiphertext = LweCiphertext32,
+        LweCiphertextVector = LweCiphertextVector32,
+        LweSeededCiphertext = LweSeededCiphertext32,
+        LweSeededCiphertextVector = LweSeededCiphertextVector32,
+        LweCompressedCiphertext = LweCompressedCiphertext32,
+        GlweCiphertext = GlweCiphertext32,
+        GlweCiphertextVector = GlweCiphertextVector32,
+        GlweSeededCiphertext = GlweSeededCiphertext32,
+        GlweSeededCiphertextVector = GlweSeededCiphertextVector32,
+        GlweCompressedCiphertext = GlweCompressedCiphertext32,
+        GgswCiphertext = GgswCiphertext32,
+        GgswSeededCiphertext = GgswSeededCiphertext32,
+        LweKeyswitchKey = LweKeyswitchKey32,
+        LweSeededKeyswitchKey = LweSeededKeyswitchKey32,
+        LwePackingKeyswitchKey = LwePackingKeyswitchKey32,
+        LweCircuitBootstrapPrivateFunctionalPackingKeyswitchKeys =
+            LweCircuitBootstrapPrivateFunctionalPackingKeyswitchKeys32,
+        LweBootstrapKey = LweBootstrapKey32,
+        LweSeededBootstrapKey = LweSeededBootstrapKey32,
+        LwePublicKey = LwePublicKey32,
+    ],
+    [
+        LweCiphertextView = LweCiphertextView32,
+        LweCiphertextMutView = LweCiphertextMutView32,
+        LweCiphertextVectorView = LweCiphertextVectorView32,
+        LweCiphertextVectorMutView = LweCiphertextVectorMutView32,
+        GlweCiphertextView = GlweCiphertextView32,
+        GlweCiphertextMutView = GlweCiphertextMutView32,
+        GlweCiphertextVectorView = GlweCiphertextVectorView32,
+        GlweCiphertextVectorMutView = GlweCiphertextVectorMutView32,
+    ],
+);
+
+test_serialization_formats!(
+    test_serialization_formats_64,
+    u64,
+    [
+        Cleartext = Cleartext64,
+        CleartextVector = CleartextVector64,
+        Plaintext = Plaintext64,
+        PlaintextVector = PlaintextVector64,
+        LweSecretKey = LweSecretKey64,
+        GlweSecretKey = GlweSecretKey64,
+        LweCiphertext = LweCiphertext64,
+        LweCiphertextVector = LweCiphertextVector64,
+        LweSeededCiphertext = LweSeededCiphertext64,
+        LweSeededCiphertextVector = LweSeededCiphertextVector64,
+        LweCompressedCiphertext = LweCompressedCiphertext64,
+        GlweCiphertext = GlweCiphertext64,
+        GlweCiphertextVector = GlweCiphertextVector64,
+        GlweSeededCiphertext = GlweSeededCiphertext64,
+        GlweSeededCiphertextVector = GlweSeededCiphertextVector64,
+        GlweCompressedCiphertext = GlweCompressedCiphertext64,
+        GgswCiphertext = GgswCiphertext64,
+        GgswSeededCiphertext = GgswSeededCiphertext64,
+        LweKeyswitchKey = LweKeyswitchKey64,
+        LweSeededKeyswitchKey = LweSeededKeyswitchKey64,
+        LwePackingKeyswitchKey = LwePackingKeyswitchKey64,
+        LweCircuitBootstrapPrivateFunctionalPackingKeyswitchKeys =
+            LweCircuitBootstrapPrivateFunctionalPackingKeyswitchKeys64,
+        LweBootstrapKey = LweBootstrapKey64,
+        LweSeededBootstrapKey = LweSeededBootstrapKey64,
+        LwePublicKey = LwePublicKey64,
+    ],
+    [
+        LweCiphertextView = LweCiphertextView64,
+        LweCiphertextMutView = LweCiphertextMutView64,
+        LweCiphertextVectorView = LweCiphertextVectorView64,
+        LweCiphertextVectorMutView = LweCiphertextVectorMutView64,
+        GlweCiphertextView = GlweCiphertextView64,
+        GlweCiphertextMutView = GlweCiphertextMutView64,
+        GlweCiphertextVectorView = GlweCiphertextVectorView64,
+        GlweCiphertextVectorMutView = GlweCiphertextVectorMutView64,
+    ],
+);
+
+#[test]
+fn test_serialization_formats_float() {
+    let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET))).unwrap();
+    let mut serialization_engine = DefaultSerializationEngine::new(()).unwrap();
+    let ser = &mut serialization_engine;
+
+    let cleartext: CleartextF64 = engine.create_cleartext_from(&3.).unwrap();
+    check_round_trip(ser, &cleartext);
+    let cleartext_vector: CleartextVectorF64 =
+        engine.create_cleartext_vector_from(&[3.; 8]).unwrap();
+    check_round_trip(ser, &cleartext_vector);
+    let config = FloatEncoderMinMaxConfig {
+        min: 0.,
+        max: 10.,
+        nb_bit_precision: 8,
+        nb_bit_padding: 1,
+    };
+    let encoder: FloatEncoder = engine.create_encoder_from(&config).unwrap();
+    check_round_trip(ser, &encoder);
+    let encoder_vector: FloatEncoderVector = engine
+        .create_encoder_vector_from(&[config.clone(), config])
+        .unwrap();
+    check_round_trip(ser, &encoder_vector);
+}
+
+// Checks that the deserializations of a seeded entity are rejected because of the size of its
+// expanded form.
+fn check_expansion_rejected<Entity>(engine: &mut DefaultSerializationEngine, entity: &Entity)
+where
+    Entity: AbstractEntity,
+    DefaultSerializationEngine: EntitySerializationEngine<Entity, Vec<u8>>,
+    for<'a> DefaultSerializationEngine: EntityDeserializationEngine<&'a [u8], Entity>
+        + EntityStreamDeserializationEngine<&'a [u8], Entity>,
+{
+    let serialized = engine.serialize(entity).unwrap();
+    let result: Result<Entity, _> = engine.deserialize(serialized.as_slice());
+    assert!(matches!(
+        result,
+        Err(EntityDeserializationError::SizeLimitExceeded)
+    ));
+    let result: Result<Entity, _> = engine.deserialize_from(&mut serialized.as_slice());
+    assert!(matches!(
+        result,
+        Err(EntityStreamDeserializationError::SizeLimitExceeded)
+    ));
+}
+
+#[test]
+fn test_forged_seeded_entities_are_rejected() {
+    // Expanding any of those entities would take more than `usize::MAX` bytes, which is rejected
+    // even by an engine without size limit.
+    let seed = CompressionSeed { seed: Seed(0) };
+    let huge_lwe_dimension = LweDimension(usize::MAX);
+    let huge_glwe_dimension = GlweDimension(usize::MAX);
+    let polynomial_size = PolynomialSize(64);
+    let mut engine = DefaultSerializationEngine::new(()).unwrap();
+
+    let ciphertext = LweSeededCiphertext32(ImplLweSeededCiphertext {
+        body: LweBody(0),
+        lwe_dimension: huge_lwe_dimension,
+        compression_seed: seed,
+    });
+    check_expansion_rejected(&mut engine, &ciphertext);
+    let ciphertext_vector = LweSeededCiphertextVector32(ImplLweSeededList::from_container(
+        vec![0; 4],
+        huge_lwe_dimension,
+        seed,
+    ));
+    check_expansion_rejected(&mut engine, &ciphertext_vector);
+    let ciphertext = GlweSeededCiphertext32(ImplGlweSeededCiphertext::from_container(
+        vec![0; polynomial_size.0],
+        huge_glwe_dimension,
+        seed,
+    ));
+    check_expansion_rejected(&mut engine, &ciphertext);
+    let ciphertext_vector = GlweSeededCiphertextVector32(ImplGlweSeededList::from_container(
+        vec![0; 2 * polynomial_size.0],
+        huge_glwe_dimension,
+        polynomial_size,
+        seed,
+    ));
+    check_expansion_rejected(&mut engine, &ciphertext_vector);
+    let keyswitch_key = LweSeededKeyswitchKey32(ImplLweSeededKeyswitchKey::allocate(
+        DecompositionLevelCount(3),
+        DecompositionBaseLog(4),
+        LweDimension(10),
+        LweDimension(usize::MAX - 1),
+        seed,
+    ));
+    check_expansion_rejected(&mut engine, &keyswitch_key);
+}
+
+macro_rules! check_expansion_bounded {
+    ($entity:expr) => {
+        // The serialized entity fits in the size limit, but not its expanded form.
+        let entity = $entity;
+        let mut engine = DefaultSerializationEngine::new(()).unwrap();
+        let size = engine.serialize(&entity).unwrap().len();
+        let mut bounded_engine = DefaultSerializationEngine::with_size_limit(size as u64);
+        check_expansion_rejected(&mut bounded_engine, &entity);
+    };
+}
+
+#[test]
+fn test_seeded_entities_expansion_is_bounded() {
+    let lwe_dimension = LweDimension(10);
+    let glwe_dimension = GlweDimension(2);
+    let polynomial_size = PolynomialSize(64);
+    let level = DecompositionLevelCount(3);
+    let base_log = DecompositionBaseLog(4);
+    let noise = Variance(2_f64.powf(-25.));
+
+    let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET))).unwrap();
+    let plaintext: Plaintext32 = engine.create_plaintext_from(&(3 << 20)).unwrap();
+    let plaintext_vector: PlaintextVector32 = engine
+        .create_plaintext_vector_from(&vec![3 << 20; polynomial_size.0])
+        .unwrap();
+    let lwe_key: LweSecretKey32 = engine.generate_new_lwe_secret_key(lwe_dimension).unwrap();
+    let glwe_key: GlweSecretKey32 = engine
+        .generate_new_glwe_secret_key(glwe_dimension, polynomial_size)
+        .unwrap();
+
+    check_expansion_bounded!(engine
+        .encrypt_lwe_seeded_ciphertext(&lwe_key, &plaintext, noise)
+        .unwrap());
+    check_expansion_bounded!(engine
+        .encrypt_lwe_seeded_ciphertext_vector(&lwe_key, &plaintext_vector, noise)
+        .unwrap());
+    check_expansion_bounded!(engine
+        .encrypt_glwe_seeded_ciphertext(&glwe_key, &plaintext_vector, noise)
+        .unwrap());
+    check_expansion_bounded!(engine
+        .encrypt_glwe_seeded_ciphertext_vector(&glwe_key, &plaintext_vector, noise)
+        .unwrap());
+    check_expansion_bounded!(engine
+        .generate_new_lwe_seeded_keyswitch_key(&lwe_key, &lwe_key, level, base_log, noise)
+        .unwrap());
+    check_expansion_bounded!(GgswSeededCiphertext32(
+        ImplStandardGgswSeededCiphertext::allocate(
+            polynomial_size,
+            glwe_dimension.to_glwe_size(),
+            level,
+            base_log,
+            CompressionSeed { seed: Seed(0) },
+        )
+    ));
+    check_expansion_bounded!(engine
+        .generate_new_lwe_seeded_bootstrap_key(&lwe_key, &glwe_key, base_log, level, noise)
+        .unwrap());
+}
+
+// Checks that the container deserializations of an entity are rejected when any of the
+// parameters stored in the header is tampered with, while the checksum of the payload stays
+// valid.
+fn check_tampered_parameters_rejected<Entity>(
+    engine: &mut DefaultSerializationEngine,
+    entity: &Entity,
+) where
+    Entity: AbstractEntity,
+    DefaultSerializationEngine: EntityContainerSerializationEngine<Entity, Vec<u8>>,
+    for<'a> DefaultSerializationEngine: EntityContainerDeserializationEngine<&'a [u8], Entity>,
+{
+    let serialized = engine.serialize_container(entity).unwrap();
+    for index in 0..5 {
+        let (mut header, payload) = ContainerHeader::split_payload(serialized.as_slice()).unwrap();
+        header.parameters[index] = header.parameters[index].wrapping_add(1);
+        let tampered = header.wrap(payload);
+        let (header, payload) = ContainerHeader::split_payload(tampered.as_slice()).unwrap();
+        assert!(header.verify_checksum(payload).is_ok());
+        let result: Result<Entity, _> = engine.deserialize_container(tampered.as_slice());
+        assert!(matches!(
+            result,
+            Err(EntityContainerDeserializationError::ParametersMismatch)
+        ));
+        assert!(matches!(
+            engine.deserialize_any_container(tampered.as_slice()),
+            Err(EntityContainerDeserializationError::ParametersMismatch)
+        ));
+    }
+}
+
+#[test]
+fn test_tampered_container_parameters_are_rejected() {
+    let lwe_dimension = LweDimension(10);
+    let glwe_dimension = GlweDimension(2);
+    let polynomial_size = PolynomialSize(64);
+    let level = DecompositionLevelCount(3);
+    let base_log = DecompositionBaseLog(4);
+    let noise = Variance(2_f64.powf(-25.));
+
+    let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET))).unwrap();
+    let mut ser = DefaultSerializationEngine::new(()).unwrap();
+    let plaintext: Plaintext64 = engine.create_plaintext_from(&(3 << 50)).unwrap();
+    check_tampered_parameters_rejected(&mut ser, &plaintext);
+    let plaintext_vector: PlaintextVector64 = engine
+        .create_plaintext_vector_from(&vec![3 << 50; polynomial_size.0])
+        .unwrap();
+    check_tampered_parameters_rejected(&mut ser, &plaintext_vector);
+    let lwe_key: LweSecretKey64 = engine.generate_new_lwe_secret_key(lwe_dimension).unwrap();
+    let glwe_key: GlweSecretKey64 = engine
+        .generate_new_glwe_secret_key(glwe_dimension, polynomial_size)
+        .unwrap();
+    check_tampered_parameters_rejected(&mut ser, &glwe_key);
+
+    let lwe_ciphertext_vector: LweCiphertextVector64 = engine
+        .encrypt_lwe_ciphertext_vector(&lwe_key, &plaintext_vector, noise)
+        .unwrap();
+    check_tampered_parameters_rejected(&mut ser, &lwe_ciphertext_vector);
+    let glwe_seeded_ciphertext: GlweSeededCiphertext64 = engine
+        .encrypt_glwe_seeded_ciphertext(&glwe_key, &plaintext_vector, noise)
+        .unwrap();
+    check_tampered_parameters_rejected(&mut ser, &glwe_seeded_ciphertext);
+    let ksk: LweKeyswitchKey64 = engine
+        .generate_new_lwe_keyswitch_key(&lwe_key, &lwe_key, level, base_log, noise)
+        .unwrap();
+    check_tampered_parameters_rejected(&mut ser, &ksk);
+    let bsk: LweBootstrapKey64 = engine
+        .generate_new_lwe_bootstrap_key(&lwe_key, &glwe_key, base_log, level, noise)
+        .unwrap();
+    check_tampered_parameters_rejected(&mut ser, &bsk);
+}
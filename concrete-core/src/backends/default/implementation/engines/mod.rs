@@ -14,4 +14,4 @@ mod default_serialization_engine;
 pub use default_serialization_engine::*;
 
 mod activated_generator;
-pub use activated_generator::ActivatedRandomGenerator;
+pub use activated_generator::*;
@@ -0,0 +1,62 @@
+use crate::commons::crypto::glwe::GlweCompressedCiphertext as ImplGlweCompressedCiphertext;
+use crate::prelude::{CiphertextModulusLog, GlweDimension, PolynomialSize};
+use crate::specification::entities::markers::GlweCompressedCiphertextKind;
+use crate::specification::entities::{AbstractEntity, GlweCompressedCiphertextEntity};
+#[cfg(feature = "backend_default_serialization")]
+use serde::{Deserialize, Serialize};
+
+/// A structure representing a compressed GLWE ciphertext with 32 bits of precision.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GlweCompressedCiphertext32(pub(crate) ImplGlweCompressedCiphertext<u32>);
+impl AbstractEntity for GlweCompressedCiphertext32 {
+    type Kind = GlweCompressedCiphertextKind;
+}
+impl GlweCompressedCiphertextEntity for GlweCompressedCiphertext32 {
+    fn glwe_dimension(&self) -> GlweDimension {
+        self.0.glwe_dimension()
+    }
+
+    fn polynomial_size(&self) -> PolynomialSize {
+        self.0.polynomial_size()
+    }
+
+    fn ciphertext_modulus_log(&self) -> CiphertextModulusLog {
+        self.0.ciphertext_modulus_log()
+    }
+}
+
+#[cfg(feature = "backend_default_serialization")]
+#[derive(Serialize, Deserialize)]
+pub(crate) enum GlweCompressedCiphertext32Version {
+    V0,
+    #[serde(other)]
+    Unsupported,
+}
+
+/// A structure representing a compressed GLWE ciphertext with 64 bits of precision.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GlweCompressedCiphertext64(pub(crate) ImplGlweCompressedCiphertext<u64>);
+impl AbstractEntity for GlweCompressedCiphertext64 {
+    type Kind = GlweCompressedCiphertextKind;
+}
+impl GlweCompressedCiphertextEntity for GlweCompressedCiphertext64 {
+    fn glwe_dimension(&self) -> GlweDimension {
+        self.0.glwe_dimension()
+    }
+
+    fn polynomial_size(&self) -> PolynomialSize {
+        self.0.polynomial_size()
+    }
+
+    fn ciphertext_modulus_log(&self) -> CiphertextModulusLog {
+        self.0.ciphertext_modulus_log()
+    }
+}
+
+#[cfg(feature = "backend_default_serialization")]
+#[derive(Serialize, Deserialize)]
+pub(crate) enum GlweCompressedCiphertext64Version {
+    V0,
+    #[serde(other)]
+    Unsupported,
+}
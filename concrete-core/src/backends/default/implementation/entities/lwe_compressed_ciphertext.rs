@@ -0,0 +1,54 @@
+use crate::commons::crypto::lwe::LweCompressedCiphertext as ImplLweCompressedCiphertext;
+use crate::prelude::{CiphertextModulusLog, LweDimension};
+use crate::specification::entities::markers::LweCompressedCiphertextKind;
+use crate::specification::entities::{AbstractEntity, LweCompressedCiphertextEntity};
+#[cfg(feature = "backend_default_serialization")]
+use serde::{Deserialize, Serialize};
+
+/// A structure representing a compressed LWE ciphertext with 32 bits of precision.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LweCompressedCiphertext32(pub(crate) ImplLweCompressedCiphertext<u32>);
+impl AbstractEntity for LweCompressedCiphertext32 {
+    type Kind = LweCompressedCiphertextKind;
+}
+impl LweCompressedCiphertextEntity for LweCompressedCiphertext32 {
+    fn lwe_dimension(&self) -> LweDimension {
+        self.0.lwe_dimension()
+    }
+
+    fn ciphertext_modulus_log(&self) -> CiphertextModulusLog {
+        self.0.ciphertext_modulus_log()
+    }
+}
+
+#[cfg(feature = "backend_default_serialization")]
+#[derive(Serialize, Deserialize)]
+pub(crate) enum LweCompressedCiphertext32Version {
+    V0,
+    #[serde(other)]
+    Unsupported,
+}
+
+/// A structure representing a compressed LWE ciphertext with 64 bits of precision.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LweCompressedCiphertext64(pub(crate) ImplLweCompressedCiphertext<u64>);
+impl AbstractEntity for LweCompressedCiphertext64 {
+    type Kind = LweCompressedCiphertextKind;
+}
+impl LweCompressedCiphertextEntity for LweCompressedCiphertext64 {
+    fn lwe_dimension(&self) -> LweDimension {
+        self.0.lwe_dimension()
+    }
+
+    fn ciphertext_modulus_log(&self) -> CiphertextModulusLog {
+        self.0.ciphertext_modulus_log()
+    }
+}
+
+#[cfg(feature = "backend_default_serialization")]
+#[derive(Serialize, Deserialize)]
+pub(crate) enum LweCompressedCiphertext64Version {
+    V0,
+    #[serde(other)]
+    Unsupported,
+}
@@ -9,6 +9,7 @@ mod ggsw_ciphertext;
 mod ggsw_seeded_ciphertext;
 mod glwe_ciphertext;
 mod glwe_ciphertext_vector;
+mod glwe_compressed_ciphertext;
 mod glwe_secret_key;
 mod glwe_seeded_ciphertext;
 mod glwe_seeded_ciphertext_vector;
@@ -17,6 +18,7 @@ mod lwe_bootstrap_key;
 mod lwe_ciphertext;
 mod lwe_ciphertext_vector;
 mod lwe_circuit_bootstrap_private_functional_packing_keyswitch_keys;
+mod lwe_compressed_ciphertext;
 mod lwe_keyswitch_key;
 mod lwe_packing_keyswitch_key;
 mod lwe_private_functional_packing_keyswitch_key;
@@ -37,6 +39,7 @@ pub use ggsw_ciphertext::*;
 pub use ggsw_seeded_ciphertext::*;
 pub use glwe_ciphertext::*;
 pub use glwe_ciphertext_vector::*;
+pub use glwe_compressed_ciphertext::*;
 pub use glwe_secret_key::*;
 pub use glwe_seeded_ciphertext::*;
 pub use glwe_seeded_ciphertext_vector::*;
@@ -45,6 +48,7 @@ pub use lwe_bootstrap_key::*;
 pub use lwe_ciphertext::*;
 pub use lwe_ciphertext_vector::*;
 pub use lwe_circuit_bootstrap_private_functional_packing_keyswitch_keys::*;
+pub use lwe_compressed_ciphertext::*;
 pub use lwe_keyswitch_key::*;
 pub use lwe_packing_keyswitch_key::*;
 pub use lwe_private_functional_packing_keyswitch_key::*;
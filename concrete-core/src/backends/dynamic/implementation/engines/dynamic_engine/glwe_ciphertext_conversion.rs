@@ -0,0 +1,167 @@
+#[cfg(feature = "backend_cuda")]
+use super::DynamicBackend;
+use super::{DynamicEngine, DynamicError};
+use crate::backends::dynamic::implementation::entities::{
+    DynamicGlweCiphertext64, DynamicGlweCiphertext64Repr,
+};
+use crate::prelude::GlweCiphertext64;
+use crate::specification::engines::{
+    GlweCiphertextConversionEngine, GlweCiphertextConversionError,
+};
+
+impl From<DynamicError> for GlweCiphertextConversionError<DynamicError> {
+    fn from(err: DynamicError) -> Self {
+        Self::Engine(err)
+    }
+}
+
+/// # Description
+/// Implementation of [`GlweCiphertextConversionEngine`] for [`DynamicEngine`] that stores a GLWE
+/// ciphertext on the selected backend.
+impl GlweCiphertextConversionEngine<GlweCiphertext64, DynamicGlweCiphertext64> for DynamicEngine {
+    /// # Example
+    /// ```
+    /// use concrete_core::prelude::{GlweDimension, PolynomialSize, Variance, *};
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let glwe_dimension = GlweDimension(2);
+    /// let polynomial_size = PolynomialSize(4);
+    /// // Here a hard-set encoding is applied (shift by 50 bits)
+    /// let input = vec![3_u64 << 50; polynomial_size.0];
+    /// let noise = Variance(2_f64.powf(-50.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DynamicEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let key: GlweSecretKey64 = engine
+    ///     .default_engine()
+    ///     .generate_new_glwe_secret_key(glwe_dimension, polynomial_size)?;
+    /// let plaintext_vector = engine
+    ///     .default_engine()
+    ///     .create_plaintext_vector_from(&input)?;
+    /// let ciphertext =
+    ///     engine
+    ///         .default_engine()
+    ///         .encrypt_glwe_ciphertext(&key, &plaintext_vector, noise)?;
+    ///
+    /// let dynamic_ciphertext: DynamicGlweCiphertext64 =
+    ///     engine.convert_glwe_ciphertext(&ciphertext)?;
+    /// #
+    /// assert_eq!(dynamic_ciphertext.glwe_dimension(), glwe_dimension);
+    /// assert_eq!(dynamic_ciphertext.polynomial_size(), polynomial_size);
+    /// let host_ciphertext: GlweCiphertext64 = engine.convert_glwe_ciphertext(&dynamic_ciphertext)?;
+    /// assert_eq!(host_ciphertext, ciphertext);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn convert_glwe_ciphertext(
+        &mut self,
+        input: &GlweCiphertext64,
+    ) -> Result<DynamicGlweCiphertext64, GlweCiphertextConversionError<Self::EngineError>> {
+        #[cfg(feature = "backend_cuda")]
+        if self.backend == DynamicBackend::Cuda {
+            let ciphertext = self
+                .cuda_engine()?
+                .convert_glwe_ciphertext(input)
+                .map_err(DynamicError::from_backend(DynamicBackend::Cuda))?;
+            return Ok(DynamicGlweCiphertext64(DynamicGlweCiphertext64Repr::Cuda(
+                ciphertext,
+            )));
+        }
+        Ok(DynamicGlweCiphertext64(DynamicGlweCiphertext64Repr::Host(
+            input.clone(),
+        )))
+    }
+
+    unsafe fn convert_glwe_ciphertext_unchecked(
+        &mut self,
+        input: &GlweCiphertext64,
+    ) -> DynamicGlweCiphertext64 {
+        #[cfg(feature = "backend_cuda")]
+        if self.backend == DynamicBackend::Cuda {
+            let ciphertext = self
+                .cuda_engine()
+                .unwrap()
+                .convert_glwe_ciphertext_unchecked(input);
+            return DynamicGlweCiphertext64(DynamicGlweCiphertext64Repr::Cuda(ciphertext));
+        }
+        DynamicGlweCiphertext64(DynamicGlweCiphertext64Repr::Host(input.clone()))
+    }
+}
+
+/// # Description
+/// Implementation of [`GlweCiphertextConversionEngine`] for [`DynamicEngine`] that stores a GLWE
+/// ciphertext on the host.
+impl GlweCiphertextConversionEngine<DynamicGlweCiphertext64, GlweCiphertext64> for DynamicEngine {
+    /// # Example
+    /// ```
+    /// use concrete_core::prelude::{GlweDimension, PolynomialSize, Variance, *};
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let glwe_dimension = GlweDimension(2);
+    /// let polynomial_size = PolynomialSize(4);
+    /// // Here a hard-set encoding is applied (shift by 50 bits)
+    /// let input = vec![3_u64 << 50; polynomial_size.0];
+    /// let noise = Variance(2_f64.powf(-50.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DynamicEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let key: GlweSecretKey64 = engine
+    ///     .default_engine()
+    ///     .generate_new_glwe_secret_key(glwe_dimension, polynomial_size)?;
+    /// let plaintext_vector = engine
+    ///     .default_engine()
+    ///     .create_plaintext_vector_from(&input)?;
+    /// let ciphertext =
+    ///     engine
+    ///         .default_engine()
+    ///         .encrypt_glwe_ciphertext(&key, &plaintext_vector, noise)?;
+    /// let dynamic_ciphertext: DynamicGlweCiphertext64 =
+    ///     engine.convert_glwe_ciphertext(&ciphertext)?;
+    ///
+    /// let host_ciphertext: GlweCiphertext64 = engine.convert_glwe_ciphertext(&dynamic_ciphertext)?;
+    /// #
+    /// assert_eq!(host_ciphertext.glwe_dimension(), glwe_dimension);
+    /// assert_eq!(host_ciphertext.polynomial_size(), polynomial_size);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn convert_glwe_ciphertext(
+        &mut self,
+        input: &DynamicGlweCiphertext64,
+    ) -> Result<GlweCiphertext64, GlweCiphertextConversionError<Self::EngineError>> {
+        match &input.0 {
+            DynamicGlweCiphertext64Repr::Host(ciphertext) => Ok(ciphertext.clone()),
+            #[cfg(feature = "backend_cuda")]
+            DynamicGlweCiphertext64Repr::Cuda(ciphertext) => Ok(self
+                .cuda_engine()?
+                .convert_glwe_ciphertext(ciphertext)
+                .map_err(DynamicError::from_backend(DynamicBackend::Cuda))?),
+        }
+    }
+
+    unsafe fn convert_glwe_ciphertext_unchecked(
+        &mut self,
+        input: &DynamicGlweCiphertext64,
+    ) -> GlweCiphertext64 {
+        match &input.0 {
+            DynamicGlweCiphertext64Repr::Host(ciphertext) => ciphertext.clone(),
+            #[cfg(feature = "backend_cuda")]
+            DynamicGlweCiphertext64Repr::Cuda(ciphertext) => self
+                .cuda_engine()
+                .unwrap()
+                .convert_glwe_ciphertext_unchecked(ciphertext),
+        }
+    }
+}
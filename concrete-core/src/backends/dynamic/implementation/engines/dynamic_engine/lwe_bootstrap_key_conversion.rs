@@ -0,0 +1,111 @@
+#[cfg(feature = "backend_fft")]
+use super::DynamicBackend;
+use super::{DynamicEngine, DynamicError};
+use crate::backends::dynamic::implementation::entities::{
+    DynamicLweBootstrapKey64, DynamicLweBootstrapKey64Repr,
+};
+use crate::prelude::LweBootstrapKey64;
+use crate::specification::engines::{
+    LweBootstrapKeyConversionEngine, LweBootstrapKeyConversionError,
+};
+
+impl From<DynamicError> for LweBootstrapKeyConversionError<DynamicError> {
+    fn from(err: DynamicError) -> Self {
+        Self::Engine(err)
+    }
+}
+
+/// # Description
+/// Implementation of [`LweBootstrapKeyConversionEngine`] for [`DynamicEngine`] that stores an LWE
+/// bootstrap key in the format of the backend executing the bootstrap, that is the Cuda backend
+/// when it is selected, and the FFT backend otherwise.
+///
+/// The Fourier formats of the FFT and Cuda backends differ: a key converted before switching
+/// between them with [`DynamicEngine::set_backend`] must be converted again.
+impl LweBootstrapKeyConversionEngine<LweBootstrapKey64, DynamicLweBootstrapKey64>
+    for DynamicEngine
+{
+    /// # Example
+    /// ```
+    /// use concrete_core::prelude::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, LweDimension, PolynomialSize,
+    ///     Variance, *,
+    /// };
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let (lwe_dim, glwe_dim, poly_size) = (LweDimension(4), GlweDimension(1), PolynomialSize(256));
+    /// let (dec_lc, dec_bl) = (DecompositionLevelCount(3), DecompositionBaseLog(5));
+    /// let noise = Variance(2_f64.powf(-50.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DynamicEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let lwe_sk: LweSecretKey64 = engine
+    ///     .default_engine()
+    ///     .generate_new_lwe_secret_key(lwe_dim)?;
+    /// let glwe_sk: GlweSecretKey64 = engine
+    ///     .default_engine()
+    ///     .generate_new_glwe_secret_key(glwe_dim, poly_size)?;
+    /// let bsk: LweBootstrapKey64 = engine
+    ///     .default_engine()
+    ///     .generate_new_lwe_bootstrap_key(&lwe_sk, &glwe_sk, dec_bl, dec_lc, noise)?;
+    ///
+    /// let dynamic_bsk: DynamicLweBootstrapKey64 = engine.convert_lwe_bootstrap_key(&bsk)?;
+    /// #
+    /// assert_eq!(dynamic_bsk.glwe_dimension(), glwe_dim);
+    /// assert_eq!(dynamic_bsk.polynomial_size(), poly_size);
+    /// assert_eq!(dynamic_bsk.input_lwe_dimension(), lwe_dim);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn convert_lwe_bootstrap_key(
+        &mut self,
+        input: &LweBootstrapKey64,
+    ) -> Result<DynamicLweBootstrapKey64, LweBootstrapKeyConversionError<Self::EngineError>> {
+        #[cfg(feature = "backend_cuda")]
+        if self.backend == DynamicBackend::Cuda {
+            let key = self
+                .cuda_engine()?
+                .convert_lwe_bootstrap_key(input)
+                .map_err(DynamicError::from_backend(DynamicBackend::Cuda))?;
+            return Ok(DynamicLweBootstrapKey64(
+                DynamicLweBootstrapKey64Repr::Cuda(key),
+            ));
+        }
+        #[cfg(feature = "backend_fft")]
+        let key = DynamicLweBootstrapKey64Repr::Fft(
+            self.fft_engine
+                .convert_lwe_bootstrap_key(input)
+                .map_err(DynamicError::from_backend(DynamicBackend::Fft))?,
+        );
+        #[cfg(not(feature = "backend_fft"))]
+        let key = DynamicLweBootstrapKey64Repr::Standard(input.clone());
+        Ok(DynamicLweBootstrapKey64(key))
+    }
+
+    unsafe fn convert_lwe_bootstrap_key_unchecked(
+        &mut self,
+        input: &LweBootstrapKey64,
+    ) -> DynamicLweBootstrapKey64 {
+        #[cfg(feature = "backend_cuda")]
+        if self.backend == DynamicBackend::Cuda {
+            let key = self
+                .cuda_engine()
+                .unwrap()
+                .convert_lwe_bootstrap_key_unchecked(input);
+            return DynamicLweBootstrapKey64(DynamicLweBootstrapKey64Repr::Cuda(key));
+        }
+        #[cfg(feature = "backend_fft")]
+        let key = DynamicLweBootstrapKey64Repr::Fft(
+            self.fft_engine.convert_lwe_bootstrap_key_unchecked(input),
+        );
+        #[cfg(not(feature = "backend_fft"))]
+        let key = DynamicLweBootstrapKey64Repr::Standard(input.clone());
+        DynamicLweBootstrapKey64(key)
+    }
+}
@@ -0,0 +1,153 @@
+#[cfg(feature = "backend_cuda")]
+use super::DynamicBackend;
+use super::{DynamicEngine, DynamicError};
+use crate::backends::dynamic::implementation::entities::{
+    DynamicLweCiphertext64, DynamicLweCiphertext64Repr,
+};
+use crate::prelude::LweCiphertext64;
+use crate::specification::engines::{LweCiphertextConversionEngine, LweCiphertextConversionError};
+
+impl From<DynamicError> for LweCiphertextConversionError<DynamicError> {
+    fn from(err: DynamicError) -> Self {
+        Self::Engine(err)
+    }
+}
+
+/// # Description
+/// Implementation of [`LweCiphertextConversionEngine`] for [`DynamicEngine`] that stores an LWE
+/// ciphertext on the selected backend.
+impl LweCiphertextConversionEngine<LweCiphertext64, DynamicLweCiphertext64> for DynamicEngine {
+    /// # Example
+    /// ```
+    /// use concrete_core::prelude::{LweDimension, Variance, *};
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let lwe_dimension = LweDimension(6);
+    /// // Here a hard-set encoding is applied (shift by 50 bits)
+    /// let input = 3_u64 << 50;
+    /// let noise = Variance(2_f64.powf(-50.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DynamicEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let key: LweSecretKey64 = engine
+    ///     .default_engine()
+    ///     .generate_new_lwe_secret_key(lwe_dimension)?;
+    /// let plaintext = engine.default_engine().create_plaintext_from(&input)?;
+    /// let ciphertext = engine
+    ///     .default_engine()
+    ///     .encrypt_lwe_ciphertext(&key, &plaintext, noise)?;
+    ///
+    /// let dynamic_ciphertext: DynamicLweCiphertext64 = engine.convert_lwe_ciphertext(&ciphertext)?;
+    /// #
+    /// assert_eq!(dynamic_ciphertext.lwe_dimension(), lwe_dimension);
+    /// let host_ciphertext: LweCiphertext64 = engine.convert_lwe_ciphertext(&dynamic_ciphertext)?;
+    /// assert_eq!(host_ciphertext, ciphertext);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn convert_lwe_ciphertext(
+        &mut self,
+        input: &LweCiphertext64,
+    ) -> Result<DynamicLweCiphertext64, LweCiphertextConversionError<Self::EngineError>> {
+        #[cfg(feature = "backend_cuda")]
+        if self.backend == DynamicBackend::Cuda {
+            let ciphertext = self
+                .cuda_engine()?
+                .convert_lwe_ciphertext(input)
+                .map_err(DynamicError::from_backend(DynamicBackend::Cuda))?;
+            return Ok(DynamicLweCiphertext64(DynamicLweCiphertext64Repr::Cuda(
+                ciphertext,
+            )));
+        }
+        Ok(DynamicLweCiphertext64(DynamicLweCiphertext64Repr::Host(
+            input.clone(),
+        )))
+    }
+
+    unsafe fn convert_lwe_ciphertext_unchecked(
+        &mut self,
+        input: &LweCiphertext64,
+    ) -> DynamicLweCiphertext64 {
+        #[cfg(feature = "backend_cuda")]
+        if self.backend == DynamicBackend::Cuda {
+            let ciphertext = self
+                .cuda_engine()
+                .unwrap()
+                .convert_lwe_ciphertext_unchecked(input);
+            return DynamicLweCiphertext64(DynamicLweCiphertext64Repr::Cuda(ciphertext));
+        }
+        DynamicLweCiphertext64(DynamicLweCiphertext64Repr::Host(input.clone()))
+    }
+}
+
+/// # Description
+/// Implementation of [`LweCiphertextConversionEngine`] for [`DynamicEngine`] that stores an LWE
+/// ciphertext on the host.
+impl LweCiphertextConversionEngine<DynamicLweCiphertext64, LweCiphertext64> for DynamicEngine {
+    /// # Example
+    /// ```
+    /// use concrete_core::prelude::{LweDimension, Variance, *};
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let lwe_dimension = LweDimension(6);
+    /// // Here a hard-set encoding is applied (shift by 50 bits)
+    /// let input = 3_u64 << 50;
+    /// let noise = Variance(2_f64.powf(-50.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DynamicEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let key: LweSecretKey64 = engine
+    ///     .default_engine()
+    ///     .generate_new_lwe_secret_key(lwe_dimension)?;
+    /// let plaintext = engine.default_engine().create_plaintext_from(&input)?;
+    /// let ciphertext = engine
+    ///     .default_engine()
+    ///     .encrypt_lwe_ciphertext(&key, &plaintext, noise)?;
+    /// let dynamic_ciphertext: DynamicLweCiphertext64 = engine.convert_lwe_ciphertext(&ciphertext)?;
+    ///
+    /// let host_ciphertext: LweCiphertext64 = engine.convert_lwe_ciphertext(&dynamic_ciphertext)?;
+    /// #
+    /// assert_eq!(host_ciphertext.lwe_dimension(), lwe_dimension);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn convert_lwe_ciphertext(
+        &mut self,
+        input: &DynamicLweCiphertext64,
+    ) -> Result<LweCiphertext64, LweCiphertextConversionError<Self::EngineError>> {
+        match &input.0 {
+            DynamicLweCiphertext64Repr::Host(ciphertext) => Ok(ciphertext.clone()),
+            #[cfg(feature = "backend_cuda")]
+            DynamicLweCiphertext64Repr::Cuda(ciphertext) => Ok(self
+                .cuda_engine()?
+                .convert_lwe_ciphertext(ciphertext)
+                .map_err(DynamicError::from_backend(DynamicBackend::Cuda))?),
+        }
+    }
+
+    unsafe fn convert_lwe_ciphertext_unchecked(
+        &mut self,
+        input: &DynamicLweCiphertext64,
+    ) -> LweCiphertext64 {
+        match &input.0 {
+            DynamicLweCiphertext64Repr::Host(ciphertext) => ciphertext.clone(),
+            #[cfg(feature = "backend_cuda")]
+            DynamicLweCiphertext64Repr::Cuda(ciphertext) => self
+                .cuda_engine()
+                .unwrap()
+                .convert_lwe_ciphertext_unchecked(ciphertext),
+        }
+    }
+}
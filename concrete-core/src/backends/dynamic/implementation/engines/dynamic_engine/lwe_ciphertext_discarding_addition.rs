@@ -0,0 +1,104 @@
+use super::{DynamicBackend, DynamicEngine, DynamicError};
+use crate::backends::dynamic::implementation::entities::DynamicLweCiphertext64;
+use crate::specification::engines::{
+    LweCiphertextDiscardingAdditionEngine, LweCiphertextDiscardingAdditionError,
+};
+
+impl From<DynamicError> for LweCiphertextDiscardingAdditionError<DynamicError> {
+    fn from(err: DynamicError) -> Self {
+        Self::Engine(err)
+    }
+}
+
+/// # Description:
+/// Implementation of [`LweCiphertextDiscardingAdditionEngine`] for [`DynamicEngine`] that operates
+/// on 64 bits integers, on the default backend.
+impl LweCiphertextDiscardingAdditionEngine<DynamicLweCiphertext64, DynamicLweCiphertext64>
+    for DynamicEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_core::prelude::{LweDimension, Variance, *};
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let lwe_dimension = LweDimension(2);
+    /// // Here a hard-set encoding is applied (shift by 50 bits)
+    /// let input_1 = 3_u64 << 50;
+    /// let input_2 = 7_u64 << 50;
+    /// let noise = Variance(2_f64.powf(-50.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DynamicEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let key: LweSecretKey64 = engine
+    ///     .default_engine()
+    ///     .generate_new_lwe_secret_key(lwe_dimension)?;
+    /// let plaintext_1 = engine.default_engine().create_plaintext_from(&input_1)?;
+    /// let plaintext_2 = engine.default_engine().create_plaintext_from(&input_2)?;
+    /// let ciphertext_1 = engine
+    ///     .default_engine()
+    ///     .encrypt_lwe_ciphertext(&key, &plaintext_1, noise)?;
+    /// let ciphertext_2 = engine
+    ///     .default_engine()
+    ///     .encrypt_lwe_ciphertext(&key, &plaintext_2, noise)?;
+    /// let ciphertext_3 = engine
+    ///     .default_engine()
+    ///     .zero_encrypt_lwe_ciphertext(&key, noise)?;
+    /// let ciphertext_1: DynamicLweCiphertext64 = engine.convert_lwe_ciphertext(&ciphertext_1)?;
+    /// let ciphertext_2: DynamicLweCiphertext64 = engine.convert_lwe_ciphertext(&ciphertext_2)?;
+    /// let mut ciphertext_3: DynamicLweCiphertext64 = engine.convert_lwe_ciphertext(&ciphertext_3)?;
+    ///
+    /// engine.discard_add_lwe_ciphertext(&mut ciphertext_3, &ciphertext_1, &ciphertext_2)?;
+    /// #
+    /// assert_eq!(ciphertext_3.lwe_dimension(), lwe_dimension);
+    /// let ciphertext_3: LweCiphertext64 = engine.convert_lwe_ciphertext(&ciphertext_3)?;
+    /// let output = engine
+    ///     .default_engine()
+    ///     .decrypt_lwe_ciphertext(&key, &ciphertext_3)?;
+    /// let output = engine.default_engine().retrieve_plaintext(&output)?;
+    /// assert_eq!((output + (1 << 49)) >> 50, 10);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn discard_add_lwe_ciphertext(
+        &mut self,
+        output: &mut DynamicLweCiphertext64,
+        input_1: &DynamicLweCiphertext64,
+        input_2: &DynamicLweCiphertext64,
+    ) -> Result<(), LweCiphertextDiscardingAdditionError<Self::EngineError>> {
+        LweCiphertextDiscardingAdditionError::perform_generic_checks(output, input_1, input_2)?;
+        let (mut buffer_1, mut buffer_2) = (None, None);
+        let input_1 = self.host_lwe_ciphertext(input_1, &mut buffer_1)?;
+        let input_2 = self.host_lwe_ciphertext(input_2, &mut buffer_2)?;
+        self.with_host_lwe_ciphertext_output(output, |engine, output| {
+            engine
+                .default_engine
+                .discard_add_lwe_ciphertext(output, input_1, input_2)
+                .map_err(DynamicError::from_backend(DynamicBackend::Default))
+        })?;
+        Ok(())
+    }
+
+    unsafe fn discard_add_lwe_ciphertext_unchecked(
+        &mut self,
+        output: &mut DynamicLweCiphertext64,
+        input_1: &DynamicLweCiphertext64,
+        input_2: &DynamicLweCiphertext64,
+    ) {
+        let (mut buffer_1, mut buffer_2) = (None, None);
+        let input_1 = self.host_lwe_ciphertext(input_1, &mut buffer_1).unwrap();
+        let input_2 = self.host_lwe_ciphertext(input_2, &mut buffer_2).unwrap();
+        self.with_host_lwe_ciphertext_output(output, |engine, output| {
+            engine
+                .default_engine
+                .discard_add_lwe_ciphertext_unchecked(output, input_1, input_2);
+            Ok(())
+        })
+        .unwrap();
+    }
+}
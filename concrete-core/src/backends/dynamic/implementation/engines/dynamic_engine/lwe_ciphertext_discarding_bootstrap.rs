@@ -0,0 +1,229 @@
+use super::{DynamicBackend, DynamicEngine, DynamicError};
+use crate::backends::dynamic::implementation::entities::{
+    DynamicGlweCiphertext64, DynamicLweBootstrapKey64, DynamicLweCiphertext64,
+};
+use crate::specification::engines::{
+    LweCiphertextDiscardingBootstrapEngine, LweCiphertextDiscardingBootstrapError,
+};
+
+impl From<DynamicError> for LweCiphertextDiscardingBootstrapError<DynamicError> {
+    fn from(err: DynamicError) -> Self {
+        Self::Engine(err)
+    }
+}
+
+impl DynamicEngine {
+    #[cfg(feature = "backend_fft")]
+    fn fft_discard_bootstrap_lwe_ciphertext(
+        &mut self,
+        output: &mut DynamicLweCiphertext64,
+        input: &DynamicLweCiphertext64,
+        acc: &DynamicGlweCiphertext64,
+        bsk: &DynamicLweBootstrapKey64,
+    ) -> Result<(), DynamicError> {
+        let (mut input_buffer, mut acc_buffer) = (None, None);
+        let input = self.host_lwe_ciphertext(input, &mut input_buffer)?;
+        let acc = self.host_glwe_ciphertext(acc, &mut acc_buffer)?;
+        let bsk = self.fft_lwe_bootstrap_key(bsk)?;
+        self.with_host_lwe_ciphertext_output(output, |engine, output| {
+            engine
+                .fft_engine
+                .discard_bootstrap_lwe_ciphertext(output, input, acc, bsk)
+                .map_err(DynamicError::from_backend(DynamicBackend::Fft))
+        })
+    }
+
+    #[cfg(feature = "backend_fft")]
+    unsafe fn fft_discard_bootstrap_lwe_ciphertext_unchecked(
+        &mut self,
+        output: &mut DynamicLweCiphertext64,
+        input: &DynamicLweCiphertext64,
+        acc: &DynamicGlweCiphertext64,
+        bsk: &DynamicLweBootstrapKey64,
+    ) {
+        let (mut input_buffer, mut acc_buffer) = (None, None);
+        let input = self.host_lwe_ciphertext(input, &mut input_buffer).unwrap();
+        let acc = self.host_glwe_ciphertext(acc, &mut acc_buffer).unwrap();
+        let bsk = self.fft_lwe_bootstrap_key(bsk).unwrap();
+        self.with_host_lwe_ciphertext_output(output, |engine, output| {
+            engine
+                .fft_engine
+                .discard_bootstrap_lwe_ciphertext_unchecked(output, input, acc, bsk);
+            Ok(())
+        })
+        .unwrap();
+    }
+
+    #[cfg(not(feature = "backend_fft"))]
+    fn fft_discard_bootstrap_lwe_ciphertext(
+        &mut self,
+        _output: &mut DynamicLweCiphertext64,
+        _input: &DynamicLweCiphertext64,
+        _acc: &DynamicGlweCiphertext64,
+        _bsk: &DynamicLweBootstrapKey64,
+    ) -> Result<(), DynamicError> {
+        Err(DynamicError::BackendUnavailable(DynamicBackend::Fft))
+    }
+
+    #[cfg(not(feature = "backend_fft"))]
+    unsafe fn fft_discard_bootstrap_lwe_ciphertext_unchecked(
+        &mut self,
+        _output: &mut DynamicLweCiphertext64,
+        _input: &DynamicLweCiphertext64,
+        _acc: &DynamicGlweCiphertext64,
+        _bsk: &DynamicLweBootstrapKey64,
+    ) {
+        panic!("{}", DynamicError::BackendUnavailable(DynamicBackend::Fft));
+    }
+
+    #[cfg(feature = "backend_cuda")]
+    fn cuda_discard_bootstrap_lwe_ciphertext(
+        &mut self,
+        output: &mut DynamicLweCiphertext64,
+        input: &DynamicLweCiphertext64,
+        acc: &DynamicGlweCiphertext64,
+        bsk: &DynamicLweBootstrapKey64,
+    ) -> Result<(), DynamicError> {
+        let (mut input_buffer, mut acc_buffer) = (None, None);
+        let input = self.cuda_lwe_ciphertext(input, &mut input_buffer)?;
+        let acc = self.cuda_glwe_ciphertext(acc, &mut acc_buffer)?;
+        let bsk = self.cuda_lwe_bootstrap_key(bsk)?;
+        self.with_cuda_lwe_ciphertext_output(output, |cuda_engine, output| {
+            cuda_engine
+                .discard_bootstrap_lwe_ciphertext(output, input, acc, bsk)
+                .map_err(DynamicError::from_backend(DynamicBackend::Cuda))
+        })
+    }
+
+    #[cfg(feature = "backend_cuda")]
+    unsafe fn cuda_discard_bootstrap_lwe_ciphertext_unchecked(
+        &mut self,
+        output: &mut DynamicLweCiphertext64,
+        input: &DynamicLweCiphertext64,
+        acc: &DynamicGlweCiphertext64,
+        bsk: &DynamicLweBootstrapKey64,
+    ) {
+        let (mut input_buffer, mut acc_buffer) = (None, None);
+        let input = self.cuda_lwe_ciphertext(input, &mut input_buffer).unwrap();
+        let acc = self.cuda_glwe_ciphertext(acc, &mut acc_buffer).unwrap();
+        let bsk = self.cuda_lwe_bootstrap_key(bsk).unwrap();
+        self.with_cuda_lwe_ciphertext_output(output, |cuda_engine, output| {
+            cuda_engine.discard_bootstrap_lwe_ciphertext_unchecked(output, input, acc, bsk);
+            Ok(())
+        })
+        .unwrap();
+    }
+}
+
+/// # Description:
+/// Implementation of [`LweCiphertextDiscardingBootstrapEngine`] for [`DynamicEngine`] that
+/// operates on 64 bits integers, on the Cuda backend when it is selected, and on the FFT backend
+/// otherwise.
+///
+/// The bootstrap returns a [`DynamicError::BackendUnavailable`] error when the FFT backend is not
+/// compiled in.
+impl
+    LweCiphertextDiscardingBootstrapEngine<
+        DynamicLweBootstrapKey64,
+        DynamicGlweCiphertext64,
+        DynamicLweCiphertext64,
+        DynamicLweCiphertext64,
+    > for DynamicEngine
+{
+    /// # Example
+    /// ```
+    /// use concrete_core::prelude::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, LweDimension, PolynomialSize,
+    ///     Variance, *,
+    /// };
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // Here a hard-set encoding is applied (shift by 50 bits)
+    /// let input = 3_u64 << 50;
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let (lwe_dim, lwe_dim_output, glwe_dim, poly_size) = (
+    ///     LweDimension(4),
+    ///     LweDimension(1024),
+    ///     GlweDimension(1),
+    ///     PolynomialSize(1024),
+    /// );
+    /// let (dec_lc, dec_bl) = (DecompositionLevelCount(3), DecompositionBaseLog(5));
+    /// // A constant function is applied during the bootstrap
+    /// let lut = vec![8_u64 << 50; poly_size.0];
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DynamicEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let lwe_sk: LweSecretKey64 = engine
+    ///     .default_engine()
+    ///     .generate_new_lwe_secret_key(lwe_dim)?;
+    /// let glwe_sk: GlweSecretKey64 = engine
+    ///     .default_engine()
+    ///     .generate_new_glwe_secret_key(glwe_dim, poly_size)?;
+    /// let bsk: LweBootstrapKey64 = engine
+    ///     .default_engine()
+    ///     .generate_new_lwe_bootstrap_key(&lwe_sk, &glwe_sk, dec_bl, dec_lc, noise)?;
+    /// let lwe_sk_output: LweSecretKey64 = engine
+    ///     .default_engine()
+    ///     .generate_new_lwe_secret_key(lwe_dim_output)?;
+    /// let plaintext = engine.default_engine().create_plaintext_from(&input)?;
+    /// let plaintext_vector = engine.default_engine().create_plaintext_vector_from(&lut)?;
+    /// let acc = engine
+    ///     .default_engine()
+    ///     .trivially_encrypt_glwe_ciphertext(glwe_dim.to_glwe_size(), &plaintext_vector)?;
+    /// let input = engine
+    ///     .default_engine()
+    ///     .encrypt_lwe_ciphertext(&lwe_sk, &plaintext, noise)?;
+    /// let output = engine
+    ///     .default_engine()
+    ///     .zero_encrypt_lwe_ciphertext(&lwe_sk_output, noise)?;
+    /// let bsk: DynamicLweBootstrapKey64 = engine.convert_lwe_bootstrap_key(&bsk)?;
+    /// let acc: DynamicGlweCiphertext64 = engine.convert_glwe_ciphertext(&acc)?;
+    /// let input: DynamicLweCiphertext64 = engine.convert_lwe_ciphertext(&input)?;
+    /// let mut output: DynamicLweCiphertext64 = engine.convert_lwe_ciphertext(&output)?;
+    ///
+    /// let result = engine.discard_bootstrap_lwe_ciphertext(&mut output, &input, &acc, &bsk);
+    /// #
+    /// // The bootstrap needs the FFT backend to be compiled in.
+    /// assert_eq!(result.is_ok(), engine.is_available(DynamicBackend::Fft));
+    /// assert_eq!(output.lwe_dimension(), lwe_dim_output);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn discard_bootstrap_lwe_ciphertext(
+        &mut self,
+        output: &mut DynamicLweCiphertext64,
+        input: &DynamicLweCiphertext64,
+        acc: &DynamicGlweCiphertext64,
+        bsk: &DynamicLweBootstrapKey64,
+    ) -> Result<(), LweCiphertextDiscardingBootstrapError<Self::EngineError>> {
+        LweCiphertextDiscardingBootstrapError::perform_generic_checks(output, input, acc, bsk)?;
+        #[cfg(feature = "backend_cuda")]
+        if self.backend == DynamicBackend::Cuda {
+            self.cuda_discard_bootstrap_lwe_ciphertext(output, input, acc, bsk)?;
+            return Ok(());
+        }
+        self.fft_discard_bootstrap_lwe_ciphertext(output, input, acc, bsk)?;
+        Ok(())
+    }
+
+    unsafe fn discard_bootstrap_lwe_ciphertext_unchecked(
+        &mut self,
+        output: &mut DynamicLweCiphertext64,
+        input: &DynamicLweCiphertext64,
+        acc: &DynamicGlweCiphertext64,
+        bsk: &DynamicLweBootstrapKey64,
+    ) {
+        #[cfg(feature = "backend_cuda")]
+        if self.backend == DynamicBackend::Cuda {
+            self.cuda_discard_bootstrap_lwe_ciphertext_unchecked(output, input, acc, bsk);
+            return;
+        }
+        self.fft_discard_bootstrap_lwe_ciphertext_unchecked(output, input, acc, bsk);
+    }
+}
@@ -0,0 +1,180 @@
+use super::{DynamicBackend, DynamicEngine, DynamicError};
+use crate::backends::dynamic::implementation::entities::{
+    DynamicLweCiphertext64, DynamicLweKeyswitchKey64,
+};
+use crate::specification::engines::{
+    LweCiphertextDiscardingKeyswitchEngine, LweCiphertextDiscardingKeyswitchError,
+};
+
+impl From<DynamicError> for LweCiphertextDiscardingKeyswitchError<DynamicError> {
+    fn from(err: DynamicError) -> Self {
+        Self::Engine(err)
+    }
+}
+
+impl DynamicEngine {
+    fn host_discard_keyswitch_lwe_ciphertext(
+        &mut self,
+        output: &mut DynamicLweCiphertext64,
+        input: &DynamicLweCiphertext64,
+        ksk: &DynamicLweKeyswitchKey64,
+    ) -> Result<(), DynamicError> {
+        let (mut input_buffer, mut ksk_buffer) = (None, None);
+        let input = self.host_lwe_ciphertext(input, &mut input_buffer)?;
+        let ksk = self.host_lwe_keyswitch_key(ksk, &mut ksk_buffer)?;
+        self.with_host_lwe_ciphertext_output(output, |engine, output| {
+            engine
+                .default_engine
+                .discard_keyswitch_lwe_ciphertext(output, input, ksk)
+                .map_err(DynamicError::from_backend(DynamicBackend::Default))
+        })
+    }
+
+    unsafe fn host_discard_keyswitch_lwe_ciphertext_unchecked(
+        &mut self,
+        output: &mut DynamicLweCiphertext64,
+        input: &DynamicLweCiphertext64,
+        ksk: &DynamicLweKeyswitchKey64,
+    ) {
+        let (mut input_buffer, mut ksk_buffer) = (None, None);
+        let input = self.host_lwe_ciphertext(input, &mut input_buffer).unwrap();
+        let ksk = self.host_lwe_keyswitch_key(ksk, &mut ksk_buffer).unwrap();
+        self.with_host_lwe_ciphertext_output(output, |engine, output| {
+            engine
+                .default_engine
+                .discard_keyswitch_lwe_ciphertext_unchecked(output, input, ksk);
+            Ok(())
+        })
+        .unwrap();
+    }
+
+    #[cfg(feature = "backend_cuda")]
+    fn cuda_discard_keyswitch_lwe_ciphertext(
+        &mut self,
+        output: &mut DynamicLweCiphertext64,
+        input: &DynamicLweCiphertext64,
+        ksk: &DynamicLweKeyswitchKey64,
+    ) -> Result<(), DynamicError> {
+        let (mut input_buffer, mut ksk_buffer) = (None, None);
+        let input = self.cuda_lwe_ciphertext(input, &mut input_buffer)?;
+        let ksk = self.cuda_lwe_keyswitch_key(ksk, &mut ksk_buffer)?;
+        self.with_cuda_lwe_ciphertext_output(output, |cuda_engine, output| {
+            cuda_engine
+                .discard_keyswitch_lwe_ciphertext(output, input, ksk)
+                .map_err(DynamicError::from_backend(DynamicBackend::Cuda))
+        })
+    }
+
+    #[cfg(feature = "backend_cuda")]
+    unsafe fn cuda_discard_keyswitch_lwe_ciphertext_unchecked(
+        &mut self,
+        output: &mut DynamicLweCiphertext64,
+        input: &DynamicLweCiphertext64,
+        ksk: &DynamicLweKeyswitchKey64,
+    ) {
+        let (mut input_buffer, mut ksk_buffer) = (None, None);
+        let input = self.cuda_lwe_ciphertext(input, &mut input_buffer).unwrap();
+        let ksk = self.cuda_lwe_keyswitch_key(ksk, &mut ksk_buffer).unwrap();
+        self.with_cuda_lwe_ciphertext_output(output, |cuda_engine, output| {
+            cuda_engine.discard_keyswitch_lwe_ciphertext_unchecked(output, input, ksk);
+            Ok(())
+        })
+        .unwrap();
+    }
+}
+
+/// # Description:
+/// Implementation of [`LweCiphertextDiscardingKeyswitchEngine`] for [`DynamicEngine`] that
+/// operates on 64 bits integers, on the Cuda backend when it is selected, and on the default
+/// backend otherwise.
+impl
+    LweCiphertextDiscardingKeyswitchEngine<
+        DynamicLweKeyswitchKey64,
+        DynamicLweCiphertext64,
+        DynamicLweCiphertext64,
+    > for DynamicEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_core::prelude::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, LweDimension, Variance, *,
+    /// };
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let input_lwe_dimension = LweDimension(6);
+    /// let output_lwe_dimension = LweDimension(3);
+    /// let decomposition_level_count = DecompositionLevelCount(2);
+    /// let decomposition_base_log = DecompositionBaseLog(8);
+    /// let noise = Variance(2_f64.powf(-50.));
+    /// // Here a hard-set encoding is applied (shift by 50 bits)
+    /// let input = 3_u64 << 50;
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DynamicEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let input_key: LweSecretKey64 = engine
+    ///     .default_engine()
+    ///     .generate_new_lwe_secret_key(input_lwe_dimension)?;
+    /// let output_key: LweSecretKey64 = engine
+    ///     .default_engine()
+    ///     .generate_new_lwe_secret_key(output_lwe_dimension)?;
+    /// let keyswitch_key = engine.default_engine().generate_new_lwe_keyswitch_key(
+    ///     &input_key,
+    ///     &output_key,
+    ///     decomposition_level_count,
+    ///     decomposition_base_log,
+    ///     noise,
+    /// )?;
+    /// let plaintext = engine.default_engine().create_plaintext_from(&input)?;
+    /// let ciphertext_1 = engine
+    ///     .default_engine()
+    ///     .encrypt_lwe_ciphertext(&input_key, &plaintext, noise)?;
+    /// let ciphertext_2 = engine
+    ///     .default_engine()
+    ///     .zero_encrypt_lwe_ciphertext(&output_key, noise)?;
+    /// let keyswitch_key: DynamicLweKeyswitchKey64 =
+    ///     engine.convert_lwe_keyswitch_key(&keyswitch_key)?;
+    /// let ciphertext_1: DynamicLweCiphertext64 = engine.convert_lwe_ciphertext(&ciphertext_1)?;
+    /// let mut ciphertext_2: DynamicLweCiphertext64 = engine.convert_lwe_ciphertext(&ciphertext_2)?;
+    ///
+    /// engine.discard_keyswitch_lwe_ciphertext(&mut ciphertext_2, &ciphertext_1, &keyswitch_key)?;
+    /// #
+    /// assert_eq!(ciphertext_2.lwe_dimension(), output_lwe_dimension);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn discard_keyswitch_lwe_ciphertext(
+        &mut self,
+        output: &mut DynamicLweCiphertext64,
+        input: &DynamicLweCiphertext64,
+        ksk: &DynamicLweKeyswitchKey64,
+    ) -> Result<(), LweCiphertextDiscardingKeyswitchError<Self::EngineError>> {
+        LweCiphertextDiscardingKeyswitchError::perform_generic_checks(output, input, ksk)?;
+        #[cfg(feature = "backend_cuda")]
+        if self.backend == DynamicBackend::Cuda {
+            self.cuda_discard_keyswitch_lwe_ciphertext(output, input, ksk)?;
+            return Ok(());
+        }
+        self.host_discard_keyswitch_lwe_ciphertext(output, input, ksk)?;
+        Ok(())
+    }
+
+    unsafe fn discard_keyswitch_lwe_ciphertext_unchecked(
+        &mut self,
+        output: &mut DynamicLweCiphertext64,
+        input: &DynamicLweCiphertext64,
+        ksk: &DynamicLweKeyswitchKey64,
+    ) {
+        #[cfg(feature = "backend_cuda")]
+        if self.backend == DynamicBackend::Cuda {
+            self.cuda_discard_keyswitch_lwe_ciphertext_unchecked(output, input, ksk);
+            return;
+        }
+        self.host_discard_keyswitch_lwe_ciphertext_unchecked(output, input, ksk);
+    }
+}
@@ -0,0 +1,97 @@
+use super::{DynamicBackend, DynamicEngine, DynamicError};
+use crate::backends::dynamic::implementation::entities::DynamicLweCiphertext64;
+use crate::prelude::Plaintext64;
+use crate::specification::engines::{
+    LweCiphertextPlaintextDiscardingAdditionEngine, LweCiphertextPlaintextDiscardingAdditionError,
+};
+
+impl From<DynamicError> for LweCiphertextPlaintextDiscardingAdditionError<DynamicError> {
+    fn from(err: DynamicError) -> Self {
+        Self::Engine(err)
+    }
+}
+
+/// # Description:
+/// Implementation of [`LweCiphertextPlaintextDiscardingAdditionEngine`] for [`DynamicEngine`] that
+/// operates on 64 bits integers, on the default backend.
+impl
+    LweCiphertextPlaintextDiscardingAdditionEngine<
+        DynamicLweCiphertext64,
+        Plaintext64,
+        DynamicLweCiphertext64,
+    > for DynamicEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_core::prelude::{LweDimension, Variance, *};
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let lwe_dimension = LweDimension(2);
+    /// // Here a hard-set encoding is applied (shift by 50 bits)
+    /// let input_1 = 3_u64 << 50;
+    /// let input_2 = 7_u64 << 50;
+    /// let noise = Variance(2_f64.powf(-50.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DynamicEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let key: LweSecretKey64 = engine
+    ///     .default_engine()
+    ///     .generate_new_lwe_secret_key(lwe_dimension)?;
+    /// let plaintext_1 = engine.default_engine().create_plaintext_from(&input_1)?;
+    /// let plaintext_2 = engine.default_engine().create_plaintext_from(&input_2)?;
+    /// let ciphertext_1 = engine
+    ///     .default_engine()
+    ///     .encrypt_lwe_ciphertext(&key, &plaintext_1, noise)?;
+    /// let ciphertext_2 = engine
+    ///     .default_engine()
+    ///     .zero_encrypt_lwe_ciphertext(&key, noise)?;
+    /// let ciphertext_1: DynamicLweCiphertext64 = engine.convert_lwe_ciphertext(&ciphertext_1)?;
+    /// let mut ciphertext_2: DynamicLweCiphertext64 = engine.convert_lwe_ciphertext(&ciphertext_2)?;
+    ///
+    /// engine.discard_add_lwe_ciphertext_plaintext(&mut ciphertext_2, &ciphertext_1, &plaintext_2)?;
+    /// #
+    /// assert_eq!(ciphertext_2.lwe_dimension(), lwe_dimension);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn discard_add_lwe_ciphertext_plaintext(
+        &mut self,
+        output: &mut DynamicLweCiphertext64,
+        input_1: &DynamicLweCiphertext64,
+        input_2: &Plaintext64,
+    ) -> Result<(), LweCiphertextPlaintextDiscardingAdditionError<Self::EngineError>> {
+        LweCiphertextPlaintextDiscardingAdditionError::perform_generic_checks(output, input_1)?;
+        let mut buffer = None;
+        let input_1 = self.host_lwe_ciphertext(input_1, &mut buffer)?;
+        self.with_host_lwe_ciphertext_output(output, |engine, output| {
+            engine
+                .default_engine
+                .discard_add_lwe_ciphertext_plaintext(output, input_1, input_2)
+                .map_err(DynamicError::from_backend(DynamicBackend::Default))
+        })?;
+        Ok(())
+    }
+
+    unsafe fn discard_add_lwe_ciphertext_plaintext_unchecked(
+        &mut self,
+        output: &mut DynamicLweCiphertext64,
+        input_1: &DynamicLweCiphertext64,
+        input_2: &Plaintext64,
+    ) {
+        let mut buffer = None;
+        let input_1 = self.host_lwe_ciphertext(input_1, &mut buffer).unwrap();
+        self.with_host_lwe_ciphertext_output(output, |engine, output| {
+            engine
+                .default_engine
+                .discard_add_lwe_ciphertext_plaintext_unchecked(output, input_1, input_2);
+            Ok(())
+        })
+        .unwrap();
+    }
+}
@@ -0,0 +1,106 @@
+#[cfg(feature = "backend_cuda")]
+use super::DynamicBackend;
+use super::{DynamicEngine, DynamicError};
+use crate::backends::dynamic::implementation::entities::{
+    DynamicLweKeyswitchKey64, DynamicLweKeyswitchKey64Repr,
+};
+use crate::prelude::LweKeyswitchKey64;
+use crate::specification::engines::{
+    LweKeyswitchKeyConversionEngine, LweKeyswitchKeyConversionError,
+};
+
+impl From<DynamicError> for LweKeyswitchKeyConversionError<DynamicError> {
+    fn from(err: DynamicError) -> Self {
+        Self::Engine(err)
+    }
+}
+
+/// # Description
+/// Implementation of [`LweKeyswitchKeyConversionEngine`] for [`DynamicEngine`] that stores an LWE
+/// keyswitch key on the selected backend.
+impl LweKeyswitchKeyConversionEngine<LweKeyswitchKey64, DynamicLweKeyswitchKey64>
+    for DynamicEngine
+{
+    /// # Example
+    /// ```
+    /// use concrete_core::prelude::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, LweDimension, Variance, *,
+    /// };
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let input_lwe_dimension = LweDimension(6);
+    /// let output_lwe_dimension = LweDimension(3);
+    /// let decomposition_level_count = DecompositionLevelCount(2);
+    /// let decomposition_base_log = DecompositionBaseLog(8);
+    /// let noise = Variance(2_f64.powf(-50.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DynamicEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let input_key: LweSecretKey64 = engine
+    ///     .default_engine()
+    ///     .generate_new_lwe_secret_key(input_lwe_dimension)?;
+    /// let output_key: LweSecretKey64 = engine
+    ///     .default_engine()
+    ///     .generate_new_lwe_secret_key(output_lwe_dimension)?;
+    /// let keyswitch_key = engine.default_engine().generate_new_lwe_keyswitch_key(
+    ///     &input_key,
+    ///     &output_key,
+    ///     decomposition_level_count,
+    ///     decomposition_base_log,
+    ///     noise,
+    /// )?;
+    ///
+    /// let dynamic_keyswitch_key: DynamicLweKeyswitchKey64 =
+    ///     engine.convert_lwe_keyswitch_key(&keyswitch_key)?;
+    /// #
+    /// assert_eq!(
+    ///     dynamic_keyswitch_key.input_lwe_dimension(),
+    ///     input_lwe_dimension
+    /// );
+    /// assert_eq!(
+    ///     dynamic_keyswitch_key.output_lwe_dimension(),
+    ///     output_lwe_dimension
+    /// );
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn convert_lwe_keyswitch_key(
+        &mut self,
+        input: &LweKeyswitchKey64,
+    ) -> Result<DynamicLweKeyswitchKey64, LweKeyswitchKeyConversionError<Self::EngineError>> {
+        #[cfg(feature = "backend_cuda")]
+        if self.backend == DynamicBackend::Cuda {
+            let key = self
+                .cuda_engine()?
+                .convert_lwe_keyswitch_key(input)
+                .map_err(DynamicError::from_backend(DynamicBackend::Cuda))?;
+            return Ok(DynamicLweKeyswitchKey64(
+                DynamicLweKeyswitchKey64Repr::Cuda(key),
+            ));
+        }
+        Ok(DynamicLweKeyswitchKey64(
+            DynamicLweKeyswitchKey64Repr::Host(input.clone()),
+        ))
+    }
+
+    unsafe fn convert_lwe_keyswitch_key_unchecked(
+        &mut self,
+        input: &LweKeyswitchKey64,
+    ) -> DynamicLweKeyswitchKey64 {
+        #[cfg(feature = "backend_cuda")]
+        if self.backend == DynamicBackend::Cuda {
+            let key = self
+                .cuda_engine()
+                .unwrap()
+                .convert_lwe_keyswitch_key_unchecked(input);
+            return DynamicLweKeyswitchKey64(DynamicLweKeyswitchKey64Repr::Cuda(key));
+        }
+        DynamicLweKeyswitchKey64(DynamicLweKeyswitchKey64Repr::Host(input.clone()))
+    }
+}
@@ -0,0 +1,786 @@
+use crate::backends::dynamic::implementation::entities::{
+    DynamicGlweCiphertext64, DynamicGlweCiphertext64Repr, DynamicLweCiphertext64,
+    DynamicLweCiphertext64Repr, DynamicLweKeyswitchKey64, DynamicLweKeyswitchKey64Repr,
+};
+#[cfg(feature = "backend_fft")]
+use crate::backends::dynamic::implementation::entities::{
+    DynamicLweBootstrapKey64, DynamicLweBootstrapKey64Repr,
+};
+#[cfg(feature = "backend_cuda")]
+use crate::commons::crypto::lwe::LweCiphertext as ImplLweCiphertext;
+#[cfg(feature = "backend_cuda")]
+use crate::prelude::{
+    CudaEngine, CudaFourierLweBootstrapKey64, CudaGlweCiphertext64, CudaLweCiphertext64,
+    CudaLweKeyswitchKey64, GlweCiphertextConversionEngine, LweCiphertextConversionEngine,
+    LweCiphertextEntity, LweKeyswitchKeyConversionEngine,
+};
+use crate::prelude::{DefaultEngine, GlweCiphertext64, LweCiphertext64, LweKeyswitchKey64};
+#[cfg(feature = "backend_fft")]
+use crate::prelude::{FftEngine, FftFourierLweBootstrapKey64};
+use crate::specification::engines::sealed::AbstractEngineSeal;
+use crate::specification::engines::AbstractEngine;
+use concrete_csprng::seeders::Seeder;
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+
+/// A backend the [`DynamicEngine`] can dispatch the operations to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum DynamicBackend {
+    /// The pure-rust default backend, which is always available.
+    Default,
+    /// The `Concrete-FFT` backend, available when the `backend_fft` feature is enabled.
+    Fft,
+    /// The Cuda backend, available when the `backend_cuda` feature is enabled and a GPU is found.
+    Cuda,
+}
+
+impl Display for DynamicBackend {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            DynamicBackend::Default => write!(f, "default"),
+            DynamicBackend::Fft => write!(f, "FFT"),
+            DynamicBackend::Cuda => write!(f, "Cuda"),
+        }
+    }
+}
+
+/// The error which can occur in the execution of FHE operations by the [`DynamicEngine`].
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum DynamicError {
+    /// The backend is not compiled in the build, or not supported by the machine.
+    BackendUnavailable(DynamicBackend),
+    /// An entity stored by the `from` backend can not be converted to the `to` backend.
+    UnsupportedConversion {
+        from: DynamicBackend,
+        to: DynamicBackend,
+    },
+    /// The engine of an underlying backend returned an error, kept as the source of this error.
+    Backend {
+        backend: DynamicBackend,
+        source: Box<dyn Error + Send + Sync>,
+    },
+}
+
+impl Display for DynamicError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            DynamicError::BackendUnavailable(backend) => write!(
+                f,
+                "The {} backend is not available in this build or on this machine.",
+                backend
+            ),
+            DynamicError::UnsupportedConversion { from, to } => write!(
+                f,
+                "The entities of the {} backend can not be converted to the {} backend.",
+                from, to
+            ),
+            DynamicError::Backend { backend, source } => {
+                write!(f, "Error occurred in the {} backend: {}", backend, source)
+            }
+        }
+    }
+}
+
+impl Error for DynamicError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            DynamicError::Backend { source, .. } => Some(source.as_ref()),
+            _ => None,
+        }
+    }
+}
+
+impl DynamicError {
+    /// Returns a function turning an error of the engine of `backend` into a [`DynamicError`].
+    pub(crate) fn from_backend<E: Error + Send + Sync + 'static>(
+        backend: DynamicBackend,
+    ) -> impl FnOnce(E) -> Self {
+        move |error| DynamicError::Backend {
+            backend,
+            source: Box::new(error),
+        }
+    }
+}
+
+/// The main engine exposed by the dynamic backend.
+///
+/// The engine holds an engine of every backend available, and executes each operation on the
+/// backend returned by [`DynamicEngine::backend`] when it supports it:
+///
+/// + The keyswitch is executed on the Cuda backend when it is selected, and on the default backend
+///   otherwise.
+/// + The bootstrap is executed on the Cuda backend when it is selected, and on the FFT backend
+///   otherwise.
+/// + The arithmetic operations are always executed on the default backend.
+///
+/// The input entities stored by another backend are converted to the backend executing the
+/// operation, and the output entities keep being stored by their own backend. The conversions
+/// between the host and a GPU are costly: the keys should be converted with the engine once the
+/// backend is selected, for them to be stored by the backend executing the operations.
+///
+/// The key generation and encryption operations are available through the [`DefaultEngine`]
+/// returned by [`DynamicEngine::default_engine`].
+pub struct DynamicEngine {
+    backend: DynamicBackend,
+    default_engine: DefaultEngine,
+    #[cfg(feature = "backend_fft")]
+    fft_engine: FftEngine,
+    #[cfg(feature = "backend_cuda")]
+    cuda_engine: Option<CudaEngine>,
+}
+
+impl AbstractEngineSeal for DynamicEngine {}
+
+impl AbstractEngine for DynamicEngine {
+    type EngineError = DynamicError;
+
+    type Parameters = Box<dyn Seeder>;
+
+    /// Creates an engine selecting the fastest backend available, that is the Cuda backend if a
+    /// GPU is found, then the FFT backend, then the default backend.
+    fn new(parameters: Self::Parameters) -> Result<Self, Self::EngineError> {
+        let default_engine = DefaultEngine::new(parameters)
+            .map_err(DynamicError::from_backend(DynamicBackend::Default))?;
+        let mut engine = DynamicEngine {
+            backend: DynamicBackend::Default,
+            default_engine,
+            #[cfg(feature = "backend_fft")]
+            fft_engine: FftEngine::new(())
+                .map_err(DynamicError::from_backend(DynamicBackend::Fft))?,
+            // The Cuda backend is only unavailable when no GPU can be used.
+            #[cfg(feature = "backend_cuda")]
+            cuda_engine: CudaEngine::new(()).ok(),
+        };
+        engine.backend = engine
+            .available_backends()
+            .pop()
+            .unwrap_or(DynamicBackend::Default);
+        Ok(engine)
+    }
+}
+
+impl DynamicEngine {
+    /// Returns the backend the operations are dispatched to.
+    pub fn backend(&self) -> DynamicBackend {
+        self.backend
+    }
+
+    /// Selects the backend the operations are dispatched to.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DynamicEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    ///
+    /// engine.set_backend(DynamicBackend::Default)?;
+    /// assert_eq!(engine.backend(), DynamicBackend::Default);
+    /// if !engine.is_available(DynamicBackend::Cuda) {
+    ///     assert!(matches!(
+    ///         engine.set_backend(DynamicBackend::Cuda),
+    ///         Err(DynamicError::BackendUnavailable(DynamicBackend::Cuda))
+    ///     ));
+    /// }
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn set_backend(&mut self, backend: DynamicBackend) -> Result<(), DynamicError> {
+        if !self.is_available(backend) {
+            return Err(DynamicError::BackendUnavailable(backend));
+        }
+        self.backend = backend;
+        Ok(())
+    }
+
+    /// Returns whether the operations can be dispatched to `backend`.
+    pub fn is_available(&self, backend: DynamicBackend) -> bool {
+        match backend {
+            DynamicBackend::Default => true,
+            DynamicBackend::Fft => cfg!(feature = "backend_fft"),
+            #[cfg(feature = "backend_cuda")]
+            DynamicBackend::Cuda => self.cuda_engine.is_some(),
+            #[cfg(not(feature = "backend_cuda"))]
+            DynamicBackend::Cuda => false,
+        }
+    }
+
+    /// Returns the backends the operations can be dispatched to, from the slowest to the fastest.
+    pub fn available_backends(&self) -> Vec<DynamicBackend> {
+        [
+            DynamicBackend::Default,
+            DynamicBackend::Fft,
+            DynamicBackend::Cuda,
+        ]
+        .into_iter()
+        .filter(|backend| self.is_available(*backend))
+        .collect()
+    }
+
+    /// Returns the default engine held by the engine, to generate keys and encrypt or decrypt
+    /// ciphertexts.
+    pub fn default_engine(&mut self) -> &mut DefaultEngine {
+        &mut self.default_engine
+    }
+
+    #[cfg(feature = "backend_cuda")]
+    fn cuda_engine(&mut self) -> Result<&mut CudaEngine, DynamicError> {
+        self.cuda_engine
+            .as_mut()
+            .ok_or(DynamicError::BackendUnavailable(DynamicBackend::Cuda))
+    }
+
+    /// Returns `ciphertext` stored on the host, using `buffer` to hold it if a conversion is
+    /// needed.
+    #[cfg_attr(not(feature = "backend_cuda"), allow(unused_variables))]
+    fn host_lwe_ciphertext<'a>(
+        &mut self,
+        ciphertext: &'a DynamicLweCiphertext64,
+        buffer: &'a mut Option<LweCiphertext64>,
+    ) -> Result<&'a LweCiphertext64, DynamicError> {
+        match &ciphertext.0 {
+            DynamicLweCiphertext64Repr::Host(ciphertext) => Ok(ciphertext),
+            #[cfg(feature = "backend_cuda")]
+            DynamicLweCiphertext64Repr::Cuda(ciphertext) => {
+                let converted = self
+                    .cuda_engine()?
+                    .convert_lwe_ciphertext(ciphertext)
+                    .map_err(DynamicError::from_backend(DynamicBackend::Cuda))?;
+                Ok(buffer.insert(converted))
+            }
+        }
+    }
+
+    /// Returns `ciphertext` stored on the GPU, using `buffer` to hold it if a conversion is
+    /// needed.
+    #[cfg(feature = "backend_cuda")]
+    fn cuda_lwe_ciphertext<'a>(
+        &mut self,
+        ciphertext: &'a DynamicLweCiphertext64,
+        buffer: &'a mut Option<CudaLweCiphertext64>,
+    ) -> Result<&'a CudaLweCiphertext64, DynamicError> {
+        match &ciphertext.0 {
+            DynamicLweCiphertext64Repr::Host(ciphertext) => {
+                let converted = self
+                    .cuda_engine()?
+                    .convert_lwe_ciphertext(ciphertext)
+                    .map_err(DynamicError::from_backend(DynamicBackend::Cuda))?;
+                Ok(buffer.insert(converted))
+            }
+            DynamicLweCiphertext64Repr::Cuda(ciphertext) => Ok(ciphertext),
+        }
+    }
+
+    /// Executes `operation` on `output` stored on the host, converting the result back to the
+    /// backend storing `output`.
+    fn with_host_lwe_ciphertext_output(
+        &mut self,
+        output: &mut DynamicLweCiphertext64,
+        operation: impl FnOnce(&mut Self, &mut LweCiphertext64) -> Result<(), DynamicError>,
+    ) -> Result<(), DynamicError> {
+        match &mut output.0 {
+            DynamicLweCiphertext64Repr::Host(ciphertext) => operation(self, ciphertext),
+            #[cfg(feature = "backend_cuda")]
+            DynamicLweCiphertext64Repr::Cuda(ciphertext) => {
+                let mut host_ciphertext = LweCiphertext64(ImplLweCiphertext::allocate(
+                    0,
+                    ciphertext.lwe_dimension().to_lwe_size(),
+                ));
+                operation(self, &mut host_ciphertext)?;
+                *ciphertext = self
+                    .cuda_engine()?
+                    .convert_lwe_ciphertext(&host_ciphertext)
+                    .map_err(DynamicError::from_backend(DynamicBackend::Cuda))?;
+                Ok(())
+            }
+        }
+    }
+
+    /// Executes `operation` on `output` stored on the GPU, converting the result back to the
+    /// backend storing `output`.
+    #[cfg(feature = "backend_cuda")]
+    fn with_cuda_lwe_ciphertext_output(
+        &mut self,
+        output: &mut DynamicLweCiphertext64,
+        operation: impl FnOnce(&mut CudaEngine, &mut CudaLweCiphertext64) -> Result<(), DynamicError>,
+    ) -> Result<(), DynamicError> {
+        let cuda_engine = self.cuda_engine()?;
+        match &mut output.0 {
+            DynamicLweCiphertext64Repr::Host(ciphertext) => {
+                let mut cuda_ciphertext = cuda_engine
+                    .convert_lwe_ciphertext(&*ciphertext)
+                    .map_err(DynamicError::from_backend(DynamicBackend::Cuda))?;
+                operation(cuda_engine, &mut cuda_ciphertext)?;
+                *ciphertext = cuda_engine
+                    .convert_lwe_ciphertext(&cuda_ciphertext)
+                    .map_err(DynamicError::from_backend(DynamicBackend::Cuda))?;
+                Ok(())
+            }
+            DynamicLweCiphertext64Repr::Cuda(ciphertext) => operation(cuda_engine, ciphertext),
+        }
+    }
+
+    /// Returns `ciphertext` stored on the host, using `buffer` to hold it if a conversion is
+    /// needed.
+    #[cfg_attr(not(feature = "backend_fft"), allow(dead_code))]
+    #[cfg_attr(not(feature = "backend_cuda"), allow(unused_variables))]
+    fn host_glwe_ciphertext<'a>(
+        &mut self,
+        ciphertext: &'a DynamicGlweCiphertext64,
+        buffer: &'a mut Option<GlweCiphertext64>,
+    ) -> Result<&'a GlweCiphertext64, DynamicError> {
+        match &ciphertext.0 {
+            DynamicGlweCiphertext64Repr::Host(ciphertext) => Ok(ciphertext),
+            #[cfg(feature = "backend_cuda")]
+            DynamicGlweCiphertext64Repr::Cuda(ciphertext) => {
+                let converted = self
+                    .cuda_engine()?
+                    .convert_glwe_ciphertext(ciphertext)
+                    .map_err(DynamicError::from_backend(DynamicBackend::Cuda))?;
+                Ok(buffer.insert(converted))
+            }
+        }
+    }
+
+    /// Returns `ciphertext` stored on the GPU, using `buffer` to hold it if a conversion is
+    /// needed.
+    #[cfg(feature = "backend_cuda")]
+    fn cuda_glwe_ciphertext<'a>(
+        &mut self,
+        ciphertext: &'a DynamicGlweCiphertext64,
+        buffer: &'a mut Option<CudaGlweCiphertext64>,
+    ) -> Result<&'a CudaGlweCiphertext64, DynamicError> {
+        match &ciphertext.0 {
+            DynamicGlweCiphertext64Repr::Host(ciphertext) => {
+                let converted = self
+                    .cuda_engine()?
+                    .convert_glwe_ciphertext(ciphertext)
+                    .map_err(DynamicError::from_backend(DynamicBackend::Cuda))?;
+                Ok(buffer.insert(converted))
+            }
+            DynamicGlweCiphertext64Repr::Cuda(ciphertext) => Ok(ciphertext),
+        }
+    }
+
+    /// Returns `key` stored on the host, using `buffer` to hold it if a conversion is needed.
+    #[cfg_attr(not(feature = "backend_cuda"), allow(unused_variables))]
+    fn host_lwe_keyswitch_key<'a>(
+        &mut self,
+        key: &'a DynamicLweKeyswitchKey64,
+        buffer: &'a mut Option<LweKeyswitchKey64>,
+    ) -> Result<&'a LweKeyswitchKey64, DynamicError> {
+        match &key.0 {
+            DynamicLweKeyswitchKey64Repr::Host(key) => Ok(key),
+            #[cfg(feature = "backend_cuda")]
+            DynamicLweKeyswitchKey64Repr::Cuda(key) => {
+                let converted = self
+                    .cuda_engine()?
+                    .convert_lwe_keyswitch_key(key)
+                    .map_err(DynamicError::from_backend(DynamicBackend::Cuda))?;
+                Ok(buffer.insert(converted))
+            }
+        }
+    }
+
+    /// Returns `key` stored on the GPU, using `buffer` to hold it if a conversion is needed.
+    #[cfg(feature = "backend_cuda")]
+    fn cuda_lwe_keyswitch_key<'a>(
+        &mut self,
+        key: &'a DynamicLweKeyswitchKey64,
+        buffer: &'a mut Option<CudaLweKeyswitchKey64>,
+    ) -> Result<&'a CudaLweKeyswitchKey64, DynamicError> {
+        match &key.0 {
+            DynamicLweKeyswitchKey64Repr::Host(key) => {
+                let converted = self
+                    .cuda_engine()?
+                    .convert_lwe_keyswitch_key(key)
+                    .map_err(DynamicError::from_backend(DynamicBackend::Cuda))?;
+                Ok(buffer.insert(converted))
+            }
+            DynamicLweKeyswitchKey64Repr::Cuda(key) => Ok(key),
+        }
+    }
+
+    /// Returns `key` in the format of the FFT backend.
+    #[cfg(feature = "backend_fft")]
+    fn fft_lwe_bootstrap_key<'a>(
+        &self,
+        key: &'a DynamicLweBootstrapKey64,
+    ) -> Result<&'a FftFourierLweBootstrapKey64, DynamicError> {
+        match &key.0 {
+            DynamicLweBootstrapKey64Repr::Fft(key) => Ok(key),
+            #[cfg(feature = "backend_cuda")]
+            DynamicLweBootstrapKey64Repr::Cuda(_) => Err(DynamicError::UnsupportedConversion {
+                from: DynamicBackend::Cuda,
+                to: DynamicBackend::Fft,
+            }),
+        }
+    }
+
+    /// Returns `key` in the format of the Cuda backend.
+    #[cfg(feature = "backend_cuda")]
+    fn cuda_lwe_bootstrap_key<'a>(
+        &self,
+        key: &'a DynamicLweBootstrapKey64,
+    ) -> Result<&'a CudaFourierLweBootstrapKey64, DynamicError> {
+        match &key.0 {
+            DynamicLweBootstrapKey64Repr::Fft(_) => Err(DynamicError::UnsupportedConversion {
+                from: DynamicBackend::Fft,
+                to: DynamicBackend::Cuda,
+            }),
+            DynamicLweBootstrapKey64Repr::Cuda(key) => Ok(key),
+        }
+    }
+}
+
+mod glwe_ciphertext_conversion;
+mod lwe_bootstrap_key_conversion;
+mod lwe_ciphertext_cleartext_discarding_multiplication;
+mod lwe_ciphertext_conversion;
+mod lwe_ciphertext_discarding_addition;
+mod lwe_ciphertext_discarding_bootstrap;
+mod lwe_ciphertext_discarding_keyswitch;
+mod lwe_ciphertext_discarding_opposite;
+mod lwe_ciphertext_plaintext_discarding_addition;
+mod lwe_keyswitch_key_conversion;
+
+#[cfg(test)]
+mod test {
+    use crate::prelude::*;
+    use std::error::Error;
+
+    // Unix seeder must be given a secret input.
+    // Here we just give it 0, which is totally unsafe.
+    const UNSAFE_SECRET: u128 = 0;
+
+    #[test]
+    fn test_backend_selection() -> Result<(), Box<dyn Error>> {
+        let mut engine = DynamicEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+        let available_backends = engine.available_backends();
+        assert_eq!(available_backends.first(), Some(&DynamicBackend::Default));
+        assert_eq!(available_backends.last(), Some(&engine.backend()));
+        assert_eq!(
+            engine.is_available(DynamicBackend::Fft),
+            cfg!(feature = "backend_fft")
+        );
+
+        engine.set_backend(DynamicBackend::Default)?;
+        assert_eq!(engine.backend(), DynamicBackend::Default);
+        if !engine.is_available(DynamicBackend::Cuda) {
+            assert!(matches!(
+                engine.set_backend(DynamicBackend::Cuda),
+                Err(DynamicError::BackendUnavailable(DynamicBackend::Cuda))
+            ));
+            assert_eq!(engine.backend(), DynamicBackend::Default);
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_backend_error_keeps_source() {
+        let error = DynamicError::from_backend(DynamicBackend::Default)(
+            DefaultError::FloatEncoderNullRadius,
+        );
+        assert!(matches!(
+            error,
+            DynamicError::Backend {
+                backend: DynamicBackend::Default,
+                ..
+            }
+        ));
+        let source = error.source().unwrap();
+        assert!(matches!(
+            source.downcast_ref::<DefaultError>(),
+            Some(DefaultError::FloatEncoderNullRadius)
+        ));
+        assert_eq!(
+            error.to_string(),
+            format!("Error occurred in the default backend: {}", source)
+        );
+    }
+
+    #[test]
+    fn test_conversion_roundtrip() -> Result<(), Box<dyn Error>> {
+        // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+        let lwe_dimension = LweDimension(6);
+        let output_lwe_dimension = LweDimension(3);
+        let glwe_dimension = GlweDimension(2);
+        let polynomial_size = PolynomialSize(4);
+        let noise = Variance(2_f64.powf(-50.));
+
+        let mut engine = DynamicEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+        let lwe_sk: LweSecretKey64 = engine
+            .default_engine()
+            .generate_new_lwe_secret_key(lwe_dimension)?;
+        let output_lwe_sk: LweSecretKey64 = engine
+            .default_engine()
+            .generate_new_lwe_secret_key(output_lwe_dimension)?;
+        let glwe_sk: GlweSecretKey64 = engine
+            .default_engine()
+            .generate_new_glwe_secret_key(glwe_dimension, polynomial_size)?;
+        let lwe_ciphertext = engine
+            .default_engine()
+            .zero_encrypt_lwe_ciphertext(&lwe_sk, noise)?;
+        let glwe_ciphertext = engine
+            .default_engine()
+            .zero_encrypt_glwe_ciphertext(&glwe_sk, noise)?;
+        let ksk = engine.default_engine().generate_new_lwe_keyswitch_key(
+            &lwe_sk,
+            &output_lwe_sk,
+            DecompositionLevelCount(2),
+            DecompositionBaseLog(8),
+            noise,
+        )?;
+
+        for backend in engine.available_backends() {
+            engine.set_backend(backend)?;
+
+            let dynamic_ciphertext: DynamicLweCiphertext64 =
+                engine.convert_lwe_ciphertext(&lwe_ciphertext)?;
+            let host_ciphertext: LweCiphertext64 =
+                engine.convert_lwe_ciphertext(&dynamic_ciphertext)?;
+            assert_eq!(host_ciphertext, lwe_ciphertext);
+            let dynamic_ciphertext: DynamicLweCiphertext64 =
+                unsafe { engine.convert_lwe_ciphertext_unchecked(&lwe_ciphertext) };
+            let host_ciphertext: LweCiphertext64 =
+                unsafe { engine.convert_lwe_ciphertext_unchecked(&dynamic_ciphertext) };
+            assert_eq!(host_ciphertext, lwe_ciphertext);
+
+            let dynamic_ciphertext: DynamicGlweCiphertext64 =
+                engine.convert_glwe_ciphertext(&glwe_ciphertext)?;
+            let host_ciphertext: GlweCiphertext64 =
+                engine.convert_glwe_ciphertext(&dynamic_ciphertext)?;
+            assert_eq!(host_ciphertext, glwe_ciphertext);
+            let dynamic_ciphertext: DynamicGlweCiphertext64 =
+                unsafe { engine.convert_glwe_ciphertext_unchecked(&glwe_ciphertext) };
+            let host_ciphertext: GlweCiphertext64 =
+                unsafe { engine.convert_glwe_ciphertext_unchecked(&dynamic_ciphertext) };
+            assert_eq!(host_ciphertext, glwe_ciphertext);
+
+            // The keyswitch keys are stored on the GPU only when the Cuda backend is selected.
+            let dynamic_ksk: DynamicLweKeyswitchKey64 = engine.convert_lwe_keyswitch_key(&ksk)?;
+            let unchecked_dynamic_ksk: DynamicLweKeyswitchKey64 =
+                unsafe { engine.convert_lwe_keyswitch_key_unchecked(&ksk) };
+            let expected_backend = match backend {
+                DynamicBackend::Cuda => DynamicBackend::Cuda,
+                _ => DynamicBackend::Default,
+            };
+            assert_eq!(dynamic_ksk.backend(), expected_backend);
+            assert_eq!(unchecked_dynamic_ksk.backend(), expected_backend);
+            assert_eq!(dynamic_ksk.input_lwe_dimension(), lwe_dimension);
+            assert_eq!(dynamic_ksk.output_lwe_dimension(), output_lwe_dimension);
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_arithmetic_dispatch() -> Result<(), Box<dyn Error>> {
+        // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+        let lwe_dimension = LweDimension(6);
+        let output_lwe_dimension = LweDimension(3);
+        let noise = Variance(2_f64.powf(-50.));
+
+        let mut engine = DynamicEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+        let lwe_sk: LweSecretKey64 = engine
+            .default_engine()
+            .generate_new_lwe_secret_key(lwe_dimension)?;
+        let output_lwe_sk: LweSecretKey64 = engine
+            .default_engine()
+            .generate_new_lwe_secret_key(output_lwe_dimension)?;
+        let ksk = engine.default_engine().generate_new_lwe_keyswitch_key(
+            &lwe_sk,
+            &output_lwe_sk,
+            DecompositionLevelCount(2),
+            DecompositionBaseLog(8),
+            noise,
+        )?;
+        let plaintext = engine
+            .default_engine()
+            .create_plaintext_from(&(3_u64 << 50))?;
+        let cleartext = engine.default_engine().create_cleartext_from(&12_u64)?;
+        let input_1 = engine
+            .default_engine()
+            .encrypt_lwe_ciphertext(&lwe_sk, &plaintext, noise)?;
+        let input_2 = engine
+            .default_engine()
+            .encrypt_lwe_ciphertext(&lwe_sk, &plaintext, noise)?;
+        let zero = engine
+            .default_engine()
+            .zero_encrypt_lwe_ciphertext(&lwe_sk, noise)?;
+        let output_zero = engine
+            .default_engine()
+            .zero_encrypt_lwe_ciphertext(&output_lwe_sk, noise)?;
+
+        // The results computed by the default engine are the reference.
+        let mut expected_sum = zero.clone();
+        let mut expected_opposite = zero.clone();
+        let mut expected_product = zero.clone();
+        let mut expected_plaintext_sum = zero.clone();
+        let mut expected_keyswitch = output_zero.clone();
+        let default_engine = engine.default_engine();
+        default_engine.discard_add_lwe_ciphertext(&mut expected_sum, &input_1, &input_2)?;
+        default_engine.discard_opp_lwe_ciphertext(&mut expected_opposite, &input_1)?;
+        default_engine.discard_mul_lwe_ciphertext_cleartext(
+            &mut expected_product,
+            &input_1,
+            &cleartext,
+        )?;
+        default_engine.discard_add_lwe_ciphertext_plaintext(
+            &mut expected_plaintext_sum,
+            &input_1,
+            &plaintext,
+        )?;
+        default_engine.discard_keyswitch_lwe_ciphertext(&mut expected_keyswitch, &input_1, &ksk)?;
+
+        for backend in engine.available_backends() {
+            engine.set_backend(backend)?;
+            let dynamic_ksk: DynamicLweKeyswitchKey64 = engine.convert_lwe_keyswitch_key(&ksk)?;
+            let input_1: DynamicLweCiphertext64 = engine.convert_lwe_ciphertext(&input_1)?;
+            let input_2: DynamicLweCiphertext64 = engine.convert_lwe_ciphertext(&input_2)?;
+
+            for unchecked in [false, true] {
+                let mut sum: DynamicLweCiphertext64 = engine.convert_lwe_ciphertext(&zero)?;
+                let mut opposite: DynamicLweCiphertext64 = engine.convert_lwe_ciphertext(&zero)?;
+                let mut product: DynamicLweCiphertext64 = engine.convert_lwe_ciphertext(&zero)?;
+                let mut plaintext_sum: DynamicLweCiphertext64 =
+                    engine.convert_lwe_ciphertext(&zero)?;
+                let mut keyswitch: DynamicLweCiphertext64 =
+                    engine.convert_lwe_ciphertext(&output_zero)?;
+                if unchecked {
+                    unsafe {
+                        engine.discard_add_lwe_ciphertext_unchecked(&mut sum, &input_1, &input_2);
+                        engine.discard_opp_lwe_ciphertext_unchecked(&mut opposite, &input_1);
+                        engine.discard_mul_lwe_ciphertext_cleartext_unchecked(
+                            &mut product,
+                            &input_1,
+                            &cleartext,
+                        );
+                        engine.discard_add_lwe_ciphertext_plaintext_unchecked(
+                            &mut plaintext_sum,
+                            &input_1,
+                            &plaintext,
+                        );
+                        engine.discard_keyswitch_lwe_ciphertext_unchecked(
+                            &mut keyswitch,
+                            &input_1,
+                            &dynamic_ksk,
+                        );
+                    }
+                } else {
+                    engine.discard_add_lwe_ciphertext(&mut sum, &input_1, &input_2)?;
+                    engine.discard_opp_lwe_ciphertext(&mut opposite, &input_1)?;
+                    engine.discard_mul_lwe_ciphertext_cleartext(
+                        &mut product,
+                        &input_1,
+                        &cleartext,
+                    )?;
+                    engine.discard_add_lwe_ciphertext_plaintext(
+                        &mut plaintext_sum,
+                        &input_1,
+                        &plaintext,
+                    )?;
+                    engine.discard_keyswitch_lwe_ciphertext(
+                        &mut keyswitch,
+                        &input_1,
+                        &dynamic_ksk,
+                    )?;
+                }
+
+                let sum: LweCiphertext64 = engine.convert_lwe_ciphertext(&sum)?;
+                let opposite: LweCiphertext64 = engine.convert_lwe_ciphertext(&opposite)?;
+                let product: LweCiphertext64 = engine.convert_lwe_ciphertext(&product)?;
+                let plaintext_sum: LweCiphertext64 =
+                    engine.convert_lwe_ciphertext(&plaintext_sum)?;
+                let keyswitch: LweCiphertext64 = engine.convert_lwe_ciphertext(&keyswitch)?;
+                assert_eq!(sum, expected_sum);
+                assert_eq!(opposite, expected_opposite);
+                assert_eq!(product, expected_product);
+                assert_eq!(plaintext_sum, expected_plaintext_sum);
+                assert_eq!(keyswitch, expected_keyswitch);
+            }
+        }
+        Ok(())
+    }
+
+    #[cfg(feature = "backend_fft")]
+    #[test]
+    fn test_bootstrap_dispatch() -> Result<(), Box<dyn Error>> {
+        // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+        let lwe_dimension = LweDimension(4);
+        let glwe_dimension = GlweDimension(1);
+        let polynomial_size = PolynomialSize(512);
+        let noise = Variance(2_f64.powf(-25.));
+
+        let mut engine = DynamicEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+        let lwe_sk: LweSecretKey64 = engine
+            .default_engine()
+            .generate_new_lwe_secret_key(lwe_dimension)?;
+        let glwe_sk: GlweSecretKey64 = engine
+            .default_engine()
+            .generate_new_glwe_secret_key(glwe_dimension, polynomial_size)?;
+        let bsk: LweBootstrapKey64 = engine.default_engine().generate_new_lwe_bootstrap_key(
+            &lwe_sk,
+            &glwe_sk,
+            DecompositionBaseLog(5),
+            DecompositionLevelCount(3),
+            noise,
+        )?;
+        let plaintext = engine
+            .default_engine()
+            .create_plaintext_from(&(3_u64 << 50))?;
+        let lut = engine
+            .default_engine()
+            .create_plaintext_vector_from(&vec![8_u64 << 50; polynomial_size.0])?;
+        let acc = engine
+            .default_engine()
+            .trivially_encrypt_glwe_ciphertext(glwe_dimension.to_glwe_size(), &lut)?;
+        let input = engine
+            .default_engine()
+            .encrypt_lwe_ciphertext(&lwe_sk, &plaintext, noise)?;
+        let zero = engine.default_engine().trivially_encrypt_lwe_ciphertext(
+            LweDimension(glwe_dimension.0 * polynomial_size.0).to_lwe_size(),
+            &plaintext,
+        )?;
+
+        // The result computed by the FFT engine is the reference.
+        let mut fft_engine = FftEngine::new(())?;
+        let fourier_bsk: FftFourierLweBootstrapKey64 =
+            fft_engine.convert_lwe_bootstrap_key(&bsk)?;
+        let mut expected = zero.clone();
+        fft_engine.discard_bootstrap_lwe_ciphertext(&mut expected, &input, &acc, &fourier_bsk)?;
+
+        engine.set_backend(DynamicBackend::Fft)?;
+        let dynamic_bsk: DynamicLweBootstrapKey64 = engine.convert_lwe_bootstrap_key(&bsk)?;
+        let unchecked_dynamic_bsk: DynamicLweBootstrapKey64 =
+            unsafe { engine.convert_lwe_bootstrap_key_unchecked(&bsk) };
+        assert_eq!(dynamic_bsk.backend(), DynamicBackend::Fft);
+        assert_eq!(unchecked_dynamic_bsk.backend(), DynamicBackend::Fft);
+        let acc: DynamicGlweCiphertext64 = engine.convert_glwe_ciphertext(&acc)?;
+        let input: DynamicLweCiphertext64 = engine.convert_lwe_ciphertext(&input)?;
+
+        let mut output: DynamicLweCiphertext64 = engine.convert_lwe_ciphertext(&zero)?;
+        engine.discard_bootstrap_lwe_ciphertext(&mut output, &input, &acc, &dynamic_bsk)?;
+        let output: LweCiphertext64 = engine.convert_lwe_ciphertext(&output)?;
+        assert_eq!(output, expected);
+
+        let mut output: DynamicLweCiphertext64 = engine.convert_lwe_ciphertext(&zero)?;
+        unsafe {
+            engine.discard_bootstrap_lwe_ciphertext_unchecked(
+                &mut output,
+                &input,
+                &acc,
+                &unchecked_dynamic_bsk,
+            );
+        }
+        let output: LweCiphertext64 = engine.convert_lwe_ciphertext(&output)?;
+        assert_eq!(output, expected);
+        Ok(())
+    }
+}
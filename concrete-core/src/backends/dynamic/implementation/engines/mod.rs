@@ -0,0 +1,5 @@
+//! A module containing the [engines](crate::specification::engines) exposed by the dynamic
+//! backend.
+
+mod dynamic_engine;
+pub use dynamic_engine::*;
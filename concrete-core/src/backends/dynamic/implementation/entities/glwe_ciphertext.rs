@@ -0,0 +1,51 @@
+use crate::backends::dynamic::implementation::engines::DynamicBackend;
+#[cfg(feature = "backend_cuda")]
+use crate::prelude::CudaGlweCiphertext64;
+use crate::prelude::{GlweCiphertext64, GlweDimension, PolynomialSize};
+use crate::specification::entities::markers::GlweCiphertextKind;
+use crate::specification::entities::{AbstractEntity, GlweCiphertextEntity};
+
+/// A structure representing a GLWE ciphertext with 64 bits of precision, stored in the memory of
+/// one of the backends available in the build.
+#[derive(Debug)]
+pub struct DynamicGlweCiphertext64(pub(crate) DynamicGlweCiphertext64Repr);
+
+#[derive(Debug)]
+pub(crate) enum DynamicGlweCiphertext64Repr {
+    Host(GlweCiphertext64),
+    #[cfg(feature = "backend_cuda")]
+    Cuda(CudaGlweCiphertext64),
+}
+
+impl DynamicGlweCiphertext64 {
+    /// Returns the backend whose memory holds the ciphertext.
+    pub fn backend(&self) -> DynamicBackend {
+        match &self.0 {
+            DynamicGlweCiphertext64Repr::Host(_) => DynamicBackend::Default,
+            #[cfg(feature = "backend_cuda")]
+            DynamicGlweCiphertext64Repr::Cuda(_) => DynamicBackend::Cuda,
+        }
+    }
+}
+
+impl AbstractEntity for DynamicGlweCiphertext64 {
+    type Kind = GlweCiphertextKind;
+}
+
+impl GlweCiphertextEntity for DynamicGlweCiphertext64 {
+    fn glwe_dimension(&self) -> GlweDimension {
+        match &self.0 {
+            DynamicGlweCiphertext64Repr::Host(ciphertext) => ciphertext.glwe_dimension(),
+            #[cfg(feature = "backend_cuda")]
+            DynamicGlweCiphertext64Repr::Cuda(ciphertext) => ciphertext.glwe_dimension(),
+        }
+    }
+
+    fn polynomial_size(&self) -> PolynomialSize {
+        match &self.0 {
+            DynamicGlweCiphertext64Repr::Host(ciphertext) => ciphertext.polynomial_size(),
+            #[cfg(feature = "backend_cuda")]
+            DynamicGlweCiphertext64Repr::Cuda(ciphertext) => ciphertext.polynomial_size(),
+        }
+    }
+}
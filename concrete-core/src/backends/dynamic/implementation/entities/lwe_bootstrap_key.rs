@@ -0,0 +1,105 @@
+use crate::backends::dynamic::implementation::engines::DynamicBackend;
+#[cfg(feature = "backend_cuda")]
+use crate::prelude::CudaFourierLweBootstrapKey64;
+#[cfg(feature = "backend_fft")]
+use crate::prelude::FftFourierLweBootstrapKey64;
+#[cfg(not(feature = "backend_fft"))]
+use crate::prelude::LweBootstrapKey64;
+use crate::prelude::{
+    DecompositionBaseLog, DecompositionLevelCount, GlweDimension, LweDimension, PolynomialSize,
+};
+use crate::specification::entities::markers::LweBootstrapKeyKind;
+use crate::specification::entities::{AbstractEntity, LweBootstrapKeyEntity};
+
+/// A structure representing an LWE bootstrap key with 64 bits of precision, stored in the memory
+/// and in the format of one of the backends available in the build.
+///
+/// The key is stored in the Fourier domain when a backend supporting the bootstrap is compiled in,
+/// and in the standard domain otherwise.
+#[derive(Debug)]
+pub struct DynamicLweBootstrapKey64(pub(crate) DynamicLweBootstrapKey64Repr);
+
+#[derive(Debug)]
+pub(crate) enum DynamicLweBootstrapKey64Repr {
+    #[cfg(not(feature = "backend_fft"))]
+    Standard(LweBootstrapKey64),
+    #[cfg(feature = "backend_fft")]
+    Fft(FftFourierLweBootstrapKey64),
+    #[cfg(feature = "backend_cuda")]
+    Cuda(CudaFourierLweBootstrapKey64),
+}
+
+impl DynamicLweBootstrapKey64 {
+    /// Returns the backend whose memory holds the key.
+    pub fn backend(&self) -> DynamicBackend {
+        match &self.0 {
+            #[cfg(not(feature = "backend_fft"))]
+            DynamicLweBootstrapKey64Repr::Standard(_) => DynamicBackend::Default,
+            #[cfg(feature = "backend_fft")]
+            DynamicLweBootstrapKey64Repr::Fft(_) => DynamicBackend::Fft,
+            #[cfg(feature = "backend_cuda")]
+            DynamicLweBootstrapKey64Repr::Cuda(_) => DynamicBackend::Cuda,
+        }
+    }
+}
+
+impl AbstractEntity for DynamicLweBootstrapKey64 {
+    type Kind = LweBootstrapKeyKind;
+}
+
+impl LweBootstrapKeyEntity for DynamicLweBootstrapKey64 {
+    fn glwe_dimension(&self) -> GlweDimension {
+        match &self.0 {
+            #[cfg(not(feature = "backend_fft"))]
+            DynamicLweBootstrapKey64Repr::Standard(key) => key.glwe_dimension(),
+            #[cfg(feature = "backend_fft")]
+            DynamicLweBootstrapKey64Repr::Fft(key) => key.glwe_dimension(),
+            #[cfg(feature = "backend_cuda")]
+            DynamicLweBootstrapKey64Repr::Cuda(key) => key.glwe_dimension(),
+        }
+    }
+
+    fn polynomial_size(&self) -> PolynomialSize {
+        match &self.0 {
+            #[cfg(not(feature = "backend_fft"))]
+            DynamicLweBootstrapKey64Repr::Standard(key) => key.polynomial_size(),
+            #[cfg(feature = "backend_fft")]
+            DynamicLweBootstrapKey64Repr::Fft(key) => key.polynomial_size(),
+            #[cfg(feature = "backend_cuda")]
+            DynamicLweBootstrapKey64Repr::Cuda(key) => key.polynomial_size(),
+        }
+    }
+
+    fn input_lwe_dimension(&self) -> LweDimension {
+        match &self.0 {
+            #[cfg(not(feature = "backend_fft"))]
+            DynamicLweBootstrapKey64Repr::Standard(key) => key.input_lwe_dimension(),
+            #[cfg(feature = "backend_fft")]
+            DynamicLweBootstrapKey64Repr::Fft(key) => key.input_lwe_dimension(),
+            #[cfg(feature = "backend_cuda")]
+            DynamicLweBootstrapKey64Repr::Cuda(key) => key.input_lwe_dimension(),
+        }
+    }
+
+    fn decomposition_base_log(&self) -> DecompositionBaseLog {
+        match &self.0 {
+            #[cfg(not(feature = "backend_fft"))]
+            DynamicLweBootstrapKey64Repr::Standard(key) => key.decomposition_base_log(),
+            #[cfg(feature = "backend_fft")]
+            DynamicLweBootstrapKey64Repr::Fft(key) => key.decomposition_base_log(),
+            #[cfg(feature = "backend_cuda")]
+            DynamicLweBootstrapKey64Repr::Cuda(key) => key.decomposition_base_log(),
+        }
+    }
+
+    fn decomposition_level_count(&self) -> DecompositionLevelCount {
+        match &self.0 {
+            #[cfg(not(feature = "backend_fft"))]
+            DynamicLweBootstrapKey64Repr::Standard(key) => key.decomposition_level_count(),
+            #[cfg(feature = "backend_fft")]
+            DynamicLweBootstrapKey64Repr::Fft(key) => key.decomposition_level_count(),
+            #[cfg(feature = "backend_cuda")]
+            DynamicLweBootstrapKey64Repr::Cuda(key) => key.decomposition_level_count(),
+        }
+    }
+}
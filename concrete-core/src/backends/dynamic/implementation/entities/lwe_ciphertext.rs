@@ -0,0 +1,43 @@
+use crate::backends::dynamic::implementation::engines::DynamicBackend;
+#[cfg(feature = "backend_cuda")]
+use crate::prelude::CudaLweCiphertext64;
+use crate::prelude::{LweCiphertext64, LweDimension};
+use crate::specification::entities::markers::LweCiphertextKind;
+use crate::specification::entities::{AbstractEntity, LweCiphertextEntity};
+
+/// A structure representing an LWE ciphertext with 64 bits of precision, stored in the memory of
+/// one of the backends available in the build.
+#[derive(Debug)]
+pub struct DynamicLweCiphertext64(pub(crate) DynamicLweCiphertext64Repr);
+
+#[derive(Debug)]
+pub(crate) enum DynamicLweCiphertext64Repr {
+    Host(LweCiphertext64),
+    #[cfg(feature = "backend_cuda")]
+    Cuda(CudaLweCiphertext64),
+}
+
+impl DynamicLweCiphertext64 {
+    /// Returns the backend whose memory holds the ciphertext.
+    pub fn backend(&self) -> DynamicBackend {
+        match &self.0 {
+            DynamicLweCiphertext64Repr::Host(_) => DynamicBackend::Default,
+            #[cfg(feature = "backend_cuda")]
+            DynamicLweCiphertext64Repr::Cuda(_) => DynamicBackend::Cuda,
+        }
+    }
+}
+
+impl AbstractEntity for DynamicLweCiphertext64 {
+    type Kind = LweCiphertextKind;
+}
+
+impl LweCiphertextEntity for DynamicLweCiphertext64 {
+    fn lwe_dimension(&self) -> LweDimension {
+        match &self.0 {
+            DynamicLweCiphertext64Repr::Host(ciphertext) => ciphertext.lwe_dimension(),
+            #[cfg(feature = "backend_cuda")]
+            DynamicLweCiphertext64Repr::Cuda(ciphertext) => ciphertext.lwe_dimension(),
+        }
+    }
+}
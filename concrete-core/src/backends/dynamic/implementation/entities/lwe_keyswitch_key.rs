@@ -0,0 +1,69 @@
+use crate::backends::dynamic::implementation::engines::DynamicBackend;
+#[cfg(feature = "backend_cuda")]
+use crate::prelude::CudaLweKeyswitchKey64;
+use crate::prelude::{
+    DecompositionBaseLog, DecompositionLevelCount, LweDimension, LweKeyswitchKey64,
+};
+use crate::specification::entities::markers::LweKeyswitchKeyKind;
+use crate::specification::entities::{AbstractEntity, LweKeyswitchKeyEntity};
+
+/// A structure representing an LWE keyswitch key with 64 bits of precision, stored in the memory
+/// of one of the backends available in the build.
+#[derive(Debug)]
+pub struct DynamicLweKeyswitchKey64(pub(crate) DynamicLweKeyswitchKey64Repr);
+
+#[derive(Debug)]
+pub(crate) enum DynamicLweKeyswitchKey64Repr {
+    Host(LweKeyswitchKey64),
+    #[cfg(feature = "backend_cuda")]
+    Cuda(CudaLweKeyswitchKey64),
+}
+
+impl DynamicLweKeyswitchKey64 {
+    /// Returns the backend whose memory holds the key.
+    pub fn backend(&self) -> DynamicBackend {
+        match &self.0 {
+            DynamicLweKeyswitchKey64Repr::Host(_) => DynamicBackend::Default,
+            #[cfg(feature = "backend_cuda")]
+            DynamicLweKeyswitchKey64Repr::Cuda(_) => DynamicBackend::Cuda,
+        }
+    }
+}
+
+impl AbstractEntity for DynamicLweKeyswitchKey64 {
+    type Kind = LweKeyswitchKeyKind;
+}
+
+impl LweKeyswitchKeyEntity for DynamicLweKeyswitchKey64 {
+    fn input_lwe_dimension(&self) -> LweDimension {
+        match &self.0 {
+            DynamicLweKeyswitchKey64Repr::Host(key) => key.input_lwe_dimension(),
+            #[cfg(feature = "backend_cuda")]
+            DynamicLweKeyswitchKey64Repr::Cuda(key) => key.input_lwe_dimension(),
+        }
+    }
+
+    fn output_lwe_dimension(&self) -> LweDimension {
+        match &self.0 {
+            DynamicLweKeyswitchKey64Repr::Host(key) => key.output_lwe_dimension(),
+            #[cfg(feature = "backend_cuda")]
+            DynamicLweKeyswitchKey64Repr::Cuda(key) => key.output_lwe_dimension(),
+        }
+    }
+
+    fn decomposition_level_count(&self) -> DecompositionLevelCount {
+        match &self.0 {
+            DynamicLweKeyswitchKey64Repr::Host(key) => key.decomposition_level_count(),
+            #[cfg(feature = "backend_cuda")]
+            DynamicLweKeyswitchKey64Repr::Cuda(key) => key.decomposition_level_count(),
+        }
+    }
+
+    fn decomposition_base_log(&self) -> DecompositionBaseLog {
+        match &self.0 {
+            DynamicLweKeyswitchKey64Repr::Host(key) => key.decomposition_base_log(),
+            #[cfg(feature = "backend_cuda")]
+            DynamicLweKeyswitchKey64Repr::Cuda(key) => key.decomposition_base_log(),
+        }
+    }
+}
@@ -0,0 +1,11 @@
+//! A backend dispatching the operations at runtime to the other backends compiled in the build.
+//!
+//! Picking an engine of the other backends is a compile-time decision, which forces a program
+//! supporting several backends to be duplicated for each of them. The
+//! [`DynamicEngine`](engines::DynamicEngine) of this backend instead holds the engines of every
+//! backend available in the build, and the backend used to execute the operations is chosen when
+//! the program runs. The entities of this backend are stored in the memory of one of the
+//! underlying backends, and are converted to the backend executing an operation when needed.
+
+mod implementation;
+pub use implementation::{engines, entities};
@@ -0,0 +1,385 @@
+use super::{FftEngine, FftError};
+use crate::backends::fft::private::crypto::bootstrap::keyswitch_bootstrap_scratch;
+use crate::backends::fft::private::math::fft::Fft;
+use crate::commons::math::tensor::{AsMutSlice, AsRefSlice};
+use crate::prelude::{
+    FftFourierLweBootstrapKey32, FftFourierLweBootstrapKey64, GlweCiphertext32, GlweCiphertext64,
+    GlweCiphertextEntity, GlweCiphertextView32, GlweCiphertextView64, LweCiphertext32,
+    LweCiphertext64, LweCiphertextDiscardingKeyswitchBootstrapEngine,
+    LweCiphertextDiscardingKeyswitchBootstrapError, LweCiphertextMutView32, LweCiphertextMutView64,
+    LweCiphertextView32, LweCiphertextView64, LweKeyswitchKey32, LweKeyswitchKey64,
+};
+
+impl From<FftError> for LweCiphertextDiscardingKeyswitchBootstrapError<FftError> {
+    fn from(err: FftError) -> Self {
+        Self::Engine(err)
+    }
+}
+
+/// # Description
+///
+/// Implementation of [`LweCiphertextDiscardingKeyswitchBootstrapEngine`] for [`FftEngine`] that
+/// operates on 32 bit integers.
+impl
+    LweCiphertextDiscardingKeyswitchBootstrapEngine<
+        LweKeyswitchKey32,
+        FftFourierLweBootstrapKey32,
+        GlweCiphertext32,
+        LweCiphertext32,
+        LweCiphertext32,
+    > for FftEngine
+{
+    /// # Example
+    /// ```
+    /// use concrete_core::prelude::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, LweDimension, PolynomialSize,
+    ///     Variance, *,
+    /// };
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // Here a hard-set encoding is applied (shift by 20 bits)
+    /// let input = 3_u32 << 20;
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let (small_lwe_dim, glwe_dim, poly_size) =
+    ///     (LweDimension(4), GlweDimension(1), PolynomialSize(1024));
+    /// let (bsk_dec_lc, bsk_dec_bl) = (DecompositionLevelCount(3), DecompositionBaseLog(5));
+    /// let (ksk_dec_lc, ksk_dec_bl) = (DecompositionLevelCount(5), DecompositionBaseLog(3));
+    /// // A constant function is applied during the bootstrap
+    /// let lut = vec![8_u32 << 20; poly_size.0];
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut default_engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let mut fft_engine = FftEngine::new(())?;
+    /// let small_lwe_sk: LweSecretKey32 = default_engine.generate_new_lwe_secret_key(small_lwe_dim)?;
+    /// let glwe_sk: GlweSecretKey32 =
+    ///     default_engine.generate_new_glwe_secret_key(glwe_dim, poly_size)?;
+    /// let big_lwe_sk: LweSecretKey32 =
+    ///     default_engine.transform_glwe_secret_key_to_lwe_secret_key(glwe_sk.clone())?;
+    /// let bsk: LweBootstrapKey32 = default_engine.generate_new_lwe_bootstrap_key(
+    ///     &small_lwe_sk,
+    ///     &glwe_sk,
+    ///     bsk_dec_bl,
+    ///     bsk_dec_lc,
+    ///     noise,
+    /// )?;
+    /// let bsk: FftFourierLweBootstrapKey32 = fft_engine.convert_lwe_bootstrap_key(&bsk)?;
+    /// let ksk: LweKeyswitchKey32 = default_engine.generate_new_lwe_keyswitch_key(
+    ///     &big_lwe_sk,
+    ///     &small_lwe_sk,
+    ///     ksk_dec_lc,
+    ///     ksk_dec_bl,
+    ///     noise,
+    /// )?;
+    /// let plaintext = default_engine.create_plaintext_from(&input)?;
+    /// let plaintext_vector = default_engine.create_plaintext_vector_from(&lut)?;
+    /// let acc = default_engine
+    ///     .trivially_encrypt_glwe_ciphertext(glwe_dim.to_glwe_size(), &plaintext_vector)?;
+    /// let input = default_engine.encrypt_lwe_ciphertext(&big_lwe_sk, &plaintext, noise)?;
+    /// let mut output = default_engine.zero_encrypt_lwe_ciphertext(&big_lwe_sk, noise)?;
+    ///
+    /// fft_engine.discard_keyswitch_bootstrap_lwe_ciphertext(&mut output, &input, &acc, &ksk, &bsk)?;
+    /// #
+    /// assert_eq!(output.lwe_dimension(), big_lwe_sk.lwe_dimension());
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn discard_keyswitch_bootstrap_lwe_ciphertext(
+        &mut self,
+        output: &mut LweCiphertext32,
+        input: &LweCiphertext32,
+        acc: &GlweCiphertext32,
+        ksk: &LweKeyswitchKey32,
+        bsk: &FftFourierLweBootstrapKey32,
+    ) -> Result<(), LweCiphertextDiscardingKeyswitchBootstrapError<Self::EngineError>> {
+        FftError::perform_fft_checks(acc.polynomial_size())?;
+        LweCiphertextDiscardingKeyswitchBootstrapError::perform_generic_checks(
+            output, input, acc, ksk, bsk,
+        )?;
+        unsafe {
+            self.discard_keyswitch_bootstrap_lwe_ciphertext_unchecked(output, input, acc, ksk, bsk)
+        };
+        Ok(())
+    }
+
+    unsafe fn discard_keyswitch_bootstrap_lwe_ciphertext_unchecked(
+        &mut self,
+        output: &mut LweCiphertext32,
+        input: &LweCiphertext32,
+        acc: &GlweCiphertext32,
+        ksk: &LweKeyswitchKey32,
+        bsk: &FftFourierLweBootstrapKey32,
+    ) {
+        let fft = Fft::new(acc.0.polynomial_size());
+        let fft = fft.as_view();
+        self.resize(
+            keyswitch_bootstrap_scratch::<u32>(
+                ksk.0.lwe_size(),
+                acc.0.size(),
+                acc.0.polynomial_size(),
+                fft,
+            )
+            .unwrap()
+            .unaligned_bytes_required(),
+        );
+        bsk.0.as_view().keyswitch_bootstrap(
+            output.0.tensor.as_mut_slice(),
+            input.0.tensor.as_slice(),
+            acc.0.as_view(),
+            ksk.0.as_view(),
+            fft,
+            self.stack(),
+        );
+    }
+}
+
+/// # Description
+///
+/// Implementation of [`LweCiphertextDiscardingKeyswitchBootstrapEngine`] for [`FftEngine`] that
+/// operates on 64 bit integers.
+impl
+    LweCiphertextDiscardingKeyswitchBootstrapEngine<
+        LweKeyswitchKey64,
+        FftFourierLweBootstrapKey64,
+        GlweCiphertext64,
+        LweCiphertext64,
+        LweCiphertext64,
+    > for FftEngine
+{
+    /// # Example
+    /// ```
+    /// use concrete_core::prelude::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, LweDimension, PolynomialSize,
+    ///     Variance, *,
+    /// };
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // Here a hard-set encoding is applied (shift by 50 bits)
+    /// let input = 3_u64 << 50;
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let (small_lwe_dim, glwe_dim, poly_size) =
+    ///     (LweDimension(4), GlweDimension(1), PolynomialSize(1024));
+    /// let (bsk_dec_lc, bsk_dec_bl) = (DecompositionLevelCount(3), DecompositionBaseLog(5));
+    /// let (ksk_dec_lc, ksk_dec_bl) = (DecompositionLevelCount(5), DecompositionBaseLog(3));
+    /// // A constant function is applied during the bootstrap
+    /// let lut = vec![8_u64 << 50; poly_size.0];
+    /// let noise = Variance(2_f64.powf(-50.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut default_engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let mut fft_engine = FftEngine::new(())?;
+    /// let small_lwe_sk: LweSecretKey64 = default_engine.generate_new_lwe_secret_key(small_lwe_dim)?;
+    /// let glwe_sk: GlweSecretKey64 =
+    ///     default_engine.generate_new_glwe_secret_key(glwe_dim, poly_size)?;
+    /// let big_lwe_sk: LweSecretKey64 =
+    ///     default_engine.transform_glwe_secret_key_to_lwe_secret_key(glwe_sk.clone())?;
+    /// let bsk: LweBootstrapKey64 = default_engine.generate_new_lwe_bootstrap_key(
+    ///     &small_lwe_sk,
+    ///     &glwe_sk,
+    ///     bsk_dec_bl,
+    ///     bsk_dec_lc,
+    ///     noise,
+    /// )?;
+    /// let bsk: FftFourierLweBootstrapKey64 = fft_engine.convert_lwe_bootstrap_key(&bsk)?;
+    /// let ksk: LweKeyswitchKey64 = default_engine.generate_new_lwe_keyswitch_key(
+    ///     &big_lwe_sk,
+    ///     &small_lwe_sk,
+    ///     ksk_dec_lc,
+    ///     ksk_dec_bl,
+    ///     noise,
+    /// )?;
+    /// let plaintext = default_engine.create_plaintext_from(&input)?;
+    /// let plaintext_vector = default_engine.create_plaintext_vector_from(&lut)?;
+    /// let acc = default_engine
+    ///     .trivially_encrypt_glwe_ciphertext(glwe_dim.to_glwe_size(), &plaintext_vector)?;
+    /// let input = default_engine.encrypt_lwe_ciphertext(&big_lwe_sk, &plaintext, noise)?;
+    /// let mut output = default_engine.zero_encrypt_lwe_ciphertext(&big_lwe_sk, noise)?;
+    ///
+    /// fft_engine.discard_keyswitch_bootstrap_lwe_ciphertext(&mut output, &input, &acc, &ksk, &bsk)?;
+    /// #
+    /// assert_eq!(output.lwe_dimension(), big_lwe_sk.lwe_dimension());
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn discard_keyswitch_bootstrap_lwe_ciphertext(
+        &mut self,
+        output: &mut LweCiphertext64,
+        input: &LweCiphertext64,
+        acc: &GlweCiphertext64,
+        ksk: &LweKeyswitchKey64,
+        bsk: &FftFourierLweBootstrapKey64,
+    ) -> Result<(), LweCiphertextDiscardingKeyswitchBootstrapError<Self::EngineError>> {
+        FftError::perform_fft_checks(acc.polynomial_size())?;
+        LweCiphertextDiscardingKeyswitchBootstrapError::perform_generic_checks(
+            output, input, acc, ksk, bsk,
+        )?;
+        unsafe {
+            self.discard_keyswitch_bootstrap_lwe_ciphertext_unchecked(output, input, acc, ksk, bsk)
+        };
+        Ok(())
+    }
+
+    unsafe fn discard_keyswitch_bootstrap_lwe_ciphertext_unchecked(
+        &mut self,
+        output: &mut LweCiphertext64,
+        input: &LweCiphertext64,
+        acc: &GlweCiphertext64,
+        ksk: &LweKeyswitchKey64,
+        bsk: &FftFourierLweBootstrapKey64,
+    ) {
+        let fft = Fft::new(acc.0.polynomial_size());
+        let fft = fft.as_view();
+        self.resize(
+            keyswitch_bootstrap_scratch::<u64>(
+                ksk.0.lwe_size(),
+                acc.0.size(),
+                acc.0.polynomial_size(),
+                fft,
+            )
+            .unwrap()
+            .unaligned_bytes_required(),
+        );
+        bsk.0.as_view().keyswitch_bootstrap(
+            output.0.tensor.as_mut_slice(),
+            input.0.tensor.as_slice(),
+            acc.0.as_view(),
+            ksk.0.as_view(),
+            fft,
+            self.stack(),
+        );
+    }
+}
+
+/// # Description
+///
+/// Implementation of [`LweCiphertextDiscardingKeyswitchBootstrapEngine`] for [`FftEngine`] that
+/// operates on 32 bit integers.
+impl
+    LweCiphertextDiscardingKeyswitchBootstrapEngine<
+        LweKeyswitchKey32,
+        FftFourierLweBootstrapKey32,
+        GlweCiphertextView32<'_>,
+        LweCiphertextView32<'_>,
+        LweCiphertextMutView32<'_>,
+    > for FftEngine
+{
+    fn discard_keyswitch_bootstrap_lwe_ciphertext(
+        &mut self,
+        output: &mut LweCiphertextMutView32,
+        input: &LweCiphertextView32,
+        acc: &GlweCiphertextView32,
+        ksk: &LweKeyswitchKey32,
+        bsk: &FftFourierLweBootstrapKey32,
+    ) -> Result<(), LweCiphertextDiscardingKeyswitchBootstrapError<Self::EngineError>> {
+        FftError::perform_fft_checks(acc.polynomial_size())?;
+        LweCiphertextDiscardingKeyswitchBootstrapError::perform_generic_checks(
+            output, input, acc, ksk, bsk,
+        )?;
+        unsafe {
+            self.discard_keyswitch_bootstrap_lwe_ciphertext_unchecked(output, input, acc, ksk, bsk)
+        };
+        Ok(())
+    }
+
+    unsafe fn discard_keyswitch_bootstrap_lwe_ciphertext_unchecked(
+        &mut self,
+        output: &mut LweCiphertextMutView32,
+        input: &LweCiphertextView32,
+        acc: &GlweCiphertextView32,
+        ksk: &LweKeyswitchKey32,
+        bsk: &FftFourierLweBootstrapKey32,
+    ) {
+        let fft = Fft::new(acc.0.polynomial_size());
+        let fft = fft.as_view();
+        self.resize(
+            keyswitch_bootstrap_scratch::<u32>(
+                ksk.0.lwe_size(),
+                acc.0.size(),
+                acc.0.polynomial_size(),
+                fft,
+            )
+            .unwrap()
+            .unaligned_bytes_required(),
+        );
+        bsk.0.as_view().keyswitch_bootstrap(
+            output.0.tensor.as_mut_slice(),
+            input.0.tensor.as_slice(),
+            acc.0.as_view(),
+            ksk.0.as_view(),
+            fft,
+            self.stack(),
+        );
+    }
+}
+
+/// # Description
+///
+/// Implementation of [`LweCiphertextDiscardingKeyswitchBootstrapEngine`] for [`FftEngine`] that
+/// operates on 64 bit integers.
+impl
+    LweCiphertextDiscardingKeyswitchBootstrapEngine<
+        LweKeyswitchKey64,
+        FftFourierLweBootstrapKey64,
+        GlweCiphertextView64<'_>,
+        LweCiphertextView64<'_>,
+        LweCiphertextMutView64<'_>,
+    > for FftEngine
+{
+    fn discard_keyswitch_bootstrap_lwe_ciphertext(
+        &mut self,
+        output: &mut LweCiphertextMutView64,
+        input: &LweCiphertextView64,
+        acc: &GlweCiphertextView64,
+        ksk: &LweKeyswitchKey64,
+        bsk: &FftFourierLweBootstrapKey64,
+    ) -> Result<(), LweCiphertextDiscardingKeyswitchBootstrapError<Self::EngineError>> {
+        FftError::perform_fft_checks(acc.polynomial_size())?;
+        LweCiphertextDiscardingKeyswitchBootstrapError::perform_generic_checks(
+            output, input, acc, ksk, bsk,
+        )?;
+        unsafe {
+            self.discard_keyswitch_bootstrap_lwe_ciphertext_unchecked(output, input, acc, ksk, bsk)
+        };
+        Ok(())
+    }
+
+    unsafe fn discard_keyswitch_bootstrap_lwe_ciphertext_unchecked(
+        &mut self,
+        output: &mut LweCiphertextMutView64,
+        input: &LweCiphertextView64,
+        acc: &GlweCiphertextView64,
+        ksk: &LweKeyswitchKey64,
+        bsk: &FftFourierLweBootstrapKey64,
+    ) {
+        let fft = Fft::new(acc.0.polynomial_size());
+        let fft = fft.as_view();
+        self.resize(
+            keyswitch_bootstrap_scratch::<u64>(
+                ksk.0.lwe_size(),
+                acc.0.size(),
+                acc.0.polynomial_size(),
+                fft,
+            )
+            .unwrap()
+            .unaligned_bytes_required(),
+        );
+        bsk.0.as_view().keyswitch_bootstrap(
+            output.0.tensor.as_mut_slice(),
+            input.0.tensor.as_slice(),
+            acc.0.as_view(),
+            ksk.0.as_view(),
+            fft,
+            self.stack(),
+        );
+    }
+}
@@ -0,0 +1,375 @@
+use super::{FftEngine, FftError};
+use crate::backends::fft::private::crypto::bootstrap::bootstrap_scratch;
+use crate::backends::fft::private::math::fft::Fft;
+use crate::commons::math::tensor::{AsMutSlice, AsRefSlice};
+use crate::prelude::{
+    FftFourierLweBootstrapKey32, FftFourierLweBootstrapKey64, GlweCiphertextVector32,
+    GlweCiphertextVector64, GlweCiphertextVectorEntity, GlweCiphertextVectorView64,
+    LweCiphertextVector32, LweCiphertextVector64, LweCiphertextVectorDiscardingBootstrapEngine,
+    LweCiphertextVectorDiscardingBootstrapError, LweCiphertextVectorMutView64,
+    LweCiphertextVectorView64,
+};
+
+impl From<FftError> for LweCiphertextVectorDiscardingBootstrapError<FftError> {
+    fn from(err: FftError) -> Self {
+        Self::Engine(err)
+    }
+}
+
+/// # Description
+///
+/// Implementation of [`LweCiphertextVectorDiscardingBootstrapEngine`] for [`FftEngine`] that
+/// operates on 32 bit integers.
+impl
+    LweCiphertextVectorDiscardingBootstrapEngine<
+        FftFourierLweBootstrapKey32,
+        GlweCiphertextVector32,
+        LweCiphertextVector32,
+        LweCiphertextVector32,
+    > for FftEngine
+{
+    /// # Example
+    /// ```
+    /// use concrete_core::prelude::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, LweCiphertextCount,
+    ///     LweDimension, PolynomialSize, Variance, *,
+    /// };
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // Here a hard-set encoding is applied (shift by 20 bits)
+    /// let input = vec![3_u32 << 20; 3];
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let (lwe_dim, lwe_dim_output, glwe_dim, poly_size) = (
+    ///     LweDimension(4),
+    ///     LweDimension(1024),
+    ///     GlweDimension(1),
+    ///     PolynomialSize(1024),
+    /// );
+    /// let (dec_lc, dec_bl) = (DecompositionLevelCount(3), DecompositionBaseLog(5));
+    /// // A constant function is applied during the bootstrap
+    /// let lut = vec![8_u32 << 20; poly_size.0 * 3];
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut default_engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let mut fft_engine = FftEngine::new(())?;
+    /// let lwe_sk: LweSecretKey32 = default_engine.generate_new_lwe_secret_key(lwe_dim)?;
+    /// let glwe_sk: GlweSecretKey32 =
+    ///     default_engine.generate_new_glwe_secret_key(glwe_dim, poly_size)?;
+    /// let bsk: LweBootstrapKey32 =
+    ///     default_engine.generate_new_lwe_bootstrap_key(&lwe_sk, &glwe_sk, dec_bl, dec_lc, noise)?;
+    /// let bsk: FftFourierLweBootstrapKey32 = fft_engine.convert_lwe_bootstrap_key(&bsk)?;
+    /// let lwe_sk_output: LweSecretKey32 =
+    ///     default_engine.generate_new_lwe_secret_key(lwe_dim_output)?;
+    /// let plaintext_vector = default_engine.create_plaintext_vector_from(&input)?;
+    /// let acc_plaintext_vector = default_engine.create_plaintext_vector_from(&lut)?;
+    /// let acc = default_engine.trivially_encrypt_glwe_ciphertext_vector(
+    ///     glwe_dim.to_glwe_size(),
+    ///     GlweCiphertextCount(3),
+    ///     &acc_plaintext_vector,
+    /// )?;
+    /// let input = default_engine.encrypt_lwe_ciphertext_vector(&lwe_sk, &plaintext_vector, noise)?;
+    /// let mut output = default_engine.zero_encrypt_lwe_ciphertext_vector(
+    ///     &lwe_sk_output,
+    ///     noise,
+    ///     LweCiphertextCount(3),
+    /// )?;
+    ///
+    /// fft_engine.discard_bootstrap_lwe_ciphertext_vector(&mut output, &input, &acc, &bsk)?;
+    /// #
+    /// assert_eq!(output.lwe_dimension(), lwe_dim_output);
+    /// assert_eq!(output.lwe_ciphertext_count(), LweCiphertextCount(3));
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn discard_bootstrap_lwe_ciphertext_vector(
+        &mut self,
+        output: &mut LweCiphertextVector32,
+        input: &LweCiphertextVector32,
+        acc: &GlweCiphertextVector32,
+        bsk: &FftFourierLweBootstrapKey32,
+    ) -> Result<(), LweCiphertextVectorDiscardingBootstrapError<Self::EngineError>> {
+        FftError::perform_fft_checks(acc.polynomial_size())?;
+        LweCiphertextVectorDiscardingBootstrapError::perform_generic_checks(
+            output, input, acc, bsk,
+        )?;
+        unsafe { self.discard_bootstrap_lwe_ciphertext_vector_unchecked(output, input, acc, bsk) };
+        Ok(())
+    }
+
+    unsafe fn discard_bootstrap_lwe_ciphertext_vector_unchecked(
+        &mut self,
+        output: &mut LweCiphertextVector32,
+        input: &LweCiphertextVector32,
+        acc: &GlweCiphertextVector32,
+        bsk: &FftFourierLweBootstrapKey32,
+    ) {
+        let fft = Fft::new(acc.0.polynomial_size());
+        let fft = fft.as_view();
+        self.resize(
+            bootstrap_scratch::<u32>(acc.0.glwe_size(), acc.0.polynomial_size(), fft)
+                .unwrap()
+                .unaligned_bytes_required(),
+        );
+        for ((mut o, c), a) in output
+            .0
+            .ciphertext_iter_mut()
+            .zip(input.0.ciphertext_iter())
+            .zip(acc.0.ciphertext_iter())
+        {
+            bsk.0.as_view().bootstrap(
+                o.tensor.as_mut_slice(),
+                c.tensor.as_slice(),
+                a,
+                fft,
+                self.stack(),
+            );
+        }
+    }
+}
+
+/// # Description
+///
+/// Implementation of [`LweCiphertextVectorDiscardingBootstrapEngine`] for [`FftEngine`] that
+/// operates on 64 bit integers.
+impl
+    LweCiphertextVectorDiscardingBootstrapEngine<
+        FftFourierLweBootstrapKey64,
+        GlweCiphertextVector64,
+        LweCiphertextVector64,
+        LweCiphertextVector64,
+    > for FftEngine
+{
+    /// # Example
+    /// ```
+    /// use concrete_core::prelude::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, LweCiphertextCount,
+    ///     LweDimension, PolynomialSize, Variance, *,
+    /// };
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // Here a hard-set encoding is applied (shift by 50 bits)
+    /// let input = vec![3_u64 << 50; 3];
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let (lwe_dim, lwe_dim_output, glwe_dim, poly_size) = (
+    ///     LweDimension(4),
+    ///     LweDimension(1024),
+    ///     GlweDimension(1),
+    ///     PolynomialSize(1024),
+    /// );
+    /// let (dec_lc, dec_bl) = (DecompositionLevelCount(3), DecompositionBaseLog(5));
+    /// // A constant function is applied during the bootstrap
+    /// let lut = vec![8_u64 << 50; poly_size.0 * 3];
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut default_engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let mut fft_engine = FftEngine::new(())?;
+    /// let lwe_sk: LweSecretKey64 = default_engine.generate_new_lwe_secret_key(lwe_dim)?;
+    /// let glwe_sk: GlweSecretKey64 =
+    ///     default_engine.generate_new_glwe_secret_key(glwe_dim, poly_size)?;
+    /// let bsk: LweBootstrapKey64 =
+    ///     default_engine.generate_new_lwe_bootstrap_key(&lwe_sk, &glwe_sk, dec_bl, dec_lc, noise)?;
+    /// let bsk: FftFourierLweBootstrapKey64 = fft_engine.convert_lwe_bootstrap_key(&bsk)?;
+    /// let lwe_sk_output: LweSecretKey64 =
+    ///     default_engine.generate_new_lwe_secret_key(lwe_dim_output)?;
+    /// let plaintext_vector = default_engine.create_plaintext_vector_from(&input)?;
+    /// let acc_plaintext_vector = default_engine.create_plaintext_vector_from(&lut)?;
+    /// let acc = default_engine.trivially_encrypt_glwe_ciphertext_vector(
+    ///     glwe_dim.to_glwe_size(),
+    ///     GlweCiphertextCount(3),
+    ///     &acc_plaintext_vector,
+    /// )?;
+    /// let input = default_engine.encrypt_lwe_ciphertext_vector(&lwe_sk, &plaintext_vector, noise)?;
+    /// let mut output = default_engine.zero_encrypt_lwe_ciphertext_vector(
+    ///     &lwe_sk_output,
+    ///     noise,
+    ///     LweCiphertextCount(3),
+    /// )?;
+    ///
+    /// fft_engine.discard_bootstrap_lwe_ciphertext_vector(&mut output, &input, &acc, &bsk)?;
+    /// #
+    /// assert_eq!(output.lwe_dimension(), lwe_dim_output);
+    /// assert_eq!(output.lwe_ciphertext_count(), LweCiphertextCount(3));
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn discard_bootstrap_lwe_ciphertext_vector(
+        &mut self,
+        output: &mut LweCiphertextVector64,
+        input: &LweCiphertextVector64,
+        acc: &GlweCiphertextVector64,
+        bsk: &FftFourierLweBootstrapKey64,
+    ) -> Result<(), LweCiphertextVectorDiscardingBootstrapError<Self::EngineError>> {
+        FftError::perform_fft_checks(acc.polynomial_size())?;
+        LweCiphertextVectorDiscardingBootstrapError::perform_generic_checks(
+            output, input, acc, bsk,
+        )?;
+        unsafe { self.discard_bootstrap_lwe_ciphertext_vector_unchecked(output, input, acc, bsk) };
+        Ok(())
+    }
+
+    unsafe fn discard_bootstrap_lwe_ciphertext_vector_unchecked(
+        &mut self,
+        output: &mut LweCiphertextVector64,
+        input: &LweCiphertextVector64,
+        acc: &GlweCiphertextVector64,
+        bsk: &FftFourierLweBootstrapKey64,
+    ) {
+        let fft = Fft::new(acc.0.polynomial_size());
+        let fft = fft.as_view();
+        self.resize(
+            bootstrap_scratch::<u64>(acc.0.glwe_size(), acc.0.polynomial_size(), fft)
+                .unwrap()
+                .unaligned_bytes_required(),
+        );
+        for ((mut o, c), a) in output
+            .0
+            .ciphertext_iter_mut()
+            .zip(input.0.ciphertext_iter())
+            .zip(acc.0.ciphertext_iter())
+        {
+            bsk.0.as_view().bootstrap(
+                o.tensor.as_mut_slice(),
+                c.tensor.as_slice(),
+                a,
+                fft,
+                self.stack(),
+            );
+        }
+    }
+}
+
+/// # Description
+///
+/// Implementation of [`LweCiphertextVectorDiscardingBootstrapEngine`] for [`FftEngine`] that
+/// operates on views containing 64 bit integers.
+impl
+    LweCiphertextVectorDiscardingBootstrapEngine<
+        FftFourierLweBootstrapKey64,
+        GlweCiphertextVectorView64<'_>,
+        LweCiphertextVectorView64<'_>,
+        LweCiphertextVectorMutView64<'_>,
+    > for FftEngine
+{
+    /// # Example
+    /// ```
+    /// use concrete_core::prelude::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, LweCiphertextCount,
+    ///     LweDimension, PolynomialSize, Variance, *,
+    /// };
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // Here a hard-set encoding is applied (shift by 50 bits)
+    /// let input = vec![3_u64 << 50; 3];
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let (lwe_dim, lwe_dim_output, glwe_dim, poly_size) = (
+    ///     LweDimension(4),
+    ///     LweDimension(1024),
+    ///     GlweDimension(1),
+    ///     PolynomialSize(1024),
+    /// );
+    /// let (dec_lc, dec_bl) = (DecompositionLevelCount(3), DecompositionBaseLog(5));
+    /// // A constant function is applied during the bootstrap
+    /// let lut = vec![8_u64 << 50; poly_size.0 * 3];
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut default_engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let mut fft_engine = FftEngine::new(())?;
+    /// let lwe_sk: LweSecretKey64 = default_engine.generate_new_lwe_secret_key(lwe_dim)?;
+    /// let glwe_sk: GlweSecretKey64 =
+    ///     default_engine.generate_new_glwe_secret_key(glwe_dim, poly_size)?;
+    /// let bsk: LweBootstrapKey64 =
+    ///     default_engine.generate_new_lwe_bootstrap_key(&lwe_sk, &glwe_sk, dec_bl, dec_lc, noise)?;
+    /// let bsk: FftFourierLweBootstrapKey64 = fft_engine.convert_lwe_bootstrap_key(&bsk)?;
+    /// let lwe_sk_output: LweSecretKey64 =
+    ///     default_engine.generate_new_lwe_secret_key(lwe_dim_output)?;
+    /// let plaintext_vector = default_engine.create_plaintext_vector_from(&input)?;
+    /// let acc_plaintext_vector = default_engine.create_plaintext_vector_from(&lut)?;
+    /// let acc = default_engine.trivially_encrypt_glwe_ciphertext_vector(
+    ///     glwe_dim.to_glwe_size(),
+    ///     GlweCiphertextCount(3),
+    ///     &acc_plaintext_vector,
+    /// )?;
+    /// let input = default_engine.encrypt_lwe_ciphertext_vector(&lwe_sk, &plaintext_vector, noise)?;
+    /// let raw_input = default_engine.consume_retrieve_lwe_ciphertext_vector(input)?;
+    /// let input: LweCiphertextVectorView64 =
+    ///     default_engine.create_lwe_ciphertext_vector_from(&raw_input[..], lwe_dim.to_lwe_size())?;
+    /// let raw_acc = default_engine.consume_retrieve_glwe_ciphertext_vector(acc)?;
+    /// let acc: GlweCiphertextVectorView64 =
+    ///     default_engine.create_glwe_ciphertext_vector_from(&raw_acc[..], glwe_dim, poly_size)?;
+    /// let mut output_container = vec![0_u64; lwe_dim_output.to_lwe_size().0 * 3];
+    /// let mut output: LweCiphertextVectorMutView64 = default_engine
+    ///     .create_lwe_ciphertext_vector_from(
+    ///         &mut output_container[..],
+    ///         lwe_dim_output.to_lwe_size(),
+    ///     )?;
+    ///
+    /// fft_engine.discard_bootstrap_lwe_ciphertext_vector(&mut output, &input, &acc, &bsk)?;
+    /// #
+    /// assert_eq!(output.lwe_dimension(), lwe_dim_output);
+    /// assert_eq!(output.lwe_ciphertext_count(), LweCiphertextCount(3));
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn discard_bootstrap_lwe_ciphertext_vector(
+        &mut self,
+        output: &mut LweCiphertextVectorMutView64,
+        input: &LweCiphertextVectorView64,
+        acc: &GlweCiphertextVectorView64,
+        bsk: &FftFourierLweBootstrapKey64,
+    ) -> Result<(), LweCiphertextVectorDiscardingBootstrapError<Self::EngineError>> {
+        FftError::perform_fft_checks(acc.polynomial_size())?;
+        LweCiphertextVectorDiscardingBootstrapError::perform_generic_checks(
+            output, input, acc, bsk,
+        )?;
+        unsafe { self.discard_bootstrap_lwe_ciphertext_vector_unchecked(output, input, acc, bsk) };
+        Ok(())
+    }
+
+    unsafe fn discard_bootstrap_lwe_ciphertext_vector_unchecked(
+        &mut self,
+        output: &mut LweCiphertextVectorMutView64,
+        input: &LweCiphertextVectorView64,
+        acc: &GlweCiphertextVectorView64,
+        bsk: &FftFourierLweBootstrapKey64,
+    ) {
+        let fft = Fft::new(acc.0.polynomial_size());
+        let fft = fft.as_view();
+        self.resize(
+            bootstrap_scratch::<u64>(acc.0.glwe_size(), acc.0.polynomial_size(), fft)
+                .unwrap()
+                .unaligned_bytes_required(),
+        );
+        for ((mut o, c), a) in output
+            .0
+            .ciphertext_iter_mut()
+            .zip(input.0.ciphertext_iter())
+            .zip(acc.0.ciphertext_iter())
+        {
+            bsk.0.as_view().bootstrap(
+                o.tensor.as_mut_slice(),
+                c.tensor.as_slice(),
+                a,
+                fft,
+                self.stack(),
+            );
+        }
+    }
+}
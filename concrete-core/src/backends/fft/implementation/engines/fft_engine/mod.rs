@@ -10,6 +10,7 @@ use core::mem::MaybeUninit;
 #[non_exhaustive]
 pub enum FftError {
     UnsupportedPolynomialSize,
+    ScratchSizeOverflow,
 }
 
 impl core::fmt::Display for FftError {
@@ -19,6 +20,9 @@ impl core::fmt::Display for FftError {
                 "The Concrete-FFT backend only supports polynomials of sizes that are powers of two \
                     and greater than or equal to 32.",
             ),
+            FftError::ScratchSizeOverflow => f.write_str(
+                "The scratch memory needed by the requested operation does not fit in memory.",
+            ),
         }
     }
 }
@@ -36,13 +40,52 @@ impl FftError {
 }
 
 /// The main engine exposed by the Concrete-FFT backend.
+///
+/// The engine owns a scratch memory buffer, which is grown on demand by the operations needing
+/// temporary memory, and is never shrunk. The buffer can be provided by the caller with
+/// [`FftEngine::with_memory`], using the `*_scratch_size` functions to compute its size, so that
+/// no allocation happens during the operations.
 pub struct FftEngine {
     memory: Vec<MaybeUninit<u8>>,
 }
 
 impl FftEngine {
+    /// Creates an engine using `memory` as scratch memory.
+    ///
+    /// # Example
+    /// ```
+    /// use concrete_core::prelude::{GlweDimension, PolynomialSize, *};
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// let size = FftEngine::lwe_ciphertext_bootstrap_scratch_size::<u64>(
+    ///     GlweDimension(1),
+    ///     PolynomialSize(1024),
+    /// )?;
+    /// let mut fft_engine = FftEngine::with_memory(Vec::with_capacity(size));
+    /// // The engine can now bootstrap with these parameters without allocating.
+    /// assert!(fft_engine.memory_capacity() >= size);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn with_memory(memory: Vec<MaybeUninit<u8>>) -> FftEngine {
+        FftEngine { memory }
+    }
+
+    /// Returns the size in bytes of the scratch memory the engine can use without allocating.
+    pub fn memory_capacity(&self) -> usize {
+        self.memory.capacity()
+    }
+
+    /// Takes the scratch memory back from the engine, leaving it with an empty buffer.
+    pub fn take_memory(&mut self) -> Vec<MaybeUninit<u8>> {
+        std::mem::take(&mut self.memory)
+    }
+
     pub(crate) fn resize(&mut self, capacity: usize) {
-        self.memory.resize_with(capacity, MaybeUninit::uninit);
+        if self.memory.len() < capacity {
+            self.memory.resize_with(capacity, MaybeUninit::uninit);
+        }
     }
 
     pub(crate) fn stack(&mut self) -> DynStack<'_> {
@@ -67,5 +110,14 @@ mod glwe_ciphertexts_ggsw_ciphertext_fusing_cmux;
 mod lwe_bootstrap_key_conversion;
 mod lwe_ciphertext_discarding_bit_extraction;
 mod lwe_ciphertext_discarding_bootstrap;
+mod lwe_ciphertext_discarding_bootstrap_keyswitch;
 mod lwe_ciphertext_discarding_circuit_bootstrap_boolean;
+mod lwe_ciphertext_discarding_keyswitch_bootstrap;
+mod lwe_ciphertext_vector_discarding_bootstrap;
+mod lwe_ciphertext_vector_discarding_bootstrap_keyswitch;
 mod lwe_ciphertext_vector_discarding_circuit_bootstrap_boolean_vertical_packing;
+mod lwe_ciphertext_vector_discarding_keyswitch_bootstrap;
+mod scratch_size;
+
+#[cfg(test)]
+pub(crate) mod tests;
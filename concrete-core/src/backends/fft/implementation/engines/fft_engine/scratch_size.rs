@@ -0,0 +1,222 @@
+use super::{FftEngine, FftError};
+use crate::backends::fft::private::crypto::bootstrap::{
+    bootstrap_keyswitch_scratch, bootstrap_scratch, keyswitch_bootstrap_scratch,
+};
+use crate::backends::fft::private::crypto::wop_pbs::{
+    circuit_bootstrap_boolean_vertical_packing_scratch, extract_bits_scratch,
+};
+use crate::backends::fft::private::math::fft::Fft;
+use crate::commons::math::torus::UnsignedTorus;
+use crate::prelude::{
+    CiphertextCount, DecompositionLevelCount, GlweDimension, LweCiphertextCount, LweDimension,
+    PlaintextCount, PolynomialCount, PolynomialSize,
+};
+use dyn_stack::SizeOverflow;
+
+impl From<SizeOverflow> for FftError {
+    fn from(_: SizeOverflow) -> Self {
+        FftError::ScratchSizeOverflow
+    }
+}
+
+/// Functions returning the size in bytes of the scratch memory needed by the operations of the
+/// [`FftEngine`], for a given parameter set and integer type.
+///
+/// An engine created with [`FftEngine::with_memory`] from a buffer of at least this size performs
+/// the matching operation without allocating.
+impl FftEngine {
+    /// Returns the scratch memory size needed to bootstrap an LWE ciphertext (or each ciphertext of
+    /// an LWE ciphertext vector).
+    ///
+    /// # Example
+    /// ```
+    /// use concrete_core::prelude::{GlweDimension, PolynomialSize, *};
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// let size = FftEngine::lwe_ciphertext_bootstrap_scratch_size::<u32>(
+    ///     GlweDimension(1),
+    ///     PolynomialSize(1024),
+    /// )?;
+    /// assert!(size > 0);
+    ///
+    /// // Only the polynomial sizes supported by the engine are accepted
+    /// assert!(FftEngine::lwe_ciphertext_bootstrap_scratch_size::<u32>(
+    ///     GlweDimension(1),
+    ///     PolynomialSize(1000),
+    /// )
+    /// .is_err());
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn lwe_ciphertext_bootstrap_scratch_size<Scalar: UnsignedTorus>(
+        glwe_dimension: GlweDimension,
+        polynomial_size: PolynomialSize,
+    ) -> Result<usize, FftError> {
+        FftError::perform_fft_checks(polynomial_size)?;
+        let fft = Fft::new(polynomial_size);
+        let req = bootstrap_scratch::<Scalar>(
+            glwe_dimension.to_glwe_size(),
+            polynomial_size,
+            fft.as_view(),
+        )?;
+        Ok(req.unaligned_bytes_required())
+    }
+
+    /// Returns the scratch memory size needed to keyswitch then bootstrap an LWE ciphertext (or
+    /// each ciphertext of an LWE ciphertext vector), with a keyswitch key whose output LWE
+    /// dimension is `keyswitch_output_lwe_dimension`.
+    pub fn lwe_ciphertext_keyswitch_bootstrap_scratch_size<Scalar: UnsignedTorus>(
+        keyswitch_output_lwe_dimension: LweDimension,
+        glwe_dimension: GlweDimension,
+        polynomial_size: PolynomialSize,
+    ) -> Result<usize, FftError> {
+        FftError::perform_fft_checks(polynomial_size)?;
+        let fft = Fft::new(polynomial_size);
+        let req = keyswitch_bootstrap_scratch::<Scalar>(
+            keyswitch_output_lwe_dimension.to_lwe_size(),
+            glwe_dimension.to_glwe_size(),
+            polynomial_size,
+            fft.as_view(),
+        )?;
+        Ok(req.unaligned_bytes_required())
+    }
+
+    /// Returns the scratch memory size needed to bootstrap then keyswitch an LWE ciphertext (or
+    /// each ciphertext of an LWE ciphertext vector).
+    pub fn lwe_ciphertext_bootstrap_keyswitch_scratch_size<Scalar: UnsignedTorus>(
+        glwe_dimension: GlweDimension,
+        polynomial_size: PolynomialSize,
+    ) -> Result<usize, FftError> {
+        FftError::perform_fft_checks(polynomial_size)?;
+        let fft = Fft::new(polynomial_size);
+        let req = bootstrap_keyswitch_scratch::<Scalar>(
+            glwe_dimension.to_glwe_size(),
+            polynomial_size,
+            fft.as_view(),
+        )?;
+        Ok(req.unaligned_bytes_required())
+    }
+
+    /// Returns the scratch memory size needed to extract bits from an LWE ciphertext of dimension
+    /// `input_lwe_dimension`, with a keyswitch key whose output LWE dimension is
+    /// `keyswitch_output_lwe_dimension`.
+    pub fn lwe_ciphertext_bit_extraction_scratch_size<Scalar: UnsignedTorus>(
+        input_lwe_dimension: LweDimension,
+        keyswitch_output_lwe_dimension: LweDimension,
+        glwe_dimension: GlweDimension,
+        polynomial_size: PolynomialSize,
+    ) -> Result<usize, FftError> {
+        FftError::perform_fft_checks(polynomial_size)?;
+        let fft = Fft::new(polynomial_size);
+        let req = extract_bits_scratch::<Scalar>(
+            input_lwe_dimension,
+            keyswitch_output_lwe_dimension,
+            glwe_dimension.to_glwe_size(),
+            polynomial_size,
+            fft.as_view(),
+        )?;
+        Ok(req.unaligned_bytes_required())
+    }
+
+    /// Returns the scratch memory size needed to perform a circuit bootstrap followed by a
+    /// vertical packing on `input_count` ciphertexts of dimension `input_lwe_dimension`, producing
+    /// `output_count` ciphertexts from look-up tables containing `luts_plaintext_count` plaintexts
+    /// overall.
+    #[allow(clippy::too_many_arguments)]
+    pub fn lwe_ciphertext_vector_circuit_bootstrap_boolean_vertical_packing_scratch_size<
+        Scalar: UnsignedTorus,
+    >(
+        input_count: LweCiphertextCount,
+        output_count: LweCiphertextCount,
+        input_lwe_dimension: LweDimension,
+        luts_plaintext_count: PlaintextCount,
+        glwe_dimension: GlweDimension,
+        polynomial_size: PolynomialSize,
+        cbs_level_count: DecompositionLevelCount,
+    ) -> Result<usize, FftError> {
+        FftError::perform_fft_checks(polynomial_size)?;
+        let fft = Fft::new(polynomial_size);
+        let req = circuit_bootstrap_boolean_vertical_packing_scratch::<Scalar>(
+            CiphertextCount(input_count.0),
+            CiphertextCount(output_count.0),
+            input_lwe_dimension.to_lwe_size(),
+            PolynomialCount(luts_plaintext_count.0 / polynomial_size.0),
+            LweDimension(glwe_dimension.0 * polynomial_size.0).to_lwe_size(),
+            polynomial_size,
+            glwe_dimension.to_glwe_size(),
+            cbs_level_count,
+            fft.as_view(),
+        )?;
+        Ok(req.unaligned_bytes_required())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::prelude::*;
+    use std::error::Error;
+
+    #[test]
+    fn test_bootstrap_reuses_provided_memory() -> Result<(), Box<dyn Error>> {
+        // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+        let lwe_dimension = LweDimension(10);
+        let glwe_dimension = GlweDimension(1);
+        let polynomial_size = PolynomialSize(512);
+        let decomposition_base_log = DecompositionBaseLog(3);
+        let decomposition_level_count = DecompositionLevelCount(3);
+        let noise = Variance(2_f64.powf(-25.));
+
+        // Unix seeder must be given a secret input.
+        // Here we just give it 0, which is totally unsafe.
+        const UNSAFE_SECRET: u128 = 0;
+        let mut default_engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+        let lwe_sk: LweSecretKey64 = default_engine.generate_new_lwe_secret_key(lwe_dimension)?;
+        let glwe_sk: GlweSecretKey64 =
+            default_engine.generate_new_glwe_secret_key(glwe_dimension, polynomial_size)?;
+        let bsk: LweBootstrapKey64 = default_engine.generate_new_lwe_bootstrap_key(
+            &lwe_sk,
+            &glwe_sk,
+            decomposition_base_log,
+            decomposition_level_count,
+            noise,
+        )?;
+        let fourier_bsk: FftFourierLweBootstrapKey64 =
+            FftEngine::new(())?.convert_lwe_bootstrap_key(&bsk)?;
+
+        let plaintext = default_engine.create_plaintext_from(&(3_u64 << 50))?;
+        let input = default_engine.encrypt_lwe_ciphertext(&lwe_sk, &plaintext, noise)?;
+        let accumulator_plaintexts =
+            default_engine.create_plaintext_vector_from(&vec![0_u64; polynomial_size.0])?;
+        let accumulator = default_engine.trivially_encrypt_glwe_ciphertext(
+            glwe_dimension.to_glwe_size(),
+            &accumulator_plaintexts,
+        )?;
+        let mut output = default_engine.trivially_encrypt_lwe_ciphertext(
+            LweDimension(glwe_dimension.0 * polynomial_size.0).to_lwe_size(),
+            &plaintext,
+        )?;
+
+        let size = FftEngine::lwe_ciphertext_bootstrap_scratch_size::<u64>(
+            glwe_dimension,
+            polynomial_size,
+        )?;
+        let mut fft_engine = FftEngine::with_memory(Vec::with_capacity(size));
+        let memory_ptr = fft_engine.memory.as_ptr();
+        let memory_capacity = fft_engine.memory.capacity();
+
+        for _ in 0..2 {
+            fft_engine.discard_bootstrap_lwe_ciphertext(
+                &mut output,
+                &input,
+                &accumulator,
+                &fourier_bsk,
+            )?;
+            // The scratch memory was used, and was neither reallocated nor grown.
+            assert_eq!(fft_engine.memory.len(), size);
+            assert_eq!(fft_engine.memory.as_ptr(), memory_ptr);
+            assert_eq!(fft_engine.memory.capacity(), memory_capacity);
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,435 @@
+use crate::prelude::*;
+
+// Unix seeder must be given a secret input.
+// Here we just give it 0, which is totally unsafe.
+pub(crate) const UNSAFE_SECRET: u128 = 0;
+
+// The messages are encoded on 3 bits, with a padding bit.
+pub(crate) const MESSAGE_MODULUS: usize = 8;
+
+// 2^-50, small enough for the keyswitch noise to stay below the lookup-table boxes.
+pub(crate) const NOISE: Variance = Variance(8.881_784_197_001_252e-16);
+
+macro_rules! test_fused_keyswitch_bootstrap {
+    ($module:ident, $Scalar:ty, [$($Alias:ident = $Entity:ident),* $(,)?] $(,)?) => {
+        pub(crate) mod $module {
+            use super::*;
+
+            $(pub(crate) type $Alias = $Entity;)*
+
+            pub(crate) struct Keys {
+                pub(crate) small_lwe_sk: LweSecretKey,
+                pub(crate) big_lwe_sk: LweSecretKey,
+                pub(crate) ksk: LweKeyswitchKey,
+                pub(crate) bsk: FftFourierLweBootstrapKey,
+            }
+
+            pub(crate) fn generate_keys(default_engine: &mut DefaultEngine) -> Keys {
+                // DISCLAIMER: the parameters used here are only for test purpose, and are not
+                // secure.
+                let (small_lwe_dim, glwe_dim, poly_size) =
+                    (LweDimension(4), GlweDimension(1), PolynomialSize(1024));
+                let (bsk_dec_lc, bsk_dec_bl) =
+                    (DecompositionLevelCount(3), DecompositionBaseLog(5));
+                let (ksk_dec_lc, ksk_dec_bl) =
+                    (DecompositionLevelCount(5), DecompositionBaseLog(3));
+
+                let small_lwe_sk: LweSecretKey =
+                    default_engine.generate_new_lwe_secret_key(small_lwe_dim).unwrap();
+                let glwe_sk: GlweSecretKey = default_engine
+                    .generate_new_glwe_secret_key(glwe_dim, poly_size)
+                    .unwrap();
+                let big_lwe_sk: LweSecretKey = default_engine
+                    .transform_glwe_secret_key_to_lwe_secret_key(glwe_sk.clone())
+                    .unwrap();
+                let bsk: LweBootstrapKey = default_engine
+                    .generate_new_lwe_bootstrap_key(
+                        &small_lwe_sk,
+                        &glwe_sk,
+                        bsk_dec_bl,
+                        bsk_dec_lc,
+                        NOISE,
+                    )
+                    .unwrap();
+                let bsk: FftFourierLweBootstrapKey = FftEngine::new(())
+                    .unwrap()
+                    .convert_lwe_bootstrap_key(&bsk)
+                    .unwrap();
+                // Both fused operations keyswitch from the bootstrap output key to its input key.
+                let ksk: LweKeyswitchKey = default_engine
+                    .generate_new_lwe_keyswitch_key(
+                        &big_lwe_sk,
+                        &small_lwe_sk,
+                        ksk_dec_lc,
+                        ksk_dec_bl,
+                        NOISE,
+                    )
+                    .unwrap();
+                Keys {
+                    small_lwe_sk,
+                    big_lwe_sk,
+                    ksk,
+                    bsk,
+                }
+            }
+
+            pub(crate) fn delta() -> $Scalar {
+                ((1 as $Scalar) << (<$Scalar>::BITS - 1)) / MESSAGE_MODULUS as $Scalar
+            }
+
+            // Returns the lookup table of the identity function.
+            pub(crate) fn identity_lut(polynomial_size: PolynomialSize) -> Vec<$Scalar> {
+                let box_size = polynomial_size.0 / MESSAGE_MODULUS;
+                let mut lut = vec![0 as $Scalar; polynomial_size.0];
+                for (message, lut_box) in lut.chunks_exact_mut(box_size).enumerate() {
+                    lut_box.fill(message as $Scalar * delta());
+                }
+                let half_box_size = box_size / 2;
+                for coefficient in lut[0..half_box_size].iter_mut() {
+                    *coefficient = coefficient.wrapping_neg();
+                }
+                lut.rotate_left(half_box_size);
+                lut
+            }
+
+            pub(crate) fn decode(plaintext: $Scalar) -> $Scalar {
+                (plaintext.wrapping_add(delta() / 2) / delta()) % MESSAGE_MODULUS as $Scalar
+            }
+
+            #[test]
+            fn keyswitch_bootstrap() {
+                let mut default_engine =
+                    DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET))).unwrap();
+                let mut fft_engine = FftEngine::new(()).unwrap();
+                let Keys {
+                    small_lwe_sk,
+                    big_lwe_sk,
+                    ksk,
+                    bsk,
+                } = generate_keys(&mut default_engine);
+                let lut = identity_lut(bsk.polynomial_size());
+                let count = MESSAGE_MODULUS;
+                let messages: Vec<$Scalar> = (0..count as $Scalar).collect();
+
+                let lut_plaintext: PlaintextVector = default_engine
+                    .create_plaintext_vector_from(&lut)
+                    .unwrap();
+                let acc: GlweCiphertext = default_engine
+                    .trivially_encrypt_glwe_ciphertext(
+                        bsk.glwe_dimension().to_glwe_size(),
+                        &lut_plaintext,
+                    )
+                    .unwrap();
+
+                for &message in messages.iter() {
+                    let plaintext: Plaintext =
+                        default_engine.create_plaintext_from(&(message * delta())).unwrap();
+                    let input: LweCiphertext = default_engine
+                        .encrypt_lwe_ciphertext(&big_lwe_sk, &plaintext, NOISE)
+                        .unwrap();
+
+                    let mut fused: LweCiphertext = default_engine
+                        .zero_encrypt_lwe_ciphertext(&big_lwe_sk, NOISE)
+                        .unwrap();
+                    fft_engine
+                        .discard_keyswitch_bootstrap_lwe_ciphertext(
+                            &mut fused,
+                            &input,
+                            &acc,
+                            &ksk,
+                            &bsk,
+                        )
+                        .unwrap();
+
+                    let mut keyswitched: LweCiphertext = default_engine
+                        .zero_encrypt_lwe_ciphertext(&small_lwe_sk, NOISE)
+                        .unwrap();
+                    default_engine
+                        .discard_keyswitch_lwe_ciphertext(&mut keyswitched, &input, &ksk)
+                        .unwrap();
+                    let mut separate: LweCiphertext = default_engine
+                        .zero_encrypt_lwe_ciphertext(&big_lwe_sk, NOISE)
+                        .unwrap();
+                    fft_engine
+                        .discard_bootstrap_lwe_ciphertext(&mut separate, &keyswitched, &acc, &bsk)
+                        .unwrap();
+
+                    let fused: Plaintext = default_engine
+                        .decrypt_lwe_ciphertext(&big_lwe_sk, &fused)
+                        .unwrap();
+                    let separate: Plaintext = default_engine
+                        .decrypt_lwe_ciphertext(&big_lwe_sk, &separate)
+                        .unwrap();
+                    let fused = decode(default_engine.retrieve_plaintext(&fused).unwrap());
+                    let separate =
+                        decode(default_engine.retrieve_plaintext(&separate).unwrap());
+                    assert_eq!(fused, separate);
+                    assert_eq!(fused, message);
+                }
+
+                // The vector variant applies one accumulator per ciphertext.
+                let plaintext_vector: PlaintextVector = default_engine
+                    .create_plaintext_vector_from(
+                        &messages.iter().map(|m| m * delta()).collect::<Vec<_>>(),
+                    )
+                    .unwrap();
+                let input: LweCiphertextVector = default_engine
+                    .encrypt_lwe_ciphertext_vector(&big_lwe_sk, &plaintext_vector, NOISE)
+                    .unwrap();
+                let lut_plaintext_vector: PlaintextVector = default_engine
+                    .create_plaintext_vector_from(&lut.repeat(count))
+                    .unwrap();
+                let acc: GlweCiphertextVector = default_engine
+                    .trivially_encrypt_glwe_ciphertext_vector(
+                        bsk.glwe_dimension().to_glwe_size(),
+                        GlweCiphertextCount(count),
+                        &lut_plaintext_vector,
+                    )
+                    .unwrap();
+
+                let mut fused: LweCiphertextVector = default_engine
+                    .zero_encrypt_lwe_ciphertext_vector(
+                        &big_lwe_sk,
+                        NOISE,
+                        LweCiphertextCount(count),
+                    )
+                    .unwrap();
+                fft_engine
+                    .discard_keyswitch_bootstrap_lwe_ciphertext_vector(
+                        &mut fused, &input, &acc, &ksk, &bsk,
+                    )
+                    .unwrap();
+
+                let mut keyswitched: LweCiphertextVector = default_engine
+                    .zero_encrypt_lwe_ciphertext_vector(
+                        &small_lwe_sk,
+                        NOISE,
+                        LweCiphertextCount(count),
+                    )
+                    .unwrap();
+                default_engine
+                    .discard_keyswitch_lwe_ciphertext_vector(&mut keyswitched, &input, &ksk)
+                    .unwrap();
+                let mut separate: LweCiphertextVector = default_engine
+                    .zero_encrypt_lwe_ciphertext_vector(
+                        &big_lwe_sk,
+                        NOISE,
+                        LweCiphertextCount(count),
+                    )
+                    .unwrap();
+                fft_engine
+                    .discard_bootstrap_lwe_ciphertext_vector(
+                        &mut separate,
+                        &keyswitched,
+                        &acc,
+                        &bsk,
+                    )
+                    .unwrap();
+
+                let fused: PlaintextVector = default_engine
+                    .decrypt_lwe_ciphertext_vector(&big_lwe_sk, &fused)
+                    .unwrap();
+                let separate: PlaintextVector = default_engine
+                    .decrypt_lwe_ciphertext_vector(&big_lwe_sk, &separate)
+                    .unwrap();
+                let fused: Vec<$Scalar> = default_engine
+                    .retrieve_plaintext_vector(&fused)
+                    .unwrap()
+                    .into_iter()
+                    .map(|p| decode(p))
+                    .collect();
+                let separate: Vec<$Scalar> = default_engine
+                    .retrieve_plaintext_vector(&separate)
+                    .unwrap()
+                    .into_iter()
+                    .map(|p| decode(p))
+                    .collect();
+                assert_eq!(fused, separate);
+                assert_eq!(fused, messages);
+            }
+
+            #[test]
+            fn bootstrap_keyswitch() {
+                let mut default_engine =
+                    DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET))).unwrap();
+                let mut fft_engine = FftEngine::new(()).unwrap();
+                let Keys {
+                    small_lwe_sk,
+                    big_lwe_sk,
+                    ksk,
+                    bsk,
+                } = generate_keys(&mut default_engine);
+                let lut = identity_lut(bsk.polynomial_size());
+                let count = MESSAGE_MODULUS;
+                let messages: Vec<$Scalar> = (0..count as $Scalar).collect();
+
+                let lut_plaintext: PlaintextVector = default_engine
+                    .create_plaintext_vector_from(&lut)
+                    .unwrap();
+                let acc: GlweCiphertext = default_engine
+                    .trivially_encrypt_glwe_ciphertext(
+                        bsk.glwe_dimension().to_glwe_size(),
+                        &lut_plaintext,
+                    )
+                    .unwrap();
+
+                for &message in messages.iter() {
+                    let plaintext: Plaintext =
+                        default_engine.create_plaintext_from(&(message * delta())).unwrap();
+                    let input: LweCiphertext = default_engine
+                        .encrypt_lwe_ciphertext(&small_lwe_sk, &plaintext, NOISE)
+                        .unwrap();
+
+                    let mut fused: LweCiphertext = default_engine
+                        .zero_encrypt_lwe_ciphertext(&small_lwe_sk, NOISE)
+                        .unwrap();
+                    fft_engine
+                        .discard_bootstrap_keyswitch_lwe_ciphertext(
+                            &mut fused,
+                            &input,
+                            &acc,
+                            &bsk,
+                            &ksk,
+                        )
+                        .unwrap();
+
+                    let mut bootstrapped: LweCiphertext = default_engine
+                        .zero_encrypt_lwe_ciphertext(&big_lwe_sk, NOISE)
+                        .unwrap();
+                    fft_engine
+                        .discard_bootstrap_lwe_ciphertext(&mut bootstrapped, &input, &acc, &bsk)
+                        .unwrap();
+                    let mut separate: LweCiphertext = default_engine
+                        .zero_encrypt_lwe_ciphertext(&small_lwe_sk, NOISE)
+                        .unwrap();
+                    default_engine
+                        .discard_keyswitch_lwe_ciphertext(&mut separate, &bootstrapped, &ksk)
+                        .unwrap();
+
+                    let fused: Plaintext = default_engine
+                        .decrypt_lwe_ciphertext(&small_lwe_sk, &fused)
+                        .unwrap();
+                    let separate: Plaintext = default_engine
+                        .decrypt_lwe_ciphertext(&small_lwe_sk, &separate)
+                        .unwrap();
+                    let fused = decode(default_engine.retrieve_plaintext(&fused).unwrap());
+                    let separate =
+                        decode(default_engine.retrieve_plaintext(&separate).unwrap());
+                    assert_eq!(fused, separate);
+                    assert_eq!(fused, message);
+                }
+
+                // The vector variant applies one accumulator per ciphertext.
+                let plaintext_vector: PlaintextVector = default_engine
+                    .create_plaintext_vector_from(
+                        &messages.iter().map(|m| m * delta()).collect::<Vec<_>>(),
+                    )
+                    .unwrap();
+                let input: LweCiphertextVector = default_engine
+                    .encrypt_lwe_ciphertext_vector(&small_lwe_sk, &plaintext_vector, NOISE)
+                    .unwrap();
+                let lut_plaintext_vector: PlaintextVector = default_engine
+                    .create_plaintext_vector_from(&lut.repeat(count))
+                    .unwrap();
+                let acc: GlweCiphertextVector = default_engine
+                    .trivially_encrypt_glwe_ciphertext_vector(
+                        bsk.glwe_dimension().to_glwe_size(),
+                        GlweCiphertextCount(count),
+                        &lut_plaintext_vector,
+                    )
+                    .unwrap();
+
+                let mut fused: LweCiphertextVector = default_engine
+                    .zero_encrypt_lwe_ciphertext_vector(
+                        &small_lwe_sk,
+                        NOISE,
+                        LweCiphertextCount(count),
+                    )
+                    .unwrap();
+                fft_engine
+                    .discard_bootstrap_keyswitch_lwe_ciphertext_vector(
+                        &mut fused, &input, &acc, &bsk, &ksk,
+                    )
+                    .unwrap();
+
+                let mut bootstrapped: LweCiphertextVector = default_engine
+                    .zero_encrypt_lwe_ciphertext_vector(
+                        &big_lwe_sk,
+                        NOISE,
+                        LweCiphertextCount(count),
+                    )
+                    .unwrap();
+                fft_engine
+                    .discard_bootstrap_lwe_ciphertext_vector(&mut bootstrapped, &input, &acc, &bsk)
+                    .unwrap();
+                let mut separate: LweCiphertextVector = default_engine
+                    .zero_encrypt_lwe_ciphertext_vector(
+                        &small_lwe_sk,
+                        NOISE,
+                        LweCiphertextCount(count),
+                    )
+                    .unwrap();
+                default_engine
+                    .discard_keyswitch_lwe_ciphertext_vector(&mut separate, &bootstrapped, &ksk)
+                    .unwrap();
+
+                let fused: PlaintextVector = default_engine
+                    .decrypt_lwe_ciphertext_vector(&small_lwe_sk, &fused)
+                    .unwrap();
+                let separate: PlaintextVector = default_engine
+                    .decrypt_lwe_ciphertext_vector(&small_lwe_sk, &separate)
+                    .unwrap();
+                let fused: Vec<$Scalar> = default_engine
+                    .retrieve_plaintext_vector(&fused)
+                    .unwrap()
+                    .into_iter()
+                    .map(|p| decode(p))
+                    .collect();
+                let separate: Vec<$Scalar> = default_engine
+                    .retrieve_plaintext_vector(&separate)
+                    .unwrap()
+                    .into_iter()
+                    .map(|p| decode(p))
+                    .collect();
+                assert_eq!(fused, separate);
+                assert_eq!(fused, messages);
+            }
+        }
+    };
+}
+
+test_fused_keyswitch_bootstrap!(
+    test_32,
+    u32,
+    [
+        LweSecretKey = LweSecretKey32,
+        GlweSecretKey = GlweSecretKey32,
+        LweKeyswitchKey = LweKeyswitchKey32,
+        LweBootstrapKey = LweBootstrapKey32,
+        FftFourierLweBootstrapKey = FftFourierLweBootstrapKey32,
+        Plaintext = Plaintext32,
+        PlaintextVector = PlaintextVector32,
+        GlweCiphertext = GlweCiphertext32,
+        GlweCiphertextVector = GlweCiphertextVector32,
+        LweCiphertext = LweCiphertext32,
+        LweCiphertextVector = LweCiphertextVector32,
+    ],
+);
+
+test_fused_keyswitch_bootstrap!(
+    test_64,
+    u64,
+    [
+        LweSecretKey = LweSecretKey64,
+        GlweSecretKey = GlweSecretKey64,
+        LweKeyswitchKey = LweKeyswitchKey64,
+        LweBootstrapKey = LweBootstrapKey64,
+        FftFourierLweBootstrapKey = FftFourierLweBootstrapKey64,
+        Plaintext = Plaintext64,
+        PlaintextVector = PlaintextVector64,
+        GlweCiphertext = GlweCiphertext64,
+        GlweCiphertextVector = GlweCiphertextVector64,
+        LweCiphertext = LweCiphertext64,
+        LweCiphertextVector = LweCiphertextVector64,
+    ],
+);
@@ -1,10 +1,11 @@
 use crate::prelude::{
     FftFourierLweBootstrapKey32, FftFourierLweBootstrapKey64, FftParallelEngine, FftParallelError,
     GlweCiphertextVector32, GlweCiphertextVector64, GlweCiphertextVectorEntity,
-    GlweCiphertextView32, GlweCiphertextView64, LweCiphertextDiscardingBootstrapEngine,
-    LweCiphertextMutView32, LweCiphertextMutView64, LweCiphertextVector32, LweCiphertextVector64,
-    LweCiphertextVectorDiscardingBootstrapEngine, LweCiphertextVectorDiscardingBootstrapError,
-    LweCiphertextView32, LweCiphertextView64, FFT_ENGINE,
+    GlweCiphertextVectorView64, GlweCiphertextView32, GlweCiphertextView64,
+    LweCiphertextDiscardingBootstrapEngine, LweCiphertextMutView32, LweCiphertextMutView64,
+    LweCiphertextVector32, LweCiphertextVector64, LweCiphertextVectorDiscardingBootstrapEngine,
+    LweCiphertextVectorDiscardingBootstrapError, LweCiphertextVectorMutView64,
+    LweCiphertextVectorView64, LweCiphertextView32, LweCiphertextView64, FFT_ENGINE,
 };
 use rayon::prelude::*;
 
@@ -109,3 +110,51 @@ impl
             });
     }
 }
+
+impl
+    LweCiphertextVectorDiscardingBootstrapEngine<
+        FftFourierLweBootstrapKey64,
+        GlweCiphertextVectorView64<'_>,
+        LweCiphertextVectorView64<'_>,
+        LweCiphertextVectorMutView64<'_>,
+    > for FftParallelEngine
+{
+    fn discard_bootstrap_lwe_ciphertext_vector(
+        &mut self,
+        output: &mut LweCiphertextVectorMutView64,
+        input: &LweCiphertextVectorView64,
+        acc: &GlweCiphertextVectorView64,
+        bsk: &FftFourierLweBootstrapKey64,
+    ) -> Result<(), LweCiphertextVectorDiscardingBootstrapError<FftParallelError>> {
+        LweCiphertextVectorDiscardingBootstrapError::perform_generic_checks(
+            output, input, acc, bsk,
+        )?;
+        FftParallelError::perform_fft_checks(acc.polynomial_size())?;
+        unsafe { self.discard_bootstrap_lwe_ciphertext_vector_unchecked(output, input, acc, bsk) };
+        Ok(())
+    }
+
+    unsafe fn discard_bootstrap_lwe_ciphertext_vector_unchecked(
+        &mut self,
+        output: &mut LweCiphertextVectorMutView64,
+        input: &LweCiphertextVectorView64,
+        acc: &GlweCiphertextVectorView64,
+        bsk: &FftFourierLweBootstrapKey64,
+    ) {
+        input
+            .0
+            .par_ciphertext_iter()
+            .zip(output.0.par_ciphertext_iter_mut())
+            .zip(acc.0.par_ciphertext_iter())
+            .for_each(|((c, o), a)| {
+                let c1 = LweCiphertextView64(c);
+                let a1 = GlweCiphertextView64(a);
+                let mut o1 = LweCiphertextMutView64(o);
+                FFT_ENGINE.with(|e| {
+                    e.borrow_mut()
+                        .discard_bootstrap_lwe_ciphertext(&mut o1, &c1, &a1, bsk)
+                        .unwrap();
+                });
+            });
+    }
+}
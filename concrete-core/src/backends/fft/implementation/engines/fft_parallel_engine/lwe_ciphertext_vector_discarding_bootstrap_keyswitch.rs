@@ -0,0 +1,288 @@
+use crate::prelude::{
+    FftFourierLweBootstrapKey32, FftFourierLweBootstrapKey64, FftParallelEngine, FftParallelError,
+    GlweCiphertextVector32, GlweCiphertextVector64, GlweCiphertextVectorEntity,
+    GlweCiphertextView32, GlweCiphertextView64, LweCiphertextDiscardingBootstrapKeyswitchEngine,
+    LweCiphertextMutView32, LweCiphertextMutView64, LweCiphertextVector32, LweCiphertextVector64,
+    LweCiphertextVectorDiscardingBootstrapKeyswitchEngine,
+    LweCiphertextVectorDiscardingBootstrapKeyswitchError, LweCiphertextView32, LweCiphertextView64,
+    LweKeyswitchKey32, LweKeyswitchKey64, FFT_ENGINE,
+};
+use rayon::prelude::*;
+
+impl From<FftParallelError>
+    for LweCiphertextVectorDiscardingBootstrapKeyswitchError<FftParallelError>
+{
+    fn from(err: FftParallelError) -> Self {
+        Self::Engine(err)
+    }
+}
+
+/// # Description
+///
+/// Implementation of [`LweCiphertextVectorDiscardingBootstrapKeyswitchEngine`] for
+/// [`FftParallelEngine`] that operates on 32 bit integers.
+impl
+    LweCiphertextVectorDiscardingBootstrapKeyswitchEngine<
+        FftFourierLweBootstrapKey32,
+        LweKeyswitchKey32,
+        GlweCiphertextVector32,
+        LweCiphertextVector32,
+        LweCiphertextVector32,
+    > for FftParallelEngine
+{
+    /// # Example
+    /// ```
+    /// use concrete_core::prelude::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, LweCiphertextCount,
+    ///     LweDimension, PolynomialSize, Variance, *,
+    /// };
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // Here a hard-set encoding is applied (shift by 20 bits)
+    /// let input = vec![3_u32 << 20; 3];
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let (small_lwe_dim, glwe_dim, poly_size) =
+    ///     (LweDimension(4), GlweDimension(1), PolynomialSize(1024));
+    /// let (bsk_dec_lc, bsk_dec_bl) = (DecompositionLevelCount(3), DecompositionBaseLog(5));
+    /// let (ksk_dec_lc, ksk_dec_bl) = (DecompositionLevelCount(5), DecompositionBaseLog(3));
+    /// // A constant function is applied during the bootstrap
+    /// let lut = vec![8_u32 << 20; poly_size.0 * 3];
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut default_engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let mut fft_engine = FftParallelEngine::new(())?;
+    /// let small_lwe_sk: LweSecretKey32 = default_engine.generate_new_lwe_secret_key(small_lwe_dim)?;
+    /// let glwe_sk: GlweSecretKey32 =
+    ///     default_engine.generate_new_glwe_secret_key(glwe_dim, poly_size)?;
+    /// let big_lwe_sk: LweSecretKey32 =
+    ///     default_engine.transform_glwe_secret_key_to_lwe_secret_key(glwe_sk.clone())?;
+    /// let bsk: LweBootstrapKey32 = default_engine.generate_new_lwe_bootstrap_key(
+    ///     &small_lwe_sk,
+    ///     &glwe_sk,
+    ///     bsk_dec_bl,
+    ///     bsk_dec_lc,
+    ///     noise,
+    /// )?;
+    /// let bsk: FftFourierLweBootstrapKey32 = FftEngine::new(())?.convert_lwe_bootstrap_key(&bsk)?;
+    /// let ksk: LweKeyswitchKey32 = default_engine.generate_new_lwe_keyswitch_key(
+    ///     &big_lwe_sk,
+    ///     &small_lwe_sk,
+    ///     ksk_dec_lc,
+    ///     ksk_dec_bl,
+    ///     noise,
+    /// )?;
+    /// let plaintext_vector = default_engine.create_plaintext_vector_from(&input)?;
+    /// let acc_plaintext_vector = default_engine.create_plaintext_vector_from(&lut)?;
+    /// let acc = default_engine.trivially_encrypt_glwe_ciphertext_vector(
+    ///     glwe_dim.to_glwe_size(),
+    ///     GlweCiphertextCount(3),
+    ///     &acc_plaintext_vector,
+    /// )?;
+    /// let input =
+    ///     default_engine.encrypt_lwe_ciphertext_vector(&small_lwe_sk, &plaintext_vector, noise)?;
+    /// let mut output = default_engine.zero_encrypt_lwe_ciphertext_vector(
+    ///     &small_lwe_sk,
+    ///     noise,
+    ///     LweCiphertextCount(3),
+    /// )?;
+    ///
+    /// fft_engine.discard_bootstrap_keyswitch_lwe_ciphertext_vector(
+    ///     &mut output,
+    ///     &input,
+    ///     &acc,
+    ///     &bsk,
+    ///     &ksk,
+    /// )?;
+    /// #
+    /// assert_eq!(output.lwe_dimension(), small_lwe_sk.lwe_dimension());
+    /// assert_eq!(output.lwe_ciphertext_count(), LweCiphertextCount(3));
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn discard_bootstrap_keyswitch_lwe_ciphertext_vector(
+        &mut self,
+        output: &mut LweCiphertextVector32,
+        input: &LweCiphertextVector32,
+        acc: &GlweCiphertextVector32,
+        bsk: &FftFourierLweBootstrapKey32,
+        ksk: &LweKeyswitchKey32,
+    ) -> Result<(), LweCiphertextVectorDiscardingBootstrapKeyswitchError<Self::EngineError>> {
+        FftParallelError::perform_fft_checks(acc.polynomial_size())?;
+        LweCiphertextVectorDiscardingBootstrapKeyswitchError::perform_generic_checks(
+            output, input, acc, bsk, ksk,
+        )?;
+        unsafe {
+            self.discard_bootstrap_keyswitch_lwe_ciphertext_vector_unchecked(
+                output, input, acc, bsk, ksk,
+            )
+        };
+        Ok(())
+    }
+
+    unsafe fn discard_bootstrap_keyswitch_lwe_ciphertext_vector_unchecked(
+        &mut self,
+        output: &mut LweCiphertextVector32,
+        input: &LweCiphertextVector32,
+        acc: &GlweCiphertextVector32,
+        bsk: &FftFourierLweBootstrapKey32,
+        ksk: &LweKeyswitchKey32,
+    ) {
+        input
+            .0
+            .par_ciphertext_iter()
+            .zip(output.0.par_ciphertext_iter_mut())
+            .zip(acc.0.par_ciphertext_iter())
+            .for_each(|((c, o), a)| {
+                let c1 = LweCiphertextView32(c);
+                let a1 = GlweCiphertextView32(a);
+                let mut o1 = LweCiphertextMutView32(o);
+                FFT_ENGINE.with(|e| {
+                    e.borrow_mut()
+                        .discard_bootstrap_keyswitch_lwe_ciphertext_unchecked(
+                            &mut o1, &c1, &a1, bsk, ksk,
+                        );
+                });
+            });
+    }
+}
+
+/// # Description
+///
+/// Implementation of [`LweCiphertextVectorDiscardingBootstrapKeyswitchEngine`] for
+/// [`FftParallelEngine`] that operates on 64 bit integers.
+impl
+    LweCiphertextVectorDiscardingBootstrapKeyswitchEngine<
+        FftFourierLweBootstrapKey64,
+        LweKeyswitchKey64,
+        GlweCiphertextVector64,
+        LweCiphertextVector64,
+        LweCiphertextVector64,
+    > for FftParallelEngine
+{
+    /// # Example
+    /// ```
+    /// use concrete_core::prelude::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, LweCiphertextCount,
+    ///     LweDimension, PolynomialSize, Variance, *,
+    /// };
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // Here a hard-set encoding is applied (shift by 50 bits)
+    /// let input = vec![3_u64 << 50; 3];
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let (small_lwe_dim, glwe_dim, poly_size) =
+    ///     (LweDimension(4), GlweDimension(1), PolynomialSize(1024));
+    /// let (bsk_dec_lc, bsk_dec_bl) = (DecompositionLevelCount(3), DecompositionBaseLog(5));
+    /// let (ksk_dec_lc, ksk_dec_bl) = (DecompositionLevelCount(5), DecompositionBaseLog(3));
+    /// // A constant function is applied during the bootstrap
+    /// let lut = vec![8_u64 << 50; poly_size.0 * 3];
+    /// let noise = Variance(2_f64.powf(-50.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut default_engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let mut fft_engine = FftParallelEngine::new(())?;
+    /// let small_lwe_sk: LweSecretKey64 = default_engine.generate_new_lwe_secret_key(small_lwe_dim)?;
+    /// let glwe_sk: GlweSecretKey64 =
+    ///     default_engine.generate_new_glwe_secret_key(glwe_dim, poly_size)?;
+    /// let big_lwe_sk: LweSecretKey64 =
+    ///     default_engine.transform_glwe_secret_key_to_lwe_secret_key(glwe_sk.clone())?;
+    /// let bsk: LweBootstrapKey64 = default_engine.generate_new_lwe_bootstrap_key(
+    ///     &small_lwe_sk,
+    ///     &glwe_sk,
+    ///     bsk_dec_bl,
+    ///     bsk_dec_lc,
+    ///     noise,
+    /// )?;
+    /// let bsk: FftFourierLweBootstrapKey64 = FftEngine::new(())?.convert_lwe_bootstrap_key(&bsk)?;
+    /// let ksk: LweKeyswitchKey64 = default_engine.generate_new_lwe_keyswitch_key(
+    ///     &big_lwe_sk,
+    ///     &small_lwe_sk,
+    ///     ksk_dec_lc,
+    ///     ksk_dec_bl,
+    ///     noise,
+    /// )?;
+    /// let plaintext_vector = default_engine.create_plaintext_vector_from(&input)?;
+    /// let acc_plaintext_vector = default_engine.create_plaintext_vector_from(&lut)?;
+    /// let acc = default_engine.trivially_encrypt_glwe_ciphertext_vector(
+    ///     glwe_dim.to_glwe_size(),
+    ///     GlweCiphertextCount(3),
+    ///     &acc_plaintext_vector,
+    /// )?;
+    /// let input =
+    ///     default_engine.encrypt_lwe_ciphertext_vector(&small_lwe_sk, &plaintext_vector, noise)?;
+    /// let mut output = default_engine.zero_encrypt_lwe_ciphertext_vector(
+    ///     &small_lwe_sk,
+    ///     noise,
+    ///     LweCiphertextCount(3),
+    /// )?;
+    ///
+    /// fft_engine.discard_bootstrap_keyswitch_lwe_ciphertext_vector(
+    ///     &mut output,
+    ///     &input,
+    ///     &acc,
+    ///     &bsk,
+    ///     &ksk,
+    /// )?;
+    /// #
+    /// assert_eq!(output.lwe_dimension(), small_lwe_sk.lwe_dimension());
+    /// assert_eq!(output.lwe_ciphertext_count(), LweCiphertextCount(3));
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn discard_bootstrap_keyswitch_lwe_ciphertext_vector(
+        &mut self,
+        output: &mut LweCiphertextVector64,
+        input: &LweCiphertextVector64,
+        acc: &GlweCiphertextVector64,
+        bsk: &FftFourierLweBootstrapKey64,
+        ksk: &LweKeyswitchKey64,
+    ) -> Result<(), LweCiphertextVectorDiscardingBootstrapKeyswitchError<Self::EngineError>> {
+        FftParallelError::perform_fft_checks(acc.polynomial_size())?;
+        LweCiphertextVectorDiscardingBootstrapKeyswitchError::perform_generic_checks(
+            output, input, acc, bsk, ksk,
+        )?;
+        unsafe {
+            self.discard_bootstrap_keyswitch_lwe_ciphertext_vector_unchecked(
+                output, input, acc, bsk, ksk,
+            )
+        };
+        Ok(())
+    }
+
+    unsafe fn discard_bootstrap_keyswitch_lwe_ciphertext_vector_unchecked(
+        &mut self,
+        output: &mut LweCiphertextVector64,
+        input: &LweCiphertextVector64,
+        acc: &GlweCiphertextVector64,
+        bsk: &FftFourierLweBootstrapKey64,
+        ksk: &LweKeyswitchKey64,
+    ) {
+        input
+            .0
+            .par_ciphertext_iter()
+            .zip(output.0.par_ciphertext_iter_mut())
+            .zip(acc.0.par_ciphertext_iter())
+            .for_each(|((c, o), a)| {
+                let c1 = LweCiphertextView64(c);
+                let a1 = GlweCiphertextView64(a);
+                let mut o1 = LweCiphertextMutView64(o);
+                FFT_ENGINE.with(|e| {
+                    e.borrow_mut()
+                        .discard_bootstrap_keyswitch_lwe_ciphertext_unchecked(
+                            &mut o1, &c1, &a1, bsk, ksk,
+                        );
+                });
+            });
+    }
+}
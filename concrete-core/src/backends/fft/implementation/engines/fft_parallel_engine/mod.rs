@@ -53,3 +53,8 @@ impl AbstractEngine for FftParallelEngine {
 }
 
 mod lwe_ciphertext_vector_discarding_bootstrap;
+mod lwe_ciphertext_vector_discarding_bootstrap_keyswitch;
+mod lwe_ciphertext_vector_discarding_keyswitch_bootstrap;
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,155 @@
+macro_rules! test_parallel_fused_keyswitch_bootstrap {
+    ($module:ident, $Scalar:ty $(,)?) => {
+        mod $module {
+            use super::super::super::fft_engine::tests::$module::*;
+            use super::super::super::fft_engine::tests::{MESSAGE_MODULUS, NOISE, UNSAFE_SECRET};
+            use crate::prelude::*;
+
+            // Decrypts and decodes the messages of a ciphertext vector.
+            fn decrypt(
+                default_engine: &mut DefaultEngine,
+                key: &LweSecretKey,
+                ciphertext_vector: &LweCiphertextVector,
+            ) -> Vec<$Scalar> {
+                let plaintext_vector: PlaintextVector = default_engine
+                    .decrypt_lwe_ciphertext_vector(key, ciphertext_vector)
+                    .unwrap();
+                default_engine
+                    .retrieve_plaintext_vector(&plaintext_vector)
+                    .unwrap()
+                    .into_iter()
+                    .map(decode)
+                    .collect()
+            }
+
+            // Encrypts every message under `key`, and returns one identity accumulator per
+            // message.
+            fn encrypt_inputs(
+                default_engine: &mut DefaultEngine,
+                key: &LweSecretKey,
+                bsk: &FftFourierLweBootstrapKey,
+                messages: &[$Scalar],
+            ) -> (LweCiphertextVector, GlweCiphertextVector) {
+                let plaintext_vector: PlaintextVector = default_engine
+                    .create_plaintext_vector_from(
+                        &messages.iter().map(|m| m * delta()).collect::<Vec<_>>(),
+                    )
+                    .unwrap();
+                let input: LweCiphertextVector = default_engine
+                    .encrypt_lwe_ciphertext_vector(key, &plaintext_vector, NOISE)
+                    .unwrap();
+                let lut_plaintext_vector: PlaintextVector = default_engine
+                    .create_plaintext_vector_from(
+                        &identity_lut(bsk.polynomial_size()).repeat(messages.len()),
+                    )
+                    .unwrap();
+                let acc: GlweCiphertextVector = default_engine
+                    .trivially_encrypt_glwe_ciphertext_vector(
+                        bsk.glwe_dimension().to_glwe_size(),
+                        GlweCiphertextCount(messages.len()),
+                        &lut_plaintext_vector,
+                    )
+                    .unwrap();
+                (input, acc)
+            }
+
+            #[test]
+            fn keyswitch_bootstrap() {
+                let mut default_engine =
+                    DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET))).unwrap();
+                let mut fft_engine = FftEngine::new(()).unwrap();
+                let mut fft_parallel_engine = FftParallelEngine::new(()).unwrap();
+                let Keys {
+                    small_lwe_sk,
+                    big_lwe_sk,
+                    ksk,
+                    bsk,
+                } = generate_keys(&mut default_engine);
+                let count = LweCiphertextCount(MESSAGE_MODULUS);
+                let messages: Vec<$Scalar> = (0..MESSAGE_MODULUS as $Scalar).collect();
+                let (input, acc) =
+                    encrypt_inputs(&mut default_engine, &big_lwe_sk, &bsk, &messages);
+
+                let mut fused: LweCiphertextVector = default_engine
+                    .zero_encrypt_lwe_ciphertext_vector(&big_lwe_sk, NOISE, count)
+                    .unwrap();
+                fft_parallel_engine
+                    .discard_keyswitch_bootstrap_lwe_ciphertext_vector(
+                        &mut fused, &input, &acc, &ksk, &bsk,
+                    )
+                    .unwrap();
+
+                let mut keyswitched: LweCiphertextVector = default_engine
+                    .zero_encrypt_lwe_ciphertext_vector(&small_lwe_sk, NOISE, count)
+                    .unwrap();
+                default_engine
+                    .discard_keyswitch_lwe_ciphertext_vector(&mut keyswitched, &input, &ksk)
+                    .unwrap();
+                let mut separate: LweCiphertextVector = default_engine
+                    .zero_encrypt_lwe_ciphertext_vector(&big_lwe_sk, NOISE, count)
+                    .unwrap();
+                fft_engine
+                    .discard_bootstrap_lwe_ciphertext_vector(
+                        &mut separate,
+                        &keyswitched,
+                        &acc,
+                        &bsk,
+                    )
+                    .unwrap();
+
+                let fused = decrypt(&mut default_engine, &big_lwe_sk, &fused);
+                let separate = decrypt(&mut default_engine, &big_lwe_sk, &separate);
+                assert_eq!(fused, separate);
+                assert_eq!(fused, messages);
+            }
+
+            #[test]
+            fn bootstrap_keyswitch() {
+                let mut default_engine =
+                    DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET))).unwrap();
+                let mut fft_engine = FftEngine::new(()).unwrap();
+                let mut fft_parallel_engine = FftParallelEngine::new(()).unwrap();
+                let Keys {
+                    small_lwe_sk,
+                    big_lwe_sk,
+                    ksk,
+                    bsk,
+                } = generate_keys(&mut default_engine);
+                let count = LweCiphertextCount(MESSAGE_MODULUS);
+                let messages: Vec<$Scalar> = (0..MESSAGE_MODULUS as $Scalar).collect();
+                let (input, acc) =
+                    encrypt_inputs(&mut default_engine, &small_lwe_sk, &bsk, &messages);
+
+                let mut fused: LweCiphertextVector = default_engine
+                    .zero_encrypt_lwe_ciphertext_vector(&small_lwe_sk, NOISE, count)
+                    .unwrap();
+                fft_parallel_engine
+                    .discard_bootstrap_keyswitch_lwe_ciphertext_vector(
+                        &mut fused, &input, &acc, &bsk, &ksk,
+                    )
+                    .unwrap();
+
+                let mut bootstrapped: LweCiphertextVector = default_engine
+                    .zero_encrypt_lwe_ciphertext_vector(&big_lwe_sk, NOISE, count)
+                    .unwrap();
+                fft_engine
+                    .discard_bootstrap_lwe_ciphertext_vector(&mut bootstrapped, &input, &acc, &bsk)
+                    .unwrap();
+                let mut separate: LweCiphertextVector = default_engine
+                    .zero_encrypt_lwe_ciphertext_vector(&small_lwe_sk, NOISE, count)
+                    .unwrap();
+                default_engine
+                    .discard_keyswitch_lwe_ciphertext_vector(&mut separate, &bootstrapped, &ksk)
+                    .unwrap();
+
+                let fused = decrypt(&mut default_engine, &small_lwe_sk, &fused);
+                let separate = decrypt(&mut default_engine, &small_lwe_sk, &separate);
+                assert_eq!(fused, separate);
+                assert_eq!(fused, messages);
+            }
+        }
+    };
+}
+
+test_parallel_fused_keyswitch_bootstrap!(test_32, u32);
+test_parallel_fused_keyswitch_bootstrap!(test_64, u64);
@@ -0,0 +1,418 @@
+#![allow(clippy::missing_safety_doc)]
+
+use crate::prelude::{
+    EntityContainerDeserializationEngine, EntityContainerDeserializationError,
+    EntityDeserializationEngine, FftFourierGgswCiphertext32, FftFourierGgswCiphertext64,
+    FftFourierLweBootstrapKey32, FftFourierLweBootstrapKey64, FftSerializationEngine,
+    FftSerializationError, GgswCiphertextEntity, LweBootstrapKeyEntity,
+};
+use crate::specification::container::{ContainerEntityKind, ContainerHeader};
+
+/// # Description:
+/// Implementation of [`EntityContainerDeserializationEngine`] for [`FftSerializationEngine`] that
+/// operates on 32 bits integers. It deserializes from a container a GGSW ciphertext in the Fourier
+/// domain.
+impl EntityContainerDeserializationEngine<&[u8], FftFourierGgswCiphertext32>
+    for FftSerializationEngine
+{
+    /// # Example
+    /// ```
+    /// use concrete_core::prelude::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, LweDimension, PolynomialSize,
+    ///     Variance, *,
+    /// };
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let glwe_dimension = GlweDimension(2);
+    /// let polynomial_size = PolynomialSize(256);
+    /// let level = DecompositionLevelCount(1);
+    /// let base_log = DecompositionBaseLog(4);
+    /// // Here a hard-set encoding is applied (shift by 20 bits)
+    /// let input = 3_u32 << 20;
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut default_engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let mut fft_engine = FftEngine::new(())?;
+    /// let key: GlweSecretKey32 =
+    ///     default_engine.generate_new_glwe_secret_key(glwe_dimension, polynomial_size)?;
+    /// let plaintext = default_engine.create_plaintext_from(&input)?;
+    ///
+    /// // We encrypt a GGSW ciphertext in the standard domain
+    /// let ciphertext =
+    ///     default_engine.encrypt_scalar_ggsw_ciphertext(&key, &plaintext, noise, level, base_log)?;
+    ///
+    /// // Then we convert it to the Fourier domain.
+    /// let fourier_ciphertext: FftFourierGgswCiphertext32 =
+    ///     fft_engine.convert_ggsw_ciphertext(&ciphertext)?;
+    ///
+    /// let mut serialization_engine = FftSerializationEngine::new(())?;
+    /// let serialized = serialization_engine.serialize_container(&fourier_ciphertext)?;
+    /// let recovered = serialization_engine.deserialize_container(serialized.as_slice())?;
+    /// assert_eq!(fourier_ciphertext, recovered);
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn deserialize_container(
+        &mut self,
+        serialized: &[u8],
+    ) -> Result<FftFourierGgswCiphertext32, EntityContainerDeserializationError<Self::EngineError>>
+    {
+        EntityContainerDeserializationError::perform_generic_checks(
+            serialized,
+            ContainerEntityKind::FftFourierGgswCiphertext,
+            32,
+        )?;
+        let (header, payload) = ContainerHeader::split_payload(serialized)?;
+        let entity: FftFourierGgswCiphertext32 = self.deserialize(payload)?;
+        if header.parameters
+            != [
+                entity.glwe_dimension().0 as u64,
+                entity.polynomial_size().0 as u64,
+                entity.decomposition_base_log().0 as u64,
+                entity.decomposition_level_count().0 as u64,
+                0,
+            ]
+        {
+            return Err(EntityContainerDeserializationError::ParametersMismatch);
+        }
+        Ok(entity)
+    }
+
+    unsafe fn deserialize_container_unchecked(
+        &mut self,
+        serialized: &[u8],
+    ) -> FftFourierGgswCiphertext32 {
+        let (_, payload) = ContainerHeader::split_payload(serialized).unwrap();
+        self.deserialize_unchecked(payload)
+    }
+}
+
+/// # Description:
+/// Implementation of [`EntityContainerDeserializationEngine`] for [`FftSerializationEngine`] that
+/// operates on 64 bits integers. It deserializes from a container a GGSW ciphertext in the Fourier
+/// domain.
+impl EntityContainerDeserializationEngine<&[u8], FftFourierGgswCiphertext64>
+    for FftSerializationEngine
+{
+    /// # Example
+    /// ```
+    /// use concrete_core::prelude::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, LweDimension, PolynomialSize,
+    ///     Variance, *,
+    /// };
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let glwe_dimension = GlweDimension(2);
+    /// let polynomial_size = PolynomialSize(256);
+    /// let level = DecompositionLevelCount(1);
+    /// let base_log = DecompositionBaseLog(4);
+    /// // Here a hard-set encoding is applied (shift by 50 bits)
+    /// let input = 3_u64 << 50;
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut default_engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let mut fft_engine = FftEngine::new(())?;
+    /// let key: GlweSecretKey64 =
+    ///     default_engine.generate_new_glwe_secret_key(glwe_dimension, polynomial_size)?;
+    /// let plaintext = default_engine.create_plaintext_from(&input)?;
+    ///
+    /// // We encrypt a GGSW ciphertext in the standard domain
+    /// let ciphertext =
+    ///     default_engine.encrypt_scalar_ggsw_ciphertext(&key, &plaintext, noise, level, base_log)?;
+    ///
+    /// // Then we convert it to the Fourier domain.
+    /// let fourier_ciphertext: FftFourierGgswCiphertext64 =
+    ///     fft_engine.convert_ggsw_ciphertext(&ciphertext)?;
+    ///
+    /// let mut serialization_engine = FftSerializationEngine::new(())?;
+    /// let serialized = serialization_engine.serialize_container(&fourier_ciphertext)?;
+    /// let recovered = serialization_engine.deserialize_container(serialized.as_slice())?;
+    /// assert_eq!(fourier_ciphertext, recovered);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn deserialize_container(
+        &mut self,
+        serialized: &[u8],
+    ) -> Result<FftFourierGgswCiphertext64, EntityContainerDeserializationError<Self::EngineError>>
+    {
+        EntityContainerDeserializationError::perform_generic_checks(
+            serialized,
+            ContainerEntityKind::FftFourierGgswCiphertext,
+            64,
+        )?;
+        let (header, payload) = ContainerHeader::split_payload(serialized)?;
+        let entity: FftFourierGgswCiphertext64 = self.deserialize(payload)?;
+        if header.parameters
+            != [
+                entity.glwe_dimension().0 as u64,
+                entity.polynomial_size().0 as u64,
+                entity.decomposition_base_log().0 as u64,
+                entity.decomposition_level_count().0 as u64,
+                0,
+            ]
+        {
+            return Err(EntityContainerDeserializationError::ParametersMismatch);
+        }
+        Ok(entity)
+    }
+
+    unsafe fn deserialize_container_unchecked(
+        &mut self,
+        serialized: &[u8],
+    ) -> FftFourierGgswCiphertext64 {
+        let (_, payload) = ContainerHeader::split_payload(serialized).unwrap();
+        self.deserialize_unchecked(payload)
+    }
+}
+
+/// # Description:
+/// Implementation of [`EntityContainerDeserializationEngine`] for [`FftSerializationEngine`] that
+/// operates on 32 bits integers. It deserializes from a container an LWE bootstrap key in the
+/// Fourier domain.
+impl EntityContainerDeserializationEngine<&[u8], FftFourierLweBootstrapKey32>
+    for FftSerializationEngine
+{
+    /// # Example
+    /// ```
+    /// use concrete_core::prelude::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, LweDimension, PolynomialSize,
+    ///     Variance, *,
+    /// };
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let (lwe_dim, glwe_dim, poly_size) = (LweDimension(4), GlweDimension(6), PolynomialSize(256));
+    /// let (dec_lc, dec_bl) = (DecompositionLevelCount(3), DecompositionBaseLog(5));
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut default_engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let mut fft_engine = FftEngine::new(())?;
+    /// let lwe_sk: LweSecretKey32 = default_engine.generate_new_lwe_secret_key(lwe_dim)?;
+    /// let glwe_sk: GlweSecretKey32 =
+    ///     default_engine.generate_new_glwe_secret_key(glwe_dim, poly_size)?;
+    /// let bsk: LweBootstrapKey32 =
+    ///     default_engine.generate_new_lwe_bootstrap_key(&lwe_sk, &glwe_sk, dec_bl, dec_lc, noise)?;
+    ///
+    /// let fourier_bsk: FftFourierLweBootstrapKey32 = fft_engine.convert_lwe_bootstrap_key(&bsk)?;
+    ///
+    /// let mut serialization_engine = FftSerializationEngine::new(())?;
+    /// let serialized = serialization_engine.serialize_container(&fourier_bsk)?;
+    /// let recovered = serialization_engine.deserialize_container(serialized.as_slice())?;
+    /// assert_eq!(fourier_bsk, recovered);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn deserialize_container(
+        &mut self,
+        serialized: &[u8],
+    ) -> Result<FftFourierLweBootstrapKey32, EntityContainerDeserializationError<Self::EngineError>>
+    {
+        EntityContainerDeserializationError::perform_generic_checks(
+            serialized,
+            ContainerEntityKind::FftFourierLweBootstrapKey,
+            32,
+        )?;
+        let (header, payload) = ContainerHeader::split_payload(serialized)?;
+        let entity: FftFourierLweBootstrapKey32 = self.deserialize(payload)?;
+        if header.parameters
+            != [
+                entity.input_lwe_dimension().0 as u64,
+                entity.glwe_dimension().0 as u64,
+                entity.polynomial_size().0 as u64,
+                entity.decomposition_base_log().0 as u64,
+                entity.decomposition_level_count().0 as u64,
+            ]
+        {
+            return Err(EntityContainerDeserializationError::ParametersMismatch);
+        }
+        Ok(entity)
+    }
+
+    unsafe fn deserialize_container_unchecked(
+        &mut self,
+        serialized: &[u8],
+    ) -> FftFourierLweBootstrapKey32 {
+        let (_, payload) = ContainerHeader::split_payload(serialized).unwrap();
+        self.deserialize_unchecked(payload)
+    }
+}
+
+/// # Description:
+/// Implementation of [`EntityContainerDeserializationEngine`] for [`FftSerializationEngine`] that
+/// operates on 64 bits integers. It deserializes from a container an LWE bootstrap key in the
+/// Fourier domain.
+impl EntityContainerDeserializationEngine<&[u8], FftFourierLweBootstrapKey64>
+    for FftSerializationEngine
+{
+    /// # Example
+    /// ```
+    /// use concrete_core::prelude::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, LweDimension, PolynomialSize,
+    ///     Variance, *,
+    /// };
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let (lwe_dim, glwe_dim, poly_size) = (LweDimension(4), GlweDimension(6), PolynomialSize(256));
+    /// let (dec_lc, dec_bl) = (DecompositionLevelCount(3), DecompositionBaseLog(5));
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut default_engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let mut fft_engine = FftEngine::new(())?;
+    /// let lwe_sk: LweSecretKey64 = default_engine.generate_new_lwe_secret_key(lwe_dim)?;
+    /// let glwe_sk: GlweSecretKey64 =
+    ///     default_engine.generate_new_glwe_secret_key(glwe_dim, poly_size)?;
+    /// let bsk: LweBootstrapKey64 =
+    ///     default_engine.generate_new_lwe_bootstrap_key(&lwe_sk, &glwe_sk, dec_bl, dec_lc, noise)?;
+    ///
+    /// let fourier_bsk: FftFourierLweBootstrapKey64 = fft_engine.convert_lwe_bootstrap_key(&bsk)?;
+    ///
+    /// let mut serialization_engine = FftSerializationEngine::new(())?;
+    /// let serialized = serialization_engine.serialize_container(&fourier_bsk)?;
+    /// let recovered = serialization_engine.deserialize_container(serialized.as_slice())?;
+    /// assert_eq!(fourier_bsk, recovered);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn deserialize_container(
+        &mut self,
+        serialized: &[u8],
+    ) -> Result<FftFourierLweBootstrapKey64, EntityContainerDeserializationError<Self::EngineError>>
+    {
+        EntityContainerDeserializationError::perform_generic_checks(
+            serialized,
+            ContainerEntityKind::FftFourierLweBootstrapKey,
+            64,
+        )?;
+        let (header, payload) = ContainerHeader::split_payload(serialized)?;
+        let entity: FftFourierLweBootstrapKey64 = self.deserialize(payload)?;
+        if header.parameters
+            != [
+                entity.input_lwe_dimension().0 as u64,
+                entity.glwe_dimension().0 as u64,
+                entity.polynomial_size().0 as u64,
+                entity.decomposition_base_log().0 as u64,
+                entity.decomposition_level_count().0 as u64,
+            ]
+        {
+            return Err(EntityContainerDeserializationError::ParametersMismatch);
+        }
+        Ok(entity)
+    }
+
+    unsafe fn deserialize_container_unchecked(
+        &mut self,
+        serialized: &[u8],
+    ) -> FftFourierLweBootstrapKey64 {
+        let (_, payload) = ContainerHeader::split_payload(serialized).unwrap();
+        self.deserialize_unchecked(payload)
+    }
+}
+
+/// An entity of the FFT backend, deserialized from a container whose content was not known
+/// in advance.
+#[derive(Debug, PartialEq)]
+pub enum FftContainedEntity {
+    FftFourierGgswCiphertext32(FftFourierGgswCiphertext32),
+    FftFourierGgswCiphertext64(FftFourierGgswCiphertext64),
+    FftFourierLweBootstrapKey32(FftFourierLweBootstrapKey32),
+    FftFourierLweBootstrapKey64(FftFourierLweBootstrapKey64),
+}
+
+impl FftSerializationEngine {
+    /// Deserializes an entity from a container, without knowing its type in advance.
+    ///
+    /// The kind of the entity and the width of its scalars are read from the header of the
+    /// container, which is then checked like in
+    /// [`EntityContainerDeserializationEngine::deserialize_container`].
+    ///
+    /// # Example
+    /// ```
+    /// use concrete_core::prelude::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, LweDimension, PolynomialSize,
+    ///     Variance, *,
+    /// };
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let (lwe_dim, glwe_dim, poly_size) = (LweDimension(4), GlweDimension(6), PolynomialSize(256));
+    /// let (dec_lc, dec_bl) = (DecompositionLevelCount(3), DecompositionBaseLog(5));
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut default_engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let mut fft_engine = FftEngine::new(())?;
+    /// let lwe_sk: LweSecretKey32 = default_engine.generate_new_lwe_secret_key(lwe_dim)?;
+    /// let glwe_sk: GlweSecretKey32 =
+    ///     default_engine.generate_new_glwe_secret_key(glwe_dim, poly_size)?;
+    /// let bsk: LweBootstrapKey32 =
+    ///     default_engine.generate_new_lwe_bootstrap_key(&lwe_sk, &glwe_sk, dec_bl, dec_lc, noise)?;
+    ///
+    /// let fourier_bsk: FftFourierLweBootstrapKey32 = fft_engine.convert_lwe_bootstrap_key(&bsk)?;
+    ///
+    /// let mut serialization_engine = FftSerializationEngine::new(())?;
+    /// let serialized = serialization_engine.serialize_container(&fourier_bsk)?;
+    ///
+    /// match serialization_engine.deserialize_any_container(serialized.as_slice())? {
+    ///     FftContainedEntity::FftFourierLweBootstrapKey32(recovered) => {
+    ///         assert_eq!(fourier_bsk, recovered)
+    ///     }
+    ///     _ => panic!("Unexpected entity"),
+    /// }
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn deserialize_any_container(
+        &mut self,
+        serialized: &[u8],
+    ) -> Result<FftContainedEntity, EntityContainerDeserializationError<FftSerializationError>>
+    {
+        let (header, _) = ContainerHeader::split_payload(serialized)?;
+        match (header.kind, header.scalar_width) {
+            (ContainerEntityKind::FftFourierGgswCiphertext, 32) => self
+                .deserialize_container(serialized)
+                .map(FftContainedEntity::FftFourierGgswCiphertext32),
+            (ContainerEntityKind::FftFourierGgswCiphertext, 64) => self
+                .deserialize_container(serialized)
+                .map(FftContainedEntity::FftFourierGgswCiphertext64),
+            (ContainerEntityKind::FftFourierLweBootstrapKey, 32) => self
+                .deserialize_container(serialized)
+                .map(FftContainedEntity::FftFourierLweBootstrapKey32),
+            (ContainerEntityKind::FftFourierLweBootstrapKey, 64) => self
+                .deserialize_container(serialized)
+                .map(FftContainedEntity::FftFourierLweBootstrapKey64),
+            _ => Err(EntityContainerDeserializationError::EntityKindMismatch),
+        }
+    }
+}
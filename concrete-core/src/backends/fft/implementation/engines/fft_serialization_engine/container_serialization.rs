@@ -0,0 +1,309 @@
+#![allow(clippy::missing_safety_doc)]
+
+use crate::prelude::{
+    EntityContainerSerializationEngine, EntityContainerSerializationError,
+    EntitySerializationEngine, FftFourierGgswCiphertext32, FftFourierGgswCiphertext64,
+    FftFourierLweBootstrapKey32, FftFourierLweBootstrapKey64, FftSerializationEngine,
+    GgswCiphertextEntity, LweBootstrapKeyEntity,
+};
+use crate::specification::container::{ContainerEntityKind, ContainerHeader};
+
+/// # Description:
+/// Implementation of [`EntityContainerSerializationEngine`] for [`FftSerializationEngine`] that
+/// operates on 32 bits integers. It serializes in a container a GGSW ciphertext in the Fourier
+/// domain.
+impl EntityContainerSerializationEngine<FftFourierGgswCiphertext32, Vec<u8>>
+    for FftSerializationEngine
+{
+    /// # Example
+    /// ```
+    /// use concrete_core::prelude::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, LweDimension, PolynomialSize,
+    ///     Variance, *,
+    /// };
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let glwe_dimension = GlweDimension(2);
+    /// let polynomial_size = PolynomialSize(256);
+    /// let level = DecompositionLevelCount(1);
+    /// let base_log = DecompositionBaseLog(4);
+    /// // Here a hard-set encoding is applied (shift by 20 bits)
+    /// let input = 3_u32 << 20;
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut default_engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let mut fft_engine = FftEngine::new(())?;
+    /// let key: GlweSecretKey32 =
+    ///     default_engine.generate_new_glwe_secret_key(glwe_dimension, polynomial_size)?;
+    /// let plaintext = default_engine.create_plaintext_from(&input)?;
+    ///
+    /// // We encrypt a GGSW ciphertext in the standard domain
+    /// let ciphertext =
+    ///     default_engine.encrypt_scalar_ggsw_ciphertext(&key, &plaintext, noise, level, base_log)?;
+    ///
+    /// // Then we convert it to the Fourier domain.
+    /// let fourier_ciphertext: FftFourierGgswCiphertext32 =
+    ///     fft_engine.convert_ggsw_ciphertext(&ciphertext)?;
+    ///
+    /// let mut serialization_engine = FftSerializationEngine::new(())?;
+    /// let serialized = serialization_engine.serialize_container(&fourier_ciphertext)?;
+    /// let recovered = serialization_engine.deserialize_container(serialized.as_slice())?;
+    /// assert_eq!(fourier_ciphertext, recovered);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn serialize_container(
+        &mut self,
+        entity: &FftFourierGgswCiphertext32,
+    ) -> Result<Vec<u8>, EntityContainerSerializationError<Self::EngineError>> {
+        let payload = self.serialize(entity)?;
+        let header = ContainerHeader::new(
+            ContainerEntityKind::FftFourierGgswCiphertext,
+            32,
+            [
+                entity.glwe_dimension().0 as u64,
+                entity.polynomial_size().0 as u64,
+                entity.decomposition_base_log().0 as u64,
+                entity.decomposition_level_count().0 as u64,
+                0,
+            ],
+            &payload,
+        );
+        Ok(header.wrap(&payload))
+    }
+
+    unsafe fn serialize_container_unchecked(
+        &mut self,
+        entity: &FftFourierGgswCiphertext32,
+    ) -> Vec<u8> {
+        self.serialize_container(entity).unwrap()
+    }
+}
+
+/// # Description:
+/// Implementation of [`EntityContainerSerializationEngine`] for [`FftSerializationEngine`] that
+/// operates on 64 bits integers. It serializes in a container a GGSW ciphertext in the Fourier
+/// domain.
+impl EntityContainerSerializationEngine<FftFourierGgswCiphertext64, Vec<u8>>
+    for FftSerializationEngine
+{
+    /// # Example
+    /// ```
+    /// use concrete_core::prelude::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, LweDimension, PolynomialSize,
+    ///     Variance, *,
+    /// };
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let glwe_dimension = GlweDimension(2);
+    /// let polynomial_size = PolynomialSize(256);
+    /// let level = DecompositionLevelCount(1);
+    /// let base_log = DecompositionBaseLog(4);
+    /// // Here a hard-set encoding is applied (shift by 50 bits)
+    /// let input = 3_u64 << 50;
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut default_engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let mut fft_engine = FftEngine::new(())?;
+    /// let key: GlweSecretKey64 =
+    ///     default_engine.generate_new_glwe_secret_key(glwe_dimension, polynomial_size)?;
+    /// let plaintext = default_engine.create_plaintext_from(&input)?;
+    ///
+    /// // We encrypt a GGSW ciphertext in the standard domain
+    /// let ciphertext =
+    ///     default_engine.encrypt_scalar_ggsw_ciphertext(&key, &plaintext, noise, level, base_log)?;
+    ///
+    /// // Then we convert it to the Fourier domain.
+    /// let fourier_ciphertext: FftFourierGgswCiphertext64 =
+    ///     fft_engine.convert_ggsw_ciphertext(&ciphertext)?;
+    ///
+    /// let mut serialization_engine = FftSerializationEngine::new(())?;
+    /// let serialized = serialization_engine.serialize_container(&fourier_ciphertext)?;
+    /// let recovered = serialization_engine.deserialize_container(serialized.as_slice())?;
+    /// assert_eq!(fourier_ciphertext, recovered);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn serialize_container(
+        &mut self,
+        entity: &FftFourierGgswCiphertext64,
+    ) -> Result<Vec<u8>, EntityContainerSerializationError<Self::EngineError>> {
+        let payload = self.serialize(entity)?;
+        let header = ContainerHeader::new(
+            ContainerEntityKind::FftFourierGgswCiphertext,
+            64,
+            [
+                entity.glwe_dimension().0 as u64,
+                entity.polynomial_size().0 as u64,
+                entity.decomposition_base_log().0 as u64,
+                entity.decomposition_level_count().0 as u64,
+                0,
+            ],
+            &payload,
+        );
+        Ok(header.wrap(&payload))
+    }
+
+    unsafe fn serialize_container_unchecked(
+        &mut self,
+        entity: &FftFourierGgswCiphertext64,
+    ) -> Vec<u8> {
+        self.serialize_container(entity).unwrap()
+    }
+}
+
+/// # Description:
+/// Implementation of [`EntityContainerSerializationEngine`] for [`FftSerializationEngine`] that
+/// operates on 32 bits integers. It serializes in a container an LWE bootstrap key in the Fourier
+/// domain.
+impl EntityContainerSerializationEngine<FftFourierLweBootstrapKey32, Vec<u8>>
+    for FftSerializationEngine
+{
+    /// # Example
+    /// ```
+    /// use concrete_core::prelude::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, LweDimension, PolynomialSize,
+    ///     Variance, *,
+    /// };
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let (lwe_dim, glwe_dim, poly_size) = (LweDimension(4), GlweDimension(6), PolynomialSize(256));
+    /// let (dec_lc, dec_bl) = (DecompositionLevelCount(3), DecompositionBaseLog(5));
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut default_engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let mut fft_engine = FftEngine::new(())?;
+    /// let lwe_sk: LweSecretKey32 = default_engine.generate_new_lwe_secret_key(lwe_dim)?;
+    /// let glwe_sk: GlweSecretKey32 =
+    ///     default_engine.generate_new_glwe_secret_key(glwe_dim, poly_size)?;
+    /// let bsk: LweBootstrapKey32 =
+    ///     default_engine.generate_new_lwe_bootstrap_key(&lwe_sk, &glwe_sk, dec_bl, dec_lc, noise)?;
+    ///
+    /// let fourier_bsk: FftFourierLweBootstrapKey32 = fft_engine.convert_lwe_bootstrap_key(&bsk)?;
+    ///
+    /// let mut serialization_engine = FftSerializationEngine::new(())?;
+    /// let serialized = serialization_engine.serialize_container(&fourier_bsk)?;
+    /// let recovered = serialization_engine.deserialize_container(serialized.as_slice())?;
+    /// assert_eq!(fourier_bsk, recovered);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn serialize_container(
+        &mut self,
+        entity: &FftFourierLweBootstrapKey32,
+    ) -> Result<Vec<u8>, EntityContainerSerializationError<Self::EngineError>> {
+        let payload = self.serialize(entity)?;
+        let header = ContainerHeader::new(
+            ContainerEntityKind::FftFourierLweBootstrapKey,
+            32,
+            [
+                entity.input_lwe_dimension().0 as u64,
+                entity.glwe_dimension().0 as u64,
+                entity.polynomial_size().0 as u64,
+                entity.decomposition_base_log().0 as u64,
+                entity.decomposition_level_count().0 as u64,
+            ],
+            &payload,
+        );
+        Ok(header.wrap(&payload))
+    }
+
+    unsafe fn serialize_container_unchecked(
+        &mut self,
+        entity: &FftFourierLweBootstrapKey32,
+    ) -> Vec<u8> {
+        self.serialize_container(entity).unwrap()
+    }
+}
+
+/// # Description:
+/// Implementation of [`EntityContainerSerializationEngine`] for [`FftSerializationEngine`] that
+/// operates on 64 bits integers. It serializes in a container an LWE bootstrap key in the Fourier
+/// domain.
+impl EntityContainerSerializationEngine<FftFourierLweBootstrapKey64, Vec<u8>>
+    for FftSerializationEngine
+{
+    /// # Example
+    /// ```
+    /// use concrete_core::prelude::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, LweDimension, PolynomialSize,
+    ///     Variance, *,
+    /// };
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let (lwe_dim, glwe_dim, poly_size) = (LweDimension(4), GlweDimension(6), PolynomialSize(256));
+    /// let (dec_lc, dec_bl) = (DecompositionLevelCount(3), DecompositionBaseLog(5));
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut default_engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let mut fft_engine = FftEngine::new(())?;
+    /// let lwe_sk: LweSecretKey64 = default_engine.generate_new_lwe_secret_key(lwe_dim)?;
+    /// let glwe_sk: GlweSecretKey64 =
+    ///     default_engine.generate_new_glwe_secret_key(glwe_dim, poly_size)?;
+    /// let bsk: LweBootstrapKey64 =
+    ///     default_engine.generate_new_lwe_bootstrap_key(&lwe_sk, &glwe_sk, dec_bl, dec_lc, noise)?;
+    ///
+    /// let fourier_bsk: FftFourierLweBootstrapKey64 = fft_engine.convert_lwe_bootstrap_key(&bsk)?;
+    ///
+    /// let mut serialization_engine = FftSerializationEngine::new(())?;
+    /// let serialized = serialization_engine.serialize_container(&fourier_bsk)?;
+    /// let recovered = serialization_engine.deserialize_container(serialized.as_slice())?;
+    /// assert_eq!(fourier_bsk, recovered);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn serialize_container(
+        &mut self,
+        entity: &FftFourierLweBootstrapKey64,
+    ) -> Result<Vec<u8>, EntityContainerSerializationError<Self::EngineError>> {
+        let payload = self.serialize(entity)?;
+        let header = ContainerHeader::new(
+            ContainerEntityKind::FftFourierLweBootstrapKey,
+            64,
+            [
+                entity.input_lwe_dimension().0 as u64,
+                entity.glwe_dimension().0 as u64,
+                entity.polynomial_size().0 as u64,
+                entity.decomposition_base_log().0 as u64,
+                entity.decomposition_level_count().0 as u64,
+            ],
+            &payload,
+        );
+        Ok(header.wrap(&payload))
+    }
+
+    unsafe fn serialize_container_unchecked(
+        &mut self,
+        entity: &FftFourierLweBootstrapKey64,
+    ) -> Vec<u8> {
+        self.serialize_container(entity).unwrap()
+    }
+}
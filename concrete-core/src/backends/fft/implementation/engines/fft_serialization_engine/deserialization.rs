@@ -70,9 +70,8 @@ impl EntityDeserializationEngine<&[u8], FftFourierGgswCiphertext32> for FftSeria
             version: FftFourierGgswCiphertext32Version,
             inner: FourierGgswCiphertext<ABox<[c64]>>,
         }
-        let deserialized: SerializableFftFourierGgswCiphertext32 = bincode::deserialize(serialized)
-            .map_err(FftSerializationError::Deserialization)
-            .map_err(EntityDeserializationError::Engine)?;
+        let deserialized: SerializableFftFourierGgswCiphertext32 =
+            self.deserialize_bounded(serialized)?;
         match deserialized {
             SerializableFftFourierGgswCiphertext32 {
                 version: FftFourierGgswCiphertext32Version::Unsupported,
@@ -83,7 +82,10 @@ impl EntityDeserializationEngine<&[u8], FftFourierGgswCiphertext32> for FftSeria
             SerializableFftFourierGgswCiphertext32 {
                 version: FftFourierGgswCiphertext32Version::V0,
                 inner,
-            } => Ok(FftFourierGgswCiphertext32(inner)),
+            } => {
+                inner.validate::<u32>()?;
+                Ok(FftFourierGgswCiphertext32(inner))
+            }
         }
     }
 
@@ -149,9 +151,8 @@ impl EntityDeserializationEngine<&[u8], FftFourierGgswCiphertext64> for FftSeria
             version: FftFourierGgswCiphertext64Version,
             inner: FourierGgswCiphertext<ABox<[c64]>>,
         }
-        let deserialized: SerializableFftFourierGgswCiphertext64 = bincode::deserialize(serialized)
-            .map_err(FftSerializationError::Deserialization)
-            .map_err(EntityDeserializationError::Engine)?;
+        let deserialized: SerializableFftFourierGgswCiphertext64 =
+            self.deserialize_bounded(serialized)?;
         match deserialized {
             SerializableFftFourierGgswCiphertext64 {
                 version: FftFourierGgswCiphertext64Version::Unsupported,
@@ -162,7 +163,10 @@ impl EntityDeserializationEngine<&[u8], FftFourierGgswCiphertext64> for FftSeria
             SerializableFftFourierGgswCiphertext64 {
                 version: FftFourierGgswCiphertext64Version::V0,
                 inner,
-            } => Ok(FftFourierGgswCiphertext64(inner)),
+            } => {
+                inner.validate::<u64>()?;
+                Ok(FftFourierGgswCiphertext64(inner))
+            }
         }
     }
 
@@ -221,9 +225,7 @@ impl EntityDeserializationEngine<&[u8], FftFourierLweBootstrapKey32> for FftSeri
             inner: FourierLweBootstrapKey<ABox<[c64]>>,
         }
         let deserialized: SerializableFftFourierLweBootstrapKey32 =
-            bincode::deserialize(serialized)
-                .map_err(FftSerializationError::Deserialization)
-                .map_err(EntityDeserializationError::Engine)?;
+            self.deserialize_bounded(serialized)?;
         match deserialized {
             SerializableFftFourierLweBootstrapKey32 {
                 version: FftFourierLweBootstrapKey32Version::Unsupported,
@@ -234,7 +236,10 @@ impl EntityDeserializationEngine<&[u8], FftFourierLweBootstrapKey32> for FftSeri
             SerializableFftFourierLweBootstrapKey32 {
                 version: FftFourierLweBootstrapKey32Version::V0,
                 inner,
-            } => Ok(FftFourierLweBootstrapKey32(inner)),
+            } => {
+                inner.validate::<u32>()?;
+                Ok(FftFourierLweBootstrapKey32(inner))
+            }
         }
     }
 
@@ -293,9 +298,7 @@ impl EntityDeserializationEngine<&[u8], FftFourierLweBootstrapKey64> for FftSeri
             inner: FourierLweBootstrapKey<ABox<[c64]>>,
         }
         let deserialized: SerializableFftFourierLweBootstrapKey64 =
-            bincode::deserialize(serialized)
-                .map_err(FftSerializationError::Deserialization)
-                .map_err(EntityDeserializationError::Engine)?;
+            self.deserialize_bounded(serialized)?;
         match deserialized {
             SerializableFftFourierLweBootstrapKey64 {
                 version: FftFourierLweBootstrapKey64Version::Unsupported,
@@ -306,7 +309,10 @@ impl EntityDeserializationEngine<&[u8], FftFourierLweBootstrapKey64> for FftSeri
             SerializableFftFourierLweBootstrapKey64 {
                 version: FftFourierLweBootstrapKey64Version::V0,
                 inner,
-            } => Ok(FftFourierLweBootstrapKey64(inner)),
+            } => {
+                inner.validate::<u64>()?;
+                Ok(FftFourierLweBootstrapKey64(inner))
+            }
         }
     }
 
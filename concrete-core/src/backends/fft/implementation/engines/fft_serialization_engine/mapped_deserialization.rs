@@ -0,0 +1,286 @@
+#![allow(clippy::missing_safety_doc)]
+
+use super::FftSerializationEngine;
+use crate::backends::fft::private::crypto::bootstrap::FourierLweBootstrapKeyView;
+use crate::prelude::{
+    DecompositionBaseLog, DecompositionLevelCount, EntityMappedDeserializationEngine,
+    EntityMappedDeserializationError, FftFourierLweBootstrapKeyView32,
+    FftFourierLweBootstrapKeyView64, GlweSize, LweDimension, PolynomialSize,
+};
+use crate::specification::mapped::{
+    non_zero_parameter, payload_len, slice_from_payload, MappedEntityKind, MappedFormatError,
+    MappedHeader,
+};
+use concrete_fft::c64;
+
+/// # Description:
+/// Implementation of [`EntityMappedDeserializationEngine`] for [`FftSerializationEngine`] that
+/// operates on 32 bits integers. It creates a view to an LWE bootstrap key in the Fourier domain
+/// from bytes in the mapped format, without copying them.
+///
+/// # Note:
+/// The payload of the bytes must be aligned on 8 bytes, which is the case when the bytes are
+/// memory-mapped from a file, or loaded with [`MappedBytes`](`crate::prelude::MappedBytes`).
+impl<'data> EntityMappedDeserializationEngine<&'data [u8], FftFourierLweBootstrapKeyView32<'data>>
+    for FftSerializationEngine
+{
+    /// # Example
+    /// ```
+    /// use concrete_core::prelude::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, LweDimension, PolynomialSize,
+    ///     Variance, *,
+    /// };
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let (lwe_dim, glwe_dim, poly_size) = (LweDimension(4), GlweDimension(6), PolynomialSize(256));
+    /// let (dec_lc, dec_bl) = (DecompositionLevelCount(3), DecompositionBaseLog(5));
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut default_engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let mut fft_engine = FftEngine::new(())?;
+    /// let lwe_sk: LweSecretKey32 = default_engine.generate_new_lwe_secret_key(lwe_dim)?;
+    /// let glwe_sk: GlweSecretKey32 =
+    ///     default_engine.generate_new_glwe_secret_key(glwe_dim, poly_size)?;
+    /// let bsk: LweBootstrapKey32 =
+    ///     default_engine.generate_new_lwe_bootstrap_key(&lwe_sk, &glwe_sk, dec_bl, dec_lc, noise)?;
+    ///
+    /// let fourier_bsk: FftFourierLweBootstrapKey32 = fft_engine.convert_lwe_bootstrap_key(&bsk)?;
+    ///
+    /// let mut serialization_engine = FftSerializationEngine::new(())?;
+    /// let mut serialized = Vec::new();
+    /// serialization_engine.serialize_mapped_into(&fourier_bsk, &mut serialized)?;
+    ///
+    /// // The bytes would usually be memory-mapped from a file.
+    /// let bytes = MappedBytes::read_from(&mut serialized.as_slice())?;
+    /// let fourier_bsk_view: FftFourierLweBootstrapKeyView32 =
+    ///     serialization_engine.deserialize_mapped(bytes.as_slice())?;
+    /// assert_eq!(fourier_bsk_view.glwe_dimension(), glwe_dim);
+    /// assert_eq!(fourier_bsk_view.polynomial_size(), poly_size);
+    /// assert_eq!(fourier_bsk_view.input_lwe_dimension(), lwe_dim);
+    /// assert_eq!(fourier_bsk_view.decomposition_base_log(), dec_bl);
+    /// assert_eq!(fourier_bsk_view.decomposition_level_count(), dec_lc);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn deserialize_mapped(
+        &mut self,
+        bytes: &'data [u8],
+    ) -> Result<
+        FftFourierLweBootstrapKeyView32<'data>,
+        EntityMappedDeserializationError<Self::EngineError>,
+    > {
+        EntityMappedDeserializationError::perform_generic_checks(
+            bytes,
+            MappedEntityKind::FftFourierLweBootstrapKey32,
+        )?;
+        Ok(FftFourierLweBootstrapKeyView32(
+            fourier_bootstrap_key_from_mapped(
+                bytes,
+                MappedEntityKind::FftFourierLweBootstrapKey32,
+            )?,
+        ))
+    }
+
+    unsafe fn deserialize_mapped_unchecked(
+        &mut self,
+        bytes: &'data [u8],
+    ) -> FftFourierLweBootstrapKeyView32<'data> {
+        FftFourierLweBootstrapKeyView32(
+            fourier_bootstrap_key_from_mapped(bytes, MappedEntityKind::FftFourierLweBootstrapKey32)
+                .unwrap(),
+        )
+    }
+}
+
+/// # Description:
+/// Implementation of [`EntityMappedDeserializationEngine`] for [`FftSerializationEngine`] that
+/// operates on 64 bits integers. It creates a view to an LWE bootstrap key in the Fourier domain
+/// from bytes in the mapped format, without copying them.
+///
+/// # Note:
+/// The payload of the bytes must be aligned on 8 bytes, which is the case when the bytes are
+/// memory-mapped from a file, or loaded with [`MappedBytes`](`crate::prelude::MappedBytes`).
+impl<'data> EntityMappedDeserializationEngine<&'data [u8], FftFourierLweBootstrapKeyView64<'data>>
+    for FftSerializationEngine
+{
+    /// # Example
+    /// ```
+    /// use concrete_core::prelude::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, LweDimension, PolynomialSize,
+    ///     Variance, *,
+    /// };
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let (lwe_dim, glwe_dim, poly_size) = (LweDimension(4), GlweDimension(6), PolynomialSize(256));
+    /// let (dec_lc, dec_bl) = (DecompositionLevelCount(3), DecompositionBaseLog(5));
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut default_engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let mut fft_engine = FftEngine::new(())?;
+    /// let lwe_sk: LweSecretKey64 = default_engine.generate_new_lwe_secret_key(lwe_dim)?;
+    /// let glwe_sk: GlweSecretKey64 =
+    ///     default_engine.generate_new_glwe_secret_key(glwe_dim, poly_size)?;
+    /// let bsk: LweBootstrapKey64 =
+    ///     default_engine.generate_new_lwe_bootstrap_key(&lwe_sk, &glwe_sk, dec_bl, dec_lc, noise)?;
+    ///
+    /// let fourier_bsk: FftFourierLweBootstrapKey64 = fft_engine.convert_lwe_bootstrap_key(&bsk)?;
+    ///
+    /// let mut serialization_engine = FftSerializationEngine::new(())?;
+    /// let mut serialized = Vec::new();
+    /// serialization_engine.serialize_mapped_into(&fourier_bsk, &mut serialized)?;
+    ///
+    /// // The bytes would usually be memory-mapped from a file.
+    /// let bytes = MappedBytes::read_from(&mut serialized.as_slice())?;
+    /// let fourier_bsk_view: FftFourierLweBootstrapKeyView64 =
+    ///     serialization_engine.deserialize_mapped(bytes.as_slice())?;
+    /// assert_eq!(fourier_bsk_view.glwe_dimension(), glwe_dim);
+    /// assert_eq!(fourier_bsk_view.polynomial_size(), poly_size);
+    /// assert_eq!(fourier_bsk_view.input_lwe_dimension(), lwe_dim);
+    /// assert_eq!(fourier_bsk_view.decomposition_base_log(), dec_bl);
+    /// assert_eq!(fourier_bsk_view.decomposition_level_count(), dec_lc);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn deserialize_mapped(
+        &mut self,
+        bytes: &'data [u8],
+    ) -> Result<
+        FftFourierLweBootstrapKeyView64<'data>,
+        EntityMappedDeserializationError<Self::EngineError>,
+    > {
+        EntityMappedDeserializationError::perform_generic_checks(
+            bytes,
+            MappedEntityKind::FftFourierLweBootstrapKey64,
+        )?;
+        Ok(FftFourierLweBootstrapKeyView64(
+            fourier_bootstrap_key_from_mapped(
+                bytes,
+                MappedEntityKind::FftFourierLweBootstrapKey64,
+            )?,
+        ))
+    }
+
+    unsafe fn deserialize_mapped_unchecked(
+        &mut self,
+        bytes: &'data [u8],
+    ) -> FftFourierLweBootstrapKeyView64<'data> {
+        FftFourierLweBootstrapKeyView64(
+            fourier_bootstrap_key_from_mapped(bytes, MappedEntityKind::FftFourierLweBootstrapKey64)
+                .unwrap(),
+        )
+    }
+}
+
+/// Creates a Fourier bootstrap key using the payload of `bytes` as storage.
+fn fourier_bootstrap_key_from_mapped(
+    bytes: &[u8],
+    kind: MappedEntityKind,
+) -> Result<FourierLweBootstrapKeyView<'_>, MappedFormatError> {
+    let (header, payload) = MappedHeader::split_payload(bytes, kind)?;
+    let [input_lwe_dimension, glwe_dimension, polynomial_size, base_log, level_count] =
+        header.parameters;
+    non_zero_parameter(input_lwe_dimension)?;
+    let glwe_size = non_zero_parameter(glwe_dimension)?
+        .checked_add(1)
+        .ok_or(MappedFormatError::InvalidHeader)? as u64;
+    let polynomial_size = non_zero_parameter(polynomial_size)?;
+    // The Fourier data must be usable by the FFT, whatever the header says.
+    if !polynomial_size.is_power_of_two() || polynomial_size < 32 {
+        return Err(MappedFormatError::InvalidHeader);
+    }
+    let base_log = non_zero_parameter(base_log)?;
+    let level_count = non_zero_parameter(level_count)?;
+    let scalar_bits = match kind {
+        MappedEntityKind::FftFourierLweBootstrapKey32 => u32::BITS as usize,
+        _ => u64::BITS as usize,
+    };
+    if base_log
+        .checked_mul(level_count)
+        .filter(|&bits| bits <= scalar_bits)
+        .is_none()
+    {
+        return Err(MappedFormatError::InvalidHeader);
+    }
+    let expected_len = payload_len(&[
+        input_lwe_dimension,
+        (polynomial_size / 2) as u64,
+        level_count as u64,
+        glwe_size,
+        glwe_size,
+    ])?;
+    // SAFETY: every bit pattern is a valid pair of floats.
+    let data = unsafe { slice_from_payload::<c64>(payload)? };
+    if data.len() != expected_len {
+        return Err(MappedFormatError::InvalidPayloadSize);
+    }
+    Ok(FourierLweBootstrapKeyView::new(
+        data,
+        LweDimension(input_lwe_dimension as usize),
+        PolynomialSize(polynomial_size),
+        GlweSize(glwe_size as usize),
+        DecompositionBaseLog(base_log),
+        DecompositionLevelCount(level_count),
+    ))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn check_header(parameters: [u64; 5]) -> Result<(), MappedFormatError> {
+        let header = MappedHeader {
+            kind: MappedEntityKind::FftFourierLweBootstrapKey64,
+            parameters,
+            payload_size: 0,
+        };
+        let bytes =
+            crate::specification::mapped::MappedBytes::read_from(&mut header.to_bytes().as_slice())
+                .unwrap();
+        fourier_bootstrap_key_from_mapped(
+            bytes.as_slice(),
+            MappedEntityKind::FftFourierLweBootstrapKey64,
+        )
+        .map(|_| ())
+    }
+
+    #[test]
+    fn test_invalid_headers_are_rejected() {
+        // input lwe dimension, glwe dimension, polynomial size, base log, level count
+        for parameters in [
+            [0, 1, 256, 4, 3],
+            [4, 0, 256, 4, 3],
+            [4, 1, 0, 4, 3],
+            [4, 1, 16, 4, 3],
+            [4, 1, 96, 4, 3],
+            [4, 1, 256, 0, 3],
+            [4, 1, 256, 4, 0],
+            [4, 1, 256, 33, 2],
+            [4, 1, 256, u64::MAX, 2],
+        ] {
+            assert_eq!(
+                check_header(parameters),
+                Err(MappedFormatError::InvalidHeader),
+                "{parameters:?}"
+            );
+        }
+        // A valid header is only rejected because of the empty payload.
+        assert_eq!(
+            check_header([4, 1, 256, 16, 4]),
+            Err(MappedFormatError::InvalidPayloadSize)
+        );
+    }
+}
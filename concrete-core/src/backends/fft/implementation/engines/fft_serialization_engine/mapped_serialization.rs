@@ -0,0 +1,185 @@
+#![allow(clippy::missing_safety_doc)]
+
+use super::{FftSerializationEngine, FftSerializationError};
+use crate::prelude::{
+    EntityMappedSerializationEngine, EntityMappedSerializationError, FftFourierLweBootstrapKey32,
+    FftFourierLweBootstrapKey64, LweBootstrapKeyEntity,
+};
+use crate::specification::mapped::{payload_from_slice, MappedEntityKind, MappedHeader};
+use std::io::Write;
+
+/// # Description:
+/// Implementation of [`EntityMappedSerializationEngine`] for [`FftSerializationEngine`] that
+/// operates on 32 bits integers. It serializes an LWE bootstrap key in the Fourier domain, in the
+/// mapped format.
+impl<Writer: Write> EntityMappedSerializationEngine<FftFourierLweBootstrapKey32, Writer>
+    for FftSerializationEngine
+{
+    /// # Example
+    /// ```
+    /// use concrete_core::prelude::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, LweDimension, PolynomialSize,
+    ///     Variance, *,
+    /// };
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let (lwe_dim, glwe_dim, poly_size) = (LweDimension(4), GlweDimension(6), PolynomialSize(256));
+    /// let (dec_lc, dec_bl) = (DecompositionLevelCount(3), DecompositionBaseLog(5));
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut default_engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let mut fft_engine = FftEngine::new(())?;
+    /// let lwe_sk: LweSecretKey32 = default_engine.generate_new_lwe_secret_key(lwe_dim)?;
+    /// let glwe_sk: GlweSecretKey32 =
+    ///     default_engine.generate_new_glwe_secret_key(glwe_dim, poly_size)?;
+    /// let bsk: LweBootstrapKey32 =
+    ///     default_engine.generate_new_lwe_bootstrap_key(&lwe_sk, &glwe_sk, dec_bl, dec_lc, noise)?;
+    ///
+    /// let fourier_bsk: FftFourierLweBootstrapKey32 = fft_engine.convert_lwe_bootstrap_key(&bsk)?;
+    ///
+    /// let mut serialization_engine = FftSerializationEngine::new(())?;
+    /// let mut serialized = Vec::new();
+    /// serialization_engine.serialize_mapped_into(&fourier_bsk, &mut serialized)?;
+    ///
+    /// // The bytes would usually be memory-mapped from a file.
+    /// let bytes = MappedBytes::read_from(&mut serialized.as_slice())?;
+    /// let fourier_bsk_view: FftFourierLweBootstrapKeyView32 =
+    ///     serialization_engine.deserialize_mapped(bytes.as_slice())?;
+    /// assert_eq!(
+    ///     fourier_bsk_view.input_lwe_dimension(),
+    ///     fourier_bsk.input_lwe_dimension()
+    /// );
+    /// assert_eq!(
+    ///     fourier_bsk_view.polynomial_size(),
+    ///     fourier_bsk.polynomial_size()
+    /// );
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn serialize_mapped_into(
+        &mut self,
+        entity: &FftFourierLweBootstrapKey32,
+        writer: &mut Writer,
+    ) -> Result<(), EntityMappedSerializationError<Self::EngineError>> {
+        let payload = payload_from_slice(entity.0.as_view().data());
+        let header = MappedHeader {
+            kind: MappedEntityKind::FftFourierLweBootstrapKey32,
+            parameters: [
+                entity.input_lwe_dimension().0 as u64,
+                entity.glwe_dimension().0 as u64,
+                entity.polynomial_size().0 as u64,
+                entity.decomposition_base_log().0 as u64,
+                entity.decomposition_level_count().0 as u64,
+            ],
+            payload_size: payload.len() as u64,
+        };
+        writer
+            .write_all(&header.to_bytes())
+            .and_then(|_| writer.write_all(payload))
+            .map_err(|err| FftSerializationError::Serialization(err.into()))
+            .map_err(EntityMappedSerializationError::Engine)
+    }
+
+    unsafe fn serialize_mapped_into_unchecked(
+        &mut self,
+        entity: &FftFourierLweBootstrapKey32,
+        writer: &mut Writer,
+    ) {
+        self.serialize_mapped_into(entity, writer).unwrap()
+    }
+}
+
+/// # Description:
+/// Implementation of [`EntityMappedSerializationEngine`] for [`FftSerializationEngine`] that
+/// operates on 64 bits integers. It serializes an LWE bootstrap key in the Fourier domain, in the
+/// mapped format.
+impl<Writer: Write> EntityMappedSerializationEngine<FftFourierLweBootstrapKey64, Writer>
+    for FftSerializationEngine
+{
+    /// # Example
+    /// ```
+    /// use concrete_core::prelude::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, LweDimension, PolynomialSize,
+    ///     Variance, *,
+    /// };
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let (lwe_dim, glwe_dim, poly_size) = (LweDimension(4), GlweDimension(6), PolynomialSize(256));
+    /// let (dec_lc, dec_bl) = (DecompositionLevelCount(3), DecompositionBaseLog(5));
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut default_engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let mut fft_engine = FftEngine::new(())?;
+    /// let lwe_sk: LweSecretKey64 = default_engine.generate_new_lwe_secret_key(lwe_dim)?;
+    /// let glwe_sk: GlweSecretKey64 =
+    ///     default_engine.generate_new_glwe_secret_key(glwe_dim, poly_size)?;
+    /// let bsk: LweBootstrapKey64 =
+    ///     default_engine.generate_new_lwe_bootstrap_key(&lwe_sk, &glwe_sk, dec_bl, dec_lc, noise)?;
+    ///
+    /// let fourier_bsk: FftFourierLweBootstrapKey64 = fft_engine.convert_lwe_bootstrap_key(&bsk)?;
+    ///
+    /// let mut serialization_engine = FftSerializationEngine::new(())?;
+    /// let mut serialized = Vec::new();
+    /// serialization_engine.serialize_mapped_into(&fourier_bsk, &mut serialized)?;
+    ///
+    /// // The bytes would usually be memory-mapped from a file.
+    /// let bytes = MappedBytes::read_from(&mut serialized.as_slice())?;
+    /// let fourier_bsk_view: FftFourierLweBootstrapKeyView64 =
+    ///     serialization_engine.deserialize_mapped(bytes.as_slice())?;
+    /// assert_eq!(
+    ///     fourier_bsk_view.input_lwe_dimension(),
+    ///     fourier_bsk.input_lwe_dimension()
+    /// );
+    /// assert_eq!(
+    ///     fourier_bsk_view.polynomial_size(),
+    ///     fourier_bsk.polynomial_size()
+    /// );
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn serialize_mapped_into(
+        &mut self,
+        entity: &FftFourierLweBootstrapKey64,
+        writer: &mut Writer,
+    ) -> Result<(), EntityMappedSerializationError<Self::EngineError>> {
+        let payload = payload_from_slice(entity.0.as_view().data());
+        let header = MappedHeader {
+            kind: MappedEntityKind::FftFourierLweBootstrapKey64,
+            parameters: [
+                entity.input_lwe_dimension().0 as u64,
+                entity.glwe_dimension().0 as u64,
+                entity.polynomial_size().0 as u64,
+                entity.decomposition_base_log().0 as u64,
+                entity.decomposition_level_count().0 as u64,
+            ],
+            payload_size: payload.len() as u64,
+        };
+        writer
+            .write_all(&header.to_bytes())
+            .and_then(|_| writer.write_all(payload))
+            .map_err(|err| FftSerializationError::Serialization(err.into()))
+            .map_err(EntityMappedSerializationError::Engine)
+    }
+
+    unsafe fn serialize_mapped_into_unchecked(
+        &mut self,
+        entity: &FftFourierLweBootstrapKey64,
+        writer: &mut Writer,
+    ) {
+        self.serialize_mapped_into(entity, writer).unwrap()
+    }
+}
@@ -1,7 +1,13 @@
 use crate::specification::engines::sealed::AbstractEngineSeal;
-use crate::specification::engines::AbstractEngine;
+use crate::specification::engines::{
+    AbstractEngine, EntityDeserializationError, EntityStreamDeserializationError,
+};
+use bincode::Options;
+use serde::de::DeserializeOwned;
+use serde::Deserialize;
 use std::error::Error;
 use std::fmt::{Display, Formatter};
+use std::io::Read;
 
 /// The error which can occur in the execution of FHE operations, due to the FFT implementation.
 #[derive(Debug)]
@@ -33,7 +39,105 @@ impl Display for FftSerializationError {
 impl Error for FftSerializationError {}
 
 /// The serialization engine exposed by the fft backend.
-pub struct FftSerializationEngine;
+pub struct FftSerializationEngine {
+    size_limit: Option<u64>,
+}
+
+impl FftSerializationEngine {
+    /// Creates an engine which refuses to deserialize entities larger than `size_limit` bytes.
+    ///
+    /// Engines created with [`AbstractEngine::new`] have no size limit. In both cases, the
+    /// invariants of every deserialized entity are checked before it is returned.
+    ///
+    /// # Example
+    /// ```
+    /// use concrete_core::prelude::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, LweDimension, PolynomialSize,
+    ///     Variance, *,
+    /// };
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let (lwe_dim, glwe_dim, poly_size) = (LweDimension(4), GlweDimension(6), PolynomialSize(256));
+    /// let (dec_lc, dec_bl) = (DecompositionLevelCount(3), DecompositionBaseLog(5));
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut default_engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let mut fft_engine = FftEngine::new(())?;
+    /// let lwe_sk: LweSecretKey32 = default_engine.generate_new_lwe_secret_key(lwe_dim)?;
+    /// let glwe_sk: GlweSecretKey32 =
+    ///     default_engine.generate_new_glwe_secret_key(glwe_dim, poly_size)?;
+    /// let bsk: LweBootstrapKey32 =
+    ///     default_engine.generate_new_lwe_bootstrap_key(&lwe_sk, &glwe_sk, dec_bl, dec_lc, noise)?;
+    /// let fourier_bsk: FftFourierLweBootstrapKey32 = fft_engine.convert_lwe_bootstrap_key(&bsk)?;
+    ///
+    /// let mut serialization_engine = FftSerializationEngine::new(())?;
+    /// let serialized = serialization_engine.serialize(&fourier_bsk)?;
+    ///
+    /// let mut bounded_engine = FftSerializationEngine::with_size_limit(serialized.len() as u64);
+    /// let recovered: FftFourierLweBootstrapKey32 =
+    ///     bounded_engine.deserialize(serialized.as_slice())?;
+    /// assert_eq!(fourier_bsk, recovered);
+    ///
+    /// let mut tight_engine = FftSerializationEngine::with_size_limit(1024);
+    /// let result: Result<FftFourierLweBootstrapKey32, _> =
+    ///     tight_engine.deserialize(serialized.as_slice());
+    /// assert!(matches!(
+    ///     result,
+    ///     Err(EntityDeserializationError::SizeLimitExceeded)
+    /// ));
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn with_size_limit(size_limit: u64) -> FftSerializationEngine {
+        FftSerializationEngine {
+            size_limit: Some(size_limit),
+        }
+    }
+
+    /// Returns the size limit in bytes of the deserialized entities, if any.
+    pub fn size_limit(&self) -> Option<u64> {
+        self.size_limit
+    }
+
+    fn deserialize_bounded<'de, T: Deserialize<'de>>(
+        &self,
+        serialized: &'de [u8],
+    ) -> Result<T, EntityDeserializationError<FftSerializationError>> {
+        if matches!(self.size_limit, Some(limit) if serialized.len() as u64 > limit) {
+            return Err(EntityDeserializationError::SizeLimitExceeded);
+        }
+        bincode::deserialize(serialized)
+            .map_err(FftSerializationError::Deserialization)
+            .map_err(EntityDeserializationError::Engine)
+    }
+
+    fn deserialize_bounded_from<T: DeserializeOwned>(
+        &self,
+        reader: impl Read,
+    ) -> Result<T, EntityStreamDeserializationError<FftSerializationError>> {
+        // Those are the options used by `bincode::deserialize_from`, with the size limit of the
+        // engine, which bounds the amount of data read from the stream.
+        bincode::DefaultOptions::new()
+            .with_fixint_encoding()
+            .allow_trailing_bytes()
+            .with_limit(self.size_limit.unwrap_or(u64::MAX))
+            .deserialize_from(reader)
+            .map_err(|err| match *err {
+                bincode::ErrorKind::SizeLimit => {
+                    EntityStreamDeserializationError::SizeLimitExceeded
+                }
+                _ => EntityStreamDeserializationError::Engine(
+                    FftSerializationError::Deserialization(err),
+                ),
+            })
+    }
+}
 
 impl AbstractEngineSeal for FftSerializationEngine {}
 
@@ -42,9 +146,17 @@ impl AbstractEngine for FftSerializationEngine {
     type Parameters = ();
 
     fn new(_parameters: Self::Parameters) -> Result<Self, Self::EngineError> {
-        Ok(FftSerializationEngine)
+        Ok(FftSerializationEngine { size_limit: None })
     }
 }
 
+mod container_deserialization;
+mod container_serialization;
 mod deserialization;
+mod mapped_deserialization;
+mod mapped_serialization;
 mod serialization;
+mod stream_deserialization;
+mod stream_serialization;
+
+pub use container_deserialization::FftContainedEntity;
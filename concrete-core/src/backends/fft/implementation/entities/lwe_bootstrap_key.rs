@@ -14,12 +14,34 @@ pub struct FftFourierLweBootstrapKey32(pub(crate) FourierLweBootstrapKey<ABox<[c
 #[derive(Debug, Clone, PartialEq)]
 pub struct FftFourierLweBootstrapKey64(pub(crate) FourierLweBootstrapKey<ABox<[c64]>>);
 
+/// A structure representing a view to an LWE bootstrap key with 32 bits of precision, in the
+/// Fourier domain.
+///
+/// This is typically created from a memory-mapped file, using the
+/// [mapped format](`crate::specification::mapped`).
+#[derive(Debug, Clone, PartialEq)]
+pub struct FftFourierLweBootstrapKeyView32<'a>(pub(crate) FourierLweBootstrapKey<&'a [c64]>);
+
+/// A structure representing a view to an LWE bootstrap key with 64 bits of precision, in the
+/// Fourier domain.
+///
+/// This is typically created from a memory-mapped file, using the
+/// [mapped format](`crate::specification::mapped`).
+#[derive(Debug, Clone, PartialEq)]
+pub struct FftFourierLweBootstrapKeyView64<'a>(pub(crate) FourierLweBootstrapKey<&'a [c64]>);
+
 impl AbstractEntity for FftFourierLweBootstrapKey32 {
     type Kind = LweBootstrapKeyKind;
 }
 impl AbstractEntity for FftFourierLweBootstrapKey64 {
     type Kind = LweBootstrapKeyKind;
 }
+impl AbstractEntity for FftFourierLweBootstrapKeyView32<'_> {
+    type Kind = LweBootstrapKeyKind;
+}
+impl AbstractEntity for FftFourierLweBootstrapKeyView64<'_> {
+    type Kind = LweBootstrapKeyKind;
+}
 
 impl LweBootstrapKeyEntity for FftFourierLweBootstrapKey32 {
     fn glwe_dimension(&self) -> crate::prelude::GlweDimension {
@@ -64,6 +86,50 @@ impl LweBootstrapKeyEntity for FftFourierLweBootstrapKey64 {
     }
 }
 
+impl LweBootstrapKeyEntity for FftFourierLweBootstrapKeyView32<'_> {
+    fn glwe_dimension(&self) -> crate::prelude::GlweDimension {
+        self.0.glwe_size().to_glwe_dimension()
+    }
+
+    fn polynomial_size(&self) -> crate::prelude::PolynomialSize {
+        self.0.polynomial_size()
+    }
+
+    fn input_lwe_dimension(&self) -> crate::prelude::LweDimension {
+        self.0.key_size()
+    }
+
+    fn decomposition_base_log(&self) -> crate::prelude::DecompositionBaseLog {
+        self.0.decomposition_base_log()
+    }
+
+    fn decomposition_level_count(&self) -> crate::prelude::DecompositionLevelCount {
+        self.0.decomposition_level_count()
+    }
+}
+
+impl LweBootstrapKeyEntity for FftFourierLweBootstrapKeyView64<'_> {
+    fn glwe_dimension(&self) -> crate::prelude::GlweDimension {
+        self.0.glwe_size().to_glwe_dimension()
+    }
+
+    fn polynomial_size(&self) -> crate::prelude::PolynomialSize {
+        self.0.polynomial_size()
+    }
+
+    fn input_lwe_dimension(&self) -> crate::prelude::LweDimension {
+        self.0.key_size()
+    }
+
+    fn decomposition_base_log(&self) -> crate::prelude::DecompositionBaseLog {
+        self.0.decomposition_base_log()
+    }
+
+    fn decomposition_level_count(&self) -> crate::prelude::DecompositionLevelCount {
+        self.0.decomposition_level_count()
+    }
+}
+
 #[cfg(feature = "backend_fft_serialization")]
 #[derive(Serialize, Deserialize)]
 pub(crate) enum FftFourierLweBootstrapKey32Version {
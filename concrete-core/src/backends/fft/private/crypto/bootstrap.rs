@@ -3,15 +3,21 @@ use super::ggsw::{cmux, *};
 use crate::backends::fft::private::math::fft::FourierPolynomialList;
 use crate::commons::crypto::bootstrap::StandardBootstrapKey;
 use crate::commons::crypto::glwe::GlweCiphertext;
-use crate::commons::crypto::lwe::LweCiphertext;
+use crate::commons::crypto::lwe::{LweCiphertext, LweKeyswitchKey};
+#[cfg(feature = "backend_fft_serialization")]
+use crate::commons::crypto::validation::{
+    check_container_len, check_decomposition, check_non_null, EntityValidationError,
+};
 #[cfg(feature = "backend_fft_serialization")]
 use crate::commons::math::tensor::ContainerOwned;
 use crate::commons::math::tensor::{Container, Split};
 use crate::commons::math::torus::UnsignedTorus;
 use crate::commons::numeric::CastInto;
+#[cfg(feature = "backend_fft_serialization")]
+use crate::commons::numeric::Numeric;
 use crate::commons::utils::izip;
 use crate::prelude::{
-    DecompositionBaseLog, DecompositionLevelCount, GlweSize, LutCountLog, LweDimension,
+    DecompositionBaseLog, DecompositionLevelCount, GlweSize, LutCountLog, LweDimension, LweSize,
     ModulusSwitchOffset, MonomialDegree, PolynomialSize,
 };
 use aligned_vec::CACHELINE_ALIGN;
@@ -139,6 +145,31 @@ impl<C: Container<Element = c64>> FourierLweBootstrapKey<C> {
             decomposition_level_count: self.decomposition_level_count,
         }
     }
+
+    /// Checks that the sizes of the key are consistent with its data, for a key operating on
+    /// `Scalar` values.
+    #[cfg(feature = "backend_fft_serialization")]
+    pub(crate) fn validate<Scalar: Numeric>(&self) -> Result<(), EntityValidationError> {
+        check_decomposition::<Scalar>(self.decomposition_base_log, self.decomposition_level_count)?;
+        check_non_null(&[self.key_size.0, self.glwe_size.0.saturating_sub(1)])?;
+        if self.fourier.polynomial_size.0 % 2 != 0 {
+            return Err(EntityValidationError::InvalidContainerSize);
+        }
+        let count = check_container_len(
+            self.fourier.data.container_len(),
+            &[
+                self.fourier.polynomial_size.0 / 2,
+                self.glwe_size.0,
+                self.glwe_size.0,
+                self.decomposition_level_count.0,
+            ],
+        )?;
+        if count == self.key_size.0 {
+            Ok(())
+        } else {
+            Err(EntityValidationError::InvalidContainerSize)
+        }
+    }
 }
 
 /// Returns the required memory for [`FourierLweBootstrapKeyMutView::fill_with_forward_fourier`].
@@ -184,6 +215,30 @@ pub fn bootstrap_scratch<Scalar>(
     )
 }
 
+/// Returns the required memory for [`FourierLweBootstrapKeyView::keyswitch_bootstrap`].
+pub fn keyswitch_bootstrap_scratch<Scalar>(
+    keyswitch_lwe_size: LweSize,
+    glwe_size: GlweSize,
+    polynomial_size: PolynomialSize,
+    fft: FftView<'_>,
+) -> Result<StackReq, SizeOverflow> {
+    StackReq::try_new_aligned::<Scalar>(keyswitch_lwe_size.0, CACHELINE_ALIGN)?.try_and(
+        bootstrap_scratch::<Scalar>(glwe_size, polynomial_size, fft)?,
+    )
+}
+
+/// Returns the required memory for [`FourierLweBootstrapKeyView::bootstrap_keyswitch`].
+pub fn bootstrap_keyswitch_scratch<Scalar>(
+    glwe_size: GlweSize,
+    polynomial_size: PolynomialSize,
+    fft: FftView<'_>,
+) -> Result<StackReq, SizeOverflow> {
+    let bootstrap_lwe_size = glwe_size.to_glwe_dimension().0 * polynomial_size.0 + 1;
+    StackReq::try_new_aligned::<Scalar>(bootstrap_lwe_size, CACHELINE_ALIGN)?.try_and(
+        bootstrap_scratch::<Scalar>(glwe_size, polynomial_size, fft)?,
+    )
+}
+
 impl<'a> FourierLweBootstrapKeyView<'a> {
     pub fn blind_rotate<Scalar: UnsignedTorus + CastInto<usize>>(
         self,
@@ -271,6 +326,47 @@ impl<'a> FourierLweBootstrapKeyView<'a> {
             MonomialDegree(0),
         );
     }
+
+    /// Keyswitches `lwe_in` with `ksk`, then bootstraps the result into `lwe_out`. The
+    /// intermediate ciphertext lives in `stack`.
+    pub fn keyswitch_bootstrap<Scalar: UnsignedTorus + CastInto<usize>>(
+        self,
+        lwe_out: &mut [Scalar],
+        lwe_in: &[Scalar],
+        accumulator: GlweCiphertext<&'_ [Scalar]>,
+        ksk: LweKeyswitchKey<&'_ [Scalar]>,
+        fft: FftView<'_>,
+        stack: DynStack<'_>,
+    ) {
+        let (mut lwe_ks_data, stack) =
+            stack.make_aligned_with(ksk.lwe_size().0, CACHELINE_ALIGN, |_| Scalar::ZERO);
+        let mut lwe_ks = LweCiphertext::from_container(&mut *lwe_ks_data);
+        ksk.keyswitch_ciphertext(&mut lwe_ks, &LweCiphertext::from_container(lwe_in));
+        self.bootstrap(lwe_out, &lwe_ks_data, accumulator, fft, stack);
+    }
+
+    /// Bootstraps `lwe_in`, then keyswitches the result into `lwe_out` with `ksk`. The
+    /// intermediate ciphertext lives in `stack`.
+    pub fn bootstrap_keyswitch<Scalar: UnsignedTorus + CastInto<usize>>(
+        self,
+        lwe_out: &mut [Scalar],
+        lwe_in: &[Scalar],
+        accumulator: GlweCiphertext<&'_ [Scalar]>,
+        ksk: LweKeyswitchKey<&'_ [Scalar]>,
+        fft: FftView<'_>,
+        stack: DynStack<'_>,
+    ) {
+        let (mut lwe_bs_data, stack) = stack.make_aligned_with(
+            self.output_lwe_dimension().to_lwe_size().0,
+            CACHELINE_ALIGN,
+            |_| Scalar::ZERO,
+        );
+        self.bootstrap(&mut lwe_bs_data, lwe_in, accumulator, fft, stack);
+        ksk.keyswitch_ciphertext(
+            &mut LweCiphertext::from_container(lwe_out),
+            &LweCiphertext::from_container(&*lwe_bs_data),
+        );
+    }
 }
 
 /// This function switches modulus for a single coefficient of a ciphertext,
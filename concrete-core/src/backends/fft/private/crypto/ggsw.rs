@@ -6,12 +6,18 @@ use super::super::math::polynomial::{FourierPolynomialUninitMutView, FourierPoly
 use super::super::{as_mut_uninit, assume_init_mut};
 use crate::commons::crypto::ggsw::StandardGgswCiphertext;
 use crate::commons::crypto::glwe::GlweCiphertext;
+#[cfg(feature = "backend_fft_serialization")]
+use crate::commons::crypto::validation::{
+    check_container_len, check_decomposition, check_non_null, EntityValidationError,
+};
 use crate::commons::math::decomposition::{DecompositionLevel, SignedDecomposer};
 use crate::commons::math::polynomial::Polynomial;
 #[cfg(feature = "backend_fft_serialization")]
 use crate::commons::math::tensor::ContainerOwned;
 use crate::commons::math::tensor::{AsMutSlice, Container, IntoTensor, Split};
 use crate::commons::math::torus::UnsignedTorus;
+#[cfg(feature = "backend_fft_serialization")]
+use crate::commons::numeric::Numeric;
 use crate::commons::utils::izip;
 use crate::prelude::{DecompositionBaseLog, DecompositionLevelCount, GlweSize, PolynomialSize};
 use aligned_vec::CACHELINE_ALIGN;
@@ -138,6 +144,31 @@ impl<C: Container<Element = c64>> FourierGgswCiphertext<C> {
             decomposition_level_count: self.decomposition_level_count,
         }
     }
+
+    /// Checks that the sizes of the ciphertext are consistent with its data, for a ciphertext
+    /// encrypting `Scalar` values.
+    #[cfg(feature = "backend_fft_serialization")]
+    pub(crate) fn validate<Scalar: Numeric>(&self) -> Result<(), EntityValidationError> {
+        check_decomposition::<Scalar>(self.decomposition_base_log, self.decomposition_level_count)?;
+        check_non_null(&[self.glwe_size.0.saturating_sub(1)])?;
+        if self.fourier.polynomial_size.0 % 2 != 0 {
+            return Err(EntityValidationError::InvalidContainerSize);
+        }
+        let count = check_container_len(
+            self.fourier.data.container_len(),
+            &[
+                self.fourier.polynomial_size.0 / 2,
+                self.glwe_size.0,
+                self.glwe_size.0,
+                self.decomposition_level_count.0,
+            ],
+        )?;
+        if count == 1 {
+            Ok(())
+        } else {
+            Err(EntityValidationError::InvalidContainerSize)
+        }
+    }
 }
 
 impl<C: Container<Element = c64>> FourierGgswLevelMatrix<C> {
@@ -21,11 +21,21 @@ use std::collections::hash_map::Entry;
 use std::collections::HashMap;
 use std::mem::{align_of, size_of, MaybeUninit};
 use std::sync::{Arc, RwLock};
+#[cfg(not(target_arch = "wasm32"))]
 use std::time::Duration;
 
 #[cfg(any(target_arch = "x86_64", target_arch = "x86"))]
 mod x86;
 
+// The largest size of the ordered FFT the unordered plans are built upon, on wasm32, where the
+// plans cannot be measured. It is the largest base size tried when measuring the plans on the
+// other platforms, so that both use the same kind of plans, and larger unordered FFTs are built on
+// top of it. It must not exceed 2^16 points, above which the ordered FFT panics.
+#[cfg(target_arch = "wasm32")]
+const MAX_BASE_FFT_SIZE: usize = 4096;
+#[cfg(target_arch = "wasm32")]
+const _: () = assert!(MAX_BASE_FFT_SIZE <= 1 << 16);
+
 /// Twisting factors from the paper:
 /// [Fast and Error-Free Negacyclic Integer Convolution using Extended Fourier Transform][paper]
 ///
@@ -161,7 +171,18 @@ impl Fft {
                 p.get_or_init(|| {
                     Arc::new((
                         Twisties::new(n / 2),
-                        Plan::new(n / 2, Method::Measure(Duration::from_millis(10))),
+                        Plan::new(
+                            n / 2,
+                            // Measuring the plans relies on `std::time::Instant`, which panics
+                            // on wasm32.
+                            #[cfg(not(target_arch = "wasm32"))]
+                            Method::Measure(Duration::from_millis(10)),
+                            #[cfg(target_arch = "wasm32")]
+                            Method::UserProvided {
+                                base_algo: concrete_fft::ordered::FftAlgo::Dif4,
+                                base_n: (n / 2).min(MAX_BASE_FFT_SIZE),
+                            },
+                        ),
                     ))
                 })
                 .clone()
@@ -578,6 +599,10 @@ impl<C: Container<Element = c64>> serde::Serialize for FourierPolynomialList<C>
     }
 }
 
+/// The largest polynomial size accepted when deserializing polynomials in the Fourier domain.
+#[cfg(feature = "backend_fft_serialization")]
+const MAX_DESERIALIZED_POLYNOMIAL_SIZE: usize = 1 << 20;
+
 #[cfg(feature = "backend_fft_serialization")]
 impl<'de, C: ContainerOwned<Element = c64>> serde::Deserialize<'de> for FourierPolynomialList<C> {
     fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
@@ -597,8 +622,6 @@ impl<'de, C: ContainerOwned<Element = c64>> serde::Deserialize<'de> for FourierP
                 self,
                 mut seq: A,
             ) -> Result<Self::Value, A::Error> {
-                use crate::commons::math::tensor::Split;
-
                 let str = "sequence of two fields and Fourier polynomials";
                 let polynomial_size = match seq.next_element::<PolynomialSize>()? {
                     Some(polynomial_size) => polynomial_size,
@@ -625,25 +648,59 @@ impl<'de, C: ContainerOwned<Element = c64>> serde::Deserialize<'de> for FourierP
                     }
                 }
 
-                let mut data =
-                    C::collect((0..(polynomial_size.0 / 2 * chunk_count)).map(|_| c64::default()));
-
-                if chunk_count != 0 {
-                    let fft = Fft::new(polynomial_size);
-                    for (i, buf) in data.as_mut().split_into(chunk_count).enumerate() {
-                        match seq.next_element_seed(FillFourier {
-                            fft: fft.as_view(),
-                            buf,
-                        })? {
-                            Some(()) => (),
-                            None => {
-                                return Err(serde::de::Error::invalid_length(
-                                    i,
-                                    &&*format!("sequence of {chunk_count} Fourier polynomials"),
-                                ))
+                if chunk_count == 0 {
+                    return Ok(FourierPolynomialList {
+                        data: C::collect(core::iter::empty()),
+                        polynomial_size,
+                    });
+                }
+
+                // The FFT plan is built from the announced polynomial size before any polynomial
+                // is read, so the size is checked beforehand to keep untrusted inputs from
+                // triggering arbitrarily large allocations.
+                if !polynomial_size.0.is_power_of_two()
+                    || polynomial_size.0 < 32
+                    || polynomial_size.0 > MAX_DESERIALIZED_POLYNOMIAL_SIZE
+                {
+                    return Err(serde::de::Error::invalid_value(
+                        serde::de::Unexpected::Unsigned(polynomial_size.0 as u64),
+                        &"a polynomial size supported by the FFT",
+                    ));
+                }
+
+                let fft = Fft::new(polynomial_size);
+                let mut buf = vec![c64::default(); polynomial_size.0 / 2];
+                let mut error = None;
+
+                // The polynomials are read one at a time, so that the memory allocated grows with
+                // the data actually read instead of the announced chunk count.
+                let data = C::collect(
+                    (0..chunk_count)
+                        .map_while(|i| {
+                            let fill = FillFourier {
+                                fft: fft.as_view(),
+                                buf: &mut buf,
+                            };
+                            match seq.next_element_seed(fill) {
+                                Ok(Some(())) => Some(buf.clone()),
+                                Ok(None) => {
+                                    error = Some(serde::de::Error::invalid_length(
+                                        i,
+                                        &&*format!("sequence of {chunk_count} Fourier polynomials"),
+                                    ));
+                                    None
+                                }
+                                Err(err) => {
+                                    error = Some(err);
+                                    None
+                                }
                             }
-                        };
-                    }
+                        })
+                        .flatten(),
+                );
+
+                if let Some(err) = error {
+                    return Err(err);
                 }
 
                 Ok(FourierPolynomialList {
@@ -11,3 +11,6 @@ pub mod cuda;
 
 #[cfg(feature = "backend_ntt")]
 pub mod ntt;
+
+#[cfg(feature = "backend_dynamic")]
+pub mod dynamic;
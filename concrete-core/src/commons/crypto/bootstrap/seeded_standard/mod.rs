@@ -3,6 +3,10 @@ use crate::commons::crypto::encoding::Plaintext;
 use crate::commons::crypto::ggsw::StandardGgswSeededCiphertext;
 use crate::commons::crypto::secret::generators::EncryptionRandomGenerator;
 use crate::commons::crypto::secret::{GlweSecretKey, LweSecretKey};
+use crate::commons::crypto::validation::{
+    check_container_len, check_decomposition, check_non_null, checked_size, EntityValidationError,
+    ValidateEntity, ValidateExpansion,
+};
 #[cfg(feature = "__commons_parallel")]
 use crate::commons::math::random::ParallelByteRandomGenerator;
 use crate::commons::math::random::{
@@ -717,3 +721,30 @@ impl<Cont> StandardSeededBootstrapKey<Cont> {
             });
     }
 }
+
+impl<Cont, Scalar> ValidateEntity for StandardSeededBootstrapKey<Cont>
+where
+    Cont: AsRefSlice<Element = Scalar>,
+    Scalar: Numeric,
+{
+    fn validate(&self) -> Result<(), EntityValidationError> {
+        check_decomposition::<Scalar>(self.decomp_base_log, self.decomp_level)?;
+        check_non_null(&[self.glwe_size.0.saturating_sub(1)])?;
+        check_container_len(
+            self.tensor.len(),
+            &[self.decomp_level.0, self.glwe_size.0, self.poly_size.0],
+        )?;
+        Ok(())
+    }
+}
+
+impl<Cont, Scalar> ValidateExpansion for StandardSeededBootstrapKey<Cont>
+where
+    Cont: AsRefSlice<Element = Scalar>,
+    Scalar: Numeric,
+{
+    fn expanded_size(&self) -> Option<usize> {
+        // Each body polynomial is expanded into a GLWE ciphertext.
+        checked_size::<Scalar>(&[self.tensor.len(), self.glwe_size.0])
+    }
+}
@@ -2,6 +2,9 @@ use crate::commons::crypto::encoding::Plaintext;
 use crate::commons::crypto::ggsw::StandardGgswCiphertext;
 use crate::commons::crypto::secret::generators::EncryptionRandomGenerator;
 use crate::commons::crypto::secret::{GlweSecretKey, LweSecretKey};
+use crate::commons::crypto::validation::{
+    check_container_len, check_decomposition, check_non_null, EntityValidationError, ValidateEntity,
+};
 use crate::commons::math::polynomial::Polynomial;
 use crate::commons::math::random::ByteRandomGenerator;
 #[cfg(feature = "__commons_parallel")]
@@ -747,3 +750,24 @@ impl<Cont> StandardBootstrapKey<Cont> {
             .map(|chunk| Polynomial::from_container(chunk.into_container()))
     }
 }
+
+impl<Cont, Scalar> ValidateEntity for StandardBootstrapKey<Cont>
+where
+    Cont: AsRefSlice<Element = Scalar>,
+    Scalar: Numeric,
+{
+    fn validate(&self) -> Result<(), EntityValidationError> {
+        check_decomposition::<Scalar>(self.decomp_base_log, self.decomp_level)?;
+        check_non_null(&[self.rlwe_size.0.saturating_sub(1)])?;
+        check_container_len(
+            self.tensor.len(),
+            &[
+                self.decomp_level.0,
+                self.rlwe_size.0,
+                self.rlwe_size.0,
+                self.poly_size.0,
+            ],
+        )?;
+        Ok(())
+    }
+}
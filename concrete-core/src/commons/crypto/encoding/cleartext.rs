@@ -1,4 +1,7 @@
-use crate::commons::math::tensor::{ck_dim_div, tensor_traits, AsMutTensor, AsRefTensor, Tensor};
+use crate::commons::crypto::validation::{check_non_null, EntityValidationError, ValidateEntity};
+use crate::commons::math::tensor::{
+    ck_dim_div, tensor_traits, AsMutTensor, AsRefSlice, AsRefTensor, Tensor,
+};
 use crate::commons::numeric::Numeric;
 use crate::prelude::CleartextCount;
 #[cfg(feature = "__commons_serialization")]
@@ -177,3 +180,18 @@ impl<Cont> CleartextList<Cont> {
             .map(|sub| CleartextList::from_container(sub.into_container()))
     }
 }
+
+impl<T: Numeric> ValidateEntity for Cleartext<T> {
+    fn validate(&self) -> Result<(), EntityValidationError> {
+        Ok(())
+    }
+}
+
+impl<Cont> ValidateEntity for CleartextList<Cont>
+where
+    Cont: AsRefSlice,
+{
+    fn validate(&self) -> Result<(), EntityValidationError> {
+        check_non_null(&[self.tensor.len()])
+    }
+}
@@ -1,4 +1,5 @@
 use super::{Cleartext, CleartextList, Plaintext, PlaintextList};
+use crate::commons::crypto::validation::{EntityValidationError, ValidateEntity};
 use crate::commons::math::decomposition::SignedDecomposer;
 use crate::commons::math::tensor::{AsMutTensor, AsRefTensor};
 use crate::commons::math::torus::{FromTorus, IntoTorus, UnsignedTorus};
@@ -292,6 +293,20 @@ where
     }
 }
 
+impl ValidateEntity for FloatEncoder {
+    fn validate(&self) -> Result<(), EntityValidationError> {
+        let bits_fit = matches!(
+            self.nb_bit_precision.checked_add(self.nb_bit_padding),
+            Some(bits) if bits <= u64::BITS as usize
+        );
+        if self.is_valid() && self.o.is_finite() && self.delta.is_finite() && bits_fit {
+            Ok(())
+        } else {
+            Err(EntityValidationError::InvalidEncoder)
+        }
+    }
+}
+
 #[cfg(all(test))]
 mod test {
     #![allow(clippy::float_cmp)]
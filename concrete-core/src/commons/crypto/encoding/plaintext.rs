@@ -1,3 +1,4 @@
+use crate::commons::crypto::validation::{check_non_null, EntityValidationError, ValidateEntity};
 use crate::commons::math::polynomial::Polynomial;
 use crate::commons::math::tensor::{
     ck_dim_div, tensor_traits, AsMutSlice, AsMutTensor, AsRefSlice, AsRefTensor, Tensor,
@@ -254,3 +255,18 @@ impl<Cont> PlaintextList<Cont> {
         Polynomial::from_container(self.as_mut_tensor().as_mut_slice())
     }
 }
+
+impl<T: Numeric> ValidateEntity for Plaintext<T> {
+    fn validate(&self) -> Result<(), EntityValidationError> {
+        Ok(())
+    }
+}
+
+impl<Cont> ValidateEntity for PlaintextList<Cont>
+where
+    Cont: AsRefSlice,
+{
+    fn validate(&self) -> Result<(), EntityValidationError> {
+        check_non_null(&[self.tensor.len()])
+    }
+}
@@ -1,4 +1,8 @@
 use super::{GgswSeededLevelMatrix, StandardGgswCiphertext};
+use crate::commons::crypto::validation::{
+    check_container_len, check_decomposition, check_non_null, checked_size, EntityValidationError,
+    ValidateEntity, ValidateExpansion,
+};
 use crate::commons::math::decomposition::DecompositionLevel;
 use crate::commons::math::random::{
     ByteRandomGenerator, CompressionSeed, RandomGenerable, RandomGenerator, Uniform,
@@ -584,3 +588,27 @@ impl<Cont> StandardGgswSeededCiphertext<Cont> {
         self.expand_into_with_existing_generator(output, &mut generator);
     }
 }
+
+impl<Cont, Scalar> ValidateEntity for StandardGgswSeededCiphertext<Cont>
+where
+    Cont: AsRefSlice<Element = Scalar>,
+    Scalar: Numeric,
+{
+    fn validate(&self) -> Result<(), EntityValidationError> {
+        check_non_null(&[self.glwe_size.0.saturating_sub(1)])?;
+        let level_count =
+            check_container_len(self.tensor.len(), &[self.glwe_size.0, self.poly_size.0])?;
+        check_decomposition::<Scalar>(self.decomp_base_log, DecompositionLevelCount(level_count))
+    }
+}
+
+impl<Cont, Scalar> ValidateExpansion for StandardGgswSeededCiphertext<Cont>
+where
+    Cont: AsRefSlice<Element = Scalar>,
+    Scalar: Numeric,
+{
+    fn expanded_size(&self) -> Option<usize> {
+        // Each body polynomial is expanded into a GLWE ciphertext.
+        checked_size::<Scalar>(&[self.tensor.len(), self.glwe_size.0])
+    }
+}
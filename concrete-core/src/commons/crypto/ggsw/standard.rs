@@ -1,4 +1,7 @@
 use crate::commons::crypto::encoding::Plaintext;
+use crate::commons::crypto::validation::{
+    check_container_len, check_decomposition, check_non_null, EntityValidationError, ValidateEntity,
+};
 use crate::commons::math::tensor::Container;
 
 use crate::commons::crypto::glwe::GlweList;
@@ -533,3 +536,18 @@ impl<Cont> StandardGgswCiphertext<Cont> {
         }
     }
 }
+
+impl<Cont, Scalar> ValidateEntity for StandardGgswCiphertext<Cont>
+where
+    Cont: AsRefSlice<Element = Scalar>,
+    Scalar: Numeric,
+{
+    fn validate(&self) -> Result<(), EntityValidationError> {
+        check_non_null(&[self.rlwe_size.0.saturating_sub(1)])?;
+        let level_count = check_container_len(
+            self.tensor.len(),
+            &[self.rlwe_size.0, self.rlwe_size.0, self.poly_size.0],
+        )?;
+        check_decomposition::<Scalar>(self.decomp_base_log, DecompositionLevelCount(level_count))
+    }
+}
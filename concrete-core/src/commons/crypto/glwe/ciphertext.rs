@@ -1,6 +1,9 @@
 use super::{GlweBody, GlweMask};
 use crate::commons::crypto::encoding::{Plaintext, PlaintextList};
 use crate::commons::crypto::lwe::LweCiphertext;
+use crate::commons::crypto::validation::{
+    check_container_len, check_non_null, EntityValidationError, ValidateEntity,
+};
 use crate::commons::math::polynomial::PolynomialList;
 use crate::commons::math::tensor::{
     tensor_traits, AsMutSlice, AsMutTensor, AsRefSlice, AsRefTensor, Container, Tensor,
@@ -532,3 +535,13 @@ impl<Cont> GlweCiphertext<Cont> {
             );
     }
 }
+
+impl<Cont> ValidateEntity for GlweCiphertext<Cont>
+where
+    Cont: AsRefSlice,
+{
+    fn validate(&self) -> Result<(), EntityValidationError> {
+        let glwe_size = check_container_len(self.tensor.len(), &[self.poly_size.0])?;
+        check_non_null(&[glwe_size - 1])
+    }
+}
@@ -0,0 +1,123 @@
+use super::GlweCiphertext;
+use crate::commons::crypto::validation::{check_non_null, EntityValidationError, ValidateEntity};
+use crate::commons::math::packing::PackedIntegers;
+use crate::commons::math::tensor::{AsMutSlice, AsMutTensor, AsRefSlice, AsRefTensor};
+use crate::commons::numeric::{CastFrom, CastInto, UnsignedInteger};
+use crate::prelude::{CiphertextModulusLog, GlweDimension, GlweSize, PolynomialSize};
+#[cfg(feature = "__commons_serialization")]
+use serde::{Deserialize, Serialize};
+use std::marker::PhantomData;
+
+/// A GLWE ciphertext whose coefficients were switched to a smaller modulus and bit-packed.
+///
+/// The mask and the body of the ciphertext are rounded to their `ciphertext_modulus_log` most
+/// significant bits, which adds some noise to the ciphertext, and stored with only these bits.
+#[cfg_attr(feature = "__commons_serialization", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GlweCompressedCiphertext<Scalar> {
+    pub(crate) coefficients: PackedIntegers,
+    pub(crate) glwe_dimension: GlweDimension,
+    pub(crate) poly_size: PolynomialSize,
+    pub(crate) _scalar: PhantomData<Scalar>,
+}
+
+impl<Scalar> GlweCompressedCiphertext<Scalar>
+where
+    Scalar: UnsignedInteger + CastInto<u64> + CastFrom<u64>,
+{
+    /// Compresses a ciphertext, by switching its coefficients to the modulus
+    /// $2^{\text{ciphertext\_modulus\_log}}$.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use concrete_core::commons::crypto::glwe::{GlweCiphertext, GlweCompressedCiphertext};
+    /// use concrete_core::prelude::{CiphertextModulusLog, GlweDimension, GlweSize, PolynomialSize};
+    ///
+    /// let ciphertext = GlweCiphertext::allocate(0x1280_0000_u32, PolynomialSize(8), GlweSize(3));
+    /// let compressed = GlweCompressedCiphertext::compress(&ciphertext, CiphertextModulusLog(8));
+    /// assert_eq!(compressed.glwe_dimension(), GlweDimension(2));
+    /// assert_eq!(compressed.polynomial_size(), PolynomialSize(8));
+    /// assert_eq!(compressed.ciphertext_modulus_log(), CiphertextModulusLog(8));
+    ///
+    /// let mut decompressed = GlweCiphertext::allocate(0_u32, PolynomialSize(8), GlweSize(3));
+    /// compressed.decompress_into(&mut decompressed);
+    /// assert_eq!(decompressed.into_container(), vec![0x1300_0000_u32; 24]);
+    /// ```
+    pub fn compress<Cont>(
+        input: &GlweCiphertext<Cont>,
+        ciphertext_modulus_log: CiphertextModulusLog,
+    ) -> Self
+    where
+        GlweCiphertext<Cont>: AsRefTensor<Element = Scalar>,
+    {
+        GlweCompressedCiphertext {
+            coefficients: PackedIntegers::pack_modulus_switched(
+                input.as_tensor().as_slice(),
+                ciphertext_modulus_log.0,
+            ),
+            glwe_dimension: input.mask_size(),
+            poly_size: input.polynomial_size(),
+            _scalar: PhantomData,
+        }
+    }
+
+    /// Decompresses the ciphertext into `output`, by switching its coefficients back to the
+    /// modulus of `Scalar`.
+    pub fn decompress_into<Cont>(&self, output: &mut GlweCiphertext<Cont>)
+    where
+        GlweCiphertext<Cont>: AsMutTensor<Element = Scalar>,
+    {
+        debug_assert!(
+            output.size() == self.size() && output.polynomial_size() == self.poly_size,
+            "The output has not the right GLWE size or polynomial size."
+        );
+        self.coefficients
+            .unpack_modulus_switched_into(output.as_mut_tensor().as_mut_slice());
+    }
+}
+
+impl<Scalar> GlweCompressedCiphertext<Scalar> {
+    /// Returns the GLWE dimension of the ciphertext.
+    pub fn glwe_dimension(&self) -> GlweDimension {
+        self.glwe_dimension
+    }
+
+    /// Returns the size of the ciphertext, e.g. the number of masks + 1 for the body.
+    pub fn size(&self) -> GlweSize {
+        self.glwe_dimension.to_glwe_size()
+    }
+
+    /// Returns the size of the polynomials of the ciphertext.
+    pub fn polynomial_size(&self) -> PolynomialSize {
+        self.poly_size
+    }
+
+    /// Returns the logarithm of the modulus the coefficients were switched to.
+    pub fn ciphertext_modulus_log(&self) -> CiphertextModulusLog {
+        CiphertextModulusLog(self.coefficients.bit_width())
+    }
+
+    /// Returns the packed coefficients of the ciphertext.
+    pub fn as_packed_integers(&self) -> &PackedIntegers {
+        &self.coefficients
+    }
+}
+
+impl<Scalar: UnsignedInteger> ValidateEntity for GlweCompressedCiphertext<Scalar> {
+    fn validate(&self) -> Result<(), EntityValidationError> {
+        check_non_null(&[self.glwe_dimension.0, self.poly_size.0])?;
+        if !self.coefficients.is_consistent() || self.coefficients.bit_width() > Scalar::BITS {
+            return Err(EntityValidationError::InvalidContainerSize);
+        }
+        let expected_len = self
+            .size()
+            .0
+            .checked_mul(self.poly_size.0)
+            .ok_or(EntityValidationError::InvalidContainerSize)?;
+        if self.coefficients.len() != expected_len {
+            return Err(EntityValidationError::InvalidContainerSize);
+        }
+        Ok(())
+    }
+}
@@ -3,6 +3,9 @@ use crate::commons::crypto::encoding::PlaintextList;
 use crate::commons::crypto::lwe::{LweCiphertext, LweList};
 use crate::commons::crypto::secret::generators::EncryptionRandomGenerator;
 use crate::commons::crypto::secret::{GlweSecretKey, LweSecretKey};
+use crate::commons::crypto::validation::{
+    check_container_len, check_decomposition, check_non_null, EntityValidationError, ValidateEntity,
+};
 use crate::commons::math::decomposition::{
     DecompositionLevel, DecompositionTerm, SignedDecomposer,
 };
@@ -14,6 +17,7 @@ use crate::commons::math::tensor::{
     ck_dim_div, ck_dim_eq, tensor_traits, AsMutTensor, AsRefSlice, AsRefTensor, Container, Tensor,
 };
 use crate::commons::math::torus::UnsignedTorus;
+use crate::commons::numeric::Numeric;
 use crate::prelude::{
     BinaryKeyKind, CiphertextCount, DecompositionBaseLog, DecompositionLevelCount,
     DispersionParameter, FunctionalPackingKeyswitchKeyCount, GlweDimension, GlweSize, LweDimension,
@@ -608,15 +612,15 @@ impl<Cont> LwePackingKeyswitchKey<Cont> {
         Self: AsRefTensor<Element = Scalar>,
         LweList<InCont>: AsRefTensor<Element = Scalar>,
         GlweCiphertext<OutCont>: AsMutTensor<Element = Scalar>,
-        OutCont: Clone,
         Scalar: UnsignedTorus,
     {
         debug_assert!(input.count().0 <= output.polynomial_size().0);
         output.as_mut_tensor().fill_with_element(Scalar::ZERO);
-        let mut buffer = output.clone();
+        let mut buffer =
+            GlweCiphertext::allocate(Scalar::ZERO, output.polynomial_size(), output.size());
         // for each ciphertext, call mono_key_switch
         for (degree, input_cipher) in input.ciphertext_iter().enumerate() {
-            self.keyswitch_ciphertext(&mut buffer, &input_cipher);
+            self.keyswitch_ciphertext::<_, Vec<Scalar>, _>(&mut buffer, &input_cipher);
             buffer
                 .as_mut_polynomial_list()
                 .polynomial_iter_mut()
@@ -628,6 +632,55 @@ impl<Cont> LwePackingKeyswitchKey<Cont> {
                 .update_with_wrapping_add(buffer.as_tensor());
         }
     }
+
+    /// Packs several LWE ciphertexts into a single GLWE ciphertext, keyswitching the input
+    /// ciphertexts in parallel.
+    ///
+    /// The partial sums computed by the different threads are added with wrapping arithmetic,
+    /// which makes the output the same as the one of
+    /// [`LwePackingKeyswitchKey::packing_keyswitch`].
+    #[cfg(feature = "__commons_parallel")]
+    pub fn par_packing_keyswitch<InCont, OutCont, Scalar>(
+        &self,
+        output: &mut GlweCiphertext<OutCont>,
+        input: &LweList<InCont>,
+    ) where
+        Self: AsRefTensor<Element = Scalar>,
+        LweList<InCont>: AsRefTensor<Element = Scalar>,
+        GlweCiphertext<OutCont>: AsMutTensor<Element = Scalar>,
+        Scalar: UnsignedTorus + Send + Sync,
+        Cont: Sync,
+    {
+        debug_assert!(input.count().0 <= output.polynomial_size().0);
+        let poly_size = output.polynomial_size();
+        let glwe_size = output.size();
+        let zero = || GlweCiphertext::allocate(Scalar::ZERO, poly_size, glwe_size);
+        let result = input
+            .par_ciphertext_iter()
+            .enumerate()
+            .fold(
+                || (zero(), zero()),
+                |(mut acc, mut buffer), (degree, input_cipher)| {
+                    self.keyswitch_ciphertext::<_, Vec<Scalar>, _>(&mut buffer, &input_cipher);
+                    buffer
+                        .as_mut_polynomial_list()
+                        .polynomial_iter_mut()
+                        .for_each(|mut poly| {
+                            poly.update_with_wrapping_monic_monomial_mul(MonomialDegree(degree))
+                        });
+                    acc.as_mut_tensor()
+                        .update_with_wrapping_add(buffer.as_tensor());
+                    (acc, buffer)
+                },
+            )
+            .map(|(acc, _)| acc)
+            .reduce(zero, |mut acc, other| {
+                acc.as_mut_tensor()
+                    .update_with_wrapping_add(other.as_tensor());
+                acc
+            });
+        output.as_mut_tensor().fill_with_copy(result.as_tensor());
+    }
 }
 
 /// A private functional packing keyswitching key.
@@ -2159,17 +2212,65 @@ impl<Cont> LwePrivateFunctionalPackingKeyswitchKeyList<Cont> {
     }
 }
 
+impl<Cont, Scalar> ValidateEntity for LwePackingKeyswitchKey<Cont>
+where
+    Cont: AsRefSlice<Element = Scalar>,
+    Scalar: Numeric,
+{
+    fn validate(&self) -> Result<(), EntityValidationError> {
+        check_decomposition::<Scalar>(self.decomp_base_log, self.decomp_level_count)?;
+        check_non_null(&[self.output_glwe_size.0.saturating_sub(1)])?;
+        check_container_len(
+            self.tensor.len(),
+            &[
+                self.output_glwe_size.0,
+                self.output_polynomial_size.0,
+                self.decomp_level_count.0,
+            ],
+        )?;
+        Ok(())
+    }
+}
+
+impl<Cont, Scalar> ValidateEntity for LwePrivateFunctionalPackingKeyswitchKeyList<Cont>
+where
+    Cont: AsRefSlice<Element = Scalar>,
+    Scalar: Numeric,
+{
+    fn validate(&self) -> Result<(), EntityValidationError> {
+        check_decomposition::<Scalar>(self.decomp_base_log, self.decomp_level_count)?;
+        check_non_null(&[
+            self.input_lwe_size.0.saturating_sub(1),
+            self.output_glwe_size.0.saturating_sub(1),
+        ])?;
+        check_container_len(
+            self.tensor.len(),
+            &[
+                self.output_glwe_size.0,
+                self.output_polynomial_size.0,
+                self.decomp_level_count.0,
+                self.input_lwe_size.0,
+            ],
+        )?;
+        Ok(())
+    }
+}
+
 #[cfg(feature = "__commons_parallel")]
 #[cfg(test)]
 mod test {
-    use crate::commons::crypto::glwe::LwePrivateFunctionalPackingKeyswitchKeyList;
+    use crate::commons::crypto::glwe::{
+        GlweCiphertext, LwePackingKeyswitchKey, LwePrivateFunctionalPackingKeyswitchKeyList,
+    };
+    use crate::commons::crypto::lwe::LweList;
     use crate::commons::crypto::secret::generators::{
         DeterministicSeeder, EncryptionRandomGenerator, SecretRandomGenerator,
     };
     use crate::commons::crypto::secret::{GlweSecretKey, LweSecretKey};
     use crate::prelude::{
-        DecompositionBaseLog, DecompositionLevelCount, FunctionalPackingKeyswitchKeyCount,
-        GlweDimension, LogStandardDev, LweDimension, PolynomialSize,
+        CiphertextCount, DecompositionBaseLog, DecompositionLevelCount,
+        FunctionalPackingKeyswitchKeyCount, GlweDimension, LogStandardDev, LweDimension,
+        PolynomialSize,
     };
     use concrete_csprng::generators::SoftwareRandomGenerator;
     use concrete_csprng::seeders::Seed;
@@ -2247,4 +2348,68 @@ mod test {
             assert_eq!(pfpksk_par, pfpksk_serial);
         }
     }
+
+    #[test]
+    fn check_equivalence_serial_parallel_packing_keyswitch() {
+        let input_lwe_dimension = LweDimension(10);
+        let output_glwe_dimension = GlweDimension(3);
+        let polynomial_size = PolynomialSize(256);
+        let decomp_base_log = DecompositionBaseLog(3);
+        let decomp_level_count = DecompositionLevelCount(5);
+        let mut secret_generator = SecretRandomGenerator::<SoftwareRandomGenerator>::new(Seed(0));
+        let noise = LogStandardDev::from_log_standard_dev(-15.);
+        let number_of_runs = 10_usize;
+
+        for _ in 0..number_of_runs {
+            let input_key =
+                LweSecretKey::generate_binary(input_lwe_dimension, &mut secret_generator);
+            let output_key = GlweSecretKey::generate_binary(
+                output_glwe_dimension,
+                polynomial_size,
+                &mut secret_generator,
+            );
+            let mut encryption_generator =
+                crate::commons::test_tools::new_encryption_random_generator();
+
+            let mut pksk = LwePackingKeyswitchKey::allocate(
+                0u64,
+                decomp_level_count,
+                decomp_base_log,
+                input_lwe_dimension,
+                output_glwe_dimension,
+                polynomial_size,
+            );
+            pksk.fill_with_packing_keyswitch_key(
+                &input_key,
+                &output_key,
+                noise,
+                &mut encryption_generator,
+            );
+
+            let count = CiphertextCount(crate::commons::test_tools::random_usize_between(
+                1..polynomial_size.0,
+            ));
+            let input = LweList::from_container(
+                (0..input_lwe_dimension.to_lwe_size().0 * count.0)
+                    .map(|_| crate::commons::test_tools::any_uint::<u64>())
+                    .collect::<Vec<_>>(),
+                input_lwe_dimension.to_lwe_size(),
+            );
+
+            let mut output_serial = GlweCiphertext::allocate(
+                0u64,
+                polynomial_size,
+                output_glwe_dimension.to_glwe_size(),
+            );
+            pksk.packing_keyswitch(&mut output_serial, &input);
+            let mut output_par = GlweCiphertext::allocate(
+                0u64,
+                polynomial_size,
+                output_glwe_dimension.to_glwe_size(),
+            );
+            pksk.par_packing_keyswitch(&mut output_par, &input);
+
+            assert_eq!(output_par, output_serial);
+        }
+    }
 }
@@ -1,5 +1,8 @@
 use super::GlweCiphertext;
 use crate::commons::crypto::encoding::PlaintextList;
+use crate::commons::crypto::validation::{
+    check_container_len, check_non_null, EntityValidationError, ValidateEntity,
+};
 use crate::commons::math::tensor::{
     ck_dim_div, tensor_traits, AsMutTensor, AsRefSlice, AsRefTensor, Tensor,
 };
@@ -291,3 +294,14 @@ impl<Cont> GlweList<Cont> {
         }
     }
 }
+
+impl<Cont> ValidateEntity for GlweList<Cont>
+where
+    Cont: AsRefSlice,
+{
+    fn validate(&self) -> Result<(), EntityValidationError> {
+        check_non_null(&[self.rlwe_size.0.saturating_sub(1)])?;
+        check_container_len(self.tensor.len(), &[self.rlwe_size.0, self.poly_size.0])?;
+        Ok(())
+    }
+}
@@ -2,6 +2,7 @@
 
 mod body;
 mod ciphertext;
+mod compressed_ciphertext;
 mod keyswitch;
 mod list;
 mod mask;
@@ -10,6 +11,7 @@ mod seeded_list;
 
 pub use body::*;
 pub use ciphertext::*;
+pub use compressed_ciphertext::*;
 pub use keyswitch::*;
 pub use list::*;
 pub use mask::*;
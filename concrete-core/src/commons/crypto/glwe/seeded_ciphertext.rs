@@ -1,3 +1,6 @@
+use crate::commons::crypto::validation::{
+    check_non_null, checked_size, EntityValidationError, ValidateEntity, ValidateExpansion,
+};
 use crate::commons::numeric::Numeric;
 use crate::prelude::{GlweDimension, GlweSize, PolynomialSize};
 #[cfg(feature = "__commons_serialization")]
@@ -365,3 +368,22 @@ impl<Cont> GlweSeededCiphertext<Cont> {
         self.expand_into_with_existing_generator::<_, _, Gen>(output, &mut generator);
     }
 }
+
+impl<Cont> ValidateEntity for GlweSeededCiphertext<Cont>
+where
+    Cont: AsRefSlice,
+{
+    fn validate(&self) -> Result<(), EntityValidationError> {
+        // Only the body is stored, so the length of the container is the polynomial size.
+        check_non_null(&[self.glwe_dimension.0, self.tensor.len()])
+    }
+}
+
+impl<Cont> ValidateExpansion for GlweSeededCiphertext<Cont>
+where
+    Cont: AsRefSlice,
+{
+    fn expanded_size(&self) -> Option<usize> {
+        checked_size::<Cont::Element>(&[self.glwe_dimension.0.checked_add(1)?, self.tensor.len()])
+    }
+}
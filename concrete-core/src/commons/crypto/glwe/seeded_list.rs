@@ -1,8 +1,12 @@
+use crate::commons::crypto::validation::{
+    check_container_len, check_non_null, checked_size, EntityValidationError, ValidateEntity,
+    ValidateExpansion,
+};
 use crate::prelude::{CiphertextCount, GlweDimension, GlweSize, PolynomialSize};
 #[cfg(feature = "__commons_serialization")]
 use serde::{Deserialize, Serialize};
 
-use super::{GlweBody, GlweList};
+use super::{GlweBody, GlweCiphertext, GlweList};
 use crate::commons::math::random::{
     ByteRandomGenerator, CompressionSeed, RandomGenerable, RandomGenerator, Uniform,
 };
@@ -10,6 +14,7 @@ use crate::commons::math::tensor::{
     ck_dim_div, tensor_traits, AsMutSlice, AsMutTensor, AsRefSlice, AsRefTensor, Tensor,
 };
 use crate::commons::numeric::Numeric;
+use concrete_csprng::generators::SkipError;
 
 /// A list of ciphertexts encoded with the GLWE scheme.
 #[cfg_attr(feature = "__commons_serialization", derive(Serialize, Deserialize))]
@@ -379,4 +384,200 @@ impl<Cont> GlweSeededList<Cont> {
             body.as_mut_tensor().fill_with_copy(body_in.as_tensor());
         }
     }
+
+    /// Expands the ciphertext at position `index` in the list into `output`.
+    ///
+    /// The masks of the previous ciphertexts are not generated: the generator directly skips the
+    /// bytes used to generate them, which makes the cost of the expansion independent of `index`.
+    ///
+    /// An error is returned if the bytes to skip go beyond the bound of the generator.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use concrete_core::commons::crypto::glwe::{GlweCiphertext, GlweList, GlweSeededList};
+    /// use concrete_core::commons::math::random::{CompressionSeed, Seed};
+    /// use concrete_core::commons::math::tensor::{AsRefSlice, AsRefTensor};
+    /// use concrete_core::prelude::{CiphertextCount, GlweDimension, PolynomialSize};
+    /// use concrete_csprng::generators::SoftwareRandomGenerator;
+    ///
+    /// let compression_seed = CompressionSeed { seed: Seed(42) };
+    ///
+    /// let seeded_list = GlweSeededList::<Vec<u32>>::allocate(
+    ///     PolynomialSize(16),
+    ///     GlweDimension(2),
+    ///     CiphertextCount(10),
+    ///     compression_seed,
+    /// );
+    ///
+    /// let mut ciphertext =
+    ///     GlweCiphertext::allocate(0u32, seeded_list.polynomial_size(), seeded_list.glwe_size());
+    /// seeded_list
+    ///     .expand_ciphertext_into::<_, _, SoftwareRandomGenerator>(3, &mut ciphertext)
+    ///     .unwrap();
+    ///
+    /// let mut list = GlweList::allocate(
+    ///     0u32,
+    ///     seeded_list.polynomial_size(),
+    ///     seeded_list.glwe_size().to_glwe_dimension(),
+    ///     seeded_list.ciphertext_count(),
+    /// );
+    /// seeded_list.expand_into::<_, _, SoftwareRandomGenerator>(&mut list);
+    /// assert_eq!(
+    ///     list.ciphertext_iter()
+    ///         .nth(3)
+    ///         .unwrap()
+    ///         .as_tensor()
+    ///         .as_slice(),
+    ///     ciphertext.as_tensor().as_slice()
+    /// );
+    /// ```
+    pub fn expand_ciphertext_into<OutCont, Scalar, Gen>(
+        &self,
+        index: usize,
+        output: &mut GlweCiphertext<OutCont>,
+    ) -> Result<(), SkipError>
+    where
+        Self: AsRefTensor<Element = Scalar>,
+        GlweCiphertext<OutCont>: AsMutTensor<Element = Scalar>,
+        Scalar: Numeric + RandomGenerable<Uniform>,
+        Gen: ByteRandomGenerator,
+    {
+        debug_assert!(
+            index < self.ciphertext_count().0,
+            "The index is out of the list."
+        );
+        debug_assert!(
+            output.size() == self.glwe_size() && output.polynomial_size() == self.poly_size,
+            "The output has not the right GLWE size or polynomial size."
+        );
+        let mut generator = self.mask_generator_at::<Scalar, Gen>(index)?;
+        let body_in = self.body_iter().nth(index).unwrap();
+        let (mut body, mut mask) = output.get_mut_body_and_mask();
+        generator.fill_tensor_with_random_uniform(mask.as_mut_tensor());
+        body.as_mut_tensor().fill_with_copy(body_in.as_tensor());
+        Ok(())
+    }
+
+    /// Expands the ciphertexts of the list starting at position `first_index` into `output`. The
+    /// number of expanded ciphertexts is the number of ciphertexts of `output`.
+    ///
+    /// As for [`GlweSeededList::expand_ciphertext_into`], the masks of the ciphertexts preceding
+    /// `first_index` are not generated.
+    ///
+    /// An error is returned if the bytes to skip go beyond the bound of the generator.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use concrete_core::commons::crypto::glwe::{GlweList, GlweSeededList};
+    /// use concrete_core::commons::math::random::{CompressionSeed, Seed};
+    /// use concrete_core::commons::math::tensor::AsRefTensor;
+    /// use concrete_core::prelude::{CiphertextCount, GlweDimension, PolynomialSize};
+    /// use concrete_csprng::generators::SoftwareRandomGenerator;
+    ///
+    /// let compression_seed = CompressionSeed { seed: Seed(42) };
+    ///
+    /// let seeded_list = GlweSeededList::<Vec<u32>>::allocate(
+    ///     PolynomialSize(16),
+    ///     GlweDimension(2),
+    ///     CiphertextCount(10),
+    ///     compression_seed,
+    /// );
+    ///
+    /// let mut sub_list = GlweList::allocate(
+    ///     0u32,
+    ///     seeded_list.polynomial_size(),
+    ///     seeded_list.glwe_size().to_glwe_dimension(),
+    ///     CiphertextCount(4),
+    /// );
+    /// seeded_list
+    ///     .expand_sub_list_into::<_, _, SoftwareRandomGenerator>(5, &mut sub_list)
+    ///     .unwrap();
+    ///
+    /// let mut list = GlweList::allocate(
+    ///     0u32,
+    ///     seeded_list.polynomial_size(),
+    ///     seeded_list.glwe_size().to_glwe_dimension(),
+    ///     seeded_list.ciphertext_count(),
+    /// );
+    /// seeded_list.expand_into::<_, _, SoftwareRandomGenerator>(&mut list);
+    /// for (expected, ciphertext) in list
+    ///     .ciphertext_iter()
+    ///     .skip(5)
+    ///     .zip(sub_list.ciphertext_iter())
+    /// {
+    ///     assert_eq!(expected.as_tensor(), ciphertext.as_tensor());
+    /// }
+    /// ```
+    pub fn expand_sub_list_into<OutCont, Scalar, Gen>(
+        &self,
+        first_index: usize,
+        output: &mut GlweList<OutCont>,
+    ) -> Result<(), SkipError>
+    where
+        Self: AsRefTensor<Element = Scalar>,
+        GlweList<OutCont>: AsMutTensor<Element = Scalar>,
+        Scalar: Numeric + RandomGenerable<Uniform>,
+        Gen: ByteRandomGenerator,
+    {
+        debug_assert!(
+            first_index + output.ciphertext_count().0 <= self.ciphertext_count().0,
+            "The output range is out of the list."
+        );
+        debug_assert!(
+            output.glwe_size() == self.glwe_size() && output.polynomial_size() == self.poly_size,
+            "The output has not the right GLWE size or polynomial size."
+        );
+        let mut generator = self.mask_generator_at::<Scalar, Gen>(first_index)?;
+        for (mut glwe_out, body_in) in output
+            .ciphertext_iter_mut()
+            .zip(self.body_iter().skip(first_index))
+        {
+            let (mut body, mut mask) = glwe_out.get_mut_body_and_mask();
+            generator.fill_tensor_with_random_uniform(mask.as_mut_tensor());
+            body.as_mut_tensor().fill_with_copy(body_in.as_tensor());
+        }
+        Ok(())
+    }
+
+    // Returns a generator positioned on the first byte used to generate the mask of the `index`-th
+    // ciphertext of the list, each mask containing `glwe_dimension * poly_size` elements.
+    fn mask_generator_at<Scalar, Gen>(
+        &self,
+        index: usize,
+    ) -> Result<RandomGenerator<Gen>, SkipError>
+    where
+        Gen: ByteRandomGenerator,
+    {
+        let n_bytes = index
+            .checked_mul(self.glwe_dimension.0)
+            .and_then(|n_polynomials| n_polynomials.checked_mul(self.poly_size.0))
+            .and_then(|n_elements| n_elements.checked_mul(std::mem::size_of::<Scalar>()))
+            .ok_or(SkipError::SkipTooLarge)?;
+        let mut generator = RandomGenerator::<Gen>::new(self.compression_seed.seed);
+        generator.try_skip(n_bytes)?;
+        Ok(generator)
+    }
+}
+
+impl<Cont> ValidateEntity for GlweSeededList<Cont>
+where
+    Cont: AsRefSlice,
+{
+    fn validate(&self) -> Result<(), EntityValidationError> {
+        check_non_null(&[self.glwe_dimension.0])?;
+        check_container_len(self.tensor.len(), &[self.poly_size.0])?;
+        Ok(())
+    }
+}
+
+impl<Cont> ValidateExpansion for GlweSeededList<Cont>
+where
+    Cont: AsRefSlice,
+{
+    fn expanded_size(&self) -> Option<usize> {
+        // Each body polynomial is expanded into a ciphertext.
+        checked_size::<Cont::Element>(&[self.tensor.len(), self.glwe_dimension.0.checked_add(1)?])
+    }
 }
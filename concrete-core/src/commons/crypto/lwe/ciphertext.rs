@@ -2,10 +2,15 @@ use super::LweList;
 use crate::commons::crypto::encoding::{Cleartext, CleartextList, Plaintext};
 use crate::commons::crypto::glwe::GlweCiphertext;
 use crate::commons::crypto::secret::LweSecretKey;
-use crate::commons::math::tensor::{tensor_traits, AsMutTensor, AsRefTensor, Container, Tensor};
+use crate::commons::crypto::validation::{check_non_null, EntityValidationError, ValidateEntity};
+use crate::commons::math::tensor::{
+    tensor_traits, AsMutTensor, AsRefSlice, AsRefTensor, Container, Tensor,
+};
 use crate::commons::math::torus::UnsignedTorus;
 use crate::commons::numeric::{Numeric, UnsignedInteger};
 use crate::prelude::{KeyKind, LweDimension, LweSize, MonomialDegree};
+#[cfg(feature = "__commons_parallel")]
+use rayon::prelude::*;
 #[cfg(feature = "__commons_serialization")]
 use serde::{Deserialize, Serialize};
 
@@ -407,6 +412,50 @@ impl<Cont> LweCiphertext<Cont> {
         *self.get_mut_body() = LweBody(new_body);
     }
 
+    /// Fills the current ciphertext with the result of a multisum between an LWE list and a
+    /// cleartext list, plus a bias, the products being computed in parallel.
+    ///
+    /// The partial sums computed by the different threads are added with wrapping arithmetic,
+    /// which makes the output the same as the one of
+    /// [`LweCiphertext::fill_with_multisum_with_bias`].
+    #[cfg(feature = "__commons_parallel")]
+    pub fn par_fill_with_multisum_with_bias<Scalar, InputCont, WeightCont>(
+        &mut self,
+        input_list: &LweList<InputCont>,
+        weights: &CleartextList<WeightCont>,
+        bias: &Plaintext<Scalar>,
+    ) where
+        Self: AsMutTensor<Element = Scalar>,
+        LweList<InputCont>: AsRefTensor<Element = Scalar>,
+        CleartextList<WeightCont>: AsRefTensor<Element = Scalar>,
+        Scalar: UnsignedInteger + Send + Sync,
+    {
+        let lwe_size = input_list.lwe_size();
+        let zero = || LweCiphertext::allocate(Scalar::ZERO, lwe_size);
+        let result = input_list
+            .par_ciphertext_iter()
+            .zip(weights.as_tensor().par_iter())
+            .fold(zero, |mut acc, (input_cipher, weight)| {
+                acc.as_mut_tensor()
+                    .update_with_one(input_cipher.as_tensor(), |o, c| {
+                        *o = o.wrapping_add(c.wrapping_mul(*weight))
+                    });
+                acc
+            })
+            .reduce(zero, |mut acc, other| {
+                acc.as_mut_tensor()
+                    .update_with_wrapping_add(other.as_tensor());
+                acc
+            });
+
+        self.as_mut_tensor()
+            .update_with_wrapping_add(result.as_tensor());
+
+        // add the bias
+        let new_body = (self.get_body().0).wrapping_add(bias.0);
+        *self.get_mut_body() = LweBody(new_body);
+    }
+
     /// Adds the `other` ciphertext to the current one.
     ///
     /// # Example
@@ -818,3 +867,13 @@ impl<Cont> LweMask<Cont> {
 #[derive(Debug, Copy, Clone, PartialEq, Eq)]
 #[repr(transparent)]
 pub struct LweBody<T>(pub T);
+
+impl<Cont> ValidateEntity for LweCiphertext<Cont>
+where
+    Cont: AsRefSlice,
+{
+    fn validate(&self) -> Result<(), EntityValidationError> {
+        // The container holds the mask followed by the body, hence the mask must not be empty.
+        check_non_null(&[self.tensor.len().saturating_sub(1)])
+    }
+}
@@ -0,0 +1,110 @@
+use super::LweCiphertext;
+use crate::commons::crypto::validation::{check_non_null, EntityValidationError, ValidateEntity};
+use crate::commons::math::packing::PackedIntegers;
+use crate::commons::math::tensor::{AsMutSlice, AsMutTensor, AsRefSlice, AsRefTensor};
+use crate::commons::numeric::{CastFrom, CastInto, UnsignedInteger};
+use crate::prelude::{CiphertextModulusLog, LweDimension, LweSize};
+#[cfg(feature = "__commons_serialization")]
+use serde::{Deserialize, Serialize};
+use std::marker::PhantomData;
+
+/// An LWE ciphertext whose coefficients were switched to a smaller modulus and bit-packed.
+///
+/// The mask and the body of the ciphertext are rounded to their `ciphertext_modulus_log` most
+/// significant bits, which adds some noise to the ciphertext, and stored with only these bits.
+#[cfg_attr(feature = "__commons_serialization", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LweCompressedCiphertext<Scalar> {
+    pub(crate) coefficients: PackedIntegers,
+    pub(crate) lwe_dimension: LweDimension,
+    pub(crate) _scalar: PhantomData<Scalar>,
+}
+
+impl<Scalar> LweCompressedCiphertext<Scalar>
+where
+    Scalar: UnsignedInteger + CastInto<u64> + CastFrom<u64>,
+{
+    /// Compresses a ciphertext, by switching its coefficients to the modulus
+    /// $2^{\text{ciphertext\_modulus\_log}}$.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use concrete_core::commons::crypto::lwe::{LweCiphertext, LweCompressedCiphertext};
+    /// use concrete_core::prelude::{CiphertextModulusLog, LweDimension, LweSize};
+    ///
+    /// let ciphertext = LweCiphertext::from_container(vec![0x1280_0000_u32; 11]);
+    /// let compressed = LweCompressedCiphertext::compress(&ciphertext, CiphertextModulusLog(8));
+    /// assert_eq!(compressed.lwe_dimension(), LweDimension(10));
+    /// assert_eq!(compressed.ciphertext_modulus_log(), CiphertextModulusLog(8));
+    ///
+    /// let mut decompressed = LweCiphertext::allocate(0_u32, LweSize(11));
+    /// compressed.decompress_into(&mut decompressed);
+    /// assert_eq!(decompressed.into_container(), vec![0x1300_0000_u32; 11]);
+    /// ```
+    pub fn compress<Cont>(
+        input: &LweCiphertext<Cont>,
+        ciphertext_modulus_log: CiphertextModulusLog,
+    ) -> Self
+    where
+        LweCiphertext<Cont>: AsRefTensor<Element = Scalar>,
+    {
+        LweCompressedCiphertext {
+            coefficients: PackedIntegers::pack_modulus_switched(
+                input.as_tensor().as_slice(),
+                ciphertext_modulus_log.0,
+            ),
+            lwe_dimension: input.lwe_size().to_lwe_dimension(),
+            _scalar: PhantomData,
+        }
+    }
+
+    /// Decompresses the ciphertext into `output`, by switching its coefficients back to the
+    /// modulus of `Scalar`.
+    pub fn decompress_into<Cont>(&self, output: &mut LweCiphertext<Cont>)
+    where
+        LweCiphertext<Cont>: AsMutTensor<Element = Scalar>,
+    {
+        debug_assert!(
+            output.lwe_size() == self.lwe_size(),
+            "The output has not the right LWE size."
+        );
+        self.coefficients
+            .unpack_modulus_switched_into(output.as_mut_tensor().as_mut_slice());
+    }
+}
+
+impl<Scalar> LweCompressedCiphertext<Scalar> {
+    /// Returns the LWE dimension of the ciphertext.
+    pub fn lwe_dimension(&self) -> LweDimension {
+        self.lwe_dimension
+    }
+
+    /// Returns the size of the ciphertext, e.g. the size of the mask + 1 for the body.
+    pub fn lwe_size(&self) -> LweSize {
+        self.lwe_dimension.to_lwe_size()
+    }
+
+    /// Returns the logarithm of the modulus the coefficients were switched to.
+    pub fn ciphertext_modulus_log(&self) -> CiphertextModulusLog {
+        CiphertextModulusLog(self.coefficients.bit_width())
+    }
+
+    /// Returns the packed coefficients of the ciphertext.
+    pub fn as_packed_integers(&self) -> &PackedIntegers {
+        &self.coefficients
+    }
+}
+
+impl<Scalar: UnsignedInteger> ValidateEntity for LweCompressedCiphertext<Scalar> {
+    fn validate(&self) -> Result<(), EntityValidationError> {
+        check_non_null(&[self.lwe_dimension.0])?;
+        if !self.coefficients.is_consistent() || self.coefficients.bit_width() > Scalar::BITS {
+            return Err(EntityValidationError::InvalidContainerSize);
+        }
+        if self.coefficients.len() != self.lwe_size().0 {
+            return Err(EntityValidationError::InvalidContainerSize);
+        }
+        Ok(())
+    }
+}
@@ -2,6 +2,9 @@ use super::{LweCiphertext, LweList};
 use crate::commons::crypto::encoding::{Plaintext, PlaintextList};
 use crate::commons::crypto::secret::generators::EncryptionRandomGenerator;
 use crate::commons::crypto::secret::LweSecretKey;
+use crate::commons::crypto::validation::{
+    check_container_len, check_decomposition, check_non_null, EntityValidationError, ValidateEntity,
+};
 use crate::commons::math::decomposition::{
     DecompositionLevel, DecompositionTerm, SignedDecomposer,
 };
@@ -10,10 +13,13 @@ use crate::commons::math::tensor::{
     ck_dim_div, ck_dim_eq, tensor_traits, AsMutTensor, AsRefSlice, AsRefTensor, Container, Tensor,
 };
 use crate::commons::math::torus::UnsignedTorus;
+use crate::commons::numeric::Numeric;
 use crate::prelude::{
     BinaryKeyKind, CiphertextCount, DecompositionBaseLog, DecompositionLevelCount,
     DispersionParameter, LweDimension, LweSize,
 };
+#[cfg(feature = "__commons_parallel")]
+use rayon::{iter::IndexedParallelIterator, prelude::*};
 #[cfg(feature = "__commons_serialization")]
 use serde::{Deserialize, Serialize};
 
@@ -393,13 +399,15 @@ impl<Cont> LweKeyswitchKey<Cont> {
                 );
             }
 
-            // We encrypt the buffer
-            after_key.encrypt_lwe_list(
-                &mut keyswitch_key_block.into_lwe_list(),
-                &messages,
-                noise_parameters,
-                generator,
-            );
+            // We encrypt the buffer, drawing from the generator in the same order as the seeded
+            // keyswitch key generation
+            for (mut cipher, message) in keyswitch_key_block
+                .into_lwe_list()
+                .ciphertext_iter_mut()
+                .zip(messages.plaintext_iter())
+            {
+                after_key.encrypt_lwe(&mut cipher, message, noise_parameters, generator);
+            }
         }
     }
 
@@ -593,6 +601,31 @@ impl<Cont> LweKeyswitchKey<Cont> {
             self.keyswitch_ciphertext(&mut output_cipher, &input_cipher);
         }
     }
+
+    /// Keyswitches a list of ciphertexts in parallel.
+    ///
+    /// Every ciphertext being keyswitched independently, the output is the same as the one of
+    /// [`LweKeyswitchKey::keyswitch_list`].
+    #[cfg(feature = "__commons_parallel")]
+    pub fn par_keyswitch_list<InCont, OutCont, Scalar>(
+        &self,
+        output: &mut LweList<OutCont>,
+        input: &LweList<InCont>,
+    ) where
+        Self: AsRefTensor<Element = Scalar>,
+        LweList<InCont>: AsRefTensor<Element = Scalar>,
+        LweList<OutCont>: AsMutTensor<Element = Scalar>,
+        Scalar: UnsignedTorus + Send + Sync,
+        Cont: Sync,
+    {
+        ck_dim_eq!(input.count().0 => output.count().0);
+        output
+            .par_ciphertext_iter_mut()
+            .zip(input.par_ciphertext_iter())
+            .for_each(|(mut output_cipher, input_cipher)| {
+                self.keyswitch_ciphertext(&mut output_cipher, &input_cipher);
+            });
+    }
 }
 
 /// The encryption of a single bit of the output key.
@@ -735,3 +768,19 @@ impl<Cont> LweKeyBitDecomposition<Cont> {
         }
     }
 }
+
+impl<Cont, Scalar> ValidateEntity for LweKeyswitchKey<Cont>
+where
+    Cont: AsRefSlice<Element = Scalar>,
+    Scalar: Numeric,
+{
+    fn validate(&self) -> Result<(), EntityValidationError> {
+        check_decomposition::<Scalar>(self.decomp_base_log, self.decomp_level_count)?;
+        check_non_null(&[self.lwe_size.0.saturating_sub(1)])?;
+        check_container_len(
+            self.tensor.len(),
+            &[self.lwe_size.0, self.decomp_level_count.0],
+        )?;
+        Ok(())
+    }
+}
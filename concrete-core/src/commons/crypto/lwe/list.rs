@@ -1,5 +1,8 @@
 use super::LweCiphertext;
 use crate::commons::crypto::encoding::{CleartextList, PlaintextList};
+use crate::commons::crypto::validation::{
+    check_container_len, check_non_null, EntityValidationError, ValidateEntity,
+};
 use crate::commons::math::tensor::{
     ck_dim_div, tensor_traits, AsMutTensor, AsRefSlice, AsRefTensor, Container, Tensor,
 };
@@ -496,3 +499,14 @@ impl<Cont> LweList<Cont> {
         }
     }
 }
+
+impl<Cont> ValidateEntity for LweList<Cont>
+where
+    Cont: AsRefSlice,
+{
+    fn validate(&self) -> Result<(), EntityValidationError> {
+        check_non_null(&[self.lwe_size.0.saturating_sub(1)])?;
+        check_container_len(self.tensor.len(), &[self.lwe_size.0])?;
+        Ok(())
+    }
+}
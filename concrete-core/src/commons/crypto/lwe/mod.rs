@@ -1,5 +1,6 @@
 //! LWE encryption scheme.
 mod ciphertext;
+mod compressed_ciphertext;
 mod keyswitch;
 mod list;
 mod seeded_ciphertext;
@@ -7,6 +8,7 @@ mod seeded_keyswitch;
 mod seeded_list;
 
 pub use ciphertext::*;
+pub use compressed_ciphertext::*;
 pub use keyswitch::*;
 pub use list::*;
 pub use seeded_ciphertext::*;
@@ -15,6 +17,12 @@ pub use seeded_list::*;
 
 #[cfg(test)]
 mod test {
+    #[cfg(feature = "__commons_parallel")]
+    use crate::commons::crypto::encoding::{CleartextList, Plaintext, PlaintextList};
+    #[cfg(feature = "__commons_parallel")]
+    use crate::commons::crypto::lwe::LweCiphertext;
+    #[cfg(feature = "__commons_parallel")]
+    use crate::commons::crypto::lwe::LweList;
     use crate::commons::crypto::lwe::{LweKeyswitchKey, LweSeededKeyswitchKey};
     use crate::commons::crypto::secret::generators::{
         DeterministicSeeder, EncryptionRandomGenerator,
@@ -23,6 +31,13 @@ mod test {
     use crate::commons::math::random::CompressionSeed;
     use crate::commons::math::torus::UnsignedTorus;
     use crate::commons::test_tools::new_secret_random_generator;
+    #[cfg(feature = "__commons_parallel")]
+    use crate::commons::test_tools::{
+        any_uint, any_usize, assert_delta_std_dev, new_encryption_random_generator,
+        random_usize_between,
+    };
+    #[cfg(feature = "__commons_parallel")]
+    use crate::prelude::{CiphertextCount, LogStandardDev, PlaintextCount};
     use crate::prelude::{
         DecompositionBaseLog, DecompositionLevelCount, LweDimension, StandardDev,
     };
@@ -100,4 +115,195 @@ mod test {
     fn test_ksk_seeded_gen_equivalence_u64() {
         test_ksk_seeded_gen_equivalence::<u64>()
     }
+
+    #[cfg(feature = "__commons_parallel")]
+    fn new_deterministic_encryption_generator(
+        seed: Seed,
+    ) -> EncryptionRandomGenerator<SoftwareRandomGenerator> {
+        EncryptionRandomGenerator::new(
+            seed,
+            &mut DeterministicSeeder::<SoftwareRandomGenerator>::new(seed),
+        )
+    }
+
+    #[cfg(feature = "__commons_parallel")]
+    fn random_plaintext_list<T: UnsignedTorus>(count: CiphertextCount) -> PlaintextList<Vec<T>> {
+        PlaintextList::from_container((0..count.0).map(|_| any_uint::<T>()).collect::<Vec<_>>())
+    }
+
+    #[cfg(feature = "__commons_parallel")]
+    fn test_par_encrypt_lwe_list_equivalence<T: UnsignedTorus + Send + Sync>() {
+        // The sequential and parallel encryptions fork the generator the same way, and output the
+        // same ciphertexts for the same seed.
+        for _ in 0..10 {
+            let lwe_dim = LweDimension(random_usize_between(5..10));
+            let count = CiphertextCount(random_usize_between(1..10));
+            let seed = Seed(any_usize() as u128);
+            let noise = LogStandardDev::from_log_standard_dev(-15.);
+
+            let key = LweSecretKey::generate_binary(lwe_dim, &mut new_secret_random_generator());
+            let plaintexts = random_plaintext_list::<T>(count);
+
+            let mut list = LweList::allocate(T::ZERO, lwe_dim.to_lwe_size(), count);
+            key.encrypt_lwe_list(
+                &mut list,
+                &plaintexts,
+                noise,
+                &mut new_deterministic_encryption_generator(seed),
+            );
+
+            let mut par_list = LweList::allocate(T::ZERO, lwe_dim.to_lwe_size(), count);
+            key.par_encrypt_lwe_list(
+                &mut par_list,
+                &plaintexts,
+                noise,
+                &mut new_deterministic_encryption_generator(seed),
+            );
+
+            assert_eq!(list, par_list);
+        }
+    }
+
+    #[cfg(feature = "__commons_parallel")]
+    #[test]
+    fn test_par_encrypt_lwe_list_equivalence_u32() {
+        test_par_encrypt_lwe_list_equivalence::<u32>()
+    }
+
+    #[cfg(feature = "__commons_parallel")]
+    #[test]
+    fn test_par_encrypt_lwe_list_equivalence_u64() {
+        test_par_encrypt_lwe_list_equivalence::<u64>()
+    }
+
+    #[cfg(feature = "__commons_parallel")]
+    fn test_par_encrypt_decrypt_lwe_list<T: UnsignedTorus + Send + Sync>() {
+        for _ in 0..10 {
+            let lwe_dim = LweDimension(random_usize_between(5..10));
+            let count = CiphertextCount(random_usize_between(1..10));
+            let noise = LogStandardDev::from_log_standard_dev(-15.);
+
+            let key = LweSecretKey::generate_binary(lwe_dim, &mut new_secret_random_generator());
+            let plaintexts = random_plaintext_list::<T>(count);
+
+            let mut list = LweList::allocate(T::ZERO, lwe_dim.to_lwe_size(), count);
+            key.par_encrypt_lwe_list(
+                &mut list,
+                &plaintexts,
+                noise,
+                &mut new_encryption_random_generator(),
+            );
+
+            let mut decrypted = PlaintextList::allocate(T::ZERO, PlaintextCount(count.0));
+            key.par_decrypt_lwe_list(&mut decrypted, &list);
+            let mut sequentially_decrypted =
+                PlaintextList::allocate(T::ZERO, PlaintextCount(count.0));
+            key.decrypt_lwe_list(&mut sequentially_decrypted, &list);
+
+            assert_eq!(decrypted, sequentially_decrypted);
+            assert_delta_std_dev(&plaintexts, &decrypted, noise);
+        }
+    }
+
+    #[cfg(feature = "__commons_parallel")]
+    #[test]
+    fn test_par_encrypt_decrypt_lwe_list_u32() {
+        test_par_encrypt_decrypt_lwe_list::<u32>()
+    }
+
+    #[cfg(feature = "__commons_parallel")]
+    #[test]
+    fn test_par_encrypt_decrypt_lwe_list_u64() {
+        test_par_encrypt_decrypt_lwe_list::<u64>()
+    }
+
+    #[cfg(feature = "__commons_parallel")]
+    fn test_par_keyswitch_list_equivalence<T: UnsignedTorus + Send + Sync>() {
+        for _ in 0..10 {
+            let input_lwe_dim = LweDimension(random_usize_between(5..10));
+            let output_lwe_dim = LweDimension(random_usize_between(5..10));
+            let level = DecompositionLevelCount(random_usize_between(2..5));
+            let base_log = DecompositionBaseLog(random_usize_between(2..5));
+            let count = CiphertextCount(random_usize_between(1..10));
+            let noise = LogStandardDev::from_log_standard_dev(-15.);
+
+            let mut secret_generator = new_secret_random_generator();
+            let input_key = LweSecretKey::generate_binary(input_lwe_dim, &mut secret_generator);
+            let output_key = LweSecretKey::generate_binary(output_lwe_dim, &mut secret_generator);
+            let mut encryption_generator = new_encryption_random_generator();
+
+            let mut ksk =
+                LweKeyswitchKey::allocate(T::ZERO, level, base_log, input_lwe_dim, output_lwe_dim);
+            ksk.fill_with_keyswitch_key(&input_key, &output_key, noise, &mut encryption_generator);
+
+            let mut input = LweList::allocate(T::ZERO, input_lwe_dim.to_lwe_size(), count);
+            input_key.encrypt_lwe_list(
+                &mut input,
+                &random_plaintext_list::<T>(count),
+                noise,
+                &mut encryption_generator,
+            );
+
+            let mut output = LweList::allocate(T::ZERO, output_lwe_dim.to_lwe_size(), count);
+            ksk.par_keyswitch_list(&mut output, &input);
+            let mut expected_output =
+                LweList::allocate(T::ZERO, output_lwe_dim.to_lwe_size(), count);
+            ksk.keyswitch_list(&mut expected_output, &input);
+
+            assert_eq!(output, expected_output);
+        }
+    }
+
+    #[cfg(feature = "__commons_parallel")]
+    #[test]
+    fn test_par_keyswitch_list_equivalence_u32() {
+        test_par_keyswitch_list_equivalence::<u32>()
+    }
+
+    #[cfg(feature = "__commons_parallel")]
+    #[test]
+    fn test_par_keyswitch_list_equivalence_u64() {
+        test_par_keyswitch_list_equivalence::<u64>()
+    }
+
+    #[cfg(feature = "__commons_parallel")]
+    fn test_par_multisum_equivalence<T: UnsignedTorus + Send + Sync>() {
+        for _ in 0..10 {
+            let lwe_size = LweDimension(random_usize_between(5..10)).to_lwe_size();
+            let count = CiphertextCount(random_usize_between(1..100));
+
+            let input = LweList::from_container(
+                (0..lwe_size.0 * count.0)
+                    .map(|_| any_uint::<T>())
+                    .collect::<Vec<_>>(),
+                lwe_size,
+            );
+            let weights = CleartextList::from_container(
+                (0..count.0).map(|_| any_uint::<T>()).collect::<Vec<_>>(),
+            );
+            let bias = Plaintext(any_uint::<T>());
+            let initial_output = LweCiphertext::from_container(
+                (0..lwe_size.0).map(|_| any_uint::<T>()).collect::<Vec<_>>(),
+            );
+
+            let mut output = initial_output.clone();
+            output.par_fill_with_multisum_with_bias(&input, &weights, &bias);
+            let mut expected_output = initial_output;
+            expected_output.fill_with_multisum_with_bias(&input, &weights, &bias);
+
+            assert_eq!(output, expected_output);
+        }
+    }
+
+    #[cfg(feature = "__commons_parallel")]
+    #[test]
+    fn test_par_multisum_equivalence_u32() {
+        test_par_multisum_equivalence::<u32>()
+    }
+
+    #[cfg(feature = "__commons_parallel")]
+    #[test]
+    fn test_par_multisum_equivalence_u64() {
+        test_par_multisum_equivalence::<u64>()
+    }
 }
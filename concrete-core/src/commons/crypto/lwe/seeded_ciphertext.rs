@@ -1,3 +1,6 @@
+use crate::commons::crypto::validation::{
+    check_non_null, checked_size, EntityValidationError, ValidateEntity, ValidateExpansion,
+};
 #[cfg(feature = "__commons_serialization")]
 use serde::{Deserialize, Serialize};
 
@@ -177,3 +180,15 @@ impl<Scalar: Numeric> LweSeededCiphertext<Scalar> {
         output_body.0 = self.body.0;
     }
 }
+
+impl<Scalar> ValidateEntity for LweSeededCiphertext<Scalar> {
+    fn validate(&self) -> Result<(), EntityValidationError> {
+        check_non_null(&[self.lwe_dimension.0])
+    }
+}
+
+impl<Scalar> ValidateExpansion for LweSeededCiphertext<Scalar> {
+    fn expanded_size(&self) -> Option<usize> {
+        checked_size::<Scalar>(&[self.lwe_dimension.0.checked_add(1)?])
+    }
+}
@@ -1,3 +1,7 @@
+use crate::commons::crypto::validation::{
+    check_container_len, check_decomposition, check_non_null, checked_size, EntityValidationError,
+    ValidateEntity, ValidateExpansion,
+};
 #[cfg(feature = "__commons_serialization")]
 use serde::{Deserialize, Serialize};
 
@@ -539,3 +543,28 @@ impl<Cont> SeededLweKeyBitDecomposition<Cont> {
         )
     }
 }
+
+impl<Cont, Scalar> ValidateEntity for LweSeededKeyswitchKey<Cont>
+where
+    Cont: AsRefSlice<Element = Scalar>,
+    Scalar: Numeric,
+{
+    fn validate(&self) -> Result<(), EntityValidationError> {
+        check_decomposition::<Scalar>(self.decomp_base_log, self.decomp_level_count)?;
+        check_non_null(&[self.lwe_size.0.saturating_sub(1)])?;
+        // Only the bodies are stored, one per level and per input key element.
+        check_container_len(self.tensor.len(), &[self.decomp_level_count.0])?;
+        Ok(())
+    }
+}
+
+impl<Cont, Scalar> ValidateExpansion for LweSeededKeyswitchKey<Cont>
+where
+    Cont: AsRefSlice<Element = Scalar>,
+    Scalar: Numeric,
+{
+    fn expanded_size(&self) -> Option<usize> {
+        // Each body is expanded into a ciphertext of the output key.
+        checked_size::<Scalar>(&[self.tensor.len(), self.lwe_size.0])
+    }
+}
@@ -1,14 +1,18 @@
+use crate::commons::crypto::validation::{
+    check_non_null, checked_size, EntityValidationError, ValidateEntity, ValidateExpansion,
+};
 #[cfg(feature = "__commons_serialization")]
 use serde::{Deserialize, Serialize};
 
 use crate::commons::numeric::Numeric;
 use crate::prelude::{CiphertextCount, LweDimension, LweSize};
 
-use crate::commons::crypto::lwe::LweList;
+use crate::commons::crypto::lwe::{LweCiphertext, LweList};
 use crate::commons::math::random::{
     ByteRandomGenerator, CompressionSeed, RandomGenerable, RandomGenerator, Uniform,
 };
 use crate::commons::math::tensor::{tensor_traits, AsMutTensor, AsRefSlice, AsRefTensor, Tensor};
+use concrete_csprng::generators::SkipError;
 
 use super::LweBody;
 
@@ -289,4 +293,170 @@ impl<Cont> LweSeededList<Cont> {
 
         self.expand_into_with_existing_generator(output, &mut generator);
     }
+
+    /// Expands the ciphertext at position `index` in the list into `output`.
+    ///
+    /// The masks of the previous ciphertexts are not generated: the generator directly skips the
+    /// bytes used to generate them, which makes the cost of the expansion independent of `index`.
+    ///
+    /// An error is returned if the bytes to skip go beyond the bound of the generator.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use concrete_core::commons::crypto::lwe::{LweCiphertext, LweList, LweSeededList};
+    /// use concrete_core::commons::math::random::{CompressionSeed, Seed};
+    /// use concrete_core::commons::math::tensor::{AsRefSlice, AsRefTensor};
+    /// use concrete_core::prelude::{CiphertextCount, LweDimension};
+    /// use concrete_csprng::generators::SoftwareRandomGenerator;
+    ///
+    /// let compression_seed = CompressionSeed { seed: Seed(42) };
+    ///
+    /// let seeded_list =
+    ///     LweSeededList::<Vec<u32>>::allocate(LweDimension(9), CiphertextCount(20), compression_seed);
+    ///
+    /// let mut ciphertext = LweCiphertext::allocate(0u32, seeded_list.lwe_size());
+    /// seeded_list
+    ///     .expand_ciphertext_into::<_, _, SoftwareRandomGenerator>(7, &mut ciphertext)
+    ///     .unwrap();
+    ///
+    /// let mut list = LweList::allocate(0u32, seeded_list.lwe_size(), seeded_list.count());
+    /// seeded_list.expand_into::<_, _, SoftwareRandomGenerator>(&mut list);
+    /// assert_eq!(
+    ///     list.ciphertext_iter()
+    ///         .nth(7)
+    ///         .unwrap()
+    ///         .as_tensor()
+    ///         .as_slice(),
+    ///     ciphertext.as_tensor().as_slice()
+    /// );
+    /// ```
+    pub fn expand_ciphertext_into<OutCont, Scalar, Gen>(
+        &self,
+        index: usize,
+        output: &mut LweCiphertext<OutCont>,
+    ) -> Result<(), SkipError>
+    where
+        LweCiphertext<OutCont>: AsMutTensor<Element = Scalar>,
+        Self: AsRefTensor<Element = Scalar>,
+        Scalar: RandomGenerable<Uniform> + Numeric,
+        Gen: ByteRandomGenerator,
+    {
+        debug_assert!(index < self.count().0, "The index is out of the list.");
+        debug_assert!(
+            output.lwe_size() == self.lwe_size(),
+            "The output has not the right LWE size."
+        );
+        let mut generator = self.mask_generator_at::<Scalar, Gen>(index)?;
+        let (output_body, mut output_mask) = output.get_mut_body_and_mask();
+        generator.fill_tensor_with_random_uniform(output_mask.as_mut_tensor());
+        output_body.0 = *self.as_tensor().get_element(index);
+        Ok(())
+    }
+
+    /// Expands the ciphertexts of the list starting at position `first_index` into `output`. The
+    /// number of expanded ciphertexts is the number of ciphertexts of `output`.
+    ///
+    /// As for [`LweSeededList::expand_ciphertext_into`], the masks of the ciphertexts preceding
+    /// `first_index` are not generated.
+    ///
+    /// An error is returned if the bytes to skip go beyond the bound of the generator.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use concrete_core::commons::crypto::lwe::{LweList, LweSeededList};
+    /// use concrete_core::commons::math::random::{CompressionSeed, Seed};
+    /// use concrete_core::commons::math::tensor::AsRefTensor;
+    /// use concrete_core::prelude::{CiphertextCount, LweDimension};
+    /// use concrete_csprng::generators::SoftwareRandomGenerator;
+    ///
+    /// let compression_seed = CompressionSeed { seed: Seed(42) };
+    ///
+    /// let seeded_list =
+    ///     LweSeededList::<Vec<u32>>::allocate(LweDimension(9), CiphertextCount(20), compression_seed);
+    ///
+    /// let mut sub_list = LweList::allocate(0u32, seeded_list.lwe_size(), CiphertextCount(5));
+    /// seeded_list
+    ///     .expand_sub_list_into::<_, _, SoftwareRandomGenerator>(12, &mut sub_list)
+    ///     .unwrap();
+    ///
+    /// let mut list = LweList::allocate(0u32, seeded_list.lwe_size(), seeded_list.count());
+    /// seeded_list.expand_into::<_, _, SoftwareRandomGenerator>(&mut list);
+    /// for (expected, ciphertext) in list
+    ///     .ciphertext_iter()
+    ///     .skip(12)
+    ///     .zip(sub_list.ciphertext_iter())
+    /// {
+    ///     assert_eq!(expected.as_tensor(), ciphertext.as_tensor());
+    /// }
+    /// ```
+    pub fn expand_sub_list_into<OutCont, Scalar, Gen>(
+        &self,
+        first_index: usize,
+        output: &mut LweList<OutCont>,
+    ) -> Result<(), SkipError>
+    where
+        LweList<OutCont>: AsMutTensor<Element = Scalar>,
+        Self: AsRefTensor<Element = Scalar>,
+        Scalar: RandomGenerable<Uniform> + Numeric,
+        Gen: ByteRandomGenerator,
+    {
+        debug_assert!(
+            first_index + output.count().0 <= self.count().0,
+            "The output range is out of the list."
+        );
+        debug_assert!(
+            output.lwe_size() == self.lwe_size(),
+            "The output has not the right LWE size."
+        );
+        let mut generator = self.mask_generator_at::<Scalar, Gen>(first_index)?;
+        for (mut lwe_out, body_in) in output
+            .ciphertext_iter_mut()
+            .zip(self.body_iter().skip(first_index))
+        {
+            let (output_body, mut output_mask) = lwe_out.get_mut_body_and_mask();
+            generator.fill_tensor_with_random_uniform(output_mask.as_mut_tensor());
+            output_body.0 = body_in.0;
+        }
+        Ok(())
+    }
+
+    // Returns a generator positioned on the first byte used to generate the mask of the `index`-th
+    // ciphertext of the list. The masks being generated sequentially, each mask element consuming
+    // `size_of::<Scalar>()` bytes, this is the byte `index * lwe_dimension * size_of::<Scalar>()`.
+    fn mask_generator_at<Scalar, Gen>(
+        &self,
+        index: usize,
+    ) -> Result<RandomGenerator<Gen>, SkipError>
+    where
+        Gen: ByteRandomGenerator,
+    {
+        let n_bytes = index
+            .checked_mul(self.lwe_dimension.0)
+            .and_then(|n_elements| n_elements.checked_mul(std::mem::size_of::<Scalar>()))
+            .ok_or(SkipError::SkipTooLarge)?;
+        let mut generator = RandomGenerator::<Gen>::new(self.compression_seed.seed);
+        generator.try_skip(n_bytes)?;
+        Ok(generator)
+    }
+}
+
+impl<Cont> ValidateEntity for LweSeededList<Cont>
+where
+    Cont: AsRefSlice,
+{
+    fn validate(&self) -> Result<(), EntityValidationError> {
+        check_non_null(&[self.lwe_dimension.0, self.tensor.len()])
+    }
+}
+
+impl<Cont> ValidateExpansion for LweSeededList<Cont>
+where
+    Cont: AsRefSlice,
+{
+    fn expanded_size(&self) -> Option<usize> {
+        // Each body is expanded into a ciphertext.
+        checked_size::<Cont::Element>(&[self.tensor.len(), self.lwe_dimension.0.checked_add(1)?])
+    }
 }
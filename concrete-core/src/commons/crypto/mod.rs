@@ -9,3 +9,4 @@ pub mod glwe;
 pub mod gsw;
 pub mod lwe;
 pub mod secret;
+pub mod validation;
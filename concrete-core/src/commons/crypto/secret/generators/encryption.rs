@@ -1,7 +1,8 @@
 #[cfg(feature = "__commons_parallel")]
 use crate::commons::math::random::ParallelByteRandomGenerator;
 use crate::commons::math::random::{
-    ByteRandomGenerator, Gaussian, RandomGenerable, RandomGenerator, Seed, Seeder, Uniform,
+    ByteRandomGenerator, Gaussian, RandomGenerable, RandomGenerator, Seed, Seeder,
+    SnapshotableByteRandomGenerator, Uniform,
 };
 use crate::commons::math::tensor::AsMutTensor;
 
@@ -10,9 +11,21 @@ use crate::prelude::{
     DecompositionLevelCount, DispersionParameter, FunctionalPackingKeyswitchKeyCount,
     GlweDimension, GlweSize, LweCiphertextCount, LweDimension, LweSize, PolynomialSize,
 };
-use concrete_csprng::generators::ForkError;
+use concrete_csprng::generators::{ForkError, GeneratorState, RestoreError};
 #[cfg(feature = "__commons_parallel")]
 use rayon::prelude::*;
+#[cfg(feature = "__commons_serialization")]
+use serde::{Deserialize, Serialize};
+
+/// A snapshot of the state of an [`EncryptionRandomGenerator`].
+#[cfg_attr(feature = "__commons_serialization", derive(Serialize, Deserialize))]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct EncryptionRandomGeneratorState {
+    /// The state of the mask generator.
+    pub mask: GeneratorState,
+    /// The state of the noise generator.
+    pub noise: GeneratorState,
+}
 
 /// A random number generator which can be used to encrypt messages.
 pub struct EncryptionRandomGenerator<G: ByteRandomGenerator> {
@@ -32,6 +45,14 @@ impl<G: ByteRandomGenerator> EncryptionRandomGenerator<G> {
         }
     }
 
+    /// Creates a new encryption generator from already created mask and noise generators.
+    pub fn from_generators(
+        mask: RandomGenerator<G>,
+        noise: RandomGenerator<G>,
+    ) -> EncryptionRandomGenerator<G> {
+        EncryptionRandomGenerator { mask, noise }
+    }
+
     // Allows to seed the noise generator. For testing purpose only.
     #[cfg(test)]
     pub(crate) fn seed_noise_generator(&mut self, seed: Seed) {
@@ -196,6 +217,26 @@ impl<G: ByteRandomGenerator> EncryptionRandomGenerator<G> {
     }
 }
 
+impl<G: SnapshotableByteRandomGenerator> EncryptionRandomGenerator<G> {
+    /// Returns a snapshot of the state of the mask and noise generators.
+    pub fn save_state(&self) -> EncryptionRandomGeneratorState {
+        EncryptionRandomGeneratorState {
+            mask: self.mask.save_state(),
+            noise: self.noise.save_state(),
+        }
+    }
+
+    /// Restores a generator from a snapshot of the state of its mask and noise generators.
+    pub fn restore_state(
+        state: EncryptionRandomGeneratorState,
+    ) -> Result<EncryptionRandomGenerator<G>, RestoreError> {
+        Ok(EncryptionRandomGenerator {
+            mask: RandomGenerator::restore_state(state.mask)?,
+            noise: RandomGenerator::restore_state(state.noise)?,
+        })
+    }
+}
+
 #[cfg(feature = "__commons_parallel")]
 impl<G: ParallelByteRandomGenerator> EncryptionRandomGenerator<G> {
     // Forks the generator into a parallel iterator, when splitting a bootstrap key into ggsw ct.
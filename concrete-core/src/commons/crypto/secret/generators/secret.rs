@@ -1,9 +1,11 @@
 use crate::commons::math::random::{
     ByteRandomGenerator, Gaussian, RandomGenerable, RandomGenerator, Seed,
+    SnapshotableByteRandomGenerator,
 };
 use crate::commons::math::tensor::Tensor;
 use crate::commons::math::torus::UnsignedTorus;
 use crate::prelude::DispersionParameter;
+use concrete_csprng::generators::{GeneratorState, RestoreError};
 
 /// A random number generator which can be used to generate secret keys.
 pub struct SecretRandomGenerator<G: ByteRandomGenerator>(RandomGenerator<G>);
@@ -14,6 +16,11 @@ impl<G: ByteRandomGenerator> SecretRandomGenerator<G> {
         SecretRandomGenerator(RandomGenerator::new(seed))
     }
 
+    /// Creates a new generator from an already created random generator.
+    pub fn from_generator(generator: RandomGenerator<G>) -> SecretRandomGenerator<G> {
+        SecretRandomGenerator(generator)
+    }
+
     /// Returns the number of remaining bytes, if the generator is bounded.
     pub fn remaining_bytes(&self) -> Option<usize> {
         self.0.remaining_bytes()
@@ -53,3 +60,15 @@ impl<G: ByteRandomGenerator> SecretRandomGenerator<G> {
             .random_gaussian_tensor(length, 0.0, Scalar::GAUSSIAN_KEY_LOG_STD.get_standard_dev())
     }
 }
+
+impl<G: SnapshotableByteRandomGenerator> SecretRandomGenerator<G> {
+    /// Returns a snapshot of the state of the generator.
+    pub fn save_state(&self) -> GeneratorState {
+        self.0.save_state()
+    }
+
+    /// Restores a generator from a snapshot of its state.
+    pub fn restore_state(state: GeneratorState) -> Result<SecretRandomGenerator<G>, RestoreError> {
+        RandomGenerator::restore_state(state).map(SecretRandomGenerator)
+    }
+}
@@ -1,6 +1,8 @@
 use crate::commons::math::random::{
-    ByteRandomGenerator, RandomGenerable, RandomGenerator, Seed, Seeder, Uniform,
+    ByteRandomGenerator, RandomGenerable, RandomGenerator, Seed, Seeder,
+    SnapshotableByteRandomGenerator, Uniform,
 };
+use concrete_csprng::generators::{GeneratorState, RestoreError};
 
 /// Seeder backed by a CSPRNG
 ///
@@ -40,6 +42,23 @@ impl<G: ByteRandomGenerator> DeterministicSeeder<G> {
             generator: RandomGenerator::new(seed),
         }
     }
+
+    /// Creates a new seeder from an already created random generator.
+    pub fn from_generator(generator: RandomGenerator<G>) -> Self {
+        DeterministicSeeder { generator }
+    }
+}
+
+impl<G: SnapshotableByteRandomGenerator> DeterministicSeeder<G> {
+    /// Returns a snapshot of the state of the seeder.
+    pub fn save_state(&self) -> GeneratorState {
+        self.generator.save_state()
+    }
+
+    /// Restores a seeder from a snapshot of its state.
+    pub fn restore_state(state: GeneratorState) -> Result<Self, RestoreError> {
+        RandomGenerator::restore_state(state).map(Self::from_generator)
+    }
 }
 
 impl<G: ByteRandomGenerator> Seeder for DeterministicSeeder<G> {
@@ -7,6 +7,9 @@ use crate::commons::crypto::secret::generators::{
     EncryptionRandomGenerator, SecretRandomGenerator,
 };
 use crate::commons::crypto::secret::LweSecretKey;
+use crate::commons::crypto::validation::{
+    check_container_len, EntityValidationError, ValidateEntity,
+};
 use crate::commons::math::polynomial::PolynomialList;
 #[cfg(feature = "__commons_parallel")]
 use crate::commons::math::random::ParallelByteRandomGenerator;
@@ -1670,3 +1673,14 @@ where
         self.tensor
     }
 }
+
+impl<Kind, Cont> ValidateEntity for GlweSecretKey<Kind, Cont>
+where
+    Kind: KeyKind,
+    Cont: AsRefSlice,
+{
+    fn validate(&self) -> Result<(), EntityValidationError> {
+        check_container_len(self.tensor.len(), &[self.poly_size.0])?;
+        Ok(())
+    }
+}
@@ -6,6 +6,7 @@ use crate::commons::crypto::lwe::{
 use crate::commons::crypto::secret::generators::{
     EncryptionRandomGenerator, SecretRandomGenerator,
 };
+use crate::commons::crypto::validation::{check_non_null, EntityValidationError, ValidateEntity};
 #[cfg(feature = "__commons_parallel")]
 use crate::commons::math::random::ParallelByteRandomGenerator;
 use crate::commons::math::random::{ByteRandomGenerator, Gaussian, RandomGenerable, Seeder};
@@ -14,11 +15,9 @@ use crate::commons::math::tensor::{
 };
 use crate::commons::math::torus::UnsignedTorus;
 use crate::commons::numeric::Numeric;
-#[cfg(feature = "__commons_parallel")]
-use crate::prelude::LweCiphertextCount;
 use crate::prelude::{
-    BinaryKeyKind, DispersionParameter, GaussianKeyKind, KeyKind, LweDimension, TernaryKeyKind,
-    UniformKeyKind,
+    BinaryKeyKind, DispersionParameter, GaussianKeyKind, KeyKind, LweCiphertextCount, LweDimension,
+    TernaryKeyKind, UniformKeyKind,
 };
 #[cfg(feature = "__commons_parallel")]
 use rayon::{iter::IndexedParallelIterator, prelude::*};
@@ -544,8 +543,20 @@ where
             output.count().0 == encoded.count().0,
             "Lwe cipher list size and encoded list size are not compatible"
         );
-        for (mut cipher, message) in output.ciphertext_iter_mut().zip(encoded.plaintext_iter()) {
-            self.encrypt_lwe(&mut cipher, message, noise_parameters, generator);
+        if output.count().0 == 0 {
+            return;
+        }
+        // We fork the generator the same way as in `par_encrypt_lwe_list`, so that both functions
+        // output the same ciphertexts.
+        let gen_iter = generator
+            .fork_lwe_list_to_lwe::<Scalar>(LweCiphertextCount(output.count().0), output.lwe_size)
+            .expect("Failed to split generator into lwe");
+        for ((mut cipher, message), mut generator) in output
+            .ciphertext_iter_mut()
+            .zip(encoded.plaintext_iter())
+            .zip(gen_iter)
+        {
+            self.encrypt_lwe(&mut cipher, message, noise_parameters, &mut generator);
         }
     }
 
@@ -568,6 +579,9 @@ where
             output.count().0 == encoded.count().0,
             "Lwe cipher list size and encoded list size are not compatible"
         );
+        if output.count().0 == 0 {
+            return;
+        }
         let ct_count = LweCiphertextCount(output.count().0);
         let ct_size = output.lwe_size;
         output
@@ -738,6 +752,35 @@ where
         }
     }
 
+    /// Decrypts a list of ciphertexts in parallel.
+    ///
+    /// The output is the same as the one of [`LweSecretKey::decrypt_lwe_list`].
+    #[cfg(feature = "__commons_parallel")]
+    pub fn par_decrypt_lwe_list<Scalar, EncodedCont, CipherCont>(
+        &self,
+        output: &mut PlaintextList<EncodedCont>,
+        cipher: &LweList<CipherCont>,
+    ) where
+        Self: AsRefTensor<Element = Scalar>,
+        PlaintextList<EncodedCont>: AsMutTensor<Element = Scalar>,
+        LweList<CipherCont>: AsRefTensor<Element = Scalar>,
+        Scalar: UnsignedTorus + Send + Sync,
+        Cont: Sync,
+    {
+        debug_assert!(
+            output.count().0 == cipher.count().0,
+            "Tried to decrypt a list into one with incompatible size.Expected {} found {}",
+            output.count().0,
+            cipher.count().0
+        );
+        cipher
+            .par_ciphertext_iter()
+            .zip(output.par_plaintext_iter_mut())
+            .for_each(|(cipher, output)| {
+                self.decrypt_lwe(output, &cipher);
+            });
+    }
+
     /// This function encrypts a message as a GSW ciphertext.
     ///
     /// # Examples
@@ -1048,3 +1091,13 @@ where
         self.tensor
     }
 }
+
+impl<Kind, Cont> ValidateEntity for LweSecretKey<Kind, Cont>
+where
+    Kind: KeyKind,
+    Cont: AsRefSlice,
+{
+    fn validate(&self) -> Result<(), EntityValidationError> {
+        check_non_null(&[self.tensor.len()])
+    }
+}
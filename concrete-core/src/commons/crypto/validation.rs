@@ -0,0 +1,126 @@
+//! Validation of the invariants of entities coming from untrusted sources.
+//!
+//! Entities built through the constructors of this module are consistent by construction, but
+//! entities decoded from bytes are not: the sizes they store may not match the length of their
+//! container. The [`ValidateEntity`] trait makes it possible to check these invariants before the
+//! entity is handed to code which assumes them.
+
+use crate::commons::numeric::Numeric;
+use crate::prelude::{DecompositionBaseLog, DecompositionLevelCount};
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+
+/// The error which can occur when validating the invariants of an entity.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EntityValidationError {
+    /// One of the dimensions, sizes or counts of the entity is null.
+    NullDimension,
+    /// The decomposition parameters of the entity are null, or exceed the precision of its
+    /// scalars.
+    InvalidDecomposition,
+    /// The length of the container of the entity does not match its dimensions.
+    InvalidContainerSize,
+    /// The parameters of the encoder are not valid.
+    InvalidEncoder,
+}
+
+impl Display for EntityValidationError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            EntityValidationError::NullDimension => {
+                write!(f, "The entity has a null dimension, size or count.")
+            }
+            EntityValidationError::InvalidDecomposition => write!(
+                f,
+                "The decomposition parameters of the entity are null or exceed the precision of \
+                its scalars."
+            ),
+            EntityValidationError::InvalidContainerSize => write!(
+                f,
+                "The length of the container of the entity does not match its dimensions."
+            ),
+            EntityValidationError::InvalidEncoder => {
+                write!(f, "The parameters of the encoder are not valid.")
+            }
+        }
+    }
+}
+
+impl Error for EntityValidationError {}
+
+/// A trait for entities whose invariants can be checked after they were decoded.
+pub(crate) trait ValidateEntity {
+    /// Checks that the sizes stored in the entity are consistent with its container.
+    fn validate(&self) -> Result<(), EntityValidationError>;
+}
+
+/// A trait for seeded entities, whose masks are regenerated from a seed when they are expanded.
+///
+/// The serialized form of such an entity only holds its bodies, such that the container of the
+/// expanded entity can be much larger than the serialized data. Its size must be checked before the
+/// entity is expanded.
+pub(crate) trait ValidateExpansion: ValidateEntity {
+    /// Returns the size in bytes of the container of the expanded entity, or `None` if it does not
+    /// fit in a `usize`.
+    fn expanded_size(&self) -> Option<usize>;
+}
+
+impl<Entity: ValidateEntity> ValidateEntity for Vec<Entity> {
+    fn validate(&self) -> Result<(), EntityValidationError> {
+        if self.is_empty() {
+            return Err(EntityValidationError::NullDimension);
+        }
+        self.iter().try_for_each(ValidateEntity::validate)
+    }
+}
+
+/// Checks that none of the `dimensions` is null.
+pub(crate) fn check_non_null(dimensions: &[usize]) -> Result<(), EntityValidationError> {
+    if dimensions.contains(&0) {
+        Err(EntityValidationError::NullDimension)
+    } else {
+        Ok(())
+    }
+}
+
+/// Checks that the decomposition parameters are not null, and fit in the bits of `Scalar`.
+pub(crate) fn check_decomposition<Scalar: Numeric>(
+    base_log: DecompositionBaseLog,
+    level_count: DecompositionLevelCount,
+) -> Result<(), EntityValidationError> {
+    if base_log.0 == 0 || level_count.0 == 0 {
+        return Err(EntityValidationError::InvalidDecomposition);
+    }
+    match base_log.0.checked_mul(level_count.0) {
+        Some(bits) if bits <= Scalar::BITS => Ok(()),
+        _ => Err(EntityValidationError::InvalidDecomposition),
+    }
+}
+
+/// Returns the product of the `dimensions`, or `None` if it overflows.
+fn checked_product(dimensions: &[usize]) -> Option<usize> {
+    dimensions
+        .iter()
+        .try_fold(1_usize, |acc, dimension| acc.checked_mul(*dimension))
+}
+
+/// Returns the size in bytes of a container of `Scalar` with the given `dimensions`, or `None` if
+/// it overflows.
+pub(crate) fn checked_size<Scalar>(dimensions: &[usize]) -> Option<usize> {
+    checked_product(dimensions)?.checked_mul(std::mem::size_of::<Scalar>())
+}
+
+/// Checks that `len` is a non-null multiple of the product of the `dimensions`, and returns the
+/// quotient.
+pub(crate) fn check_container_len(
+    len: usize,
+    dimensions: &[usize],
+) -> Result<usize, EntityValidationError> {
+    check_non_null(dimensions)?;
+    let chunk = checked_product(dimensions).ok_or(EntityValidationError::InvalidContainerSize)?;
+    if len == 0 || len % chunk != 0 {
+        Err(EntityValidationError::InvalidContainerSize)
+    } else {
+        Ok(len / chunk)
+    }
+}
@@ -1,6 +1,7 @@
 //! A module containing general mathematical tools.
 
 pub mod decomposition;
+pub mod packing;
 pub mod polynomial;
 pub mod random;
 pub mod tensor;
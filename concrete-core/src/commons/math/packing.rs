@@ -0,0 +1,215 @@
+//! A module containing a bit-packed container for integers switched to a smaller modulus.
+//!
+//! The [`PackedIntegers`] type stores a sequence of integers of `bit_width` bits each, packed
+//! contiguously in 64 bits words. Coupled with a modulus switching, it allows to represent the
+//! coefficients of a ciphertext with a lower precision, which is cheaper to transmit.
+use crate::commons::numeric::{CastFrom, CastInto, UnsignedInteger};
+#[cfg(feature = "__commons_serialization")]
+use serde::{Deserialize, Serialize};
+
+/// A sequence of integers of `bit_width` bits, packed contiguously in 64 bits words.
+#[cfg_attr(feature = "__commons_serialization", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PackedIntegers {
+    pub(crate) words: Vec<u64>,
+    pub(crate) bit_width: usize,
+    pub(crate) len: usize,
+}
+
+impl PackedIntegers {
+    /// Packs the given values, keeping only the `bit_width` lowest bits of each of them.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use concrete_core::commons::math::packing::PackedIntegers;
+    ///
+    /// let packed = PackedIntegers::pack(vec![1_u64, 7, 4, 3].into_iter(), 3);
+    /// assert_eq!(packed.len(), 4);
+    /// assert_eq!(packed.bit_width(), 3);
+    /// assert_eq!(packed.unpack().collect::<Vec<_>>(), vec![1, 7, 4, 3]);
+    /// ```
+    pub fn pack<Values>(values: Values, bit_width: usize) -> Self
+    where
+        Values: ExactSizeIterator<Item = u64>,
+    {
+        debug_assert!(
+            (1..=u64::BITS as usize).contains(&bit_width),
+            "The bit width must be between 1 and 64."
+        );
+        let len = values.len();
+        let word_count = Self::word_count(len, bit_width).expect("Too many values to pack.");
+        let mut words = vec![0_u64; word_count];
+        let mask = Self::mask(bit_width);
+        for (index, value) in values.enumerate() {
+            let value = value & mask;
+            let start = index * bit_width;
+            let (word, offset) = (start / 64, start % 64);
+            words[word] |= value << offset;
+            if offset + bit_width > 64 {
+                words[word + 1] |= value >> (64 - offset);
+            }
+        }
+        PackedIntegers {
+            words,
+            bit_width,
+            len,
+        }
+    }
+
+    /// Switches the given torus elements to the modulus $2^{\text{bit\_width}}$ with rounding,
+    /// and packs the result.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use concrete_core::commons::math::packing::PackedIntegers;
+    ///
+    /// let packed = PackedIntegers::pack_modulus_switched(&[0x1280_0000_u32, 0xff00_0000], 8);
+    /// assert_eq!(packed.unpack().collect::<Vec<_>>(), vec![0x13, 0xff]);
+    /// ```
+    pub fn pack_modulus_switched<Scalar>(values: &[Scalar], bit_width: usize) -> Self
+    where
+        Scalar: UnsignedInteger + CastInto<u64>,
+    {
+        debug_assert!(
+            bit_width <= Scalar::BITS,
+            "The bit width can not exceed the precision of the input."
+        );
+        let shift = Scalar::BITS - bit_width;
+        let switched = values.iter().map(|value| {
+            let value: u64 = (*value).cast_into();
+            if shift == 0 {
+                value
+            } else {
+                ((value >> (shift - 1)).wrapping_add(1)) >> 1
+            }
+        });
+        Self::pack(switched, bit_width)
+    }
+
+    /// Unpacks the integers, and switches them back to the modulus of `Scalar`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use concrete_core::commons::math::packing::PackedIntegers;
+    ///
+    /// let packed = PackedIntegers::pack_modulus_switched(&[0x1280_0000_u32, 0xff00_0000], 8);
+    /// let mut values = vec![0_u32; 2];
+    /// packed.unpack_modulus_switched_into(&mut values);
+    /// assert_eq!(values, vec![0x1300_0000, 0xff00_0000]);
+    /// ```
+    pub fn unpack_modulus_switched_into<Scalar>(&self, output: &mut [Scalar])
+    where
+        Scalar: UnsignedInteger + CastFrom<u64>,
+    {
+        debug_assert!(
+            output.len() == self.len,
+            "The output has not the right length."
+        );
+        let shift = Scalar::BITS - self.bit_width;
+        for (output, value) in output.iter_mut().zip(self.unpack()) {
+            *output = Scalar::cast_from(value) << shift;
+        }
+    }
+
+    /// Returns an iterator over the packed integers.
+    pub fn unpack(&self) -> impl Iterator<Item = u64> + '_ {
+        let mask = Self::mask(self.bit_width);
+        (0..self.len).map(move |index| {
+            let start = index * self.bit_width;
+            let (word, offset) = (start / 64, start % 64);
+            let mut value = self.words[word] >> offset;
+            if offset + self.bit_width > 64 {
+                value |= self.words[word + 1] << (64 - offset);
+            }
+            value & mask
+        })
+    }
+
+    /// Returns the number of packed integers.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns whether no integers are packed.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Returns the number of bits used by each packed integer.
+    pub fn bit_width(&self) -> usize {
+        self.bit_width
+    }
+
+    /// Returns the packed words.
+    pub fn as_words(&self) -> &[u64] {
+        &self.words
+    }
+
+    /// Returns whether the number of words matches the number of packed integers, for a bit width
+    /// between 1 and 64.
+    pub(crate) fn is_consistent(&self) -> bool {
+        (1..=u64::BITS as usize).contains(&self.bit_width)
+            && Self::word_count(self.len, self.bit_width) == Some(self.words.len())
+    }
+
+    fn word_count(len: usize, bit_width: usize) -> Option<usize> {
+        let bits = len.checked_mul(bit_width)?;
+        Some(bits / 64 + usize::from(bits % 64 != 0))
+    }
+
+    fn mask(bit_width: usize) -> u64 {
+        if bit_width >= 64 {
+            u64::MAX
+        } else {
+            (1 << bit_width) - 1
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::PackedIntegers;
+    use crate::commons::test_tools::any_uint;
+
+    fn test_pack_unpack_straddling_words(bit_width: usize) {
+        // Enough values so that several of them overlap two consecutive words.
+        let len = 3 * 64 / bit_width + 5;
+        let mask = (1_u64 << bit_width) - 1;
+        let values: Vec<u64> = (0..len).map(|_| any_uint::<u64>() & mask).collect();
+        let packed = PackedIntegers::pack(values.iter().copied(), bit_width);
+        assert!(packed.is_consistent());
+        let word_bits = packed.as_words().len() * 64;
+        assert!(word_bits >= len * bit_width && word_bits < len * bit_width + 64);
+        assert!((0..len).any(|index| (index * bit_width) % 64 + bit_width > 64));
+        assert_eq!(packed.unpack().collect::<Vec<_>>(), values);
+
+        // The values are packed contiguously, starting from the lowest bits of the first word.
+        let mut expected_words = vec![0_u64; packed.as_words().len()];
+        for (index, value) in values.iter().enumerate() {
+            for bit in 0..bit_width {
+                let position = index * bit_width + bit;
+                expected_words[position / 64] |= ((value >> bit) & 1) << (position % 64);
+            }
+        }
+        assert_eq!(packed.as_words(), expected_words.as_slice());
+    }
+
+    #[test]
+    fn test_pack_unpack_straddling_words_7() {
+        test_pack_unpack_straddling_words(7);
+    }
+
+    #[test]
+    fn test_pack_unpack_straddling_words_13() {
+        test_pack_unpack_straddling_words(13);
+    }
+
+    #[test]
+    fn test_pack_masks_high_bits() {
+        let packed = PackedIntegers::pack(vec![u64::MAX; 11].into_iter(), 13);
+        assert!(packed.unpack().all(|value| value == (1 << 13) - 1));
+    }
+}
@@ -4,14 +4,19 @@ use crate::commons::math::random::{
 };
 use crate::commons::math::tensor::{AsMutSlice, AsMutTensor, Tensor};
 use crate::commons::numeric::{FloatingPoint, Numeric};
-use concrete_csprng::generators::{BytesPerChild, ChildrenCount, ForkError};
+use concrete_csprng::generators::{
+    ByteCount, BytesPerChild, ChildrenCount, ForkError, GeneratorState, RestoreError, SkipError,
+};
 #[cfg(feature = "__commons_parallel")]
 use rayon::prelude::*;
 use std::convert::TryInto;
 
 #[cfg(feature = "__commons_parallel")]
 pub use concrete_csprng::generators::ParallelRandomGenerator as ParallelByteRandomGenerator;
-pub use concrete_csprng::generators::RandomGenerator as ByteRandomGenerator;
+pub use concrete_csprng::generators::{
+    RandomGenerator as ByteRandomGenerator,
+    SnapshotableGenerator as SnapshotableByteRandomGenerator,
+};
 pub use concrete_csprng::seeders::{Seed, Seeder};
 
 /// Module to proxy the serialization for `concrete-csprng::Seed` to avoid adding serde as a
@@ -96,6 +101,22 @@ impl<G: ByteRandomGenerator> RandomGenerator<G> {
         RandomGenerator(G::new(seed))
     }
 
+    /// Generates a new generator wrapping an already created byte generator.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use concrete_core::commons::math::random::RandomGenerator;
+    /// use concrete_csprng::generators::{DynamicRandomGenerator, GeneratorKind};
+    /// use concrete_csprng::seeders::Seed;
+    /// let byte_generator =
+    ///     DynamicRandomGenerator::new_with_kind(Seed(0), GeneratorKind::Software).unwrap();
+    /// let mut generator = RandomGenerator::from_byte_generator(byte_generator);
+    /// ```
+    pub fn from_byte_generator(generator: G) -> RandomGenerator<G> {
+        RandomGenerator(generator)
+    }
+
     /// Returns the number of bytes that can still be generated, if the generator is bounded.
     ///
     /// # Example
@@ -136,6 +157,27 @@ impl<G: ByteRandomGenerator> RandomGenerator<G> {
             .map(|iter| iter.map(Self))
     }
 
+    /// Tries to skip the next `n_bytes` bytes of the generator, without generating them. If
+    /// `n_bytes` exceeds the bound of the current generator, the method returns an error.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use concrete_core::commons::math::random::RandomGenerator;
+    /// use concrete_csprng::generators::SoftwareRandomGenerator;
+    /// use concrete_csprng::seeders::Seed;
+    /// let mut first = RandomGenerator::<SoftwareRandomGenerator>::new(Seed(0));
+    /// let mut second = RandomGenerator::<SoftwareRandomGenerator>::new(Seed(0));
+    /// for _ in 0..1000 {
+    ///     first.generate_next();
+    /// }
+    /// second.try_skip(1000).unwrap();
+    /// assert_eq!(first.generate_next(), second.generate_next());
+    /// ```
+    pub fn try_skip(&mut self, n_bytes: usize) -> Result<(), SkipError> {
+        self.0.try_skip(ByteCount(n_bytes as u128))
+    }
+
     /// Generates a random uniform unsigned integer.
     ///
     /// # Example
@@ -640,6 +682,31 @@ impl<G: ByteRandomGenerator> RandomGenerator<G> {
     }
 }
 
+impl<G: SnapshotableByteRandomGenerator> RandomGenerator<G> {
+    /// Returns a snapshot of the state of the generator.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use concrete_core::commons::math::random::RandomGenerator;
+    /// use concrete_csprng::generators::SoftwareRandomGenerator;
+    /// use concrete_csprng::seeders::Seed;
+    /// let mut generator = RandomGenerator::<SoftwareRandomGenerator>::new(Seed(0));
+    /// generator.generate_next();
+    /// let state = generator.save_state();
+    /// let mut restored = RandomGenerator::<SoftwareRandomGenerator>::restore_state(state).unwrap();
+    /// assert_eq!(restored.generate_next(), generator.generate_next());
+    /// ```
+    pub fn save_state(&self) -> GeneratorState {
+        self.0.save_state()
+    }
+
+    /// Restores a generator from a snapshot of its state.
+    pub fn restore_state(state: GeneratorState) -> Result<RandomGenerator<G>, RestoreError> {
+        G::restore_state(state).map(RandomGenerator)
+    }
+}
+
 #[cfg(feature = "__commons_parallel")]
 impl<G: ParallelByteRandomGenerator> RandomGenerator<G> {
     /// Tries to fork the current generator into `n_child` generator bounded to `bytes_per_child`,
@@ -100,3 +100,12 @@ impl UnsignedTorus for u32 {
 impl UnsignedTorus for u64 {
     const GAUSSIAN_KEY_LOG_STD: LogStandardDev = LogStandardDev(-62.32192809488736);
 }
+
+/// Returns the signed distance between two torus elements in unsigned integer representation, as
+/// a floating point torus value in $\left[-\frac{1}{2}, \frac{1}{2}\right)$.
+///
+/// The output is positive when `value` lies (modularly) above `reference`.
+pub fn signed_torus_distance<T: UnsignedTorus>(value: T, reference: T) -> f64 {
+    let distance: f64 = value.wrapping_sub(reference).into_signed().cast_into();
+    distance * 2_f64.powi(-(T::BITS as i32))
+}
@@ -22,9 +22,17 @@ pub use super::backends::cuda::engines::*;
 #[cfg(feature = "backend_cuda")]
 pub use super::backends::cuda::entities::*;
 
+// --------------------------------------------------------------------------------- DYNAMIC BACKEND
+#[cfg(feature = "backend_dynamic")]
+pub use super::backends::dynamic::engines::*;
+#[cfg(feature = "backend_dynamic")]
+pub use super::backends::dynamic::entities::*;
+
 // -------------------------------------------------------------------------------- COMMONS REEXPORT
+pub use super::specification::container::*;
 pub use super::specification::dispersion::*;
 pub use super::specification::key_kinds::*;
+pub use super::specification::mapped::*;
 pub use super::specification::parameters::*;
 pub use super::specification::*;
 
@@ -33,6 +41,8 @@ pub use super::specification::*;
 // default engines.
 #[cfg(target_os = "macos")]
 pub use concrete_csprng::seeders::AppleSecureEnclaveSeeder;
+#[cfg(feature = "seeder_entropy_pool")]
+pub use concrete_csprng::seeders::EntropyPoolSeeder;
 #[cfg(feature = "seeder_x86_64_rdseed")]
 pub use concrete_csprng::seeders::RdseedSeeder;
 pub use concrete_csprng::seeders::Seeder;
@@ -0,0 +1,353 @@
+//! This module contains the self-describing container format used to store serialized entities.
+//!
+//! The serialization engines produce blobs which can only be deserialized when their type is known
+//! in advance. The container format wraps such a blob (the payload) in a header describing its
+//! content, so that it can be inspected and checked before being deserialized:
+//!
+//! | Offset | Size | Content                                                          |
+//! |--------|------|------------------------------------------------------------------|
+//! | 0      | 8    | The magic bytes [`CONTAINER_MAGIC`]                              |
+//! | 8      | 4    | The version of the format, as a `u32`                            |
+//! | 12     | 4    | The kind of the entity, as a `u32` (see [`ContainerEntityKind`]) |
+//! | 16     | 4    | The width of the scalars of the entity in bits, as a `u32`       |
+//! | 20     | 4    | Reserved, set to zero                                            |
+//! | 24     | 40   | Five parameters of the entity, as `u64`s                         |
+//! | 64     | 8    | The size of the payload in bytes, as a `u64`                     |
+//! | 72     | 4    | The CRC-32 checksum of the payload, as a `u32`                   |
+//! | 76     | 4    | Reserved, set to zero                                            |
+//! | 80     | ...  | The payload                                                      |
+//!
+//! All the integers are stored in little endian. The parameters depend on the kind of the entity,
+//! and are documented in [`ContainerEntityKind`]. Unused parameters are set to zero.
+use std::fmt::{Display, Formatter};
+
+/// The magic bytes found at the start of every container.
+pub const CONTAINER_MAGIC: [u8; 8] = *b"CCENTITY";
+
+/// The version of the container format written by this version of the library.
+pub const CONTAINER_FORMAT_VERSION: u32 = 0;
+
+/// The size in bytes of the header of a container.
+pub const CONTAINER_HEADER_SIZE: usize = 80;
+
+/// The kinds of entities which can be stored in a container.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ContainerEntityKind {
+    /// A cleartext. There are no parameters.
+    Cleartext,
+    /// A vector of cleartexts. The parameter is the cleartext count.
+    CleartextVector,
+    /// A floating point cleartext. There are no parameters.
+    CleartextF64,
+    /// A vector of floating point cleartexts. The parameter is the cleartext count.
+    CleartextVectorF64,
+    /// A floating point encoder. There are no parameters.
+    FloatEncoder,
+    /// A vector of floating point encoders. The parameter is the encoder count.
+    FloatEncoderVector,
+    /// A plaintext. There are no parameters.
+    Plaintext,
+    /// A vector of plaintexts. The parameter is the plaintext count.
+    PlaintextVector,
+    /// An LWE secret key. The parameter is the LWE dimension.
+    LweSecretKey,
+    /// A GLWE secret key. The parameters are the GLWE dimension and the polynomial size.
+    GlweSecretKey,
+    /// An LWE public key. The parameters are the LWE dimension and the zero encryption count.
+    LwePublicKey,
+    /// An LWE ciphertext. The parameter is the LWE dimension.
+    LweCiphertext,
+    /// A vector of LWE ciphertexts. The parameters are the LWE dimension and the ciphertext count.
+    LweCiphertextVector,
+    /// A seeded LWE ciphertext. The parameter is the LWE dimension.
+    LweSeededCiphertext,
+    /// A vector of seeded LWE ciphertexts. The parameters are the LWE dimension and the
+    /// ciphertext count.
+    LweSeededCiphertextVector,
+    /// A GLWE ciphertext. The parameters are the GLWE dimension and the polynomial size.
+    GlweCiphertext,
+    /// A vector of GLWE ciphertexts. The parameters are the GLWE dimension, the polynomial size
+    /// and the ciphertext count.
+    GlweCiphertextVector,
+    /// A seeded GLWE ciphertext. The parameters are the GLWE dimension and the polynomial size.
+    GlweSeededCiphertext,
+    /// A vector of seeded GLWE ciphertexts. The parameters are the GLWE dimension, the polynomial
+    /// size and the ciphertext count.
+    GlweSeededCiphertextVector,
+    /// A GGSW ciphertext. The parameters are the GLWE dimension, the polynomial size, the
+    /// decomposition base log and the decomposition level count.
+    GgswCiphertext,
+    /// A seeded GGSW ciphertext. The parameters are the same as for
+    /// [`ContainerEntityKind::GgswCiphertext`].
+    GgswSeededCiphertext,
+    /// A GGSW ciphertext in the Fourier domain of the FFT backend. The parameters are the same as
+    /// for [`ContainerEntityKind::GgswCiphertext`].
+    FftFourierGgswCiphertext,
+    /// An LWE keyswitch key. The parameters are the input LWE dimension, the output LWE
+    /// dimension, the decomposition base log and the decomposition level count.
+    LweKeyswitchKey,
+    /// A seeded LWE keyswitch key. The parameters are the same as for
+    /// [`ContainerEntityKind::LweKeyswitchKey`].
+    LweSeededKeyswitchKey,
+    /// An LWE bootstrap key. The parameters are the input LWE dimension, the GLWE dimension, the
+    /// polynomial size, the decomposition base log and the decomposition level count.
+    LweBootstrapKey,
+    /// A seeded LWE bootstrap key. The parameters are the same as for
+    /// [`ContainerEntityKind::LweBootstrapKey`].
+    LweSeededBootstrapKey,
+    /// An LWE bootstrap key in the Fourier domain of the FFT backend. The parameters are the same
+    /// as for [`ContainerEntityKind::LweBootstrapKey`].
+    FftFourierLweBootstrapKey,
+    /// An LWE packing keyswitch key. The parameters are the input LWE dimension, the output GLWE
+    /// dimension, the output polynomial size, the decomposition base log and the decomposition
+    /// level count.
+    LwePackingKeyswitchKey,
+    /// The private functional packing keyswitch keys used in the circuit bootstrap. The
+    /// parameters are the same as for [`ContainerEntityKind::LwePackingKeyswitchKey`].
+    LweCircuitBootstrapPrivateFunctionalPackingKeyswitchKeys,
+    /// A compressed LWE ciphertext. The parameters are the LWE dimension and the ciphertext
+    /// modulus log.
+    LweCompressedCiphertext,
+    /// A compressed GLWE ciphertext. The parameters are the GLWE dimension, the polynomial size
+    /// and the ciphertext modulus log.
+    GlweCompressedCiphertext,
+}
+
+impl ContainerEntityKind {
+    const ALL: [ContainerEntityKind; 31] = [
+        ContainerEntityKind::Cleartext,
+        ContainerEntityKind::CleartextVector,
+        ContainerEntityKind::CleartextF64,
+        ContainerEntityKind::CleartextVectorF64,
+        ContainerEntityKind::FloatEncoder,
+        ContainerEntityKind::FloatEncoderVector,
+        ContainerEntityKind::Plaintext,
+        ContainerEntityKind::PlaintextVector,
+        ContainerEntityKind::LweSecretKey,
+        ContainerEntityKind::GlweSecretKey,
+        ContainerEntityKind::LwePublicKey,
+        ContainerEntityKind::LweCiphertext,
+        ContainerEntityKind::LweCiphertextVector,
+        ContainerEntityKind::LweSeededCiphertext,
+        ContainerEntityKind::LweSeededCiphertextVector,
+        ContainerEntityKind::GlweCiphertext,
+        ContainerEntityKind::GlweCiphertextVector,
+        ContainerEntityKind::GlweSeededCiphertext,
+        ContainerEntityKind::GlweSeededCiphertextVector,
+        ContainerEntityKind::GgswCiphertext,
+        ContainerEntityKind::GgswSeededCiphertext,
+        ContainerEntityKind::FftFourierGgswCiphertext,
+        ContainerEntityKind::LweKeyswitchKey,
+        ContainerEntityKind::LweSeededKeyswitchKey,
+        ContainerEntityKind::LweBootstrapKey,
+        ContainerEntityKind::LweSeededBootstrapKey,
+        ContainerEntityKind::FftFourierLweBootstrapKey,
+        ContainerEntityKind::LwePackingKeyswitchKey,
+        ContainerEntityKind::LweCircuitBootstrapPrivateFunctionalPackingKeyswitchKeys,
+        ContainerEntityKind::LweCompressedCiphertext,
+        ContainerEntityKind::GlweCompressedCiphertext,
+    ];
+
+    // The tags are stored in the containers: new kinds must be appended to `ALL`.
+    fn to_u32(self) -> u32 {
+        Self::ALL.iter().position(|kind| *kind == self).unwrap() as u32 + 1
+    }
+
+    fn from_u32(value: u32) -> Option<Self> {
+        let index = usize::try_from(value).ok()?.checked_sub(1)?;
+        Self::ALL.get(index).copied()
+    }
+}
+
+/// The errors which can occur when reading a container.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ContainerFormatError {
+    /// The bytes do not start with a valid header.
+    InvalidHeader,
+    /// The container was written with an unsupported version of the format.
+    UnsupportedVersion,
+    /// The container does not hold an entity of the expected kind.
+    EntityKindMismatch,
+    /// The container does not hold an entity with the expected scalar width.
+    ScalarWidthMismatch,
+    /// The payload is truncated.
+    InvalidPayloadSize,
+    /// The checksum of the payload does not match the one stored in the header.
+    ChecksumMismatch,
+}
+
+impl Display for ContainerFormatError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ContainerFormatError::InvalidHeader => {
+                write!(f, "The bytes do not start with a valid container header.")
+            }
+            ContainerFormatError::UnsupportedVersion => {
+                write!(f, "The version of the container is not supported.")
+            }
+            ContainerFormatError::EntityKindMismatch => {
+                write!(
+                    f,
+                    "The container does not hold an entity of the expected kind."
+                )
+            }
+            ContainerFormatError::ScalarWidthMismatch => write!(
+                f,
+                "The container does not hold an entity with the expected scalar width."
+            ),
+            ContainerFormatError::InvalidPayloadSize => {
+                write!(f, "The payload of the container is truncated.")
+            }
+            ContainerFormatError::ChecksumMismatch => {
+                write!(
+                    f,
+                    "The checksum of the payload of the container does not match."
+                )
+            }
+        }
+    }
+}
+
+impl std::error::Error for ContainerFormatError {}
+
+/// The header of a container.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ContainerHeader {
+    pub kind: ContainerEntityKind,
+    pub scalar_width: u32,
+    pub parameters: [u64; 5],
+    pub payload_size: u64,
+    pub checksum: u32,
+}
+
+impl ContainerHeader {
+    /// Creates the header of a container holding `payload`.
+    pub fn new(
+        kind: ContainerEntityKind,
+        scalar_width: u32,
+        parameters: [u64; 5],
+        payload: &[u8],
+    ) -> ContainerHeader {
+        ContainerHeader {
+            kind,
+            scalar_width,
+            parameters,
+            payload_size: payload.len() as u64,
+            checksum: crc32(payload),
+        }
+    }
+
+    /// Returns the binary representation of the header.
+    pub fn to_bytes(&self) -> [u8; CONTAINER_HEADER_SIZE] {
+        let mut bytes = [0; CONTAINER_HEADER_SIZE];
+        bytes[0..8].copy_from_slice(&CONTAINER_MAGIC);
+        bytes[8..12].copy_from_slice(&CONTAINER_FORMAT_VERSION.to_le_bytes());
+        bytes[12..16].copy_from_slice(&self.kind.to_u32().to_le_bytes());
+        bytes[16..20].copy_from_slice(&self.scalar_width.to_le_bytes());
+        for (chunk, parameter) in bytes[24..64].chunks_exact_mut(8).zip(self.parameters) {
+            chunk.copy_from_slice(&parameter.to_le_bytes());
+        }
+        bytes[64..72].copy_from_slice(&self.payload_size.to_le_bytes());
+        bytes[72..76].copy_from_slice(&self.checksum.to_le_bytes());
+        bytes
+    }
+
+    /// Reads a header from the first [`CONTAINER_HEADER_SIZE`] bytes of `bytes`.
+    pub fn from_bytes(bytes: &[u8]) -> Result<ContainerHeader, ContainerFormatError> {
+        if bytes.len() < CONTAINER_HEADER_SIZE || bytes[0..8] != CONTAINER_MAGIC {
+            return Err(ContainerFormatError::InvalidHeader);
+        }
+        let read_u32 =
+            |offset: usize| u32::from_le_bytes(bytes[offset..offset + 4].try_into().unwrap());
+        let read_u64 =
+            |offset: usize| u64::from_le_bytes(bytes[offset..offset + 8].try_into().unwrap());
+        if read_u32(8) != CONTAINER_FORMAT_VERSION {
+            return Err(ContainerFormatError::UnsupportedVersion);
+        }
+        let kind = ContainerEntityKind::from_u32(read_u32(12))
+            .ok_or(ContainerFormatError::InvalidHeader)?;
+        let mut parameters = [0; 5];
+        for (i, parameter) in parameters.iter_mut().enumerate() {
+            *parameter = read_u64(24 + 8 * i);
+        }
+        Ok(ContainerHeader {
+            kind,
+            scalar_width: read_u32(16),
+            parameters,
+            payload_size: read_u64(64),
+            checksum: read_u32(72),
+        })
+    }
+
+    /// Returns the container made of this header followed by `payload`.
+    pub fn wrap(&self, payload: &[u8]) -> Vec<u8> {
+        let mut container = Vec::with_capacity(CONTAINER_HEADER_SIZE + payload.len());
+        container.extend_from_slice(&self.to_bytes());
+        container.extend_from_slice(payload);
+        container
+    }
+
+    /// Reads the header at the start of `bytes`, and returns it along with the payload.
+    ///
+    /// The checksum of the payload is not verified, see [`ContainerHeader::verify_checksum`].
+    pub fn split_payload(bytes: &[u8]) -> Result<(ContainerHeader, &[u8]), ContainerFormatError> {
+        let header = ContainerHeader::from_bytes(bytes)?;
+        let payload = &bytes[CONTAINER_HEADER_SIZE..];
+        let payload_size = usize::try_from(header.payload_size)
+            .map_err(|_| ContainerFormatError::InvalidPayloadSize)?;
+        if payload.len() < payload_size {
+            return Err(ContainerFormatError::InvalidPayloadSize);
+        }
+        Ok((header, &payload[..payload_size]))
+    }
+
+    /// Checks that the header describes an entity of the `expected` kind and scalar width.
+    pub fn verify_entity(
+        &self,
+        expected_kind: ContainerEntityKind,
+        expected_scalar_width: u32,
+    ) -> Result<(), ContainerFormatError> {
+        if self.kind != expected_kind {
+            return Err(ContainerFormatError::EntityKindMismatch);
+        }
+        if self.scalar_width != expected_scalar_width {
+            return Err(ContainerFormatError::ScalarWidthMismatch);
+        }
+        Ok(())
+    }
+
+    /// Checks that the checksum stored in the header matches the one of `payload`.
+    pub fn verify_checksum(&self, payload: &[u8]) -> Result<(), ContainerFormatError> {
+        if crc32(payload) != self.checksum {
+            return Err(ContainerFormatError::ChecksumMismatch);
+        }
+        Ok(())
+    }
+}
+
+const CRC32_TABLE: [u32; 256] = {
+    let mut table = [0; 256];
+    let mut i = 0;
+    while i < 256 {
+        let mut crc = i as u32;
+        let mut bit = 0;
+        while bit < 8 {
+            crc = if crc & 1 == 1 {
+                (crc >> 1) ^ 0xEDB8_8320
+            } else {
+                crc >> 1
+            };
+            bit += 1;
+        }
+        table[i] = crc;
+        i += 1;
+    }
+    table
+};
+
+/// Computes the CRC-32 (IEEE 802.3) checksum of `bytes`.
+fn crc32(bytes: &[u8]) -> u32 {
+    !bytes.iter().fold(!0, |crc, byte| {
+        CRC32_TABLE[((crc ^ *byte as u32) & 0xFF) as usize] ^ (crc >> 8)
+    })
+}
@@ -196,3 +196,83 @@ impl DispersionParameter for Variance {
         log2_modulus as f64 + self.0.sqrt().log2()
     }
 }
+
+/// Empirical statistics of a sample of errors measured on ciphertexts.
+///
+/// The errors are expected in signed floating point torus representation, i.e. in
+/// $\left[-\frac{1}{2}, \frac{1}{2}\right)$, as returned by the `*NoiseMeasurementEngine` traits.
+///
+/// On top of the sample mean and variance, the structure contains the result of a
+/// [Jarque-Bera](https://en.wikipedia.org/wiki/Jarque%E2%80%93Bera_test) normality test, which
+/// compares the sample skewness and excess kurtosis with the ones of a gaussian distribution.
+///
+/// # Example:
+///
+/// ```
+/// use concrete_core::prelude::{DispersionParameter, NoiseStatistics};
+/// let errors = vec![-2e-5, 1e-5, -1e-5, 2e-5, 0., 3e-6, -3e-6];
+/// let statistics = NoiseStatistics::from_torus_errors(&errors);
+/// assert_eq!(statistics.sample_count, 7);
+/// assert!(statistics.mean.abs() < 1e-20);
+/// assert!(statistics.variance.get_standard_dev() < 2e-5);
+/// assert!(statistics.is_normal(0.05));
+/// ```
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct NoiseStatistics {
+    /// The number of errors in the sample.
+    pub sample_count: usize,
+    /// The sample mean of the errors.
+    pub mean: f64,
+    /// The (unbiased) sample variance of the errors.
+    pub variance: Variance,
+    /// The sample skewness of the errors.
+    pub skewness: f64,
+    /// The sample excess kurtosis of the errors.
+    pub excess_kurtosis: f64,
+    /// The p-value of the Jarque-Bera normality test performed on the errors.
+    pub normality_p_value: f64,
+}
+
+impl NoiseStatistics {
+    /// Computes the statistics of a sample of signed torus errors.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the sample contains less than two errors.
+    pub fn from_torus_errors(errors: &[f64]) -> NoiseStatistics {
+        assert!(
+            errors.len() >= 2,
+            "At least two errors are needed to compute noise statistics, got {}.",
+            errors.len()
+        );
+        let n = errors.len() as f64;
+        let mean = errors.iter().sum::<f64>() / n;
+        let (m2, m3, m4) = errors.iter().fold((0., 0., 0.), |(m2, m3, m4), e| {
+            let d = e - mean;
+            let d2 = d * d;
+            (m2 + d2, m3 + d2 * d, m4 + d2 * d2)
+        });
+        let (m2, m3, m4) = (m2 / n, m3 / n, m4 / n);
+        let skewness = m3 / m2.powf(1.5);
+        let excess_kurtosis = m4 / (m2 * m2) - 3.;
+        // The Jarque-Bera statistic asymptotically follows a chi-squared distribution with two
+        // degrees of freedom, whose survival function is exp(-x/2).
+        let jarque_bera = n / 6. * (skewness * skewness + excess_kurtosis * excess_kurtosis / 4.);
+        NoiseStatistics {
+            sample_count: errors.len(),
+            mean,
+            variance: Variance(m2 * n / (n - 1.)),
+            skewness,
+            excess_kurtosis,
+            normality_p_value: (-jarque_bera / 2.).exp(),
+        }
+    }
+
+    /// Returns whether the normality hypothesis is accepted at the given significance level.
+    ///
+    /// Note that a sample with null variance (for instance errors measured on trivial ciphertexts)
+    /// is never considered normal.
+    pub fn is_normal(&self, significance_level: f64) -> bool {
+        self.normality_p_value >= significance_level
+    }
+}
@@ -0,0 +1,94 @@
+use super::engine_error;
+use crate::prelude::AbstractEntity;
+use crate::specification::container::{ContainerEntityKind, ContainerFormatError, ContainerHeader};
+use crate::specification::engines::{AbstractEngine, EntityDeserializationError};
+
+engine_error! {
+    EntityContainerDeserializationError for EntityContainerDeserializationEngine @
+    InvalidHeader => "The serialized data do not start with a valid container header.",
+    UnsupportedVersion => "The version of the container is not supported.",
+    EntityKindMismatch => "The container does not hold an entity of the expected kind.",
+    ScalarWidthMismatch => "The container does not hold an entity with the expected scalar width.",
+    InvalidPayloadSize => "The payload of the container is truncated.",
+    ChecksumMismatch => "The checksum of the payload of the container does not match.",
+    ParametersMismatch => "The parameters of the deserialized entity do not match the ones stored \
+                           in the container header.",
+    SizeLimitExceeded => "The serialized entity exceeds the size limit of the engine.",
+    NullDimension => "The deserialized entity has a null dimension, size or count.",
+    InvalidDecomposition => "The decomposition parameters of the deserialized entity are null or \
+                             exceed the precision of its scalars.",
+    InvalidContainerSize => "The length of the container of the deserialized entity does not \
+                             match its dimensions.",
+    InvalidEncoder => "The parameters of the deserialized encoder are not valid."
+}
+
+impl<EngineError: std::error::Error> From<ContainerFormatError>
+    for EntityContainerDeserializationError<EngineError>
+{
+    fn from(err: ContainerFormatError) -> Self {
+        match err {
+            ContainerFormatError::InvalidHeader => Self::InvalidHeader,
+            ContainerFormatError::UnsupportedVersion => Self::UnsupportedVersion,
+            ContainerFormatError::EntityKindMismatch => Self::EntityKindMismatch,
+            ContainerFormatError::ScalarWidthMismatch => Self::ScalarWidthMismatch,
+            ContainerFormatError::InvalidPayloadSize => Self::InvalidPayloadSize,
+            ContainerFormatError::ChecksumMismatch => Self::ChecksumMismatch,
+        }
+    }
+}
+
+impl<EngineError: std::error::Error> From<EntityDeserializationError<EngineError>>
+    for EntityContainerDeserializationError<EngineError>
+{
+    fn from(err: EntityDeserializationError<EngineError>) -> Self {
+        match err {
+            EntityDeserializationError::SizeLimitExceeded => Self::SizeLimitExceeded,
+            EntityDeserializationError::NullDimension => Self::NullDimension,
+            EntityDeserializationError::InvalidDecomposition => Self::InvalidDecomposition,
+            EntityDeserializationError::InvalidContainerSize => Self::InvalidContainerSize,
+            EntityDeserializationError::InvalidEncoder => Self::InvalidEncoder,
+            EntityDeserializationError::Engine(err) => Self::Engine(err),
+        }
+    }
+}
+
+impl<EngineError: std::error::Error> EntityContainerDeserializationError<EngineError> {
+    /// Validates the inputs
+    pub fn perform_generic_checks(
+        serialized: &[u8],
+        expected_kind: ContainerEntityKind,
+        expected_scalar_width: u32,
+    ) -> Result<(), Self> {
+        let (header, payload) = ContainerHeader::split_payload(serialized)?;
+        header.verify_entity(expected_kind, expected_scalar_width)?;
+        header.verify_checksum(payload)?;
+        Ok(())
+    }
+}
+
+/// A trait for engines deserializing entities from a self-describing container.
+///
+/// # Semantics
+///
+/// This [pure](super#operation-semantics) operation recovers an entity from a container generated
+/// by an [`EntityContainerSerializationEngine`](`super::EntityContainerSerializationEngine`). The
+/// header of the container is checked against the expected entity before the payload is
+/// deserialized, and the parameters of the deserialized entity are checked against the header.
+pub trait EntityContainerDeserializationEngine<Serialized, Entity>: AbstractEngine
+where
+    Entity: AbstractEntity,
+{
+    /// Deserializes an entity from a container.
+    fn deserialize_container(
+        &mut self,
+        serialized: Serialized,
+    ) -> Result<Entity, EntityContainerDeserializationError<Self::EngineError>>;
+
+    /// Unsafely deserializes an entity from a container.
+    ///
+    /// # Safety
+    /// For the _general_ safety concerns regarding this operation, refer to the different variants
+    /// of [`EntityContainerDeserializationError`]. For safety concerns _specific_ to an engine,
+    /// refer to the implementer safety section.
+    unsafe fn deserialize_container_unchecked(&mut self, serialized: Serialized) -> Entity;
+}
@@ -0,0 +1,44 @@
+use super::engine_error;
+use crate::prelude::AbstractEntity;
+use crate::specification::engines::{AbstractEngine, EntitySerializationError};
+
+engine_error! {
+    EntityContainerSerializationError for EntityContainerSerializationEngine @
+}
+
+impl<EngineError: std::error::Error> From<EntitySerializationError<EngineError>>
+    for EntityContainerSerializationError<EngineError>
+{
+    fn from(err: EntitySerializationError<EngineError>) -> Self {
+        match err {
+            EntitySerializationError::Engine(err) => Self::Engine(err),
+        }
+    }
+}
+
+/// A trait for engines serializing entities in a self-describing container.
+///
+/// # Semantics
+///
+/// This [pure](super#operation-semantics) operation generates a value containing the serialization
+/// of `entity`, preceded by a header describing its kind, the width of its scalars and its
+/// parameters, along with a checksum of the serialized data. See the
+/// [container format](`crate::specification::container`) for details.
+pub trait EntityContainerSerializationEngine<Entity, Serialized>: AbstractEngine
+where
+    Entity: AbstractEntity,
+{
+    /// Serializes an entity in a container.
+    fn serialize_container(
+        &mut self,
+        entity: &Entity,
+    ) -> Result<Serialized, EntityContainerSerializationError<Self::EngineError>>;
+
+    /// Unsafely serializes an entity in a container.
+    ///
+    /// # Safety
+    /// For the _general_ safety concerns regarding this operation, refer to the different variants
+    /// of [`EntityContainerSerializationError`]. For safety concerns _specific_ to an engine, refer
+    /// to the implementer safety section.
+    unsafe fn serialize_container_unchecked(&mut self, entity: &Entity) -> Serialized;
+}
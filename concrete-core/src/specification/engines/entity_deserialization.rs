@@ -1,9 +1,30 @@
 use super::engine_error;
+use crate::commons::crypto::validation::EntityValidationError;
 use crate::prelude::AbstractEntity;
 use crate::specification::engines::AbstractEngine;
 
 engine_error! {
     EntityDeserializationError for EntityDeserializationEngine @
+    SizeLimitExceeded => "The serialized entity exceeds the size limit of the engine.",
+    NullDimension => "The deserialized entity has a null dimension, size or count.",
+    InvalidDecomposition => "The decomposition parameters of the deserialized entity are null or \
+                             exceed the precision of its scalars.",
+    InvalidContainerSize => "The length of the container of the deserialized entity does not \
+                             match its dimensions.",
+    InvalidEncoder => "The parameters of the deserialized encoder are not valid."
+}
+
+impl<EngineError: std::error::Error> From<EntityValidationError>
+    for EntityDeserializationError<EngineError>
+{
+    fn from(err: EntityValidationError) -> Self {
+        match err {
+            EntityValidationError::NullDimension => Self::NullDimension,
+            EntityValidationError::InvalidDecomposition => Self::InvalidDecomposition,
+            EntityValidationError::InvalidContainerSize => Self::InvalidContainerSize,
+            EntityValidationError::InvalidEncoder => Self::InvalidEncoder,
+        }
+    }
 }
 
 /// A trait for engines deserializing entities.
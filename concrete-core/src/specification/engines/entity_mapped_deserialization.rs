@@ -0,0 +1,71 @@
+use super::engine_error;
+use crate::prelude::AbstractEntity;
+use crate::specification::engines::AbstractEngine;
+use crate::specification::mapped::{MappedEntityKind, MappedFormatError, MappedHeader};
+
+engine_error! {
+    EntityMappedDeserializationError for EntityMappedDeserializationEngine @
+    InvalidHeader => "The bytes do not start with a valid mapped entity header.",
+    UnsupportedVersion => "The version of the mapped entity is not supported.",
+    UnsupportedPlatform => "Mapped entities are not supported on big-endian platforms.",
+    EntityKindMismatch => "The mapped entity is not of the expected kind.",
+    InvalidPayloadSize => "The payload size of the mapped entity does not match its parameters.",
+    MisalignedPayload => "The payload of the mapped entity is not aligned enough to be used in \
+                          place."
+}
+
+impl<EngineError: std::error::Error> From<MappedFormatError>
+    for EntityMappedDeserializationError<EngineError>
+{
+    fn from(err: MappedFormatError) -> Self {
+        match err {
+            MappedFormatError::InvalidHeader => Self::InvalidHeader,
+            MappedFormatError::UnsupportedVersion => Self::UnsupportedVersion,
+            MappedFormatError::UnsupportedPlatform => Self::UnsupportedPlatform,
+            MappedFormatError::EntityKindMismatch => Self::EntityKindMismatch,
+            MappedFormatError::InvalidPayloadSize => Self::InvalidPayloadSize,
+            MappedFormatError::MisalignedPayload => Self::MisalignedPayload,
+        }
+    }
+}
+
+impl<EngineError: std::error::Error> EntityMappedDeserializationError<EngineError> {
+    /// Validates the inputs
+    pub fn perform_generic_checks(
+        bytes: &[u8],
+        expected_kind: MappedEntityKind,
+    ) -> Result<(), Self> {
+        MappedHeader::split_payload(bytes, expected_kind)?;
+        Ok(())
+    }
+}
+
+/// A trait for engines creating entities from bytes in the mapped format.
+///
+/// # Semantics
+///
+/// This [pure](super#operation-semantics) operation generates an entity using the `bytes` in
+/// the [mapped format](`crate::specification::mapped`) as storage. Depending on the entity type,
+/// the payload is either used in place (for view entities), or copied.
+///
+/// Using a view entity, the bytes are neither copied nor deserialized: when they come from a
+/// memory-mapped file, loading a key is immediate, and the key is shared through the page cache by
+/// all the processes mapping the same file.
+pub trait EntityMappedDeserializationEngine<Bytes, Entity>: AbstractEngine
+where
+    Entity: AbstractEntity,
+{
+    /// Creates an entity from bytes in the mapped format.
+    fn deserialize_mapped(
+        &mut self,
+        bytes: Bytes,
+    ) -> Result<Entity, EntityMappedDeserializationError<Self::EngineError>>;
+
+    /// Unsafely creates an entity from bytes in the mapped format.
+    ///
+    /// # Safety
+    /// For the _general_ safety concerns regarding this operation, refer to the different variants
+    /// of [`EntityMappedDeserializationError`]. For safety concerns _specific_ to an engine, refer
+    /// to the implementer safety section.
+    unsafe fn deserialize_mapped_unchecked(&mut self, bytes: Bytes) -> Entity;
+}
@@ -0,0 +1,37 @@
+use super::engine_error;
+use crate::prelude::AbstractEntity;
+use crate::specification::engines::AbstractEngine;
+use std::io::Write;
+
+engine_error! {
+    EntityMappedSerializationError for EntityMappedSerializationEngine @
+}
+
+/// A trait for engines serializing entities in the mapped format.
+///
+/// # Semantics
+///
+/// This [pure](super#operation-semantics) operation writes `entity` to the `writer` stream, in
+/// the [mapped format](`crate::specification::mapped`). The written bytes can then be used in
+/// place, for instance from a memory-mapped file, with an
+/// [`EntityMappedDeserializationEngine`](`super::EntityMappedDeserializationEngine`).
+pub trait EntityMappedSerializationEngine<Entity, Writer>: AbstractEngine
+where
+    Entity: AbstractEntity,
+    Writer: Write,
+{
+    /// Serializes an entity in the mapped format.
+    fn serialize_mapped_into(
+        &mut self,
+        entity: &Entity,
+        writer: &mut Writer,
+    ) -> Result<(), EntityMappedSerializationError<Self::EngineError>>;
+
+    /// Unsafely serializes an entity in the mapped format.
+    ///
+    /// # Safety
+    /// For the _general_ safety concerns regarding this operation, refer to the different variants
+    /// of [`EntityMappedSerializationError`]. For safety concerns _specific_ to an engine, refer to
+    /// the implementer safety section.
+    unsafe fn serialize_mapped_into_unchecked(&mut self, entity: &Entity, writer: &mut Writer);
+}
@@ -0,0 +1,62 @@
+use super::engine_error;
+use crate::commons::crypto::validation::EntityValidationError;
+use crate::prelude::AbstractEntity;
+use crate::specification::engines::AbstractEngine;
+use std::io::Read;
+
+engine_error! {
+    EntityStreamDeserializationError for EntityStreamDeserializationEngine @
+    SizeLimitExceeded => "The serialized entity exceeds the size limit of the engine.",
+    NullDimension => "The deserialized entity has a null dimension, size or count.",
+    InvalidDecomposition => "The decomposition parameters of the deserialized entity are null or \
+                             exceed the precision of its scalars.",
+    InvalidContainerSize => "The length of the container of the deserialized entity does not \
+                             match its dimensions.",
+    InvalidEncoder => "The parameters of the deserialized encoder are not valid."
+}
+
+impl<EngineError: std::error::Error> From<EntityValidationError>
+    for EntityStreamDeserializationError<EngineError>
+{
+    fn from(err: EntityValidationError) -> Self {
+        match err {
+            EntityValidationError::NullDimension => Self::NullDimension,
+            EntityValidationError::InvalidDecomposition => Self::InvalidDecomposition,
+            EntityValidationError::InvalidContainerSize => Self::InvalidContainerSize,
+            EntityValidationError::InvalidEncoder => Self::InvalidEncoder,
+        }
+    }
+}
+
+/// A trait for engines deserializing entities from a stream.
+///
+/// # Semantics
+///
+/// This [pure](super#operation-semantics) operation generates an entity by deserializing the
+/// content read from the `reader` stream.
+///
+/// Contrary to the [`EntityDeserializationEngine`](`super::EntityDeserializationEngine`), the
+/// serialized entity does not need to be loaded in memory beforehand, which makes it possible to
+/// read large keys from files or sockets with bounded memory. Only the bytes of the entity are
+/// consumed from the reader, so several entities can be read one after the other from the same
+/// stream. Wrapping unbuffered readers (such as [`std::fs::File`]) in a [`std::io::BufReader`] is
+/// advised.
+pub trait EntityStreamDeserializationEngine<Reader, Entity>: AbstractEngine
+where
+    Reader: Read,
+    Entity: AbstractEntity,
+{
+    /// Deserializes an entity from a reader.
+    fn deserialize_from(
+        &mut self,
+        reader: &mut Reader,
+    ) -> Result<Entity, EntityStreamDeserializationError<Self::EngineError>>;
+
+    /// Unsafely deserializes an entity from a reader.
+    ///
+    /// # Safety
+    /// For the _general_ safety concerns regarding this operation, refer to the different variants
+    /// of [`EntityStreamDeserializationError`]. For safety concerns _specific_ to an engine, refer
+    /// to the implementer safety section.
+    unsafe fn deserialize_from_unchecked(&mut self, reader: &mut Reader) -> Entity;
+}
@@ -0,0 +1,40 @@
+use super::engine_error;
+use crate::prelude::AbstractEntity;
+use crate::specification::engines::AbstractEngine;
+use std::io::Write;
+
+engine_error! {
+    EntityStreamSerializationError for EntityStreamSerializationEngine @
+}
+
+/// A trait for engines serializing entities to a stream.
+///
+/// # Semantics
+///
+/// This [pure](super#operation-semantics) operation writes the serialization of `entity` to the
+/// `writer` stream.
+///
+/// Contrary to the [`EntitySerializationEngine`](`super::EntitySerializationEngine`), the
+/// serialization is never held as a whole in memory, which makes it possible to write large keys
+/// to files or sockets with bounded memory. Wrapping unbuffered writers (such as
+/// [`std::fs::File`]) in a [`std::io::BufWriter`] is advised.
+pub trait EntityStreamSerializationEngine<Entity, Writer>: AbstractEngine
+where
+    Entity: AbstractEntity,
+    Writer: Write,
+{
+    /// Serializes an entity into a writer.
+    fn serialize_into(
+        &mut self,
+        entity: &Entity,
+        writer: &mut Writer,
+    ) -> Result<(), EntityStreamSerializationError<Self::EngineError>>;
+
+    /// Unsafely serializes an entity into a writer.
+    ///
+    /// # Safety
+    /// For the _general_ safety concerns regarding this operation, refer to the different variants
+    /// of [`EntityStreamSerializationError`]. For safety concerns _specific_ to an engine, refer to
+    /// the implementer safety section.
+    unsafe fn serialize_into_unchecked(&mut self, entity: &Entity, writer: &mut Writer);
+}
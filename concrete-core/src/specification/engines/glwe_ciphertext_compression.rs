@@ -0,0 +1,83 @@
+use super::engine_error;
+use crate::prelude::CiphertextModulusLog;
+use crate::specification::engines::AbstractEngine;
+use crate::specification::entities::{GlweCiphertextEntity, GlweCompressedCiphertextEntity};
+
+engine_error! {
+    GlweCiphertextCompressionError for GlweCiphertextCompressionEngine @
+    NullCiphertextModulusLog => "The ciphertext modulus log must be greater than zero.",
+    CiphertextModulusLogTooLarge => "The ciphertext modulus log must not exceed the precision of \
+                                     the ciphertext."
+}
+
+impl<EngineError: std::error::Error> GlweCiphertextCompressionError<EngineError> {
+    /// Validates the inputs
+    pub fn perform_generic_checks(
+        ciphertext_modulus_log: CiphertextModulusLog,
+        integer_precision: usize,
+    ) -> Result<(), Self> {
+        if ciphertext_modulus_log.0 == 0 {
+            return Err(Self::NullCiphertextModulusLog);
+        }
+
+        if ciphertext_modulus_log.0 > integer_precision {
+            return Err(Self::CiphertextModulusLogTooLarge);
+        }
+
+        Ok(())
+    }
+}
+
+/// A trait for engines compressing GLWE ciphertexts.
+///
+/// # Semantics
+///
+/// This [pure](super#operation-semantics) operation generates a GLWE compressed ciphertext
+/// containing the coefficients of the `input` GLWE ciphertext, switched to the modulus
+/// $2^{\text{ciphertext\_modulus\_log}}$ and bit-packed.
+///
+/// # Formal Definition
+///
+/// ## GLWE ciphertext compression
+/// ###### inputs:
+/// - $\mathsf{ct} = \left( \vec{A} , B\right) \in \mathsf{GLWE}^{k,N}\_{\vec{S}}( \mathsf{pt})$: a
+///   GLWE ciphertext with coefficients modulo $q = 2^w$
+/// - $b$: the logarithm of the smaller modulus, with $1 \le b \le w$
+///
+/// ###### outputs:
+/// - $\tilde{\mathsf{ct}}$: a GLWE compressed ciphertext, storing $b$ bits per coefficient
+///
+/// ###### algorithm:
+/// 1. for each coefficient $c$ of $\mathsf{ct}$, compute $\tilde{c} = \left\lfloor
+///    \frac{2^b \cdot c}{q} \right\rceil \bmod 2^b$
+/// 2. pack the $\tilde{c}$ using $b$ bits each, and output the result
+///
+/// # Noise
+///
+/// The rounding of the coefficients adds some noise to the ciphertext. This noise can be
+/// estimated with `concrete_npe::estimate_glwe_ciphertext_compression_noise`.
+pub trait GlweCiphertextCompressionEngine<Ciphertext, CompressedCiphertext>:
+    AbstractEngine
+where
+    Ciphertext: GlweCiphertextEntity,
+    CompressedCiphertext: GlweCompressedCiphertextEntity,
+{
+    /// Compresses a GLWE ciphertext.
+    fn compress_glwe_ciphertext(
+        &mut self,
+        input: &Ciphertext,
+        ciphertext_modulus_log: CiphertextModulusLog,
+    ) -> Result<CompressedCiphertext, GlweCiphertextCompressionError<Self::EngineError>>;
+
+    /// Unsafely compresses a GLWE ciphertext.
+    ///
+    /// # Safety
+    /// For the _general_ safety concerns regarding this operation, refer to the different variants
+    /// of [`GlweCiphertextCompressionError`]. For safety concerns _specific_ to an engine, refer to
+    /// the implementer safety section.
+    unsafe fn compress_glwe_ciphertext_unchecked(
+        &mut self,
+        input: &Ciphertext,
+        ciphertext_modulus_log: CiphertextModulusLog,
+    ) -> CompressedCiphertext;
+}
@@ -0,0 +1,52 @@
+use super::engine_error;
+use crate::specification::engines::AbstractEngine;
+use crate::specification::entities::{GlweCiphertextEntity, GlweCompressedCiphertextEntity};
+
+engine_error! {
+    GlweCiphertextDecompressionError for GlweCiphertextDecompressionEngine @
+}
+
+/// A trait for engines decompressing GLWE compressed ciphertexts.
+///
+/// # Semantics
+///
+/// This [pure](super#operation-semantics) operation generates a GLWE ciphertext from the `input`
+/// GLWE compressed ciphertext, by switching its coefficients back to the modulus of the output.
+///
+/// # Formal Definition
+///
+/// ## GLWE ciphertext decompression
+/// ###### inputs:
+/// - $\tilde{\mathsf{ct}}$: a GLWE compressed ciphertext, storing $b$ bits per coefficient
+///
+/// ###### outputs:
+/// - $\mathsf{ct} \in \mathsf{GLWE}^{k,N}\_{\vec{S}}( \mathsf{pt})$: a GLWE ciphertext with
+///   coefficients modulo $q = 2^w$
+///
+/// ###### algorithm:
+/// 1. for each packed coefficient $\tilde{c}$ of $\tilde{\mathsf{ct}}$, compute
+///    $c = \tilde{c} \cdot 2^{w - b}$
+/// 2. output the $c$ as a GLWE ciphertext
+pub trait GlweCiphertextDecompressionEngine<CompressedCiphertext, Ciphertext>:
+    AbstractEngine
+where
+    CompressedCiphertext: GlweCompressedCiphertextEntity,
+    Ciphertext: GlweCiphertextEntity,
+{
+    /// Decompresses a GLWE compressed ciphertext.
+    fn decompress_glwe_ciphertext(
+        &mut self,
+        input: &CompressedCiphertext,
+    ) -> Result<Ciphertext, GlweCiphertextDecompressionError<Self::EngineError>>;
+
+    /// Unsafely decompresses a GLWE compressed ciphertext.
+    ///
+    /// # Safety
+    /// For the _general_ safety concerns regarding this operation, refer to the different variants
+    /// of [`GlweCiphertextDecompressionError`]. For safety concerns _specific_ to an engine, refer
+    /// to the implementer safety section.
+    unsafe fn decompress_glwe_ciphertext_unchecked(
+        &mut self,
+        input: &CompressedCiphertext,
+    ) -> Ciphertext;
+}
@@ -0,0 +1,81 @@
+use super::engine_error;
+use crate::prelude::{AbstractEngine, GlweCiphertextIndex};
+
+use crate::specification::entities::{GlweCiphertextEntity, GlweSeededCiphertextVectorEntity};
+
+engine_error! {
+    GlweSeededCiphertextVectorElementExpansionError
+    for GlweSeededCiphertextVectorElementExpansionEngine @
+    OutOfVectorIndex => "The input vector must contain the index."
+}
+
+impl<EngineError: std::error::Error> GlweSeededCiphertextVectorElementExpansionError<EngineError> {
+    /// Validates the inputs
+    pub fn perform_generic_checks<SeededCiphertextVector>(
+        seeded_ciphertext_vector: &SeededCiphertextVector,
+        index: GlweCiphertextIndex,
+    ) -> Result<(), Self>
+    where
+        SeededCiphertextVector: GlweSeededCiphertextVectorEntity,
+    {
+        if index.0 >= seeded_ciphertext_vector.glwe_ciphertext_count().0 {
+            return Err(Self::OutOfVectorIndex);
+        }
+        Ok(())
+    }
+}
+
+/// A trait for engines expanding a single GLWE ciphertext out of a GLWE seeded ciphertext vector.
+///
+/// # Semantics
+///
+/// This [pure](super#operation-semantics) operation generates a GLWE ciphertext containing the
+/// expansion of the `index`-th ciphertext of the `seeded_ciphertext_vector` GLWE seeded ciphertext
+/// vector. The result is the same as the `index`-th ciphertext of the vector returned by the
+/// [`GlweSeededCiphertextVectorToGlweCiphertextVectorTransformationEngine`](
+/// super::GlweSeededCiphertextVectorToGlweCiphertextVectorTransformationEngine), but the masks of
+/// the other ciphertexts of the vector are not generated.
+///
+/// # Formal Definition
+///
+/// ## GLWE seeded ciphertext vector element expansion
+/// ###### inputs:
+/// - $G$: a CSPRNG working with seeds from $\mathcal{S}$
+/// - $\vec{\tilde{\mathsf{ct}}} = \left( \mathsf{seed} , \vec{\tilde{b}}\right)$: a seeded GLWE
+///   ciphertext vector of $t$ ciphertexts, with masks of $m$ elements
+/// - $i \in [0, t[$: the index of the ciphertext to expand
+///
+/// ###### outputs:
+/// - $\mathsf{ct} = \left( \vec{a} , b\right)$: a GLWE ciphertext
+///
+/// ###### algorithm:
+/// 1. Seed $G$ with the seed $\mathsf{seed}\in\mathcal{S}$
+/// 2. skip the $i \cdot m$ first integers of $G$
+/// 3. uniformly sample $m$ integers from $G$ and store them in $\vec{a}$
+/// 4. set $b = \tilde{b\_i}$
+/// 5. output $\left( \vec{a} , b\right)$
+pub trait GlweSeededCiphertextVectorElementExpansionEngine<SeededCiphertextVector, Ciphertext>:
+    AbstractEngine
+where
+    SeededCiphertextVector: GlweSeededCiphertextVectorEntity,
+    Ciphertext: GlweCiphertextEntity,
+{
+    /// Expands the `index`-th ciphertext of a GLWE seeded ciphertext vector.
+    fn expand_glwe_seeded_ciphertext_vector_element(
+        &mut self,
+        seeded_ciphertext_vector: &SeededCiphertextVector,
+        index: GlweCiphertextIndex,
+    ) -> Result<Ciphertext, GlweSeededCiphertextVectorElementExpansionError<Self::EngineError>>;
+
+    /// Unsafely expands the `index`-th ciphertext of a GLWE seeded ciphertext vector.
+    ///
+    /// # Safety
+    /// For the _general_ safety concerns regarding this operation, refer to the different variants
+    /// of [`GlweSeededCiphertextVectorElementExpansionError`]. For safety concerns _specific_ to an
+    /// engine, refer to the implementer safety section.
+    unsafe fn expand_glwe_seeded_ciphertext_vector_element_unchecked(
+        &mut self,
+        seeded_ciphertext_vector: &SeededCiphertextVector,
+        index: GlweCiphertextIndex,
+    ) -> Ciphertext;
+}
@@ -0,0 +1,82 @@
+use super::engine_error;
+use crate::prelude::CiphertextModulusLog;
+use crate::specification::engines::AbstractEngine;
+use crate::specification::entities::{LweCiphertextEntity, LweCompressedCiphertextEntity};
+
+engine_error! {
+    LweCiphertextCompressionError for LweCiphertextCompressionEngine @
+    NullCiphertextModulusLog => "The ciphertext modulus log must be greater than zero.",
+    CiphertextModulusLogTooLarge => "The ciphertext modulus log must not exceed the precision of \
+                                     the ciphertext."
+}
+
+impl<EngineError: std::error::Error> LweCiphertextCompressionError<EngineError> {
+    /// Validates the inputs
+    pub fn perform_generic_checks(
+        ciphertext_modulus_log: CiphertextModulusLog,
+        integer_precision: usize,
+    ) -> Result<(), Self> {
+        if ciphertext_modulus_log.0 == 0 {
+            return Err(Self::NullCiphertextModulusLog);
+        }
+
+        if ciphertext_modulus_log.0 > integer_precision {
+            return Err(Self::CiphertextModulusLogTooLarge);
+        }
+
+        Ok(())
+    }
+}
+
+/// A trait for engines compressing LWE ciphertexts.
+///
+/// # Semantics
+///
+/// This [pure](super#operation-semantics) operation generates an LWE compressed ciphertext
+/// containing the coefficients of the `input` LWE ciphertext, switched to the modulus
+/// $2^{\text{ciphertext\_modulus\_log}}$ and bit-packed.
+///
+/// # Formal Definition
+///
+/// ## LWE ciphertext compression
+/// ###### inputs:
+/// - $\mathsf{ct} = \left( \vec{a} , b\right) \in \mathsf{LWE}^n\_{\vec{s}}( \mathsf{pt})$: an LWE
+///   ciphertext with coefficients modulo $q = 2^w$
+/// - $b$: the logarithm of the smaller modulus, with $1 \le b \le w$
+///
+/// ###### outputs:
+/// - $\tilde{\mathsf{ct}}$: an LWE compressed ciphertext, storing $b$ bits per coefficient
+///
+/// ###### algorithm:
+/// 1. for each coefficient $c$ of $\mathsf{ct}$, compute $\tilde{c} = \left\lfloor
+///    \frac{2^b \cdot c}{q} \right\rceil \bmod 2^b$
+/// 2. pack the $\tilde{c}$ using $b$ bits each, and output the result
+///
+/// # Noise
+///
+/// The rounding of the coefficients adds some noise to the ciphertext. This noise can be
+/// estimated with `concrete_npe::estimate_lwe_ciphertext_compression_noise`.
+pub trait LweCiphertextCompressionEngine<Ciphertext, CompressedCiphertext>: AbstractEngine
+where
+    Ciphertext: LweCiphertextEntity,
+    CompressedCiphertext: LweCompressedCiphertextEntity,
+{
+    /// Compresses an LWE ciphertext.
+    fn compress_lwe_ciphertext(
+        &mut self,
+        input: &Ciphertext,
+        ciphertext_modulus_log: CiphertextModulusLog,
+    ) -> Result<CompressedCiphertext, LweCiphertextCompressionError<Self::EngineError>>;
+
+    /// Unsafely compresses an LWE ciphertext.
+    ///
+    /// # Safety
+    /// For the _general_ safety concerns regarding this operation, refer to the different variants
+    /// of [`LweCiphertextCompressionError`]. For safety concerns _specific_ to an engine, refer to
+    /// the implementer safety section.
+    unsafe fn compress_lwe_ciphertext_unchecked(
+        &mut self,
+        input: &Ciphertext,
+        ciphertext_modulus_log: CiphertextModulusLog,
+    ) -> CompressedCiphertext;
+}
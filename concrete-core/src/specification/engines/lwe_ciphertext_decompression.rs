@@ -0,0 +1,52 @@
+use super::engine_error;
+use crate::specification::engines::AbstractEngine;
+use crate::specification::entities::{LweCiphertextEntity, LweCompressedCiphertextEntity};
+
+engine_error! {
+    LweCiphertextDecompressionError for LweCiphertextDecompressionEngine @
+}
+
+/// A trait for engines decompressing LWE compressed ciphertexts.
+///
+/// # Semantics
+///
+/// This [pure](super#operation-semantics) operation generates an LWE ciphertext from the `input`
+/// LWE compressed ciphertext, by switching its coefficients back to the modulus of the output.
+///
+/// # Formal Definition
+///
+/// ## LWE ciphertext decompression
+/// ###### inputs:
+/// - $\tilde{\mathsf{ct}}$: an LWE compressed ciphertext, storing $b$ bits per coefficient
+///
+/// ###### outputs:
+/// - $\mathsf{ct} \in \mathsf{LWE}^n\_{\vec{s}}( \mathsf{pt})$: an LWE ciphertext with coefficients
+///   modulo $q = 2^w$
+///
+/// ###### algorithm:
+/// 1. for each packed coefficient $\tilde{c}$ of $\tilde{\mathsf{ct}}$, compute
+///    $c = \tilde{c} \cdot 2^{w - b}$
+/// 2. output the $c$ as an LWE ciphertext
+pub trait LweCiphertextDecompressionEngine<CompressedCiphertext, Ciphertext>:
+    AbstractEngine
+where
+    CompressedCiphertext: LweCompressedCiphertextEntity,
+    Ciphertext: LweCiphertextEntity,
+{
+    /// Decompresses an LWE compressed ciphertext.
+    fn decompress_lwe_ciphertext(
+        &mut self,
+        input: &CompressedCiphertext,
+    ) -> Result<Ciphertext, LweCiphertextDecompressionError<Self::EngineError>>;
+
+    /// Unsafely decompresses an LWE compressed ciphertext.
+    ///
+    /// # Safety
+    /// For the _general_ safety concerns regarding this operation, refer to the different variants
+    /// of [`LweCiphertextDecompressionError`]. For safety concerns _specific_ to an engine, refer
+    /// to the implementer safety section.
+    unsafe fn decompress_lwe_ciphertext_unchecked(
+        &mut self,
+        input: &CompressedCiphertext,
+    ) -> Ciphertext;
+}
@@ -0,0 +1,115 @@
+use super::engine_error;
+use crate::specification::engines::AbstractEngine;
+
+use crate::specification::entities::{
+    GlweCiphertextEntity, LweBootstrapKeyEntity, LweCiphertextEntity, LweKeyswitchKeyEntity,
+};
+
+engine_error! {
+    LweCiphertextDiscardingBootstrapKeyswitchError for LweCiphertextDiscardingBootstrapKeyswitchEngine @
+    InputLweDimensionMismatch => "The input ciphertext and bootstrap key input LWE dimension must \
+                                  be the same.",
+    KeysLweDimensionMismatch => "The bootstrap key output LWE dimension and keyswitch key input \
+                                 LWE dimension must be the same.",
+    OutputLweDimensionMismatch => "The output ciphertext and keyswitch key output LWE dimension \
+                                   must be the same.",
+    AccumulatorPolynomialSizeMismatch => "The accumulator and bootstrap key polynomial sizes must \
+                                          be the same.",
+    AccumulatorGlweDimensionMismatch => "The accumulator and bootstrap key GLWE dimensions must be \
+                                         the same."
+}
+
+impl<EngineError: std::error::Error> LweCiphertextDiscardingBootstrapKeyswitchError<EngineError> {
+    /// Validates the inputs
+    pub fn perform_generic_checks<
+        BootstrapKey,
+        KeyswitchKey,
+        Accumulator,
+        InputCiphertext,
+        OutputCiphertext,
+    >(
+        output: &OutputCiphertext,
+        input: &InputCiphertext,
+        acc: &Accumulator,
+        bsk: &BootstrapKey,
+        ksk: &KeyswitchKey,
+    ) -> Result<(), Self>
+    where
+        BootstrapKey: LweBootstrapKeyEntity,
+        KeyswitchKey: LweKeyswitchKeyEntity,
+        Accumulator: GlweCiphertextEntity,
+        InputCiphertext: LweCiphertextEntity,
+        OutputCiphertext: LweCiphertextEntity,
+    {
+        if input.lwe_dimension() != bsk.input_lwe_dimension() {
+            return Err(Self::InputLweDimensionMismatch);
+        }
+        if acc.polynomial_size() != bsk.polynomial_size() {
+            return Err(Self::AccumulatorPolynomialSizeMismatch);
+        }
+        if acc.glwe_dimension() != bsk.glwe_dimension() {
+            return Err(Self::AccumulatorGlweDimensionMismatch);
+        }
+        if bsk.output_lwe_dimension() != ksk.input_lwe_dimension() {
+            return Err(Self::KeysLweDimensionMismatch);
+        }
+        if output.lwe_dimension() != ksk.output_lwe_dimension() {
+            return Err(Self::OutputLweDimensionMismatch);
+        }
+        Ok(())
+    }
+}
+
+/// A trait for engines bootstrapping then keyswitching (discarding) LWE ciphertexts.
+///
+/// # Semantics
+///
+/// This [discarding](super#operation-semantics) operation fills the `output` LWE ciphertext with
+/// the keyswitch of the bootstrap of the `input` LWE ciphertext, using the `acc` accumulator as
+/// lookup-table, the `bsk` bootstrap key and the `ksk` keyswitch key. The intermediate ciphertext
+/// is never exposed to the caller, which allows engines to avoid allocating it.
+///
+/// # Formal Definition
+///
+/// This is the composition of the
+/// [`LWE bootstrap`](`crate::specification::engines::LweCiphertextDiscardingBootstrapEngine`) and
+/// of the [`LWE
+/// keyswitch`](`crate::specification::engines::LweCiphertextDiscardingKeyswitchEngine`).
+pub trait LweCiphertextDiscardingBootstrapKeyswitchEngine<
+    BootstrapKey,
+    KeyswitchKey,
+    Accumulator,
+    InputCiphertext,
+    OutputCiphertext,
+>: AbstractEngine where
+    BootstrapKey: LweBootstrapKeyEntity,
+    KeyswitchKey: LweKeyswitchKeyEntity,
+    Accumulator: GlweCiphertextEntity,
+    InputCiphertext: LweCiphertextEntity,
+    OutputCiphertext: LweCiphertextEntity,
+{
+    /// Bootstraps then keyswitches an LWE ciphertext.
+    fn discard_bootstrap_keyswitch_lwe_ciphertext(
+        &mut self,
+        output: &mut OutputCiphertext,
+        input: &InputCiphertext,
+        acc: &Accumulator,
+        bsk: &BootstrapKey,
+        ksk: &KeyswitchKey,
+    ) -> Result<(), LweCiphertextDiscardingBootstrapKeyswitchError<Self::EngineError>>;
+
+    /// Unsafely bootstraps then keyswitches an LWE ciphertext.
+    ///
+    /// # Safety
+    /// For the _general_ safety concerns regarding this operation, refer to the different variants
+    /// of [`LweCiphertextDiscardingBootstrapKeyswitchError`]. For safety concerns _specific_ to an
+    /// engine, refer to the implementer safety section.
+    unsafe fn discard_bootstrap_keyswitch_lwe_ciphertext_unchecked(
+        &mut self,
+        output: &mut OutputCiphertext,
+        input: &InputCiphertext,
+        acc: &Accumulator,
+        bsk: &BootstrapKey,
+        ksk: &KeyswitchKey,
+    );
+}
@@ -0,0 +1,115 @@
+use super::engine_error;
+use crate::specification::engines::AbstractEngine;
+
+use crate::specification::entities::{
+    GlweCiphertextEntity, LweBootstrapKeyEntity, LweCiphertextEntity, LweKeyswitchKeyEntity,
+};
+
+engine_error! {
+    LweCiphertextDiscardingKeyswitchBootstrapError for LweCiphertextDiscardingKeyswitchBootstrapEngine @
+    InputLweDimensionMismatch => "The input ciphertext and keyswitch key input LWE dimension must \
+                                  be the same.",
+    KeysLweDimensionMismatch => "The keyswitch key output LWE dimension and bootstrap key input \
+                                 LWE dimension must be the same.",
+    OutputLweDimensionMismatch => "The output ciphertext dimension and bootstrap key size \
+                                   (dimension * polynomial size) must be the same.",
+    AccumulatorPolynomialSizeMismatch => "The accumulator and bootstrap key polynomial sizes must \
+                                          be the same.",
+    AccumulatorGlweDimensionMismatch => "The accumulator and bootstrap key GLWE dimensions must be \
+                                         the same."
+}
+
+impl<EngineError: std::error::Error> LweCiphertextDiscardingKeyswitchBootstrapError<EngineError> {
+    /// Validates the inputs
+    pub fn perform_generic_checks<
+        KeyswitchKey,
+        BootstrapKey,
+        Accumulator,
+        InputCiphertext,
+        OutputCiphertext,
+    >(
+        output: &OutputCiphertext,
+        input: &InputCiphertext,
+        acc: &Accumulator,
+        ksk: &KeyswitchKey,
+        bsk: &BootstrapKey,
+    ) -> Result<(), Self>
+    where
+        KeyswitchKey: LweKeyswitchKeyEntity,
+        BootstrapKey: LweBootstrapKeyEntity,
+        Accumulator: GlweCiphertextEntity,
+        InputCiphertext: LweCiphertextEntity,
+        OutputCiphertext: LweCiphertextEntity,
+    {
+        if input.lwe_dimension() != ksk.input_lwe_dimension() {
+            return Err(Self::InputLweDimensionMismatch);
+        }
+        if ksk.output_lwe_dimension() != bsk.input_lwe_dimension() {
+            return Err(Self::KeysLweDimensionMismatch);
+        }
+        if acc.polynomial_size() != bsk.polynomial_size() {
+            return Err(Self::AccumulatorPolynomialSizeMismatch);
+        }
+        if acc.glwe_dimension() != bsk.glwe_dimension() {
+            return Err(Self::AccumulatorGlweDimensionMismatch);
+        }
+        if output.lwe_dimension() != bsk.output_lwe_dimension() {
+            return Err(Self::OutputLweDimensionMismatch);
+        }
+        Ok(())
+    }
+}
+
+/// A trait for engines keyswitching then bootstrapping (discarding) LWE ciphertexts.
+///
+/// # Semantics
+///
+/// This [discarding](super#operation-semantics) operation fills the `output` LWE ciphertext with
+/// the bootstrap of the keyswitch of the `input` LWE ciphertext, using the `ksk` keyswitch key, the
+/// `acc` accumulator as lookup-table, and the `bsk` bootstrap key. The intermediate ciphertext is
+/// never exposed to the caller, which allows engines to avoid allocating it.
+///
+/// # Formal Definition
+///
+/// This is the composition of the
+/// [`LWE keyswitch`](`crate::specification::engines::LweCiphertextDiscardingKeyswitchEngine`) and
+/// of the [`LWE
+/// bootstrap`](`crate::specification::engines::LweCiphertextDiscardingBootstrapEngine`).
+pub trait LweCiphertextDiscardingKeyswitchBootstrapEngine<
+    KeyswitchKey,
+    BootstrapKey,
+    Accumulator,
+    InputCiphertext,
+    OutputCiphertext,
+>: AbstractEngine where
+    KeyswitchKey: LweKeyswitchKeyEntity,
+    BootstrapKey: LweBootstrapKeyEntity,
+    Accumulator: GlweCiphertextEntity,
+    InputCiphertext: LweCiphertextEntity,
+    OutputCiphertext: LweCiphertextEntity,
+{
+    /// Keyswitches then bootstraps an LWE ciphertext.
+    fn discard_keyswitch_bootstrap_lwe_ciphertext(
+        &mut self,
+        output: &mut OutputCiphertext,
+        input: &InputCiphertext,
+        acc: &Accumulator,
+        ksk: &KeyswitchKey,
+        bsk: &BootstrapKey,
+    ) -> Result<(), LweCiphertextDiscardingKeyswitchBootstrapError<Self::EngineError>>;
+
+    /// Unsafely keyswitches then bootstraps an LWE ciphertext.
+    ///
+    /// # Safety
+    /// For the _general_ safety concerns regarding this operation, refer to the different variants
+    /// of [`LweCiphertextDiscardingKeyswitchBootstrapError`]. For safety concerns _specific_ to an
+    /// engine, refer to the implementer safety section.
+    unsafe fn discard_keyswitch_bootstrap_lwe_ciphertext_unchecked(
+        &mut self,
+        output: &mut OutputCiphertext,
+        input: &InputCiphertext,
+        acc: &Accumulator,
+        ksk: &KeyswitchKey,
+        bsk: &BootstrapKey,
+    );
+}
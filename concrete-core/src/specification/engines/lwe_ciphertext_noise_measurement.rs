@@ -0,0 +1,78 @@
+use super::engine_error;
+use crate::specification::engines::AbstractEngine;
+use crate::specification::entities::{LweCiphertextEntity, LweSecretKeyEntity, PlaintextEntity};
+
+engine_error! {
+    LweCiphertextNoiseMeasurementError for LweCiphertextNoiseMeasurementEngine @
+    LweDimensionMismatch => "The key and input ciphertext LWE dimensions must be the same."
+}
+
+impl<EngineError: std::error::Error> LweCiphertextNoiseMeasurementError<EngineError> {
+    /// Validates the inputs
+    pub fn perform_generic_checks<SecretKey, Ciphertext>(
+        key: &SecretKey,
+        input: &Ciphertext,
+    ) -> Result<(), Self>
+    where
+        SecretKey: LweSecretKeyEntity,
+        Ciphertext: LweCiphertextEntity,
+    {
+        if key.lwe_dimension() != input.lwe_dimension() {
+            return Err(Self::LweDimensionMismatch);
+        }
+        Ok(())
+    }
+}
+
+/// A trait for engines measuring the noise of LWE ciphertexts.
+///
+/// # Semantics
+///
+/// This [pure](super#operation-semantics) operation decrypts the `input` LWE ciphertext under the
+/// `key` secret key, and returns the signed distance between the decrypted plaintext and the
+/// `expected` plaintext, in floating point torus representation.
+///
+/// # Formal Definition
+///
+/// ###### inputs:
+/// - $\mathsf{ct} = \left( \vec{a} , b\right) \in \mathsf{LWE}^n\_{\vec{s}}( \mathsf{pt} )\subseteq
+///   \mathbb{Z}\_q^{(n+1)}$: an LWE ciphertext
+/// - $\vec{s}\in\mathbb{Z}\_q^n$: a secret key
+/// - $\mathsf{pt}\in\mathbb{Z}\_q$: the plaintext expected to be encrypted in $\mathsf{ct}$
+///
+/// ###### outputs:
+/// - $e\in\left[-\frac{1}{2}, \frac{1}{2}\right)$: the error contained in $\mathsf{ct}$
+///
+/// ###### algorithm:
+/// 1. compute $\mathsf{pt}' = b - \left\langle \vec{a} , \vec{s} \right\rangle \in\mathbb{Z}\_q$
+/// 2. compute the centered representative $d\in\left[-\frac{q}{2}, \frac{q}{2}\right)$ of
+///    $\mathsf{pt}' - \mathsf{pt}$
+/// 3. output $e = \frac{d}{q}$
+pub trait LweCiphertextNoiseMeasurementEngine<SecretKey, Ciphertext, Plaintext>:
+    AbstractEngine
+where
+    SecretKey: LweSecretKeyEntity,
+    Ciphertext: LweCiphertextEntity,
+    Plaintext: PlaintextEntity,
+{
+    /// Measures the noise of an LWE ciphertext.
+    fn measure_lwe_ciphertext_noise(
+        &mut self,
+        key: &SecretKey,
+        input: &Ciphertext,
+        expected: &Plaintext,
+    ) -> Result<f64, LweCiphertextNoiseMeasurementError<Self::EngineError>>;
+
+    /// Unsafely measures the noise of an LWE ciphertext.
+    ///
+    /// # Safety
+    /// For the _general_ safety concerns regarding this operation, refer to the different variants
+    /// of [`LweCiphertextNoiseMeasurementError`]. For safety concerns _specific_ to an engine,
+    /// refer to the implementer safety section.
+    unsafe fn measure_lwe_ciphertext_noise_unchecked(
+        &mut self,
+        key: &SecretKey,
+        input: &Ciphertext,
+        expected: &Plaintext,
+    ) -> f64;
+}
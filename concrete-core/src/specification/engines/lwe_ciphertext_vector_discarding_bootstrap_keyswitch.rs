@@ -0,0 +1,124 @@
+use super::engine_error;
+use crate::specification::engines::AbstractEngine;
+
+use crate::specification::entities::{
+    GlweCiphertextVectorEntity, LweBootstrapKeyEntity, LweCiphertextVectorEntity,
+    LweKeyswitchKeyEntity,
+};
+
+engine_error! {
+    LweCiphertextVectorDiscardingBootstrapKeyswitchError for LweCiphertextVectorDiscardingBootstrapKeyswitchEngine @
+    InputLweDimensionMismatch => "The input vector and bootstrap key input LWE dimension must \
+                                  be the same.",
+    KeysLweDimensionMismatch => "The bootstrap key output LWE dimension and keyswitch key input \
+                                 LWE dimension must be the same.",
+    OutputLweDimensionMismatch => "The output vector and keyswitch key output LWE dimension \
+                                   must be the same.",
+    AccumulatorPolynomialSizeMismatch => "The accumulator and bootstrap key polynomial sizes must \
+                                          be the same.",
+    AccumulatorGlweDimensionMismatch => "The accumulator and bootstrap key GLWE dimensions must be \
+                                         the same.",
+    AccumulatorCountMismatch => "The accumulator count and input ciphertext count must be the \
+                                 same.",
+    CiphertextCountMismatch => "The input and output ciphertext count must be the same."
+}
+
+impl<EngineError: std::error::Error>
+    LweCiphertextVectorDiscardingBootstrapKeyswitchError<EngineError>
+{
+    /// Validates the inputs
+    pub fn perform_generic_checks<
+        BootstrapKey,
+        KeyswitchKey,
+        AccumulatorVector,
+        InputCiphertextVector,
+        OutputCiphertextVector,
+    >(
+        output: &OutputCiphertextVector,
+        input: &InputCiphertextVector,
+        acc: &AccumulatorVector,
+        bsk: &BootstrapKey,
+        ksk: &KeyswitchKey,
+    ) -> Result<(), Self>
+    where
+        BootstrapKey: LweBootstrapKeyEntity,
+        KeyswitchKey: LweKeyswitchKeyEntity,
+        AccumulatorVector: GlweCiphertextVectorEntity,
+        InputCiphertextVector: LweCiphertextVectorEntity,
+        OutputCiphertextVector: LweCiphertextVectorEntity,
+    {
+        if input.lwe_dimension() != bsk.input_lwe_dimension() {
+            return Err(Self::InputLweDimensionMismatch);
+        }
+        if acc.polynomial_size() != bsk.polynomial_size() {
+            return Err(Self::AccumulatorPolynomialSizeMismatch);
+        }
+        if acc.glwe_dimension() != bsk.glwe_dimension() {
+            return Err(Self::AccumulatorGlweDimensionMismatch);
+        }
+        if bsk.output_lwe_dimension() != ksk.input_lwe_dimension() {
+            return Err(Self::KeysLweDimensionMismatch);
+        }
+        if output.lwe_dimension() != ksk.output_lwe_dimension() {
+            return Err(Self::OutputLweDimensionMismatch);
+        }
+        if acc.glwe_ciphertext_count().0 != input.lwe_ciphertext_count().0 {
+            return Err(Self::AccumulatorCountMismatch);
+        }
+        if input.lwe_ciphertext_count() != output.lwe_ciphertext_count() {
+            return Err(Self::CiphertextCountMismatch);
+        }
+        Ok(())
+    }
+}
+
+/// A trait for engines bootstrapping then keyswitching (discarding) LWE ciphertext vectors.
+///
+/// # Semantics
+///
+/// This [discarding](super#operation-semantics) operation fills the `output` LWE ciphertext vector
+/// with the element-wise bootstrap and keyswitch of the `input` LWE ciphertext vector, using the
+/// `acc` accumulator vector as lookup-tables (one per ciphertext). The intermediate ciphertexts are
+/// never exposed to the caller, which allows engines to avoid allocating them.
+///
+/// # Formal Definition
+///
+/// cf [`here`](`crate::specification::engines::LweCiphertextDiscardingBootstrapKeyswitchEngine`)
+pub trait LweCiphertextVectorDiscardingBootstrapKeyswitchEngine<
+    BootstrapKey,
+    KeyswitchKey,
+    AccumulatorVector,
+    InputCiphertextVector,
+    OutputCiphertextVector,
+>: AbstractEngine where
+    BootstrapKey: LweBootstrapKeyEntity,
+    KeyswitchKey: LweKeyswitchKeyEntity,
+    AccumulatorVector: GlweCiphertextVectorEntity,
+    InputCiphertextVector: LweCiphertextVectorEntity,
+    OutputCiphertextVector: LweCiphertextVectorEntity,
+{
+    /// Bootstraps then keyswitches an LWE ciphertext vector.
+    fn discard_bootstrap_keyswitch_lwe_ciphertext_vector(
+        &mut self,
+        output: &mut OutputCiphertextVector,
+        input: &InputCiphertextVector,
+        acc: &AccumulatorVector,
+        bsk: &BootstrapKey,
+        ksk: &KeyswitchKey,
+    ) -> Result<(), LweCiphertextVectorDiscardingBootstrapKeyswitchError<Self::EngineError>>;
+
+    /// Unsafely bootstraps then keyswitches an LWE ciphertext vector.
+    ///
+    /// # Safety
+    /// For the _general_ safety concerns regarding this operation, refer to the different variants
+    /// of [`LweCiphertextVectorDiscardingBootstrapKeyswitchError`]. For safety concerns _specific_
+    /// to an engine, refer to the implementer safety section.
+    unsafe fn discard_bootstrap_keyswitch_lwe_ciphertext_vector_unchecked(
+        &mut self,
+        output: &mut OutputCiphertextVector,
+        input: &InputCiphertextVector,
+        acc: &AccumulatorVector,
+        bsk: &BootstrapKey,
+        ksk: &KeyswitchKey,
+    );
+}
@@ -0,0 +1,124 @@
+use super::engine_error;
+use crate::specification::engines::AbstractEngine;
+
+use crate::specification::entities::{
+    GlweCiphertextVectorEntity, LweBootstrapKeyEntity, LweCiphertextVectorEntity,
+    LweKeyswitchKeyEntity,
+};
+
+engine_error! {
+    LweCiphertextVectorDiscardingKeyswitchBootstrapError for LweCiphertextVectorDiscardingKeyswitchBootstrapEngine @
+    InputLweDimensionMismatch => "The input vector and keyswitch key input LWE dimension must \
+                                  be the same.",
+    KeysLweDimensionMismatch => "The keyswitch key output LWE dimension and bootstrap key input \
+                                 LWE dimension must be the same.",
+    OutputLweDimensionMismatch => "The output vector and bootstrap key size \
+                                   (dimension * polynomial size) must be the same.",
+    AccumulatorPolynomialSizeMismatch => "The accumulator and bootstrap key polynomial sizes must \
+                                          be the same.",
+    AccumulatorGlweDimensionMismatch => "The accumulator and bootstrap key GLWE dimensions must be \
+                                         the same.",
+    AccumulatorCountMismatch => "The accumulator count and input ciphertext count must be the \
+                                 same.",
+    CiphertextCountMismatch => "The input and output ciphertext count must be the same."
+}
+
+impl<EngineError: std::error::Error>
+    LweCiphertextVectorDiscardingKeyswitchBootstrapError<EngineError>
+{
+    /// Validates the inputs
+    pub fn perform_generic_checks<
+        KeyswitchKey,
+        BootstrapKey,
+        AccumulatorVector,
+        InputCiphertextVector,
+        OutputCiphertextVector,
+    >(
+        output: &OutputCiphertextVector,
+        input: &InputCiphertextVector,
+        acc: &AccumulatorVector,
+        ksk: &KeyswitchKey,
+        bsk: &BootstrapKey,
+    ) -> Result<(), Self>
+    where
+        KeyswitchKey: LweKeyswitchKeyEntity,
+        BootstrapKey: LweBootstrapKeyEntity,
+        AccumulatorVector: GlweCiphertextVectorEntity,
+        InputCiphertextVector: LweCiphertextVectorEntity,
+        OutputCiphertextVector: LweCiphertextVectorEntity,
+    {
+        if input.lwe_dimension() != ksk.input_lwe_dimension() {
+            return Err(Self::InputLweDimensionMismatch);
+        }
+        if ksk.output_lwe_dimension() != bsk.input_lwe_dimension() {
+            return Err(Self::KeysLweDimensionMismatch);
+        }
+        if acc.polynomial_size() != bsk.polynomial_size() {
+            return Err(Self::AccumulatorPolynomialSizeMismatch);
+        }
+        if acc.glwe_dimension() != bsk.glwe_dimension() {
+            return Err(Self::AccumulatorGlweDimensionMismatch);
+        }
+        if output.lwe_dimension() != bsk.output_lwe_dimension() {
+            return Err(Self::OutputLweDimensionMismatch);
+        }
+        if acc.glwe_ciphertext_count().0 != input.lwe_ciphertext_count().0 {
+            return Err(Self::AccumulatorCountMismatch);
+        }
+        if input.lwe_ciphertext_count() != output.lwe_ciphertext_count() {
+            return Err(Self::CiphertextCountMismatch);
+        }
+        Ok(())
+    }
+}
+
+/// A trait for engines keyswitching then bootstrapping (discarding) LWE ciphertext vectors.
+///
+/// # Semantics
+///
+/// This [discarding](super#operation-semantics) operation fills the `output` LWE ciphertext vector
+/// with the element-wise keyswitch and bootstrap of the `input` LWE ciphertext vector, using the
+/// `acc` accumulator vector as lookup-tables (one per ciphertext). The intermediate ciphertexts are
+/// never exposed to the caller, which allows engines to avoid allocating them.
+///
+/// # Formal Definition
+///
+/// cf [`here`](`crate::specification::engines::LweCiphertextDiscardingKeyswitchBootstrapEngine`)
+pub trait LweCiphertextVectorDiscardingKeyswitchBootstrapEngine<
+    KeyswitchKey,
+    BootstrapKey,
+    AccumulatorVector,
+    InputCiphertextVector,
+    OutputCiphertextVector,
+>: AbstractEngine where
+    KeyswitchKey: LweKeyswitchKeyEntity,
+    BootstrapKey: LweBootstrapKeyEntity,
+    AccumulatorVector: GlweCiphertextVectorEntity,
+    InputCiphertextVector: LweCiphertextVectorEntity,
+    OutputCiphertextVector: LweCiphertextVectorEntity,
+{
+    /// Keyswitches then bootstraps an LWE ciphertext vector.
+    fn discard_keyswitch_bootstrap_lwe_ciphertext_vector(
+        &mut self,
+        output: &mut OutputCiphertextVector,
+        input: &InputCiphertextVector,
+        acc: &AccumulatorVector,
+        ksk: &KeyswitchKey,
+        bsk: &BootstrapKey,
+    ) -> Result<(), LweCiphertextVectorDiscardingKeyswitchBootstrapError<Self::EngineError>>;
+
+    /// Unsafely keyswitches then bootstraps an LWE ciphertext vector.
+    ///
+    /// # Safety
+    /// For the _general_ safety concerns regarding this operation, refer to the different variants
+    /// of [`LweCiphertextVectorDiscardingKeyswitchBootstrapError`]. For safety concerns _specific_
+    /// to an engine, refer to the implementer safety section.
+    unsafe fn discard_keyswitch_bootstrap_lwe_ciphertext_vector_unchecked(
+        &mut self,
+        output: &mut OutputCiphertextVector,
+        input: &InputCiphertextVector,
+        acc: &AccumulatorVector,
+        ksk: &KeyswitchKey,
+        bsk: &BootstrapKey,
+    );
+}
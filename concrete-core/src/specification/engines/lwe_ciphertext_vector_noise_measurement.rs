@@ -0,0 +1,80 @@
+use super::engine_error;
+use crate::prelude::NoiseStatistics;
+use crate::specification::engines::AbstractEngine;
+use crate::specification::entities::{
+    LweCiphertextVectorEntity, LweSecretKeyEntity, PlaintextVectorEntity,
+};
+
+engine_error! {
+    LweCiphertextVectorNoiseMeasurementError for LweCiphertextVectorNoiseMeasurementEngine @
+    LweDimensionMismatch => "The key and input ciphertext vector LWE dimensions must be the same.",
+    PlaintextCountMismatch => "The expected plaintext count and the input ciphertext count must be \
+                               the same.",
+    NotEnoughCiphertexts => "At least two ciphertexts are needed to compute noise statistics."
+}
+
+impl<EngineError: std::error::Error> LweCiphertextVectorNoiseMeasurementError<EngineError> {
+    /// Validates the inputs
+    pub fn perform_generic_checks<SecretKey, CiphertextVector, PlaintextVector>(
+        key: &SecretKey,
+        input: &CiphertextVector,
+        expected: &PlaintextVector,
+    ) -> Result<(), Self>
+    where
+        SecretKey: LweSecretKeyEntity,
+        CiphertextVector: LweCiphertextVectorEntity,
+        PlaintextVector: PlaintextVectorEntity,
+    {
+        if key.lwe_dimension() != input.lwe_dimension() {
+            return Err(Self::LweDimensionMismatch);
+        }
+        if input.lwe_ciphertext_count().0 != expected.plaintext_count().0 {
+            return Err(Self::PlaintextCountMismatch);
+        }
+        if input.lwe_ciphertext_count().0 < 2 {
+            return Err(Self::NotEnoughCiphertexts);
+        }
+        Ok(())
+    }
+}
+
+/// A trait for engines measuring the noise of LWE ciphertext vectors.
+///
+/// # Semantics
+///
+/// This [pure](super#operation-semantics) operation measures the error of every ciphertext of the
+/// `input` LWE ciphertext vector with respect to the matching plaintext of the `expected`
+/// plaintext vector (as done by the
+/// [`LweCiphertextNoiseMeasurementEngine`](`crate::specification::engines::LweCiphertextNoiseMeasurementEngine`)),
+/// and returns [`NoiseStatistics`] computed over the resulting errors.
+///
+/// This makes it possible to check the noise predicted by `concrete-npe` for a given set of
+/// parameters against the noise actually observed on a batch of ciphertexts.
+pub trait LweCiphertextVectorNoiseMeasurementEngine<SecretKey, CiphertextVector, PlaintextVector>:
+    AbstractEngine
+where
+    SecretKey: LweSecretKeyEntity,
+    CiphertextVector: LweCiphertextVectorEntity,
+    PlaintextVector: PlaintextVectorEntity,
+{
+    /// Measures the noise statistics of an LWE ciphertext vector.
+    fn measure_lwe_ciphertext_vector_noise(
+        &mut self,
+        key: &SecretKey,
+        input: &CiphertextVector,
+        expected: &PlaintextVector,
+    ) -> Result<NoiseStatistics, LweCiphertextVectorNoiseMeasurementError<Self::EngineError>>;
+
+    /// Unsafely measures the noise statistics of an LWE ciphertext vector.
+    ///
+    /// # Safety
+    /// For the _general_ safety concerns regarding this operation, refer to the different variants
+    /// of [`LweCiphertextVectorNoiseMeasurementError`]. For safety concerns _specific_ to an
+    /// engine, refer to the implementer safety section.
+    unsafe fn measure_lwe_ciphertext_vector_noise_unchecked(
+        &mut self,
+        key: &SecretKey,
+        input: &CiphertextVector,
+        expected: &PlaintextVector,
+    ) -> NoiseStatistics;
+}
@@ -0,0 +1,89 @@
+use super::engine_error;
+use crate::prelude::{AbstractEngine, LweCiphertextRange};
+
+use crate::specification::entities::{LweCiphertextVectorEntity, LweSeededCiphertextVectorEntity};
+
+engine_error! {
+    LweSeededCiphertextVectorRangeExpansionError
+    for LweSeededCiphertextVectorRangeExpansionEngine @
+    UnorderedRange => "The range bounds must be ordered.",
+    OutOfVectorRange => "The input vector must contain the range."
+}
+
+impl<EngineError: std::error::Error> LweSeededCiphertextVectorRangeExpansionError<EngineError> {
+    /// Validates the inputs
+    pub fn perform_generic_checks<SeededCiphertextVector>(
+        seeded_ciphertext_vector: &SeededCiphertextVector,
+        range: LweCiphertextRange,
+    ) -> Result<(), Self>
+    where
+        SeededCiphertextVector: LweSeededCiphertextVectorEntity,
+    {
+        if !range.is_ordered() {
+            return Err(Self::UnorderedRange);
+        }
+
+        if range.1 >= seeded_ciphertext_vector.lwe_ciphertext_count().0 {
+            return Err(Self::OutOfVectorRange);
+        }
+        Ok(())
+    }
+}
+
+/// A trait for engines expanding a range of LWE ciphertexts out of an LWE seeded ciphertext vector.
+///
+/// # Semantics
+///
+/// This [pure](super#operation-semantics) operation generates an LWE ciphertext vector containing
+/// the expansion of the ciphertexts of the `seeded_ciphertext_vector` LWE seeded ciphertext vector
+/// whose indices are in `range`. The result is the same as the corresponding piece of the vector
+/// returned by the
+/// [`LweSeededCiphertextVectorToLweCiphertextVectorTransformationEngine`](
+/// super::LweSeededCiphertextVectorToLweCiphertextVectorTransformationEngine), but the masks of the
+/// ciphertexts outside of the range are not generated.
+///
+/// # Formal Definition
+///
+/// ## LWE seeded ciphertext vector range expansion
+/// ###### inputs:
+/// - $G$: a CSPRNG working with seeds from $\mathcal{S}$
+/// - $\vec{\tilde{\mathsf{ct}}} = \left( \mathsf{seed} , \vec{\tilde{b}}\right)$: a seeded LWE
+///   ciphertext vector of $t$ ciphertexts, with masks of $m$ elements
+/// - $(i, j)$ with $0 \leq i \leq j < t$: the range of ciphertexts to expand
+///
+/// ###### outputs:
+/// - $\vec{\mathsf{ct}} = \vec{\left( \vec{a} , b\right)}$: an LWE ciphertext vector of $j - i + 1$
+///   ciphertexts
+///
+/// ###### algorithm:
+/// 1. Seed $G$ with the seed $\mathsf{seed}\in\mathcal{S}$
+/// 2. skip the $i \cdot m$ first integers of $G$
+/// 3. for each $k$ in $[i, j]$
+///     - uniformly sample $m$ integers from $G$ and store them in $\vec{a}\_{k-i}$
+///     - set $b\_{k-i} = \tilde{b\_k}$
+/// 4. output $\vec{\left( \vec{a} , b\right)}$
+pub trait LweSeededCiphertextVectorRangeExpansionEngine<SeededCiphertextVector, CiphertextVector>:
+    AbstractEngine
+where
+    SeededCiphertextVector: LweSeededCiphertextVectorEntity,
+    CiphertextVector: LweCiphertextVectorEntity,
+{
+    /// Expands the ciphertexts of an LWE seeded ciphertext vector contained in a range.
+    fn expand_lwe_seeded_ciphertext_vector_range(
+        &mut self,
+        seeded_ciphertext_vector: &SeededCiphertextVector,
+        range: LweCiphertextRange,
+    ) -> Result<CiphertextVector, LweSeededCiphertextVectorRangeExpansionError<Self::EngineError>>;
+
+    /// Unsafely expands the ciphertexts of an LWE seeded ciphertext vector contained in a range.
+    ///
+    /// # Safety
+    /// For the _general_ safety concerns regarding this operation, refer to the different variants
+    /// of [`LweSeededCiphertextVectorRangeExpansionError`]. For safety concerns _specific_ to an
+    /// engine, refer to the implementer safety section.
+    unsafe fn expand_lwe_seeded_ciphertext_vector_range_unchecked(
+        &mut self,
+        seeded_ciphertext_vector: &SeededCiphertextVector,
+        range: LweCiphertextRange,
+    ) -> CiphertextVector;
+}
@@ -162,16 +162,24 @@ mod cleartext_vector_encoding;
 mod cleartext_vector_retrieval;
 mod encoder_creation;
 mod encoder_vector_creation;
+mod entity_container_deserialization;
+mod entity_container_serialization;
 mod entity_deserialization;
+mod entity_mapped_deserialization;
+mod entity_mapped_serialization;
 mod entity_serialization;
+mod entity_stream_deserialization;
+mod entity_stream_serialization;
 mod ggsw_ciphertext_conversion;
 mod ggsw_ciphertext_discarding_conversion;
 mod ggsw_ciphertext_scalar_discarding_encryption;
 mod ggsw_ciphertext_scalar_encryption;
 mod ggsw_ciphertext_scalar_trivial_encryption;
+mod glwe_ciphertext_compression;
 mod glwe_ciphertext_consuming_retrieval;
 mod glwe_ciphertext_conversion;
 mod glwe_ciphertext_creation;
+mod glwe_ciphertext_decompression;
 mod glwe_ciphertext_decryption;
 mod glwe_ciphertext_discarding_conversion;
 mod glwe_ciphertext_discarding_decryption;
@@ -180,6 +188,7 @@ mod glwe_ciphertext_discarding_trivial_encryption;
 mod glwe_ciphertext_encryption;
 mod glwe_ciphertext_ggsw_ciphertext_discarding_external_product;
 mod glwe_ciphertext_ggsw_ciphertext_external_product;
+mod glwe_ciphertext_noise_measurement;
 mod glwe_ciphertext_trivial_decryption;
 mod glwe_ciphertext_trivial_encryption;
 mod glwe_ciphertext_vector_consuming_retrieval;
@@ -200,7 +209,9 @@ mod glwe_secret_key_discarding_conversion;
 mod glwe_secret_key_generation;
 mod glwe_seeded_ciphertext_encryption;
 mod glwe_seeded_ciphertext_to_glwe_ciphertext_transformation;
+mod glwe_seeded_ciphertext_vector_element_expansion;
 mod glwe_seeded_ciphertext_vector_encryption;
+mod glwe_seeded_ciphertext_vector_range_expansion;
 mod glwe_seeded_ciphertext_vector_to_glwe_ciphertext_vector_transformation;
 mod glwe_to_lwe_secret_key_transformation;
 mod lwe_bootstrap_key_consuming_retrieval;
@@ -210,18 +221,22 @@ mod lwe_bootstrap_key_discarding_conversion;
 mod lwe_bootstrap_key_generation;
 mod lwe_ciphertext_cleartext_discarding_multiplication;
 mod lwe_ciphertext_cleartext_fusing_multiplication;
+mod lwe_ciphertext_compression;
 mod lwe_ciphertext_consuming_retrieval;
 mod lwe_ciphertext_conversion;
 mod lwe_ciphertext_creation;
+mod lwe_ciphertext_decompression;
 mod lwe_ciphertext_decryption;
 mod lwe_ciphertext_discarding_addition;
 mod lwe_ciphertext_discarding_bit_extraction;
 mod lwe_ciphertext_discarding_bootstrap;
+mod lwe_ciphertext_discarding_bootstrap_keyswitch;
 mod lwe_ciphertext_discarding_conversion;
 mod lwe_ciphertext_discarding_decryption;
 mod lwe_ciphertext_discarding_encryption;
 mod lwe_ciphertext_discarding_extraction;
 mod lwe_ciphertext_discarding_keyswitch;
+mod lwe_ciphertext_discarding_keyswitch_bootstrap;
 mod lwe_ciphertext_discarding_loading;
 mod lwe_ciphertext_discarding_opposite;
 mod lwe_ciphertext_discarding_public_key_encryption;
@@ -232,6 +247,7 @@ mod lwe_ciphertext_fusing_addition;
 mod lwe_ciphertext_fusing_opposite;
 mod lwe_ciphertext_fusing_subtraction;
 mod lwe_ciphertext_loading;
+mod lwe_ciphertext_noise_measurement;
 mod lwe_ciphertext_plaintext_discarding_addition;
 mod lwe_ciphertext_plaintext_discarding_subtraction;
 mod lwe_ciphertext_plaintext_fusing_addition;
@@ -246,12 +262,14 @@ mod lwe_ciphertext_vector_decryption;
 mod lwe_ciphertext_vector_discarding_addition;
 mod lwe_ciphertext_vector_discarding_affine_transformation;
 mod lwe_ciphertext_vector_discarding_bootstrap;
+mod lwe_ciphertext_vector_discarding_bootstrap_keyswitch;
 mod lwe_ciphertext_vector_discarding_circuit_bootstrap_boolean;
 mod lwe_ciphertext_vector_discarding_circuit_bootstrap_boolean_vertical_packing;
 mod lwe_ciphertext_vector_discarding_conversion;
 mod lwe_ciphertext_vector_discarding_decryption;
 mod lwe_ciphertext_vector_discarding_encryption;
 mod lwe_ciphertext_vector_discarding_keyswitch;
+mod lwe_ciphertext_vector_discarding_keyswitch_bootstrap;
 mod lwe_ciphertext_vector_discarding_loading;
 mod lwe_ciphertext_vector_discarding_opposite;
 mod lwe_ciphertext_vector_discarding_subtraction;
@@ -262,6 +280,7 @@ mod lwe_ciphertext_vector_fusing_subtraction;
 mod lwe_ciphertext_vector_glwe_ciphertext_discarding_packing_keyswitch;
 mod lwe_ciphertext_vector_glwe_ciphertext_discarding_private_functional_packing_keyswitch;
 mod lwe_ciphertext_vector_loading;
+mod lwe_ciphertext_vector_noise_measurement;
 mod lwe_ciphertext_vector_plaintext_vector_discarding_addition;
 mod lwe_ciphertext_vector_trivial_decryption;
 mod lwe_ciphertext_vector_trivial_encryption;
@@ -285,7 +304,9 @@ mod lwe_seeded_bootstrap_key_generation;
 mod lwe_seeded_bootstrap_key_to_lwe_bootstrap_key_transformation;
 mod lwe_seeded_ciphertext_encryption;
 mod lwe_seeded_ciphertext_to_lwe_ciphertext_transformation;
+mod lwe_seeded_ciphertext_vector_element_expansion;
 mod lwe_seeded_ciphertext_vector_encryption;
+mod lwe_seeded_ciphertext_vector_range_expansion;
 mod lwe_seeded_ciphertext_vector_to_lwe_ciphertext_vector_transformation;
 mod lwe_seeded_keyswitch_key_generation;
 mod lwe_seeded_keyswitch_key_to_lwe_keyswitch_key_transformation;
@@ -317,16 +338,24 @@ pub use cleartext_vector_encoding::*;
 pub use cleartext_vector_retrieval::*;
 pub use encoder_creation::*;
 pub use encoder_vector_creation::*;
+pub use entity_container_deserialization::*;
+pub use entity_container_serialization::*;
 pub use entity_deserialization::*;
+pub use entity_mapped_deserialization::*;
+pub use entity_mapped_serialization::*;
 pub use entity_serialization::*;
+pub use entity_stream_deserialization::*;
+pub use entity_stream_serialization::*;
 pub use ggsw_ciphertext_conversion::*;
 pub use ggsw_ciphertext_discarding_conversion::*;
 pub use ggsw_ciphertext_scalar_discarding_encryption::*;
 pub use ggsw_ciphertext_scalar_encryption::*;
 pub use ggsw_ciphertext_scalar_trivial_encryption::*;
+pub use glwe_ciphertext_compression::*;
 pub use glwe_ciphertext_consuming_retrieval::*;
 pub use glwe_ciphertext_conversion::*;
 pub use glwe_ciphertext_creation::*;
+pub use glwe_ciphertext_decompression::*;
 pub use glwe_ciphertext_decryption::*;
 pub use glwe_ciphertext_discarding_conversion::*;
 pub use glwe_ciphertext_discarding_decryption::*;
@@ -335,6 +364,7 @@ pub use glwe_ciphertext_discarding_trivial_encryption::*;
 pub use glwe_ciphertext_encryption::*;
 pub use glwe_ciphertext_ggsw_ciphertext_discarding_external_product::*;
 pub use glwe_ciphertext_ggsw_ciphertext_external_product::*;
+pub use glwe_ciphertext_noise_measurement::*;
 pub use glwe_ciphertext_trivial_decryption::*;
 pub use glwe_ciphertext_trivial_encryption::*;
 pub use glwe_ciphertext_vector_consuming_retrieval::*;
@@ -355,7 +385,9 @@ pub use glwe_secret_key_discarding_conversion::*;
 pub use glwe_secret_key_generation::*;
 pub use glwe_seeded_ciphertext_encryption::*;
 pub use glwe_seeded_ciphertext_to_glwe_ciphertext_transformation::*;
+pub use glwe_seeded_ciphertext_vector_element_expansion::*;
 pub use glwe_seeded_ciphertext_vector_encryption::*;
+pub use glwe_seeded_ciphertext_vector_range_expansion::*;
 pub use glwe_seeded_ciphertext_vector_to_glwe_ciphertext_vector_transformation::*;
 pub use glwe_to_lwe_secret_key_transformation::*;
 pub use lwe_bootstrap_key_consuming_retrieval::*;
@@ -365,18 +397,22 @@ pub use lwe_bootstrap_key_discarding_conversion::*;
 pub use lwe_bootstrap_key_generation::*;
 pub use lwe_ciphertext_cleartext_discarding_multiplication::*;
 pub use lwe_ciphertext_cleartext_fusing_multiplication::*;
+pub use lwe_ciphertext_compression::*;
 pub use lwe_ciphertext_consuming_retrieval::*;
 pub use lwe_ciphertext_conversion::*;
 pub use lwe_ciphertext_creation::*;
+pub use lwe_ciphertext_decompression::*;
 pub use lwe_ciphertext_decryption::*;
 pub use lwe_ciphertext_discarding_addition::*;
 pub use lwe_ciphertext_discarding_bit_extraction::*;
 pub use lwe_ciphertext_discarding_bootstrap::*;
+pub use lwe_ciphertext_discarding_bootstrap_keyswitch::*;
 pub use lwe_ciphertext_discarding_conversion::*;
 pub use lwe_ciphertext_discarding_decryption::*;
 pub use lwe_ciphertext_discarding_encryption::*;
 pub use lwe_ciphertext_discarding_extraction::*;
 pub use lwe_ciphertext_discarding_keyswitch::*;
+pub use lwe_ciphertext_discarding_keyswitch_bootstrap::*;
 pub use lwe_ciphertext_discarding_loading::*;
 pub use lwe_ciphertext_discarding_opposite::*;
 pub use lwe_ciphertext_discarding_public_key_encryption::*;
@@ -387,6 +423,7 @@ pub use lwe_ciphertext_fusing_addition::*;
 pub use lwe_ciphertext_fusing_opposite::*;
 pub use lwe_ciphertext_fusing_subtraction::*;
 pub use lwe_ciphertext_loading::*;
+pub use lwe_ciphertext_noise_measurement::*;
 pub use lwe_ciphertext_plaintext_discarding_addition::*;
 pub use lwe_ciphertext_plaintext_discarding_subtraction::*;
 pub use lwe_ciphertext_plaintext_fusing_addition::*;
@@ -401,12 +438,14 @@ pub use lwe_ciphertext_vector_decryption::*;
 pub use lwe_ciphertext_vector_discarding_addition::*;
 pub use lwe_ciphertext_vector_discarding_affine_transformation::*;
 pub use lwe_ciphertext_vector_discarding_bootstrap::*;
+pub use lwe_ciphertext_vector_discarding_bootstrap_keyswitch::*;
 pub use lwe_ciphertext_vector_discarding_circuit_bootstrap_boolean::*;
 pub use lwe_ciphertext_vector_discarding_circuit_bootstrap_boolean_vertical_packing::*;
 pub use lwe_ciphertext_vector_discarding_conversion::*;
 pub use lwe_ciphertext_vector_discarding_decryption::*;
 pub use lwe_ciphertext_vector_discarding_encryption::*;
 pub use lwe_ciphertext_vector_discarding_keyswitch::*;
+pub use lwe_ciphertext_vector_discarding_keyswitch_bootstrap::*;
 pub use lwe_ciphertext_vector_discarding_loading::*;
 pub use lwe_ciphertext_vector_discarding_opposite::*;
 pub use lwe_ciphertext_vector_discarding_subtraction::*;
@@ -417,6 +456,7 @@ pub use lwe_ciphertext_vector_fusing_subtraction::*;
 pub use lwe_ciphertext_vector_glwe_ciphertext_discarding_packing_keyswitch::*;
 pub use lwe_ciphertext_vector_glwe_ciphertext_discarding_private_functional_packing_keyswitch::*;
 pub use lwe_ciphertext_vector_loading::*;
+pub use lwe_ciphertext_vector_noise_measurement::*;
 pub use lwe_ciphertext_vector_plaintext_vector_discarding_addition::*;
 pub use lwe_ciphertext_vector_trivial_decryption::*;
 pub use lwe_ciphertext_vector_trivial_encryption::*;
@@ -440,7 +480,9 @@ pub use lwe_seeded_bootstrap_key_generation::*;
 pub use lwe_seeded_bootstrap_key_to_lwe_bootstrap_key_transformation::*;
 pub use lwe_seeded_ciphertext_encryption::*;
 pub use lwe_seeded_ciphertext_to_lwe_ciphertext_transformation::*;
+pub use lwe_seeded_ciphertext_vector_element_expansion::*;
 pub use lwe_seeded_ciphertext_vector_encryption::*;
+pub use lwe_seeded_ciphertext_vector_range_expansion::*;
 pub use lwe_seeded_ciphertext_vector_to_lwe_ciphertext_vector_transformation::*;
 pub use lwe_seeded_keyswitch_key_generation::*;
 pub use lwe_seeded_keyswitch_key_to_lwe_keyswitch_key_transformation::*;
@@ -0,0 +1,20 @@
+use crate::prelude::{CiphertextModulusLog, GlweDimension, PolynomialSize};
+use crate::specification::entities::markers::GlweCompressedCiphertextKind;
+use crate::specification::entities::AbstractEntity;
+
+/// A trait implemented by types embodying a compressed GLWE ciphertext.
+///
+/// A compressed GLWE ciphertext stores the coefficients of a regular GLWE ciphertext switched to a
+/// smaller modulus, packed together using only the bits of this smaller modulus.
+pub trait GlweCompressedCiphertextEntity:
+    AbstractEntity<Kind = GlweCompressedCiphertextKind>
+{
+    /// Returns the GLWE dimension of the ciphertext.
+    fn glwe_dimension(&self) -> GlweDimension;
+
+    /// Returns the polynomial size of the ciphertext.
+    fn polynomial_size(&self) -> PolynomialSize;
+
+    /// Returns the logarithm of the modulus the coefficients of the ciphertext were switched to.
+    fn ciphertext_modulus_log(&self) -> CiphertextModulusLog;
+}
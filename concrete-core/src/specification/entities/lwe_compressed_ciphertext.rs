@@ -0,0 +1,19 @@
+use crate::prelude::{CiphertextModulusLog, LweDimension};
+use crate::specification::entities::markers::LweCompressedCiphertextKind;
+use crate::specification::entities::AbstractEntity;
+
+/// A trait implemented by types embodying a compressed LWE ciphertext.
+///
+/// A compressed LWE ciphertext is a version of a regular LWE ciphertext whose coefficients were
+/// switched to a smaller modulus, and packed together using only the bits of this smaller modulus.
+/// This makes it cheaper to send over the network, at the price of some additional noise. It can
+/// then be decompressed into a regular LWE ciphertext that can be decrypted.
+pub trait LweCompressedCiphertextEntity:
+    AbstractEntity<Kind = LweCompressedCiphertextKind>
+{
+    /// Returns the LWE dimension of the ciphertext.
+    fn lwe_dimension(&self) -> LweDimension;
+
+    /// Returns the logarithm of the modulus the coefficients of the ciphertext were switched to.
+    fn ciphertext_modulus_log(&self) -> CiphertextModulusLog;
+}
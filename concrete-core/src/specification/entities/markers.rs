@@ -38,6 +38,8 @@ entity_kind_marker! {
             => "An empty type representing the LWE ciphertext kind in the type system.",
         LweCiphertextVectorKind
             => "An empty type representing the LWE ciphertext vector kind in the type system.",
+        LweCompressedCiphertextKind
+            => "An empty type representing the compressed LWE ciphertext kind in the type system.",
         LweSeededCiphertextKind
             => "An empty type representing the seeded LWE ciphertext kind in the type system.",
         LweSeededCiphertextVectorKind
@@ -46,6 +48,8 @@ entity_kind_marker! {
             => "An empty type representing the GLWE ciphertext kind in the type system.",
         GlweCiphertextVectorKind
             => "An empty type representing the GLWE ciphertext vector kind in the type system.",
+        GlweCompressedCiphertextKind
+            => "An empty type representing the compressed GLWE ciphertext kind in the type system.",
         GlweSeededCiphertextKind
             => "An empty type representing the seeded GLWE ciphertext kind in the type system.",
         GlweSeededCiphertextVectorKind
@@ -0,0 +1,373 @@
+//! This module contains the file format used to map large entities in memory.
+//!
+//! Keys such as bootstrap keys and keyswitch keys can be written in a format which allows to use
+//! them in place, without copying nor deserializing their content, for instance from a
+//! memory-mapped file shared by several processes. A mapped entity is made of a header of
+//! [`MAPPED_HEADER_SIZE`] bytes, followed by the raw data of the entity (the payload):
+//!
+//! | Offset | Size | Content                                                       |
+//! |--------|------|---------------------------------------------------------------|
+//! | 0      | 8    | The magic bytes [`MAPPED_MAGIC`]                              |
+//! | 8      | 4    | The version of the format, as a `u32`                         |
+//! | 12     | 4    | The kind of the entity, as a `u32` (see [`MappedEntityKind`]) |
+//! | 16     | 40   | Five parameters of the entity, as `u64`s                      |
+//! | 56     | 8    | The size of the payload in bytes, as a `u64`                  |
+//! | 64     | ...  | The payload                                                   |
+//!
+//! All the integers are stored in little endian, and the payload is stored in the memory
+//! representation of little-endian platforms, which are the only ones supported. The parameters
+//! stored in the header depend on the kind of the entity, and are documented in
+//! [`MappedEntityKind`].
+//!
+//! Since the header is [`MAPPED_ALIGNMENT`] bytes long, the payload of an entity mapped at a page
+//! boundary is aligned on [`MAPPED_ALIGNMENT`] bytes, which is enough for the vectorized
+//! instructions used by the FFT backend on its Fourier data. When memory-mapping is not available,
+//! [`MappedBytes`] can be used to load a mapped entity in an aligned buffer.
+use aligned_vec::{AVec, RuntimeAlign};
+use std::fmt::{Display, Formatter};
+use std::fs::File;
+use std::io::{Read, Seek};
+
+/// The magic bytes found at the start of every mapped entity.
+pub const MAPPED_MAGIC: [u8; 8] = *b"CCMAPPED";
+
+/// The version of the mapped format written by this version of the library.
+pub const MAPPED_FORMAT_VERSION: u32 = 0;
+
+/// The size in bytes of the header of a mapped entity.
+pub const MAPPED_HEADER_SIZE: usize = 64;
+
+/// The alignment of the buffers returned by [`MappedBytes`].
+pub const MAPPED_ALIGNMENT: usize = 64;
+
+/// The kinds of entities which can be mapped.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MappedEntityKind {
+    /// An LWE keyswitch key with 32 bits of precision.
+    ///
+    /// The parameters are the input LWE dimension, the output LWE dimension, the decomposition
+    /// base log and the decomposition level count. The payload contains `u32`s.
+    LweKeyswitchKey32,
+    /// An LWE keyswitch key with 64 bits of precision.
+    ///
+    /// The parameters are the same as for [`MappedEntityKind::LweKeyswitchKey32`]. The payload
+    /// contains `u64`s.
+    LweKeyswitchKey64,
+    /// An LWE bootstrap key with 32 bits of precision, in the Fourier domain.
+    ///
+    /// The parameters are the input LWE dimension, the GLWE dimension, the polynomial size, the
+    /// decomposition base log and the decomposition level count. The payload contains complex
+    /// numbers, each one stored as two `f64`s.
+    FftFourierLweBootstrapKey32,
+    /// An LWE bootstrap key with 64 bits of precision, in the Fourier domain.
+    ///
+    /// The parameters are the same as for [`MappedEntityKind::FftFourierLweBootstrapKey32`].
+    FftFourierLweBootstrapKey64,
+}
+
+impl MappedEntityKind {
+    fn to_u32(self) -> u32 {
+        match self {
+            MappedEntityKind::LweKeyswitchKey32 => 1,
+            MappedEntityKind::LweKeyswitchKey64 => 2,
+            MappedEntityKind::FftFourierLweBootstrapKey32 => 3,
+            MappedEntityKind::FftFourierLweBootstrapKey64 => 4,
+        }
+    }
+
+    fn from_u32(value: u32) -> Option<Self> {
+        match value {
+            1 => Some(MappedEntityKind::LweKeyswitchKey32),
+            2 => Some(MappedEntityKind::LweKeyswitchKey64),
+            3 => Some(MappedEntityKind::FftFourierLweBootstrapKey32),
+            4 => Some(MappedEntityKind::FftFourierLweBootstrapKey64),
+            _ => None,
+        }
+    }
+}
+
+/// The errors which can occur when reading a mapped entity.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MappedFormatError {
+    /// The bytes do not start with a valid header.
+    InvalidHeader,
+    /// The entity was written with an unsupported version of the format.
+    UnsupportedVersion,
+    /// The mapped format is not supported on big-endian platforms.
+    UnsupportedPlatform,
+    /// The entity is not of the expected kind.
+    EntityKindMismatch,
+    /// The payload is truncated, or its size does not match the parameters of the entity.
+    InvalidPayloadSize,
+    /// The payload is not aligned enough to be used in place.
+    MisalignedPayload,
+}
+
+impl Display for MappedFormatError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            MappedFormatError::InvalidHeader => {
+                write!(
+                    f,
+                    "The bytes do not start with a valid mapped entity header."
+                )
+            }
+            MappedFormatError::UnsupportedVersion => {
+                write!(f, "The version of the mapped entity is not supported.")
+            }
+            MappedFormatError::UnsupportedPlatform => {
+                write!(
+                    f,
+                    "Mapped entities are not supported on big-endian platforms."
+                )
+            }
+            MappedFormatError::EntityKindMismatch => {
+                write!(f, "The mapped entity is not of the expected kind.")
+            }
+            MappedFormatError::InvalidPayloadSize => write!(
+                f,
+                "The payload size of the mapped entity does not match its parameters."
+            ),
+            MappedFormatError::MisalignedPayload => write!(
+                f,
+                "The payload of the mapped entity is not aligned enough to be used in place."
+            ),
+        }
+    }
+}
+
+impl std::error::Error for MappedFormatError {}
+
+/// The header of a mapped entity.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct MappedHeader {
+    pub kind: MappedEntityKind,
+    pub parameters: [u64; 5],
+    pub payload_size: u64,
+}
+
+impl MappedHeader {
+    /// Returns the binary representation of the header.
+    pub fn to_bytes(&self) -> [u8; MAPPED_HEADER_SIZE] {
+        let mut bytes = [0; MAPPED_HEADER_SIZE];
+        bytes[0..8].copy_from_slice(&MAPPED_MAGIC);
+        bytes[8..12].copy_from_slice(&MAPPED_FORMAT_VERSION.to_le_bytes());
+        bytes[12..16].copy_from_slice(&self.kind.to_u32().to_le_bytes());
+        for (chunk, parameter) in bytes[16..56].chunks_exact_mut(8).zip(self.parameters) {
+            chunk.copy_from_slice(&parameter.to_le_bytes());
+        }
+        bytes[56..64].copy_from_slice(&self.payload_size.to_le_bytes());
+        bytes
+    }
+
+    /// Reads a header from the first [`MAPPED_HEADER_SIZE`] bytes of `bytes`.
+    pub fn from_bytes(bytes: &[u8]) -> Result<MappedHeader, MappedFormatError> {
+        if bytes.len() < MAPPED_HEADER_SIZE || bytes[0..8] != MAPPED_MAGIC {
+            return Err(MappedFormatError::InvalidHeader);
+        }
+        let read_u32 =
+            |offset: usize| u32::from_le_bytes(bytes[offset..offset + 4].try_into().unwrap());
+        let read_u64 =
+            |offset: usize| u64::from_le_bytes(bytes[offset..offset + 8].try_into().unwrap());
+        if read_u32(8) != MAPPED_FORMAT_VERSION {
+            return Err(MappedFormatError::UnsupportedVersion);
+        }
+        let kind =
+            MappedEntityKind::from_u32(read_u32(12)).ok_or(MappedFormatError::InvalidHeader)?;
+        let mut parameters = [0; 5];
+        for (i, parameter) in parameters.iter_mut().enumerate() {
+            *parameter = read_u64(16 + 8 * i);
+        }
+        Ok(MappedHeader {
+            kind,
+            parameters,
+            payload_size: read_u64(56),
+        })
+    }
+
+    /// Reads the header at the start of `bytes`, checks that it describes an entity of the
+    /// `expected` kind, and returns it along with the payload.
+    pub fn split_payload(
+        bytes: &[u8],
+        expected: MappedEntityKind,
+    ) -> Result<(MappedHeader, &[u8]), MappedFormatError> {
+        if cfg!(target_endian = "big") {
+            return Err(MappedFormatError::UnsupportedPlatform);
+        }
+        let header = MappedHeader::from_bytes(bytes)?;
+        if header.kind != expected {
+            return Err(MappedFormatError::EntityKindMismatch);
+        }
+        let payload = &bytes[MAPPED_HEADER_SIZE..];
+        let payload_size = usize::try_from(header.payload_size)
+            .map_err(|_| MappedFormatError::InvalidPayloadSize)?;
+        if payload.len() < payload_size {
+            return Err(MappedFormatError::InvalidPayloadSize);
+        }
+        Ok((header, &payload[..payload_size]))
+    }
+}
+
+/// Returns the bytes of a slice of plain numeric values, to be written as a payload.
+pub(crate) fn payload_from_slice<T: Copy>(data: &[T]) -> &[u8] {
+    // SAFETY: the payloads are only made of integers and floats, which have no padding bytes.
+    unsafe { std::slice::from_raw_parts(data.as_ptr() as *const u8, std::mem::size_of_val(data)) }
+}
+
+/// Converts a parameter read from a header to a `usize`, rejecting zero values.
+pub(crate) fn non_zero_parameter(value: u64) -> Result<usize, MappedFormatError> {
+    match usize::try_from(value) {
+        Ok(value) if value != 0 => Ok(value),
+        _ => Err(MappedFormatError::InvalidHeader),
+    }
+}
+
+/// Returns the number of elements of a payload with the given dimensions, or an error if it
+/// overflows.
+pub(crate) fn payload_len(dimensions: &[u64]) -> Result<usize, MappedFormatError> {
+    dimensions
+        .iter()
+        .try_fold(1_usize, |acc, &dim| {
+            usize::try_from(dim)
+                .ok()
+                .and_then(|dim| acc.checked_mul(dim))
+        })
+        .ok_or(MappedFormatError::InvalidPayloadSize)
+}
+
+/// Reinterprets the bytes of a payload as a slice of plain numeric values, without copying.
+///
+/// # Safety
+///
+/// Every bit pattern must be a valid value of `T`, which is the case for integers and floats.
+pub(crate) unsafe fn slice_from_payload<T: Copy>(
+    payload: &[u8],
+) -> Result<&[T], MappedFormatError> {
+    if payload.len() % std::mem::size_of::<T>() != 0 {
+        return Err(MappedFormatError::InvalidPayloadSize);
+    }
+    if payload.as_ptr() as usize % std::mem::align_of::<T>() != 0 {
+        return Err(MappedFormatError::MisalignedPayload);
+    }
+    Ok(std::slice::from_raw_parts(
+        payload.as_ptr() as *const T,
+        payload.len() / std::mem::size_of::<T>(),
+    ))
+}
+
+/// A buffer aligned on [`MAPPED_ALIGNMENT`] bytes, containing a mapped entity.
+///
+/// This is useful to load mapped entities on platforms where memory-mapping files is not
+/// available, or when the entity does not come from a file.
+#[derive(Debug, Clone)]
+pub struct MappedBytes(AVec<u8, RuntimeAlign>);
+
+impl MappedBytes {
+    /// Reads a whole mapped entity (header and payload) from `reader`.
+    ///
+    /// Only the bytes of the entity are consumed from the reader. Since the size of the data
+    /// available in the reader is not known, the payload is read in a temporary buffer which only
+    /// grows with the bytes actually read, before being copied to an aligned buffer. Use
+    /// [`MappedBytes::read_from_file`] to avoid this copy when reading from a file.
+    pub fn read_from<R: Read>(reader: &mut R) -> std::io::Result<MappedBytes> {
+        let (header, total_size) = read_header(reader)?;
+        let mut buffer = Vec::from(header);
+        reader
+            .take((total_size - MAPPED_HEADER_SIZE) as u64)
+            .read_to_end(&mut buffer)?;
+        if buffer.len() != total_size {
+            return Err(std::io::ErrorKind::UnexpectedEof.into());
+        }
+        Ok(MappedBytes(AVec::from_slice(MAPPED_ALIGNMENT, &buffer)))
+    }
+
+    /// Reads a whole mapped entity (header and payload) from the current position of `file`.
+    ///
+    /// The payload size stored in the header is checked against the size of the file before
+    /// allocating the buffer. Only the bytes of the entity are consumed from the file.
+    pub fn read_from_file(file: &mut File) -> std::io::Result<MappedBytes> {
+        let remaining = file
+            .metadata()?
+            .len()
+            .saturating_sub(file.stream_position()?);
+        let (header, total_size) = read_header(file)?;
+        if total_size as u64 > remaining {
+            return Err(invalid_payload_size());
+        }
+        let mut bytes = AVec::from_iter(MAPPED_ALIGNMENT, (0..total_size).map(|_| 0));
+        bytes.as_mut_slice()[..MAPPED_HEADER_SIZE].copy_from_slice(&header);
+        file.read_exact(&mut bytes.as_mut_slice()[MAPPED_HEADER_SIZE..])?;
+        Ok(MappedBytes(bytes))
+    }
+
+    /// Returns the bytes of the entity.
+    pub fn as_slice(&self) -> &[u8] {
+        self.0.as_slice()
+    }
+}
+
+/// Reads the header of a mapped entity, and returns it along with the total size of the entity.
+fn read_header<R: Read>(reader: &mut R) -> std::io::Result<([u8; MAPPED_HEADER_SIZE], usize)> {
+    let mut header = [0; MAPPED_HEADER_SIZE];
+    reader.read_exact(&mut header)?;
+    let payload_size = MappedHeader::from_bytes(&header)
+        .map_err(|err| std::io::Error::new(std::io::ErrorKind::InvalidData, err))?
+        .payload_size;
+    let total_size = usize::try_from(payload_size)
+        .ok()
+        .and_then(|payload_size| payload_size.checked_add(MAPPED_HEADER_SIZE))
+        .ok_or_else(invalid_payload_size)?;
+    Ok((header, total_size))
+}
+
+fn invalid_payload_size() -> std::io::Error {
+    std::io::Error::new(
+        std::io::ErrorKind::InvalidData,
+        MappedFormatError::InvalidPayloadSize,
+    )
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use std::io::Write;
+
+    fn header_bytes(payload_size: u64) -> [u8; MAPPED_HEADER_SIZE] {
+        MappedHeader {
+            kind: MappedEntityKind::LweKeyswitchKey64,
+            parameters: [1; 5],
+            payload_size,
+        }
+        .to_bytes()
+    }
+
+    #[test]
+    fn test_read_from_rejects_oversized_payload() {
+        let bytes = header_bytes(u64::MAX);
+        let err = MappedBytes::read_from(&mut bytes.as_slice()).unwrap_err();
+        assert_eq!(err.kind(), std::io::ErrorKind::InvalidData);
+
+        let mut bytes = header_bytes(1 << 40).to_vec();
+        bytes.extend_from_slice(&[0; 16]);
+        let err = MappedBytes::read_from(&mut bytes.as_slice()).unwrap_err();
+        assert_eq!(err.kind(), std::io::ErrorKind::UnexpectedEof);
+    }
+
+    #[test]
+    fn test_read_from_file_checks_file_size() {
+        let path = std::env::temp_dir().join(format!("mapped_test_{}", std::process::id()));
+        let mut file = File::create(&path).unwrap();
+        file.write_all(&header_bytes(16)).unwrap();
+        file.write_all(&[0; 16]).unwrap();
+        file.write_all(&header_bytes(1 << 40)).unwrap();
+        file.write_all(&[0; 16]).unwrap();
+        drop(file);
+
+        let mut file = File::open(&path).unwrap();
+        let bytes = MappedBytes::read_from_file(&mut file).unwrap();
+        assert_eq!(bytes.as_slice().len(), MAPPED_HEADER_SIZE + 16);
+        let err = MappedBytes::read_from_file(&mut file).unwrap_err();
+        assert_eq!(err.kind(), std::io::ErrorKind::InvalidData);
+        std::fs::remove_file(&path).unwrap();
+    }
+}
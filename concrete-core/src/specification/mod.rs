@@ -10,10 +10,16 @@
 //!
 //! This module also contains common tools for the concrete packages
 //!
+//! # Container
+//! This module contains the self-describing format used to store serialized entities.
+//!
 //! # Dispersion
 //! This module contains the functions used to compute the variance, standard
 //! deviation, etc.
 //!
+//! # Mapped
+//! This module contains the file format used to map large entities in memory.
+//!
 //! # Key kinds
 //! This module contains types to manage the different kinds of secret keys.
 //!
@@ -24,6 +30,8 @@
 pub mod engines;
 pub mod entities;
 
+pub mod container;
 pub mod dispersion;
 pub mod key_kinds;
+pub mod mapped;
 pub mod parameters;
@@ -33,13 +33,15 @@ pub struct LweCiphertextCount(pub usize);
 pub struct LweCiphertextIndex(pub usize);
 
 /// The range of indices of multiple contiguous ciphertexts in an lwe ciphertext list.
+///
+/// Both bounds are included in the range.
 #[derive(Copy, Clone, Eq, PartialEq, Debug)]
 #[cfg_attr(feature = "__commons_serialization", derive(Serialize, Deserialize))]
 pub struct LweCiphertextRange(pub usize, pub usize);
 
 impl LweCiphertextRange {
     pub fn is_ordered(&self) -> bool {
-        self.1 <= self.0
+        self.0 <= self.1
     }
 }
 
@@ -48,6 +50,24 @@ impl LweCiphertextRange {
 #[cfg_attr(feature = "__commons_serialization", derive(Serialize, Deserialize))]
 pub struct GlweCiphertextCount(pub usize);
 
+/// The index of a ciphertext in a glwe ciphertext list.
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+#[cfg_attr(feature = "__commons_serialization", derive(Serialize, Deserialize))]
+pub struct GlweCiphertextIndex(pub usize);
+
+/// The range of indices of multiple contiguous ciphertexts in a glwe ciphertext list.
+///
+/// Both bounds are included in the range.
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+#[cfg_attr(feature = "__commons_serialization", derive(Serialize, Deserialize))]
+pub struct GlweCiphertextRange(pub usize, pub usize);
+
+impl GlweCiphertextRange {
+    pub fn is_ordered(&self) -> bool {
+        self.0 <= self.1
+    }
+}
+
 /// The number of ciphertexts in a gsw ciphertext list.
 #[derive(Copy, Clone, Eq, PartialEq, Debug)]
 #[cfg_attr(feature = "__commons_serialization", derive(Serialize, Deserialize))]
@@ -213,3 +233,18 @@ pub struct FunctionalPackingKeyswitchKeyCount(pub usize);
 #[derive(Debug, PartialEq, Eq, Copy, Clone)]
 #[cfg_attr(feature = "__commons_serialization", derive(Serialize, Deserialize))]
 pub struct CiphertextModulusLog(pub usize);
+
+#[cfg(test)]
+mod test {
+    use super::{GlweCiphertextRange, LweCiphertextRange};
+
+    #[test]
+    fn test_ranges_are_ordered_when_start_is_not_after_end() {
+        assert!(LweCiphertextRange(1, 2).is_ordered());
+        assert!(LweCiphertextRange(3, 3).is_ordered());
+        assert!(!LweCiphertextRange(2, 1).is_ordered());
+        assert!(GlweCiphertextRange(1, 2).is_ordered());
+        assert!(GlweCiphertextRange(3, 3).is_ordered());
+        assert!(!GlweCiphertextRange(2, 1).is_ordered());
+    }
+}
@@ -0,0 +1,76 @@
+//! Compares the generator whose implementation is selected at runtime with the statically typed
+//! one, both using the AES-NI implementation.
+use concrete_csprng::generators::{
+    AesniRandomGenerator, BytesPerChild, ChildrenCount, DynamicRandomGenerator, GeneratorKind,
+    RandomGenerator,
+};
+use concrete_csprng::seeders::{RdseedSeeder, Seeder};
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+
+// The number of bytes to generate during one benchmark iteration.
+const N_GEN: usize = 1_000_000;
+
+fn static_generate(c: &mut Criterion) {
+    let mut seeder = RdseedSeeder;
+    let mut generator = AesniRandomGenerator::new(seeder.seed());
+    c.bench_function("static_generate", |b| {
+        b.iter(|| {
+            (0..N_GEN).for_each(|_| {
+                generator.next();
+            })
+        })
+    });
+}
+
+fn dynamic_generate(c: &mut Criterion) {
+    let mut seeder = RdseedSeeder;
+    let mut generator =
+        DynamicRandomGenerator::new_with_kind(seeder.seed(), GeneratorKind::Aesni).unwrap();
+    c.bench_function("dynamic_generate", |b| {
+        b.iter(|| {
+            (0..N_GEN).for_each(|_| {
+                generator.next();
+            })
+        })
+    });
+}
+
+fn static_fork(c: &mut Criterion) {
+    let mut seeder = RdseedSeeder;
+    let mut generator = AesniRandomGenerator::new(seeder.seed());
+    c.bench_function("static_fork", |b| {
+        b.iter(|| {
+            generator
+                .try_fork(ChildrenCount(2048), BytesPerChild(2048))
+                .unwrap()
+                .for_each(|child| {
+                    black_box(child);
+                })
+        })
+    });
+}
+
+fn dynamic_fork(c: &mut Criterion) {
+    let mut seeder = RdseedSeeder;
+    let mut generator =
+        DynamicRandomGenerator::new_with_kind(seeder.seed(), GeneratorKind::Aesni).unwrap();
+    c.bench_function("dynamic_fork", |b| {
+        b.iter(|| {
+            generator
+                .try_fork(ChildrenCount(2048), BytesPerChild(2048))
+                .unwrap()
+                .for_each(|child| {
+                    black_box(child);
+                })
+        })
+    });
+}
+
+criterion_group!(
+    benches,
+    static_generate,
+    dynamic_generate,
+    static_fork,
+    dynamic_fork
+);
+criterion_main!(benches);
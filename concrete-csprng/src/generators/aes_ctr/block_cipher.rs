@@ -1,5 +1,6 @@
 use crate::generators::aes_ctr::index::AesIndex;
 use crate::generators::aes_ctr::BYTES_PER_BATCH;
+use crate::generators::BlockCipherKind;
 
 /// Represents a key used in the AES block cipher.
 #[derive(Clone, Copy)]
@@ -13,8 +14,12 @@ pub struct AesKey(pub u128);
 /// The block cipher is used in a batched manner (to reduce amortized cost on special hardware).
 /// For this reason we only expose a `generate_batch` method.
 pub trait AesBlockCipher: Clone + Send + Sync {
+    /// The block cipher implemented, recorded in the snapshots of the generator state.
+    const KIND: BlockCipherKind;
     /// Instantiate a new generator from a secret key.
     fn new(key: AesKey) -> Self;
+    /// Returns the secret key the block cipher was instantiated from.
+    fn key(&self) -> AesKey;
     /// Generates the batch corresponding to the given index.
     fn generate_batch(&mut self, index: AesIndex) -> [u8; BYTES_PER_BATCH];
 }
@@ -1,8 +1,10 @@
 use crate::generators::aes_ctr::block_cipher::{AesBlockCipher, AesKey};
-use crate::generators::aes_ctr::index::TableIndex;
+use crate::generators::aes_ctr::index::{AesIndex, ByteIndex, TableIndex};
 use crate::generators::aes_ctr::states::{BufferPointer, ShiftAction, State};
-use crate::generators::aes_ctr::BYTES_PER_BATCH;
-use crate::generators::{ByteCount, BytesPerChild, ChildrenCount, ForkError};
+use crate::generators::aes_ctr::{BYTES_PER_AES_CALL, BYTES_PER_BATCH};
+use crate::generators::{
+    ByteCount, BytesPerChild, ChildrenCount, ForkError, GeneratorState, RestoreError, SkipError,
+};
 
 // Usually, to work with iterators and parallel iterators, we would use opaque types such as
 // `impl Iterator<..>`. Unfortunately, it is not yet possible to return existential types in
@@ -82,6 +84,48 @@ impl<BlockCipher: AesBlockCipher> AesCtrGenerator<BlockCipher> {
         }
     }
 
+    /// Returns a snapshot of the state of the generator.
+    pub fn save_state(&self) -> GeneratorState {
+        let table_index = self.table_index();
+        let bound = self.get_bound();
+        GeneratorState {
+            block_cipher: BlockCipher::KIND,
+            key: self.block_cipher.key().0,
+            aes_index: table_index.aes_index.0,
+            byte_index: table_index.byte_index.0,
+            bound_aes_index: bound.aes_index.0,
+            bound_byte_index: bound.byte_index.0,
+        }
+    }
+
+    /// Restores a generator from a snapshot of its state.
+    pub fn restore_state(
+        state: GeneratorState,
+    ) -> Result<AesCtrGenerator<BlockCipher>, RestoreError> {
+        if state.block_cipher != BlockCipher::KIND {
+            return Err(RestoreError::BlockCipherMismatch);
+        }
+        if state.byte_index >= BYTES_PER_AES_CALL || state.bound_byte_index >= BYTES_PER_AES_CALL {
+            return Err(RestoreError::InvalidTableIndex);
+        }
+        let table_index = TableIndex::new(AesIndex(state.aes_index), ByteIndex(state.byte_index));
+        let bound = TableIndex::new(
+            AesIndex(state.bound_aes_index),
+            ByteIndex(state.bound_byte_index),
+        );
+        // The last outputted byte must precede the bound. This also ensures that the next byte
+        // index does not wrap to the first one.
+        if table_index >= bound {
+            return Err(RestoreError::IndexAfterBound);
+        }
+        Ok(AesCtrGenerator {
+            block_cipher: Box::new(BlockCipher::new(AesKey(state.key))),
+            state: State::new(table_index.incremented()),
+            last: bound.decremented(),
+            buffer: [0u8; BYTES_PER_BATCH],
+        })
+    }
+
     /// Returns the table index related to the previous random byte.
     pub fn table_index(&self) -> TableIndex {
         self.state.table_index()
@@ -168,6 +212,30 @@ impl<BlockCipher: AesBlockCipher> AesCtrGenerator<BlockCipher> {
         Ok(output)
     }
 
+    /// Tries to skip the next `n_bytes` bytes, without generating them.
+    ///
+    /// Since the generator works in counter mode, this only moves the state to the proper table
+    /// index, and the batch containing the next byte gets generated on the next call.
+    pub fn try_skip(&mut self, n_bytes: ByteCount) -> Result<(), SkipError> {
+        if n_bytes > self.remaining_bytes() {
+            return Err(SkipError::SkipTooLarge);
+        }
+        if n_bytes.0 == 0 {
+            return Ok(());
+        }
+        // As for the parent of a fork, the state is re-created at the index of the next byte to be
+        // outputted, which ensures the buffer gets refreshed on the next call.
+        let mut next_index = self.state.table_index().incremented();
+        let mut remaining = n_bytes.0;
+        while remaining > 0 {
+            let shift = remaining.min(usize::MAX as u128) as usize;
+            next_index.increase(shift);
+            remaining -= shift as u128;
+        }
+        self.state = State::new(next_index);
+        Ok(())
+    }
+
     pub(crate) fn is_fork_in_bound(
         &self,
         n_child: ChildrenCount,
@@ -1,4 +1,5 @@
 use crate::generators::aes_ctr::{AesBlockCipher, AesIndex, AesKey, BYTES_PER_BATCH};
+use crate::generators::BlockCipherKind;
 use core::arch::aarch64::{
     uint8x16_t, vaeseq_u8, vaesmcq_u8, vdupq_n_u32, vdupq_n_u8, veorq_u8, vgetq_lane_u32,
     vreinterpretq_u32_u8, vreinterpretq_u8_u32,
@@ -15,9 +16,13 @@ const NUM_ROUND_KEYS: usize = NUM_ROUNDS + 1;
 #[derive(Clone)]
 pub struct ArmAesBlockCipher {
     round_keys: [uint8x16_t; NUM_ROUND_KEYS],
+    // The key the round keys were derived from
+    key: AesKey,
 }
 
 impl AesBlockCipher for ArmAesBlockCipher {
+    const KIND: BlockCipherKind = BlockCipherKind::Aes;
+
     fn new(key: AesKey) -> ArmAesBlockCipher {
         let aes_detected = is_aarch64_feature_detected!("aes");
         let neon_detected = is_aarch64_feature_detected!("neon");
@@ -31,7 +36,11 @@ impl AesBlockCipher for ArmAesBlockCipher {
         }
 
         let round_keys = unsafe { generate_round_keys(key) };
-        ArmAesBlockCipher { round_keys }
+        ArmAesBlockCipher { round_keys, key }
+    }
+
+    fn key(&self) -> AesKey {
+        self.key
     }
 
     fn generate_batch(&mut self, AesIndex(aes_ctr): AesIndex) -> [u8; BYTES_PER_BATCH] {
@@ -1,4 +1,5 @@
 use crate::generators::aes_ctr::{AesBlockCipher, AesIndex, AesKey, BYTES_PER_BATCH};
+use crate::generators::BlockCipherKind;
 use std::arch::x86_64::{
     __m128i, _mm_aesenc_si128, _mm_aesenclast_si128, _mm_aeskeygenassist_si128, _mm_load_si128,
     _mm_shuffle_epi32, _mm_slli_si128, _mm_store_si128, _mm_xor_si128,
@@ -10,9 +11,13 @@ use std::mem::transmute;
 pub struct AesniBlockCipher {
     // The set of round keys used for the aes encryption
     round_keys: [__m128i; 11],
+    // The key the round keys were derived from
+    key: AesKey,
 }
 
 impl AesBlockCipher for AesniBlockCipher {
+    const KIND: BlockCipherKind = BlockCipherKind::Aes;
+
     fn new(key: AesKey) -> AesniBlockCipher {
         let aes_detected = is_x86_feature_detected!("aes");
         let sse2_detected = is_x86_feature_detected!("sse2");
@@ -26,7 +31,11 @@ impl AesBlockCipher for AesniBlockCipher {
         }
 
         let round_keys = generate_round_keys(key);
-        AesniBlockCipher { round_keys }
+        AesniBlockCipher { round_keys, key }
+    }
+
+    fn key(&self) -> AesKey {
+        self.key
     }
 
     fn generate_batch(&mut self, AesIndex(aes_ctr): AesIndex) -> [u8; BYTES_PER_BATCH] {
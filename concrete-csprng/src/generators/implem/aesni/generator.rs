@@ -1,6 +1,9 @@
 use crate::generators::aes_ctr::{AesCtrGenerator, AesKey, ChildrenIterator};
 use crate::generators::implem::aesni::block_cipher::AesniBlockCipher;
-use crate::generators::{ByteCount, BytesPerChild, ChildrenCount, ForkError, RandomGenerator};
+use crate::generators::{
+    ByteCount, BytesPerChild, ChildrenCount, ForkError, GeneratorState, RandomGenerator,
+    RestoreError, SkipError, SnapshotableGenerator,
+};
 use crate::seeders::Seed;
 
 /// A random number generator using the `aesni` instructions.
@@ -27,6 +30,9 @@ impl RandomGenerator for AesniRandomGenerator {
     fn remaining_bytes(&self) -> ByteCount {
         self.0.remaining_bytes()
     }
+    fn try_skip(&mut self, n_bytes: ByteCount) -> Result<(), SkipError> {
+        self.0.try_skip(n_bytes)
+    }
     fn try_fork(
         &mut self,
         n_children: ChildrenCount,
@@ -38,6 +44,15 @@ impl RandomGenerator for AesniRandomGenerator {
     }
 }
 
+impl SnapshotableGenerator for AesniRandomGenerator {
+    fn save_state(&self) -> GeneratorState {
+        self.0.save_state()
+    }
+    fn restore_state(state: GeneratorState) -> Result<Self, RestoreError> {
+        AesCtrGenerator::restore_state(state).map(AesniRandomGenerator)
+    }
+}
+
 impl Iterator for AesniRandomGenerator {
     type Item = u8;
 
@@ -97,6 +112,26 @@ mod test {
         generator_generic_test::test_generator_determinism::<AesniRandomGenerator>();
     }
 
+    #[test]
+    fn test_skip() {
+        generator_generic_test::test_skip::<AesniRandomGenerator>();
+    }
+
+    #[test]
+    fn test_bounded_skip() {
+        generator_generic_test::test_bounded_skip::<AesniRandomGenerator>();
+    }
+
+    #[test]
+    fn test_save_restore_state() {
+        generator_generic_test::test_save_restore_state::<AesniRandomGenerator>();
+    }
+
+    #[test]
+    fn test_save_restore_bounded_state() {
+        generator_generic_test::test_save_restore_bounded_state::<AesniRandomGenerator>();
+    }
+
     #[test]
     fn test_fork() {
         generator_generic_test::test_fork_children::<AesniRandomGenerator>();
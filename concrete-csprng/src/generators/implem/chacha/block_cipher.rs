@@ -0,0 +1,186 @@
+use crate::generators::aes_ctr::{
+    AesBlockCipher, AesIndex, AesKey, BYTES_PER_AES_CALL, BYTES_PER_BATCH,
+};
+use crate::generators::BlockCipherKind;
+
+// The number of bytes outputted by a single call to the ChaCha20 block function.
+const BYTES_PER_CHACHA_BLOCK: usize = 64;
+
+// The number of table entries (of `BYTES_PER_AES_CALL` bytes) contained in a ChaCha20 block.
+const ENTRIES_PER_CHACHA_BLOCK: u128 = (BYTES_PER_CHACHA_BLOCK / BYTES_PER_AES_CALL) as u128;
+
+// The "expand 32-byte k" constant.
+const SIGMA: [u32; 4] = [0x6170_7865, 0x3320_646e, 0x7962_2d32, 0x6b20_6574];
+
+/// A block function using ChaCha20, plugged into the counter mode machinery of
+/// [`AesCtrGenerator`](crate::generators::aes_ctr::AesCtrGenerator).
+///
+/// Note:
+/// -----
+///
+/// The counter mode machinery indexes the pseudo-random table by entries of
+/// [`BYTES_PER_AES_CALL`] bytes. A ChaCha20 block containing four such entries, the entry `i` of
+/// the table is made of the bytes `16 * (i % 4)` to `16 * (i % 4) + 15` of the ChaCha20 block
+/// whose counter is `i / 4`. The 128 bits key of the generator is used as the first half of the
+/// 256 bits ChaCha20 key, the second half being set to zero.
+#[derive(Clone)]
+pub struct ChaChaBlockCipher {
+    key: [u32; 8],
+    // The last block computed, along with its counter. When the batches are not aligned on the
+    // ChaCha20 blocks, it is the first block of the next batch.
+    last_block: Option<(u128, [u8; BYTES_PER_CHACHA_BLOCK])>,
+}
+
+impl AesBlockCipher for ChaChaBlockCipher {
+    const KIND: BlockCipherKind = BlockCipherKind::ChaCha20;
+
+    fn new(key: AesKey) -> ChaChaBlockCipher {
+        let bytes = key.0.to_le_bytes();
+        let mut words = [0u32; 8];
+        for (word, chunk) in words.iter_mut().zip(bytes.chunks_exact(4)) {
+            *word = u32::from_le_bytes([chunk[0], chunk[1], chunk[2], chunk[3]]);
+        }
+        ChaChaBlockCipher {
+            key: words,
+            last_block: None,
+        }
+    }
+
+    fn key(&self) -> AesKey {
+        let mut bytes = [0u8; 16];
+        for (chunk, word) in bytes.chunks_exact_mut(4).zip(self.key.iter()) {
+            chunk.copy_from_slice(&word.to_le_bytes());
+        }
+        AesKey(u128::from_le_bytes(bytes))
+    }
+
+    fn generate_batch(&mut self, AesIndex(aes_ctr): AesIndex) -> [u8; BYTES_PER_BATCH] {
+        let first_block = aes_ctr / ENTRIES_PER_CHACHA_BLOCK;
+        let offset = (aes_ctr % ENTRIES_PER_CHACHA_BLOCK) as usize * BYTES_PER_AES_CALL;
+        // A batch aligned on the blocks spans two of them, while a batch starting in the middle of
+        // a block spans three, the first of which was the last one of the previous batch.
+        let last_block = if offset == 0 {
+            first_block + 1
+        } else {
+            first_block + 2
+        };
+        let mut blocks = [0u8; BYTES_PER_BATCH + BYTES_PER_CHACHA_BLOCK];
+        let mut block = [0u8; BYTES_PER_CHACHA_BLOCK];
+        for (counter, chunk) in
+            (first_block..=last_block).zip(blocks.chunks_exact_mut(BYTES_PER_CHACHA_BLOCK))
+        {
+            block = match self.last_block {
+                Some((cached_counter, cached_block)) if cached_counter == counter => cached_block,
+                _ => chacha20_block(&self.key, counter),
+            };
+            chunk.copy_from_slice(&block);
+        }
+        self.last_block = Some((last_block, block));
+        let mut output = [0u8; BYTES_PER_BATCH];
+        output.copy_from_slice(&blocks[offset..offset + BYTES_PER_BATCH]);
+        output
+    }
+}
+
+#[inline(always)]
+fn quarter_round(state: &mut [u32; 16], a: usize, b: usize, c: usize, d: usize) {
+    state[a] = state[a].wrapping_add(state[b]);
+    state[d] = (state[d] ^ state[a]).rotate_left(16);
+    state[c] = state[c].wrapping_add(state[d]);
+    state[b] = (state[b] ^ state[c]).rotate_left(12);
+    state[a] = state[a].wrapping_add(state[b]);
+    state[d] = (state[d] ^ state[a]).rotate_left(8);
+    state[c] = state[c].wrapping_add(state[d]);
+    state[b] = (state[b] ^ state[c]).rotate_left(7);
+}
+
+// Computes the ChaCha20 block function for the given key and 128 bits block counter.
+fn chacha20_block(key: &[u32; 8], counter: u128) -> [u8; BYTES_PER_CHACHA_BLOCK] {
+    let mut initial = [0u32; 16];
+    initial[..4].copy_from_slice(&SIGMA);
+    initial[4..12].copy_from_slice(key);
+    for (i, word) in initial[12..].iter_mut().enumerate() {
+        *word = (counter >> (32 * i)) as u32;
+    }
+    let mut state = initial;
+    for _ in 0..10 {
+        // Column rounds
+        quarter_round(&mut state, 0, 4, 8, 12);
+        quarter_round(&mut state, 1, 5, 9, 13);
+        quarter_round(&mut state, 2, 6, 10, 14);
+        quarter_round(&mut state, 3, 7, 11, 15);
+        // Diagonal rounds
+        quarter_round(&mut state, 0, 5, 10, 15);
+        quarter_round(&mut state, 1, 6, 11, 12);
+        quarter_round(&mut state, 2, 7, 8, 13);
+        quarter_round(&mut state, 3, 4, 9, 14);
+    }
+    let mut output = [0u8; BYTES_PER_CHACHA_BLOCK];
+    for ((chunk, word), initial_word) in output.chunks_exact_mut(4).zip(state).zip(initial) {
+        chunk.copy_from_slice(&word.wrapping_add(initial_word).to_le_bytes());
+    }
+    output
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    // Test vector for the ChaCha20 block function, from the section 2.3.2 of RFC 8439.
+    const KEY: [u32; 8] = [
+        0x0302_0100,
+        0x0706_0504,
+        0x0b0a_0908,
+        0x0f0e_0d0c,
+        0x1312_1110,
+        0x1716_1514,
+        0x1b1a_1918,
+        0x1f1e_1d1c,
+    ];
+    // The 32 bits block counter set to 1, followed by the 96 bits nonce.
+    const COUNTER: u128 = 0x0000_0000_4a00_0000_0900_0000_0000_0001;
+    const BLOCK: [u8; BYTES_PER_CHACHA_BLOCK] = [
+        0x10, 0xf1, 0xe7, 0xe4, 0xd1, 0x3b, 0x59, 0x15, 0x50, 0x0f, 0xdd, 0x1f, 0xa3, 0x20, 0x71,
+        0xc4, 0xc7, 0xd1, 0xf4, 0xc7, 0x33, 0xc0, 0x68, 0x03, 0x04, 0x22, 0xaa, 0x9a, 0xc3, 0xd4,
+        0x6c, 0x4e, 0xd2, 0x82, 0x64, 0x46, 0x07, 0x9f, 0xaa, 0x09, 0x14, 0xc2, 0xd7, 0x05, 0xd9,
+        0x8b, 0x02, 0xa2, 0xb5, 0x12, 0x9c, 0xd1, 0xde, 0x16, 0x4e, 0xb9, 0xcb, 0xd0, 0x83, 0xe8,
+        0xa2, 0x50, 0x3c, 0x4e,
+    ];
+
+    #[test]
+    fn test_block_function() {
+        assert_eq!(chacha20_block(&KEY, COUNTER), BLOCK);
+    }
+
+    #[test]
+    fn test_batch_overlapping_blocks() {
+        // Checks that batches starting in the middle of a block are consistent with the ones
+        // starting at the beginning of a block.
+        let mut cipher = ChaChaBlockCipher::new(AesKey(0x0123_4567_89ab_cdef_0123_4567_89ab_cdef));
+        let aligned = cipher.generate_batch(AesIndex(40));
+        for shift in 1..ENTRIES_PER_CHACHA_BLOCK as usize {
+            let shifted = cipher.generate_batch(AesIndex(40 + shift as u128));
+            assert_eq!(
+                shifted[..BYTES_PER_BATCH - shift * BYTES_PER_AES_CALL],
+                aligned[shift * BYTES_PER_AES_CALL..]
+            );
+        }
+    }
+
+    #[test]
+    fn test_consecutive_batches() {
+        // Checks that the batches reusing the last block of the previous one are the same as the
+        // ones computed from scratch.
+        let key = AesKey(0x0123_4567_89ab_cdef_0123_4567_89ab_cdef);
+        for start in 0..ENTRIES_PER_CHACHA_BLOCK {
+            let mut cipher = ChaChaBlockCipher::new(key);
+            for batch in 0..8 {
+                let index = AesIndex(start + batch * 8);
+                assert_eq!(
+                    cipher.generate_batch(index),
+                    ChaChaBlockCipher::new(key).generate_batch(index)
+                );
+            }
+        }
+    }
+}
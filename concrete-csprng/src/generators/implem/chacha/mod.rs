@@ -0,0 +1,18 @@
+//! A module implementing a random number generator, using the ChaCha20 stream cipher.
+//!
+//! This module implements a cryptographically secure pseudorandom number generator (CS-PRNG)
+//! which only relies on additions, rotations and xors of 32 bits words. Contrary to the software
+//! fallback of AES, it is fast on any platform, and in particular on platforms which do not have
+//! dedicated AES instructions (such as WASM). The block function follows
+//! [RFC 8439](https://www.rfc-editor.org/rfc/rfc8439), with the 32 bits counter and the 96 bits
+//! nonce merged into a single 128 bits block counter.
+
+mod block_cipher;
+
+mod generator;
+pub use generator::*;
+
+#[cfg(feature = "parallel")]
+mod parallel;
+#[cfg(feature = "parallel")]
+pub use parallel::*;
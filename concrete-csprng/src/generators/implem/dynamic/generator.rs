@@ -0,0 +1,551 @@
+#[cfg(feature = "generator_aarch64_aes")]
+use crate::generators::implem::aarch64::{ArmAesChildrenIterator, NeonAesRandomGenerator};
+#[cfg(feature = "generator_x86_64_aesni")]
+use crate::generators::implem::aesni::{AesniChildrenIterator, AesniRandomGenerator};
+#[cfg(feature = "generator_chacha")]
+use crate::generators::implem::chacha::{ChaChaChildrenIterator, ChaChaRandomGenerator};
+use crate::generators::implem::soft::{SoftwareChildrenIterator, SoftwareRandomGenerator};
+use crate::generators::{
+    BlockCipherKind, ByteCount, BytesPerChild, ChildrenCount, ForkError, GeneratorState,
+    RandomGenerator, RestoreError, SkipError, SnapshotableGenerator,
+};
+use crate::seeders::Seed;
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+
+/// The implementations of the generator which can be selected at runtime.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum GeneratorKind {
+    /// The software implementation of AES, available on every platform.
+    Software,
+    /// The implementation of AES using the x86_64 `aes` and `sse2` instructions.
+    Aesni,
+    /// The implementation of AES using the aarch64 `aes` and `neon` instructions.
+    NeonAes,
+    /// The implementation using the ChaCha20 stream cipher.
+    ///
+    /// Contrary to the other implementations, it does not output the same stream as the AES ones,
+    /// and is hence never selected by [`fastest_available`](GeneratorKind::fastest_available).
+    ChaCha,
+}
+
+impl GeneratorKind {
+    /// Returns whether the implementation was compiled in the crate, and is supported by the
+    /// current CPU.
+    pub fn is_available(&self) -> bool {
+        match self {
+            GeneratorKind::Software => true,
+            GeneratorKind::Aesni => {
+                #[cfg(feature = "generator_x86_64_aesni")]
+                {
+                    is_x86_feature_detected!("aes") && is_x86_feature_detected!("sse2")
+                }
+                #[cfg(not(feature = "generator_x86_64_aesni"))]
+                {
+                    false
+                }
+            }
+            GeneratorKind::NeonAes => {
+                #[cfg(feature = "generator_aarch64_aes")]
+                {
+                    std::arch::is_aarch64_feature_detected!("aes")
+                        && std::arch::is_aarch64_feature_detected!("neon")
+                }
+                #[cfg(not(feature = "generator_aarch64_aes"))]
+                {
+                    false
+                }
+            }
+            GeneratorKind::ChaCha => cfg!(feature = "generator_chacha"),
+        }
+    }
+
+    /// Returns the fastest AES implementation available, falling back to the software one.
+    pub fn fastest_available() -> GeneratorKind {
+        [GeneratorKind::Aesni, GeneratorKind::NeonAes]
+            .into_iter()
+            .find(GeneratorKind::is_available)
+            .unwrap_or(GeneratorKind::Software)
+    }
+}
+
+/// An error returned when requesting a generator implementation which is not available.
+#[derive(Debug)]
+pub struct UnavailableGeneratorError(pub GeneratorKind);
+
+impl Display for UnavailableGeneratorError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "The {:?} generator was not compiled in, or is not supported by the CPU.",
+            self.0
+        )
+    }
+}
+impl Error for UnavailableGeneratorError {}
+
+/// An error returned when restoring a generator with a given implementation.
+#[derive(Debug)]
+pub enum DynamicRestoreError {
+    UnavailableGenerator(UnavailableGeneratorError),
+    InvalidState(RestoreError),
+}
+
+impl Display for DynamicRestoreError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            DynamicRestoreError::UnavailableGenerator(error) => error.fmt(f),
+            DynamicRestoreError::InvalidState(error) => error.fmt(f),
+        }
+    }
+}
+impl Error for DynamicRestoreError {}
+
+/// Evaluates an expression with `$generator` aliased to the type implementing the given kind, or
+/// returns `None` if this implementation was not compiled in the crate.
+macro_rules! with_implementation {
+    ($kind: expr, $generator: ident => $expression: expr) => {
+        match $kind {
+            GeneratorKind::Software => {
+                type $generator = SoftwareRandomGenerator;
+                Some($expression)
+            }
+            GeneratorKind::Aesni => {
+                #[cfg(feature = "generator_x86_64_aesni")]
+                {
+                    type $generator = AesniRandomGenerator;
+                    Some($expression)
+                }
+                #[cfg(not(feature = "generator_x86_64_aesni"))]
+                {
+                    None
+                }
+            }
+            GeneratorKind::NeonAes => {
+                #[cfg(feature = "generator_aarch64_aes")]
+                {
+                    type $generator = NeonAesRandomGenerator;
+                    Some($expression)
+                }
+                #[cfg(not(feature = "generator_aarch64_aes"))]
+                {
+                    None
+                }
+            }
+            GeneratorKind::ChaCha => {
+                #[cfg(feature = "generator_chacha")]
+                {
+                    type $generator = ChaChaRandomGenerator;
+                    Some($expression)
+                }
+                #[cfg(not(feature = "generator_chacha"))]
+                {
+                    None
+                }
+            }
+        }
+    };
+}
+
+/// Evaluates an expression with `$generator` bound to the value held by the `$enum` variant of
+/// the implementation.
+macro_rules! dispatch {
+    ($value: expr, $enum: ident, $generator: ident => $expression: expr) => {
+        match $value {
+            $enum::Software($generator) => $expression,
+            #[cfg(feature = "generator_x86_64_aesni")]
+            $enum::Aesni($generator) => $expression,
+            #[cfg(feature = "generator_aarch64_aes")]
+            $enum::NeonAes($generator) => $expression,
+            #[cfg(feature = "generator_chacha")]
+            $enum::ChaCha($generator) => $expression,
+        }
+    };
+}
+
+/// The generators of the implementations compiled in the crate.
+///
+/// Matching on the variant costs a predictable branch per call, and lets the compiler inline the
+/// implementations, which a trait object would prevent.
+enum Implementation {
+    Software(SoftwareRandomGenerator),
+    #[cfg(feature = "generator_x86_64_aesni")]
+    Aesni(AesniRandomGenerator),
+    #[cfg(feature = "generator_aarch64_aes")]
+    NeonAes(NeonAesRandomGenerator),
+    #[cfg(feature = "generator_chacha")]
+    ChaCha(ChaChaRandomGenerator),
+}
+
+impl Implementation {
+    fn kind(&self) -> GeneratorKind {
+        match self {
+            Implementation::Software(_) => GeneratorKind::Software,
+            #[cfg(feature = "generator_x86_64_aesni")]
+            Implementation::Aesni(_) => GeneratorKind::Aesni,
+            #[cfg(feature = "generator_aarch64_aes")]
+            Implementation::NeonAes(_) => GeneratorKind::NeonAes,
+            #[cfg(feature = "generator_chacha")]
+            Implementation::ChaCha(_) => GeneratorKind::ChaCha,
+        }
+    }
+}
+
+/// The children iterators of the implementations compiled in the crate.
+enum ImplementationChildren {
+    Software(SoftwareChildrenIterator),
+    #[cfg(feature = "generator_x86_64_aesni")]
+    Aesni(AesniChildrenIterator),
+    #[cfg(feature = "generator_aarch64_aes")]
+    NeonAes(ArmAesChildrenIterator),
+    #[cfg(feature = "generator_chacha")]
+    ChaCha(ChaChaChildrenIterator),
+}
+
+impl Iterator for ImplementationChildren {
+    type Item = Implementation;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        dispatch!(self, ImplementationChildren, children => {
+            children.next().map(Implementation::from)
+        })
+    }
+}
+
+/// Implements the conversions from the types of an implementation to the enum variants.
+macro_rules! implementation_from {
+    ($(#[$cfg: meta])? $variant: ident, $generator: ty, $children: ty) => {
+        $(#[$cfg])?
+        impl From<$generator> for Implementation {
+            fn from(generator: $generator) -> Self {
+                Implementation::$variant(generator)
+            }
+        }
+
+        $(#[$cfg])?
+        impl From<$children> for ImplementationChildren {
+            fn from(children: $children) -> Self {
+                ImplementationChildren::$variant(children)
+            }
+        }
+    };
+}
+
+implementation_from!(Software, SoftwareRandomGenerator, SoftwareChildrenIterator);
+implementation_from!(
+    #[cfg(feature = "generator_x86_64_aesni")]
+    Aesni,
+    AesniRandomGenerator,
+    AesniChildrenIterator
+);
+implementation_from!(
+    #[cfg(feature = "generator_aarch64_aes")]
+    NeonAes,
+    NeonAesRandomGenerator,
+    ArmAesChildrenIterator
+);
+implementation_from!(
+    #[cfg(feature = "generator_chacha")]
+    ChaCha,
+    ChaChaRandomGenerator,
+    ChaChaChildrenIterator
+);
+
+/// A random number generator whose implementation is selected at runtime.
+///
+/// The implementation is chosen once, when the generator is created, and is inherited by the
+/// children generators.
+pub struct DynamicRandomGenerator {
+    generator: Implementation,
+}
+
+impl DynamicRandomGenerator {
+    /// Creates a new generator from a seed, using the given implementation.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use concrete_csprng::generators::{DynamicRandomGenerator, GeneratorKind, RandomGenerator};
+    /// use concrete_csprng::seeders::Seed;
+    ///
+    /// let mut software =
+    ///     DynamicRandomGenerator::new_with_kind(Seed(0), GeneratorKind::Software).unwrap();
+    /// let mut fastest = DynamicRandomGenerator::new(Seed(0));
+    /// assert_eq!(software.kind(), GeneratorKind::Software);
+    /// assert_eq!(fastest.kind(), GeneratorKind::fastest_available());
+    /// for _ in 0..1024 {
+    ///     assert_eq!(software.next(), fastest.next());
+    /// }
+    /// ```
+    pub fn new_with_kind(
+        seed: Seed,
+        kind: GeneratorKind,
+    ) -> Result<DynamicRandomGenerator, UnavailableGeneratorError> {
+        let generator = if kind.is_available() {
+            with_implementation!(kind, Generator => Implementation::from(Generator::new(seed)))
+        } else {
+            None
+        };
+        generator
+            .map(|generator| DynamicRandomGenerator { generator })
+            .ok_or(UnavailableGeneratorError(kind))
+    }
+
+    /// Restores a generator from a snapshot of its state, using the given implementation.
+    ///
+    /// The state of a generator using any AES implementation can be restored with any other AES
+    /// implementation, as all of them output the same stream.
+    pub fn restore_state_with_kind(
+        state: GeneratorState,
+        kind: GeneratorKind,
+    ) -> Result<DynamicRandomGenerator, DynamicRestoreError> {
+        let generator = if kind.is_available() {
+            with_implementation!(kind, Generator => {
+                Generator::restore_state(state).map(Implementation::from)
+            })
+        } else {
+            None
+        };
+        generator
+            .ok_or(DynamicRestoreError::UnavailableGenerator(
+                UnavailableGeneratorError(kind),
+            ))?
+            .map(|generator| DynamicRandomGenerator { generator })
+            .map_err(DynamicRestoreError::InvalidState)
+    }
+
+    /// Returns the implementation used by the generator.
+    pub fn kind(&self) -> GeneratorKind {
+        self.generator.kind()
+    }
+}
+
+/// The children iterator used by [`DynamicRandomGenerator`].
+///
+/// Outputs children generators one by one.
+pub struct DynamicChildrenIterator(ImplementationChildren);
+
+impl Iterator for DynamicChildrenIterator {
+    type Item = DynamicRandomGenerator;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.0
+            .next()
+            .map(|generator| DynamicRandomGenerator { generator })
+    }
+}
+
+impl RandomGenerator for DynamicRandomGenerator {
+    type ChildrenIter = DynamicChildrenIterator;
+    fn new(seed: Seed) -> Self {
+        DynamicRandomGenerator::new_with_kind(seed, GeneratorKind::fastest_available()).unwrap()
+    }
+    fn remaining_bytes(&self) -> ByteCount {
+        dispatch!(&self.generator, Implementation, generator => generator.remaining_bytes())
+    }
+    fn try_skip(&mut self, n_bytes: ByteCount) -> Result<(), SkipError> {
+        dispatch!(&mut self.generator, Implementation, generator => generator.try_skip(n_bytes))
+    }
+    fn try_fork(
+        &mut self,
+        n_children: ChildrenCount,
+        n_bytes: BytesPerChild,
+    ) -> Result<Self::ChildrenIter, ForkError> {
+        dispatch!(&mut self.generator, Implementation, generator => {
+            generator
+                .try_fork(n_children, n_bytes)
+                .map(|children| DynamicChildrenIterator(children.into()))
+        })
+    }
+}
+
+impl SnapshotableGenerator for DynamicRandomGenerator {
+    fn save_state(&self) -> GeneratorState {
+        dispatch!(&self.generator, Implementation, generator => generator.save_state())
+    }
+    /// Restores a generator from a snapshot of its state, using the fastest AES implementation
+    /// available for the AES snapshots, and the ChaCha20 one for the ChaCha20 snapshots.
+    fn restore_state(state: GeneratorState) -> Result<Self, RestoreError> {
+        let kind = match state.block_cipher {
+            BlockCipherKind::Aes => GeneratorKind::fastest_available(),
+            BlockCipherKind::ChaCha20 => GeneratorKind::ChaCha,
+        };
+        DynamicRandomGenerator::restore_state_with_kind(state, kind).map_err(|error| match error {
+            DynamicRestoreError::InvalidState(error) => error,
+            DynamicRestoreError::UnavailableGenerator(_) => RestoreError::UnavailableBlockCipher,
+        })
+    }
+}
+
+impl Iterator for DynamicRandomGenerator {
+    type Item = u8;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        dispatch!(&mut self.generator, Implementation, generator => generator.next())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::generators::generator_generic_test;
+    use rand::Rng;
+
+    fn available_aes_kinds() -> impl Iterator<Item = GeneratorKind> {
+        [
+            GeneratorKind::Software,
+            GeneratorKind::Aesni,
+            GeneratorKind::NeonAes,
+        ]
+        .into_iter()
+        .filter(GeneratorKind::is_available)
+    }
+
+    fn available_kinds() -> impl Iterator<Item = GeneratorKind> {
+        available_aes_kinds().chain(
+            [GeneratorKind::ChaCha]
+                .into_iter()
+                .filter(GeneratorKind::is_available),
+        )
+    }
+
+    #[test]
+    fn test_fork() {
+        generator_generic_test::test_fork_children::<DynamicRandomGenerator>();
+    }
+
+    #[test]
+    fn test_generator_determinism() {
+        generator_generic_test::test_generator_determinism::<DynamicRandomGenerator>();
+    }
+
+    #[test]
+    fn test_skip() {
+        generator_generic_test::test_skip::<DynamicRandomGenerator>();
+    }
+
+    #[test]
+    fn test_bounded_skip() {
+        generator_generic_test::test_bounded_skip::<DynamicRandomGenerator>();
+    }
+
+    #[test]
+    fn test_save_restore_state() {
+        generator_generic_test::test_save_restore_state::<DynamicRandomGenerator>();
+    }
+
+    #[test]
+    fn test_save_restore_bounded_state() {
+        generator_generic_test::test_save_restore_bounded_state::<DynamicRandomGenerator>();
+    }
+
+    #[test]
+    fn test_restore_state_across_kinds() {
+        for saving_kind in available_aes_kinds() {
+            let mut saving = DynamicRandomGenerator::new_with_kind(Seed(0), saving_kind).unwrap();
+            saving.try_skip(ByteCount(1000)).unwrap();
+            let state = saving.save_state();
+            for restoring_kind in available_aes_kinds() {
+                let mut restored =
+                    DynamicRandomGenerator::restore_state_with_kind(state, restoring_kind).unwrap();
+                let mut saving = DynamicRandomGenerator::restore_state(state).unwrap();
+                for _ in 0..128 {
+                    assert_eq!(restored.next(), saving.next());
+                }
+            }
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "expected test panic")]
+    fn test_bounded_panic() {
+        generator_generic_test::test_bounded_none_should_panic::<DynamicRandomGenerator>();
+    }
+
+    #[test]
+    fn test_fastest_available() {
+        assert!(GeneratorKind::fastest_available().is_available());
+        assert_eq!(
+            DynamicRandomGenerator::new(Seed(0)).kind(),
+            GeneratorKind::fastest_available()
+        );
+    }
+
+    #[test]
+    fn test_unavailable_kind() {
+        for kind in [
+            GeneratorKind::Aesni,
+            GeneratorKind::NeonAes,
+            GeneratorKind::ChaCha,
+        ] {
+            if !kind.is_available() {
+                assert!(DynamicRandomGenerator::new_with_kind(Seed(0), kind).is_err());
+            }
+        }
+    }
+
+    #[test]
+    fn test_kinds_output_same_stream() {
+        for _ in 0..100 {
+            let seed = Seed(rand::thread_rng().gen());
+            let mut reference =
+                DynamicRandomGenerator::new_with_kind(seed, GeneratorKind::Software).unwrap();
+            let expected: Vec<u8> = (&mut reference).take(4096).collect();
+            for kind in available_aes_kinds() {
+                let mut generator = DynamicRandomGenerator::new_with_kind(seed, kind).unwrap();
+                let actual: Vec<u8> = (&mut generator).take(4096).collect();
+                assert_eq!(actual, expected);
+            }
+        }
+    }
+
+    #[test]
+    fn test_children_keep_kind() {
+        for kind in available_kinds() {
+            let mut generator = DynamicRandomGenerator::new_with_kind(Seed(0), kind).unwrap();
+            let children = generator
+                .try_fork(ChildrenCount(4), BytesPerChild(16))
+                .unwrap();
+            for child in children {
+                assert_eq!(child.kind(), kind);
+            }
+        }
+    }
+
+    #[test]
+    fn test_fastest_available_is_aes() {
+        assert_ne!(GeneratorKind::fastest_available(), GeneratorKind::ChaCha);
+    }
+
+    #[cfg(feature = "generator_chacha")]
+    #[test]
+    fn test_chacha_kind() {
+        let seed = Seed(rand::thread_rng().gen());
+        let mut dynamic =
+            DynamicRandomGenerator::new_with_kind(seed, GeneratorKind::ChaCha).unwrap();
+        let mut chacha = ChaChaRandomGenerator::new(seed);
+        for _ in 0..4096 {
+            assert_eq!(dynamic.next(), chacha.next());
+        }
+        let mut restored = DynamicRandomGenerator::restore_state_with_kind(
+            dynamic.save_state(),
+            GeneratorKind::ChaCha,
+        )
+        .unwrap();
+        for _ in 0..128 {
+            assert_eq!(restored.next(), chacha.next());
+        }
+        let state = chacha.save_state();
+        assert_eq!(
+            DynamicRandomGenerator::restore_state(state).unwrap().kind(),
+            GeneratorKind::ChaCha
+        );
+        assert!(matches!(
+            DynamicRandomGenerator::restore_state_with_kind(state, GeneratorKind::Software),
+            Err(DynamicRestoreError::InvalidState(
+                RestoreError::BlockCipherMismatch
+            ))
+        ));
+    }
+}
@@ -0,0 +1,15 @@
+//! A module using a random number generator whose implementation is selected at runtime.
+//!
+//! All the AES-CTR implementations compiled in the crate output the same stream for a given
+//! seed. The [`DynamicRandomGenerator`] takes advantage of this to pick the fastest one supported
+//! by the CPU the program is running on, which allows a single binary to run on machines with
+//! heterogeneous instruction sets. The ChaCha20 implementation, which outputs a different stream,
+//! is only used when explicitly requested with [`GeneratorKind::ChaCha`].
+
+mod generator;
+pub use generator::*;
+
+#[cfg(feature = "parallel")]
+mod parallel;
+#[cfg(feature = "parallel")]
+pub use parallel::*;
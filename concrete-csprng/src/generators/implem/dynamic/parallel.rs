@@ -0,0 +1,72 @@
+use super::*;
+use crate::generators::{
+    BytesPerChild, ChildrenCount, ForkError, ParallelRandomGenerator, RandomGenerator,
+};
+use rayon::iter::plumbing::{Consumer, ProducerCallback, UnindexedConsumer};
+use rayon::prelude::*;
+
+/// The parallel children iterator used by [`DynamicRandomGenerator`].
+///
+/// Outputs the children generators one by one.
+pub struct ParallelDynamicChildrenIterator(rayon::vec::IntoIter<DynamicRandomGenerator>);
+
+impl ParallelIterator for ParallelDynamicChildrenIterator {
+    type Item = DynamicRandomGenerator;
+    fn drive_unindexed<C>(self, consumer: C) -> C::Result
+    where
+        C: UnindexedConsumer<Self::Item>,
+    {
+        self.0.drive_unindexed(consumer)
+    }
+}
+
+impl IndexedParallelIterator for ParallelDynamicChildrenIterator {
+    fn len(&self) -> usize {
+        self.0.len()
+    }
+    fn drive<C: Consumer<Self::Item>>(self, consumer: C) -> C::Result {
+        self.0.drive(consumer)
+    }
+    fn with_producer<CB: ProducerCallback<Self::Item>>(self, callback: CB) -> CB::Output {
+        self.0.with_producer(callback)
+    }
+}
+
+impl ParallelRandomGenerator for DynamicRandomGenerator {
+    type ParChildrenIter = ParallelDynamicChildrenIterator;
+
+    fn par_try_fork(
+        &mut self,
+        n_children: ChildrenCount,
+        n_bytes: BytesPerChild,
+    ) -> Result<Self::ParChildrenIter, ForkError> {
+        // Creating the children is cheap compared to using them, so they are created sequentially
+        // and only handed to the parallel iterator afterwards.
+        let children: Vec<_> = self.try_fork(n_children, n_bytes)?.collect();
+        Ok(ParallelDynamicChildrenIterator(children.into_par_iter()))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::generators::RandomGenerator;
+    use crate::seeders::Seed;
+
+    #[test]
+    fn test_par_fork_matches_fork() {
+        let mut sequential = DynamicRandomGenerator::new(Seed(0));
+        let mut parallel = DynamicRandomGenerator::new(Seed(0));
+        let expected: Vec<Vec<u8>> = sequential
+            .try_fork(ChildrenCount(8), BytesPerChild(32))
+            .unwrap()
+            .map(|child| child.collect())
+            .collect();
+        let actual: Vec<Vec<u8>> = parallel
+            .par_try_fork(ChildrenCount(8), BytesPerChild(32))
+            .unwrap()
+            .map(|child| child.collect())
+            .collect();
+        assert_eq!(actual, expected);
+    }
+}
@@ -8,6 +8,16 @@ mod aarch64;
 #[cfg(feature = "generator_aarch64_aes")]
 pub use aarch64::*;
 
+#[cfg(feature = "generator_chacha")]
+mod chacha;
+#[cfg(feature = "generator_chacha")]
+pub use chacha::*;
+
+#[cfg(feature = "generator_fallback")]
+mod dynamic;
+#[cfg(feature = "generator_fallback")]
+pub use dynamic::*;
+
 #[cfg(feature = "generator_fallback")]
 mod soft;
 #[cfg(feature = "generator_fallback")]
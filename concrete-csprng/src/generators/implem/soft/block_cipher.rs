@@ -1,6 +1,7 @@
 use crate::generators::aes_ctr::{
     AesBlockCipher, AesIndex, AesKey, AES_CALLS_PER_BATCH, BYTES_PER_AES_CALL, BYTES_PER_BATCH,
 };
+use crate::generators::BlockCipherKind;
 use aes::cipher::generic_array::GenericArray;
 use aes::cipher::{BlockEncrypt, KeyInit};
 use aes::Aes128;
@@ -9,14 +10,22 @@ use aes::Aes128;
 pub struct SoftwareBlockCipher {
     // Aes structure
     aes: Aes128,
+    // The key the aes structure was created from
+    key: AesKey,
 }
 
 impl AesBlockCipher for SoftwareBlockCipher {
+    const KIND: BlockCipherKind = BlockCipherKind::Aes;
+
     fn new(key: AesKey) -> SoftwareBlockCipher {
-        let key: [u8; BYTES_PER_AES_CALL] = key.0.to_ne_bytes();
-        let key = GenericArray::clone_from_slice(&key[..]);
-        let aes = Aes128::new(&key);
-        SoftwareBlockCipher { aes }
+        let bytes: [u8; BYTES_PER_AES_CALL] = key.0.to_ne_bytes();
+        let bytes = GenericArray::clone_from_slice(&bytes[..]);
+        let aes = Aes128::new(&bytes);
+        SoftwareBlockCipher { aes, key }
+    }
+
+    fn key(&self) -> AesKey {
+        self.key
     }
 
     fn generate_batch(&mut self, AesIndex(aes_ctr): AesIndex) -> [u8; BYTES_PER_BATCH] {
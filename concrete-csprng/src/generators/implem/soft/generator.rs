@@ -1,6 +1,9 @@
 use crate::generators::aes_ctr::{AesCtrGenerator, AesKey, ChildrenIterator};
 use crate::generators::implem::soft::block_cipher::SoftwareBlockCipher;
-use crate::generators::{ByteCount, BytesPerChild, ChildrenCount, ForkError, RandomGenerator};
+use crate::generators::{
+    ByteCount, BytesPerChild, ChildrenCount, ForkError, GeneratorState, RandomGenerator,
+    RestoreError, SkipError, SnapshotableGenerator,
+};
 use crate::seeders::Seed;
 
 /// A random number generator using a software implementation.
@@ -27,6 +30,9 @@ impl RandomGenerator for SoftwareRandomGenerator {
     fn remaining_bytes(&self) -> ByteCount {
         self.0.remaining_bytes()
     }
+    fn try_skip(&mut self, n_bytes: ByteCount) -> Result<(), SkipError> {
+        self.0.try_skip(n_bytes)
+    }
     fn try_fork(
         &mut self,
         n_children: ChildrenCount,
@@ -38,6 +44,15 @@ impl RandomGenerator for SoftwareRandomGenerator {
     }
 }
 
+impl SnapshotableGenerator for SoftwareRandomGenerator {
+    fn save_state(&self) -> GeneratorState {
+        self.0.save_state()
+    }
+    fn restore_state(state: GeneratorState) -> Result<Self, RestoreError> {
+        AesCtrGenerator::restore_state(state).map(SoftwareRandomGenerator)
+    }
+}
+
 impl Iterator for SoftwareRandomGenerator {
     type Item = u8;
 
@@ -102,6 +117,47 @@ mod test {
         generator_generic_test::test_generator_determinism::<SoftwareRandomGenerator>();
     }
 
+    #[test]
+    fn test_skip() {
+        generator_generic_test::test_skip::<SoftwareRandomGenerator>();
+    }
+
+    #[test]
+    fn test_bounded_skip() {
+        generator_generic_test::test_bounded_skip::<SoftwareRandomGenerator>();
+    }
+
+    #[test]
+    fn test_save_restore_state() {
+        generator_generic_test::test_save_restore_state::<SoftwareRandomGenerator>();
+    }
+
+    #[test]
+    fn test_save_restore_bounded_state() {
+        generator_generic_test::test_save_restore_bounded_state::<SoftwareRandomGenerator>();
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_serialize_state() {
+        let mut generator = SoftwareRandomGenerator::new(Seed(0));
+        generator.try_skip(ByteCount(1000)).unwrap();
+        let serialized = bincode::serialize(&generator.save_state()).unwrap();
+        let state = bincode::deserialize(&serialized).unwrap();
+        let mut restored = SoftwareRandomGenerator::restore_state(state).unwrap();
+        for _ in 0..128 {
+            assert_eq!(restored.next(), generator.next());
+        }
+    }
+
+    #[test]
+    fn test_state_debug_hides_key() {
+        let state = SoftwareRandomGenerator::new(Seed(0x0123_4567_89ab_cdef)).save_state();
+        let debug = format!("{:?}", state);
+        assert!(debug.contains("<redacted>"));
+        assert!(!debug.contains(&format!("{}", state.key)));
+    }
+
     #[test]
     #[should_panic(expected = "expected test panic")]
     fn test_bounded_panic() {
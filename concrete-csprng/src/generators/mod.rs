@@ -51,6 +51,118 @@ impl Display for ForkError {
 }
 impl Error for ForkError {}
 
+/// An error occuring when skipping bytes of a generator.
+#[derive(Debug)]
+pub enum SkipError {
+    SkipTooLarge,
+}
+
+impl Display for SkipError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            SkipError::SkipTooLarge => {
+                write!(f, "The skipped bytes would go beyond the generator bound.")
+            }
+        }
+    }
+}
+impl Error for SkipError {}
+
+/// The block ciphers the generators can be built upon.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum BlockCipherKind {
+    /// The AES block cipher, used by all the AES implementations.
+    Aes,
+    /// The ChaCha20 block function.
+    ChaCha20,
+}
+
+/// A snapshot of the state of a generator, from which it can be restored later.
+///
+/// Note:
+/// -----
+///
+/// The snapshot contains the secret key of the generator, and must hence be kept as secret as
+/// the seed the generator was created from. For this reason, the key is left out of the `Debug`
+/// output. The snapshot records the block cipher it was taken with, and can only be restored by a
+/// generator using the same block cipher (all the AES implementations being interchangeable).
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub struct GeneratorState {
+    // The block cipher of the generator.
+    pub(crate) block_cipher: BlockCipherKind,
+    // The key of the block cipher.
+    pub(crate) key: u128,
+    // The aes index of the last outputted byte.
+    pub(crate) aes_index: u128,
+    // The byte index of the last outputted byte.
+    pub(crate) byte_index: usize,
+    // The aes index of the first byte that can not be outputted.
+    pub(crate) bound_aes_index: u128,
+    // The byte index of the first byte that can not be outputted.
+    pub(crate) bound_byte_index: usize,
+}
+
+impl GeneratorState {
+    /// Returns the block cipher of the generator the snapshot was taken from.
+    pub fn block_cipher(&self) -> BlockCipherKind {
+        self.block_cipher
+    }
+}
+
+impl std::fmt::Debug for GeneratorState {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("GeneratorState")
+            .field("block_cipher", &self.block_cipher)
+            .field("key", &"<redacted>")
+            .field("aes_index", &self.aes_index)
+            .field("byte_index", &self.byte_index)
+            .field("bound_aes_index", &self.bound_aes_index)
+            .field("bound_byte_index", &self.bound_byte_index)
+            .finish()
+    }
+}
+
+/// An error occuring when restoring a generator from a snapshot of its state.
+#[derive(Debug)]
+pub enum RestoreError {
+    InvalidTableIndex,
+    IndexAfterBound,
+    BlockCipherMismatch,
+    UnavailableBlockCipher,
+}
+
+impl Display for RestoreError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            RestoreError::InvalidTableIndex => {
+                write!(
+                    f,
+                    "The state contains a byte index larger than an aes call."
+                )
+            }
+            RestoreError::IndexAfterBound => {
+                write!(f, "The state points to a byte after the generator bound.")
+            }
+            RestoreError::BlockCipherMismatch => {
+                write!(
+                    f,
+                    "The state was saved by a generator using a different block cipher."
+                )
+            }
+            RestoreError::UnavailableBlockCipher => {
+                write!(
+                    f,
+                    "The state was saved by a generator using a block cipher which is not \
+                    available."
+                )
+            }
+        }
+    }
+}
+impl Error for RestoreError {}
+
 /// A trait for cryptographically secure pseudo-random generators.
 ///
 /// See the [crate-level](#crate) documentation for details.
@@ -82,6 +194,27 @@ pub trait RandomGenerator: Iterator<Item = u8> {
         self.next()
     }
 
+    /// Tries to skip the next `n_bytes` bytes of the stream, so that the next outputted byte is the
+    /// one that would have been outputted after generating `n_bytes` bytes.
+    ///
+    /// Note:
+    /// -----
+    ///
+    /// To be successful, the number of remaining bytes for the generator must be larger than
+    /// `n_bytes`. The default implementation generates and discards the skipped bytes, but
+    /// generators able to seek in their stream should override it to skip in constant time.
+    fn try_skip(&mut self, n_bytes: ByteCount) -> Result<(), SkipError> {
+        if n_bytes > self.remaining_bytes() {
+            return Err(SkipError::SkipTooLarge);
+        }
+        let mut skipped = 0;
+        while skipped < n_bytes.0 {
+            self.next();
+            skipped += 1;
+        }
+        Ok(())
+    }
+
     /// Tries to fork the generator into an iterator of `n_children` new generators, each able to
     /// output `n_bytes` bytes.
     ///
@@ -97,6 +230,21 @@ pub trait RandomGenerator: Iterator<Item = u8> {
     ) -> Result<Self::ChildrenIter, ForkError>;
 }
 
+/// A trait for random generators whose state can be saved, and restored later on.
+pub trait SnapshotableGenerator: RandomGenerator {
+    /// Returns a snapshot of the state of the generator, which can be given to
+    /// [`restore_state`](SnapshotableGenerator::restore_state) to resume the stream later on.
+    fn save_state(&self) -> GeneratorState;
+
+    /// Restores a generator from a snapshot of its state.
+    ///
+    /// The restored generator outputs the same bytes as the generator the snapshot was taken from,
+    /// and has the same bound.
+    fn restore_state(state: GeneratorState) -> Result<Self, RestoreError>
+    where
+        Self: Sized;
+}
+
 /// A trait extending [`RandomGenerator`] to the parallel iterators of `rayon`.
 #[cfg(feature = "parallel")]
 pub trait ParallelRandomGenerator: RandomGenerator + Send {
@@ -232,4 +380,80 @@ pub mod generator_generic_test {
         // One call too many, should panic
         bounded.next().ok_or("expected test panic").unwrap();
     }
+
+    /// Checks that skipping bytes leads to the same stream as generating and discarding them.
+    pub fn test_skip<G: RandomGenerator>() {
+        for _ in 0..REPEATS {
+            let seed = any_seed().next().unwrap();
+            let n_bytes = rand::thread_rng().gen::<usize>() % 4096;
+            let mut skipping = G::new(seed);
+            let mut generating = G::new(seed);
+            skipping.try_skip(ByteCount(n_bytes as u128)).unwrap();
+            for _ in 0..n_bytes {
+                generating.next().unwrap();
+            }
+            for _ in 0..128 {
+                assert_eq!(skipping.next(), generating.next());
+            }
+        }
+    }
+
+    /// Checks that a restored generator outputs the same bytes as the saved one.
+    pub fn test_save_restore_state<G: SnapshotableGenerator>() {
+        for _ in 0..REPEATS {
+            let seed = any_seed().next().unwrap();
+            let n_bytes = rand::thread_rng().gen::<usize>() % 4096;
+            let mut generator = G::new(seed);
+            for _ in 0..n_bytes {
+                generator.next().unwrap();
+            }
+            let mut restored = G::restore_state(generator.save_state()).unwrap();
+            assert_eq!(restored.remaining_bytes(), generator.remaining_bytes());
+            for _ in 0..128 {
+                assert_eq!(restored.next(), generator.next());
+            }
+        }
+    }
+
+    /// Checks that a restored bounded generator keeps the bound of the saved one.
+    pub fn test_save_restore_bounded_state<G: SnapshotableGenerator>() {
+        for _ in 0..REPEATS {
+            let ((seed, n_children), n_bytes) = any_seed()
+                .zip(some_children_count())
+                .zip(some_bytes_per_child())
+                .next()
+                .unwrap();
+            let mut gen = G::new(seed);
+            let mut bounded = gen.try_fork(n_children, n_bytes).unwrap().next().unwrap();
+            for _ in 0..n_bytes.0 {
+                let mut restored = G::restore_state(bounded.save_state()).unwrap();
+                assert_eq!(restored.remaining_bytes(), bounded.remaining_bytes());
+                assert_eq!(restored.next(), bounded.next());
+            }
+            let mut restored = G::restore_state(bounded.save_state()).unwrap();
+            assert_eq!(restored.remaining_bytes(), ByteCount(0));
+            assert!(restored.next().is_none());
+        }
+    }
+
+    /// Checks that a bounded generator can be skipped up to its bound, but not further.
+    pub fn test_bounded_skip<G: RandomGenerator>() {
+        for _ in 0..REPEATS {
+            let ((seed, n_children), n_bytes) = any_seed()
+                .zip(some_children_count())
+                .zip(some_bytes_per_child())
+                .next()
+                .unwrap();
+            let mut gen = G::new(seed);
+            let mut bounded = gen.try_fork(n_children, n_bytes).unwrap().next().unwrap();
+            assert!(matches!(
+                bounded.try_skip(ByteCount(n_bytes.0 as u128 + 1)),
+                Err(SkipError::SkipTooLarge)
+            ));
+            bounded.try_skip(ByteCount(n_bytes.0 as u128 - 1)).unwrap();
+            assert_eq!(bounded.remaining_bytes(), ByteCount(1));
+            assert!(bounded.next().is_some());
+            assert!(bounded.next().is_none());
+        }
+    }
 }
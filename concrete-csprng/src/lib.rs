@@ -114,5 +114,17 @@
 //!
 //! The implementation is based on the AES blockcipher used in counter (CTR) mode, as presented
 //! in the ISO/IEC 18033-4 document.
+//!
+//! The AES implementations (software, x86_64 `aes` instructions, aarch64 `aes` instructions)
+//! output the same stream for a given seed. The
+//! [`DynamicRandomGenerator`](generators::DynamicRandomGenerator) selects at runtime the fastest
+//! one compiled in and supported by the CPU, which allows a single binary to run on heterogeneous
+//! machines.
+//!
+//! A generator based on the ChaCha20 stream cipher is also available behind the `generator_chacha`
+//! feature. It uses the same counter mode machinery (and hence has the same forking behavior), but
+//! does not rely on dedicated instructions to be fast. It can also be selected at runtime in the
+//! [`DynamicRandomGenerator`](generators::DynamicRandomGenerator), with
+//! [`GeneratorKind::ChaCha`](generators::GeneratorKind::ChaCha).
 pub mod generators;
 pub mod seeders;
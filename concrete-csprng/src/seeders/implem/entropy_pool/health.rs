@@ -0,0 +1,132 @@
+//! The continuous health tests of NIST SP 800-90B (section 4.4).
+//!
+//! Both tests are parametrized with a false positive probability α = 2⁻²⁰, and a conservative
+//! min-entropy estimate of H = 1 bit per byte sample.
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+
+/// The number of identical consecutive samples triggering a repetition count test failure,
+/// computed as C = 1 + ⌈-log₂(α) / H⌉.
+const REPETITION_COUNT_CUTOFF: usize = 21;
+
+/// The size of the windows of the adaptive proportion test, for non-binary sources.
+const ADAPTIVE_PROPORTION_WINDOW: usize = 512;
+
+/// The number of occurrences of the first sample of a window triggering an adaptive proportion
+/// test failure (see table 2 of SP 800-90B, for H = 1).
+const ADAPTIVE_PROPORTION_CUTOFF: usize = 410;
+
+/// A failure of a health test.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum HealthTestFailure {
+    RepetitionCount,
+    AdaptiveProportion,
+}
+
+impl Display for HealthTestFailure {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            HealthTestFailure::RepetitionCount => write!(f, "repetition count"),
+            HealthTestFailure::AdaptiveProportion => write!(f, "adaptive proportion"),
+        }
+    }
+}
+impl Error for HealthTestFailure {}
+
+/// The state of the health tests run on the byte samples of a single source.
+pub struct HealthTests {
+    // The last sample seen by the repetition count test.
+    last_sample: Option<u8>,
+    // The number of consecutive occurrences of the last sample.
+    repetitions: usize,
+    // The first sample of the current adaptive proportion window.
+    window_sample: u8,
+    // The number of samples already seen in the current window, zero if no window is open.
+    window_position: usize,
+    // The number of occurrences of the first sample in the current window.
+    window_count: usize,
+}
+
+impl HealthTests {
+    /// Creates the state of the health tests of a new source.
+    pub fn new() -> HealthTests {
+        HealthTests {
+            last_sample: None,
+            repetitions: 0,
+            window_sample: 0,
+            window_position: 0,
+            window_count: 0,
+        }
+    }
+
+    /// Runs the health tests on new samples of the source.
+    pub fn check(&mut self, samples: &[u8]) -> Result<(), HealthTestFailure> {
+        samples
+            .iter()
+            .try_for_each(|sample| self.check_sample(*sample))
+    }
+
+    fn check_sample(&mut self, sample: u8) -> Result<(), HealthTestFailure> {
+        // Repetition count test.
+        if self.last_sample == Some(sample) {
+            self.repetitions += 1;
+            if self.repetitions >= REPETITION_COUNT_CUTOFF {
+                return Err(HealthTestFailure::RepetitionCount);
+            }
+        } else {
+            self.last_sample = Some(sample);
+            self.repetitions = 1;
+        }
+
+        // Adaptive proportion test.
+        if self.window_position == 0 {
+            self.window_sample = sample;
+            self.window_count = 1;
+        } else if self.window_sample == sample {
+            self.window_count += 1;
+            if self.window_count >= ADAPTIVE_PROPORTION_CUTOFF {
+                return Err(HealthTestFailure::AdaptiveProportion);
+            }
+        }
+        self.window_position = (self.window_position + 1) % ADAPTIVE_PROPORTION_WINDOW;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use rand::Rng;
+
+    #[test]
+    fn test_random_source_passes() {
+        let mut tests = HealthTests::new();
+        for _ in 0..1000 {
+            let samples: [u8; 1024] = std::array::from_fn(|_| rand::thread_rng().gen());
+            tests.check(&samples).unwrap();
+        }
+    }
+
+    #[test]
+    fn test_stuck_source_fails_repetition_count() {
+        let mut tests = HealthTests::new();
+        assert_eq!(
+            tests.check(&[42; REPETITION_COUNT_CUTOFF]),
+            Err(HealthTestFailure::RepetitionCount)
+        );
+    }
+
+    #[test]
+    fn test_biased_source_fails_adaptive_proportion() {
+        // Each sample is repeated less than the repetition count cutoff, but the source outputs
+        // the same value far too often.
+        let mut tests = HealthTests::new();
+        let samples: Vec<u8> = (0..ADAPTIVE_PROPORTION_WINDOW)
+            .map(|i| if i % 8 == 7 { 1 } else { 0 })
+            .collect();
+        assert_eq!(
+            tests.check(&samples),
+            Err(HealthTestFailure::AdaptiveProportion)
+        );
+    }
+}
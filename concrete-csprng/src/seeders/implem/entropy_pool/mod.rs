@@ -0,0 +1,130 @@
+use crate::seeders::{Seed, Seeder};
+use sha2::{Digest, Sha256};
+
+mod health;
+use health::HealthTests;
+
+/// The number of bytes drawn from each source to extract a seed.
+const BYTES_PER_SOURCE: usize = 32;
+
+/// The number of bytes drawn from each source to run the startup health tests.
+const STARTUP_BYTES: usize = 1024;
+
+/// A prefix to the inputs of the extractor, to separate them from other uses of SHA-256.
+const DOMAIN_SEPARATOR: &[u8] = b"concrete-csprng entropy pool seeder";
+
+/// A seeder pooling several sources of entropy.
+///
+/// Each seed is extracted with SHA-256 from:
+/// - bytes read with the `getrandom` system call,
+/// - bytes output by the `rdseed` x86_64 instruction, if the `seeder_x86_64_rdseed` feature is
+///   enabled and the CPU supports it,
+/// - a user defined secret and a counter.
+///
+/// The bytes of the `getrandom` and `rdseed` sources go through the continuous health tests of
+/// NIST SP 800-90B (repetition count and adaptive proportion tests), at startup and for every
+/// seed. The seeder panics if one of the tests fails, as it means that the source is likely stuck.
+pub struct EntropyPoolSeeder {
+    secret: u128,
+    counter: u128,
+    getrandom_health: HealthTests,
+    #[cfg(feature = "seeder_x86_64_rdseed")]
+    rdseed_health: Option<HealthTests>,
+}
+
+impl EntropyPoolSeeder {
+    /// Creates a new seeder from a user defined secret.
+    ///
+    /// Important:
+    /// ----------
+    ///
+    /// As for the [`UnixSeeder`](crate::seeders::UnixSeeder), the secret ensures the quality of
+    /// the seeds in scenarios where the other sources could be predicted by an attacker who can
+    /// not read data from the process where `concrete-csprng` is running.
+    pub fn new(secret: u128) -> EntropyPoolSeeder {
+        let mut seeder = EntropyPoolSeeder {
+            secret,
+            counter: 0,
+            getrandom_health: HealthTests::new(),
+            #[cfg(feature = "seeder_x86_64_rdseed")]
+            rdseed_health: is_x86_feature_detected!("rdseed").then(HealthTests::new),
+        };
+        // Startup health tests.
+        for _ in 0..STARTUP_BYTES / BYTES_PER_SOURCE {
+            seeder.getrandom_bytes();
+            #[cfg(feature = "seeder_x86_64_rdseed")]
+            seeder.rdseed_bytes();
+        }
+        seeder
+    }
+
+    fn getrandom_bytes(&mut self) -> [u8; BYTES_PER_SOURCE] {
+        let mut bytes = [0u8; BYTES_PER_SOURCE];
+        getrandom::getrandom(&mut bytes).expect("Failed to read from getrandom.");
+        checked("getrandom", &mut self.getrandom_health, bytes)
+    }
+
+    #[cfg(feature = "seeder_x86_64_rdseed")]
+    fn rdseed_bytes(&mut self) -> Option<[u8; BYTES_PER_SOURCE]> {
+        let health = self.rdseed_health.as_mut()?;
+        let mut bytes = [0u8; BYTES_PER_SOURCE];
+        for chunk in bytes.chunks_exact_mut(16) {
+            chunk.copy_from_slice(&super::rdseed::rdseed_random_m128().to_ne_bytes());
+        }
+        Some(checked("rdseed", health, bytes))
+    }
+}
+
+// Runs the health tests on the bytes of a source, and panics if they fail.
+fn checked(
+    source: &str,
+    health: &mut HealthTests,
+    bytes: [u8; BYTES_PER_SOURCE],
+) -> [u8; BYTES_PER_SOURCE] {
+    if let Err(failure) = health.check(&bytes) {
+        panic!(
+            "The {} entropy source failed the {} health test.",
+            source, failure
+        );
+    }
+    bytes
+}
+
+impl Seeder for EntropyPoolSeeder {
+    fn seed(&mut self) -> Seed {
+        let mut hasher = Sha256::new();
+        hasher.update(DOMAIN_SEPARATOR);
+        hasher.update(self.counter.to_le_bytes());
+        hasher.update(self.secret.to_le_bytes());
+        hasher.update(self.getrandom_bytes());
+        #[cfg(feature = "seeder_x86_64_rdseed")]
+        if let Some(bytes) = self.rdseed_bytes() {
+            hasher.update(bytes);
+        }
+        self.counter = self.counter.wrapping_add(1);
+        let digest = hasher.finalize();
+        let mut output = [0u8; 16];
+        output.copy_from_slice(&digest[..16]);
+        Seed(u128::from_le_bytes(output))
+    }
+
+    fn is_available() -> bool {
+        getrandom::getrandom(&mut [0u8; 1]).is_ok()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::seeders::generic_tests::check_seeder_fixed_sequences_different;
+
+    #[test]
+    fn check_bounded_sequence_difference() {
+        check_seeder_fixed_sequences_different(EntropyPoolSeeder::new);
+    }
+
+    #[test]
+    fn check_is_available() {
+        assert!(EntropyPoolSeeder::is_available());
+    }
+}
@@ -3,6 +3,11 @@ mod apple_secure_enclave_seeder;
 #[cfg(target_os = "macos")]
 pub use apple_secure_enclave_seeder::AppleSecureEnclaveSeeder;
 
+#[cfg(feature = "seeder_entropy_pool")]
+mod entropy_pool;
+#[cfg(feature = "seeder_entropy_pool")]
+pub use entropy_pool::EntropyPoolSeeder;
+
 #[cfg(feature = "seeder_x86_64_rdseed")]
 mod rdseed;
 #[cfg(feature = "seeder_x86_64_rdseed")]
@@ -0,0 +1,266 @@
+//! Contains material needed to turn noise dispersions into failure probabilities.
+//!
+//! All the functions of this module assume a message encoded in the most significant bits of the
+//! ciphertext: a message of `message_bits` bits, preceded by `padding_bits` bits of padding, is
+//! multiplied by $\Delta = 2^{\log\_2(q) - \mathsf{message\\_bits} - \mathsf{padding\\_bits}}$.
+//! Such a message is wrongly decoded as soon as the absolute value of the noise exceeds
+//! $\frac{\Delta}{2}$.
+use concrete_core::prelude::{DispersionParameter, LweDimension, PolynomialSize, Variance};
+
+use super::*;
+
+/// Computes the probability that the decryption of a ciphertext with a given noise dispersion
+/// returns a wrong message.
+/// # Example
+/// ```rust
+/// use concrete_core::prelude::Variance;
+/// use concrete_npe::estimate_decryption_failure_probability;
+/// let dispersion = Variance(2_f64.powi(-40));
+/// let message_bits = 4;
+/// let padding_bits = 1;
+/// let p_fail = estimate_decryption_failure_probability(dispersion, message_bits, padding_bits);
+/// assert!(p_fail < 2_f64.powi(-40));
+/// ```
+pub fn estimate_decryption_failure_probability<D>(
+    dispersion: D,
+    message_bits: u32,
+    padding_bits: u32,
+) -> f64
+where
+    D: DispersionParameter,
+{
+    let half_delta = 2_f64.powi(-((message_bits + padding_bits + 1) as i32));
+    erfc(half_delta / (dispersion.get_standard_dev() * std::f64::consts::SQRT_2))
+}
+
+/// Computes the probability that a programmable bootstrap of a ciphertext with a given noise
+/// dispersion selects a wrong value in the lookup table.
+///
+/// On top of the dispersion of the input ciphertext, this takes into account the noise added by the
+/// modulus switching from $q$ to $2N$ performed at the start of the bootstrap (see
+/// [`estimate_modulus_switching_noise_with_binary_key`]).
+/// # Example
+/// ```rust
+/// use concrete_core::prelude::{LweDimension, PolynomialSize, Variance};
+/// use concrete_npe::{estimate_decryption_failure_probability, estimate_pbs_failure_probability};
+/// let lwe_mask_size = LweDimension(630);
+/// let poly_size = PolynomialSize(1024);
+/// let dispersion = Variance(2_f64.powi(-30));
+/// let p_fail = estimate_pbs_failure_probability(lwe_mask_size, poly_size, dispersion, 2, 1, 64);
+/// // The modulus switching can only make things worse
+/// assert!(p_fail > estimate_decryption_failure_probability(dispersion, 2, 1));
+/// ```
+pub fn estimate_pbs_failure_probability<D>(
+    lwe_mask_size: LweDimension,
+    poly_size: PolynomialSize,
+    dispersion: D,
+    message_bits: u32,
+    padding_bits: u32,
+    log2_modulus: u32,
+) -> f64
+where
+    D: DispersionParameter,
+{
+    let dispersion_after_ms = estimate_modulus_switching_noise_with_binary_key(
+        lwe_mask_size,
+        log2_modulus_switching_target(poly_size),
+        dispersion,
+        log2_modulus,
+    );
+    estimate_decryption_failure_probability(dispersion_after_ms, message_bits, padding_bits)
+}
+
+/// Computes the probability that at least one of several independent operations fails, given the
+/// failure probability of every operation.
+/// # Panics
+/// Panics if one of the failure probabilities is not in $[0, 1]$.
+/// # Example
+/// ```rust
+/// use concrete_npe::estimate_circuit_failure_probability;
+/// let p_fail = estimate_circuit_failure_probability(&[2_f64.powi(-40); 1000]);
+/// assert!((p_fail - 1000. * 2_f64.powi(-40)).abs() < 2_f64.powi(-60));
+/// ```
+pub fn estimate_circuit_failure_probability(failure_probabilities: &[f64]) -> f64 {
+    assert!(
+        failure_probabilities.iter().all(|p| (0. ..=1.).contains(p)),
+        "The failure probabilities must be in [0, 1]."
+    );
+    // 1 - prod(1 - p_i), computed in log space to keep the precision for small probabilities.
+    let log_success: f64 = failure_probabilities.iter().map(|p| (-p).ln_1p()).sum();
+    -log_success.exp_m1()
+}
+
+/// Computes the failure probability each of `operation_count` independent operations must not
+/// exceed, for a whole circuit to fail with at most a `target_failure_probability` probability.
+/// # Panics
+/// Panics if `target_failure_probability` is not in $[0, 1]$.
+/// # Example
+/// ```rust
+/// use concrete_npe::{
+///     estimate_circuit_failure_probability, estimate_operation_failure_probability_for_circuit,
+/// };
+/// let target = 2_f64.powi(-30);
+/// let p_op = estimate_operation_failure_probability_for_circuit(target, 1000);
+/// let p_circuit = estimate_circuit_failure_probability(&vec![p_op; 1000]);
+/// assert!((p_circuit - target).abs() < 2_f64.powi(-60));
+/// ```
+pub fn estimate_operation_failure_probability_for_circuit(
+    target_failure_probability: f64,
+    operation_count: usize,
+) -> f64 {
+    assert!(
+        (0. ..=1.).contains(&target_failure_probability),
+        "The target failure probability must be in [0, 1], got {}.",
+        target_failure_probability
+    );
+    -((-target_failure_probability).ln_1p() / operation_count as f64).exp_m1()
+}
+
+/// Computes the maximal variance a ciphertext can have, for its decryption to return a wrong
+/// message with at most a `target_failure_probability` probability.
+/// # Panics
+/// Panics if `target_failure_probability` is not in $(0, 1]$.
+/// # Example
+/// ```rust
+/// use concrete_core::prelude::Variance;
+/// use concrete_npe::{
+///     estimate_decryption_failure_probability, estimate_maximal_variance_for_failure_probability,
+/// };
+/// let target = 2_f64.powi(-40);
+/// let variance = estimate_maximal_variance_for_failure_probability(target, 4, 1);
+/// let p_fail = estimate_decryption_failure_probability(variance, 4, 1);
+/// assert!(((p_fail - target) / target).abs() < 1e-6);
+/// ```
+pub fn estimate_maximal_variance_for_failure_probability(
+    target_failure_probability: f64,
+    message_bits: u32,
+    padding_bits: u32,
+) -> Variance {
+    assert!(
+        target_failure_probability > 0. && target_failure_probability <= 1.,
+        "The target failure probability must be in (0, 1], got {}.",
+        target_failure_probability
+    );
+    let half_delta = 2_f64.powi(-((message_bits + padding_bits + 1) as i32));
+    let std_dev =
+        half_delta / (std::f64::consts::SQRT_2 * inverse_erfc(target_failure_probability));
+    Variance(square(std_dev))
+}
+
+/// Computes the maximal variance the input ciphertext of a programmable bootstrap can have, for
+/// the bootstrap to select a wrong value in the lookup table with at most a
+/// `target_failure_probability` probability.
+///
+/// Returns `None` if the noise added by the modulus switching alone already exceeds the target.
+/// # Panics
+/// Panics if `target_failure_probability` is not in $(0, 1]$.
+/// # Example
+/// ```rust
+/// use concrete_core::prelude::{LweDimension, PolynomialSize, Variance};
+/// use concrete_npe::{
+///     estimate_maximal_pbs_input_variance_for_failure_probability,
+///     estimate_pbs_failure_probability,
+/// };
+/// let lwe_mask_size = LweDimension(630);
+/// let poly_size = PolynomialSize(1024);
+/// let target = 2_f64.powi(-20);
+/// let variance = estimate_maximal_pbs_input_variance_for_failure_probability(
+///     lwe_mask_size,
+///     poly_size,
+///     target,
+///     2,
+///     1,
+///     64,
+/// )
+/// .unwrap();
+/// let p_fail = estimate_pbs_failure_probability(lwe_mask_size, poly_size, variance, 2, 1, 64);
+/// assert!(((p_fail - target) / target).abs() < 1e-6);
+///
+/// // Too many message bits for this polynomial size.
+/// assert!(estimate_maximal_pbs_input_variance_for_failure_probability(
+///     lwe_mask_size,
+///     poly_size,
+///     target,
+///     9,
+///     1,
+///     64,
+/// )
+/// .is_none());
+/// ```
+pub fn estimate_maximal_pbs_input_variance_for_failure_probability(
+    lwe_mask_size: LweDimension,
+    poly_size: PolynomialSize,
+    target_failure_probability: f64,
+    message_bits: u32,
+    padding_bits: u32,
+    log2_modulus: u32,
+) -> Option<Variance> {
+    let maximal_variance = estimate_maximal_variance_for_failure_probability(
+        target_failure_probability,
+        message_bits,
+        padding_bits,
+    );
+    // The modulus switching noise is the input variance plus a constant term.
+    let modulus_switching_variance = estimate_modulus_switching_noise_with_binary_key(
+        lwe_mask_size,
+        log2_modulus_switching_target(poly_size),
+        Variance(0.),
+        log2_modulus,
+    );
+    let remaining = maximal_variance.get_modular_variance(log2_modulus)
+        - modulus_switching_variance.get_modular_variance(log2_modulus);
+    if remaining <= 0. {
+        None
+    } else {
+        Some(Variance::from_modular_variance(remaining, log2_modulus))
+    }
+}
+
+/// Returns the number of bits kept by the modulus switching of a bootstrap, i.e. $\log\_2(2N)$.
+fn log2_modulus_switching_target(poly_size: PolynomialSize) -> usize {
+    poly_size.log2().0 + 1
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        erfc, estimate_maximal_variance_for_failure_probability,
+        estimate_operation_failure_probability_for_circuit, inverse_erfc,
+    };
+    use crate::tools::tests::assert_float_eq;
+
+    #[test]
+    fn erfc_known_values() {
+        assert_float_eq!(erfc(0.), 1., eps = 1e-15);
+        assert_float_eq!(erfc(0.5), 0.4795001221869535, eps = 1e-15);
+        assert_float_eq!(erfc(1.), 0.15729920705028513, eps = 1e-15);
+        assert_float_eq!(erfc(-1.), 1.8427007929497148, eps = 1e-15);
+        assert_float_eq!(erfc(3.) / 2.209049699858544e-5, 1., eps = 1e-12);
+        assert_float_eq!(erfc(6.) / 2.1519736712498913e-17, 1., eps = 1e-12);
+    }
+
+    #[test]
+    #[should_panic]
+    fn inverse_erfc_rejects_zero() {
+        inverse_erfc(0.);
+    }
+
+    #[test]
+    #[should_panic]
+    fn maximal_variance_rejects_probability_above_one() {
+        estimate_maximal_variance_for_failure_probability(1.5, 4, 1);
+    }
+
+    #[test]
+    #[should_panic]
+    fn operation_failure_probability_rejects_negative_probability() {
+        estimate_operation_failure_probability_for_circuit(-0.1, 10);
+    }
+
+    #[test]
+    fn inverse_erfc_round_trip() {
+        for p in [0.5, 1e-3, 1e-9, 2_f64.powi(-40), 2_f64.powi(-128)] {
+            assert_float_eq!(erfc(inverse_erfc(p)) / p, 1., eps = 1e-9);
+        }
+    }
+}
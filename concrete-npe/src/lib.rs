@@ -36,10 +36,12 @@
 
 #![allow(clippy::upper_case_acronyms)]
 
+mod failure_probability;
 mod key_dispersion;
 mod operators;
 mod tools;
 
+pub use failure_probability::*;
 pub use key_dispersion::*;
 pub use operators::*;
 pub use tools::*;
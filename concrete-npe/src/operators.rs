@@ -1,7 +1,7 @@
 use concrete_core::commons::numeric::{CastInto, UnsignedInteger};
 use concrete_core::prelude::{
-    BinaryKeyKind, DecompositionBaseLog, DecompositionLevelCount, ExtractedBitsCount,
-    GlweDimension, LweDimension, PolynomialSize,
+    BinaryKeyKind, CiphertextModulusLog, DecompositionBaseLog, DecompositionLevelCount,
+    ExtractedBitsCount, GlweDimension, LweDimension, PolynomialSize,
 };
 /// Contains material needed to estimate the growth of the noise when performing homomorphic
 /// computation
@@ -457,6 +457,108 @@ where
     )
 }
 
+/// Computes the dispersion of an LWE ciphertext encrypted with a binary key, after it was
+/// compressed by switching its coefficients to $2^{\text{ciphertext\_modulus\_log}}$, and
+/// decompressed.
+/// # Example
+/// ```rust
+/// use concrete_core::prelude::{CiphertextModulusLog, LweDimension, Variance};
+/// use concrete_core::prelude::DispersionParameter;
+/// use concrete_npe::{
+///     estimate_lwe_ciphertext_compression_noise, estimate_modulus_switching_noise_with_binary_key,
+/// };
+/// let lwe_mask_size = LweDimension(630);
+/// let ciphertext_modulus_log = CiphertextModulusLog(16);
+/// let dispersion_input = Variance(2_f64.powi(-40));
+/// let var_out = estimate_lwe_ciphertext_compression_noise::<_>(
+///     lwe_mask_size,
+///     ciphertext_modulus_log,
+///     dispersion_input,
+///     64,
+/// );
+/// // The rounding of the mask dominates the input noise.
+/// assert!(var_out.get_variance() > dispersion_input.get_variance());
+/// assert_eq!(
+///     var_out,
+///     estimate_modulus_switching_noise_with_binary_key(
+///         lwe_mask_size,
+///         ciphertext_modulus_log.0,
+///         dispersion_input,
+///         64,
+///     )
+/// );
+/// ```
+pub fn estimate_lwe_ciphertext_compression_noise<D>(
+    lwe_mask_size: LweDimension,
+    ciphertext_modulus_log: CiphertextModulusLog,
+    var_in: D,
+    log2_modulus: u32,
+) -> Variance
+where
+    D: DispersionParameter,
+{
+    estimate_modulus_switching_noise_with_binary_key(
+        lwe_mask_size,
+        ciphertext_modulus_log.0,
+        var_in,
+        log2_modulus,
+    )
+}
+
+/// Computes the dispersion of the coefficients of a GLWE ciphertext encrypted with a binary key,
+/// after it was compressed by switching its coefficients to
+/// $2^{\text{ciphertext\_modulus\_log}}$, and decompressed.
+/// # Example
+/// ```rust
+/// use concrete_core::prelude::{
+///     CiphertextModulusLog, DispersionParameter, GlweDimension, LweDimension, PolynomialSize,
+///     Variance,
+/// };
+/// use concrete_npe::{
+///     estimate_glwe_ciphertext_compression_noise, estimate_lwe_ciphertext_compression_noise,
+/// };
+/// let glwe_mask_size = GlweDimension(1);
+/// let poly_size = PolynomialSize(1024);
+/// let ciphertext_modulus_log = CiphertextModulusLog(16);
+/// let dispersion_input = Variance(2_f64.powi(-40));
+/// let var_out = estimate_glwe_ciphertext_compression_noise::<_>(
+///     glwe_mask_size,
+///     poly_size,
+///     ciphertext_modulus_log,
+///     dispersion_input,
+///     64,
+/// );
+/// // A GLWE ciphertext behaves as an LWE ciphertext with a mask of k * N coefficients.
+/// assert!(var_out.get_variance() > dispersion_input.get_variance());
+/// assert_eq!(
+///     var_out,
+///     estimate_lwe_ciphertext_compression_noise(
+///         LweDimension(glwe_mask_size.0 * poly_size.0),
+///         ciphertext_modulus_log,
+///         dispersion_input,
+///         64,
+///     )
+/// );
+/// ```
+pub fn estimate_glwe_ciphertext_compression_noise<D>(
+    glwe_mask_size: GlweDimension,
+    poly_size: PolynomialSize,
+    ciphertext_modulus_log: CiphertextModulusLog,
+    var_in: D,
+    log2_modulus: u32,
+) -> Variance
+where
+    D: DispersionParameter,
+{
+    // Each coefficient of the decrypted polynomial involves k * N rounded mask coefficients.
+    estimate_modulus_switching_noise_with_binary_key(
+        LweDimension(glwe_mask_size.0 * poly_size.0),
+        ciphertext_modulus_log.0,
+        var_in,
+        log2_modulus,
+    )
+}
+
 /// Computes the dispersion of the constant terms of a GLWE after an LWE
 /// to GLWE keyswitch.
 /// # Example
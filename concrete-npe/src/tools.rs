@@ -30,6 +30,59 @@ where
     x * x
 }
 
+/// Computes the complementary error function $\mathrm{erfc}(x) = 1 - \mathrm{erf}(x)$.
+///
+/// The Taylor series of $\mathrm{erf}$ is used for small inputs, and a continued fraction for large
+/// ones, so that the (tiny) values taken by $\mathrm{erfc}$ in the tail keep a good relative
+/// precision.
+pub(super) fn erfc(x: f64) -> f64 {
+    if x < 0. {
+        return 2. - erfc(-x);
+    }
+    if x < 2. {
+        // erf(x) = 2 / sqrt(pi) * sum_n (-1)^n x^(2n+1) / (n! (2n+1))
+        let mut term = x;
+        let mut sum = x;
+        let mut n = 0.;
+        while term.abs() > f64::EPSILON * sum.abs() {
+            n += 1.;
+            term *= -square(x) / n;
+            sum += term / (2. * n + 1.);
+        }
+        1. - 2. / std::f64::consts::PI.sqrt() * sum
+    } else {
+        // erfc(x) = exp(-x^2) / sqrt(pi) * 1 / (x + (1/2) / (x + 1 / (x + (3/2) / (x + ...))))
+        let mut t = x;
+        for k in (1..=60).rev() {
+            t = x + (k as f64 / 2.) / t;
+        }
+        (-square(x)).exp() / (std::f64::consts::PI.sqrt() * t)
+    }
+}
+
+/// Computes the inverse of the complementary error function, for a value in $(0, 1]$.
+///
+/// # Panics
+/// Panics if `p` is not in $(0, 1]$.
+pub(super) fn inverse_erfc(p: f64) -> f64 {
+    assert!(
+        p > 0. && p <= 1.,
+        "The inverse complementary error function is computed for values in (0, 1], got {}.",
+        p
+    );
+    // erfc is decreasing, and erfc(27) is below the smallest positive f64.
+    let (mut low, mut high) = (0_f64, 27_f64);
+    for _ in 0..100 {
+        let middle = (low + high) / 2.;
+        if erfc(middle) > p {
+            low = middle;
+        } else {
+            high = middle;
+        }
+    }
+    (low + high) / 2.
+}
+
 #[cfg(test)]
 pub mod tests {
     #[macro_export]